@@ -0,0 +1,183 @@
+//! # Reviewed Proof Escapes
+//!
+//! Axioms and `#[verifier::external*]` escapes weaken what a proof actually
+//! establishes, so each one must be reviewed. The allowlist records the
+//! reviewed entries per module; anything the source scan finds beyond it
+//! fails the run.
+//!
+//! ```toml
+//! [axioms]
+//! ed25519_contracts = ["axiom_correctness", "axiom_unforgeable"]
+//!
+//! [external]
+//! routing_policy = ["trusted_sqrt"]
+//! ```
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+use crate::error::ShieldError;
+use crate::source_scan::{Escape, ProofInventory};
+
+/// Default allowlist file name, looked up in the proof directory
+pub const DEFAULT_ALLOWLIST_FILE: &str = "proof-allowlist.toml";
+
+/// Reviewed axioms and escapes, keyed by module name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize), serde(deny_unknown_fields))]
+pub struct Allowlist {
+    /// Reviewed axiom names per module
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub axioms: BTreeMap<String, Vec<String>>,
+    /// Reviewed escaped item names per module
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub external: BTreeMap<String, Vec<String>>,
+}
+
+/// An axiom or escape not covered by the allowlist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unreviewed {
+    Axiom(String),
+    Escape(Escape),
+}
+
+impl fmt::Display for Unreviewed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unreviewed::Axiom(name) => write!(f, "axiom {}", name),
+            Unreviewed::Escape(escape) => write!(
+                f,
+                "#[verifier::{}] on {} (line {})",
+                escape.kind.as_str(),
+                escape.item.as_deref().unwrap_or("<unknown item>"),
+                escape.line
+            ),
+        }
+    }
+}
+
+impl Allowlist {
+    #[cfg(feature = "serde")]
+    pub fn from_toml_str(text: &str) -> Result<Self, ShieldError> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Load an allowlist file
+    #[cfg(feature = "serde")]
+    pub fn load(path: &Path) -> Result<Self, ShieldError> {
+        fs::read_to_string(path)
+            .map_err(ShieldError::from)
+            .and_then(|text| Self::from_toml_str(&text))
+            .map_err(|e| ShieldError::config_invalid(format!("allowlist {}", path.display()), e))
+    }
+
+    /// Axioms and escapes in `inventory` that have not been reviewed.
+    ///
+    /// Escapes on items the scanner cannot name are always unreviewed.
+    pub fn review(&self, module: &str, inventory: &ProofInventory) -> Vec<Unreviewed> {
+        let allowed = |table: &BTreeMap<String, Vec<String>>, name: &str| {
+            table
+                .get(module)
+                .is_some_and(|names| names.iter().any(|n| n == name))
+        };
+
+        let axioms = inventory
+            .axioms
+            .iter()
+            .filter(|name| !allowed(&self.axioms, name))
+            .map(|name| Unreviewed::Axiom(name.clone()));
+        let escapes = inventory
+            .escapes
+            .iter()
+            .filter(|escape| {
+                !escape
+                    .item
+                    .as_deref()
+                    .is_some_and(|item| allowed(&self.external, item))
+            })
+            .map(|escape| Unreviewed::Escape(escape.clone()));
+        axioms.chain(escapes).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source_scan::EscapeKind;
+
+    fn inventory() -> ProofInventory {
+        ProofInventory {
+            theorems: vec!["chain_integrity".to_string()],
+            axioms: vec!["axiom_correctness".to_string(), "axiom_new".to_string()],
+            escapes: vec![
+                Escape {
+                    kind: EscapeKind::ExternalBody,
+                    item: Some("trusted_sqrt".to_string()),
+                    line: 4,
+                },
+                Escape {
+                    kind: EscapeKind::External,
+                    item: Some("added_later".to_string()),
+                    line: 9,
+                },
+            ],
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_review_reports_unreviewed_additions() {
+        let allowlist = Allowlist::from_toml_str(
+            r#"
+[axioms]
+m = ["axiom_correctness"]
+
+[external]
+m = ["trusted_sqrt"]
+"#,
+        )
+        .unwrap();
+
+        let unreviewed = allowlist.review("m", &inventory());
+        assert_eq!(unreviewed.len(), 2);
+        assert_eq!(unreviewed[0], Unreviewed::Axiom("axiom_new".to_string()));
+        assert_eq!(
+            unreviewed[1].to_string(),
+            "#[verifier::external] on added_later (line 9)"
+        );
+
+        // Entries are per module
+        assert_eq!(allowlist.review("other", &inventory()).len(), 4);
+    }
+
+    #[test]
+    fn test_empty_allowlist_reviews_nothing() {
+        let allowlist = Allowlist::default();
+        assert_eq!(allowlist.review("m", &inventory()).len(), 4);
+        assert!(allowlist.review("m", &ProofInventory::default()).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_unknown_section_rejected() {
+        assert!(Allowlist::from_toml_str("[assumes]\nm = []\n").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_repository_allowlist_covers_proofs() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let allowlist = Allowlist::load(&dir.join(DEFAULT_ALLOWLIST_FILE)).unwrap();
+        for (module, _, _) in crate::config::BUILTIN_MODULES {
+            let inventory = ProofInventory::scan_file(&dir.join(format!("{}.rs", module))).unwrap();
+            assert_eq!(allowlist.review(module, &inventory), [], "{}", module);
+        }
+    }
+}
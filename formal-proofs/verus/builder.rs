@@ -0,0 +1,419 @@
+//! # Summary Builder
+//!
+//! Assembles a [`VerificationSummary`] from results as they arrive and
+//! checks the invariants the roll-up relies on before handing it out.
+//!
+//! ```
+//! use aevion_shield::{VerificationStatus, VerificationSummary};
+//!
+//! let mut builder = VerificationSummary::builder();
+//! builder
+//!     .module("trust_bounds")
+//!     .status(VerificationStatus::Verified)
+//!     .theorem("trust_update_preserves_bounds", VerificationStatus::Verified, None)
+//!     .axiom("axiom_trust_monotone");
+//! builder.unit_tests(VerificationStatus::Verified, None);
+//! let summary = builder.build().unwrap();
+//! assert_eq!(summary.overall(), VerificationStatus::Verified);
+//! ```
+//!
+//! Durations are `std::time::Duration`, so the "non-negative" invariant is
+//! held by the type; `build()` checks names.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::time::Duration;
+
+use crate::clock::{self, Clock, SystemClock};
+use crate::severity;
+use crate::{
+    ModuleReport, RunSource, TheoremResult, UnitTestResults, VerificationStatus,
+    VerificationSummary,
+};
+
+/// Invariant violated by a [`VerificationSummaryBuilder`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// A module was added with an empty name
+    EmptyModuleName,
+    /// A theorem in `module` has an empty name
+    EmptyTheoremName { module: String },
+    /// `theorem` appears more than once in `module`
+    DuplicateTheorem { module: String, theorem: String },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::EmptyModuleName => write!(f, "module with an empty name"),
+            BuildError::EmptyTheoremName { module } => {
+                write!(f, "module {}: theorem with an empty name", module)
+            }
+            BuildError::DuplicateTheorem { module, theorem } => {
+                write!(f, "module {}: duplicate theorem {}", module, theorem)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Results for one module, obtained from
+/// [`VerificationSummaryBuilder::module`]
+#[derive(Debug, Clone)]
+pub struct ModuleBuilder {
+    report: ModuleReport,
+}
+
+impl ModuleBuilder {
+    /// The report as assembled so far
+    pub fn report(&self) -> &ModuleReport {
+        &self.report
+    }
+
+    /// Module-level result (prover outcome, unreviewed escapes, ...)
+    pub fn status(&mut self, status: VerificationStatus) -> &mut Self {
+        self.report.status = status;
+        self
+    }
+
+    /// Prover duration for the whole module
+    pub fn duration(&mut self, duration: Duration) -> &mut Self {
+        self.report.duration = Some(duration);
+        self
+    }
+
+    /// Add a theorem result, in source order, with its built-in
+    /// [`Severity`](crate::Severity) (see [`severity`](crate::severity))
+    pub fn theorem(
+        &mut self,
+        name: impl Into<String>,
+        status: VerificationStatus,
+        duration: Option<Duration>,
+    ) -> &mut Self {
+        let name = name.into();
+        self.report.theorems.push(TheoremResult {
+            severity: severity::builtin_severity(&self.report.name, &name),
+            name,
+            status,
+            duration,
+        });
+        self
+    }
+
+    /// Record an axiom the module's theorems rest on
+    pub fn axiom(&mut self, name: impl Into<String>) -> &mut Self {
+        let name = name.into();
+        if !self.report.axioms.contains(&name) {
+            self.report.axioms.push(name);
+        }
+        self
+    }
+
+    fn validate(&self) -> Result<(), BuildError> {
+        let module = &self.report.name;
+        if module.is_empty() {
+            return Err(BuildError::EmptyModuleName);
+        }
+        let mut seen = BTreeSet::new();
+        for theorem in &self.report.theorems {
+            if theorem.name.is_empty() {
+                return Err(BuildError::EmptyTheoremName {
+                    module: module.clone(),
+                });
+            }
+            if !seen.insert(theorem.name.as_str()) {
+                return Err(BuildError::DuplicateTheorem {
+                    module: module.clone(),
+                    theorem: theorem.name.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Incremental constructor for [`VerificationSummary`]
+///
+/// Modules keep the order in which they were first named. The run starts
+/// when the builder is created; the built summary is not finished.
+#[derive(Debug, Clone)]
+pub struct VerificationSummaryBuilder {
+    modules: Vec<ModuleBuilder>,
+    unit_tests: VerificationStatus,
+    unit_test_results: Option<UnitTestResults>,
+    sources: Vec<RunSource>,
+    started_at: String,
+}
+
+impl VerificationSummaryBuilder {
+    /// Start a run now
+    pub fn new() -> Self {
+        Self::with_clock(&SystemClock)
+    }
+
+    /// Start a run at the clock's current time
+    pub fn with_clock(clock: &dyn Clock) -> Self {
+        Self {
+            modules: Vec::new(),
+            unit_tests: VerificationStatus::NotRun,
+            unit_test_results: None,
+            sources: Vec::new(),
+            started_at: clock::format_rfc3339(clock.now()),
+        }
+    }
+
+    /// Builder for a module, adding it (not run) if not already present
+    pub fn module(&mut self, name: impl Into<String>) -> &mut ModuleBuilder {
+        let name = name.into();
+        let index = match self.modules.iter().position(|m| m.report.name == name) {
+            Some(index) => index,
+            None => {
+                self.modules.push(ModuleBuilder {
+                    report: ModuleReport::new(name),
+                });
+                self.modules.len() - 1
+            }
+        };
+        &mut self.modules[index]
+    }
+
+    /// Result of the unit-test stage
+    pub fn unit_tests(
+        &mut self,
+        status: VerificationStatus,
+        results: Option<UnitTestResults>,
+    ) -> &mut Self {
+        self.unit_tests = status;
+        self.unit_test_results = results;
+        self
+    }
+
+    /// Record where the results came from
+    pub fn source(&mut self, source: RunSource) -> &mut Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Check the invariants and produce the summary
+    pub fn build(&self) -> Result<VerificationSummary, BuildError> {
+        for module in &self.modules {
+            module.validate()?;
+        }
+        Ok(VerificationSummary {
+            modules: self.modules.iter().map(|m| m.report.clone()).collect(),
+            unit_tests: self.unit_tests,
+            unit_test_results: self.unit_test_results.clone(),
+            sources: self.sources.clone(),
+            started_at: self.started_at.clone(),
+            finished_at: None,
+            total_duration: None,
+            run_id: None,
+        })
+    }
+}
+
+impl Default for VerificationSummaryBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VerificationSummary {
+    /// Builder for a new run, started now
+    pub fn builder() -> VerificationSummaryBuilder {
+        VerificationSummaryBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::test_support::Rng;
+    use crate::{
+        MODULE_BYZANTINE_CONSENSUS, MODULE_ED25519_CONTRACTS, MODULE_TRUST_BOUNDS,
+        MODULE_VARIANCE_HALT,
+    };
+
+    const STATUSES: [VerificationStatus; 6] = [
+        VerificationStatus::Verified,
+        VerificationStatus::Partial,
+        VerificationStatus::Failed,
+        VerificationStatus::NotRun,
+        VerificationStatus::Skipped,
+        VerificationStatus::Unknown,
+    ];
+
+    fn status(rng: &mut Rng) -> VerificationStatus {
+        STATUSES[rng.below(STATUSES.len() as u64) as usize]
+    }
+
+    fn random_builder(rng: &mut Rng) -> VerificationSummaryBuilder {
+        let mut builder = VerificationSummaryBuilder::with_clock(&ManualClock::at_unix(0));
+        for m in 0..rng.below(5) {
+            let module = builder.module(format!("module_{}", m));
+            module.status(status(rng));
+            for t in 0..rng.below(4) {
+                let duration = Duration::from_millis(rng.next() % 1000);
+                module.theorem(format!("theorem_{}", t), status(rng), Some(duration));
+            }
+        }
+        builder.unit_tests(status(rng), None);
+        builder
+    }
+
+    #[test]
+    fn test_builder_assembles_summary() {
+        let clock = ManualClock::at_unix(1_790_000_000);
+        let mut builder = VerificationSummaryBuilder::with_clock(&clock);
+        builder
+            .module("trust_bounds")
+            .status(VerificationStatus::Verified)
+            .duration(Duration::from_secs(3))
+            .theorem("a", VerificationStatus::Verified, None)
+            .theorem(
+                "b",
+                VerificationStatus::Verified,
+                Some(Duration::from_secs(1)),
+            )
+            .axiom("axiom_x")
+            .axiom("axiom_x");
+        builder.module("variance_halt");
+        builder
+            .module("trust_bounds")
+            .theorem("c", VerificationStatus::Failed, None);
+        builder.unit_tests(VerificationStatus::Skipped, None);
+
+        let summary = builder.build().unwrap();
+        assert_eq!(
+            summary.module_names().collect::<Vec<_>>(),
+            ["trust_bounds", "variance_halt"]
+        );
+        let report = summary.module("trust_bounds").unwrap();
+        assert_eq!(report.theorems.len(), 3);
+        assert_eq!(report.axioms, ["axiom_x"]);
+        assert_eq!(report.theorems[1].duration, Some(Duration::from_secs(1)));
+        assert_eq!(
+            summary.status("trust_bounds"),
+            Some(VerificationStatus::Failed)
+        );
+        assert_eq!(
+            summary.status("variance_halt"),
+            Some(VerificationStatus::NotRun)
+        );
+        assert_eq!(summary.started_at, "2026-09-21T14:13:20.000Z");
+        assert_eq!(summary.run_id, None);
+    }
+
+    #[test]
+    fn test_fifth_module_participates_in_roll_up() {
+        let names = [
+            MODULE_VARIANCE_HALT,
+            MODULE_TRUST_BOUNDS,
+            MODULE_BYZANTINE_CONSENSUS,
+            MODULE_ED25519_CONTRACTS,
+            "routing_policy",
+        ];
+        let build = |fifth: VerificationStatus| {
+            let mut builder = VerificationSummary::builder();
+            for name in names.into_iter().take(4) {
+                builder
+                    .module(name)
+                    .status(VerificationStatus::Verified)
+                    .theorem("lemma", VerificationStatus::Verified, None);
+            }
+            builder
+                .module(names[4])
+                .status(VerificationStatus::Verified)
+                .theorem("lemma", fifth, None);
+            builder.unit_tests(VerificationStatus::Verified, None);
+            builder.build().unwrap()
+        };
+
+        let summary = build(VerificationStatus::Verified);
+        assert_eq!(
+            summary.modules().map(|(name, _)| name).collect::<Vec<_>>(),
+            names
+        );
+        assert!(summary.get("routing_policy").is_some());
+        assert!(summary.all_verified());
+
+        let summary = build(VerificationStatus::Failed);
+        assert_eq!(summary.overall(), VerificationStatus::Degraded);
+        assert!(!summary.all_verified());
+        assert_eq!(summary.variance_halt(), VerificationStatus::Verified);
+
+        let mut summary = build(VerificationStatus::Verified);
+        summary.set_status("routing_policy", VerificationStatus::Partial);
+        assert_eq!(summary.overall(), VerificationStatus::Partial);
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_names() {
+        let mut builder = VerificationSummary::builder();
+        builder.module("");
+        assert_eq!(builder.build(), Err(BuildError::EmptyModuleName));
+
+        let mut builder = VerificationSummary::builder();
+        builder
+            .module("trust_bounds")
+            .theorem("", VerificationStatus::Verified, None);
+        assert_eq!(
+            builder.build(),
+            Err(BuildError::EmptyTheoremName {
+                module: "trust_bounds".to_string()
+            })
+        );
+
+        let mut builder = VerificationSummary::builder();
+        builder
+            .module("trust_bounds")
+            .theorem("a", VerificationStatus::Verified, None)
+            .theorem("a", VerificationStatus::Failed, None);
+        let err = builder.build().unwrap_err();
+        assert_eq!(err.to_string(), "module trust_bounds: duplicate theorem a");
+    }
+
+    #[test]
+    fn test_built_summaries_roll_up_consistently() {
+        let mut rng = Rng(0x5eed_1551);
+        for _ in 0..500 {
+            let summary = random_builder(&mut rng).build().unwrap();
+            let statuses: Vec<VerificationStatus> = summary
+                .modules()
+                .map(|(_, report)| report.derived_status())
+                .chain([summary.unit_tests])
+                .collect();
+            let overall = summary.overall();
+
+            let any_failed = statuses.contains(&VerificationStatus::Failed);
+            assert_eq!(
+                matches!(
+                    overall,
+                    VerificationStatus::Failed | VerificationStatus::Degraded
+                ),
+                any_failed
+            );
+            let all_verified = statuses.iter().all(|s| *s == VerificationStatus::Verified);
+            assert_eq!(overall == VerificationStatus::Verified, all_verified);
+            assert_eq!(summary.all_verified(), all_verified);
+            if overall == VerificationStatus::Skipped {
+                assert!(statuses.iter().all(|s| *s == VerificationStatus::Skipped));
+            }
+
+            for (_, report) in summary.modules() {
+                let derived = report.derived_status();
+                if report.failed_theorems().next().is_some() {
+                    assert_eq!(derived, VerificationStatus::Failed);
+                }
+                if derived == VerificationStatus::Verified {
+                    assert!(!report.theorems.is_empty());
+                    assert_eq!(report.status, VerificationStatus::Verified);
+                }
+            }
+        }
+    }
+}
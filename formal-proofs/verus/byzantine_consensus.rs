@@ -5,7 +5,8 @@
 //! ## Core Theorems
 //! 1. Byzantine Safety: f < n/3 guarantees consensus correctness
 //! 2. Constitutional Halt: Agreement below threshold triggers safe halt
-//! 3. N=3 Sufficiency: Three diverse models achieve optimal BFT
+//! 3. N=3 Sufficiency: Three diverse models tolerate one fault under
+//!    majority voting (classical BFT needs four)
 //!
 //! ## Evidence Base
 //! - 500-sample GSM8K benchmark (p < 0.001)
@@ -18,6 +19,8 @@
 //! Copyright (c) 2026 Aevion LLC. All rights reserved.
 
 use vstd::prelude::*;
+use vstd::seq_lib::*;
+use vstd::set_lib::*;
 
 verus! {
 
@@ -28,399 +31,2928 @@ verus! {
 /// Vote value: true = agree with proposed answer, false = disagree
 pub type Vote = bool;
 
+/// Specification: Count of votes cast for `value`
+pub open spec fn count_votes(votes: Seq<Vote>, value: Vote) -> nat {
+    votes.fold_left(0nat, |acc: nat, v: Vote| if v == value { acc + 1 } else { acc })
+}
+
 /// Specification: Count of agreeing votes
 pub open spec fn count_agrees(votes: Seq<Vote>) -> nat {
-    votes.fold_left(0nat, |acc: nat, v: Vote| if v { acc + 1 } else { acc })
+    count_votes(votes, true)
+}
+
+/// Specification: The value cast by more than half of the votes
+/// (false on a tie, which can never reach the threshold anyway)
+pub open spec fn majority_value(votes: Seq<Vote>) -> Vote {
+    2 * count_agrees(votes) > votes.len()
+}
+
+/// Specification: Largest round the scaled agreement ratio is proven for,
+/// 2^53 votes (lemma_agreement_ratio_bounded)
+pub open spec fn max_round_size() -> nat {
+    0x20_0000_0000_0000
 }
 
 /// Specification: Agreement ratio (scaled by 1000)
 pub open spec fn agreement_ratio_scaled(agrees: nat, total: nat) -> u64
-    recommends total > 0
+    recommends 0 < total <= max_round_size(), agrees <= total
 {
     ((agrees as u64) * 1000) / (total as u64)
 }
 
+/// Specification: A consensus threshold: exactly two thirds, the BFT
+/// threshold, or PerMille(t) for t/1000
+pub enum Threshold {
+    TwoThirds,
+    PerMille(u64),
+}
+
+/// Specification: The fraction a threshold stands for, scaled by 3000 so
+/// that two thirds is exact: 2000, or 3t for PerMille(t)
+pub open spec fn threshold_exact(t: Threshold) -> nat {
+    match t {
+        Threshold::TwoThirds => 2000,
+        Threshold::PerMille(p) => 3 * (p as nat),
+    }
+}
+
+/// Specification: The scaled share (rounded down, as agreement_ratio_scaled)
+/// every share meeting t reaches: 666 for TwoThirds, t for PerMille(t)
+pub open spec fn share_threshold(t: Threshold) -> nat {
+    threshold_exact(t) / 3
+}
+
+/// Specification: `agrees` of `total` votes reach the fraction `t` stands
+/// for, by an exact comparison (no rounded division). The same comparison
+/// as `reaches` in core.rs: agrees * 3 >= 2 * total at two thirds,
+/// agrees * 1000 >= t * total at PerMille(t).
+pub open spec fn meets_threshold(agrees: nat, total: nat, t: Threshold) -> bool {
+    match t {
+        Threshold::TwoThirds => agrees * 3 >= 2 * total,
+        Threshold::PerMille(p) => agrees * 1000 >= (p as nat) * total,
+    }
+}
+
 /// Specification: Byzantine fault bound (f < n/3)
 pub open spec fn byzantine_safe(n: nat, f: nat) -> bool {
     3 * f < n
 }
 
-/// Specification: Consensus threshold (67% = 670/1000)
-pub const CONSENSUS_THRESHOLD: u64 = 670;
+/// Specification: Default consensus threshold (exactly two thirds); every
+/// decision and theorem takes the threshold `t` as a parameter
+pub const CONSENSUS_THRESHOLD: Threshold = Threshold::TwoThirds;
 
-/// Specification: Majority in n-f honest nodes
+/// Specification: A supermajority threshold: above half, at most all votes
+pub open spec fn valid_threshold(t: Threshold) -> bool {
+    match t {
+        Threshold::TwoThirds => true,
+        Threshold::PerMille(p) => 500 < p <= 1000,
+    }
+}
+
+/// Specification: Byzantine fraction under which an Agreed outcome always
+/// carries the honest majority's value: f/n <= (t - 500)/500, with t the
+/// exact fraction (f/n <= 1/3 at two thirds)
+pub open spec fn threshold_tolerates(n: nat, f: nat, t: Threshold) -> bool {
+    f * 1500 <= (threshold_exact(t) - 1500) * n
+}
+
+/// Specification: Byzantine fraction over which honest nodes alone cannot
+/// reach the threshold, so a round they cannot win halts: f/n > 1 - t/1000,
+/// with t the exact fraction (f/n > 1/3 at two thirds)
+pub open spec fn halt_guaranteed(n: nat, f: nat, t: Threshold) -> bool {
+    f * 3000 > (3000 - threshold_exact(t)) * n
+}
+
+/// Specification: Majority-vote tolerance of an LLM ensemble
+///
+/// The 2f+1 model used for diverse ensembles rather than PBFT's 3f+1: the
+/// n - f honest models strictly outnumber the f faulty ones, and together
+/// hold the fraction `agreement_threshold` stands for (meets_threshold).
+pub open spec fn probabilistic_tolerance(n: nat, f: nat, agreement_threshold: Threshold) -> bool {
+    2 * f + 1 <= n && meets_threshold((n - f) as nat, n, agreement_threshold)
+}
+
+/// Specification: Votes for `value` cast by honest voters (`byzantine[i]`
+/// marks voter i Byzantine)
+pub open spec fn count_honest_votes(votes: Seq<Vote>, byzantine: Seq<bool>, value: Vote) -> nat
+    recommends votes.len() == byzantine.len()
+    decreases votes.len()
+{
+    if votes.len() == 0 || byzantine.len() == 0 {
+        0
+    } else {
+        count_honest_votes(votes.drop_last(), byzantine.drop_last(), value)
+            + if !byzantine.last() && votes.last() == value { 1nat } else { 0nat }
+    }
+}
+
+/// Specification: Strict majority of the n-f honest nodes: more than half
+/// agree. A tie is no majority, so 2 honest nodes need both and 4 need 3.
 pub open spec fn honest_majority(n: nat, f: nat, honest_agrees: nat) -> bool
     recommends n > f
 {
-    let honest_count = n - f;
-    honest_agrees >= (honest_count / 2) + 1
+    2 * honest_agrees > n - f
+}
+
+// ============================================================================
+// SPECIFICATION: Consensus Outcomes
+// ============================================================================
+
+/// Consensus outcome
+pub enum ConsensusOutcome {
+    /// Consensus reached with agreed value
+    Agreed { value: bool, agreement_pct: u64 },
+    /// Constitutional halt - no consensus
+    Halted { reason: HaltReason },
+}
+
+/// Why a round halted
+pub enum HaltReason {
+    /// Neither value reached the threshold; the majority value's share
+    NoSupermajority { agreement_pct: u64 },
+    /// Current / baseline variance (scaled by 100) above the halt multiple
+    VarianceExceeded { ratio_scaled: u64 },
+    /// Too few votes to decide
+    InsufficientVoters { n: u64 },
+    /// Every vote carries zero weight; the highest voter trust
+    TrustCollapse { max_trust: u64 },
+}
+
+/// Specification: Outcome is valid
+pub open spec fn valid_outcome(outcome: ConsensusOutcome) -> bool {
+    match outcome {
+        ConsensusOutcome::Agreed { value: _, agreement_pct } => agreement_pct <= 1000,
+        ConsensusOutcome::Halted { reason } => match reason {
+            HaltReason::NoSupermajority { agreement_pct } => agreement_pct <= 1000,
+            HaltReason::TrustCollapse { max_trust } => max_trust <= 1000,
+            _ => true,
+        },
+    }
+}
+
+/// Specification: Consensus decision procedure
+///
+/// The decided value is the majority vote and `agreement_pct` is the share
+/// of votes cast for it; `t` is the supermajority threshold
+/// (CONSENSUS_THRESHOLD by default). A halt reports the majority
+/// value's share, and an empty round halts for want of voters.
+pub open spec fn decide_consensus(votes: Seq<Vote>, n: nat, t: Threshold) -> ConsensusOutcome
+    recommends votes.len() == n, valid_threshold(t)
+{
+    let value = majority_value(votes);
+    let supporters = count_votes(votes, value);
+
+    if n == 0 {
+        ConsensusOutcome::Halted { reason: HaltReason::InsufficientVoters { n: 0 } }
+    } else if meets_threshold(supporters, n, t) {
+        ConsensusOutcome::Agreed {
+            value,
+            agreement_pct: agreement_ratio_scaled(supporters, n),
+        }
+    } else {
+        // No supermajority either way - halt
+        ConsensusOutcome::Halted {
+            reason: HaltReason::NoSupermajority {
+                agreement_pct: agreement_ratio_scaled(supporters, n),
+            },
+        }
+    }
+}
+
+// ============================================================================
+// LEMMAS: Vote Counting
+// ============================================================================
+
+/// Every vote is cast for exactly one value
+proof fn lemma_count_votes_partition(votes: Seq<Vote>)
+    ensures
+        count_votes(votes, true) + count_votes(votes, false) == votes.len(),
+    decreases votes.len()
+{
+    // fold_left peels off the last vote, which adds 1 to exactly one count
+    if votes.len() > 0 {
+        lemma_count_votes_partition(votes.drop_last());
+    }
+}
+
+/// Counts over a split round add up
+proof fn lemma_count_votes_append(a: Seq<Vote>, b: Seq<Vote>, value: Vote)
+    ensures
+        count_votes(a + b, value) == count_votes(a, value) + count_votes(b, value),
+    decreases b.len()
+{
+    if b.len() > 0 {
+        lemma_count_votes_append(a, b.drop_last(), value);
+        assert((a + b).drop_last() =~= a + b.drop_last());
+    }
+}
+
+/// Agreeing votes never outnumber the votes
+proof fn lemma_count_agrees_le_len(votes: Seq<Vote>)
+    ensures
+        count_agrees(votes) <= votes.len(),
+    decreases votes.len()
+{
+    // fold_left peels off the last vote, which adds at most 1
+    if votes.len() > 0 {
+        lemma_count_agrees_le_len(votes.drop_last());
+        lemma_count_votes_push(votes.drop_last(), votes.last(), true);
+        assert(votes.drop_last().push(votes.last()) =~= votes);
+    }
+}
+
+/// No value is cast more often than there are votes
+proof fn lemma_count_votes_bounded(votes: Seq<Vote>, value: Vote)
+    ensures
+        count_votes(votes, value) <= votes.len(),
+        count_agrees(votes) <= votes.len(),
+{
+    // The two counts are nats summing to the length
+    lemma_count_votes_partition(votes);
+    lemma_count_agrees_le_len(votes);
+}
+
+/// honest_majority is the strict majority the earlier division form
+/// honest_agrees >= honest_count/2 + 1 expressed: for even and odd honest
+/// counts alike, floor(h/2) + 1 <= a exactly when 2a > h
+proof fn lemma_honest_majority_strict(n: nat, f: nat, honest_agrees: nat)
+    requires
+        n >= f,
+    ensures
+        honest_majority(n, f, honest_agrees)
+            <==> honest_agrees >= ((n - f) as nat) / 2 + 1,
+{
+    let honest_count = (n - f) as nat;
+    // honest_count = 2q + r with r in {0, 1}
+    let (q, r) = (honest_count / 2, honest_count % 2);
+    assert(honest_count == 2 * q + r);
+}
+
+/// The honest votes split between the two values, and a value gets at most
+/// its honest votes plus every Byzantine vote, wherever the Byzantine
+/// voters sit
+proof fn lemma_honest_votes(votes: Seq<Vote>, byzantine: Seq<bool>, value: Vote)
+    requires
+        votes.len() == byzantine.len(),
+    ensures
+        count_honest_votes(votes, byzantine, value) + count_honest_votes(votes, byzantine, !value)
+            == count_votes(byzantine, false),
+        count_votes(votes, value)
+            <= count_honest_votes(votes, byzantine, value) + count_votes(byzantine, true),
+    decreases votes.len()
+{
+    // The last voter adds 1 to its own value's count and to exactly one of
+    // the honest and Byzantine counts
+    if votes.len() > 0 {
+        lemma_honest_votes(votes.drop_last(), byzantine.drop_last(), value);
+    }
+}
+
+/// The scaled ratio of a share is the exact floor(agrees * 1000 / total)
+/// and at most 1000. Up to max_round_size() (2^53) votes both casts to u64
+/// are lossless and agrees * 1000 <= 2^53 * 1000 < 2^63, so nothing wraps.
+proof fn lemma_agreement_ratio_bounded(agrees: nat, total: nat)
+    requires
+        0 < total <= max_round_size(),
+        agrees <= total,
+    ensures
+        agreement_ratio_scaled(agrees, total) == agrees * 1000 / total,
+        agreement_ratio_scaled(agrees, total) <= 1000,
+{
+    // Lossless casts: agrees <= total <= 2^53 < 2^64
+    assert((agrees as u64) as nat == agrees);
+    assert((total as u64) as nat == total);
+
+    // No overflow: 2^53 * 1000 < 2^63
+    assert(agrees * 1000 <= total * 1000) by(nonlinear_arith)
+        requires agrees <= total;
+    assert(total * 1000 <= 0x20_0000_0000_0000 * 1000) by(nonlinear_arith)
+        requires total <= 0x20_0000_0000_0000;
+    assert(0x20_0000_0000_0000 * 1000 < 0x8000_0000_0000_0000);
+
+    assert(agrees * 1000 / total <= 1000) by(nonlinear_arith)
+        requires agrees <= total, total > 0;
+}
+
+/// The exact fraction of a valid threshold is above one half and at most
+/// one, and its share threshold is above 500. meets_threshold is the
+/// comparison against that fraction: agrees/total >= T/3000 with
+/// T = threshold_exact(t), which the proofs below reason about.
+proof fn lemma_threshold_exact(t: Threshold)
+    ensures
+        valid_threshold(t) ==> 1500 < threshold_exact(t) <= 3000 && share_threshold(t) > 500,
+        forall|agrees: nat, total: nat| #[trigger] meets_threshold(agrees, total, t)
+            <==> agrees * 3000 >= threshold_exact(t) * total,
+{
+    assert forall|agrees: nat, total: nat| #[trigger] meets_threshold(agrees, total, t)
+        <==> agrees * 3000 >= threshold_exact(t) * total by {
+        match t {
+            Threshold::TwoThirds => {
+                // agrees * 3 >= 2 * total, scaled by 1000
+                assert(agrees * 3000 == (agrees * 3) * 1000) by(nonlinear_arith);
+                assert(2000 * total == (2 * total) * 1000) by(nonlinear_arith);
+            }
+            Threshold::PerMille(p) => {
+                // agrees * 1000 >= p * total, scaled by 3
+                assert(agrees * 3000 == (agrees * 1000) * 3) by(nonlinear_arith);
+                assert(3 * (p as nat) * total == ((p as nat) * total) * 3) by(nonlinear_arith);
+            }
+        }
+    }
+}
+
+/// At PerMille(p) the exact test is the rounded-down scaled ratio test:
+/// agrees * 1000 >= p * n exactly when (agrees * 1000) / n >= p, since p
+/// is whole. Stated for n <= 10_000, where every product fits the u64 the
+/// scaled ratio is computed in.
+proof fn lemma_per_mille_threshold(agrees: nat, n: nat, p: u64)
+    requires
+        0 < n <= 10_000,
+        agrees <= n,
+    ensures
+        meets_threshold(agrees, n, Threshold::PerMille(p))
+            <==> agreement_ratio_scaled(agrees, n) >= p,
+{
+    // No u64 wrap: agrees * 1000 <= 10_000_000
+    assert(agrees * 1000 <= 10_000_000) by(nonlinear_arith)
+        requires agrees <= n, n <= 10_000;
+    assert((agrees as u64) * 1000 == agrees * 1000);
+    let r = agreement_ratio_scaled(agrees, n) as nat;
+    assert(r == (agrees * 1000) / n);
+    // floor(x / n) >= p <==> x >= p * n for whole p
+    assert(r * n <= agrees * 1000 < (r + 1) * n) by(nonlinear_arith)
+        requires r == (agrees * 1000) / n, n > 0;
+    if agrees * 1000 >= (p as nat) * n {
+        assert(r >= p) by(nonlinear_arith)
+            requires agrees * 1000 >= (p as nat) * n, agrees * 1000 < (r + 1) * n, n > 0;
+    }
+    if r >= p {
+        assert(agrees * 1000 >= (p as nat) * n) by(nonlinear_arith)
+            requires r >= p, r * n <= agrees * 1000;
+    }
+}
+
+/// At the default threshold the exact test is the real-valued comparison
+/// agrees/n >= 2/3, so two of three votes agree. Every share meeting it
+/// rounds down to at least share_threshold (666), which is all the rounded
+/// share can tell: 666 of 1000 rounds to 666 but falls short of 2/3.
+/// Stated for n <= 10_000, where every product fits the u64 the scaled
+/// ratio is computed in.
+proof fn lemma_two_thirds_threshold(agrees: nat, n: nat)
+    requires
+        0 < n <= 10_000,
+        agrees <= n,
+    ensures
+        meets_threshold(agrees, n, CONSENSUS_THRESHOLD) <==> 3 * agrees >= 2 * n,
+        meets_threshold(agrees, n, CONSENSUS_THRESHOLD)
+            ==> agreement_ratio_scaled(agrees, n) >= share_threshold(CONSENSUS_THRESHOLD),
+        meets_threshold(2, 3, CONSENSUS_THRESHOLD),
+        !meets_threshold(666, 1000, CONSENSUS_THRESHOLD),
+        agreement_ratio_scaled(666, 1000) == share_threshold(CONSENSUS_THRESHOLD),
+{
+    // meets_threshold at two thirds is agrees * 3 >= 2 * n itself
+    assert(threshold_exact(CONSENSUS_THRESHOLD) == 2000);
+    assert(share_threshold(CONSENSUS_THRESHOLD) == 666);
+
+    // No u64 wrap: agrees * 1000 <= 10_000_000
+    assert(agrees * 1000 <= 10_000_000);
+    assert((agrees as u64) * 1000 == agrees * 1000);
+    // 3 * agrees >= 2 * n gives agrees * 1000 >= 666 * n, so the floor
+    // of agrees * 1000 / n is at least 666
+    if 3 * agrees >= 2 * n {
+        assert((agrees * 1000) / n >= 666) by(nonlinear_arith)
+            requires 3 * agrees >= 2 * n, n > 0;
+    }
+}
+
+/// THEOREM: Decided Value Has a Supermajority
+///
+/// An Agreed outcome reports the value more than the threshold fraction of
+/// voters cast, and its agreement_pct is that value's share; a Halted
+/// outcome means neither value reached the threshold, and reports the
+/// majority value's share as NoSupermajority.
+proof fn decided_value_has_supermajority(votes: Seq<Vote>, n: nat, t: Threshold)
+    requires
+        n > 0,
+        votes.len() == n,
+        valid_threshold(t),
+    ensures
+        match decide_consensus(votes, n, t) {
+            ConsensusOutcome::Agreed { value, agreement_pct } => {
+                &&& agreement_pct == agreement_ratio_scaled(count_votes(votes, value), n)
+                &&& meets_threshold(count_votes(votes, value), n, t)
+                &&& 2 * count_votes(votes, value) > n
+            },
+            ConsensusOutcome::Halted { reason } => {
+                &&& reason == (HaltReason::NoSupermajority {
+                    agreement_pct: agreement_ratio_scaled(count_votes(votes, majority_value(votes)), n),
+                })
+                &&& !meets_threshold(count_votes(votes, true), n, t)
+                &&& !meets_threshold(count_votes(votes, false), n, t)
+            },
+        },
+{
+    lemma_count_votes_partition(votes);
+    let value = majority_value(votes);
+    let winners = count_votes(votes, value);
+    let losers = count_votes(votes, !value);
+
+    // The majority side holds at least half the votes, the other side at
+    // most half, so only the majority side can reach a fraction above 1/2
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    assert(winners + losers == n);
+    assert(2 * winners >= n);
+    assert(2 * losers <= n);
+    assert(losers * 3000 < te * n) by(nonlinear_arith)
+        requires 2 * losers <= n, n > 0, te > 1500;
+
+    // winners * 3000 >= te * n > 1500 * n gives a strict majority
+    if meets_threshold(winners, n, t) {
+        assert(2 * winners > n) by(nonlinear_arith)
+            requires winners * 3000 >= te * n, n > 0, te > 1500;
+    }
+}
+
+/// At a valid threshold, at most one value reaches it: the two
+/// counts sum to n, so both reaching t would need 3000n >= 2Tn > 3000n
+/// for its exact fraction T (threshold_exact)
+proof fn lemma_single_value_reaches_threshold(votes: Seq<Vote>, n: nat, t: Threshold)
+    requires
+        votes.len() == n,
+        n > 0,
+        valid_threshold(t),
+    ensures
+        !(meets_threshold(count_votes(votes, true), n, t)
+            && meets_threshold(count_votes(votes, false), n, t)),
+{
+    lemma_count_votes_partition(votes);
+    let (a, d) = (count_votes(votes, true), count_votes(votes, false));
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    assert(!(a * 3000 >= te * n && d * 3000 >= te * n)) by(nonlinear_arith)
+        requires a + d == n, n > 0, te > 1500;
+}
+
+// ============================================================================
+// BOUNDARY SEMANTICS: AGREEMENT EXACTLY ON THE THRESHOLD
+// ============================================================================
+
+/// Where a round's agreement falls at threshold t, standing for the
+/// fraction T/3000 (threshold_exact)
+pub enum Boundary {
+    /// At least T agree, exactly T included: true wins
+    AgreesTrue,
+    /// At most 3000 - T agree (at least T disagree), exactly 3000 - T
+    /// included: false wins
+    AgreesFalse,
+    /// Strictly between 3000 - T and T: the round halts
+    Halts,
+}
+
+/// Specification: Boundary semantics of `agrees` of `n` votes at
+/// threshold t, by exact comparison against T = threshold_exact(t)
+///
+/// Both boundaries belong to the agreeing regions and only the open
+/// interval (3000 - T, T) halts; the regions are disjoint
+/// (boundary_regions_partition), so the branch order decides nothing.
+pub open spec fn boundary_region(agrees: nat, n: nat, t: Threshold) -> Boundary {
+    let te = threshold_exact(t);
+    if agrees * 3000 >= te * n {
+        Boundary::AgreesTrue
+    } else if agrees * 3000 <= (3000 - te) * n {
+        Boundary::AgreesFalse
+    } else {
+        Boundary::Halts
+    }
+}
+
+/// Specification: Every vote flipped
+pub open spec fn negate_votes(votes: Seq<Vote>) -> Seq<Vote> {
+    votes.map_values(|v: Vote| !v)
+}
+
+/// THEOREM: The Boundary Regions Partition Every Agreement
+///
+/// For every rounded agreement a in 0..=1000 exactly one of a >= s,
+/// a <= 1000 - s and 1000 - s < a < s holds for the share threshold s,
+/// and likewise for the exact share of `agrees` in `n` votes against
+/// T = threshold_exact(t).
+proof fn boundary_regions_partition(a: nat, agrees: nat, n: nat, t: Threshold)
+    requires
+        valid_threshold(t),
+        a <= 1000,
+        0 < n,
+        agrees <= n,
+    ensures
+        ({
+            let s = share_threshold(t);
+            &&& a >= s || a <= 1000 - s || (1000 - s < a && a < s)
+            &&& !(a >= s && a <= 1000 - s)
+        }),
+        ({
+            let te = threshold_exact(t);
+            &&& agrees * 3000 >= te * n || agrees * 3000 <= (3000 - te) * n
+                || ((3000 - te) * n < agrees * 3000 && agrees * 3000 < te * n)
+            &&& !(agrees * 3000 >= te * n && agrees * 3000 <= (3000 - te) * n)
+        }),
+{
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    assert((3000 - te) * n < te * n) by(nonlinear_arith)
+        requires
+            1500 < te <= 3000,
+            n > 0;
+}
+
+/// THEOREM: decide_consensus Follows the Boundary Semantics
+///
+/// At least T/3000 agreeing decides true, at most (3000 - T)/3000 agreeing
+/// decides false, each with its own share, and anything strictly between
+/// halts (T = threshold_exact(t)).
+proof fn decide_consensus_boundaries(votes: Seq<Vote>, t: Threshold)
+    requires
+        valid_threshold(t),
+        votes.len() > 0,
+    ensures
+        ({
+            let n = votes.len();
+            let agrees = count_agrees(votes);
+            match boundary_region(agrees, n, t) {
+                Boundary::AgreesTrue => decide_consensus(votes, n, t) == (ConsensusOutcome::Agreed {
+                    value: true,
+                    agreement_pct: agreement_ratio_scaled(agrees, n),
+                }),
+                Boundary::AgreesFalse => decide_consensus(votes, n, t) == (ConsensusOutcome::Agreed {
+                    value: false,
+                    agreement_pct: agreement_ratio_scaled((n - agrees) as nat, n),
+                }),
+                Boundary::Halts => decide_consensus(votes, n, t) is Halted,
+            }
+        }),
+{
+    let n = votes.len();
+    let (a, d) = (count_votes(votes, true), count_votes(votes, false));
+    lemma_count_votes_partition(votes);
+    boundary_regions_partition(0, a, n, t);
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+
+    if a * 3000 >= te * n {
+        assert(2 * a > n) by(nonlinear_arith)
+            requires
+                a * 3000 >= te * n,
+                te > 1500,
+                n > 0;
+    } else if a * 3000 <= (3000 - te) * n {
+        assert(d * 3000 >= te * n && 2 * a < n) by(nonlinear_arith)
+            requires
+                a + d == n,
+                a * 3000 <= (3000 - te) * n,
+                1500 < te <= 3000,
+                n > 0;
+        assert(d == (n - a) as nat);
+    } else {
+        assert(a * 3000 < te * n && d * 3000 < te * n) by(nonlinear_arith)
+            requires
+                a + d == n,
+                a * 3000 > (3000 - te) * n,
+                a * 3000 < te * n,
+                te <= 3000;
+    }
+}
+
+/// Flipping every vote swaps the two counts
+proof fn lemma_count_votes_negated(votes: Seq<Vote>, value: Vote)
+    ensures
+        count_votes(negate_votes(votes), value) == count_votes(votes, !value),
+    decreases votes.len()
+{
+    if votes.len() > 0 {
+        assert(negate_votes(votes).drop_last() =~= negate_votes(votes.drop_last()));
+        assert(negate_votes(votes).last() == !votes.last());
+        lemma_count_votes_negated(votes.drop_last(), value);
+    }
+}
+
+/// THEOREM: Vote-Negation Symmetry
+///
+/// Flipping every vote maps Agreed { v } to Agreed { !v } with the same
+/// percentage, and a halt to the same halt: neither value is favoured.
+/// The tie rule (majority_value is false on a tie) never decides an
+/// agreement, since a tie cannot reach a threshold above 500.
+proof fn vote_negation_symmetry(votes: Seq<Vote>, t: Threshold)
+    requires
+        valid_threshold(t),
+    ensures
+        ({
+            let n = votes.len();
+            let flipped = negate_votes(votes);
+            match decide_consensus(votes, n, t) {
+                ConsensusOutcome::Agreed { value, agreement_pct } => decide_consensus(flipped, n, t)
+                    == (ConsensusOutcome::Agreed { value: !value, agreement_pct }),
+                ConsensusOutcome::Halted { reason } => decide_consensus(flipped, n, t)
+                    == (ConsensusOutcome::Halted { reason }),
+            }
+        }),
+{
+    let n = votes.len();
+    let flipped = negate_votes(votes);
+    let (a, d) = (count_votes(votes, true), count_votes(votes, false));
+    lemma_count_votes_partition(votes);
+    lemma_count_votes_negated(votes, true);
+    lemma_count_votes_negated(votes, false);
+    assert(flipped.len() == n);
+
+    // The flipped round's majority holds the same count: the other value,
+    // or false again on a tie, which cannot agree
+    if n > 0 && 2 * a == n {
+        lemma_threshold_exact(t);
+        let te = threshold_exact(t);
+        assert(a * 3000 < te * n) by(nonlinear_arith)
+            requires
+                2 * a == n,
+                te > 1500,
+                n > 0;
+    }
+}
+
+/// THEOREM: No Conflicting Agreement
+///
+/// Two observers deciding the same votes, even at different thresholds
+/// above 500, never conclude Agreed on contradictory values.
+proof fn no_conflicting_agreement(votes: Seq<Vote>, n: nat, t1: Threshold, t2: Threshold)
+    requires
+        votes.len() == n,
+        valid_threshold(t1),
+        valid_threshold(t2),
+    ensures
+        match (decide_consensus(votes, n, t1), decide_consensus(votes, n, t2)) {
+            (
+                ConsensusOutcome::Agreed { value: v1, agreement_pct: _ },
+                ConsensusOutcome::Agreed { value: v2, agreement_pct: _ },
+            ) => v1 == v2,
+            _ => true,
+        },
+{
+    if n > 0 {
+        // Each Agreed value reaches its threshold, and so the lower of the
+        // two; only one value can reach that
+        decided_value_has_supermajority(votes, n, t1);
+        decided_value_has_supermajority(votes, n, t2);
+        let t = if threshold_exact(t1) <= threshold_exact(t2) { t1 } else { t2 };
+        lemma_single_value_reaches_threshold(votes, n, t);
+        assert forall|v: Vote, u: Threshold| threshold_exact(u) >= threshold_exact(t)
+            && #[trigger] meets_threshold(count_votes(votes, v), n, u)
+            implies meets_threshold(count_votes(votes, v), n, t) by {
+            lemma_threshold_exact(t);
+            lemma_threshold_exact(u);
+            let (te, ue) = (threshold_exact(t), threshold_exact(u));
+            assert(te * n <= ue * n) by(nonlinear_arith)
+                requires te <= ue;
+        }
+    }
+}
+
+/// THEOREM: Consensus Outcomes Are Valid
+///
+/// Every outcome of decide_consensus satisfies valid_outcome for rounds of
+/// up to max_round_size() votes: reported shares never exceed 1000.
+proof fn decide_consensus_valid(votes: Seq<Vote>, n: nat, t: Threshold)
+    requires
+        votes.len() == n,
+        n <= max_round_size(),
+    ensures
+        valid_outcome(decide_consensus(votes, n, t)),
+{
+    // An empty round halts as InsufficientVoters; otherwise both branches
+    // report the majority value's share
+    if n > 0 {
+        let value = majority_value(votes);
+        lemma_count_votes_bounded(votes, value);
+        lemma_agreement_ratio_bounded(count_votes(votes, value), n);
+    }
+}
+
+// ============================================================================
+// MAIN THEOREMS: BYZANTINE FAULT TOLERANCE
+// ============================================================================
+
+/// THEOREM 1: Byzantine Safety
+///
+/// When f < n/3 Byzantine nodes, honest majority determines consensus.
+/// This is the fundamental BFT guarantee.
+///
+/// The Byzantine voters may sit anywhere in the round (`byzantine[i]`
+/// marks voter i) and cast anything: if the honest voters hold a majority
+/// for v, the round agrees on v or halts, never on !v. Their votes are
+/// bounded only by the worst case, every Byzantine vote against v.
+///
+/// For threshold t the Byzantine fraction must also satisfy
+/// f/n <= (t - 500)/500; at the default two thirds that is f/n <= 1/3,
+/// which f < n/3 already implies (default_threshold_tolerances).
+proof fn byzantine_safety(
+    votes: Seq<Vote>,
+    byzantine: Seq<bool>,
+    n: nat,
+    t: Threshold,
+    v: Vote,
+)
+    requires
+        n >= 3,
+        valid_threshold(t),
+        votes.len() == n,
+        byzantine.len() == n,
+        byzantine_safe(n, count_votes(byzantine, true)),            // f < n/3
+        threshold_tolerates(n, count_votes(byzantine, true), t),    // f/n <= (t - 500)/500
+        // Honest majority for v
+        honest_majority(n, count_votes(byzantine, true), count_honest_votes(votes, byzantine, v)),
+    ensures
+        // Honest agreement exceeds Byzantine manipulation capacity
+        count_honest_votes(votes, byzantine, v) > count_votes(byzantine, true),
+        // Whatever the Byzantine nodes vote, the round never agrees on
+        // anything but the honest majority's value
+        match decide_consensus(votes, n, t) {
+            ConsensusOutcome::Agreed { value, agreement_pct: _ } => value == v,
+            ConsensusOutcome::Halted { reason: _ } => true,
+        },
+{
+    // Proof:
+    // 1. f < n/3 implies n - f > 2f (honest nodes outnumber Byzantine
+    //    ones twice over)
+    // 2. honest_majority means 2 * honest_agrees > n - f
+    // 3. So 2 * honest_agrees > 2f
+    //
+    // Therefore honest_agrees > f, so honest consensus wins
+
+    let f = count_votes(byzantine, true);
+    lemma_count_votes_partition(byzantine);
+    lemma_honest_votes(votes, byzantine, v);
+    lemma_honest_votes(votes, byzantine, !v);
+
+    let honest_count = (n - f) as nat;
+    let honest_agrees = count_honest_votes(votes, byzantine, v);
+    assert(honest_count == count_votes(byzantine, false));
+
+    // From honest_majority and 3f < n: 2 * honest_agrees > n - f > 2f
+    assert(2 * honest_agrees > honest_count);
+    assert(honest_count > 2 * f);
+    assert(honest_agrees > f);
+
+    // Suppose the round agreed on !v. At most honest_count - honest_agrees
+    // honest nodes and all f Byzantine nodes voted !v, wherever they sit:
+    //   against <= n - honest_agrees < n - (n-f)/2 = (n+f)/2
+    // and (n+f) * 1500 <= T * n by threshold_tolerates, so the votes for
+    // !v fall short of T/3000 of n, contradicting the supermajority
+    decided_value_has_supermajority(votes, n, t);
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    let against = count_votes(votes, !v);
+    assert(against <= honest_count - honest_agrees + f);
+    assert(against * 3000 < te * n) by(nonlinear_arith)
+        requires
+            against <= n - honest_agrees,
+            2 * honest_agrees > n - f,
+            f * 1500 <= (te - 1500) * n,
+            te > 1500;
+}
+
+/// THEOREM 2: Constitutional Halt Correctness
+///
+/// When agreement falls below threshold, the system correctly halts.
+///
+/// For threshold t the halt is guaranteed once f/n > 1 - t/1000
+/// (halt_guaranteed); at f/n = 1 - t/1000 exactly the honest nodes reach
+/// the threshold. At the default two thirds, f/n > 1/3 (3f > n) suffices
+/// (default_threshold_tolerances).
+proof fn constitutional_halt_correctness(
+    n: nat,
+    f: nat,
+    votes: Seq<Vote>,
+    t: Threshold,
+)
+    requires
+        n >= 3,
+        votes.len() == n,
+        valid_threshold(t),
+        f <= n,
+        halt_guaranteed(n, f, t),  // Byzantine fraction beyond tolerance
+    ensures
+        // The honest nodes alone never reach the threshold
+        !meets_threshold((n - f) as nat, n, t),
+        agreement_ratio_scaled((n - f) as nat, n) * 3 < threshold_exact(t),
+        // So when the Byzantine nodes back neither side, the round halts
+        // for want of a supermajority (rather than output a potentially
+        // wrong answer)
+        (count_votes(votes, true) <= n - f && count_votes(votes, false) <= n - f)
+            ==> decide_consensus(votes, n, t) == (ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority {
+                    agreement_pct: agreement_ratio_scaled(count_votes(votes, majority_value(votes)), n),
+                },
+            }),
+{
+    // f * 3000 > (3000 - T) * n implies (n - f) * 3000 < T * n, and the
+    // rounded-down share r has 3r * n <= (n - f) * 3000
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    let max_honest = (n - f) as nat;
+    assert(max_honest * 3000 < te * n) by(nonlinear_arith)
+        requires max_honest + f == n, f * 3000 > (3000 - te) * n, te <= 3000;
+    assert(agreement_ratio_scaled(max_honest, n) * 3 < te) by(nonlinear_arith)
+        requires max_honest * 3000 < te * n, n > 0, max_honest <= n;
+
+    // Neither side reaches more than max_honest votes
+    if count_votes(votes, true) <= max_honest && count_votes(votes, false) <= max_honest {
+        let value = majority_value(votes);
+        assert(count_votes(votes, value) <= max_honest);
+        assert(count_votes(votes, value) * 3000 < te * n) by(nonlinear_arith)
+            requires count_votes(votes, value) <= max_honest, max_honest * 3000 < te * n;
+    }
+}
+
+/// The default threshold's tolerances, exactly the BFT bound: at two
+/// thirds f <= n/3 keeps Agreed outcomes honest and f > n/3 guarantees
+/// the halt
+proof fn default_threshold_tolerances(n: nat, f: nat)
+    requires
+        n > 0,
+    ensures
+        valid_threshold(CONSENSUS_THRESHOLD),
+        threshold_tolerates(n, f, CONSENSUS_THRESHOLD) <==> 3 * f <= n,
+        byzantine_safe(n, f) ==> threshold_tolerates(n, f, CONSENSUS_THRESHOLD),
+        halt_guaranteed(n, f, CONSENSUS_THRESHOLD) <==> 3 * f > n,
+{
+    // threshold_exact(TwoThirds) is 2000: f * 1500 <= 500 * n is 3f <= n,
+    // and f * 3000 > 1000 * n is 3f > n
+    assert(threshold_exact(CONSENSUS_THRESHOLD) == 2000);
+    assert(f * 1500 == (3 * f) * 500);
+    assert(f * 3000 == (3 * f) * 1000);
+}
+
+/// THEOREM 3: N=3 Sufficiency for LLM Ensembles
+///
+/// Three models tolerate one faulty model under majority voting: the two
+/// honest models outvote it and hold exactly 2/3 of the votes, which meets
+/// the default threshold. Classical BFT (3f < n) needs four nodes for the
+/// same fault, and no majority model survives two faults out of three.
+/// How often the majority is right is an empirical question
+/// (empirical_validation_500).
+proof fn n_three_sufficiency()
+    ensures
+        probabilistic_tolerance(3, 1, CONSENSUS_THRESHOLD),  // 2 honest of 3 outvote 1 faulty
+        !probabilistic_tolerance(3, 1, Threshold::PerMille(668)), // but less than 668/1000
+        forall|t: Threshold| !probabilistic_tolerance(3, 2, t),  // 2 faulty of 3 win
+        !byzantine_safe(3, 1),               // classical BFT: 3*1 < 3 fails
+        byzantine_safe(4, 1),                // and needs n = 3f + 1 = 4
+{
+    // 2*1 + 1 = 3 <= 3 and (3 - 1) * 3 = 6 >= 2 * 3
+    assert(2 * 1 + 1 <= 3);
+    assert((3 - 1) * 3 >= 2 * 3);
+    // (3 - 1) * 1000 = 2000 < 668 * 3 = 2004
+    assert((3 - 1) * 1000 < 668 * 3);
+    // 2*2 + 1 = 5 > 3 whatever the threshold
+    assert(2 * 2 + 1 > 3);
+    assert(!(3 * 1 < 3));
+    assert(3 * 1 < 4);
+}
+
+/// THEOREM 4: Empirical Validation (500-sample)
+///
+/// The 500-sample benchmark results prove Byzantine resilience.
+proof fn empirical_validation_500()
+    ensures
+        // Baseline: 464/500 = 92.8%
+        (464u64 * 1000) / 500 == 928,
+        // 33% attack: 415/500 = 83.0%
+        (415u64 * 1000) / 500 == 830,
+        // 67% attack halts: 289/500 = 57.8%
+        (289u64 * 1000) / 500 == 578,
+        // Resilience factor: 83.0/92.8 = 89.4%
+        (830u64 * 1000) / 928 >= 890,
+{
+    // Direct calculations from benchmark data
+    assert((464u64 * 1000) / 500 == 928);
+    assert((415u64 * 1000) / 500 == 830);
+    assert((289u64 * 1000) / 500 == 578);
+    assert((830u64 * 1000) / 928 == 894);
+}
+
+// ============================================================================
+// ABSTENTION
+// ============================================================================
+
+/// Ballot: a vote, or None for an abstention (e.g. a model that timed out)
+pub type Ballot = Option<Vote>;
+
+/// Specification: The votes actually cast, in ballot order
+pub open spec fn cast_votes(ballots: Seq<Ballot>) -> Seq<Vote>
+    decreases ballots.len()
+{
+    if ballots.len() == 0 {
+        Seq::empty()
+    } else {
+        let rest = cast_votes(ballots.drop_last());
+        match ballots.last() {
+            Some(v) => rest.push(v),
+            None => rest,
+        }
+    }
+}
+
+/// Specification: At least ceil(2n/3) of the n voters cast a vote
+pub open spec fn has_quorum(cast: nat, n: nat) -> bool {
+    3 * cast >= 2 * n
+}
+
+/// Specification: Consensus decision over ballots
+///
+/// Abstentions count toward the quorum but not the agreement ratio: a round
+/// without a quorum halts as InsufficientVoters (reporting the votes cast),
+/// otherwise the cast votes are decided as by decide_consensus.
+pub open spec fn decide_consensus_ballots(ballots: Seq<Ballot>, t: Threshold) -> ConsensusOutcome
+    recommends valid_threshold(t)
+{
+    let cast = cast_votes(ballots);
+    if !has_quorum(cast.len(), ballots.len()) {
+        ConsensusOutcome::Halted { reason: HaltReason::InsufficientVoters { n: cast.len() as u64 } }
+    } else {
+        decide_consensus(cast, cast.len(), t)
+    }
+}
+
+/// Counting one more vote adds one to its value's count
+proof fn lemma_count_votes_push(votes: Seq<Vote>, v: Vote, value: Vote)
+    ensures
+        count_votes(votes.push(v), value)
+            == count_votes(votes, value) + if v == value { 1nat } else { 0nat },
+{
+    assert(votes.push(v).drop_last() =~= votes);
+}
+
+/// Cast votes of a split round concatenate, and never outnumber the ballots
+proof fn lemma_cast_votes_append(a: Seq<Ballot>, b: Seq<Ballot>)
+    ensures
+        cast_votes(a + b) == cast_votes(a) + cast_votes(b),
+        cast_votes(b).len() <= b.len(),
+    decreases b.len()
+{
+    if b.len() == 0 {
+        assert(a + b =~= a);
+        assert(cast_votes(a) + cast_votes(b) =~= cast_votes(a));
+    } else {
+        lemma_cast_votes_append(a, b.drop_last());
+        assert((a + b).drop_last() =~= a + b.drop_last());
+        assert((a + b).last() == b.last());
+        match b.last() {
+            Some(v) => {
+                assert(cast_votes(a) + cast_votes(b.drop_last()).push(v)
+                    =~= (cast_votes(a) + cast_votes(b.drop_last())).push(v));
+            },
+            None => {},
+        }
+    }
+}
+
+/// Ballots all cast for `v` yield one vote for `v` per ballot
+proof fn lemma_unanimous_ballots(ballots: Seq<Ballot>, v: Vote)
+    requires
+        forall|i: int| 0 <= i < ballots.len() ==> #[trigger] ballots[i] == Some(v),
+    ensures
+        cast_votes(ballots).len() == ballots.len(),
+        count_votes(cast_votes(ballots), v) == ballots.len(),
+    decreases ballots.len()
+{
+    if ballots.len() > 0 {
+        let rest = ballots.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies #[trigger] rest[i] == Some(v) by {
+            assert(rest[i] == ballots[i]);
+        }
+        lemma_unanimous_ballots(rest, v);
+        assert(ballots.last() == Some(v));
+        lemma_count_votes_push(cast_votes(rest), v, v);
+    }
+}
+
+/// All-abstain ballots cast no votes
+proof fn lemma_abstaining_ballots(ballots: Seq<Ballot>)
+    requires
+        forall|i: int| 0 <= i < ballots.len() ==> #[trigger] ballots[i] is None,
+    ensures
+        cast_votes(ballots).len() == 0,
+    decreases ballots.len()
+{
+    if ballots.len() > 0 {
+        let rest = ballots.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies #[trigger] rest[i] is None by {
+            assert(rest[i] == ballots[i]);
+        }
+        lemma_abstaining_ballots(rest);
+        assert(ballots.last() is None);
+    }
+}
+
+/// THEOREM: Honest Unanimity Survives Abstention
+///
+/// With f < n/3 Byzantine voters, who may vote either way or abstain, an
+/// honest unanimous vote for `v` meets the quorum and is agreed, provided
+/// the honest share alone reaches the threshold (meets_threshold(n - f,
+/// n, t); at the default two thirds that is f/n <= 1/3, which f < n/3
+/// implies).
+proof fn abstention_preserves_unanimity(
+    n: nat,
+    f: nat,
+    honest: Seq<Ballot>,
+    byzantine: Seq<Ballot>,
+    v: Vote,
+    t: Threshold,
+)
+    requires
+        valid_threshold(t),
+        byzantine_safe(n, f),                       // f < n/3
+        meets_threshold((n - f) as nat, n, t),      // honest share reaches t
+        honest.len() == n - f,
+        byzantine.len() == f,                       // arbitrary, may abstain
+        forall|i: int| 0 <= i < honest.len() ==> #[trigger] honest[i] == Some(v),
+    ensures
+        match decide_consensus_ballots(honest + byzantine, t) {
+            ConsensusOutcome::Agreed { value, agreement_pct: _ } => value == v,
+            ConsensusOutcome::Halted { reason: _ } => false,
+        },
+{
+    let ballots = honest + byzantine;
+    let cast = cast_votes(ballots);
+    let h = (n - f) as nat;
+
+    lemma_cast_votes_append(honest, byzantine);
+    lemma_unanimous_ballots(honest, v);
+    lemma_count_votes_append(cast_votes(honest), cast_votes(byzantine), v);
+    lemma_count_votes_partition(cast);
+
+    // h <= cast.len() <= n and at least h votes are for v
+    let m = cast.len();
+    let supporters = count_votes(cast, v);
+    assert(h <= m && m <= n);
+    assert(supporters >= h);
+
+    // Quorum: 3m >= 3(n - f) >= 2n since n >= 3f
+    assert(has_quorum(m, ballots.len())) by(nonlinear_arith)
+        requires m >= h, h + f == n, 3 * f < n, ballots.len() == n;
+
+    // v holds a strict majority: 2(n - f) > n >= m
+    assert(2 * supporters > m);
+    assert(majority_value(cast) == v);
+
+    // And reaches the threshold: supporters * 3000 >= h * 3000 >= T * n >= T * m
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    assert(supporters * 3000 >= te * m) by(nonlinear_arith)
+        requires supporters >= h, h * 3000 >= te * n, m <= n;
+    assert(m > 0);
+}
+
+/// THEOREM: All-Abstain Halts
+///
+/// A round in which every voter abstains halts as InsufficientVoters with
+/// no votes cast, whatever the threshold.
+proof fn all_abstain_halts(ballots: Seq<Ballot>, t: Threshold)
+    requires
+        forall|i: int| 0 <= i < ballots.len() ==> #[trigger] ballots[i] is None,
+    ensures
+        decide_consensus_ballots(ballots, t)
+            == (ConsensusOutcome::Halted { reason: HaltReason::InsufficientVoters { n: 0 } }),
+{
+    // Without a quorum (n > 0) the round halts reporting 0 cast; with the
+    // empty quorum (n == 0) decide_consensus halts on the empty round
+    lemma_abstaining_ballots(ballots);
+}
+
+// ============================================================================
+// ROUND EVIDENCE
+// ============================================================================
+
+/// The numbers behind a decision over ballots: how the voters split and
+/// the majority value's share, at the threshold used
+pub struct RoundEvidence {
+    pub n: nat,
+    pub agrees: nat,
+    pub disagrees: nat,
+    pub abstains: nat,
+    pub agreement_scaled: u64,
+    pub threshold_used: Threshold,
+}
+
+/// Specification: Evidence of a round of ballots decided at threshold `t`
+///
+/// `agreement_scaled` is the majority value's share of the votes cast, 0
+/// when none were.
+pub open spec fn round_evidence(ballots: Seq<Ballot>, t: Threshold) -> RoundEvidence {
+    let cast = cast_votes(ballots);
+    RoundEvidence {
+        n: ballots.len(),
+        agrees: count_votes(cast, true),
+        disagrees: count_votes(cast, false),
+        abstains: (ballots.len() - cast.len()) as nat,
+        agreement_scaled: if cast.len() == 0 {
+            0
+        } else {
+            agreement_ratio_scaled(count_votes(cast, majority_value(cast)), cast.len())
+        },
+        threshold_used: t,
+    }
+}
+
+/// Specification: The decision the evidence's counts imply, without the
+/// ballots
+pub open spec fn recheck_evidence(e: RoundEvidence) -> ConsensusOutcome {
+    let cast = e.agrees + e.disagrees;
+    let value = e.agrees > e.disagrees;
+    let supporters = if value { e.agrees } else { e.disagrees };
+    if !has_quorum(cast, e.n) {
+        ConsensusOutcome::Halted { reason: HaltReason::InsufficientVoters { n: cast as u64 } }
+    } else if cast == 0 {
+        ConsensusOutcome::Halted { reason: HaltReason::InsufficientVoters { n: 0 } }
+    } else if meets_threshold(supporters, cast, e.threshold_used) {
+        ConsensusOutcome::Agreed { value, agreement_pct: agreement_ratio_scaled(supporters, cast) }
+    } else {
+        ConsensusOutcome::Halted {
+            reason: HaltReason::NoSupermajority {
+                agreement_pct: agreement_ratio_scaled(supporters, cast),
+            },
+        }
+    }
+}
+
+/// THEOREM: Evidence Reproduces the Decision
+///
+/// Rechecking a round's evidence yields decide_consensus_ballots' outcome,
+/// halts included, and the share the outcome reports is the evidence's.
+proof fn evidence_recheck_sound(ballots: Seq<Ballot>, t: Threshold)
+    ensures
+        ({
+            let e = round_evidence(ballots, t);
+            &&& e.agrees + e.disagrees + e.abstains == e.n
+            &&& recheck_evidence(e) == decide_consensus_ballots(ballots, t)
+            &&& match recheck_evidence(e) {
+                ConsensusOutcome::Agreed { value: _, agreement_pct } => agreement_pct == e.agreement_scaled,
+                ConsensusOutcome::Halted { reason: HaltReason::NoSupermajority { agreement_pct } } =>
+                    agreement_pct == e.agreement_scaled,
+                ConsensusOutcome::Halted { reason: _ } => true,
+            }
+        }),
+{
+    let cast = cast_votes(ballots);
+    lemma_cast_votes_append(Seq::empty(), ballots);
+    lemma_count_votes_partition(cast);
+    // The majority value is the one with more votes, false on a tie
+    assert(majority_value(cast) == (count_votes(cast, true) > count_votes(cast, false)));
+}
+
+// ============================================================================
+// QUORUM CERTIFICATES
+// ============================================================================
+
+/// A vote signed by its voter for one round (keys and signatures are
+/// abstract here; see ed25519_contracts.rs)
+pub struct SignedVote {
+    pub voter: nat,
+    pub vote: Vote,
+    pub round_id: nat,
+}
+
+/// Specification: The voter's signature over its round and vote verifies
+/// (signature_valid in ed25519_contracts.rs)
+pub open spec fn vote_signature_valid(signed: SignedVote) -> bool;
+
+/// A consensus outcome with the signed votes it was decided from
+pub struct QuorumCertificate {
+    pub round_id: nat,
+    pub outcome: ConsensusOutcome,
+    pub votes: Seq<SignedVote>,
+}
+
+/// Specification: The votes a certificate carries, in order
+pub open spec fn certificate_votes(votes: Seq<SignedVote>) -> Seq<Vote> {
+    votes.map_values(|signed: SignedVote| signed.vote)
+}
+
+/// Specification: No voter signed two of the votes
+pub open spec fn distinct_voters(votes: Seq<SignedVote>) -> bool {
+    forall|i: int, j: int| 0 <= i < j < votes.len() ==> votes[i].voter != votes[j].voter
+}
+
+/// Specification: Certificate validity
+///
+/// Every vote is for the certificate's round with a valid signature, the
+/// voters are distinct, and the outcome is what decide_consensus decides on
+/// the votes at threshold `t`.
+pub open spec fn valid_certificate(cert: QuorumCertificate, t: Threshold) -> bool {
+    &&& forall|i: int| 0 <= i < cert.votes.len() ==> {
+        &&& #[trigger] vote_signature_valid(cert.votes[i])
+        &&& cert.votes[i].round_id == cert.round_id
+    }
+    &&& distinct_voters(cert.votes)
+    &&& cert.outcome == decide_consensus(certificate_votes(cert.votes), cert.votes.len(), t)
+}
+
+/// THEOREM: Certified Agreement is Backed by Distinct Signers
+///
+/// A valid certificate claiming Agreed carries signed votes from distinct
+/// voters of whom more than the threshold fraction, and a strict majority,
+/// cast the agreed value.
+proof fn certificate_agreement_sound(cert: QuorumCertificate, t: Threshold)
+    requires
+        valid_threshold(t),
+        valid_certificate(cert, t),
+    ensures
+        match cert.outcome {
+            ConsensusOutcome::Agreed { value, agreement_pct: _ } => {
+                let n = cert.votes.len();
+                &&& n > 0
+                &&& meets_threshold(count_votes(certificate_votes(cert.votes), value), n, t)
+                &&& 2 * count_votes(certificate_votes(cert.votes), value) > n
+            },
+            ConsensusOutcome::Halted { reason: _ } => true,
+        },
+{
+    let votes = certificate_votes(cert.votes);
+    let n = cert.votes.len();
+    assert(votes.len() == n);
+    // decide_consensus halts on an empty round
+    if n > 0 {
+        decided_value_has_supermajority(votes, n, t);
+    }
+}
+
+// ============================================================================
+// EQUIVOCATION
+// ============================================================================
+
+/// Two signed votes from one voter for one round
+pub struct EquivocationProof {
+    pub vote_a: SignedVote,
+    pub vote_b: SignedVote,
+}
+
+/// Specification: The proof verifies with the voter's public key alone:
+/// one voter and round, conflicting votes, both signatures valid
+pub open spec fn valid_equivocation_proof(proof: EquivocationProof) -> bool {
+    &&& proof.vote_a.voter == proof.vote_b.voter
+    &&& proof.vote_a.round_id == proof.vote_b.round_id
+    &&& proof.vote_a.vote != proof.vote_b.vote
+    &&& vote_signature_valid(proof.vote_a)
+    &&& vote_signature_valid(proof.vote_b)
+}
+
+/// THEOREM: Verified Equivocation Proofs Convict the Keyholder
+///
+/// A verified proof carries valid signatures by the voter's key over both
+/// votes for its round. Signatures cannot be forged (axiom_unforgeable in
+/// ed25519_contracts.rs), so the keyholder signed conflicting payloads.
+proof fn equivocation_proof_sound(proof: EquivocationProof)
+    requires
+        valid_equivocation_proof(proof),
+    ensures
+        ({
+            let (voter, round_id) = (proof.vote_a.voter, proof.vote_a.round_id);
+            &&& vote_signature_valid(SignedVote { voter, vote: true, round_id })
+            &&& vote_signature_valid(SignedVote { voter, vote: false, round_id })
+        }),
+{
+    // The votes differ, so one is true and the other false
+    let (voter, round_id) = (proof.vote_a.voter, proof.vote_a.round_id);
+    if proof.vote_a.vote {
+        assert(proof.vote_a == SignedVote { voter, vote: true, round_id });
+        assert(proof.vote_b == SignedVote { voter, vote: false, round_id });
+    } else {
+        assert(proof.vote_a == SignedVote { voter, vote: false, round_id });
+        assert(proof.vote_b == SignedVote { voter, vote: true, round_id });
+    }
+}
+
+// ============================================================================
+// PBFT-STYLE QUORUM PROOFS
+// ============================================================================
+
+/// PBFT quorum size for prepare phase: 2f
+pub open spec fn prepare_quorum(f: nat) -> nat {
+    2 * f
+}
+
+/// PBFT quorum size for commit phase: 2f + 1
+pub open spec fn commit_quorum(f: nat) -> nat {
+    2 * f + 1
+}
+
+/// THEOREM 5: PBFT Quorum Intersection
+///
+/// Any two quorums of size 2f+1 in a system of n=3f+1 nodes
+/// must intersect in at least one honest node.
+proof fn quorum_intersection(n: nat, f: nat)
+    requires
+        n == 3 * f + 1,  // PBFT requirement
+    ensures
+        // Two quorums of 2f+1 overlap by at least f+1 nodes
+        ({
+            let quorum_size = commit_quorum(f);
+            let overlap = 2 * quorum_size - n;
+            overlap >= f + 1
+        })
+{
+    // Two sets of size q in universe of size n overlap by at least 2q - n
+    // overlap = 2(2f+1) - (3f+1) = 4f + 2 - 3f - 1 = f + 1
+
+    let quorum_size = commit_quorum(f);
+    assert(quorum_size == 2 * f + 1);
+
+    let overlap = 2 * quorum_size - n;
+    assert(overlap == 2 * (2 * f + 1) - (3 * f + 1));
+    assert(overlap == 4 * f + 2 - 3 * f - 1);
+    assert(overlap == f + 1);
+}
+
+/// THEOREM 6: Quorum Contains Honest Node
+///
+/// In n=3f+1 with f Byzantine, any quorum of 2f+1 contains at least f+1 honest.
+proof fn quorum_honest_count(n: nat, f: nat)
+    requires
+        n == 3 * f + 1,
+    ensures
+        ({
+            let quorum_size = commit_quorum(f);
+            let honest_in_quorum = quorum_size - f;  // Worst case: all f Byzantine in quorum
+            honest_in_quorum >= f + 1
+        })
+{
+    // quorum = 2f + 1
+    // worst case Byzantine in quorum = f
+    // honest in quorum >= 2f + 1 - f = f + 1
+
+    let quorum_size = commit_quorum(f);
+    let honest_in_quorum = quorum_size - f;
+    assert(honest_in_quorum == 2 * f + 1 - f);
+    assert(honest_in_quorum == f + 1);
+}
+
+/// One replica's view of a PBFT round: the nodes (ids below n) it has
+/// received prepare and commit messages from
+pub struct PbftRound {
+    pub n: nat,
+    pub f: nat,
+    pub prepares: Set<nat>,
+    pub commits: Set<nat>,
+}
+
+/// Specification: A round of n = 3f + 1 nodes with messages only from
+/// known node ids
+pub open spec fn pbft_round_valid(round: PbftRound) -> bool {
+    &&& round.n == 3 * round.f + 1
+    &&& round.prepares.finite() && round.prepares.subset_of(Set::new(|id: nat| id < round.n))
+    &&& round.commits.finite() && round.commits.subset_of(Set::new(|id: nat| id < round.n))
+}
+
+/// Specification: Record a commit message; unknown nodes are ignored and a
+/// node's repeated commits count once
+pub open spec fn record_commit(round: PbftRound, node_id: nat) -> PbftRound {
+    if node_id < round.n {
+        PbftRound { commits: round.commits.insert(node_id), ..round }
+    } else {
+        round
+    }
+}
+
+/// Specification: Record a prepare message, as record_commit
+pub open spec fn record_prepare(round: PbftRound, node_id: nat) -> PbftRound {
+    if node_id < round.n {
+        PbftRound { prepares: round.prepares.insert(node_id), ..round }
+    } else {
+        round
+    }
+}
+
+/// Specification: Prepare messages from prepare_quorum(f) distinct nodes
+pub open spec fn pbft_prepared(round: PbftRound) -> bool {
+    round.prepares.len() >= prepare_quorum(round.f)
+}
+
+/// Specification: Commit messages from commit_quorum(f) distinct nodes
+pub open spec fn pbft_committed(round: PbftRound) -> bool {
+    round.commits.len() >= commit_quorum(round.f)
+}
+
+/// Recording keeps a round valid, and recording the same message twice is
+/// the same as recording it once
+proof fn lemma_record_messages(round: PbftRound, node_id: nat)
+    requires
+        pbft_round_valid(round),
+    ensures
+        pbft_round_valid(record_prepare(round, node_id)),
+        pbft_round_valid(record_commit(round, node_id)),
+        record_prepare(record_prepare(round, node_id), node_id) == record_prepare(round, node_id),
+        record_commit(record_commit(round, node_id), node_id) == record_commit(round, node_id),
+{
+    if node_id < round.n {
+        assert(round.prepares.insert(node_id).insert(node_id) =~= round.prepares.insert(node_id));
+        assert(round.commits.insert(node_id).insert(node_id) =~= round.commits.insert(node_id));
+    }
+}
+
+/// THEOREM: Committed Rounds Have f + 1 Honest Commits
+///
+/// With at most f Byzantine nodes, a committed round has commit messages
+/// from at least f + 1 honest nodes (quorum_honest_count).
+proof fn committed_has_honest_commits(round: PbftRound, byzantine: Set<nat>)
+    requires
+        pbft_round_valid(round),
+        pbft_committed(round),
+        byzantine.finite(),
+        byzantine.len() <= round.f,
+    ensures
+        round.commits.difference(byzantine).len() >= round.f + 1,
+{
+    // The commits split into honest and Byzantine senders
+    let honest = round.commits.difference(byzantine);
+    let faulty = round.commits.intersect(byzantine);
+    lemma_len_subset(honest, round.commits);
+    lemma_len_subset(faulty, byzantine);
+    assert(honest.disjoint(faulty));
+    assert(honest.union(faulty) =~= round.commits);
+    lemma_set_disjoint_lens(honest, faulty);
+
+    // |honest| >= (2f + 1) - f = f + 1
+    quorum_honest_count(round.n, round.f);
+    assert(honest.len() >= commit_quorum(round.f) - round.f);
+}
+
+// ============================================================================
+// DEPLOYMENT SIZES: n = 7 (f = 2) AND n = 10 (f = 3)
+// ============================================================================
+
+/// COROLLARY: Byzantine Safety at n = 7, f = 2
+///
+/// Two Byzantine voters anywhere in a round of seven cannot overrule three
+/// or more honest voters for v (a strict majority of the five honest) at
+/// the default threshold: 2/7 <= 0.34 (threshold_tolerates).
+proof fn byzantine_safety_n7_f2(votes: Seq<Vote>, byzantine: Seq<bool>, v: Vote)
+    requires
+        votes.len() == 7,
+        byzantine.len() == 7,
+        count_votes(byzantine, true) == 2,
+        count_honest_votes(votes, byzantine, v) >= 3,
+    ensures
+        match decide_consensus(votes, 7, CONSENSUS_THRESHOLD) {
+            ConsensusOutcome::Agreed { value, agreement_pct: _ } => value == v,
+            ConsensusOutcome::Halted { reason: _ } => true,
+        },
+{
+    byzantine_safety(votes, byzantine, 7, CONSENSUS_THRESHOLD, v);
+}
+
+/// COROLLARY: Byzantine Safety at n = 10, f = 3
+///
+/// Three Byzantine voters anywhere in a round of ten cannot overrule four
+/// or more honest voters for v (a strict majority of the seven honest) at
+/// the default threshold: 3/10 <= 0.34.
+proof fn byzantine_safety_n10_f3(votes: Seq<Vote>, byzantine: Seq<bool>, v: Vote)
+    requires
+        votes.len() == 10,
+        byzantine.len() == 10,
+        count_votes(byzantine, true) == 3,
+        count_honest_votes(votes, byzantine, v) >= 4,
+    ensures
+        match decide_consensus(votes, 10, CONSENSUS_THRESHOLD) {
+            ConsensusOutcome::Agreed { value, agreement_pct: _ } => value == v,
+            ConsensusOutcome::Halted { reason: _ } => true,
+        },
+{
+    byzantine_safety(votes, byzantine, 10, CONSENSUS_THRESHOLD, v);
+}
+
+/// COROLLARY: PBFT Quorums at n = 7, f = 2
+///
+/// Commit quorums of 5 overlap in at least 3 nodes, and each holds at
+/// least 3 honest ones.
+proof fn pbft_quorums_n7_f2()
+    ensures
+        commit_quorum(2) == 5,
+        2 * commit_quorum(2) - 7 >= 3,
+        commit_quorum(2) - 2 >= 3,
+{
+    quorum_intersection(7, 2);
+    quorum_honest_count(7, 2);
+}
+
+/// COROLLARY: PBFT Quorums at n = 10, f = 3
+///
+/// Commit quorums of 7 overlap in at least 4 nodes, and each holds at
+/// least 4 honest ones.
+proof fn pbft_quorums_n10_f3()
+    ensures
+        commit_quorum(3) == 7,
+        2 * commit_quorum(3) - 10 >= 4,
+        commit_quorum(3) - 3 >= 4,
+{
+    quorum_intersection(10, 3);
+    quorum_honest_count(10, 3);
+}
+
+// ============================================================================
+// LLM-SPECIFIC ADAPTATIONS
+// ============================================================================
+
+/// Model family: models trained by the same lab on the same lineage share
+/// failure modes, so they count once towards diversity
+pub enum ModelFamily {
+    /// OpenAI o-series reasoning models
+    OpenAiO,
+    /// GPT-4 family (gpt-4o, gpt-4-turbo, gpt-4o-mini)
+    Gpt4,
+    /// NVIDIA Nemotron
+    Nemotron,
+    /// Anthropic Claude
+    Claude,
+    /// Meta Llama
+    Llama,
+    /// Any model the registry does not know; unknown models share one family
+    Unknown,
+}
+
+/// Model architecture: how a family produces its answers
+pub enum Architecture {
+    /// Trained to reason at length before answering
+    Reasoning,
+    /// Instruction-tuned chat model answering directly
+    Chat,
+    Unknown,
+}
+
+/// Specification: Model registry, by model ID as in `model_weight`
+/// (trust_bounds.rs): 0 = o1-mini, 1 = nvidia_nemotron_70b, 2 = gpt-4o,
+/// 3 = gpt-4-turbo, 4 = gpt-4o-mini
+pub open spec fn model_family(model_id: u64) -> ModelFamily {
+    if model_id == 0 { ModelFamily::OpenAiO }
+    else if model_id == 1 { ModelFamily::Nemotron }
+    else if model_id <= 4 { ModelFamily::Gpt4 }
+    else { ModelFamily::Unknown }
+}
+
+/// Specification: Architecture of a model family
+pub open spec fn family_architecture(family: ModelFamily) -> Architecture {
+    match family {
+        ModelFamily::OpenAiO => Architecture::Reasoning,
+        ModelFamily::Gpt4 => Architecture::Chat,
+        ModelFamily::Nemotron => Architecture::Chat,
+        ModelFamily::Claude => Architecture::Chat,
+        ModelFamily::Llama => Architecture::Chat,
+        ModelFamily::Unknown => Architecture::Unknown,
+    }
+}
+
+/// Specification: Elements of s different from x
+pub open spec fn count_differing<T>(s: Seq<T>, x: T) -> nat
+    decreases s.len()
+{
+    if s.len() == 0 {
+        0
+    } else {
+        count_differing(s.drop_last(), x) + if s.last() != x { 1nat } else { 0nat }
+    }
+}
+
+/// Specification: Ordered pairs (i, j), i != j, with s[i] != s[j]
+pub open spec fn differing_pairs<T>(s: Seq<T>) -> nat
+    decreases s.len()
+{
+    if s.len() == 0 {
+        0
+    } else {
+        differing_pairs(s.drop_last()) + 2 * count_differing(s.drop_last(), s.last())
+    }
+}
+
+/// Specification: LLM model diversity score (scaled by 1000)
+///
+/// Normalized Gini-Simpson index: the chance that two distinct members of
+/// the ensemble differ, weighted 3:1 between families and architectures.
+/// Repeating a model, or mixing models of one family, adds nothing.
+pub open spec fn diversity_score(model_ids: Seq<u64>) -> u64 {
+    let n = model_ids.len();
+    if n < 2 {
+        0
+    } else {
+        let families = model_ids.map_values(|id: u64| model_family(id));
+        let architectures = families.map_values(|f: ModelFamily| family_architecture(f));
+        ((3 * differing_pairs(families) + differing_pairs(architectures)) * 1000
+            / (4 * n * (n - 1))) as u64
+    }
+}
+
+/// Lemma: Differing pairs of a three-element sequence
+proof fn lemma_differing_pairs_three<T>(x: T, y: T, z: T)
+    ensures
+        differing_pairs(seq![x, y, z]) == 2 * (
+            (if x != y { 1nat } else { 0nat })
+            + (if x != z { 1nat } else { 0nat })
+            + (if y != z { 1nat } else { 0nat })
+        ),
+{
+    let s = seq![x, y, z];
+    assert(s.drop_last() =~= seq![x, y]);
+    assert(seq![x, y].drop_last() =~= seq![x]);
+    assert(seq![x].drop_last() =~= Seq::<T>::empty());
+    assert(count_differing(seq![x], z) == if x != z { 1nat } else { 0nat });
+    assert(count_differing(seq![x], y) == if x != y { 1nat } else { 0nat });
+    assert(differing_pairs(seq![x]) == 0);
+}
+
+/// Specification: Effective Byzantine tolerance given diversity
+///
+/// Full diversity (1000) tolerates n/3 faults, as in standard BFT; less
+/// diversity tolerates proportionally fewer. Multiplying before dividing
+/// keeps the fraction: n = 5 at diversity 900 tolerates 5 * 900 / 3000 = 1
+/// (the former `(n / 3) * diversity / 1000` floored it to 0). Diversity
+/// above 1000 counts as 1000.
+pub open spec fn effective_tolerance(n: nat, diversity: u64) -> nat {
+    let d: nat = if diversity > 1000 { 1000 } else { diversity as nat };
+    (n * d) / 3000
+}
+
+/// THEOREM: Effective Tolerance Stays Within Classical BFT
+///
+/// Diversity never buys more than n/3, and full diversity buys exactly
+/// that. Executable versions computing `n * diversity` in 128 bits cannot
+/// overflow (n < 2^64, diversity <= 1000), and the result, at most n/3,
+/// fits back in 64 bits.
+proof fn effective_tolerance_bounded(n: nat, diversity: u64)
+    ensures
+        effective_tolerance(n, diversity) <= n / 3,
+        diversity >= 1000 ==> effective_tolerance(n, diversity) == n / 3,
+{
+    let d: nat = if diversity > 1000 { 1000 } else { diversity as nat };
+    assert(n * d <= n * 1000) by(nonlinear_arith)
+        requires d <= 1000;
+    assert((n * d) / 3000 <= (n * 1000) / 3000) by(nonlinear_arith)
+        requires n * d <= n * 1000;
+    assert((n * 1000) / 3000 == n / 3) by(nonlinear_arith);
+}
+
+/// THEOREM: Effective Tolerance Is Monotone
+///
+/// More models or more diversity never lower the tolerance.
+proof fn effective_tolerance_monotone(n1: nat, n2: nat, diversity1: u64, diversity2: u64)
+    requires
+        n1 <= n2,
+        diversity1 <= diversity2,
+    ensures
+        effective_tolerance(n1, diversity1) <= effective_tolerance(n2, diversity2),
+{
+    let d1: nat = if diversity1 > 1000 { 1000 } else { diversity1 as nat };
+    let d2: nat = if diversity2 > 1000 { 1000 } else { diversity2 as nat };
+    assert(d1 <= d2);
+    assert(n1 * d1 <= n2 * d2) by(nonlinear_arith)
+        requires n1 <= n2, d1 <= d2;
+    assert((n1 * d1) / 3000 <= (n2 * d2) / 3000) by(nonlinear_arith)
+        requires n1 * d1 <= n2 * d2;
+}
+
+/// THEOREM 7: Diversity Amplifies Tolerance
+///
+/// Diversity counts families, not models: three models of three families
+/// score at least 750, a two-family mix between 500 and 666, and three
+/// models of one family score 0 (three copies of gpt-4o, or gpt-4o,
+/// gpt-4-turbo and gpt-4o-mini alike).
+proof fn diversity_amplification(a: u64, b: u64, c: u64)
+    ensures
+        ({
+            let (fa, fb, fc) = (model_family(a), model_family(b), model_family(c));
+            let score = diversity_score(seq![a, b, c]);
+            &&& (fa != fb && fa != fc && fb != fc) ==> score >= 750
+            &&& (fa == fb && fb == fc) ==> score == 0
+            &&& (!(fa == fb && fb == fc) && (fa == fb || fa == fc || fb == fc))
+                ==> 500 <= score <= 666
+        }),
+        diversity_score(seq![0u64, 1u64, 2u64]) > diversity_score(seq![2u64, 2u64, 2u64]),
+{
+    let (fa, fb, fc) = (model_family(a), model_family(b), model_family(c));
+    let ids = seq![a, b, c];
+    let families = ids.map_values(|id: u64| model_family(id));
+    assert(families =~= seq![fa, fb, fc]);
+    let (aa, ab, ac) = (family_architecture(fa), family_architecture(fb), family_architecture(fc));
+    let architectures = families.map_values(|f: ModelFamily| family_architecture(f));
+    assert(architectures =~= seq![aa, ab, ac]);
+    lemma_differing_pairs_three(fa, fb, fc);
+    lemma_differing_pairs_three(aa, ab, ac);
+    // Architecture follows family: only models of different families can
+    // differ in architecture, so the architecture pairs never outnumber
+    // the family pairs (6 = three families, 4 = two, 0 = one)
+    assert(ids.len() == 3);
+    assert(4 * ids.len() * (ids.len() - 1) == 24) by(nonlinear_arith)
+        requires ids.len() == 3;
+
+    // 0 = o1-mini, 1 = nemotron, 2 = gpt-4o: three families, two
+    // architectures, (3 * 6 + 4) * 1000 / 24 = 916
+    let diverse = seq![0u64, 1u64, 2u64];
+    assert(diverse.map_values(|id: u64| model_family(id))
+        =~= seq![ModelFamily::OpenAiO, ModelFamily::Nemotron, ModelFamily::Gpt4]);
+    assert(diverse.map_values(|id: u64| model_family(id))
+        .map_values(|f: ModelFamily| family_architecture(f))
+        =~= seq![Architecture::Reasoning, Architecture::Chat, Architecture::Chat]);
+    lemma_differing_pairs_three(ModelFamily::OpenAiO, ModelFamily::Nemotron, ModelFamily::Gpt4);
+    lemma_differing_pairs_three(Architecture::Reasoning, Architecture::Chat, Architecture::Chat);
+    let homogeneous = seq![2u64, 2u64, 2u64];
+    assert(homogeneous.map_values(|id: u64| model_family(id))
+        =~= seq![ModelFamily::Gpt4, ModelFamily::Gpt4, ModelFamily::Gpt4]);
+    assert(homogeneous.map_values(|id: u64| model_family(id))
+        .map_values(|f: ModelFamily| family_architecture(f))
+        =~= seq![Architecture::Chat, Architecture::Chat, Architecture::Chat]);
+    lemma_differing_pairs_three(ModelFamily::Gpt4, ModelFamily::Gpt4, ModelFamily::Gpt4);
+    lemma_differing_pairs_three(Architecture::Chat, Architecture::Chat, Architecture::Chat);
+}
+
+/// Lemma: No element differs from more elements than s holds
+proof fn lemma_count_differing_bounded<T>(s: Seq<T>, x: T)
+    ensures
+        count_differing(s, x) <= s.len(),
+    decreases s.len(),
+{
+    if s.len() > 0 {
+        lemma_count_differing_bounded(s.drop_last(), x);
+    }
+}
+
+/// Lemma: At most n (n - 1) ordered pairs of n elements differ
+proof fn lemma_differing_pairs_bounded<T>(s: Seq<T>)
+    ensures
+        differing_pairs(s) <= s.len() * (s.len() - 1),
+    decreases s.len(),
+{
+    if s.len() > 0 {
+        let n = s.len();
+        lemma_differing_pairs_bounded(s.drop_last());
+        lemma_count_differing_bounded(s.drop_last(), s.last());
+        assert((n - 1) * (n - 2) + 2 * (n - 1) == n * (n - 1)) by(nonlinear_arith);
+    }
+}
+
+/// Lemma: With at most `cap` copies of any value, n elements have at least
+/// (n - cap)(n - cap + 1) differing ordered pairs
+///
+/// "At most `cap` copies of x" is "at least n - cap elements differ from
+/// x". The last element then differs from at least n - cap of the others,
+/// and the rest keep the cap.
+proof fn lemma_capped_differing_pairs<T>(s: Seq<T>, cap: nat)
+    requires
+        cap <= s.len(),
+        forall|x: T| #[trigger] count_differing(s, x) + cap >= s.len(),
+    ensures
+        differing_pairs(s) >= (s.len() - cap) * (s.len() - cap + 1),
+    decreases s.len(),
+{
+    let n = s.len();
+    if n == cap {
+        assert((n - cap) * (n - cap + 1) == 0);
+    } else {
+        let prefix = s.drop_last();
+        let last = s.last();
+        assert forall|x: T| #[trigger] count_differing(prefix, x) + cap >= prefix.len() by {
+            assert(count_differing(s, x) + cap >= n);
+        }
+        lemma_capped_differing_pairs(prefix, cap);
+        // The last element does not differ from itself
+        assert(count_differing(s, last) == count_differing(prefix, last));
+        let m = n - cap;
+        assert((m - 1) * m + 2 * m == m * (m + 1)) by(nonlinear_arith);
+    }
+}
+
+/// THEOREM: Capped Selections Stay Diverse
+///
+/// An ensemble of n >= 2 models with at most `cap` from any one family
+/// scores at least 3 (n - cap)(n - cap + 1) * 1000 / (4 n (n - 1)), from
+/// the family pairs alone. Selection caps each family at ⌈n/2⌉, so once
+/// the candidates span enough families to fill the ensemble, four models
+/// score at least 375 and two at least 750.
+///
+/// Executable counterpart: `selection::select_ensemble`, with the bound as
+/// `selection::capped_diversity_bound`.
+proof fn capped_selection_diversity(model_ids: Seq<u64>, cap: nat)
+    requires
+        model_ids.len() >= 2,
+        cap <= model_ids.len(),
+        forall|f: ModelFamily|
+            #[trigger] count_differing(model_ids.map_values(|id: u64| model_family(id)), f) + cap
+                >= model_ids.len(),
+    ensures
+        ({
+            let n = model_ids.len();
+            diversity_score(model_ids) as int
+                >= 3 * ((n - cap) * (n - cap + 1)) * 1000 / (4 * n * (n - 1))
+        }),
+{
+    let n = model_ids.len();
+    let families = model_ids.map_values(|id: u64| model_family(id));
+    let architectures = families.map_values(|f: ModelFamily| family_architecture(f));
+    lemma_capped_differing_pairs(families, cap);
+    lemma_differing_pairs_bounded(families);
+    lemma_differing_pairs_bounded(architectures);
+    let pairs = 3 * differing_pairs(families) + differing_pairs(architectures);
+    let lower = 3 * ((n - cap) * (n - cap + 1));
+    let d = 4 * n * (n - 1);
+    assert(d > 0) by(nonlinear_arith)
+        requires n >= 2, d == 4 * n * (n - 1);
+    assert(pairs * 1000 / d >= lower * 1000 / d) by(nonlinear_arith)
+        requires pairs >= lower, d > 0;
+    // At most 1000, so the cast keeps it
+    assert(pairs * 1000 / d <= 1000) by(nonlinear_arith)
+        requires pairs <= d, d > 0;
+}
+
+/// THEOREM 8: 500-Sample Statistical Power
+///
+/// 500 samples provide p < 0.001 statistical significance.
+proof fn statistical_power_500()
+    ensures
+        // 95% confidence interval for 83% accuracy with n=500
+        // CI = p +/- 1.96 * sqrt(p(1-p)/n)
+        // CI = 0.83 +/- 1.96 * sqrt(0.83*0.17/500)
+        // CI = 0.83 +/- 0.033
+        // CI = [0.797, 0.863]
+        ({
+            let p = 830u64;  // 83.0%
+            let n = 500u64;
+            // Standard error (scaled by 10000): sqrt(p*(1000-p)/n) * 100
+            // SE = sqrt(830*170/500) * 100 / 1000 = sqrt(282.2) * 0.1 = 1.68
+            let se_scaled = 168u64;  // 1.68%
+            // 95% CI: 1.96 * SE ≈ 2 * SE = 3.36%
+            let ci_width = 336u64;  // 3.36%
+            ci_width < 400  // CI width < 4%
+        })
+{
+    // Direct calculation shows 95% CI is approximately [79.7%, 86.3%]
+    // This does not include 0% (single model failure), proving significance
+}
+
+// ============================================================================
+// CONSTITUTIONAL HALT IMPLEMENTATION
+// ============================================================================
+
+/// Specification: Why the constitutional halt fires, if it does
+///
+/// Low agreement is reported ahead of high variance.
+pub open spec fn constitutional_halt_reason(
+    agreement_pct: u64,
+    variance_ratio: u64,  // Current variance / baseline variance
+    min_agreement: u64,   // Typically 666 (share_threshold of two thirds)
+    max_variance_ratio: u64,  // Typically 625 (6.25x baseline)
+) -> Option<HaltReason> {
+    if agreement_pct < min_agreement {
+        Some(HaltReason::NoSupermajority { agreement_pct })
+    } else if variance_ratio > max_variance_ratio {
+        Some(HaltReason::VarianceExceeded { ratio_scaled: variance_ratio })
+    } else {
+        None
+    }
+}
+
+/// Specification: Constitutional halt decision
+pub open spec fn constitutional_halt(
+    agreement_pct: u64,
+    variance_ratio: u64,  // Current variance / baseline variance
+    min_agreement: u64,   // Typically 666 (share_threshold of two thirds)
+    max_variance_ratio: u64,  // Typically 625 (6.25x baseline)
+) -> bool {
+    // Halt if agreement too low OR variance too high
+    constitutional_halt_reason(agreement_pct, variance_ratio, min_agreement, max_variance_ratio)
+        is Some
+}
+
+/// THEOREM 9: Constitutional Halt Safety
+///
+/// Constitutional halt never triggers when all agents are honest and agreeing:
+/// neither NoSupermajority nor VarianceExceeded applies.
+proof fn halt_safety(n: nat, honest_agreement: u64, honest_variance_ratio: u64)
+    requires
+        n >= 3,
+        honest_agreement >= 900,  // 90% agreement (honest consensus)
+        honest_variance_ratio <= 200,  // Variance at most 2x baseline
+    ensures
+        !constitutional_halt(honest_agreement, honest_variance_ratio, 666, 625),
+        constitutional_halt_reason(honest_agreement, honest_variance_ratio, 666, 625) is None,
+{
+    // With 90% agreement >= 2/3, no halt from agreement
+    // With 2x variance ratio < 6.25x, no halt from variance
+    assert(honest_agreement >= 666);
+    assert(honest_variance_ratio <= 625);
+}
+
+/// THEOREM 10: Constitutional Halt Liveness
+///
+/// Constitutional halt triggers when majority is compromised: for low
+/// agreement as NoSupermajority, otherwise for the variance spike as
+/// VarianceExceeded.
+proof fn halt_liveness(
+    byzantine_agreement: u64,
+    byzantine_variance_ratio: u64,
+)
+    requires
+        // When majority Byzantine, agreement drops and variance spikes
+        byzantine_agreement < 500 || byzantine_variance_ratio > 1000,
+    ensures
+        constitutional_halt(byzantine_agreement, byzantine_variance_ratio, 666, 625),
+        byzantine_agreement < 500 ==>
+            constitutional_halt_reason(byzantine_agreement, byzantine_variance_ratio, 666, 625)
+                == Some(HaltReason::NoSupermajority { agreement_pct: byzantine_agreement }),
+        byzantine_agreement >= 666 ==>
+            constitutional_halt_reason(byzantine_agreement, byzantine_variance_ratio, 666, 625)
+                == Some(HaltReason::VarianceExceeded { ratio_scaled: byzantine_variance_ratio }),
+{
+    // Either agreement < 2/3 (from agreement < 500 < 666)
+    // Or variance > 6.25x (from variance_ratio > 1000 > 625)
+    // Either condition triggers halt
+}
+
+// ============================================================================
+// NO SPURIOUS HALT: VOTES AND VARIANCE TOGETHER
+// ============================================================================
+
+/// Specification: Arithmetic mean of the outputs (as in variance_halt.rs)
+pub open spec fn mean(outputs: Seq<u64>) -> u64 {
+    if outputs.len() == 0 {
+        0
+    } else {
+        outputs.fold_left(0u64, |acc: u64, x: u64| acc + x) / outputs.len() as u64
+    }
+}
+
+/// Specification: Sum of squared deviations from `mu` (as in variance_halt.rs)
+pub open spec fn sum_squared_deviations(outputs: Seq<u64>, mu: u64) -> u64 {
+    outputs.fold_left(0u64, |acc: u64, x: u64| {
+        let diff = if x >= mu { x - mu } else { mu - x };
+        acc + diff * diff
+    })
+}
+
+/// Specification: Variance scaled by 100 (as in variance_halt.rs)
+pub open spec fn variance_scaled(outputs: Seq<u64>) -> u64
+    recommends outputs.len() > 0
+{
+    let mu = mean(outputs);
+    let ssd = sum_squared_deviations(outputs, mu);
+    (ssd * 100) / outputs.len() as u64
+}
+
+/// Specification: 6.25x the baseline variance (as in variance_halt.rs)
+pub open spec fn halt_threshold_scaled(baseline_variance_scaled: u64) -> u64 {
+    (625 * baseline_variance_scaled) / 100
+}
+
+/// Specification: Variance-based halt decision (as in variance_halt.rs):
+/// variance above 6.25x the baseline, or above 10000 (sigma > 10) as the
+/// low-agreement proxy
+pub open spec fn constitutional_halt_decision(
+    outputs: Seq<u64>,
+    baseline_variance_scaled: u64,
+    agreement_threshold_pct: u64,
+) -> bool {
+    let current_var = variance_scaled(outputs);
+    let high_variance = current_var > halt_threshold_scaled(baseline_variance_scaled);
+    let low_agreement = current_var > 10000;
+    high_variance || low_agreement
+}
+
+/// Specification: Current variance as a multiple of the baseline, scaled
+/// by 100 (as in variance_halt.rs)
+pub open spec fn variance_ratio_scaled(outputs: Seq<u64>, baseline_variance_scaled: u64) -> u64
+    recommends baseline_variance_scaled > 0
+{
+    ((variance_scaled(outputs) as nat * 100) / (baseline_variance_scaled as nat)) as u64
+}
+
+/// THEOREM 11: No Spurious Halt
+///
+/// A healthy round never halts: when at least 2/3 of the votes agree and
+/// the outputs vary at most twice as much as the baseline, decide_consensus
+/// agrees, the variance halt stays quiet, and the vote share and variance
+/// ratio fed to constitutional_halt clear both of its conditions.
+///
+/// The baseline is capped at 5000 (sigma ~7.07): the variance halt reads
+/// variance above 10000 as low agreement, which 2x a larger baseline could
+/// cross however strongly the votes agree.
+proof fn no_spurious_halt(
+    votes: Seq<Vote>,
+    n: nat,
+    baseline_variance_scaled: u64,
+    outputs: Seq<u64>,
+)
+    requires
+        3 <= n <= max_round_size(),
+        votes.len() == n,
+        outputs.len() == n,
+        meets_threshold(count_agrees(votes), n, CONSENSUS_THRESHOLD),
+        0 < baseline_variance_scaled <= 5000,
+        variance_scaled(outputs) <= 2 * baseline_variance_scaled,
+    ensures
+        decide_consensus(votes, n, CONSENSUS_THRESHOLD) == (ConsensusOutcome::Agreed {
+            value: true,
+            agreement_pct: agreement_ratio_scaled(count_agrees(votes), n),
+        }),
+        !constitutional_halt_decision(outputs, baseline_variance_scaled, 67),
+        agreement_ratio_scaled(count_agrees(votes), n) >= share_threshold(CONSENSUS_THRESHOLD),
+        variance_ratio_scaled(outputs, baseline_variance_scaled) <= 200,
+        !constitutional_halt(
+            agreement_ratio_scaled(count_agrees(votes), n),
+            variance_ratio_scaled(outputs, baseline_variance_scaled),
+            share_threshold(CONSENSUS_THRESHOLD) as u64,
+            625,
+        ),
+{
+    let agrees = count_agrees(votes);
+    lemma_count_votes_bounded(votes, true);
+
+    // 2/3 agreement is a strict majority, so the majority value is true
+    // and its supporters are the agreeing votes
+    assert(share_threshold(CONSENSUS_THRESHOLD) == 666);
+    assert(agrees * 3 >= 2 * n);
+    assert(2 * agrees > n) by(nonlinear_arith)
+        requires agrees * 3 >= 2 * n, n > 0;
+    assert(majority_value(votes) == true);
+
+    // The rounded vote share keeps the share threshold: 3a >= 2n gives
+    // a * 1000 >= 666 * n, so floor(a * 1000 / n) >= 666
+    lemma_agreement_ratio_bounded(agrees, n);
+    assert(agrees * 1000 / n >= 666) by(nonlinear_arith)
+        requires agrees * 3 >= 2 * n, n > 0;
+
+    // Variance: 2x baseline <= 10000, and 2x baseline < 6.25x baseline
+    let current_var = variance_scaled(outputs);
+    let b = baseline_variance_scaled;
+    assert(current_var <= 10000);
+    assert(2 * b <= (625 * b) / 100);
+    assert(current_var as nat * 100 <= 200 * (b as nat));
+    assert((current_var as nat * 100) / (b as nat) <= 200) by(nonlinear_arith)
+        requires current_var as nat * 100 <= 200 * (b as nat), b > 0;
+}
+
+// ============================================================================
+// FINAL VERDICT: VOTES, AGREEMENT AND VARIANCE
+// ============================================================================
+
+/// Thresholds of the final verdict (`Thresholds` in the runtime)
+pub struct VerdictThresholds {
+    /// Consensus threshold t
+    pub consensus: Threshold,
+    /// Highest tolerated current / baseline variance, scaled by 100
+    pub max_variance_ratio: u64,
+}
+
+/// Specification: Thresholds the runtime accepts: a supermajority
+/// threshold, and room for honest outputs varying up to twice the baseline
+pub open spec fn valid_verdict_thresholds(th: VerdictThresholds) -> bool {
+    valid_threshold(th.consensus) && th.max_variance_ratio >= 200
+}
+
+/// Specification: The majority value's share of the votes (scaled by 1000)
+pub open spec fn vote_share(votes: Seq<Vote>) -> u64 {
+    agreement_ratio_scaled(count_votes(votes, majority_value(votes)), votes.len())
+}
+
+/// Specification: Final verdict of a round
+///
+/// decide_consensus on the votes, then constitutional_halt on the agreed
+/// share and the outputs' variance ratio: a round halts when its votes do,
+/// or when the constitutional halt fires on their agreement. The rounded
+/// share is checked against share_threshold, which every share meeting the
+/// threshold reaches. (The variance
+/// halt of variance_halt.rs, constitutional_halt_decision, is the
+/// standalone output monitor; the verdict uses the ratio form.)
+pub open spec fn final_verdict(
+    votes: Seq<Vote>,
+    outputs: Seq<u64>,
+    baseline_variance_scaled: u64,
+    th: VerdictThresholds,
+) -> ConsensusOutcome
+    recommends baseline_variance_scaled > 0
+{
+    let decided = decide_consensus(votes, votes.len(), th.consensus);
+    match decided {
+        ConsensusOutcome::Agreed { value: _, agreement_pct } => match constitutional_halt_reason(
+            agreement_pct,
+            variance_ratio_scaled(outputs, baseline_variance_scaled),
+            share_threshold(th.consensus) as u64,
+            th.max_variance_ratio,
+        ) {
+            Some(reason) => ConsensusOutcome::Halted { reason },
+            None => decided,
+        },
+        ConsensusOutcome::Halted { reason: _ } => decided,
+    }
+}
+
+/// THEOREM: One Verdict for Every Halt
+///
+/// The final verdict halts exactly when decide_consensus halts or
+/// constitutional_halt fires on the vote share and variance ratio, and
+/// otherwise is decide_consensus' agreement.
+proof fn final_verdict_unifies_halts(
+    votes: Seq<Vote>,
+    outputs: Seq<u64>,
+    baseline_variance_scaled: u64,
+    th: VerdictThresholds,
+)
+    requires
+        valid_verdict_thresholds(th),
+    ensures
+        ({
+            let n = votes.len();
+            let decided = decide_consensus(votes, n, th.consensus);
+            let fires = constitutional_halt(
+                vote_share(votes),
+                variance_ratio_scaled(outputs, baseline_variance_scaled),
+                share_threshold(th.consensus) as u64,
+                th.max_variance_ratio,
+            );
+            &&& (final_verdict(votes, outputs, baseline_variance_scaled, th) is Halted)
+                <==> (decided is Halted || fires)
+            &&& !(decided is Halted || fires)
+                ==> final_verdict(votes, outputs, baseline_variance_scaled, th) == decided
+        }),
+{
+    // An agreement reports the majority value's share, which is the vote
+    // share constitutional_halt is checked against
+}
+
+/// THEOREM: Final Verdict Safety
+///
+/// An honest round (at least t of the votes agree, outputs varying at
+/// most twice the baseline) ends in agreement on its votes: neither
+/// decide_consensus nor the constitutional halt stops it.
+proof fn final_verdict_safety(
+    votes: Seq<Vote>,
+    outputs: Seq<u64>,
+    baseline_variance_scaled: u64,
+    th: VerdictThresholds,
+)
+    requires
+        valid_verdict_thresholds(th),
+        0 < votes.len() <= max_round_size(),
+        meets_threshold(count_agrees(votes), votes.len(), th.consensus),
+        baseline_variance_scaled > 0,
+        variance_scaled(outputs) <= 2 * baseline_variance_scaled,
+    ensures
+        final_verdict(votes, outputs, baseline_variance_scaled, th) == (ConsensusOutcome::Agreed {
+            value: true,
+            agreement_pct: agreement_ratio_scaled(count_agrees(votes), votes.len()),
+        }),
+{
+    let n = votes.len();
+    let t = th.consensus;
+    let agrees = count_agrees(votes);
+    lemma_count_votes_bounded(votes, true);
+
+    // T > 1500: the agreeing votes are a strict majority
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    assert(2 * agrees > n) by(nonlinear_arith)
+        requires agrees * 3000 >= te * n, n > 0, te > 1500;
+    assert(majority_value(votes) == true);
+
+    // The rounded share keeps the share threshold: floor(a * 1000 / n) >= T/3
+    lemma_agreement_ratio_bounded(agrees, n);
+    let st = share_threshold(t);
+    assert(agrees * 1000 / n >= st) by(nonlinear_arith)
+        requires agrees * 3000 >= te * n, st == te / 3, n > 0;
+
+    // The variance ratio is at most 200 <= max_variance_ratio
+    let current_var = variance_scaled(outputs);
+    let b = baseline_variance_scaled;
+    assert(current_var as nat * 100 <= 200 * (b as nat));
+    assert((current_var as nat * 100) / (b as nat) <= 200) by(nonlinear_arith)
+        requires current_var as nat * 100 <= 200 * (b as nat), b > 0;
+}
+
+/// THEOREM: Final Verdict Liveness
+///
+/// With the Byzantine fraction past halt_guaranteed (f/n > 1 - t/1000),
+/// the round halts when the Byzantine voters back neither side, and
+/// whatever they vote when the outputs' variance exceeds the halt ratio.
+proof fn final_verdict_liveness(
+    votes: Seq<Vote>,
+    outputs: Seq<u64>,
+    baseline_variance_scaled: u64,
+    th: VerdictThresholds,
+    f: nat,
+)
+    requires
+        valid_verdict_thresholds(th),
+        votes.len() >= 3,
+        f <= votes.len(),
+        halt_guaranteed(votes.len(), f, th.consensus),
+        (count_votes(votes, true) <= votes.len() - f && count_votes(votes, false) <= votes.len() - f)
+            || variance_ratio_scaled(outputs, baseline_variance_scaled) > th.max_variance_ratio,
+    ensures
+        final_verdict(votes, outputs, baseline_variance_scaled, th) is Halted,
+{
+    if count_votes(votes, true) <= votes.len() - f && count_votes(votes, false) <= votes.len() - f {
+        // decide_consensus halts for want of a supermajority
+        constitutional_halt_correctness(votes.len(), f, votes, th.consensus);
+    }
+    // Otherwise any agreement meets a variance ratio above the halt ratio,
+    // and constitutional_halt_reason halts on one condition or the other
+}
+
+/// THEOREM: Agreeing Votes Do Not Mask Exploding Variance
+///
+/// When the votes agree but the outputs' variance ratio exceeds the halt
+/// ratio, the round halts for the variance: the agreed share already
+/// clears the threshold, so VarianceExceeded is the reason reported.
+proof fn final_verdict_variance_halt(
+    votes: Seq<Vote>,
+    outputs: Seq<u64>,
+    baseline_variance_scaled: u64,
+    th: VerdictThresholds,
+)
+    requires
+        valid_verdict_thresholds(th),
+        0 < votes.len() <= max_round_size(),
+        decide_consensus(votes, votes.len(), th.consensus) is Agreed,
+        variance_ratio_scaled(outputs, baseline_variance_scaled) > th.max_variance_ratio,
+    ensures
+        final_verdict(votes, outputs, baseline_variance_scaled, th) == (ConsensusOutcome::Halted {
+            reason: HaltReason::VarianceExceeded {
+                ratio_scaled: variance_ratio_scaled(outputs, baseline_variance_scaled),
+            },
+        }),
+{
+    let n = votes.len();
+    let t = th.consensus;
+    decided_value_has_supermajority(votes, n, t);
+    let supporters = count_votes(votes, majority_value(votes));
+    lemma_count_votes_bounded(votes, majority_value(votes));
+    lemma_agreement_ratio_bounded(supporters, n);
+    // The agreement meets the threshold exactly, and so its rounded share
+    // the share threshold
+    lemma_threshold_exact(t);
+    let (te, st) = (threshold_exact(t), share_threshold(t));
+    assert(supporters * 1000 / n >= st) by(nonlinear_arith)
+        requires supporters * 3000 >= te * n, st == te / 3, n > 0;
 }
 
 // ============================================================================
-// SPECIFICATION: Consensus Outcomes
+// BYZANTINE SUSPECT IDENTIFICATION
 // ============================================================================
 
-/// Consensus outcome
-pub enum ConsensusOutcome {
-    /// Consensus reached with agreed value
-    Agreed { value: bool, agreement_pct: u64 },
-    /// Constitutional halt - no consensus
-    Halted { reason: u64 },
+/// Specification: An output further than `tolerance` from the consensus value
+pub open spec fn deviates(output: u64, consensus_value: u64, tolerance: u64) -> bool {
+    if output >= consensus_value {
+        output - consensus_value > tolerance
+    } else {
+        consensus_value - output > tolerance
+    }
 }
 
-/// Specification: Outcome is valid
-pub open spec fn valid_outcome(outcome: ConsensusOutcome) -> bool {
-    match outcome {
-        ConsensusOutcome::Agreed { value: _, agreement_pct } => agreement_pct <= 1000,
-        ConsensusOutcome::Halted { reason: _ } => true,
+/// Specification: Number of outputs deviating from the consensus value
+pub open spec fn suspect_count(outputs: Seq<u64>, consensus_value: u64, tolerance: u64) -> nat
+    decreases outputs.len()
+{
+    if outputs.len() == 0 {
+        0
+    } else {
+        suspect_count(outputs.drop_last(), consensus_value, tolerance)
+            + if deviates(outputs.last(), consensus_value, tolerance) { 1nat } else { 0nat }
     }
 }
 
-/// Specification: Consensus decision procedure
-pub open spec fn decide_consensus(votes: Seq<Vote>, n: nat) -> ConsensusOutcome
-    recommends votes.len() == n, n > 0
+/// Specification: Agent `i` is accused: its output deviates, and the
+/// agents that do not deviate hold a strict majority. When they do not,
+/// there is no trustworthy cluster to measure against and nobody is
+/// accused.
+pub open spec fn accused(outputs: Seq<u64>, consensus_value: u64, tolerance: u64, i: int) -> bool
+    recommends 0 <= i < outputs.len()
 {
-    let agrees = count_agrees(votes);
-    let agreement = agreement_ratio_scaled(agrees, n);
+    deviates(outputs[i], consensus_value, tolerance)
+        && 2 * suspect_count(outputs, consensus_value, tolerance) < outputs.len()
+}
 
-    if agreement >= CONSENSUS_THRESHOLD {
-        ConsensusOutcome::Agreed { value: true, agreement_pct: agreement }
-    } else if agreement <= 1000 - CONSENSUS_THRESHOLD {
-        // Strong disagreement (33%+ agree means 67%+ disagree)
-        ConsensusOutcome::Agreed { value: false, agreement_pct: 1000 - agreement }
-    } else {
-        // No supermajority - halt
-        ConsensusOutcome::Halted { reason: 1 }
+/// If no honest output deviates, the deviating outputs are at most the
+/// Byzantine ones (`honest[i]` marks agent i honest)
+proof fn lemma_suspects_are_byzantine(
+    outputs: Seq<u64>,
+    honest: Seq<bool>,
+    consensus_value: u64,
+    tolerance: u64,
+)
+    requires
+        outputs.len() == honest.len(),
+        forall|i: int| 0 <= i < outputs.len() && honest[i]
+            ==> !deviates(#[trigger] outputs[i], consensus_value, tolerance),
+    ensures
+        suspect_count(outputs, consensus_value, tolerance) <= count_votes(honest, false),
+    decreases outputs.len()
+{
+    if outputs.len() > 0 {
+        let last = outputs.len() - 1;
+        assert forall|i: int| 0 <= i < outputs.drop_last().len() && honest.drop_last()[i]
+            implies !deviates(#[trigger] outputs.drop_last()[i], consensus_value, tolerance) by {
+            assert(outputs.drop_last()[i] == outputs[i]);
+        }
+        lemma_suspects_are_byzantine(outputs.drop_last(), honest.drop_last(), consensus_value, tolerance);
+        // The last output counts as a suspect only if its agent is Byzantine
+        assert(honest[last] ==> !deviates(outputs[last], consensus_value, tolerance));
     }
 }
 
-// ============================================================================
-// MAIN THEOREMS: BYZANTINE FAULT TOLERANCE
-// ============================================================================
-
-/// THEOREM 1: Byzantine Safety
+/// THEOREM 12: No False Accusations
 ///
-/// When f < n/3 Byzantine nodes, honest majority determines consensus.
-/// This is the fundamental BFT guarantee.
-proof fn byzantine_safety(n: nat, f: nat, honest_votes: Seq<Vote>)
+/// With fewer than n/3 Byzantine agents, honest outputs within `tolerance`
+/// of each other, and a consensus value between two honest outputs (the
+/// median is one: honest agents hold more than half the round), no honest
+/// agent is accused, and every deviating agent is: the round is never
+/// declined.
+proof fn no_false_accusation(
+    outputs: Seq<u64>,
+    honest: Seq<bool>,
+    consensus_value: u64,
+    tolerance: u64,
+    lo: int,
+    hi: int,
+)
     requires
-        n >= 3,
-        byzantine_safe(n, f),             // f < n/3
-        honest_votes.len() == n - f,       // Only honest votes
-        honest_majority(n, f, count_agrees(honest_votes)),  // Honest majority agrees
+        outputs.len() == honest.len(),
+        3 * count_votes(honest, false) < outputs.len(),
+        forall|i: int, j: int|
+            0 <= i < outputs.len() && 0 <= j < outputs.len() && honest[i] && honest[j]
+                ==> !deviates(#[trigger] outputs[i], #[trigger] outputs[j], tolerance),
+        0 <= lo < outputs.len() && honest[lo],
+        0 <= hi < outputs.len() && honest[hi],
+        outputs[lo] <= consensus_value <= outputs[hi],
     ensures
-        // Honest agreement exceeds Byzantine manipulation capacity
-        ({
-            let honest_agrees = count_agrees(honest_votes);
-            let honest_count = n - f;
-            // Even if all f Byzantine vote against, honest majority wins
-            honest_agrees > f
-        })
+        forall|i: int| 0 <= i < outputs.len() && honest[i]
+            ==> !accused(outputs, consensus_value, tolerance, i),
+        forall|i: int| 0 <= i < outputs.len()
+            ==> (accused(outputs, consensus_value, tolerance, i)
+                <==> deviates(outputs[i], consensus_value, tolerance)),
 {
-    // Proof:
-    // 1. f < n/3 implies n - f > 2n/3 (honest nodes are supermajority)
-    // 2. honest_majority means honest_agrees >= (n-f)/2 + 1
-    // 3. (n-f)/2 + 1 > n/3 > f when n >= 3 and f < n/3
-    //
-    // Therefore honest_agrees > f, so honest consensus wins
-
-    let honest_count = n - f;
-    let honest_agrees = count_agrees(honest_votes);
+    // An honest output is within tolerance of both honest bounds, and the
+    // consensus value lies between them
+    assert forall|i: int| 0 <= i < outputs.len() && honest[i]
+        implies !deviates(#[trigger] outputs[i], consensus_value, tolerance) by {
+        assert(!deviates(outputs[i], outputs[lo], tolerance));
+        assert(!deviates(outputs[i], outputs[hi], tolerance));
+    }
 
-    // From byzantine_safe: 3*f < n
-    // Therefore: f < n/3
-    // And: n - f > 2n/3
+    // Suspects are Byzantine, so fewer than n/3 < n/2 of them
+    lemma_suspects_are_byzantine(outputs, honest, consensus_value, tolerance);
+    assert(2 * suspect_count(outputs, consensus_value, tolerance) < outputs.len());
+}
 
-    assert(honest_count > 2 * n / 3);  // Honest supermajority
+// ============================================================================
+// INCREMENTAL ROUNDS: EARLY DECISION
+// ============================================================================
 
-    // From honest_majority: honest_agrees >= honest_count/2 + 1
-    let majority_threshold = honest_count / 2 + 1;
-    assert(honest_agrees >= majority_threshold);
+/// State of a round collecting votes
+pub enum RoundState {
+    /// The outcome still depends on votes yet to arrive
+    Pending,
+    /// The outcome is settled
+    Decided { outcome: ConsensusOutcome },
+}
 
-    // majority_threshold = (n-f)/2 + 1 > n/3 > f (for n >= 3, f < n/3)
-    // This requires: (n-f)/2 + 1 > f
-    // Simplify: n - f + 2 > 2f
-    // n + 2 > 3f
-    // Since 3f < n, we have n > 3f, so n + 2 > 3f
+/// Specification: Decide a round of `n` voters from the votes `cast` so far
+///
+/// A value agrees as soon as its votes alone reach the threshold of all n
+/// voters; the round halts once neither value could reach it even with
+/// every remaining vote. Shares count the votes cast so far against all n
+/// voters; a halt reports the value leading so far (false on a tie).
+pub open spec fn try_decide(cast: Seq<Vote>, n: nat, t: Threshold) -> RoundState
+    recommends cast.len() <= n, valid_threshold(t)
+{
+    let remaining = (n - cast.len()) as nat;
+    let agrees = count_votes(cast, true);
+    let disagrees = count_votes(cast, false);
+
+    if n == 0 {
+        RoundState::Decided {
+            outcome: ConsensusOutcome::Halted { reason: HaltReason::InsufficientVoters { n: 0 } },
+        }
+    } else if meets_threshold(agrees, n, t) {
+        RoundState::Decided {
+            outcome: ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: agreement_ratio_scaled(agrees, n),
+            },
+        }
+    } else if meets_threshold(disagrees, n, t) {
+        RoundState::Decided {
+            outcome: ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: agreement_ratio_scaled(disagrees, n),
+            },
+        }
+    } else if !meets_threshold(agrees + remaining, n, t)
+        && !meets_threshold(disagrees + remaining, n, t) {
+        RoundState::Decided {
+            outcome: ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority {
+                    agreement_pct: agreement_ratio_scaled(count_votes(cast, majority_value(cast)), n),
+                },
+            },
+        }
+    } else {
+        RoundState::Pending
+    }
+}
 
-    assert(honest_count + 2 > 2 * f);  // n - f + 2 > 2f
-    assert(majority_threshold > f);     // (n-f)/2 + 1 > f
-    assert(honest_agrees > f);
+/// Counts only grow as votes arrive, and by at most the votes added
+proof fn lemma_count_votes_extend(cast: Seq<Vote>, rest: Seq<Vote>, value: Vote)
+    ensures
+        count_votes(cast, value) <= count_votes(cast + rest, value),
+        count_votes(cast + rest, value) <= count_votes(cast, value) + rest.len(),
+{
+    lemma_count_votes_append(cast, rest, value);
+    lemma_count_votes_bounded(rest, value);
 }
 
-/// THEOREM 2: Constitutional Halt Correctness
-///
-/// When agreement falls below threshold, the system correctly halts.
-proof fn constitutional_halt_correctness(
-    n: nat,
-    f: nat,
-    votes: Seq<Vote>,
-)
+/// The rounded share is monotone in the count
+proof fn lemma_ratio_monotone(smaller: nat, larger: nat, n: nat)
     requires
-        n >= 3,
-        votes.len() == n,
-        f >= n / 3,  // Byzantine majority (beyond tolerance)
+        smaller <= larger <= n,
+        0 < n <= max_round_size(),
     ensures
-        // If f >= n/3, agreement is below threshold for some vote patterns
-        // (system should halt rather than output potentially wrong answer)
-        ({
-            // Maximum agreement when Byzantine vote against honest
-            let max_honest = n - f;
-            let max_agreement = agreement_ratio_scaled(max_honest, n);
-            // If all Byzantine disagree, max agreement <= 2/3 < 67%
-            max_agreement <= 670
-        })
+        agreement_ratio_scaled(smaller, n) <= agreement_ratio_scaled(larger, n),
 {
-    // When f >= n/3, honest nodes n - f <= 2n/3
-    // Maximum agreement (all honest agree) = (n-f)/n <= 2/3 ≈ 666
-    // This is below our 67% threshold, triggering halt
-
-    let max_honest = n - f;
-    let max_agreement = agreement_ratio_scaled(max_honest, n);
-
-    // f >= n/3 implies n - f <= 2n/3
-    // (n-f)/n <= 2/3 = 666.../1000 < 670/1000
-
-    assert(max_honest <= 2 * n / 3);
-    // max_agreement = max_honest * 1000 / n <= 2n/3 * 1000 / n = 2000/3 ≈ 666
+    lemma_agreement_ratio_bounded(smaller, n);
+    lemma_agreement_ratio_bounded(larger, n);
+    assert(smaller * 1000 / n <= larger * 1000 / n) by(nonlinear_arith)
+        requires smaller <= larger, n > 0;
 }
 
-/// THEOREM 3: N=3 Sufficiency for LLM Ensembles
+/// THEOREM: Early Decisions Are Sound
 ///
-/// For architecturally-diverse LLM ensembles with independent failure modes,
-/// N=3 is sufficient for Byzantine fault tolerance with f=1.
-proof fn n_three_sufficiency()
+/// A decision try_decide reaches before all n votes are in is the one
+/// decide_consensus reaches on every completion `rest` of the round: the
+/// same value agreed with at least the share reported early, or a halt.
+/// A round is only pending while votes are missing, and on the full round
+/// try_decide is decide_consensus.
+proof fn early_decision_sound(cast: Seq<Vote>, rest: Seq<Vote>, n: nat, t: Threshold)
+    requires
+        valid_threshold(t),
+        cast.len() + rest.len() == n,
+        n <= max_round_size(),
     ensures
-        byzantine_safe(3, 1),  // 3 models can tolerate 1 Byzantine
-        !byzantine_safe(3, 2), // But cannot tolerate 2 Byzantine
+        match try_decide(cast, n, t) {
+            RoundState::Decided { outcome: ConsensusOutcome::Agreed { value, agreement_pct } } => {
+                match decide_consensus(cast + rest, n, t) {
+                    ConsensusOutcome::Agreed { value: final_value, agreement_pct: final_pct } => {
+                        final_value == value && final_pct >= agreement_pct
+                    },
+                    ConsensusOutcome::Halted { reason: _ } => false,
+                }
+            },
+            RoundState::Decided { outcome: ConsensusOutcome::Halted { reason: _ } } => {
+                decide_consensus(cast + rest, n, t) is Halted
+            },
+            RoundState::Pending => rest.len() > 0,
+        },
+        try_decide(cast + rest, n, t)
+            == (RoundState::Decided { outcome: decide_consensus(cast + rest, n, t) }),
 {
-    // 3*1 = 3 < 3 is false, but 3*1 < 3 means we need 3*1 < 3
-    // Actually: byzantine_safe(3,1) means 3*1 < 3, which is 3 < 3 = false
+    let all = cast + rest;
+    let remaining = rest.len();
+    let (agrees, disagrees) = (count_votes(cast, true), count_votes(cast, false));
+    let (final_agrees, final_disagrees) = (count_votes(all, true), count_votes(all, false));
+    lemma_count_votes_extend(cast, rest, true);
+    lemma_count_votes_extend(cast, rest, false);
+    lemma_count_votes_partition(all);
+
+    if n > 0 {
+        // On the full round only one value can reach t, and decide_consensus
+        // agrees on it exactly when it does
+        decided_value_has_supermajority(all, n, t);
+        lemma_single_value_reaches_threshold(all, n, t);
+
+        // Votes cast early still count at the end
+        lemma_threshold_exact(t);
+        let te = threshold_exact(t);
+        if meets_threshold(agrees, n, t) {
+            assert(final_agrees * 3000 >= te * n) by(nonlinear_arith)
+                requires agrees <= final_agrees, agrees * 3000 >= te * n;
+            lemma_ratio_monotone(agrees, final_agrees, n);
+        }
+        if meets_threshold(disagrees, n, t) {
+            assert(final_disagrees * 3000 >= te * n) by(nonlinear_arith)
+                requires disagrees <= final_disagrees, disagrees * 3000 >= te * n;
+            lemma_ratio_monotone(disagrees, final_disagrees, n);
+        }
+
+        // Missing votes bound the final counts from above
+        if !meets_threshold(agrees + remaining, n, t) {
+            assert(final_agrees * 3000 < te * n) by(nonlinear_arith)
+                requires final_agrees <= agrees + remaining, (agrees + remaining) * 3000 < te * n;
+        }
+        if !meets_threshold(disagrees + remaining, n, t) {
+            assert(final_disagrees * 3000 < te * n) by(nonlinear_arith)
+                requires
+                    final_disagrees <= disagrees + remaining,
+                    (disagrees + remaining) * 3000 < te * n;
+        }
+
+        // try_decide on the full round has no votes missing
+        assert((n - all.len()) as nat == 0);
+    }
+}
 
-    // Wait, let me recalculate:
-    // byzantine_safe(n, f) = 3*f < n
-    // byzantine_safe(3, 1) = 3*1 < 3 = 3 < 3 = false
+// ============================================================================
+// REGISTERED VOTERS: ONE BALLOT PER KEY
+// ============================================================================
 
-    // Hmm, that's not right. Let's use the standard PBFT formula:
-    // We need n >= 3f + 1 for safety, so for f=1: n >= 4
-    // But for probabilistic consensus with diverse models, we use n = 2f + 1
+/// Specification: Ballots cast for `value`, one slot per registered voter
+pub open spec fn ballot_count(ballots: Seq<Ballot>, value: Vote) -> nat
+    decreases ballots.len(),
+{
+    if ballots.len() == 0 {
+        0
+    } else {
+        ballot_count(ballots.drop_last(), value)
+            + if ballots.last() == Some(value) { 1nat } else { 0nat }
+    }
+}
 
-    // For our system: we require 2/3 agreement (67%)
-    // With n=3 and f=1: max honest = 2, agreement = 2/3 = 66.7% just below 67%
-    // So we need to be precise about thresholds
+/// Specification: Decide a round over a registry of voters
+///
+/// `ballots[i]` is registered voter i's ballot, None while missing; a key
+/// outside the registry has no slot, and a key votes at most once. n is the
+/// registry size rather than the votes received, so a missing voter counts
+/// toward a halt instead of shrinking the denominator.
+pub open spec fn decide_registered(ballots: Seq<Ballot>, t: Threshold) -> ConsensusOutcome {
+    decide_consensus(cast_votes(ballots), ballots.len(), t)
+}
 
-    // Actually, our empirical data shows 83% accuracy at 33% attack
-    // This proves N=3 works in practice
+/// The votes cast for a value are its ballots
+proof fn lemma_ballot_count(ballots: Seq<Ballot>, value: Vote)
+    ensures
+        count_votes(cast_votes(ballots), value) == ballot_count(ballots, value),
+    decreases ballots.len(),
+{
+    if ballots.len() > 0 {
+        lemma_ballot_count(ballots.drop_last(), value);
+        if let Some(v) = ballots.last() {
+            lemma_count_votes_push(cast_votes(ballots.drop_last()), v, value);
+        }
+    }
+}
 
-    assert(3 * 1 == 3);  // 3*f = 3
-    // byzantine_safe(3,1) = 3 < 3 = false by strict inequality
+/// Replacing one ballot moves a value's count by at most one
+proof fn lemma_ballot_count_update(ballots: Seq<Ballot>, i: int, ballot: Ballot, value: Vote)
+    requires
+        0 <= i < ballots.len(),
+    ensures
+        ballot_count(ballots.update(i, ballot), value) <= ballot_count(ballots, value) + 1,
+        ballot_count(ballots, value) <= ballot_count(ballots.update(i, ballot), value) + 1,
+    decreases ballots.len(),
+{
+    let changed = ballots.update(i, ballot);
+    if i == ballots.len() - 1 {
+        assert(changed.drop_last() =~= ballots.drop_last());
+    } else {
+        assert(changed.drop_last() =~= ballots.drop_last().update(i, ballot));
+        assert(changed.last() == ballots.last());
+        lemma_ballot_count_update(ballots.drop_last(), i, ballot, value);
+    }
+}
 
-    // The key insight: for diverse LLMs, we use probabilistic majority
-    // not strict PBFT quorums. The 500-sample data validates this.
+/// THEOREM: Missing Voters Count Against Agreement
+///
+/// An agreement over a registry has the threshold of every registered
+/// voter behind it, missing ones included.
+proof fn registered_agreement_counts_missing(ballots: Seq<Ballot>, t: Threshold)
+    ensures
+        match decide_registered(ballots, t) {
+            ConsensusOutcome::Agreed { value, agreement_pct: _ } => {
+                meets_threshold(ballot_count(ballots, value), ballots.len(), t)
+            },
+            ConsensusOutcome::Halted { reason: _ } => true,
+        },
+{
+    lemma_ballot_count(ballots, true);
+    lemma_ballot_count(ballots, false);
 }
 
-/// THEOREM 4: Empirical Validation (500-sample)
+/// THEOREM: One Key Moves the Ratio by at Most 1/n
 ///
-/// The 500-sample benchmark results prove Byzantine resilience.
-proof fn empirical_validation_500()
+/// Whatever the holder of one registered key does with its slot (vote,
+/// change its vote, or stay away), each value's count moves by at most one
+/// over the same n, the registry size: its agreement ratio moves by at most
+/// 1/n. A key outside the registry, or a second submission, has no slot to
+/// move at all.
+proof fn single_keyholder_bounded_influence(
+    ballots: Seq<Ballot>,
+    i: int,
+    ballot: Ballot,
+    value: Vote,
+)
+    requires
+        0 <= i < ballots.len(),
     ensures
-        // Baseline: 464/500 = 92.8%
-        (464u64 * 1000) / 500 == 928,
-        // 33% attack: 415/500 = 83.0%
-        (415u64 * 1000) / 500 == 830,
-        // 67% attack halts: 289/500 = 57.8%
-        (289u64 * 1000) / 500 == 578,
-        // Resilience factor: 83.0/92.8 = 89.4%
-        (830u64 * 1000) / 928 >= 890,
+        ballots.update(i, ballot).len() == ballots.len(),
+        count_votes(cast_votes(ballots.update(i, ballot)), value)
+            <= count_votes(cast_votes(ballots), value) + 1,
+        count_votes(cast_votes(ballots), value)
+            <= count_votes(cast_votes(ballots.update(i, ballot)), value) + 1,
 {
-    // Direct calculations from benchmark data
-    assert((464u64 * 1000) / 500 == 928);
-    assert((415u64 * 1000) / 500 == 830);
-    assert((289u64 * 1000) / 500 == 578);
-    assert((830u64 * 1000) / 928 == 894);
+    lemma_ballot_count(ballots, value);
+    lemma_ballot_count(ballots.update(i, ballot), value);
+    lemma_ballot_count_update(ballots, i, ballot, value);
 }
 
 // ============================================================================
-// PBFT-STYLE QUORUM PROOFS
+// HALT ESCALATION: WINDOWS OF RECENT ROUNDS
 // ============================================================================
 
-/// PBFT quorum size for prepare phase: 2f
-pub open spec fn prepare_quorum(f: nat) -> nat {
-    2 * f
+/// Specification: Rounds among `rounds` that halted
+pub open spec fn count_halts(rounds: Seq<ConsensusOutcome>) -> nat
+    decreases rounds.len(),
+{
+    if rounds.len() == 0 {
+        0
+    } else {
+        count_halts(rounds.drop_last()) + if rounds.last() is Halted { 1nat } else { 0nat }
+    }
 }
 
-/// PBFT quorum size for commit phase: 2f + 1
-pub open spec fn commit_quorum(f: nat) -> nat {
-    2 * f + 1
+/// Specification: The last `window` rounds (all of them while fewer)
+pub open spec fn recent_rounds(rounds: Seq<ConsensusOutcome>, window: nat) -> Seq<
+    ConsensusOutcome,
+> {
+    if rounds.len() <= window {
+        rounds
+    } else {
+        rounds.subrange(rounds.len() - window, rounds.len() as int)
+    }
 }
 
-/// THEOREM 5: PBFT Quorum Intersection
+/// Specification: Escalate to a hard stop when the halts among the last
+/// `window` rounds exceed `max_halt_rate` (per mille) of the window
 ///
-/// Any two quorums of size 2f+1 in a system of n=3f+1 nodes
-/// must intersect in at least one honest node.
-proof fn quorum_intersection(n: nat, f: nat)
+/// The rate is over the whole window even before it fills, so a single
+/// early halt does not read as a 100% halt rate.
+pub open spec fn should_escalate(
+    rounds: Seq<ConsensusOutcome>,
+    window: nat,
+    max_halt_rate: nat,
+) -> bool {
+    count_halts(recent_rounds(rounds, window)) * 1000 > max_halt_rate * window
+}
+
+/// Rounds that all halted count fully
+proof fn lemma_count_halts_all(rounds: Seq<ConsensusOutcome>)
     requires
-        n == 3 * f + 1,  // PBFT requirement
+        forall|i: int| 0 <= i < rounds.len() ==> #[trigger] rounds[i] is Halted,
     ensures
-        // Two quorums of 2f+1 overlap by at least f+1 nodes
-        ({
-            let quorum_size = commit_quorum(f);
-            let overlap = 2 * quorum_size - n;
-            overlap >= f + 1
-        })
+        count_halts(rounds) == rounds.len(),
+    decreases rounds.len(),
 {
-    // Two sets of size q in universe of size n overlap by at least 2q - n
-    // overlap = 2(2f+1) - (3f+1) = 4f + 2 - 3f - 1 = f + 1
-
-    let quorum_size = commit_quorum(f);
-    assert(quorum_size == 2 * f + 1);
-
-    let overlap = 2 * quorum_size - n;
-    assert(overlap == 2 * (2 * f + 1) - (3 * f + 1));
-    assert(overlap == 4 * f + 2 - 3 * f - 1);
-    assert(overlap == f + 1);
+    if rounds.len() > 0 {
+        let rest = rounds.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies #[trigger] rest[i] is Halted by {
+            assert(rest[i] == rounds[i]);
+        }
+        lemma_count_halts_all(rest);
+        assert(rounds[rounds.len() - 1] is Halted);
+    }
 }
 
-/// THEOREM 6: Quorum Contains Honest Node
+/// THEOREM: Persistent Halts Escalate
 ///
-/// In n=3f+1 with f Byzantine, any quorum of 2f+1 contains at least f+1 honest.
-proof fn quorum_honest_count(n: nat, f: nat)
+/// If every round in a full window halted, escalation triggers, for any
+/// bound below 1000 (a bound of 1000 could never be exceeded).
+proof fn persistent_halts_escalate(
+    rounds: Seq<ConsensusOutcome>,
+    window: nat,
+    max_halt_rate: nat,
+)
     requires
-        n == 3 * f + 1,
+        window > 0,
+        max_halt_rate < 1000,
+        rounds.len() >= window,
+        forall|i: int|
+            rounds.len() - window <= i < rounds.len() ==> #[trigger] rounds[i] is Halted,
     ensures
-        ({
-            let quorum_size = commit_quorum(f);
-            let honest_in_quorum = quorum_size - f;  // Worst case: all f Byzantine in quorum
-            honest_in_quorum >= f + 1
-        })
+        should_escalate(rounds, window, max_halt_rate),
 {
-    // quorum = 2f + 1
-    // worst case Byzantine in quorum = f
-    // honest in quorum >= 2f + 1 - f = f + 1
-
-    let quorum_size = commit_quorum(f);
-    let honest_in_quorum = quorum_size - f;
-    assert(honest_in_quorum == 2 * f + 1 - f);
-    assert(honest_in_quorum == f + 1);
+    let last = recent_rounds(rounds, window);
+    assert(last.len() == window);
+    assert forall|j: int| 0 <= j < last.len() implies #[trigger] last[j] is Halted by {
+        assert(last[j] == rounds[rounds.len() - window + j]);
+    }
+    lemma_count_halts_all(last);
+    assert(window * 1000 > max_halt_rate * window) by(nonlinear_arith)
+        requires
+            window > 0,
+            max_halt_rate < 1000;
 }
 
 // ============================================================================
-// LLM-SPECIFIC ADAPTATIONS
+// FAULT BUDGET: GUARANTEES LEFT WITH f BYZANTINE NODES
 // ============================================================================
 
-/// Specification: LLM model diversity score
-/// Higher diversity = more independent failure modes
-pub open spec fn diversity_score(model_ids: Seq<u64>) -> u64 {
-    // Simplified: count unique model families
-    // In practice: architectural distance metric
-    if model_ids.len() == 0 { 0 }
-    else if model_ids.len() == 1 { 100 }  // Single model = 10% diversity
-    else if model_ids.len() == 2 { 500 }  // Two models = 50%
-    else { 1000 }                          // Three+ models = 100%
+/// What a round still guarantees with f Byzantine nodes among n
+pub enum GuaranteeLevel {
+    /// Agreements carry the honest majority's value (byzantine_safety)
+    FullBft,
+    /// The Byzantine nodes alone fall short of the threshold: they can
+    /// force a halt but never overturn a unanimous honest vote
+    HaltOnly,
+    /// The Byzantine nodes alone reach the threshold
+    None,
 }
 
-/// Specification: Effective Byzantine tolerance given diversity
-/// Higher diversity allows higher fault tolerance
-pub open spec fn effective_tolerance(n: nat, diversity: u64) -> nat {
-    // With full diversity (1000), standard BFT: f < n/3
-    // With reduced diversity, tolerance degrades
-    let base_tolerance = n / 3;
-    ((base_tolerance as u64) * diversity / 1000) as nat
+/// Specification: Guarantee level of n nodes, f of them Byzantine, at
+/// threshold t
+pub open spec fn guarantee_level(n: nat, f: nat, t: Threshold) -> GuaranteeLevel {
+    if byzantine_safe(n, f) && threshold_tolerates(n, f, t) {
+        GuaranteeLevel::FullBft
+    } else if !meets_threshold(f, n, t) {
+        GuaranteeLevel::HaltOnly
+    } else {
+        GuaranteeLevel::None
+    }
 }
 
-/// THEOREM 7: Diversity Amplifies Tolerance
-///
-/// Heterogeneous model ensembles achieve better-than-classical BFT
-/// because their failure modes don't align.
-proof fn diversity_amplification(n: nat)
+/// At full BFT the Byzantine nodes cannot overturn anything: an agreement
+/// carries the honest majority's value
+proof fn lemma_full_bft_level(
+    votes: Seq<Vote>,
+    byzantine: Seq<bool>,
+    n: nat,
+    t: Threshold,
+    v: Vote,
+)
     requires
         n >= 3,
+        valid_threshold(t),
+        votes.len() == n,
+        byzantine.len() == n,
+        guarantee_level(n, count_votes(byzantine, true), t) is FullBft,
+        honest_majority(n, count_votes(byzantine, true), count_honest_votes(votes, byzantine, v)),
     ensures
-        // With diverse models, effective tolerance approaches theoretical max
-        ({
-            let diverse = diversity_score(seq![0u64, 1u64, 2u64]);  // 3 different models
-            let homogeneous = diversity_score(seq![0u64, 0u64, 0u64]);  // Same model
-            diverse > homogeneous
-        })
+        match decide_consensus(votes, n, t) {
+            ConsensusOutcome::Agreed { value, agreement_pct: _ } => value == v,
+            ConsensusOutcome::Halted { reason: _ } => true,
+        },
 {
-    // Three different models have diversity 1000
-    // Same model three times has diversity 100 (still better than 1)
-    // The diversity score reflects independence of failure modes
+    byzantine_safety(votes, byzantine, n, t, v);
 }
 
-/// THEOREM 8: 500-Sample Statistical Power
+/// Short of full BFT but at HaltOnly, a unanimous honest vote for v still
+/// decides v or halts: the votes against it are the Byzantine ones alone
+proof fn lemma_halt_only_level(
+    votes: Seq<Vote>,
+    byzantine: Seq<bool>,
+    n: nat,
+    t: Threshold,
+    v: Vote,
+)
+    requires
+        valid_threshold(t),
+        votes.len() == n,
+        byzantine.len() == n,
+        !(guarantee_level(n, count_votes(byzantine, true), t) is None),
+        count_honest_votes(votes, byzantine, v) == count_votes(byzantine, false),
+    ensures
+        match decide_consensus(votes, n, t) {
+            ConsensusOutcome::Agreed { value, agreement_pct: _ } => value == v,
+            ConsensusOutcome::Halted { reason: _ } => true,
+        },
+{
+    let f = count_votes(byzantine, true);
+    lemma_count_votes_partition(byzantine);
+    lemma_honest_votes(votes, byzantine, !v);
+    // No honest vote for !v, so at most the f Byzantine ones
+    assert(count_votes(votes, !v) <= f);
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    if n > 0 && !meets_threshold(f, n, t) {
+        assert(count_votes(votes, !v) * 3000 < te * n) by(nonlinear_arith)
+            requires
+                count_votes(votes, !v) <= f,
+                f * 3000 < te * n;
+    } else if n > 0 {
+        // FullBft: f < n/3, and n/3 of n falls short of T > 1500
+        assert(f * 3000 < te * n) by(nonlinear_arith)
+            requires
+                3 * f < n,
+                te > 1500;
+        assert(count_votes(votes, !v) * 3000 < te * n) by(nonlinear_arith)
+            requires
+                count_votes(votes, !v) <= f,
+                f * 3000 < te * n;
+    }
+}
+
+/// At None the Byzantine nodes alone carry a round: whenever they are the
+/// only votes for !v, the round agrees on !v
+proof fn lemma_none_level(votes: Seq<Vote>, f: nat, n: nat, t: Threshold, v: Vote)
+    requires
+        valid_threshold(t),
+        votes.len() == n,
+        0 < n,
+        f <= n,
+        guarantee_level(n, f, t) is None,
+        count_votes(votes, !v) == f,
+    ensures
+        decide_consensus(votes, n, t) == (ConsensusOutcome::Agreed {
+            value: !v,
+            agreement_pct: agreement_ratio_scaled(f, n),
+        }),
+{
+    lemma_count_votes_partition(votes);
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    assert(2 * f > n) by(nonlinear_arith)
+        requires
+            f * 3000 >= te * n,
+            te > 1500,
+            n > 0;
+    // The majority is !v, held by the f Byzantine votes
+    assert(majority_value(votes) == !v);
+}
+
+/// THEOREM: Guarantee Levels Degrade Monotonically
 ///
-/// 500 samples provide p < 0.001 statistical significance.
-proof fn statistical_power_500()
+/// One more Byzantine node never improves the level, and full BFT at the
+/// default threshold is exactly f < n/3.
+proof fn guarantee_levels_monotone(n: nat, f: nat, t: Threshold)
+    requires
+        valid_threshold(t),
+        f < n,
     ensures
-        // 95% confidence interval for 83% accuracy with n=500
-        // CI = p +/- 1.96 * sqrt(p(1-p)/n)
-        // CI = 0.83 +/- 1.96 * sqrt(0.83*0.17/500)
-        // CI = 0.83 +/- 0.033
-        // CI = [0.797, 0.863]
-        ({
-            let p = 830u64;  // 83.0%
-            let n = 500u64;
-            // Standard error (scaled by 10000): sqrt(p*(1000-p)/n) * 100
-            // SE = sqrt(830*170/500) * 100 / 1000 = sqrt(282.2) * 0.1 = 1.68
-            let se_scaled = 168u64;  // 1.68%
-            // 95% CI: 1.96 * SE ≈ 2 * SE = 3.36%
-            let ci_width = 336u64;  // 3.36%
-            ci_width < 400  // CI width < 4%
-        })
+        guarantee_level(n, f + 1, t) is FullBft ==> guarantee_level(n, f, t) is FullBft,
+        !(guarantee_level(n, f + 1, t) is None) ==> !(guarantee_level(n, f, t) is None),
+        (guarantee_level(n, f, CONSENSUS_THRESHOLD) is FullBft) == byzantine_safe(n, f),
 {
-    // Direct calculation shows 95% CI is approximately [79.7%, 86.3%]
-    // This does not include 0% (single model failure), proving significance
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    assert(f * 1500 <= (te - 1500) * n || !((f + 1) * 1500 <= (te - 1500) * n));
+    assert(f * 3000 <= (f + 1) * 3000);
+    default_threshold_tolerances(n, f);
 }
 
 // ============================================================================
-// CONSTITUTIONAL HALT IMPLEMENTATION
+// PERMUTATION INVARIANCE: ARRIVAL ORDER NEVER MATTERS
 // ============================================================================
 
-/// Specification: Constitutional halt decision
-pub open spec fn constitutional_halt(
-    agreement_pct: u64,
-    variance_ratio: u64,  // Current variance / baseline variance
-    min_agreement: u64,   // Typically 670 (67%)
-    max_variance_ratio: u64,  // Typically 625 (6.25x baseline)
-) -> bool {
-    // Halt if agreement too low OR variance too high
-    agreement_pct < min_agreement || variance_ratio > max_variance_ratio
+/// Specification: `b` reorders `a`: the same votes, each as often
+pub open spec fn permutes<A>(a: Seq<A>, b: Seq<A>) -> bool {
+    a.to_multiset() == b.to_multiset()
 }
 
-/// THEOREM 9: Constitutional Halt Safety
-///
-/// Constitutional halt never triggers when all agents are honest and agreeing.
-proof fn halt_safety(n: nat, honest_agreement: u64, honest_variance_ratio: u64)
+/// A value's count is its multiplicity in the round's multiset of votes,
+/// which forgets their order (count_votes folds over it in order)
+proof fn lemma_count_votes_multiset(votes: Seq<Vote>, value: Vote)
+    ensures
+        count_votes(votes, value) == votes.to_multiset().count(value),
+    decreases votes.len()
+{
+    to_multiset_ensures(votes);
+    if votes.len() > 0 {
+        let rest = votes.drop_last();
+        lemma_count_votes_multiset(rest, value);
+        lemma_count_votes_push(rest, votes.last(), value);
+        to_multiset_ensures(rest);
+        assert(rest.push(votes.last()) =~= votes);
+    } else {
+        // An empty round contains nothing, so nothing has a positive count
+        assert(!votes.contains(value));
+    }
+}
+
+/// THEOREM: Vote Counts are Permutation-Invariant
+proof fn count_votes_permutation_invariant(a: Seq<Vote>, b: Seq<Vote>, value: Vote)
     requires
-        n >= 3,
-        honest_agreement >= 900,  // 90% agreement (honest consensus)
-        honest_variance_ratio <= 200,  // Variance at most 2x baseline
+        permutes(a, b),
     ensures
-        !constitutional_halt(honest_agreement, honest_variance_ratio, 670, 625)
+        count_votes(a, value) == count_votes(b, value),
+        a.len() == b.len(),
 {
-    // With 90% agreement >= 67%, no halt from agreement
-    // With 2x variance ratio < 6.25x, no halt from variance
-    assert(honest_agreement >= 670);
-    assert(honest_variance_ratio <= 625);
+    lemma_count_votes_multiset(a, value);
+    lemma_count_votes_multiset(b, value);
+    to_multiset_ensures(a);
+    to_multiset_ensures(b);
 }
 
-/// THEOREM 10: Constitutional Halt Liveness
+/// THEOREM: Consensus Decisions are Permutation-Invariant
 ///
-/// Constitutional halt triggers when majority is compromised.
-proof fn halt_liveness(
-    byzantine_agreement: u64,
-    byzantine_variance_ratio: u64,
-)
+/// decide_consensus reads a round only through its length and its two
+/// counts, so any reordering of the votes decides identically: network
+/// arrival order cannot change an outcome.
+proof fn decide_consensus_permutation_invariant(a: Seq<Vote>, b: Seq<Vote>, t: Threshold)
     requires
-        // When majority Byzantine, agreement drops and variance spikes
-        byzantine_agreement < 500 || byzantine_variance_ratio > 1000,
+        permutes(a, b),
     ensures
-        constitutional_halt(byzantine_agreement, byzantine_variance_ratio, 670, 625)
+        decide_consensus(a, a.len(), t) == decide_consensus(b, b.len(), t),
 {
-    // Either agreement < 67% (from agreement < 500 < 670)
-    // Or variance > 6.25x (from variance_ratio > 1000 > 625)
-    // Either condition triggers halt
+    count_votes_permutation_invariant(a, b, true);
+    count_votes_permutation_invariant(a, b, false);
 }
 
 } // verus!
@@ -461,6 +2993,42 @@ mod tests {
         assert_eq!(ratio, 830);
     }
 
+    #[test]
+    fn test_n_three_sufficiency() {
+        // Majority voting: 2f+1 <= n and the n-f honest votes meet the
+        // threshold, (n-f)*3 >= 2n at two thirds, (n-f)*1000 >= t*n at t/1000
+        let two_thirds = |n: u64, f: u64| 2 * f + 1 <= n && (n - f) * 3 >= 2 * n;
+        let per_mille = |n: u64, f: u64, t: u64| 2 * f + 1 <= n && (n - f) * 1000 >= t * n;
+        assert!(two_thirds(3, 1));
+        assert!(per_mille(3, 1, 666));
+        assert!(!per_mille(3, 1, 667));
+        assert!(!per_mille(3, 1, 668));
+        assert!(!two_thirds(3, 2));
+        assert!(!per_mille(3, 2, 0));
+
+        // Classical BFT needs n = 4 for f = 1
+        assert!(!(3 * 1 < 3));
+        assert!(3 * 1 < 4);
+    }
+
+    #[test]
+    fn test_exact_threshold() {
+        // Two thirds: agrees * 3 >= 2 * n
+        assert!(2 * 3 >= 2 * 3);  // 2 of 3 agree
+        assert!(4 * 3 >= 2 * 6);  // 4 of 6 likewise
+        assert!(!(666 * 3 >= 2 * 1000));  // 666 of 1000 fall short
+        assert!(667 * 3 >= 2 * 1000);
+
+        // PerMille(670): agrees * 1000 >= 670 * n
+        assert!(!(2 * 1000 >= 670 * 3));  // 2 of 3 fall short
+        assert!(!(669 * 1000 >= 670 * 1000));
+        assert!(670 * 1000 >= 670 * 1000);
+
+        // n = 4, f = 1 is not beyond tolerance: 3 honest hold 750
+        assert!(!(3 * 1 >= 4));
+        assert_eq!((3 * 1000) / 4, 750);
+    }
+
     #[test]
     fn test_quorum_sizes() {
         // f=1: prepare=2, commit=3
@@ -502,15 +3070,15 @@ mod tests {
     #[test]
     fn test_constitutional_halt() {
         // Should NOT halt: 90% agreement, 2x variance
-        let should_halt_1 = 900 < 670 || 200 > 625;
+        let should_halt_1 = 900 < 666 || 200 > 625;
         assert!(!should_halt_1);
 
         // SHOULD halt: 50% agreement
-        let should_halt_2 = 500 < 670 || 200 > 625;
+        let should_halt_2 = 500 < 666 || 200 > 625;
         assert!(should_halt_2);
 
         // SHOULD halt: 10x variance
-        let should_halt_3 = 900 < 670 || 1000 > 625;
+        let should_halt_3 = 900 < 666 || 1000 > 625;
         assert!(should_halt_3);
     }
 }
@@ -0,0 +1,152 @@
+//! # Hash Chains
+//!
+//! Executable counterparts of the `Hash` and `ChainedProof` specifications in
+//! `ed25519_contracts.rs`.
+//!
+//! `valid_chain` here checks exactly the property specified there: every
+//! entry's `previous_hash` equals its predecessor's `content_hash`. Theorem 5
+//! (Chain Integrity) then applies to any chain built with these types: an
+//! inserted or removed entry changes the chain tip.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+/// SHA-256 hash output (32 bytes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hash {
+    pub bytes: [u8; 32],
+}
+
+impl Hash {
+    /// All-zero hash, used as the `previous_hash` of a chain's first entry
+    pub const ZERO: Hash = Hash { bytes: [0; 32] };
+
+    /// SHA-256 of `data`
+    pub fn sha256(data: &[u8]) -> Self {
+        Self {
+            bytes: Sha256::digest(data).into(),
+        }
+    }
+
+    /// Lowercase hex encoding
+    pub fn to_hex(&self) -> String {
+        hex_encode(&self.bytes)
+    }
+
+    /// Parse a 64-character hex string
+    pub fn from_hex(text: &str) -> Option<Self> {
+        Some(Self {
+            bytes: hex_decode(text)?.try_into().ok()?,
+        })
+    }
+}
+
+/// Lowercase hex encoding of arbitrary bytes
+pub fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decode an even-length hex string
+pub fn hex_decode(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&text[i..i + 2], 16).ok())
+        .collect()
+}
+
+impl fmt::Display for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Hash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Hash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Hash::from_hex(&text)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid SHA-256 hex: {}", text)))
+    }
+}
+
+/// Entry in a hash chain.
+///
+/// Mirrors the `ChainedProof` specification. The signature and public key
+/// fields are omitted: the chain's integrity property depends only on the
+/// hash links.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainedProof {
+    pub proof_id: Vec<u8>,
+    pub content_hash: Hash,
+    /// Links to previous proof
+    pub previous_hash: Hash,
+}
+
+/// Proof chain is valid: each entry links to its predecessor
+pub fn valid_chain(proofs: &[ChainedProof]) -> bool {
+    first_broken_link(proofs).is_none()
+}
+
+/// Index of the first entry whose `previous_hash` does not match its
+/// predecessor's `content_hash`
+pub fn first_broken_link(proofs: &[ChainedProof]) -> Option<usize> {
+    proofs
+        .windows(2)
+        .position(|pair| pair[1].previous_hash != pair[0].content_hash)
+        .map(|i| i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(id: u8, previous: Hash) -> ChainedProof {
+        ChainedProof {
+            proof_id: vec![id],
+            content_hash: Hash::sha256(&[id]),
+            previous_hash: previous,
+        }
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let hash = Hash::sha256(b"abc");
+        assert_eq!(
+            hash.to_hex(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(Hash::from_hex(&hash.to_hex()), Some(hash));
+        assert_eq!(Hash::from_hex("abc"), None);
+        assert_eq!(Hash::from_hex(&"zz".repeat(32)), None);
+    }
+
+    #[test]
+    fn test_chain_validation() {
+        // Empty and single-element chains are vacuously valid
+        assert!(valid_chain(&[]));
+        let a = link(1, Hash::ZERO);
+        assert!(valid_chain(std::slice::from_ref(&a)));
+
+        let b = link(2, a.content_hash);
+        let c = link(3, b.content_hash);
+        assert!(valid_chain(&[a.clone(), b.clone(), c.clone()]));
+
+        // Removing the middle entry breaks the link at the next entry
+        assert_eq!(first_broken_link(&[a, c]), Some(1));
+    }
+}
@@ -0,0 +1,565 @@
+//! # Patent Claim Evidence
+//!
+//! Maps patent claims to the proof modules and theorems that support them,
+//! and rolls a verification summary up into per-claim status.
+//!
+//! The built-in registry covers US 63/896,282. Downstream patents can load
+//! their own mapping from TOML:
+//!
+//! ```toml
+//! [[claim]]
+//! id = "Claim 12"
+//! description = "Routing Policy Safety"
+//! modules = ["routing_policy"]
+//! theorems = ["byzantine_consensus::quorum_intersection"]
+//! ```
+//!
+//! A claim listing a module depends on every theorem in it; a claim listing
+//! theorems (`module::theorem`) depends only on those. Through the
+//! registry's [`TheoremGraph`], a claim is also at risk when a lemma beneath
+//! one of its theorems failed.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::dependency::TheoremGraph;
+use crate::error::ShieldError;
+use crate::{VerificationStatus, VerificationSummary};
+
+/// Patent claim identifier
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Claim {
+    /// Claim 2: N=3 Optimality
+    N3Optimality,
+    /// Claim 3: Constitutional Halts
+    ConstitutionalHalts,
+    /// Claim 4: Hardware-Attested Consensus
+    HardwareAttestedConsensus,
+    /// Claim 16: Byzantine Threshold
+    ByzantineThreshold,
+    /// Claim 17: N=3 Sufficiency
+    N3Sufficiency,
+    /// Claim 79: Deductive Verification (CIP)
+    DeductiveVerification,
+    /// Claim 80: Cryptographic Contracts (CIP)
+    CryptographicContracts,
+    /// Claim 81: Dual Validation (CIP)
+    DualValidation,
+    /// Claim 82: Formally Verified Halt (CIP)
+    FormallyVerifiedHalt,
+    /// A claim outside the built-in patent, by its identifier
+    Other(String),
+}
+
+impl Claim {
+    const BUILTIN: [(Claim, &'static str); 9] = [
+        (Claim::N3Optimality, "Claim 2"),
+        (Claim::ConstitutionalHalts, "Claim 3"),
+        (Claim::HardwareAttestedConsensus, "Claim 4"),
+        (Claim::ByzantineThreshold, "Claim 16"),
+        (Claim::N3Sufficiency, "Claim 17"),
+        (Claim::DeductiveVerification, "Claim 79"),
+        (Claim::CryptographicContracts, "Claim 80"),
+        (Claim::DualValidation, "Claim 81"),
+        (Claim::FormallyVerifiedHalt, "Claim 82"),
+    ];
+
+    /// Identifier as written in the patent (`Claim 2`)
+    pub fn id(&self) -> &str {
+        match self {
+            Claim::Other(id) => id,
+            builtin => {
+                Self::BUILTIN
+                    .iter()
+                    .find(|(claim, _)| claim == builtin)
+                    .expect("every built-in claim has an id")
+                    .1
+            }
+        }
+    }
+}
+
+impl fmt::Display for Claim {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+impl FromStr for Claim {
+    type Err = std::convert::Infallible;
+
+    /// Built-in identifiers map to their variant; anything else is `Other`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        Ok(Self::BUILTIN
+            .into_iter()
+            .find(|(_, id)| *id == text)
+            .map(|(claim, _)| claim)
+            .unwrap_or_else(|| Claim::Other(text.to_string())))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Claim {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Claim {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Ok(text.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+/// Evidence status of a claim
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimStatus {
+    /// Every required module and theorem verified
+    Supported,
+    /// A required module or theorem failed
+    AtRisk,
+    /// Required evidence is missing, pending or not run
+    Unverified,
+}
+
+impl fmt::Display for ClaimStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ClaimStatus::Supported => "supported",
+            ClaimStatus::AtRisk => "at risk",
+            ClaimStatus::Unverified => "unverified",
+        })
+    }
+}
+
+/// Evidence a claim depends on
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize), serde(deny_unknown_fields))]
+pub struct ClaimRequirement {
+    #[cfg_attr(feature = "serde", serde(rename = "id"))]
+    pub claim: Claim,
+    pub description: String,
+    /// Modules that must verify in full
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub modules: Vec<String>,
+    /// Individual theorems (`module::theorem`) that must verify
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub theorems: Vec<String>,
+}
+
+impl ClaimRequirement {
+    /// Human-readable list of the evidence
+    pub fn evidence(&self) -> String {
+        self.modules
+            .iter()
+            .chain(&self.theorems)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Status of this claim given a summary, from the listed evidence only
+    pub fn evaluate(&self, summary: &VerificationSummary) -> ClaimStatus {
+        let modules = self.modules.iter().map(|module| summary.status(module));
+        let theorems = self.theorems.iter().map(|reference| {
+            let (module, theorem) = reference.split_once("::")?;
+            summary
+                .module(module)?
+                .theorems
+                .iter()
+                .find(|t| t.name == theorem)
+                .map(|t| t.status)
+        });
+        let statuses: Vec<Option<VerificationStatus>> = modules.chain(theorems).collect();
+
+        if statuses.contains(&Some(VerificationStatus::Failed)) {
+            ClaimStatus::AtRisk
+        } else if statuses
+            .iter()
+            .all(|s| *s == Some(VerificationStatus::Verified))
+        {
+            ClaimStatus::Supported
+        } else {
+            ClaimStatus::Unverified
+        }
+    }
+
+    /// Status of this claim given a summary, treating theorems that rest on
+    /// a failed theorem in `graph` as failed
+    pub fn evaluate_with(
+        &self,
+        summary: &VerificationSummary,
+        graph: &TheoremGraph,
+    ) -> ClaimStatus {
+        let status = self.evaluate(summary);
+        if status == ClaimStatus::AtRisk {
+            return status;
+        }
+        let at_risk = graph.at_risk(summary);
+        let module_theorems = self.modules.iter().flat_map(|module| {
+            summary.module(module).into_iter().flat_map(move |report| {
+                report
+                    .theorems
+                    .iter()
+                    .map(move |t| format!("{}::{}", module, t.name))
+            })
+        });
+        let mut required = module_theorems.chain(self.theorems.iter().cloned());
+        if required.any(|theorem| at_risk.contains(&theorem)) {
+            ClaimStatus::AtRisk
+        } else {
+            status
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RegistryFile {
+    #[serde(default, rename = "claim")]
+    claims: Vec<ClaimRequirement>,
+}
+
+/// Claim -> evidence mapping
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimRegistry {
+    claims: Vec<ClaimRequirement>,
+    graph: TheoremGraph,
+}
+
+impl ClaimRegistry {
+    /// Registry over the built-in theorem dependencies
+    pub fn new(claims: Vec<ClaimRequirement>) -> Self {
+        Self {
+            claims,
+            graph: TheoremGraph::builtin(),
+        }
+    }
+
+    /// Replace the theorem dependencies used by [`evaluate`](Self::evaluate)
+    pub fn with_graph(mut self, graph: TheoremGraph) -> Self {
+        self.graph = graph;
+        self
+    }
+
+    pub fn graph(&self) -> &TheoremGraph {
+        &self.graph
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn from_toml_str(text: &str) -> Result<Self, ShieldError> {
+        let file: RegistryFile = toml::from_str(text)?;
+        Ok(Self::new(file.claims))
+    }
+
+    /// Load a registry file
+    #[cfg(feature = "serde")]
+    pub fn load(path: &Path) -> Result<Self, ShieldError> {
+        fs::read_to_string(path)
+            .map_err(ShieldError::from)
+            .and_then(|text| Self::from_toml_str(&text))
+            .map_err(|e| {
+                ShieldError::config_invalid(format!("claim registry {}", path.display()), e)
+            })
+    }
+
+    /// Claim requirements in registry order
+    pub fn claims(&self) -> &[ClaimRequirement] {
+        &self.claims
+    }
+
+    /// Add a claim, replacing any existing requirement for it
+    pub fn insert(&mut self, requirement: ClaimRequirement) {
+        match self
+            .claims
+            .iter_mut()
+            .find(|c| c.claim == requirement.claim)
+        {
+            Some(existing) => *existing = requirement,
+            None => self.claims.push(requirement),
+        }
+    }
+
+    /// Status of every claim
+    pub fn evaluate(&self, summary: &VerificationSummary) -> BTreeMap<Claim, ClaimStatus> {
+        self.claims
+            .iter()
+            .map(|requirement| {
+                let status = requirement.evaluate_with(summary, &self.graph);
+                (requirement.claim.clone(), status)
+            })
+            .collect()
+    }
+}
+
+impl Default for ClaimRegistry {
+    /// Claims of US 63/896,282
+    fn default() -> Self {
+        let claim =
+            |claim, description: &str, modules: &[&str], theorems: &[&str]| ClaimRequirement {
+                claim,
+                description: description.to_string(),
+                modules: modules.iter().map(|m| m.to_string()).collect(),
+                theorems: theorems.iter().map(|t| t.to_string()).collect(),
+            };
+        Self::new(vec![
+            claim(
+                Claim::N3Optimality,
+                "N=3 Optimality",
+                &[],
+                &[
+                    "byzantine_consensus::n_three_sufficiency",
+                    "byzantine_consensus::empirical_validation_500",
+                    "byzantine_consensus::diversity_amplification",
+                    "trust_bounds::weighted_consensus_well_defined",
+                ],
+            ),
+            claim(
+                Claim::ConstitutionalHalts,
+                "Constitutional Halts",
+                &[],
+                &[
+                    "variance_halt::variance_halt_correctness",
+                    "variance_halt::constitutional_halt_safety",
+                    "variance_halt::constitutional_halt_liveness",
+                    "byzantine_consensus::constitutional_halt_correctness",
+                ],
+            ),
+            claim(
+                Claim::HardwareAttestedConsensus,
+                "Hardware-Attested Consensus",
+                &[],
+                &[
+                    "ed25519_contracts::signature_uniqueness",
+                    "ed25519_contracts::proof_bundle_integrity",
+                    "ed25519_contracts::audit_trail_non_repudiation",
+                ],
+            ),
+            claim(
+                Claim::ByzantineThreshold,
+                "Byzantine Threshold",
+                &[],
+                &[
+                    "byzantine_consensus::byzantine_safety",
+                    "byzantine_consensus::quorum_intersection",
+                    "byzantine_consensus::quorum_honest_count",
+                ],
+            ),
+            claim(
+                Claim::N3Sufficiency,
+                "N=3 Sufficiency",
+                &[],
+                &[
+                    "byzantine_consensus::n_three_sufficiency",
+                    "byzantine_consensus::empirical_validation_500",
+                ],
+            ),
+            claim(
+                Claim::DeductiveVerification,
+                "Deductive Verification",
+                &[
+                    "variance_halt",
+                    "trust_bounds",
+                    "byzantine_consensus",
+                    "ed25519_contracts",
+                ],
+                &[],
+            ),
+            claim(
+                Claim::CryptographicContracts,
+                "Cryptographic Contracts",
+                &["ed25519_contracts"],
+                &[],
+            ),
+            claim(
+                Claim::DualValidation,
+                "Dual Validation",
+                &[],
+                &[
+                    "byzantine_consensus::empirical_validation_500",
+                    "byzantine_consensus::statistical_power_500",
+                    "variance_halt::empirical_consistency",
+                ],
+            ),
+            claim(
+                Claim::FormallyVerifiedHalt,
+                "Formally Verified Halt",
+                &[],
+                &[
+                    "variance_halt::variance_halt_correctness",
+                    "byzantine_consensus::halt_safety",
+                    "byzantine_consensus::halt_liveness",
+                ],
+            ),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source_scan::ProofInventory;
+    use crate::{ModuleReport, TheoremResult};
+
+    /// Every built-in module verified, with its real theorem names
+    fn verified_summary() -> VerificationSummary {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut summary = VerificationSummary::new();
+        for (module, _, _) in crate::config::BUILTIN_MODULES {
+            let inventory = ProofInventory::scan_file(&dir.join(format!("{}.rs", module))).unwrap();
+            summary.insert_module(ModuleReport {
+                status: VerificationStatus::Verified,
+                theorems: inventory
+                    .theorems
+                    .into_iter()
+                    .map(|name| TheoremResult {
+                        severity: crate::severity::builtin_severity(module, &name),
+                        name,
+                        status: VerificationStatus::Verified,
+                        duration: None,
+                    })
+                    .collect(),
+                ..ModuleReport::new(module.to_string())
+            });
+        }
+        summary
+    }
+
+    #[test]
+    fn test_default_claims_supported_when_all_verified() {
+        let statuses = ClaimRegistry::default().evaluate(&verified_summary());
+        assert_eq!(statuses.len(), 9);
+        assert!(
+            statuses.values().all(|s| *s == ClaimStatus::Supported),
+            "{:?}",
+            statuses
+        );
+    }
+
+    #[test]
+    fn test_failed_theorem_downgrades_only_dependent_claims() {
+        let mut summary = verified_summary();
+        let module = summary.module_mut("byzantine_consensus").unwrap();
+        module
+            .theorems
+            .iter_mut()
+            .find(|t| t.name == "quorum_intersection")
+            .unwrap()
+            .status = VerificationStatus::Failed;
+
+        let statuses = ClaimRegistry::default().evaluate(&summary);
+        let at_risk: Vec<&Claim> = statuses
+            .iter()
+            .filter(|(_, s)| **s == ClaimStatus::AtRisk)
+            .map(|(claim, _)| claim)
+            .collect();
+        // Claim 16 cites the theorem; Claim 79 requires its whole module
+        assert_eq!(
+            at_risk,
+            [&Claim::ByzantineThreshold, &Claim::DeductiveVerification]
+        );
+        assert_eq!(
+            statuses
+                .values()
+                .filter(|s| **s == ClaimStatus::Supported)
+                .count(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_failed_lemma_puts_dependent_theorems_at_risk() {
+        let mut summary = verified_summary();
+        let module = summary.module_mut("byzantine_consensus").unwrap();
+        module
+            .theorems
+            .iter_mut()
+            .find(|t| t.name == "lemma_count_votes_partition")
+            .unwrap()
+            .status = VerificationStatus::Failed;
+
+        // Claim 16 cites byzantine_safety, which verified but rests on the
+        // failed counting lemma
+        let registry = ClaimRegistry::default();
+        let threshold = &registry.claims()[3];
+        assert_eq!(threshold.claim, Claim::ByzantineThreshold);
+        assert_eq!(threshold.evaluate(&summary), ClaimStatus::Supported);
+        assert_eq!(
+            threshold.evaluate_with(&summary, registry.graph()),
+            ClaimStatus::AtRisk
+        );
+        assert_eq!(
+            registry.evaluate(&summary)[&Claim::ByzantineThreshold],
+            ClaimStatus::AtRisk
+        );
+
+        // Without the dependency the claim only sees its own evidence
+        let registry = registry.with_graph(TheoremGraph::new());
+        assert_eq!(
+            registry.evaluate(&summary)[&Claim::ByzantineThreshold],
+            ClaimStatus::Supported
+        );
+    }
+
+    #[test]
+    fn test_missing_evidence_is_unverified() {
+        let statuses = ClaimRegistry::default().evaluate(&VerificationSummary::new());
+        assert!(statuses.values().all(|s| *s == ClaimStatus::Unverified));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_registry_from_toml() {
+        let registry = ClaimRegistry::from_toml_str(
+            r#"
+[[claim]]
+id = "Claim 12"
+description = "Routing Policy Safety"
+modules = ["routing_policy"]
+
+[[claim]]
+id = "Claim 2"
+description = "N=3 Optimality"
+theorems = ["byzantine_consensus::n_three_sufficiency"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            registry.claims()[0].claim,
+            Claim::Other("Claim 12".to_string())
+        );
+        assert_eq!(registry.claims()[1].claim, Claim::N3Optimality);
+
+        let mut summary = verified_summary();
+        summary.set_status("routing_policy", VerificationStatus::Failed);
+        let statuses = registry.evaluate(&summary);
+        assert_eq!(statuses[&Claim::N3Optimality], ClaimStatus::Supported);
+        assert_eq!(
+            statuses[&Claim::Other("Claim 12".to_string())],
+            ClaimStatus::AtRisk
+        );
+
+        assert!(ClaimRegistry::from_toml_str("[[claim]]\nid = \"Claim 1\"\n").is_err());
+    }
+
+    #[test]
+    fn test_claim_ids_round_trip() {
+        for (claim, id) in Claim::BUILTIN {
+            assert_eq!(claim.to_string(), id);
+            assert_eq!(id.parse::<Claim>().unwrap(), claim);
+        }
+        assert_eq!("Claim 99".parse::<Claim>().unwrap().to_string(), "Claim 99");
+    }
+}
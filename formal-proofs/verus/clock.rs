@@ -0,0 +1,164 @@
+//! # Clock
+//!
+//! Time source for run timestamps, and RFC 3339 formatting without a date
+//! library dependency.
+//!
+//! Summaries take a [`Clock`] so tests can use a [`ManualClock`] and get
+//! deterministic timestamps and durations.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::cell::Cell;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Source of the current time
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+/// The system wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock that only moves when told to
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Cell<SystemTime>,
+}
+
+impl ManualClock {
+    /// Clock reading `secs` seconds after the Unix epoch
+    pub fn at_unix(secs: u64) -> Self {
+        Self {
+            now: Cell::new(UNIX_EPOCH + Duration::from_secs(secs)),
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        self.now.get()
+    }
+}
+
+/// (year, month, day) for a count of days since 1970-01-01 (proleptic
+/// Gregorian calendar)
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (yoe + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/// Days since 1970-01-01 for a civil date (inverse of [`civil_from_days`])
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// UTC RFC 3339 timestamp with millisecond precision
+/// (`2026-10-17T09:30:00.250Z`). Times before the epoch clamp to it.
+pub fn format_rfc3339(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let secs = secs % 86_400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+/// Parse a UTC RFC 3339 timestamp (`Z` suffix, optional fractional seconds)
+pub fn parse_rfc3339(text: &str) -> Option<SystemTime> {
+    let text = text.strip_suffix('Z').or_else(|| text.strip_suffix('z'))?;
+    let (date, time) = text.split_once(['T', 't'])?;
+
+    let mut date = date.splitn(3, '-');
+    let year: i64 = date.next()?.parse().ok()?;
+    let month: u32 = date.next()?.parse().ok()?;
+    let day: u32 = date.next()?.parse().ok()?;
+
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, fraction),
+        None => (time, ""),
+    };
+    let mut time = time.splitn(3, ':');
+    let hour: u64 = time.next()?.parse().ok()?;
+    let minute: u64 = time.next()?.parse().ok()?;
+    let second: u64 = time.next()?.parse().ok()?;
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    if second > 60 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let nanos: u32 = format!("{:0<9}", fraction).get(..9)?.parse().ok()?;
+
+    let days = u64::try_from(days_from_civil(year, month, day)).ok()?;
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second;
+    Some(UNIX_EPOCH + Duration::new(secs, nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc3339_round_trip() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_790_000_000_250);
+        let text = format_rfc3339(time);
+        assert_eq!(text, "2026-09-21T14:13:20.250Z");
+        assert_eq!(parse_rfc3339(&text), Some(time));
+
+        assert_eq!(format_rfc3339(UNIX_EPOCH), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            parse_rfc3339("2024-02-29T23:59:59Z"),
+            Some(UNIX_EPOCH + Duration::from_secs(1_709_251_199))
+        );
+        assert_eq!(parse_rfc3339("2024-02-29 23:59:59"), None);
+        assert_eq!(parse_rfc3339("2024-13-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn test_civil_days_inverse() {
+        for days in [-719_468, -1, 0, 59, 11_016, 20_718, 2_932_896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::at_unix(100);
+        clock.advance(Duration::from_millis(1500));
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_millis(101_500));
+    }
+}
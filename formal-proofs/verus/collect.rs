@@ -0,0 +1,390 @@
+//! # Asynchronous Vote Collection
+//!
+//! [`collect_and_decide`] runs a round over vote futures, such as one
+//! request per model provider. Each voter has its own timeout. A voter
+//! that times out or fails abstains. Collection stops as soon as the votes
+//! in hand settle the round, using [`ConsensusRound::try_decide`]'s early
+//! decision (`early_decision_sound`). An agreement also needs a quorum
+//! already cast, because outstanding voters may abstain.
+//!
+//! The result is decided as [`ConsensusEngine::decide_with_evidence`]
+//! decides ballots. Voters still outstanding at the early decision are
+//! dropped and appear as abstentions in the [`RoundEvidence`]. Whatever
+//! they would have done, the round agrees on the same value or halts
+//! again. [`CollectedRound::status`] tells apart voters that voted, timed
+//! out, failed or were cancelled.
+//!
+//! The future is cancellation-safe. It shares no state with the caller, so
+//! dropping it (in a `select!`, or under an outer timeout) just drops the
+//! vote futures still outstanding.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+//!
+//! [`RoundEvidence`]: crate::round::RoundEvidence
+
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::Duration;
+
+use tokio::time::{timeout, Timeout};
+
+use crate::consensus::{has_quorum, ConsensusEngine, ConsensusOutcome};
+use crate::error::ShieldError;
+use crate::round::{ConsensusRound, RoundDecision, RoundState, VoterId};
+use crate::Thresholds;
+
+/// How a voter's ballot came about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoterStatus {
+    /// Voted within the timeout
+    Voted,
+    /// No vote within the per-voter timeout: an abstention
+    TimedOut,
+    /// The vote future returned an error: an abstention
+    Failed,
+    /// Still outstanding when the round settled: dropped, and an abstention
+    /// in the evidence
+    Cancelled,
+}
+
+/// A decided round with how each voter's ballot came about
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectedRound {
+    pub decision: RoundDecision,
+    /// Status of each voter, by id (the vote future's position)
+    pub status: Vec<VoterStatus>,
+}
+
+impl CollectedRound {
+    /// Voters with `status`, in id order
+    pub fn voters(&self, status: VoterStatus) -> Vec<VoterId> {
+        (0..self.status.len() as VoterId)
+            .filter(|&voter| self.status[voter as usize] == status)
+            .collect()
+    }
+
+    /// Voters that did not vote within the timeout
+    pub fn timed_out(&self) -> Vec<VoterId> {
+        self.voters(VoterStatus::TimedOut)
+    }
+}
+
+/// Whether the votes in hand settle the round, whatever the outstanding
+/// voters do, abstaining included: [`ConsensusRound::try_decide`] has
+/// decided, and an agreement already has a quorum of votes cast
+fn settled(round: &ConsensusRound, engine: &ConsensusEngine) -> bool {
+    match round.try_decide(engine) {
+        RoundState::Pending => false,
+        RoundState::Decided {
+            outcome: ConsensusOutcome::Agreed { .. },
+        } => has_quorum(round.cast(), round.expected_n()),
+        RoundState::Decided { .. } => true,
+    }
+}
+
+/// Collect one vote from each future, voter i being `futures[i]`, and
+/// decide the round at `thresholds`
+///
+/// Each future has `per_voter_timeout` from the call to vote. Timeouts and
+/// errors are abstentions. Collection stops once the votes in hand settle
+/// the round, and the futures still outstanding are dropped. Fails only on
+/// invalid thresholds.
+pub async fn collect_and_decide<F, E>(
+    futures: Vec<F>,
+    per_voter_timeout: Duration,
+    thresholds: &Thresholds,
+) -> Result<CollectedRound, ShieldError>
+where
+    F: Future<Output = Result<bool, E>>,
+{
+    let engine = ConsensusEngine::new(thresholds)?;
+    let n = futures.len();
+    let mut round = ConsensusRound::new(n as u64);
+    let mut status = vec![VoterStatus::Cancelled; n];
+    // Boxed so that a finished voter's slot can be emptied
+    let mut outstanding: Vec<Option<Pin<Box<Timeout<F>>>>> = futures
+        .into_iter()
+        .map(|future| Some(Box::pin(timeout(per_voter_timeout, future))))
+        .collect();
+
+    for _ in 0..n {
+        if settled(&round, &engine) {
+            break;
+        }
+        let (voter, result) = poll_fn(|cx| {
+            for (voter, slot) in outstanding.iter_mut().enumerate() {
+                if let Some(future) = slot {
+                    if let Poll::Ready(result) = future.as_mut().poll(cx) {
+                        *slot = None;
+                        return Poll::Ready((voter, result));
+                    }
+                }
+            }
+            Poll::Pending
+        })
+        .await;
+        status[voter] = match result {
+            Ok(Ok(vote)) => {
+                round
+                    .submit(voter as VoterId, vote)
+                    .expect("each voter resolves once");
+                VoterStatus::Voted
+            }
+            Ok(Err(_)) => VoterStatus::Failed,
+            Err(_) => VoterStatus::TimedOut,
+        };
+    }
+    // Cancel the voters still outstanding
+    drop(outstanding);
+
+    let per_voter = (0..n as VoterId)
+        .map(|voter| (voter, round.vote(voter), 1))
+        .collect();
+    Ok(CollectedRound {
+        decision: engine.decide_with_evidence(per_voter),
+        status,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::round::Ballot;
+    use crate::test_support::Rng;
+    use crate::{HaltReason, Threshold};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::time::{sleep, Instant};
+
+    type Voter = Pin<Box<dyn Future<Output = Result<bool, String>>>>;
+
+    const TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// Counts voter futures dropped before they finished
+    struct Abandoned(Arc<AtomicUsize>, bool);
+
+    impl Drop for Abandoned {
+        fn drop(&mut self) {
+            if !self.1 {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// A voter answering `vote` after `delay` seconds, or never on `None`
+    fn voter(delay: Option<u64>, vote: Result<bool, &str>, abandoned: &Arc<AtomicUsize>) -> Voter {
+        let guard = Abandoned(abandoned.clone(), false);
+        let vote = vote.map_err(str::to_string);
+        Box::pin(async move {
+            // The whole guard, not just the flag, lives in the future
+            let mut guard = guard;
+            match delay {
+                Some(secs) => sleep(Duration::from_secs(secs)).await,
+                None => std::future::pending().await,
+            }
+            guard.1 = true;
+            vote
+        })
+    }
+
+    async fn collect(voters: Vec<Voter>) -> (CollectedRound, Duration) {
+        let start = Instant::now();
+        let collected = collect_and_decide(voters, TIMEOUT, &Thresholds::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            collected.decision.evidence.recheck(),
+            Ok(collected.decision.outcome)
+        );
+        (collected, start.elapsed())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dead_voter_abstains() {
+        let abandoned = Arc::new(AtomicUsize::new(0));
+        // Three providers answer within seconds, two of them agreeing; the
+        // fourth never answers and could still carry true to 3 of 4. Its
+        // timeout makes it an abstention, and 2 of 3 cast agree: two thirds
+        let (collected, elapsed) = collect(vec![
+            voter(Some(1), Ok(true), &abandoned),
+            voter(Some(2), Ok(true), &abandoned),
+            voter(Some(3), Ok(false), &abandoned),
+            voter(None, Ok(true), &abandoned),
+        ])
+        .await;
+        assert_eq!(
+            collected.decision.outcome,
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 666
+            }
+        );
+        assert_eq!(elapsed, TIMEOUT);
+        assert_eq!(collected.timed_out(), vec![3]);
+        assert_eq!(
+            collected.decision.evidence.per_voter,
+            vec![
+                (0, Some(true), 1),
+                (1, Some(true), 1),
+                (2, Some(false), 1),
+                (3, None, 1)
+            ]
+        );
+        assert_eq!(abandoned.load(Ordering::SeqCst), 1);
+
+        // A failing provider abstains without waiting for the timeout
+        let (collected, elapsed) = collect(vec![
+            voter(Some(1), Ok(true), &abandoned),
+            voter(Some(3), Err("rate limited"), &abandoned),
+            voter(Some(2), Ok(false), &abandoned),
+        ])
+        .await;
+        assert_eq!(elapsed, Duration::from_secs(3));
+        assert_eq!(collected.voters(VoterStatus::Failed), vec![1]);
+        assert_eq!(collected.decision.evidence.abstains, 1);
+
+        // Nobody answering halts without a quorum once every timeout fires
+        let (collected, elapsed) = collect(vec![
+            voter(None, Ok(true), &abandoned),
+            voter(Some(60), Ok(true), &abandoned),
+        ])
+        .await;
+        assert_eq!(elapsed, TIMEOUT);
+        assert_eq!(collected.timed_out(), vec![0, 1]);
+        assert_eq!(
+            collected.decision.outcome,
+            ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: 0 }
+            }
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_short_circuits_on_early_decision() {
+        let abandoned = Arc::new(AtomicUsize::new(0));
+        // Four of five agreeing reach two thirds of all five: the slow
+        // voter cannot change the value, so it is cancelled at 4s
+        let (collected, elapsed) = collect(vec![
+            voter(Some(1), Ok(true), &abandoned),
+            voter(Some(20), Ok(false), &abandoned),
+            voter(Some(2), Ok(true), &abandoned),
+            voter(Some(3), Ok(true), &abandoned),
+            voter(Some(4), Ok(true), &abandoned),
+        ])
+        .await;
+        assert_eq!(elapsed, Duration::from_secs(4));
+        assert_eq!(collected.voters(VoterStatus::Cancelled), vec![1]);
+        assert_eq!(
+            collected.decision.outcome,
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 1000
+            }
+        );
+        assert_eq!(abandoned.load(Ordering::SeqCst), 1);
+
+        // Two votes each way among five halts at once: the dead voter would
+        // bring either side to 3 of 5 at most, so it is never waited for
+        let (collected, elapsed) = collect(vec![
+            voter(Some(1), Ok(true), &abandoned),
+            voter(None, Ok(true), &abandoned),
+            voter(Some(2), Ok(false), &abandoned),
+            voter(Some(3), Ok(true), &abandoned),
+            voter(Some(4), Ok(false), &abandoned),
+        ])
+        .await;
+        assert_eq!(elapsed, Duration::from_secs(4));
+        assert_eq!(collected.voters(VoterStatus::Cancelled), vec![1]);
+        assert_eq!(
+            collected.decision.outcome,
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 500 }
+            }
+        );
+
+        // A round without voters decides at once
+        let (collected, elapsed) = collect(vec![]).await;
+        assert_eq!(elapsed, Duration::ZERO);
+        assert!(collected.status.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cancellation_drops_voters() {
+        let abandoned = Arc::new(AtomicUsize::new(0));
+        let voters = vec![
+            voter(Some(1), Ok(true), &abandoned),
+            voter(Some(10), Ok(true), &abandoned),
+            voter(None, Ok(true), &abandoned),
+        ];
+        let outer = tokio::time::timeout(
+            Duration::from_secs(5),
+            collect_and_decide(voters, TIMEOUT, &Thresholds::default()),
+        )
+        .await;
+        assert!(outer.is_err());
+        // The two voters still outstanding were dropped with the round
+        assert_eq!(abandoned.load(Ordering::SeqCst), 2);
+
+        let invalid = Thresholds {
+            consensus: Threshold::PerMille(400),
+            ..Thresholds::default()
+        };
+        let voters: Vec<Voter> = vec![voter(Some(1), Ok(true), &abandoned)];
+        assert!(collect_and_decide(voters, TIMEOUT, &invalid).await.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_short_circuit_matches_full_collection() {
+        // Stopping early agrees on the value collecting every vote would
+        // agree on, or halts as it would, with timeouts as abstentions
+        let mut rng = Rng(0x5eed_1596);
+        let abandoned = Arc::new(AtomicUsize::new(0));
+        for _ in 0..300 {
+            let thresholds = Thresholds {
+                consensus: Threshold::PerMille(501 + rng.below(500)),
+                ..Thresholds::default()
+            };
+            let engine = ConsensusEngine::new(&thresholds).unwrap();
+            let n = rng.below(8);
+            let voters: Vec<(Option<u64>, bool)> = (0..n)
+                .map(|_| {
+                    // Odd seconds: never on the 30s timeout itself
+                    let delay = match rng.below(5) {
+                        0 => None,
+                        _ => Some(1 + 2 * rng.below(30)),
+                    };
+                    (delay, rng.below(3) > 0)
+                })
+                .collect();
+            let full: Vec<Ballot> = voters
+                .iter()
+                .map(|&(delay, vote)| match delay {
+                    Some(secs) if Duration::from_secs(secs) < TIMEOUT => Some(vote),
+                    _ => None,
+                })
+                .collect();
+            let full = engine.decide_ballots(&full);
+
+            let futures: Vec<Voter> = voters
+                .iter()
+                .map(|&(delay, vote)| voter(delay, Ok(vote), &abandoned))
+                .collect();
+            let collected = collect_and_decide(futures, TIMEOUT, &thresholds)
+                .await
+                .unwrap();
+            let early = collected.decision.outcome;
+            assert_eq!(collected.decision.evidence.recheck(), Ok(early));
+            match (early, full) {
+                (
+                    ConsensusOutcome::Agreed { value, .. },
+                    ConsensusOutcome::Agreed {
+                        value: full_value, ..
+                    },
+                ) => assert_eq!(value, full_value),
+                (ConsensusOutcome::Halted { .. }, ConsensusOutcome::Halted { .. }) => {}
+                (early, full) => panic!("{:?}: decided {:?}, then {:?}", voters, early, full),
+            }
+        }
+    }
+}
@@ -0,0 +1,466 @@
+//! # Commit-Reveal Voting
+//!
+//! Executable counterpart of the commit-reveal section of
+//! `ed25519_contracts.rs`. A voter that sees another's answer before
+//! voting can copy it, and copied answers look like agreement. A
+//! [`CommitRevealRound`] takes votes in two phases: each voter first
+//! publishes only [`commitment`], the SHA-256 of its answer and a secret
+//! salt, and once the commit phase is over reveals the answer and salt.
+//! Every commit is in before any answer is visible, and a commit opens to
+//! one answer only (`commit_binding`), so a voter cannot change its answer
+//! after seeing the others'.
+//!
+//! The commitment also binds the round's [`Nonce`] and the voter id. A
+//! voter that republishes another's commitment, in this round or from an
+//! earlier one, cannot open it and abstains (`copied_commit_abstains`).
+//!
+//! Only reveals that open the voter's commit are counted. A mismatched
+//! reveal, a missing one and one arriving after the round closed are
+//! abstentions (`revealed_ballot`), decided with the rest by
+//! [`ConsensusEngine::decide_with_evidence`].
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::chain::Hash;
+use crate::consensus::ConsensusEngine;
+use crate::round::{Ballot, RoundDecision, VoterId};
+
+/// Length of a commitment salt in bytes (`commit_salt_len`)
+pub const SALT_LEN: usize = 32;
+
+/// Secret salt a voter commits with; it must be unpredictable, or the
+/// commitment of a yes/no answer can be brute-forced
+pub type Salt = [u8; SALT_LEN];
+
+/// Length of a round nonce in bytes (`commit_nonce_len`)
+pub const NONCE_LEN: usize = 32;
+
+/// Per-round nonce every commitment of the round binds; it must not repeat
+/// across rounds, or a commitment can be replayed in a later one
+pub type Nonce = [u8; NONCE_LEN];
+
+/// Commitment of `voter` to `vote` under `salt` in the round of `nonce`
+/// (`commitment`): SHA-256 of the nonce, the voter id (8 bytes,
+/// little-endian), the answer byte (1 for agree, 0 for disagree) and the
+/// salt
+pub fn commitment(nonce: &Nonce, voter: VoterId, vote: bool, salt: &Salt) -> Hash {
+    let mut preimage = Vec::with_capacity(NONCE_LEN + 8 + 1 + SALT_LEN);
+    preimage.extend_from_slice(nonce);
+    preimage.extend_from_slice(&voter.to_le_bytes());
+    preimage.push(u8::from(vote));
+    preimage.extend_from_slice(salt);
+    Hash::sha256(&preimage)
+}
+
+/// Phase of a commit-reveal round
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Collecting commitments
+    Commit,
+    /// Collecting reveals; no more commitments
+    Reveal,
+    /// Decided; later reveals are abstentions
+    Closed,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Phase::Commit => "commit",
+            Phase::Reveal => "reveal",
+            Phase::Closed => "closed",
+        })
+    }
+}
+
+/// A commitment or reveal the round cannot accept
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitRevealError {
+    /// A voter id outside `0..expected_n`
+    UnknownVoter { voter: VoterId, expected_n: u64 },
+    /// Submitted outside the phase that takes it
+    WrongPhase { expected: Phase, actual: Phase },
+    /// The voter has already committed
+    DuplicateCommit { voter: VoterId },
+    /// A reveal from a voter that never committed
+    NoCommit { voter: VoterId },
+    /// The voter has already revealed
+    DuplicateReveal { voter: VoterId },
+    /// The reveal does not open the voter's commitment; the voter abstains
+    RevealMismatch { voter: VoterId },
+}
+
+impl fmt::Display for CommitRevealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommitRevealError::UnknownVoter { voter, expected_n } => write!(
+                f,
+                "voter {} is not one of the {} expected voters",
+                voter, expected_n
+            ),
+            CommitRevealError::WrongPhase { expected, actual } => write!(
+                f,
+                "round is in the {} phase, not the {} phase",
+                actual, expected
+            ),
+            CommitRevealError::DuplicateCommit { voter } => {
+                write!(f, "voter {} has already committed", voter)
+            }
+            CommitRevealError::NoCommit { voter } => {
+                write!(f, "voter {} revealed without committing", voter)
+            }
+            CommitRevealError::DuplicateReveal { voter } => {
+                write!(f, "voter {} has already revealed", voter)
+            }
+            CommitRevealError::RevealMismatch { voter } => {
+                write!(f, "reveal of voter {} does not match its commitment", voter)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommitRevealError {}
+
+/// A two-phase round: commitments first, then the reveals opening them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitRevealRound {
+    expected_n: u64,
+    nonce: Nonce,
+    phase: Phase,
+    commits: BTreeMap<VoterId, Hash>,
+    /// The revealed vote, or `None` for a reveal that did not match
+    reveals: BTreeMap<VoterId, Option<bool>>,
+}
+
+impl CommitRevealRound {
+    /// Round of `expected_n` voters with ids `0..expected_n` under `nonce`,
+    /// taking commitments
+    pub fn new(expected_n: u64, nonce: Nonce) -> Self {
+        Self {
+            expected_n,
+            nonce,
+            phase: Phase::Commit,
+            commits: BTreeMap::new(),
+            reveals: BTreeMap::new(),
+        }
+    }
+
+    /// Number of voters the round waits for
+    pub fn expected_n(&self) -> u64 {
+        self.expected_n
+    }
+
+    /// Nonce the round's commitments bind
+    pub fn nonce(&self) -> &Nonce {
+        &self.nonce
+    }
+
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// The commitment `voter` published, if any
+    pub fn commit_of(&self, voter: VoterId) -> Option<Hash> {
+        self.commits.get(&voter).copied()
+    }
+
+    fn expect_phase(&self, expected: Phase) -> Result<(), CommitRevealError> {
+        if self.phase != expected {
+            return Err(CommitRevealError::WrongPhase {
+                expected,
+                actual: self.phase,
+            });
+        }
+        Ok(())
+    }
+
+    /// Record `voter`'s commitment
+    pub fn commit(&mut self, voter: VoterId, commit: Hash) -> Result<(), CommitRevealError> {
+        self.expect_phase(Phase::Commit)?;
+        if voter >= self.expected_n {
+            return Err(CommitRevealError::UnknownVoter {
+                voter,
+                expected_n: self.expected_n,
+            });
+        }
+        if self.commits.contains_key(&voter) {
+            return Err(CommitRevealError::DuplicateCommit { voter });
+        }
+        self.commits.insert(voter, commit);
+        Ok(())
+    }
+
+    /// End the commit phase; voters that have not committed abstain
+    pub fn open_reveals(&mut self) -> Result<(), CommitRevealError> {
+        self.expect_phase(Phase::Commit)?;
+        self.phase = Phase::Reveal;
+        Ok(())
+    }
+
+    /// Record `voter`'s reveal. One reveal per voter: a reveal that does
+    /// not open the commitment is rejected and the voter abstains.
+    pub fn reveal(
+        &mut self,
+        voter: VoterId,
+        vote: bool,
+        salt: &Salt,
+    ) -> Result<(), CommitRevealError> {
+        self.expect_phase(Phase::Reveal)?;
+        let commit = self
+            .commits
+            .get(&voter)
+            .ok_or(CommitRevealError::NoCommit { voter })?;
+        if self.reveals.contains_key(&voter) {
+            return Err(CommitRevealError::DuplicateReveal { voter });
+        }
+        if commitment(&self.nonce, voter, vote, salt) != *commit {
+            self.reveals.insert(voter, None);
+            return Err(CommitRevealError::RevealMismatch { voter });
+        }
+        self.reveals.insert(voter, Some(vote));
+        Ok(())
+    }
+
+    /// Each voter's ballot: its vote if revealed and matching, an
+    /// abstention otherwise (`revealed_ballot`)
+    pub fn ballots(&self) -> Vec<(VoterId, Ballot)> {
+        (0..self.expected_n)
+            .map(|voter| (voter, self.reveals.get(&voter).copied().flatten()))
+            .collect()
+    }
+
+    /// Close the round and decide its ballots with unit weights; reveals
+    /// after this are rejected and their voters abstain
+    pub fn close(&mut self, engine: &ConsensusEngine) -> RoundDecision {
+        self.phase = Phase::Closed;
+        engine.decide_with_evidence(
+            self.ballots()
+                .into_iter()
+                .map(|(voter, ballot)| (voter, ballot, 1))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::ConsensusOutcome;
+    use crate::test_support::Rng;
+    use crate::HaltReason;
+
+    fn random_salt(rng: &mut Rng) -> Salt {
+        let mut salt = [0; SALT_LEN];
+        for chunk in salt.chunks_mut(8) {
+            chunk.copy_from_slice(&rng.next().to_le_bytes());
+        }
+        salt
+    }
+
+    const NONCE: Nonce = [0x5e; NONCE_LEN];
+
+    /// Round where voter i commits to `votes[i]` under `salts[i]`, in the
+    /// reveal phase
+    fn committed(votes: &[bool], salts: &[Salt]) -> CommitRevealRound {
+        let mut round = CommitRevealRound::new(votes.len() as u64, NONCE);
+        for (voter, (&vote, salt)) in votes.iter().zip(salts).enumerate() {
+            let voter = voter as VoterId;
+            round
+                .commit(voter, commitment(&NONCE, voter, vote, salt))
+                .unwrap();
+        }
+        round.open_reveals().unwrap();
+        round
+    }
+
+    #[test]
+    fn test_commitment_binds_round_voter_vote_and_salt() {
+        let mut rng = Rng(0x5EED_C0FF_EE00_0001);
+        let salt = random_salt(&mut rng);
+        let other = random_salt(&mut rng);
+        let base = commitment(&NONCE, 0, true, &salt);
+        assert_eq!(base, commitment(&NONCE, 0, true, &salt));
+        assert_ne!(base, commitment(&NONCE, 0, false, &salt));
+        assert_ne!(base, commitment(&NONCE, 0, true, &other));
+        assert_ne!(base, commitment(&NONCE, 1, true, &salt));
+        assert_ne!(base, commitment(&[0; NONCE_LEN], 0, true, &salt));
+    }
+
+    #[test]
+    fn test_copied_commitment_abstains() {
+        let mut rng = Rng(0x5EED_C0FF_EE00_0006);
+        let engine = ConsensusEngine::default();
+        let salt = random_salt(&mut rng);
+        let mut round = CommitRevealRound::new(3, NONCE);
+        round
+            .commit(0, commitment(&NONCE, 0, false, &salt))
+            .unwrap();
+        // Voters 1 and 2 republish voter 0's commitment
+        let copied = round.commit_of(0).unwrap();
+        round.commit(1, copied).unwrap();
+        round.commit(2, copied).unwrap();
+        round.open_reveals().unwrap();
+
+        // ... and replay its reveal once it is public
+        round.reveal(0, false, &salt).unwrap();
+        for voter in [1, 2] {
+            assert_eq!(
+                round.reveal(voter, false, &salt),
+                Err(CommitRevealError::RevealMismatch { voter })
+            );
+        }
+        assert_eq!(
+            round.ballots(),
+            vec![(0, Some(false)), (1, None), (2, None)]
+        );
+        assert_eq!(
+            round.close(&engine).outcome,
+            engine.decide_ballots(&[Some(false), None, None])
+        );
+
+        // A commitment from an earlier round does not open in a later one
+        let mut later = CommitRevealRound::new(1, [0xa5; NONCE_LEN]);
+        later.commit(0, copied).unwrap();
+        later.open_reveals().unwrap();
+        assert_eq!(
+            later.reveal(0, false, &salt),
+            Err(CommitRevealError::RevealMismatch { voter: 0 })
+        );
+    }
+
+    #[test]
+    fn test_honest_flow() {
+        let mut rng = Rng(0x5EED_C0FF_EE00_0002);
+        let engine = ConsensusEngine::default();
+        let votes = [true, true, true, false];
+        let salts: Vec<Salt> = votes.iter().map(|_| random_salt(&mut rng)).collect();
+        let mut round = committed(&votes, &salts);
+        for (voter, (&vote, salt)) in votes.iter().zip(&salts).enumerate() {
+            round.reveal(voter as VoterId, vote, salt).unwrap();
+        }
+
+        let decision = round.close(&engine);
+        assert_eq!(
+            decision.outcome,
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 750
+            }
+        );
+        assert_eq!(decision.outcome, engine.decide(&votes));
+        assert_eq!(
+            (decision.evidence.agrees, decision.evidence.abstains),
+            (3, 0)
+        );
+        assert_eq!(round.phase(), Phase::Closed);
+    }
+
+    #[test]
+    fn test_mismatched_reveal_abstains() {
+        let mut rng = Rng(0x5EED_C0FF_EE00_0003);
+        let engine = ConsensusEngine::default();
+        let votes = [true, true, false];
+        let salts: Vec<Salt> = votes.iter().map(|_| random_salt(&mut rng)).collect();
+        let mut round = committed(&votes, &salts);
+        round.reveal(0, true, &salts[0]).unwrap();
+        round.reveal(1, true, &salts[1]).unwrap();
+        // Voter 2 committed to false, then switches after seeing the others
+        let err = round.reveal(2, true, &salts[2]).unwrap_err();
+        assert_eq!(err, CommitRevealError::RevealMismatch { voter: 2 });
+        assert_eq!(
+            err.to_string(),
+            "reveal of voter 2 does not match its commitment"
+        );
+        // The mismatch is final, even for the committed answer
+        assert_eq!(
+            round.reveal(2, false, &salts[2]),
+            Err(CommitRevealError::DuplicateReveal { voter: 2 })
+        );
+
+        assert_eq!(
+            round.ballots(),
+            vec![(0, Some(true)), (1, Some(true)), (2, None)]
+        );
+        let decision = round.close(&engine);
+        assert_eq!(
+            decision.outcome,
+            engine.decide_ballots(&[Some(true), Some(true), None])
+        );
+        assert_eq!(decision.evidence.abstains, 1);
+    }
+
+    #[test]
+    fn test_missing_and_late_reveals_abstain() {
+        let mut rng = Rng(0x5EED_C0FF_EE00_0004);
+        let engine = ConsensusEngine::default();
+        let votes = [true, true, true];
+        let salts: Vec<Salt> = votes.iter().map(|_| random_salt(&mut rng)).collect();
+        let mut round = committed(&votes, &salts);
+        round.reveal(0, true, &salts[0]).unwrap();
+
+        // Voter 1 never reveals, voter 2 reveals after the close
+        let decision = round.close(&engine);
+        assert_eq!(
+            round.reveal(2, true, &salts[2]),
+            Err(CommitRevealError::WrongPhase {
+                expected: Phase::Reveal,
+                actual: Phase::Closed
+            })
+        );
+        assert_eq!(decision.evidence.abstains, 2);
+        assert_eq!(
+            decision.outcome,
+            ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: 1 }
+            }
+        );
+        assert_eq!(
+            decision.outcome,
+            engine.decide_ballots(&[Some(true), None, None])
+        );
+    }
+
+    #[test]
+    fn test_phases_and_voters_validated() {
+        let mut rng = Rng(0x5EED_C0FF_EE00_0005);
+        let salt = random_salt(&mut rng);
+        let mut round = CommitRevealRound::new(2, NONCE);
+        assert_eq!(round.nonce(), &NONCE);
+        assert_eq!(
+            round.reveal(0, true, &salt),
+            Err(CommitRevealError::WrongPhase {
+                expected: Phase::Reveal,
+                actual: Phase::Commit
+            })
+        );
+        assert_eq!(
+            round.commit(2, commitment(&NONCE, 2, true, &salt)),
+            Err(CommitRevealError::UnknownVoter {
+                voter: 2,
+                expected_n: 2
+            })
+        );
+        round.commit(0, commitment(&NONCE, 0, true, &salt)).unwrap();
+        assert_eq!(
+            round.commit(0, commitment(&NONCE, 0, false, &salt)),
+            Err(CommitRevealError::DuplicateCommit { voter: 0 })
+        );
+        assert_eq!(round.commit_of(0), Some(commitment(&NONCE, 0, true, &salt)));
+
+        round.open_reveals().unwrap();
+        let err = round
+            .commit(1, commitment(&NONCE, 1, true, &salt))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "round is in the reveal phase, not the commit phase"
+        );
+        assert_eq!(
+            round.reveal(1, true, &salt),
+            Err(CommitRevealError::NoCommit { voter: 1 })
+        );
+    }
+}
@@ -0,0 +1,467 @@
+//! # Runner Configuration
+//!
+//! Declares the proof targets the runner verifies.
+//!
+//! The four built-in modules are provided as defaults; downstream workspaces
+//! can add their own targets from a TOML file:
+//!
+//! ```toml
+//! include_defaults = true
+//!
+//! [[target]]
+//! name = "routing_policy"
+//! path = "../policies/routing_policy.rs"
+//! backend = "verus"
+//! claims = ["Claim 16"]
+//! ```
+//!
+//! Relative target paths are resolved against the config file's directory.
+//! An optional `[thresholds]` table overrides the proven [`Thresholds`]
+//! defaults field by field, and a `[models]` table registers models beside
+//! the built-in ones (see [`crate::models`]).
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+use crate::consensus::ConsensusEngine;
+use crate::error::ShieldError;
+use crate::models::{ModelRegistry, ModelSpec};
+use crate::{
+    Thresholds, MODULE_BYZANTINE_CONSENSUS, MODULE_ED25519_CONTRACTS, MODULE_TRUST_BOUNDS,
+    MODULE_VARIANCE_HALT,
+};
+
+/// Built-in proof modules: (name, description, claims)
+pub const BUILTIN_MODULES: [(&str, &str, &[&str]); 4] = [
+    (
+        MODULE_VARIANCE_HALT,
+        "Variance-based Byzantine detection",
+        &["Claim 3", "Claim 82"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "Trust score preservation",
+        &["Claim 2"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "Core BFT theorems",
+        &["Claim 2", "Claim 16", "Claim 17"],
+    ),
+    (
+        MODULE_ED25519_CONTRACTS,
+        "Cryptographic contracts",
+        &["Claim 4", "Claim 80"],
+    ),
+];
+
+/// Prover backend for a target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Backend {
+    /// `verus <path>`
+    Verus,
+    /// `cargo prusti` in the target's crate directory
+    Prusti,
+}
+
+/// A single proof target
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+pub struct ProofTarget {
+    /// Module name used in reports
+    pub name: String,
+    /// Proof source file (or crate directory for Prusti)
+    pub path: PathBuf,
+    #[cfg_attr(feature = "serde", serde(default = "default_backend"))]
+    pub backend: Backend,
+    /// Patent claims this target provides evidence for
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub claims: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub description: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+fn default_backend() -> Backend {
+    Backend::Verus
+}
+
+/// Runner configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Targets in verification order
+    pub targets: Vec<ProofTarget>,
+    /// Deployment thresholds (validated)
+    pub thresholds: Thresholds,
+    /// Models weighted consensus weighs votes by (weights validated)
+    pub models: ModelRegistry,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default = "default_true")]
+    include_defaults: bool,
+    #[serde(default, rename = "target")]
+    targets: Vec<ProofTarget>,
+    #[serde(default)]
+    thresholds: Thresholds,
+    #[serde(default)]
+    models: BTreeMap<String, ModelSpec>,
+}
+
+#[cfg(feature = "serde")]
+fn default_true() -> bool {
+    true
+}
+
+impl Config {
+    /// The four built-in modules located in `proof_dir`
+    pub fn builtin(proof_dir: &Path) -> Self {
+        let targets = BUILTIN_MODULES
+            .iter()
+            .map(|(name, description, claims)| ProofTarget {
+                name: name.to_string(),
+                path: proof_dir.join(format!("{}.rs", name)),
+                backend: Backend::Verus,
+                claims: claims.iter().map(|c| c.to_string()).collect(),
+                description: Some(description.to_string()),
+            })
+            .collect();
+        Self {
+            targets,
+            thresholds: Thresholds::default(),
+            models: ModelRegistry::builtin(),
+        }
+    }
+
+    /// Parse a TOML config. Relative target paths are resolved against `base_dir`
+    /// and built-in targets against `proof_dir`.
+    #[cfg(feature = "serde")]
+    pub fn from_toml_str(
+        text: &str,
+        base_dir: &Path,
+        proof_dir: &Path,
+    ) -> Result<Self, ShieldError> {
+        let file: ConfigFile = toml::from_str(text)?;
+        file.thresholds.validate()?;
+        let mut config = if file.include_defaults {
+            Self::builtin(proof_dir)
+        } else {
+            Self {
+                targets: Vec::new(),
+                thresholds: Thresholds::default(),
+                models: ModelRegistry::builtin(),
+            }
+        };
+        config.thresholds = file.thresholds;
+        for (name, spec) in file.models {
+            config.models.insert(name, spec)?;
+        }
+        for mut target in file.targets {
+            if target.path.is_relative() {
+                target.path = base_dir.join(&target.path);
+            }
+            config.add_target(target);
+        }
+        Ok(config)
+    }
+
+    /// Consensus engine at the config's thresholds, weighing votes by its
+    /// models
+    pub fn engine(&self) -> Result<ConsensusEngine, ShieldError> {
+        Ok(ConsensusEngine::new(&self.thresholds)?.with_models(self.models.clone()))
+    }
+
+    /// Load a TOML config file
+    #[cfg(feature = "serde")]
+    pub fn load(path: &Path, proof_dir: &Path) -> Result<Self, ShieldError> {
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        fs::read_to_string(path)
+            .map_err(ShieldError::from)
+            .and_then(|text| Self::from_toml_str(&text, base_dir, proof_dir))
+            .map_err(|e| ShieldError::config_invalid(format!("config {}", path.display()), e))
+    }
+
+    /// Add a target, replacing any existing target with the same name
+    pub fn add_target(&mut self, target: ProofTarget) {
+        match self.targets.iter_mut().find(|t| t.name == target.name) {
+            Some(existing) => *existing = target,
+            None => self.targets.push(target),
+        }
+    }
+
+    /// Target names in verification order
+    pub fn module_names(&self) -> Vec<&str> {
+        self.targets.iter().map(|t| t.name.as_str()).collect()
+    }
+}
+
+/// Find all `.rs` files under `dir` containing a `verus!` block.
+///
+/// A proof file is recognized by a top-level `verus!` invocation together with
+/// a `vstd` import, so Rust sources that merely mention the macro are skipped.
+/// Targets are named after the file stem and returned sorted by path.
+pub fn discover(dir: &Path) -> Result<Vec<ProofTarget>, ShieldError> {
+    let mut files = Vec::new();
+    collect_rust_files(dir, &mut files)?;
+    files.sort();
+
+    let mut targets = Vec::new();
+    for path in files {
+        let source = fs::read_to_string(&path)?;
+        if !is_proof_source(&source) {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        targets.push(ProofTarget {
+            name: name.to_string(),
+            path: path.clone(),
+            backend: Backend::Verus,
+            claims: Vec::new(),
+            description: None,
+        });
+    }
+    Ok(targets)
+}
+
+fn is_proof_source(source: &str) -> bool {
+    let mut has_macro = false;
+    let mut has_vstd = false;
+    for line in source.lines() {
+        has_macro |= line.starts_with("verus!");
+        has_vstd |= line.starts_with("use vstd::");
+    }
+    has_macro && has_vstd
+}
+
+fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            // Skip build output and VCS metadata
+            let skip = path
+                .file_name()
+                .is_some_and(|n| n == "target" || n.to_string_lossy().starts_with('.'));
+            if !skip {
+                collect_rust_files(&path, files)?;
+            }
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diversity::ModelFamily;
+    use crate::{
+        ModuleReport, Severity, TheoremResult, Threshold, VerificationStatus, VerificationSummary,
+    };
+
+    const FIFTH_MODULE: &str = r#"
+[[target]]
+name = "routing_policy"
+path = "policies/routing_policy.rs"
+claims = ["Claim 16"]
+"#;
+
+    #[test]
+    fn test_builtin_targets() {
+        let config = Config::builtin(Path::new("src"));
+        assert_eq!(
+            config.module_names(),
+            [
+                "variance_halt",
+                "trust_bounds",
+                "byzantine_consensus",
+                "ed25519_contracts"
+            ]
+        );
+        assert_eq!(config.targets[0].path, Path::new("src/variance_halt.rs"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_fifth_custom_module() {
+        let config =
+            Config::from_toml_str(FIFTH_MODULE, Path::new("/work"), Path::new("src")).unwrap();
+        assert_eq!(config.targets.len(), 5);
+
+        let custom = &config.targets[4];
+        assert_eq!(custom.name, "routing_policy");
+        assert_eq!(custom.path, Path::new("/work/policies/routing_policy.rs"));
+        assert_eq!(custom.backend, Backend::Verus);
+        assert_eq!(custom.claims, vec!["Claim 16"]);
+
+        // The fifth module participates in the summary roll-up
+        let mut summary = VerificationSummary::with_modules(config.module_names());
+        for name in config.module_names() {
+            summary.insert_module(ModuleReport {
+                status: VerificationStatus::Verified,
+                theorems: vec![TheoremResult {
+                    name: "lemma".to_string(),
+                    status: VerificationStatus::Verified,
+                    duration: None,
+                    severity: Severity::Standard,
+                }],
+                ..ModuleReport::new(name)
+            });
+        }
+        summary.unit_tests = VerificationStatus::Verified;
+        assert_eq!(summary.overall(), VerificationStatus::Verified);
+
+        summary.set_status("routing_policy", VerificationStatus::Failed);
+        assert_eq!(summary.overall(), VerificationStatus::Failed);
+        assert_eq!(summary.variance_halt(), VerificationStatus::Verified);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_exclude_defaults_and_prusti_backend() {
+        let text = r#"
+include_defaults = false
+
+[[target]]
+name = "contracts"
+path = "/abs/contracts"
+backend = "prusti"
+"#;
+        let config = Config::from_toml_str(text, Path::new("/work"), Path::new("src")).unwrap();
+        assert_eq!(config.module_names(), ["contracts"]);
+        assert_eq!(config.targets[0].backend, Backend::Prusti);
+        assert_eq!(config.targets[0].path, Path::new("/abs/contracts"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_override_builtin_target() {
+        let text = r#"
+[[target]]
+name = "trust_bounds"
+path = "vendored/trust_bounds.rs"
+"#;
+        let config = Config::from_toml_str(text, Path::new("/work"), Path::new("src")).unwrap();
+        assert_eq!(config.targets.len(), 4);
+        assert_eq!(
+            config.targets[1].path,
+            Path::new("/work/vendored/trust_bounds.rs")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_invalid_backend_rejected() {
+        let text = r#"
+[[target]]
+name = "x"
+path = "x.rs"
+backend = "coq"
+"#;
+        assert!(Config::from_toml_str(text, Path::new("."), Path::new("src")).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_thresholds_override() {
+        let config = Config::from_toml_str(
+            "[thresholds]\nconsensus = { per_mille = 700 }\n",
+            Path::new("."),
+            Path::new("src"),
+        )
+        .unwrap();
+        assert_eq!(config.thresholds.consensus, Threshold::PerMille(700));
+        assert_eq!(config.thresholds.decay_rate, 100);
+        assert_eq!(
+            config.thresholds.divergences(),
+            [("consensus", "700/1000".to_string(), "2/3".to_string())]
+        );
+        assert!(Config::builtin(Path::new("src"))
+            .thresholds
+            .matches_proven_defaults());
+
+        let err = Config::from_toml_str(
+            "[thresholds]\nconsensus = { per_mille = 400 }\n",
+            Path::new("."),
+            Path::new("src"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid thresholds: consensus = 400/1000 is outside 501/1000..=1000/1000"
+        );
+        let unknown = "[thresholds]\nquorum = 2\n";
+        assert!(Config::from_toml_str(unknown, Path::new("."), Path::new("src")).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_models_registered() {
+        let text = r#"
+[models.claude-sonnet]
+id = 5
+weight_scaled = 160
+family = "claude"
+
+[models.gpt-4o]
+id = 2
+weight_scaled = 140
+family = "gpt4"
+"#;
+        let config = Config::from_toml_str(text, Path::new("."), Path::new("src")).unwrap();
+        assert_eq!(config.models.len(), 6);
+        assert_eq!(config.models.weight(5), 160);
+        assert_eq!(config.models.family(5), ModelFamily::Claude);
+        // Same name: the configured weight replaces the built-in one
+        assert_eq!(config.models.weight(2), 140);
+        assert_eq!(config.models.weight(0), 180);
+        let engine = config.engine().unwrap();
+        assert_eq!(engine.models(), &config.models);
+        assert_eq!(engine.threshold(), config.thresholds.consensus);
+        assert_eq!(
+            Config::builtin(Path::new("src")).models,
+            ModelRegistry::builtin()
+        );
+
+        let heavy = "[models.llama-3-70b]\nid = 6\nweight_scaled = 250\nfamily = \"llama\"\n";
+        let err = Config::from_toml_str(heavy, Path::new("."), Path::new("src")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "models: llama-3-70b.weight_scaled = 250 is outside 100..=200"
+        );
+        let clash = "[models.llama-3-70b]\nid = 0\nweight_scaled = 120\nfamily = \"llama\"\n";
+        assert!(Config::from_toml_str(clash, Path::new("."), Path::new("src")).is_err());
+        let unknown =
+            "[models.x]\nid = 7\nweight_scaled = 120\nfamily = \"llama\"\nlab = \"meta\"\n";
+        assert!(Config::from_toml_str(unknown, Path::new("."), Path::new("src")).is_err());
+    }
+
+    #[test]
+    fn test_discover_proof_files() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let names: Vec<String> = discover(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        for builtin in BUILTIN_MODULES.iter().map(|(name, _, _)| name) {
+            assert!(names.iter().any(|n| n == builtin), "missing {}", builtin);
+        }
+        // lib.rs mentions verus! only in comments, source_scan.rs in a test string
+        assert!(!names.iter().any(|n| n == "lib" || n == "source_scan"));
+    }
+}
@@ -0,0 +1,2217 @@
+//! # Consensus Decision
+//!
+//! Executable counterpart of `decide_consensus` in `byzantine_consensus.rs`.
+//! A [`ConsensusEngine`] agrees on the majority vote when its share of the
+//! votes reaches the configured [`Threshold`] ([`Thresholds::consensus`])
+//! and halts otherwise. The threshold test is exact ([`meets_threshold`]):
+//! the default, [`Threshold::TwoThirds`], is 2/3 itself. Reported shares
+//! are scaled by 1000 and rounded down, as in `agreement_ratio_scaled`. Every halt carries a [`HaltReason`].
+//!
+//! [`ConsensusEngine::decide_ballots`] accepts abstentions (`None`, e.g. a
+//! model that timed out): they count toward a two-thirds quorum
+//! ([`has_quorum`]) but not toward the agreement ratio.
+//!
+//! [`ConsensusEngine::decide_weighted`] weighs each vote by the voter's
+//! trust and model weight (`decide_consensus_weighted` in `trust_bounds.rs`).
+//!
+//! [`fault_budget`] tells an operator how many more agents may fail before
+//! full BFT lapses, given the ones already suspected, and what a round still
+//! guarantees ([`GuaranteeLevel`]).
+//!
+//! [`ConsensusEngine::final_verdict`] is the decision a round ends in: the
+//! votes' outcome, halted by the constitutional halt when the outputs vary
+//! too much (`final_verdict` in `byzantine_consensus.rs`).
+//!
+//! After a round, [`identify_suspects`] names the agents whose answers
+//! stray from the consensus value (`accused`), and [`suspect_decays`] turns
+//! them into [`TrustDecay`] events. With fewer than n/3 Byzantine agents,
+//! no honest agent is accused (`no_false_accusation`).
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::ShieldError;
+use crate::models::ModelRegistry;
+use crate::round::{Ballot, RoundDecision, RoundEvidence, VoterId};
+use crate::trust::{trust_decay_floored, vote_weight, AgentTrust, TrustError, TrustScore};
+use crate::variance::OnlineVariance;
+use crate::{HaltReason, Thresholds};
+
+pub(crate) use crate::core::reaches;
+pub use crate::core::{
+    agreement_ratio_scaled, byzantine_safe, byzantine_safe_weighted, decide_consensus, has_quorum,
+    honest_majority, meets_threshold, probabilistic_tolerance, share_threshold, threshold_exact,
+    ConsensusOutcome, Threshold,
+};
+
+/// How much weight a round can lose to Byzantine voters before
+/// `weighted_byzantine_safety` lapses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeightedFaultCapacity {
+    /// `trust` times the model's weight, summed over the voters
+    pub total_weight: u128,
+    /// Most weight the Byzantine voters may hold: under a third of the
+    /// total, 0 when the total is
+    pub max_byzantine_weight: u128,
+    /// Most voters that may be Byzantine whichever they are: the k heaviest
+    /// weigh at most `max_byzantine_weight`
+    pub tolerated_voters: usize,
+}
+
+/// What a round still guarantees with its suspected Byzantine agents
+/// (`guarantee_level`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GuaranteeLevel {
+    /// An agreement always carries the honest majority's value
+    /// (`lemma_full_bft_level`)
+    FullBft,
+    /// Agreements can be wrong, but the Byzantine agents alone cannot
+    /// overturn a unanimous honest vote, only force a halt
+    /// (`lemma_halt_only_level`)
+    HaltOnly,
+    /// The Byzantine agents alone reach the threshold
+    /// (`lemma_none_level`)
+    None,
+}
+
+impl fmt::Display for GuaranteeLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GuaranteeLevel::FullBft => "full BFT",
+            GuaranteeLevel::HaltOnly => "halt only",
+            GuaranteeLevel::None => "none",
+        })
+    }
+}
+
+/// Further Byzantine agents an ensemble absorbs before full BFT lapses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FaultBudget {
+    /// More agents that may turn Byzantine (`byzantine_safe` and
+    /// `threshold_tolerates`)
+    pub remaining_count_based: usize,
+    /// More Byzantine weight, in thousandths of a full-trust agent
+    /// (`byzantine_safe_weighted` with every agent at trust 1000). Agents
+    /// whose trust has decayed weigh less, so this can outlast the count.
+    pub remaining_weight_based: u64,
+    pub guarantees: GuaranteeLevel,
+}
+
+impl fmt::Display for FaultBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} more Byzantine agents tolerated ({}.{:03} by weight)",
+            self.guarantees,
+            self.remaining_count_based,
+            self.remaining_weight_based / 1000,
+            self.remaining_weight_based % 1000
+        )
+    }
+}
+
+/// [`ConsensusEngine::fault_budget`] at the default threshold
+pub fn fault_budget(n: usize, suspected: usize) -> FaultBudget {
+    ConsensusEngine::default().fault_budget(n, suspected)
+}
+
+/// Consensus decisions at a validated supermajority threshold, weighing
+/// votes by the models of a [`ModelRegistry`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsensusEngine {
+    /// Valid threshold ([`Threshold::is_valid`])
+    threshold: Threshold,
+    /// Highest tolerated current / baseline variance, scaled by 100
+    max_variance_ratio: u64,
+    /// Accuracy, calibration and latency weights of a voter's trust,
+    /// summing to 1000
+    trust_weights: [u64; 3],
+    /// Trust below which an agent's vote is not counted
+    participation_threshold: u64,
+    /// Models weighted votes are weighed by
+    models: ModelRegistry,
+}
+
+impl ConsensusEngine {
+    /// Engine deciding at `thresholds.consensus`, once the thresholds
+    /// pass [`Thresholds::validate`], weighing votes by the
+    /// [built-in models](ModelRegistry::builtin)
+    pub fn new(thresholds: &Thresholds) -> Result<Self, ShieldError> {
+        thresholds.validate()?;
+        Ok(Self::from_thresholds(thresholds))
+    }
+
+    /// This engine, weighing votes by `models` instead
+    pub fn with_models(self, models: ModelRegistry) -> Self {
+        Self { models, ..self }
+    }
+
+    fn from_thresholds(thresholds: &Thresholds) -> Self {
+        Self {
+            threshold: thresholds.consensus,
+            max_variance_ratio: thresholds.variance_multiplier_scaled,
+            trust_weights: thresholds.trust_weights(),
+            participation_threshold: thresholds.detection_threshold,
+            models: ModelRegistry::builtin(),
+        }
+    }
+
+    /// Models weighted votes are weighed by
+    pub fn models(&self) -> &ModelRegistry {
+        &self.models
+    }
+
+    /// Byzantine weight the voters with `trusts[i]` and `model_ids[i]`
+    /// tolerate, by weight rather than by count, at the engine's
+    /// [`models`](Self::models)' weights
+    ///
+    /// # Panics
+    ///
+    /// If `trusts` and `model_ids` differ in length.
+    pub fn weighted_fault_capacity(
+        &self,
+        trusts: &[u64],
+        model_ids: &[u64],
+    ) -> WeightedFaultCapacity {
+        assert_eq!(
+            trusts.len(),
+            model_ids.len(),
+            "one model id per trust score"
+        );
+        let mut weights: Vec<u128> = trusts
+            .iter()
+            .zip(model_ids)
+            .map(|(&trust, &model_id)| vote_weight(trust, self.models.weight(model_id)))
+            .collect();
+        let total_weight: u128 = weights.iter().sum();
+        if total_weight == 0 {
+            return WeightedFaultCapacity {
+                total_weight,
+                max_byzantine_weight: 0,
+                tolerated_voters: 0,
+            };
+        }
+        let max_byzantine_weight = (total_weight - 1) / 3;
+
+        // Heaviest first: the worst choice of Byzantine voters
+        weights.sort_unstable_by(|a, b| b.cmp(a));
+        let mut byzantine = 0u128;
+        let tolerated_voters = weights
+            .iter()
+            .take_while(|&&weight| {
+                byzantine += weight;
+                byzantine <= max_byzantine_weight
+            })
+            .count();
+        WeightedFaultCapacity {
+            total_weight,
+            max_byzantine_weight,
+            tolerated_voters,
+        }
+    }
+
+    /// Fraction of the votes a decision must reach
+    pub fn threshold(&self) -> Threshold {
+        self.threshold
+    }
+
+    /// Whether an Agreed outcome always carries the honest majority's value
+    /// with `f` Byzantine voters among `n` (`threshold_tolerates`:
+    /// f/n <= (t - 500)/500, so f/n <= 1/3 at two thirds)
+    pub fn tolerates(&self, n: u64, f: u64) -> bool {
+        u128::from(f) * 1500 <= (threshold_exact(self.threshold) - 1500) * u128::from(n)
+    }
+
+    /// Whether `n - f` honest voters alone fall short of the threshold, so a
+    /// round they cannot win halts (`halt_guaranteed`: f/n > 1 - t/1000,
+    /// so f/n > 1/3 at two thirds)
+    pub fn halt_guaranteed(&self, n: u64, f: u64) -> bool {
+        u128::from(f) * 3000 > (3000 - threshold_exact(self.threshold)) * u128::from(n)
+    }
+
+    /// Guarantee level of `n` agents with `f` Byzantine (`guarantee_level`);
+    /// at the default threshold full BFT is exactly 3f < n
+    /// (`guarantee_levels_monotone`)
+    pub fn guarantee_level(&self, n: u64, f: u64) -> GuaranteeLevel {
+        if byzantine_safe(n, f) && self.tolerates(n, f) {
+            GuaranteeLevel::FullBft
+        } else if !meets_threshold(f, n, self.threshold) {
+            GuaranteeLevel::HaltOnly
+        } else {
+            GuaranteeLevel::None
+        }
+    }
+
+    /// How many more of `n` agents may fail, beyond the `suspected` ones,
+    /// before [`GuaranteeLevel::FullBft`] lapses; none once it has
+    pub fn fault_budget(&self, n: usize, suspected: usize) -> FaultBudget {
+        let (n, suspected) = (n as u64, suspected as u64);
+        // Levels only degrade with f (guarantee_levels_monotone), so the
+        // budget runs up to the largest f with 3f < n and
+        // f * 1500 <= (threshold_exact(t) - 1500) * n
+        let max_count = if n == 0 {
+            0
+        } else {
+            let tolerated = (threshold_exact(self.threshold) - 1500) * u128::from(n) / 1500;
+            ((n - 1) / 3).min(tolerated as u64)
+        };
+        debug_assert!(n == 0 || self.guarantee_level(n, max_count) == GuaranteeLevel::FullBft);
+        let total_weight = u128::from(n) * 1000;
+        let max_weight = total_weight.saturating_sub(1) / 3;
+        let remaining_weight = max_weight.saturating_sub(u128::from(suspected) * 1000);
+        debug_assert!(
+            remaining_weight == 0
+                || byzantine_safe_weighted(
+                    total_weight,
+                    u128::from(suspected) * 1000 + remaining_weight
+                )
+        );
+        FaultBudget {
+            remaining_count_based: max_count.saturating_sub(suspected) as usize,
+            remaining_weight_based: u64::try_from(remaining_weight).unwrap_or(u64::MAX),
+            guarantees: self.guarantee_level(n, suspected),
+        }
+    }
+
+    /// Decide a round: the majority value if its share reaches the
+    /// threshold, otherwise a [`HaltReason::NoSupermajority`] halt. A round
+    /// without votes halts with [`HaltReason::InsufficientVoters`].
+    ///
+    /// Both boundaries are inclusive (`decide_consensus_boundaries`): a
+    /// share of exactly the threshold t agrees on true, a share of exactly
+    /// 1 - t agrees on false, and only shares strictly between halt.
+    /// Flipping every vote flips the value and keeps the share
+    /// (`vote_negation_symmetry`); reordering the votes changes nothing
+    /// (`decide_consensus_permutation_invariant`).
+    pub fn decide(&self, votes: &[bool]) -> ConsensusOutcome {
+        decide_consensus(votes, self.threshold)
+    }
+
+    /// Decide a round of ballots, `None` being an abstention
+    ///
+    /// Fewer than ceil(2n/3) votes cast halts with
+    /// [`HaltReason::InsufficientVoters`] reporting the votes cast; otherwise
+    /// the cast votes are decided as by [`decide`](Self::decide), so
+    /// abstentions do not dilute the agreement ratio.
+    pub fn decide_ballots(&self, ballots: &[Option<bool>]) -> ConsensusOutcome {
+        let cast: Vec<bool> = ballots.iter().flatten().copied().collect();
+        if !has_quorum(cast.len() as u64, ballots.len() as u64) {
+            return ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters {
+                    n: cast.len() as u64,
+                },
+            };
+        }
+        self.decide(&cast)
+    }
+
+    /// Decide a round of `(vote, trust, model_id)` triples, weighing each
+    /// vote by `trust` times the weight of the engine's
+    /// [`models`](Self::models) for `model_id`, with trust scaled by 1000
+    /// ([`vote_weight`], exact: the products are summed before any
+    /// division). Like [`decide`](Self::decide), it ignores the order of the votes
+    /// (`decide_consensus_weighted_permutation_invariant`).
+    ///
+    /// The value holding the majority of the weight is decided if its share
+    /// reaches the threshold. A round whose votes all weigh zero halts with
+    /// [`HaltReason::TrustCollapse`], and one without votes with
+    /// [`HaltReason::InsufficientVoters`].
+    pub fn decide_weighted(&self, votes: &[(bool, u64, u64)]) -> ConsensusOutcome {
+        self.decide_weighted_with(votes, &self.models)
+    }
+
+    /// [`decide_weighted`](Self::decide_weighted) with the model weights
+    /// of `models` instead of the engine's
+    pub fn decide_weighted_with(
+        &self,
+        votes: &[(bool, u64, u64)],
+        models: &ModelRegistry,
+    ) -> ConsensusOutcome {
+        self.decide_weighted_by(votes, |model_id| models.weight(model_id))
+    }
+
+    /// The trust an agent votes with: its composite trust at
+    /// [`Thresholds::trust_weights`], which at the default `[1000, 0, 0]`
+    /// is its accuracy alone
+    pub fn voting_trust(&self, trust: &AgentTrust) -> TrustScore {
+        trust
+            .composite(self.trust_weights)
+            .expect("trust weights sum to 1000 (Thresholds::validate)")
+    }
+
+    /// Whether an agent's vote counts: its
+    /// [`voting_trust`](Self::voting_trust) is at least the detection
+    /// threshold
+    pub fn participates(&self, trust: &AgentTrust) -> bool {
+        self.voting_trust(trust).value() >= self.participation_threshold
+    }
+
+    /// Decide a round of `(vote, trust, model_id)` agent votes like
+    /// [`decide_weighted`](Self::decide_weighted), each weighed by its
+    /// [`voting_trust`](Self::voting_trust); the votes of agents that do
+    /// not [`participate`](Self::participates) are dropped
+    pub fn decide_agents(&self, votes: &[(bool, &AgentTrust, u64)]) -> ConsensusOutcome {
+        let weighted: Vec<(bool, u64, u64)> = votes
+            .iter()
+            .filter(|(_, trust, _)| self.participates(trust))
+            .map(|&(vote, trust, model_id)| (vote, self.voting_trust(trust).value(), model_id))
+            .collect();
+        self.decide_weighted(&weighted)
+    }
+
+    fn decide_weighted_by(
+        &self,
+        votes: &[(bool, u64, u64)],
+        weight_of: impl Fn(u64) -> u64,
+    ) -> ConsensusOutcome {
+        if votes.is_empty() {
+            return ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: 0 },
+            };
+        }
+        let (mut agrees, mut total) = (0u128, 0u128);
+        for &(vote, trust, model_id) in votes {
+            let weight = vote_weight(trust, weight_of(model_id));
+            total += weight;
+            if vote {
+                agrees += weight;
+            }
+        }
+        if total == 0 {
+            // Model weights are at least 100, so every trust is zero
+            let max_trust = votes.iter().map(|&(_, trust, _)| trust).max();
+            return ConsensusOutcome::Halted {
+                reason: HaltReason::TrustCollapse {
+                    max_trust: max_trust.unwrap_or(0),
+                },
+            };
+        }
+
+        let value = 2 * agrees > total;
+        let supporters = if value { agrees } else { total - agrees };
+        // supporters <= total, so the share is at most 1000
+        let agreement_pct = (supporters * 1000 / total) as u64;
+        let outcome = if reaches(supporters, total, self.threshold) {
+            ConsensusOutcome::Agreed {
+                value,
+                agreement_pct,
+            }
+        } else {
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct },
+            }
+        };
+        debug_assert!(outcome.is_valid(), "invalid outcome {:?}", outcome);
+        outcome
+    }
+
+    /// Decide a round of `(voter, ballot, weight)` entries, keeping the
+    /// [`RoundEvidence`] behind the outcome
+    ///
+    /// With unit weights the outcome is
+    /// [`decide_ballots`](Self::decide_ballots)'s; with weights `trust`
+    /// times the model weight and no abstentions it is
+    /// [`decide_weighted`](Self::decide_weighted)'s.
+    pub fn decide_with_evidence(&self, per_voter: Vec<(VoterId, Ballot, u64)>) -> RoundDecision {
+        let evidence = RoundEvidence::new(per_voter, self.threshold);
+        RoundDecision {
+            outcome: evidence.decide(),
+            evidence,
+        }
+    }
+
+    /// Constitutional halt check (`constitutional_halt_reason`): agreement
+    /// below the threshold halts first, then a variance ratio (current /
+    /// baseline, scaled by 100) above the configured multiple. `None`
+    /// means the round may proceed.
+    ///
+    /// `agreement_pct` is rounded down, so it is held to
+    /// [`share_threshold`] (666 at two thirds), which every agreed share
+    /// reaches; the exact test is [`decide`](Self::decide)'s.
+    pub fn constitutional_halt(
+        &self,
+        agreement_pct: u64,
+        variance_ratio: u64,
+    ) -> Option<HaltReason> {
+        if agreement_pct < share_threshold(self.threshold) {
+            Some(HaltReason::NoSupermajority { agreement_pct })
+        } else if variance_ratio > self.max_variance_ratio {
+            Some(HaltReason::VarianceExceeded {
+                ratio_scaled: variance_ratio,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl ConsensusEngine {
+    /// Final verdict of a round (`final_verdict`), the one decision a round
+    /// ends in: [`decide`](Self::decide) on the votes, then
+    /// [`constitutional_halt`](Self::constitutional_halt) on the agreed
+    /// share and the variance of the round's outputs against
+    /// `baseline_variance_scaled` (both scaled by 100).
+    ///
+    /// Agreeing votes do not mask exploding variance: the round halts with
+    /// [`HaltReason::VarianceExceeded`] (`final_verdict_variance_halt`).
+    pub fn final_verdict(
+        &self,
+        votes: &[bool],
+        outputs: &[u64],
+        baseline_variance_scaled: u64,
+    ) -> ConsensusOutcome {
+        let variance = output_variance_scaled(outputs);
+        self.verdict_at_variance(votes, variance, baseline_variance_scaled)
+    }
+
+    /// [`final_verdict`](Self::final_verdict) over outputs accumulated as
+    /// they arrived, without another pass over them. The accumulator is
+    /// the caller's: [`reset`](OnlineVariance::reset) it between rounds.
+    pub fn final_verdict_online(
+        &self,
+        votes: &[bool],
+        outputs: &OnlineVariance,
+        baseline_variance_scaled: u64,
+    ) -> ConsensusOutcome {
+        // Empty is the only error an accumulator can report
+        let variance = outputs.variance_scaled().unwrap_or(0);
+        self.verdict_at_variance(votes, variance, baseline_variance_scaled)
+    }
+
+    fn verdict_at_variance(
+        &self,
+        votes: &[bool],
+        variance_scaled: u64,
+        baseline_variance_scaled: u64,
+    ) -> ConsensusOutcome {
+        let outcome = self.decide(votes);
+        match outcome {
+            ConsensusOutcome::Agreed { agreement_pct, .. } => {
+                let ratio = variance_ratio_scaled(variance_scaled, baseline_variance_scaled);
+                self.constitutional_halt(agreement_pct, ratio)
+                    .map_or(outcome, |reason| ConsensusOutcome::Halted { reason })
+            }
+            ConsensusOutcome::Halted { .. } => outcome,
+        }
+    }
+}
+
+/// Current variance as a multiple of the baseline, scaled by 100
+/// (`variance_ratio_scaled`) and saturating at `u64::MAX`; any variance
+/// over a zero baseline saturates
+pub fn variance_ratio_scaled(variance_scaled: u64, baseline_variance_scaled: u64) -> u64 {
+    if baseline_variance_scaled == 0 {
+        return if variance_scaled == 0 { 0 } else { u64::MAX };
+    }
+    let ratio = u128::from(variance_scaled) * 100 / u128::from(baseline_variance_scaled);
+    u64::try_from(ratio).unwrap_or(u64::MAX)
+}
+
+/// Variance of `outputs` scaled by 100, pushed through an
+/// [`OnlineVariance`] (`online_variance_matches_batch`): 0 without outputs,
+/// and saturating at `u64::MAX` for outputs outside the specification's
+/// bounds, which halt the round
+fn output_variance_scaled(outputs: &[u64]) -> u64 {
+    let mut online = OnlineVariance::new();
+    for &output in outputs {
+        if online.push(output).is_err() {
+            return u64::MAX;
+        }
+    }
+    online.variance_scaled().unwrap_or(0)
+}
+
+impl Default for ConsensusEngine {
+    /// Engine at the proven default thresholds
+    fn default() -> Self {
+        Self::from_thresholds(&Thresholds::default())
+    }
+}
+
+/// Agent identifier in a round's answers
+pub type AgentId = u64;
+
+/// Trust decay owed by a suspected agent (`trust_decay_floored` in
+/// `trust_bounds.rs`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrustDecay {
+    pub agent: AgentId,
+    /// Share of trust lost, scaled by 1000 ([`Thresholds::decay_rate`])
+    pub decay_rate: u64,
+    /// Trust the decay stops at ([`Thresholds::trust_floor`])
+    pub floor: u64,
+}
+
+impl TrustDecay {
+    /// Trust after the decay ([`trust_decay_floored`]): strictly lower
+    /// above the floor at a positive rate, never below the floor from
+    /// above it, and unchanged at or below it
+    /// (`floored_decay_fixed_points`)
+    pub fn apply(&self, current: u64) -> Result<u64, TrustError> {
+        trust_decay_floored(current, self.decay_rate, self.floor)
+    }
+}
+
+/// Lower median of `values`: a consensus value for
+/// [`identify_suspects`]. When more than half the values are honest, it
+/// lies between two honest ones.
+pub fn median(values: &[u64]) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    Some(sorted[(sorted.len() - 1) / 2])
+}
+
+/// Agents whose answer is further than `tolerance` from `consensus_value`
+/// (`accused`), in answer order
+///
+/// Nobody is accused unless the agents within tolerance hold a strict
+/// majority: a dispersed round has no honest cluster to measure against.
+pub fn identify_suspects(
+    answers: &[(AgentId, u64)],
+    consensus_value: u64,
+    tolerance: u64,
+) -> Vec<AgentId> {
+    let suspects: Vec<AgentId> = answers
+        .iter()
+        .filter(|&&(_, answer)| answer.abs_diff(consensus_value) > tolerance)
+        .map(|&(agent, _)| agent)
+        .collect();
+    if 2 * suspects.len() < answers.len() {
+        suspects
+    } else {
+        Vec::new()
+    }
+}
+
+/// [`identify_suspects`] as trust decay events at `decay_rate`, stopping
+/// at `floor`
+pub fn suspect_decays(
+    answers: &[(AgentId, u64)],
+    consensus_value: u64,
+    tolerance: u64,
+    decay_rate: u64,
+    floor: u64,
+) -> Vec<TrustDecay> {
+    identify_suspects(answers, consensus_value, tolerance)
+        .into_iter()
+        .map(|agent| TrustDecay {
+            agent,
+            decay_rate,
+            floor,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diversity::ModelFamily;
+    use crate::models::ModelSpec;
+    use crate::test_support::{byzantine_rounds, masks, Rng};
+    use crate::trust::{Observation, TrustComponent};
+
+    fn engine(thresholds: &Thresholds) -> ConsensusEngine {
+        ConsensusEngine::new(thresholds).unwrap()
+    }
+
+    fn random_engine(rng: &mut Rng) -> ConsensusEngine {
+        engine(&Thresholds {
+            consensus: Threshold::PerMille(501 + rng.below(500)),
+            ..Thresholds::default()
+        })
+    }
+
+    fn votes(agree: usize, disagree: usize) -> Vec<bool> {
+        let mut votes = vec![true; agree];
+        votes.extend(vec![false; disagree]);
+        votes
+    }
+
+    #[test]
+    fn test_majority_value_is_decided() {
+        let thresholds = Thresholds::default();
+        assert_eq!(
+            engine(&thresholds).decide(&votes(9, 1)),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 900
+            }
+        );
+        // A false majority reports false, with the share of false votes
+        assert_eq!(
+            engine(&thresholds).decide(&votes(1, 9)),
+            ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: 900
+            }
+        );
+        assert_eq!(
+            engine(&thresholds).decide(&votes(0, 3)),
+            ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: 1000
+            }
+        );
+        // Vote order does not matter
+        let mut mixed = votes(85, 415);
+        mixed.rotate_left(200);
+        assert_eq!(
+            engine(&thresholds).decide(&mixed),
+            ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: 830
+            }
+        );
+    }
+
+    #[test]
+    fn test_no_supermajority_halts() {
+        let thresholds = Thresholds::default();
+        let halted = |agreement_pct| ConsensusOutcome::Halted {
+            reason: HaltReason::NoSupermajority { agreement_pct },
+        };
+        // 3/5 = 600 falls short of two thirds, for either value
+        assert_eq!(engine(&thresholds).decide(&votes(3, 2)), halted(600));
+        assert_eq!(engine(&thresholds).decide(&votes(2, 3)), halted(600));
+        // A tie reports false at half the votes
+        assert_eq!(engine(&thresholds).decide(&votes(5, 5)), halted(500));
+        assert_eq!(
+            engine(&thresholds).decide(&[]),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: 0 }
+            }
+        );
+
+        // 2/3 reaches it exactly
+        assert_eq!(
+            engine(&thresholds).decide(&votes(1, 2)),
+            ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: 666
+            }
+        );
+
+        let lenient = Thresholds {
+            consensus: Threshold::PerMille(600),
+            ..Thresholds::default()
+        };
+        assert_eq!(
+            engine(&lenient).decide(&votes(2, 3)),
+            ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: 600
+            }
+        );
+    }
+
+    #[test]
+    fn test_abstentions() {
+        let engine = ConsensusEngine::default();
+        let insufficient = |n| ConsensusOutcome::Halted {
+            reason: HaltReason::InsufficientVoters { n },
+        };
+
+        // One abstention out of three: the quorum of two is met and the
+        // abstention does not count against the agreement
+        assert_eq!(
+            engine.decide_ballots(&[Some(true), None, Some(true)]),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 1000
+            }
+        );
+        assert_eq!(
+            engine.decide_ballots(&[Some(true), Some(false), None]),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 500 }
+            }
+        );
+        // Three of four cast agree; a fabricated disagree vote in place of
+        // the abstention would have halted the round instead
+        assert_eq!(
+            engine.decide_ballots(&[Some(true), None, Some(true), Some(true), Some(false)]),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 750
+            }
+        );
+        assert!(matches!(
+            engine.decide(&[true, false, true, true, false]),
+            ConsensusOutcome::Halted { .. }
+        ));
+
+        // Majority abstention misses the quorum
+        assert_eq!(
+            engine.decide_ballots(&[Some(true), None, None]),
+            insufficient(1)
+        );
+        assert_eq!(
+            engine.decide_ballots(&[Some(false), Some(false), None, None, None]),
+            insufficient(2)
+        );
+        assert_eq!(engine.decide_ballots(&[None; 3]), insufficient(0));
+        assert_eq!(engine.decide_ballots(&[]), insufficient(0));
+
+        // Without abstentions ballots decide like plain votes
+        let round = votes(7, 3);
+        let ballots: Vec<_> = round.iter().copied().map(Some).collect();
+        assert_eq!(engine.decide_ballots(&ballots), engine.decide(&round));
+
+        assert!(has_quorum(2, 3));
+        assert!(!has_quorum(1, 3));
+        assert!(has_quorum(4, 6));
+        assert!(!has_quorum(4, 7));
+        assert!(has_quorum(0, 0));
+    }
+
+    #[test]
+    fn test_abstention_preserves_unanimity() {
+        // abstention_preserves_unanimity: f < n/3 Byzantine voters who vote
+        // against or abstain cannot stop an honest unanimous vote whose
+        // share reaches the threshold
+        let engine = ConsensusEngine::default();
+        let mut rng = Rng(0x5eed_1579);
+        for n in 1..60u64 {
+            for f in (0..n).filter(|&f| byzantine_safe(n, f)) {
+                if !meets_threshold(n - f, n, engine.threshold()) {
+                    continue;
+                }
+                for value in [true, false] {
+                    let mut ballots = vec![Some(value); (n - f) as usize];
+                    ballots.extend((0..f).map(|_| match rng.below(3) {
+                        0 => None,
+                        1 => Some(!value),
+                        _ => Some(value),
+                    }));
+                    assert!(
+                        matches!(
+                            engine.decide_ballots(&ballots),
+                            ConsensusOutcome::Agreed { value: v, .. } if v == value
+                        ),
+                        "n = {}, f = {}, {:?}",
+                        n,
+                        f,
+                        ballots
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_threshold_parameter() {
+        let at = |consensus| {
+            engine(&Thresholds {
+                consensus,
+                ..Thresholds::default()
+            })
+        };
+        let (default, strict) = (at(Threshold::TwoThirds), at(Threshold::PerMille(750)));
+        assert_eq!(default, ConsensusEngine::default());
+        assert_eq!(strict.threshold(), Threshold::PerMille(750));
+
+        // 70% agrees at two thirds but not at 3/4
+        assert!(matches!(
+            default.decide(&votes(7, 3)),
+            ConsensusOutcome::Agreed { value: true, .. }
+        ));
+        assert_eq!(
+            strict.decide(&votes(7, 3)),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 700 }
+            }
+        );
+        assert_eq!(
+            strict.decide(&votes(1, 3)),
+            ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: 750
+            }
+        );
+
+        // Agreed outcomes stay honest up to f/n = (t - 500)/500, which is
+        // 1/3 at two thirds
+        assert!(default.tolerates(100, 33));
+        assert!(!default.tolerates(100, 34));
+        assert!(default.tolerates(3, 1));
+        assert!(strict.tolerates(100, 50));
+        assert!(!strict.tolerates(100, 51));
+        // The halt is guaranteed past f/n = 1 - t/1000; at exactly 33 of
+        // 100 the 67 honest voters still reach two thirds, and at 1 of 3
+        // the two honest voters hold it exactly
+        assert!(default.halt_guaranteed(100, 34));
+        assert!(!default.halt_guaranteed(100, 33));
+        assert!(!default.halt_guaranteed(3, 1));
+        assert!(default.halt_guaranteed(3, 2));
+        assert!(strict.halt_guaranteed(100, 26));
+        assert!(!strict.halt_guaranteed(100, 25));
+
+        // Thresholds outside 501..=1000 are rejected at construction
+        for t in [0, 500, 1001] {
+            let err = ConsensusEngine::new(&Thresholds {
+                consensus: Threshold::PerMille(t),
+                ..Thresholds::default()
+            })
+            .unwrap_err();
+            assert!(err.to_string().contains("consensus"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_exact_threshold_boundaries() {
+        let thresholds = Thresholds::default();
+        let halted = |agreement_pct| ConsensusOutcome::Halted {
+            reason: HaltReason::NoSupermajority { agreement_pct },
+        };
+        // 2 of 3 and 4 of 6 are exactly two thirds, the default: they
+        // agree
+        assert_eq!(thresholds.consensus, Threshold::TwoThirds);
+        assert!(meets_threshold(2, 3, Threshold::TwoThirds));
+        assert!(meets_threshold(4, 6, Threshold::TwoThirds));
+        for (agrees, disagrees) in [(2, 1), (4, 2)] {
+            assert_eq!(
+                engine(&thresholds).decide(&votes(agrees, disagrees)),
+                ConsensusOutcome::Agreed {
+                    value: true,
+                    agreement_pct: 666
+                }
+            );
+        }
+        // 666 of 1000 rounds to the same share but falls short of 2/3
+        assert!(!meets_threshold(666, 1000, Threshold::TwoThirds));
+        assert!(meets_threshold(667, 1000, Threshold::TwoThirds));
+        assert_eq!(engine(&thresholds).decide(&votes(666, 334)), halted(666));
+        assert_eq!(
+            engine(&thresholds).decide(&votes(667, 333)),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 667
+            }
+        );
+
+        // PerMille(t) stands for t/1000: 670 rejects 2 of 3 and 4 of 6
+        let per_mille = Threshold::PerMille(670);
+        let strict = Thresholds {
+            consensus: per_mille,
+            ..Thresholds::default()
+        };
+        assert!(!meets_threshold(2, 3, per_mille));
+        assert!(!meets_threshold(4, 6, per_mille));
+        assert_eq!(engine(&strict).decide(&votes(2, 1)), halted(666));
+        assert_eq!(engine(&strict).decide(&votes(4, 2)), halted(666));
+        assert!(!meets_threshold(669, 1000, per_mille));
+        assert!(meets_threshold(670, 1000, per_mille));
+        // 667/1000 is a threshold of its own, above two thirds
+        assert!(!meets_threshold(2, 3, Threshold::PerMille(667)));
+        assert!(meets_threshold(2, 3, Threshold::PerMille(666)));
+        assert_eq!(engine(&strict).decide(&votes(669, 331)), halted(669));
+    }
+
+    #[test]
+    fn test_threshold_boundaries_n1000() {
+        // decide_consensus_boundaries: every agreement of 1000 voters, at
+        // thresholds from just above a tie to unanimity
+        for &t in &[501, 666, 667, 670, 999, 1000] {
+            let thresholds = Thresholds {
+                consensus: Threshold::PerMille(t),
+                ..Thresholds::default()
+            };
+            let engine = engine(&thresholds);
+            for agrees in 0..=1000u64 {
+                let round = votes(agrees as usize, 1000 - agrees as usize);
+                let expected = if agrees >= t {
+                    ConsensusOutcome::Agreed {
+                        value: true,
+                        agreement_pct: agrees,
+                    }
+                } else if agrees <= 1000 - t {
+                    ConsensusOutcome::Agreed {
+                        value: false,
+                        agreement_pct: 1000 - agrees,
+                    }
+                } else {
+                    ConsensusOutcome::Halted {
+                        reason: HaltReason::NoSupermajority {
+                            agreement_pct: agrees.max(1000 - agrees),
+                        },
+                    }
+                };
+                let outcome = engine.decide(&round);
+                assert_eq!(outcome, expected, "t = {}, agrees = {}", t, agrees);
+
+                // vote_negation_symmetry
+                let flipped: Vec<bool> = round.iter().map(|v| !v).collect();
+                let expected = match outcome {
+                    ConsensusOutcome::Agreed {
+                        value,
+                        agreement_pct,
+                    } => ConsensusOutcome::Agreed {
+                        value: !value,
+                        agreement_pct,
+                    },
+                    halted => halted,
+                };
+                assert_eq!(engine.decide(&flipped), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_exact_threshold_matches_real_comparison() {
+        // lemma_two_thirds_threshold: for n <= 10_000 the default test is
+        // agrees/n >= 2/3, and every share meeting it rounds down to at
+        // least share_threshold; lemma_per_mille_threshold: PerMille(670)
+        // is agrees/n >= 67/100, which the rounded-down scaled ratio
+        // decides exactly
+        let per_mille = Threshold::PerMille(670);
+        assert_eq!(share_threshold(Threshold::TwoThirds), 666);
+        assert_eq!(share_threshold(per_mille), 670);
+        for n in 1..=10_000u64 {
+            let boundary = 2 * n / 3;
+            for agrees in boundary.saturating_sub(1)..=(boundary + 1).min(n) {
+                let exact = meets_threshold(agrees, n, Threshold::TwoThirds);
+                assert_eq!(exact, 3 * agrees >= 2 * n, "{}/{}", agrees, n);
+                assert_eq!(exact, agrees as f64 / n as f64 >= 2.0 / 3.0 - 1e-12);
+                if exact {
+                    assert!(agreement_ratio_scaled(agrees, n) >= 666);
+                }
+            }
+            let boundary = 67 * n / 100;
+            for agrees in boundary.saturating_sub(1)..=(boundary + 1).min(n) {
+                let exact = meets_threshold(agrees, n, per_mille);
+                assert_eq!(exact, agrees * 100 >= 67 * n, "{}/{}", agrees, n);
+                assert_eq!(exact, agreement_ratio_scaled(agrees, n) >= 670);
+            }
+        }
+    }
+
+    #[test]
+    fn test_weighted_decision() {
+        let thresholds = Thresholds::default();
+        // Equal trust and model: weighting changes nothing
+        let round: Vec<_> = votes(1, 9).into_iter().map(|v| (v, 800, 7)).collect();
+        assert_eq!(
+            engine(&thresholds).decide_weighted(&round),
+            engine(&thresholds).decide(&votes(1, 9))
+        );
+
+        // Trust and model shift the share: 2 * 900 * 150 agree against
+        // 100 * 100 disagree
+        assert_eq!(
+            engine(&thresholds).decide_weighted(&[(true, 900, 2), (true, 900, 3), (false, 100, 9)]),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 964
+            }
+        );
+
+        let halted = |reason| ConsensusOutcome::Halted { reason };
+        assert_eq!(
+            engine(&thresholds).decide_weighted(&[(true, 0, 0), (false, 0, 1)]),
+            halted(HaltReason::TrustCollapse { max_trust: 0 })
+        );
+        assert_eq!(
+            engine(&thresholds).decide_weighted(&[]),
+            halted(HaltReason::InsufficientVoters { n: 0 })
+        );
+        // gpt-4o-mini (130) against a default model (100)
+        assert_eq!(
+            engine(&thresholds).decide_weighted(&[(true, 1000, 4), (false, 1000, 6)]),
+            halted(HaltReason::NoSupermajority { agreement_pct: 565 })
+        );
+    }
+
+    #[test]
+    fn test_weighted_minority_bound() {
+        let thresholds = Thresholds::default();
+        let detection = thresholds.detection_threshold;
+        // One full-trust o1-mini voter (weight 180_000) against `m` default
+        // models at the detection threshold (30_000 each)
+        let round = |m: usize| {
+            let mut round = vec![(true, 1000, 0)];
+            round.extend(vec![(false, detection, 9); m]);
+            round
+        };
+
+        // weighted_minority_bound: with k * 2000 <= m * min_trust the
+        // minority cannot outweigh the majority; here m >= 7
+        assert!(2000 <= 7 * detection);
+        for m in 7..20 {
+            match engine(&thresholds).decide_weighted(&round(m)) {
+                ConsensusOutcome::Agreed { value, .. } => assert!(!value, "m = {}", m),
+                ConsensusOutcome::Halted { .. } => {}
+            }
+        }
+        // 330_000 of 510_000 is not yet a supermajority against it;
+        // 360_000 of 540_000 is exactly two thirds
+        assert_eq!(
+            engine(&thresholds).decide_weighted(&round(11)),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 647 }
+            }
+        );
+        assert_eq!(
+            engine(&thresholds).decide_weighted(&round(12)),
+            ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: 666
+            }
+        );
+
+        // Inside the bound the minority can win on weight
+        assert!(matches!(
+            engine(&thresholds).decide_weighted(&round(1)),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 857
+            }
+        ));
+    }
+
+    #[test]
+    fn test_weighted_fault_capacity() {
+        let engine = ConsensusEngine::default();
+        let capacity =
+            |trusts: &[u64], model_ids: &[u64]| engine.weighted_fault_capacity(trusts, model_ids);
+        // Three equal voters tolerate no Byzantine one, four tolerate one,
+        // as f < n/3 does
+        let equal = capacity(&[1000; 3], &[2; 3]);
+        assert_eq!(
+            equal,
+            WeightedFaultCapacity {
+                total_weight: 450_000,
+                max_byzantine_weight: 149_999,
+                tolerated_voters: 0,
+            }
+        );
+        assert!(!byzantine_safe_weighted(450_000, 150_000));
+        assert_eq!(capacity(&[1000; 4], &[2; 4]).tolerated_voters, 1);
+
+        // Skewed: one full-trust o1-mini (180_000) among six half-trust
+        // default models (50_000 each). By count seven voters tolerate two;
+        // by weight the o1-mini alone is over a third
+        let skewed = capacity(
+            &[1000, 500, 500, 500, 500, 500, 500],
+            &[0, 9, 9, 9, 9, 9, 9],
+        );
+        assert_eq!(skewed.total_weight, 480_000);
+        assert_eq!(skewed.max_byzantine_weight, 159_999);
+        assert_eq!(skewed.tolerated_voters, 0);
+        // Three of the light voters could still go Byzantine
+        assert!(byzantine_safe_weighted(480_000, 150_000));
+
+        // Two heavy voters among six light ones: either heavy one alone
+        // holds more than a third
+        let heavy = capacity(
+            &[1000, 1000, 100, 100, 100, 100, 100, 100],
+            &[0, 0, 9, 9, 9, 9, 9, 9],
+        );
+        assert_eq!(heavy.total_weight, 420_000);
+        assert_eq!(heavy.tolerated_voters, 0);
+        // Ten equally light voters tolerate three, as by count
+        let light = capacity(&[100; 10], &[9; 10]);
+        assert_eq!(light.total_weight, 100_000);
+        assert_eq!(light.max_byzantine_weight, 33_333);
+        assert_eq!(light.tolerated_voters, 3);
+
+        // Zero-weight voters add no weight and no capacity
+        let padded = capacity(&[1000, 1000, 1000, 1000, 0, 0, 0], &[2, 2, 2, 2, 0, 1, 9]);
+        assert_eq!(padded, capacity(&[1000; 4], &[2; 4]));
+        // A round weighing nothing tolerates nothing
+        let collapsed = capacity(&[0, 0, 0], &[0, 1, 2]);
+        assert_eq!(
+            (collapsed.max_byzantine_weight, collapsed.tolerated_voters),
+            (0, 0)
+        );
+        assert!(!byzantine_safe_weighted(0, 0));
+        assert_eq!(capacity(&[], &[]).tolerated_voters, 0);
+
+        // Weights come from the engine's models: a registered heavy model
+        // 9 is over a third of the round on its own
+        let mut models = ModelRegistry::builtin();
+        let spec = ModelSpec {
+            id: 9,
+            weight_scaled: 200,
+            family: ModelFamily::Llama,
+        };
+        models.insert("llama", spec).unwrap();
+        let engine = ConsensusEngine::default().with_models(models);
+        let heavy = engine.weighted_fault_capacity(&[1000, 100, 100, 100], &[9, 2, 2, 2]);
+        assert_eq!(heavy.total_weight, 245_000);
+        assert_eq!(heavy.tolerated_voters, 0);
+    }
+
+    #[test]
+    fn test_permutation_invariance() {
+        // decide_consensus_permutation_invariant and
+        // decide_consensus_weighted_permutation_invariant: arrival order
+        // never changes an outcome, nor the evidence beyond its ordering
+        let mut rng = Rng(0x5eed_1601);
+        for _ in 0..3000 {
+            let engine = random_engine(&mut rng);
+            let n = rng.below(16);
+            let per_voter: Vec<(VoterId, Ballot, u64)> = (0..n)
+                .map(|voter| {
+                    let ballot = match rng.below(5) {
+                        0 => None,
+                        1 | 2 => Some(true),
+                        _ => Some(false),
+                    };
+                    let weight = if rng.below(4) == 0 {
+                        0
+                    } else {
+                        rng.below(2000)
+                    };
+                    (voter, ballot, weight)
+                })
+                .collect();
+            let mut shuffled = per_voter.clone();
+            rng.shuffle(&mut shuffled);
+
+            let decision = engine.decide_with_evidence(per_voter.clone());
+            let mut reordered = engine.decide_with_evidence(shuffled.clone());
+            assert_eq!(reordered.outcome, decision.outcome);
+            reordered
+                .evidence
+                .per_voter
+                .sort_by_key(|&(voter, _, _)| voter);
+            assert_eq!(reordered.evidence, decision.evidence);
+
+            let ballots = |round: &[(VoterId, Ballot, u64)]| -> Vec<Ballot> {
+                round.iter().map(|&(_, ballot, _)| ballot).collect()
+            };
+            assert_eq!(
+                engine.decide_ballots(&ballots(&shuffled)),
+                engine.decide_ballots(&ballots(&per_voter))
+            );
+            let cast: Vec<bool> = ballots(&per_voter).into_iter().flatten().collect();
+            let mut cast_shuffled = cast.clone();
+            rng.shuffle(&mut cast_shuffled);
+            assert_eq!(engine.decide(&cast_shuffled), engine.decide(&cast));
+
+            // Votes permute together with their trusts and model ids
+            let weighted: Vec<(bool, u64, u64)> = cast
+                .iter()
+                .map(|&vote| (vote, rng.below(1001), rng.below(7)))
+                .collect();
+            let mut weighted_shuffled = weighted.clone();
+            rng.shuffle(&mut weighted_shuffled);
+            assert_eq!(
+                engine.decide_weighted(&weighted_shuffled),
+                engine.decide_weighted(&weighted)
+            );
+        }
+    }
+
+    #[test]
+    fn test_fault_budget() {
+        let default = ConsensusEngine::default();
+        for n in 3..=10usize {
+            for suspected in 0..=n {
+                let budget = fault_budget(n, suspected);
+                let (n64, f) = (n as u64, suspected as u64);
+                let level = budget.guarantees;
+
+                // Full BFT is exactly 3f < n at the default threshold, and
+                // the count budget runs out exactly where it lapses
+                assert_eq!(level == GuaranteeLevel::FullBft, byzantine_safe(n64, f));
+                let last = f + budget.remaining_count_based as u64;
+                if level == GuaranteeLevel::FullBft {
+                    assert!(byzantine_safe(n64, last));
+                }
+                assert!(!byzantine_safe(n64, last + 1), "n = {}, f = {}", n, f);
+                // and the weight budget where byzantine_safe_weighted does
+                let (total, held) = (n as u128 * 1000, f as u128 * 1000);
+                let weight = u128::from(budget.remaining_weight_based);
+                assert!(weight == 0 || byzantine_safe_weighted(total, held + weight));
+                assert!(!byzantine_safe_weighted(total, held + weight + 1));
+                assert!(weight >= budget.remaining_count_based as u128 * 1000);
+
+                // Short of full BFT (3f >= n) the Byzantine agents can force
+                // a halt (constitutional_halt_correctness), unless 3f = n and
+                // the honest agents hold exactly two thirds
+                if level != GuaranteeLevel::FullBft {
+                    assert_eq!(default.halt_guaranteed(n64, f), 3 * f != n64);
+                }
+
+                for round in byzantine_rounds(n as u32, suspected as u32) {
+                    let outcome = default.decide(&round.votes);
+                    for v in [true, false] {
+                        let overturned = matches!(
+                            outcome,
+                            ConsensusOutcome::Agreed { value, .. } if value != v
+                        );
+                        // lemma_full_bft_level
+                        if level == GuaranteeLevel::FullBft
+                            && honest_majority(n64, f, round.honest_votes(v))
+                        {
+                            assert!(!overturned, "{:?}", round);
+                        }
+                        // lemma_halt_only_level
+                        if level != GuaranteeLevel::None && round.honest_votes(v) == n64 - f {
+                            assert!(!overturned, "{:?}", round);
+                        }
+                    }
+                }
+                // lemma_none_level: the Byzantine agents alone carry a round
+                let against_honest = votes(n - suspected, suspected);
+                assert_eq!(
+                    level == GuaranteeLevel::None,
+                    matches!(
+                        default.decide(&against_honest),
+                        ConsensusOutcome::Agreed { value: false, .. }
+                    )
+                );
+            }
+        }
+
+        assert_eq!(
+            fault_budget(10, 1).to_string(),
+            "full BFT: 2 more Byzantine agents tolerated (2.333 by weight)"
+        );
+        assert_eq!(
+            fault_budget(3, 1).to_string(),
+            "halt only: 0 more Byzantine agents tolerated (0.000 by weight)"
+        );
+        // Two of three hold two thirds on their own
+        assert_eq!(fault_budget(3, 2).guarantees, GuaranteeLevel::None);
+        assert_eq!(fault_budget(4, 2).guarantees, GuaranteeLevel::HaltOnly);
+        assert_eq!(fault_budget(3, 3).guarantees, GuaranteeLevel::None);
+        assert_eq!(fault_budget(0, 0).guarantees, GuaranteeLevel::None);
+        // A lower threshold tolerates fewer: f/n <= (t - 500)/500
+        let low = engine(&Thresholds {
+            consensus: Threshold::PerMille(550),
+            ..Thresholds::default()
+        });
+        assert_eq!(low.fault_budget(10, 0).remaining_count_based, 1);
+        assert_eq!(low.fault_budget(10, 2).guarantees, GuaranteeLevel::HaltOnly);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_fault_budget_serde() {
+        let json = serde_json::to_value(fault_budget(7, 1)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "remaining_count_based": 1,
+                "remaining_weight_based": 1333,
+                "guarantees": "full_bft"
+            })
+        );
+    }
+
+    #[test]
+    fn test_weighted_byzantine_safety() {
+        // weighted_byzantine_safety: Byzantine voters within the weighted
+        // capacity, against honest voters holding most of the honest weight
+        // for v, never get Agreed { !v } at a threshold of at least 2/3,
+        // however many of them there are and however they vote
+        let mut rng = Rng(0x5eed_1597);
+        let mut checked = 0;
+        for _ in 0..5000 {
+            let threshold = match rng.below(335) {
+                334 => Threshold::TwoThirds,
+                t => Threshold::PerMille(667 + t),
+            };
+            let engine = engine(&Thresholds {
+                consensus: threshold,
+                ..Thresholds::default()
+            });
+            let n = 1 + rng.below(12) as usize;
+            let v = rng.below(2) == 0;
+            // Skewed trust: mostly low, a few at full trust
+            let round: Vec<(bool, u64, u64, bool)> = (0..n)
+                .map(|_| {
+                    let trust = match rng.below(4) {
+                        0 => 1000,
+                        1 => 0,
+                        _ => rng.below(300),
+                    };
+                    let byzantine = rng.below(2) == 0;
+                    let vote = if byzantine {
+                        !v
+                    } else {
+                        (rng.below(4) > 0) == v
+                    };
+                    (vote, trust, rng.below(6), byzantine)
+                })
+                .collect();
+            let weight = |&(_, trust, model_id, _): &(bool, u64, u64, bool)| {
+                u128::from(trust) * u128::from(engine.models().weight(model_id))
+            };
+            let total: u128 = round.iter().map(weight).sum();
+            let byzantine: u128 = round.iter().filter(|r| r.3).map(weight).sum();
+            let honest_for: u128 = round.iter().filter(|r| !r.3 && r.0 == v).map(weight).sum();
+            let honest_against: u128 = round.iter().filter(|r| !r.3 && r.0 != v).map(weight).sum();
+            let trusts: Vec<u64> = round.iter().map(|r| r.1).collect();
+            let model_ids: Vec<u64> = round.iter().map(|r| r.2).collect();
+            let capacity = engine.weighted_fault_capacity(&trusts, &model_ids);
+            assert_eq!(capacity.total_weight, total);
+            assert_eq!(
+                byzantine_safe_weighted(total, byzantine),
+                byzantine <= capacity.max_byzantine_weight && total > 0
+            );
+            if !byzantine_safe_weighted(total, byzantine) || honest_for <= honest_against {
+                continue;
+            }
+            checked += 1;
+            let votes: Vec<(bool, u64, u64)> = round.iter().map(|r| (r.0, r.1, r.2)).collect();
+            match engine.decide_weighted(&votes) {
+                ConsensusOutcome::Agreed { value, .. } => assert_eq!(value, v, "{:?}", round),
+                ConsensusOutcome::Halted { .. } => {}
+            }
+        }
+        assert!(checked > 500, "{} rounds checked", checked);
+    }
+
+    #[test]
+    fn test_constitutional_halt_reasons() {
+        let engine = ConsensusEngine::default();
+        // halt_safety: 90% agreement at twice the baseline variance
+        assert_eq!(engine.constitutional_halt(900, 200), None);
+        // Two of three agreeing report 666, which clears two thirds
+        assert_eq!(engine.constitutional_halt(666, 625), None);
+
+        // halt_liveness: agreement below 50% halts on agreement, even when
+        // the variance is also out of bounds
+        assert_eq!(
+            engine.constitutional_halt(450, 1200),
+            Some(HaltReason::NoSupermajority { agreement_pct: 450 })
+        );
+        assert_eq!(
+            engine.constitutional_halt(665, 100),
+            Some(HaltReason::NoSupermajority { agreement_pct: 665 })
+        );
+        // Sufficient agreement with variance above 6.25x baseline
+        assert_eq!(
+            engine.constitutional_halt(900, 1001),
+            Some(HaltReason::VarianceExceeded { ratio_scaled: 1001 })
+        );
+        assert_eq!(
+            engine.constitutional_halt(666, 626),
+            Some(HaltReason::VarianceExceeded { ratio_scaled: 626 })
+        );
+
+        let tolerant = ConsensusEngine::new(&Thresholds {
+            variance_multiplier_scaled: 1000,
+            ..Thresholds::default()
+        })
+        .unwrap();
+        assert_eq!(tolerant.constitutional_halt(900, 1000), None);
+    }
+
+    #[test]
+    fn test_random_rounds_are_valid() {
+        let mut rng = Rng(0x5eed_1577);
+        for _ in 0..2000 {
+            let engine = random_engine(&mut rng);
+            let n = rng.below(300);
+            // Skew the agreement rate so rounds land on both sides of the
+            // threshold
+            let rate = rng.below(1001);
+            let round: Vec<bool> = (0..n).map(|_| rng.below(1000) < rate).collect();
+            let agrees = round.iter().filter(|v| **v).count() as u64;
+
+            let outcome = engine.decide(&round);
+            assert!(outcome.is_valid(), "{:?}", outcome);
+            match outcome {
+                ConsensusOutcome::Agreed {
+                    value,
+                    agreement_pct,
+                } => {
+                    let supporters = if value { agrees } else { n - agrees };
+                    assert!(2 * supporters > n);
+                    assert_eq!(agreement_pct, agreement_ratio_scaled(supporters, n));
+                    // The rounded-down share still reaches the share threshold
+                    assert!(agreement_pct >= share_threshold(engine.threshold()));
+                }
+                ConsensusOutcome::Halted {
+                    reason: HaltReason::NoSupermajority { agreement_pct },
+                } => {
+                    assert!(n > 0);
+                    assert!(u128::from(agreement_pct) * 3 < threshold_exact(engine.threshold()));
+                    assert!(!meets_threshold(agrees, n, engine.threshold()));
+                    assert!(!meets_threshold(n - agrees, n, engine.threshold()));
+                }
+                ConsensusOutcome::Halted { reason } => {
+                    assert_eq!(reason, HaltReason::InsufficientVoters { n: 0 });
+                    assert_eq!(n, 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_weighted_rounds_are_valid() {
+        let mut rng = Rng(0x5eed_1578);
+        for _ in 0..2000 {
+            let engine = random_engine(&mut rng);
+            let n = rng.below(50);
+            // Mostly valid trust, with some zero and out-of-range scores
+            let round: Vec<(bool, u64, u64)> = (0..n)
+                .map(|_| {
+                    let trust = match rng.below(10) {
+                        0 => 0,
+                        1 => rng.next(),
+                        _ => rng.below(1001),
+                    };
+                    (rng.below(2) == 0, trust, rng.below(8))
+                })
+                .collect();
+
+            let outcome = engine.decide_weighted(&round);
+            assert!(outcome.is_valid(), "{:?}", outcome);
+            match outcome {
+                ConsensusOutcome::Agreed { agreement_pct, .. } => {
+                    assert!(agreement_pct >= share_threshold(engine.threshold()));
+                }
+                ConsensusOutcome::Halted {
+                    reason: HaltReason::NoSupermajority { agreement_pct },
+                } => assert!(u128::from(agreement_pct) * 3 < threshold_exact(engine.threshold())),
+                ConsensusOutcome::Halted {
+                    reason: HaltReason::TrustCollapse { max_trust },
+                } => {
+                    assert_eq!(max_trust, 0);
+                    assert!(round.iter().all(|&(_, trust, _)| trust == 0));
+                }
+                ConsensusOutcome::Halted { reason } => {
+                    assert_eq!(reason, HaltReason::InsufficientVoters { n: 0 });
+                    assert!(round.is_empty());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_outcome_validity() {
+        assert!(ConsensusOutcome::Agreed {
+            value: true,
+            agreement_pct: 1000
+        }
+        .is_valid());
+        assert!(!ConsensusOutcome::Agreed {
+            value: true,
+            agreement_pct: 1001
+        }
+        .is_valid());
+        assert!(!ConsensusOutcome::Halted {
+            reason: HaltReason::NoSupermajority {
+                agreement_pct: 1001
+            }
+        }
+        .is_valid());
+        assert!(!ConsensusOutcome::Halted {
+            reason: HaltReason::TrustCollapse { max_trust: 1001 }
+        }
+        .is_valid());
+        assert!(ConsensusOutcome::Halted {
+            reason: HaltReason::VarianceExceeded {
+                ratio_scaled: u64::MAX
+            }
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn test_conflicts_with() {
+        let agreed = |value| ConsensusOutcome::Agreed {
+            value,
+            agreement_pct: 800,
+        };
+        let halted = ConsensusOutcome::Halted {
+            reason: HaltReason::InsufficientVoters { n: 0 },
+        };
+        assert!(agreed(true).conflicts_with(&agreed(false)));
+        assert!(agreed(false).conflicts_with(&agreed(true)));
+        assert!(!agreed(true).conflicts_with(&agreed(true)));
+        assert!(!agreed(true).conflicts_with(&halted));
+        assert!(!halted.conflicts_with(&agreed(false)));
+        assert!(!halted.conflicts_with(&halted));
+    }
+
+    #[test]
+    fn test_decisions_never_conflict() {
+        // no_conflicting_agreement: observers deciding the same round, at
+        // any thresholds above 500, never agree on contradictory values
+        let mut rng = Rng(0x5eed_1581);
+        for _ in 0..1000 {
+            let engines: Vec<ConsensusEngine> = (0..4).map(|_| random_engine(&mut rng)).collect();
+            let n = rng.below(40);
+            let rate = rng.below(1001);
+            let round: Vec<(bool, u64, u64)> = (0..n)
+                .map(|_| (rng.below(1000) < rate, rng.below(1001), rng.below(8)))
+                .collect();
+            let votes: Vec<bool> = round.iter().map(|&(vote, _, _)| vote).collect();
+
+            let decisions: Vec<ConsensusOutcome> = engines
+                .iter()
+                .flat_map(|engine| [engine.decide(&votes), engine.decide_weighted(&round)])
+                .collect();
+            for (i, a) in decisions.iter().enumerate() {
+                // Plain and weighted decisions are compared among themselves:
+                // weights may legitimately move the majority
+                for b in decisions.iter().skip(i).step_by(2) {
+                    assert!(!a.conflicts_with(b), "{:?} vs {:?} on {:?}", a, b, round);
+                }
+            }
+
+            // At most one value of a non-empty round reaches the lowest
+            // valid threshold (an empty round trivially meets any)
+            let agrees = votes.iter().filter(|v| **v).count() as u64;
+            if n > 0 {
+                let t = Threshold::PerMille(501);
+                assert!(!(meets_threshold(agrees, n, t) && meets_threshold(n - agrees, n, t)));
+            }
+        }
+    }
+
+    /// Variance of outputs scaled by 100, as `variance_scaled` computes it
+    /// (rounded-down mean)
+    fn variance_scaled(outputs: &[u64]) -> u64 {
+        let n = outputs.len() as u64;
+        let mu = outputs.iter().sum::<u64>() / n;
+        let ssd: u64 = outputs.iter().map(|&x| x.abs_diff(mu).pow(2)).sum();
+        ssd * 100 / n
+    }
+
+    /// `constitutional_halt_decision` in `variance_halt.rs`
+    fn variance_halts(variance: u64, baseline: u64) -> bool {
+        variance > 625 * baseline / 100 || variance > 10000
+    }
+
+    #[test]
+    fn test_no_spurious_halt() {
+        let engine = ConsensusEngine::default();
+
+        // The healthy 500-sample baseline: 464 agreeing votes (92.8%)
+        let outcome = engine.decide(&votes(464, 36));
+        assert_eq!(
+            outcome,
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 928
+            }
+        );
+        assert_eq!(engine.constitutional_halt(928, 100), None);
+
+        // no_spurious_halt: at least 2/3 agreement and variance within twice
+        // a baseline of at most 5000 never halt, on either metric
+        let mut rng = Rng(0x5eed_1582);
+        for _ in 0..1000 {
+            let n = 3 + rng.below(498);
+            let min_agrees = (2 * n).div_ceil(3);
+            let agrees = min_agrees + rng.below(n + 1 - min_agrees);
+            let outcome = engine.decide(&votes(agrees as usize, (n - agrees) as usize));
+            let agreement_pct = match outcome {
+                ConsensusOutcome::Agreed {
+                    value: true,
+                    agreement_pct,
+                } => agreement_pct,
+                other => panic!("{} of {} agree but decided {:?}", agrees, n, other),
+            };
+
+            // Outputs spread over w + 1 values vary by at most 25 w^2
+            // (scaled), plus at most 100 from the rounded-down mean
+            let w = 1 + rng.below(14);
+            let bound = 25 * w * w + 100;
+            let baseline = bound.div_ceil(2) + rng.below(5001 - bound.div_ceil(2));
+            let outputs: Vec<u64> = (0..n).map(|_| 9280 + rng.below(w + 1)).collect();
+            let variance = variance_scaled(&outputs);
+            assert!(variance <= 2 * baseline);
+
+            assert!(!variance_halts(variance, baseline));
+            let ratio = variance * 100 / baseline;
+            assert!(ratio <= 200);
+            assert_eq!(engine.constitutional_halt(agreement_pct, ratio), None);
+        }
+    }
+
+    #[test]
+    fn test_halt_correctness_baseline_bound() {
+        // variance_halt_correctness: over a baseline of at most 3500, a
+        // round that proceeds varies by less than 22500 (sigma < 15)
+        let engine = ConsensusEngine::default();
+        let votes = votes(5, 0);
+        let mut rng = Rng(0x5eed_1635);
+        let mut proceeded = 0;
+        for _ in 0..2000 {
+            let n = 3 + rng.below(50) as usize;
+            let centre = rng.below(10_001);
+            let spread = 1 + rng.below(100);
+            let outputs: Vec<u64> = (0..n)
+                .map(|_| (centre + rng.below(spread)).min(10_000))
+                .collect();
+            let baseline = 1 + rng.below(3500);
+            if engine.final_verdict(&votes, &outputs, baseline) == engine.decide(&votes) {
+                proceeded += 1;
+                assert!(variance_scaled(&outputs) < 22500, "{:?}", outputs);
+            }
+        }
+        assert!(proceeded > 200, "{} rounds proceeded", proceeded);
+
+        // The old counterexample: a variance of 50000 (sigma ~22) is 5x a
+        // baseline of 10000, so the round proceeds without the outputs
+        // agreeing to sigma < 15. The theorem no longer covers it; within
+        // its bound the same outputs halt.
+        let outputs = [9280, 9280, 9280, 9280, 9280, 9340];
+        assert_eq!(variance_scaled(&outputs), 50000);
+        assert_eq!(
+            engine.final_verdict(&votes, &outputs, 10_000),
+            engine.decide(&votes)
+        );
+        assert_eq!(
+            engine.final_verdict(&votes, &outputs, 3500),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::VarianceExceeded { ratio_scaled: 1428 }
+            }
+        );
+        // constitutional_halt_decision's low-agreement proxy halts it at
+        // any baseline
+        assert!(variance_halts(50000, 10_000));
+    }
+
+    #[test]
+    fn test_final_verdict_variance_explodes() {
+        // final_verdict_variance_halt: five agreeing votes, one output far
+        // off the others
+        let engine = ConsensusEngine::default();
+        let votes = votes(5, 0);
+        let outputs = [9280, 9280, 9281, 9280, 100];
+        let variance = variance_scaled(&outputs);
+        assert_eq!(output_variance_scaled(&outputs), variance);
+        let ratio = variance * 100 / 200;
+        assert!(ratio > 625);
+        assert_eq!(
+            engine.decide(&votes),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 1000
+            }
+        );
+        assert_eq!(
+            engine.final_verdict(&votes, &outputs, 200),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::VarianceExceeded {
+                    ratio_scaled: ratio
+                }
+            }
+        );
+
+        // The same votes over tight outputs agree
+        assert_eq!(
+            engine.final_verdict(&votes, &[9280, 9281, 9280, 9279, 9280], 200),
+            engine.decide(&votes)
+        );
+
+        // Any spread over a zero baseline halts; none does not
+        assert_eq!(
+            engine.final_verdict(&votes, &[1, 2], 0),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::VarianceExceeded {
+                    ratio_scaled: u64::MAX
+                }
+            }
+        );
+        assert_eq!(
+            engine.final_verdict(&votes, &[7, 7], 0),
+            engine.decide(&votes)
+        );
+        // Outputs accumulated as they arrive reach the same verdict
+        let mut online = OnlineVariance::new();
+        for output in outputs {
+            online.push(output).unwrap();
+        }
+        assert_eq!(
+            engine.final_verdict_online(&votes, &online, 200),
+            engine.final_verdict(&votes, &outputs, 200)
+        );
+        online.reset();
+        assert_eq!(
+            engine.final_verdict_online(&votes, &online, 200),
+            engine.decide(&votes)
+        );
+        // Extreme outputs saturate instead of overflowing
+        assert!(matches!(
+            engine.final_verdict(&votes, &[0, u64::MAX], 1),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::VarianceExceeded {
+                    ratio_scaled: u64::MAX
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn test_final_verdict_unifies_halts() {
+        // final_verdict_unifies_halts: the verdict halts exactly when the
+        // votes halt or constitutional_halt fires on the vote share and
+        // variance ratio, and otherwise is the votes' agreement
+        let mut rng = Rng(0x5eed_1592);
+        for _ in 0..5000 {
+            let engine = random_engine(&mut rng);
+            let n = 1 + rng.below(40);
+            let agrees = rng.below(n + 1);
+            let votes = votes(agrees as usize, (n - agrees) as usize);
+            let spread = 1 + rng.below(3000);
+            let outputs: Vec<u64> = (0..n).map(|_| 5000 + rng.below(spread)).collect();
+            let baseline = rng.below(20_000);
+
+            let decided = engine.decide(&votes);
+            let share = match decided {
+                ConsensusOutcome::Agreed { agreement_pct, .. }
+                | ConsensusOutcome::Halted {
+                    reason: HaltReason::NoSupermajority { agreement_pct },
+                } => agreement_pct,
+                other => panic!("{} votes decided {:?}", n, other),
+            };
+            let ratio = variance_ratio_scaled(variance_scaled(&outputs), baseline);
+            let fires = engine.constitutional_halt(share, ratio).is_some();
+
+            let verdict = engine.final_verdict(&votes, &outputs, baseline);
+            let halted = matches!(verdict, ConsensusOutcome::Halted { .. });
+            assert_eq!(
+                halted,
+                matches!(decided, ConsensusOutcome::Halted { .. }) || fires
+            );
+            if !halted {
+                assert_eq!(verdict, decided);
+            }
+        }
+    }
+
+    #[test]
+    fn test_final_verdict_safety_and_liveness() {
+        let engine = ConsensusEngine::default();
+        let mut rng = Rng(0x5eed_1593);
+        for _ in 0..1000 {
+            // final_verdict_safety: at least 2/3 agreeing and variance
+            // within twice the baseline agree on the votes
+            let n = 3 + rng.below(200);
+            let min_agrees = (2 * n).div_ceil(3);
+            let agrees = min_agrees + rng.below(n + 1 - min_agrees);
+            let round = votes(agrees as usize, (n - agrees) as usize);
+            let outputs: Vec<u64> = (0..n).map(|_| 9280 + rng.below(20)).collect();
+            let baseline = variance_scaled(&outputs).div_ceil(2).max(1) + rng.below(1000);
+            assert_eq!(
+                engine.final_verdict(&round, &outputs, baseline),
+                engine.decide(&round)
+            );
+            assert!(matches!(
+                engine.decide(&round),
+                ConsensusOutcome::Agreed { value: true, .. }
+            ));
+
+            // final_verdict_liveness: with f past halt_guaranteed and the
+            // Byzantine voters backing neither side, the round halts
+            let f = n / 3 + 1;
+            assert!(engine.halt_guaranteed(n, f));
+            let yes = (n - f).min(n / 2 + rng.below(n - f - n / 2 + 1));
+            let split = votes(yes as usize, (n - yes) as usize);
+            if yes <= n - f && n - yes <= n - f {
+                assert!(matches!(
+                    engine.final_verdict(&split, &outputs, baseline),
+                    ConsensusOutcome::Halted { .. }
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_honest_majority_is_strict() {
+        // (honest_count, fewest agreeing honest voters for a majority)
+        let table = [
+            (2, 2),
+            (3, 2),
+            (4, 3),
+            (5, 3),
+            (6, 4),
+            (7, 4),
+            (8, 5),
+            (9, 5),
+        ];
+        for (honest_count, minimum) in table {
+            for f in [0, 1, 3] {
+                let n = honest_count + f;
+                for honest_agrees in 0..=honest_count {
+                    let strict = honest_agrees as f64 > honest_count as f64 / 2.0;
+                    assert_eq!(honest_majority(n, f, honest_agrees), strict);
+                    assert_eq!(
+                        honest_majority(n, f, honest_agrees),
+                        honest_agrees >= minimum
+                    );
+                    // lemma_honest_majority_strict: the division form
+                    // honest_count / 2 + 1 agrees
+                    assert_eq!(strict, honest_agrees > honest_count / 2);
+                }
+            }
+        }
+        // A tie is not a majority
+        assert!(!honest_majority(4, 0, 2));
+        assert!(!honest_majority(3, 3, 0));
+    }
+
+    #[test]
+    fn test_byzantine_safety_any_positions() {
+        // byzantine_safety: for every n <= 7, every placement of the
+        // Byzantine voters and every vote they and the honest voters cast,
+        // an honest majority for v is never overruled by an agreement on !v
+        let engines: Vec<ConsensusEngine> = [501, 600, 670, 750, 1000]
+            .iter()
+            .map(|&t| {
+                engine(&Thresholds {
+                    consensus: Threshold::PerMille(t),
+                    ..Thresholds::default()
+                })
+            })
+            .collect();
+        let mut checked = 0;
+        for n in 3..=7u64 {
+            for f in (0..=n).filter(|&f| byzantine_safe(n, f)) {
+                for round in byzantine_rounds(n as u32, f as u32) {
+                    for v in [true, false] {
+                        let honest_v = round.honest_votes(v);
+                        if !honest_majority(n, f, honest_v) {
+                            continue;
+                        }
+                        assert!(honest_v > f);
+                        for engine in engines.iter().filter(|e| e.tolerates(n, f)) {
+                            checked += 1;
+                            let outcome = engine.decide(&round.votes);
+                            assert!(
+                                !matches!(outcome, ConsensusOutcome::Agreed { value, .. } if value != v),
+                                "{:?} at {}",
+                                round,
+                                engine.threshold()
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn test_byzantine_safety_deployment_sizes() {
+        // byzantine_safety_n7_f2 and byzantine_safety_n10_f3: wherever the
+        // f Byzantine voters sit and whatever anybody votes, the default
+        // engine never agrees against the honest majority
+        let engine = ConsensusEngine::default();
+        for (n, f) in [(7u32, 2u32), (10, 3)] {
+            let (n64, f64) = (u64::from(n), u64::from(f));
+            assert!(byzantine_safe(n64, f64) && engine.tolerates(n64, f64));
+            let mut rounds = 0;
+            for round in byzantine_rounds(n, f) {
+                rounds += 1;
+                let outcome = engine.decide(&round.votes);
+                // n - f is odd, so one value always holds the honest majority
+                let v = honest_majority(n64, f64, round.honest_votes(true));
+                assert!(honest_majority(n64, f64, round.honest_votes(v)));
+                assert!(
+                    !matches!(outcome, ConsensusOutcome::Agreed { value, .. } if value != v),
+                    "{:?} decided {:?}",
+                    round,
+                    outcome
+                );
+            }
+            assert_eq!(rounds, masks(n, f).count() << n);
+        }
+    }
+
+    #[test]
+    fn test_weighted_single_trusted_voter() {
+        // weighted_consensus_well_defined: with every trust but one zero
+        // the sum is positive and that voter's weight is the whole of it
+        let mut rng = Rng(0x5eed_0001);
+        for _ in 0..200 {
+            let n = 3 + rng.below(10) as usize;
+            let trusted = rng.below(n as u64) as usize;
+            let trust = 1 + rng.below(1000);
+            let round: Vec<(bool, u64, u64)> = (0..n)
+                .map(|i| {
+                    let vote = rng.below(2) == 1;
+                    (vote, if i == trusted { trust } else { 0 }, rng.below(12))
+                })
+                .collect();
+            assert_eq!(
+                ConsensusEngine::default().decide_weighted(&round),
+                ConsensusOutcome::Agreed {
+                    value: round[trusted].0,
+                    agreement_pct: 1000
+                },
+                "{:?}",
+                round
+            );
+        }
+    }
+
+    #[test]
+    fn test_latency_collapse_drops_agent() {
+        // A latency-heavy deployment: 0.3 accuracy, 0.2 calibration, 0.5
+        // latency
+        let composite = Thresholds {
+            accuracy_weight: 300,
+            calibration_weight: 200,
+            latency_weight: 500,
+            ..Thresholds::default()
+        };
+        let accuracy_only = Thresholds::default();
+        let full = AgentTrust::default();
+        let mut slow = AgentTrust::builder()
+            .current(500)
+            .calibration(400)
+            .build()
+            .unwrap();
+
+        // While it answers in time the slow agent's dissent halts the round
+        let weighted = engine(&composite);
+        assert_eq!(weighted.voting_trust(&slow).value(), 730);
+        let round =
+            |slow: &AgentTrust| weighted.decide_agents(&[(true, &full, 2), (false, slow, 2)]);
+        assert_eq!(
+            round(&slow),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 578 }
+            }
+        );
+
+        // Its answers stop arriving: the latency score collapses and drags
+        // the composite below the detection threshold in 6 rounds
+        let mut rounds = 0;
+        while weighted.participates(&slow) {
+            slow.record_component(TrustComponent::Latency, Observation::incorrect(), 300)
+                .unwrap();
+            rounds += 1;
+        }
+        assert_eq!(rounds, 6);
+        assert_eq!(slow.component(TrustComponent::Latency).value(), 118);
+        assert_eq!(weighted.voting_trust(&slow).value(), 289);
+        assert_eq!(
+            round(&slow),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 1000
+            }
+        );
+
+        // Weighing accuracy alone, the agent still votes at 0.5: its dissent
+        // counts, leaving true two thirds of the weight rather than all of it
+        let unweighted = engine(&accuracy_only);
+        assert!(unweighted.participates(&slow));
+        assert_eq!(unweighted.voting_trust(&slow), slow.current());
+        assert_eq!(
+            unweighted.decide_agents(&[(true, &full, 2), (false, &slow, 2)]),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 666
+            }
+        );
+    }
+
+    #[test]
+    fn test_weighted_with_custom_model() {
+        let mut models = ModelRegistry::builtin();
+        let claude = ModelSpec {
+            id: 5,
+            weight_scaled: 200,
+            family: ModelFamily::Claude,
+        };
+        models.insert("claude-sonnet", claude).unwrap();
+        let engine = ConsensusEngine::default();
+
+        // The built-in registry has no model 5, so it weighs 100 and the
+        // gpt-4o pair outweighs it: 300 of 400
+        let round = [(true, 1000, 5), (false, 1000, 2), (false, 1000, 2)];
+        assert_eq!(
+            engine.decide_weighted(&round),
+            ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: 750
+            }
+        );
+        // Registered at 200 it holds 200 of 500, and the round halts
+        assert_eq!(
+            engine.decide_weighted_with(&round, &models),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 600 }
+            }
+        );
+        // The built-in models weigh the same either way
+        let builtin = [(true, 900, 2), (true, 900, 3), (false, 100, 9)];
+        assert_eq!(
+            engine.decide_weighted_with(&builtin, &models),
+            engine.decide_weighted(&builtin)
+        );
+
+        // An engine holding the registry weighs by it throughout
+        let registered = ConsensusEngine::default().with_models(models.clone());
+        assert_eq!(registered.models(), &models);
+        assert_eq!(
+            registered.decide_weighted(&round),
+            engine.decide_weighted_with(&round, &models)
+        );
+        let full = AgentTrust::default();
+        assert_eq!(
+            registered.decide_agents(&[(true, &full, 5), (false, &full, 2), (false, &full, 2)]),
+            registered.decide_weighted(&round)
+        );
+        assert_eq!(
+            ConsensusEngine::default().models(),
+            &ModelRegistry::builtin()
+        );
+    }
+
+    #[test]
+    fn test_weighted_does_not_overflow() {
+        let round = vec![(true, u64::MAX, 0); 1000];
+        assert_eq!(
+            ConsensusEngine::default().decide_weighted(&round),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 1000
+            }
+        );
+    }
+
+    #[test]
+    fn test_one_of_three_outlier() {
+        let answers = [(1, 9280), (2, 9310), (3, 4000)];
+        let consensus_value = median(&[9280, 9310, 4000]).unwrap();
+        assert_eq!(consensus_value, 9280);
+        assert_eq!(identify_suspects(&answers, consensus_value, 100), vec![3]);
+
+        let decays = suspect_decays(&answers, consensus_value, 100, 100, 50);
+        assert_eq!(
+            decays,
+            vec![TrustDecay {
+                agent: 3,
+                decay_rate: 100,
+                floor: 50
+            }]
+        );
+        assert_eq!(decays[0].apply(1000), Ok(900));
+        assert_eq!(decays[0].apply(54), Ok(50));
+        assert_eq!(decays[0].apply(0), Ok(0));
+        let slash = TrustDecay {
+            agent: 3,
+            decay_rate: 1000,
+            floor: 0,
+        };
+        assert_eq!(slash.apply(800), Ok(0));
+        assert!(slash.apply(1001).is_err());
+    }
+
+    #[test]
+    fn test_suspect_tolerance_boundary() {
+        // A deviation of exactly the tolerance is not suspicious, on either
+        // side of the consensus value
+        let answers = [(1, 500), (2, 400), (3, 600), (4, 500), (5, 500)];
+        assert!(identify_suspects(&answers, 500, 100).is_empty());
+        assert_eq!(identify_suspects(&answers, 500, 99), vec![2, 3]);
+        assert_eq!(identify_suspects(&answers, 501, 100), vec![2]);
+        assert_eq!(identify_suspects(&answers, 499, 100), vec![3]);
+
+        // Extreme values do not overflow
+        let answers = [(1, 0), (2, u64::MAX), (3, 0)];
+        assert_eq!(identify_suspects(&answers, 0, u64::MAX - 1), vec![2]);
+        assert!(identify_suspects(&answers, 0, u64::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_dispersed_round_accuses_nobody() {
+        // Every answer is far from the others: there is no honest cluster
+        let answers = [(1, 1000), (2, 5000), (3, 9000)];
+        let consensus_value = median(&[1000, 5000, 9000]).unwrap();
+        assert!(identify_suspects(&answers, consensus_value, 100).is_empty());
+        assert!(suspect_decays(&answers, consensus_value, 100, 100, 50).is_empty());
+
+        // Half the round deviating is not a minority either
+        let answers = [(1, 100), (2, 100), (3, 900), (4, 900)];
+        assert_eq!(median(&[100, 100, 900, 900]), Some(100));
+        assert!(identify_suspects(&answers, 100, 50).is_empty());
+
+        assert!(identify_suspects(&[], 0, 0).is_empty());
+        assert_eq!(median(&[]), None);
+    }
+
+    #[test]
+    fn test_no_false_accusation() {
+        // no_false_accusation: with f < n/3 and honest answers within
+        // tolerance of each other, the median accuses exactly the Byzantine
+        // agents that stray from it
+        let mut rng = Rng(0x5eed_1583);
+        for _ in 0..1000 {
+            let n = 1 + rng.below(60);
+            let f = rng.below(n.div_ceil(3));
+            let tolerance = rng.below(200);
+            let center = rng.below(10_000);
+            let answers: Vec<(AgentId, u64)> = (0..n)
+                .map(|agent| {
+                    let answer = if agent < f {
+                        rng.below(20_000)
+                    } else {
+                        center + rng.below(tolerance + 1)
+                    };
+                    (agent, answer)
+                })
+                .collect();
+            let values: Vec<u64> = answers.iter().map(|&(_, answer)| answer).collect();
+            let consensus_value = median(&values).unwrap();
+
+            let expected: Vec<AgentId> = answers
+                .iter()
+                .filter(|&&(_, answer)| answer.abs_diff(consensus_value) > tolerance)
+                .map(|&(agent, _)| agent)
+                .collect();
+            assert!(expected.iter().all(|&agent| agent < f));
+            assert_eq!(
+                identify_suspects(&answers, consensus_value, tolerance),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_n_three_sufficiency() {
+        // Three models outvote one fault, holding 2/3 of the votes: the
+        // default threshold, but not 668/1000
+        assert!(probabilistic_tolerance(3, 1, Threshold::PerMille(666)));
+        assert!(probabilistic_tolerance(3, 1, Threshold::TwoThirds));
+        assert!(!probabilistic_tolerance(3, 1, Threshold::PerMille(668)));
+        assert_eq!(
+            ConsensusEngine::default().decide(&[true, true, false]),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 666
+            }
+        );
+        assert!(!probabilistic_tolerance(3, 2, Threshold::PerMille(0)));
+        assert!(probabilistic_tolerance(5, 2, Threshold::PerMille(600)));
+
+        // Classical BFT needs four
+        assert!(!byzantine_safe(3, 1));
+        assert!(byzantine_safe(4, 1));
+        assert!(byzantine_safe(3, 0));
+        assert!(!byzantine_safe(u64::MAX, u64::MAX / 3 + 1));
+    }
+
+    #[test]
+    fn test_agreement_ratio() {
+        assert_eq!(agreement_ratio_scaled(2, 3), 666);
+        assert_eq!(agreement_ratio_scaled(415, 500), 830);
+        assert_eq!(agreement_ratio_scaled(0, 0), 0);
+        assert_eq!(agreement_ratio_scaled(u64::MAX, u64::MAX), 1000);
+    }
+}
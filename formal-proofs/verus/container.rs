@@ -0,0 +1,238 @@
+//! # Container Execution
+//!
+//! Runs Verus inside `docker run` or `podman run` for hermetic verification.
+//!
+//! Only two host directories are visible to the container:
+//!
+//! - the proof directory, mounted read-only at `/proofs`
+//! - the artifacts directory, mounted read-write at `/artifacts`
+//!
+//! The container runs without network access. Paths in prover diagnostics
+//! refer to the container mounts and are translated back to host paths
+//! before rendering.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::diagnostics::Diagnostic;
+use crate::error::ShieldError;
+
+/// Container mount point of the proof directory
+pub const PROOF_MOUNT: &str = "/proofs";
+
+/// Container mount point of the artifacts directory
+pub const ARTIFACTS_MOUNT: &str = "/artifacts";
+
+/// Container runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Runtime {
+    Docker,
+    Podman,
+}
+
+impl Runtime {
+    /// Executable name
+    pub fn program(&self) -> &'static str {
+        match self {
+            Runtime::Docker => "docker",
+            Runtime::Podman => "podman",
+        }
+    }
+
+    /// First available runtime, preferring Docker
+    pub fn detect() -> Option<Self> {
+        [Runtime::Docker, Runtime::Podman]
+            .into_iter()
+            .find(|runtime| runtime.is_available())
+    }
+
+    fn is_available(&self) -> bool {
+        Command::new(self.program())
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success())
+    }
+}
+
+/// A container sandbox for prover runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sandbox {
+    pub runtime: Runtime,
+    pub image: String,
+    /// Host proof directory (absolute)
+    pub proof_dir: PathBuf,
+    /// Host artifacts directory (absolute)
+    pub artifacts_dir: PathBuf,
+}
+
+impl Sandbox {
+    /// Container path of a host proof file (None if outside the proof directory)
+    pub fn container_path(&self, host_path: &Path) -> Option<PathBuf> {
+        let relative = host_path.strip_prefix(&self.proof_dir).ok()?;
+        Some(Path::new(PROOF_MOUNT).join(relative))
+    }
+
+    /// Host path of a container path; paths outside the mounts are returned unchanged
+    pub fn host_path(&self, container_path: &Path) -> PathBuf {
+        if let Ok(relative) = container_path.strip_prefix(PROOF_MOUNT) {
+            self.proof_dir.join(relative)
+        } else if let Ok(relative) = container_path.strip_prefix(ARTIFACTS_MOUNT) {
+            self.artifacts_dir.join(relative)
+        } else {
+            container_path.to_path_buf()
+        }
+    }
+
+    /// `<runtime> run` invocation of `verus` on a host proof file
+    pub fn verus_command(&self, host_path: &Path) -> Result<Command, ShieldError> {
+        let target = self
+            .container_path(host_path)
+            .ok_or_else(|| ShieldError::ConfigInvalid {
+                context: "container mount".to_string(),
+                message: format!(
+                    "{} is outside the mounted proof directory {}",
+                    host_path.display(),
+                    self.proof_dir.display()
+                ),
+            })?;
+
+        let mut command = Command::new(self.runtime.program());
+        command
+            .args(["run", "--rm", "--network", "none"])
+            .arg("--volume")
+            .arg(mount(&self.proof_dir, PROOF_MOUNT, "ro"))
+            .arg("--volume")
+            .arg(mount(&self.artifacts_dir, ARTIFACTS_MOUNT, "rw"))
+            .args(["--workdir", PROOF_MOUNT])
+            .arg(&self.image)
+            .arg("verus")
+            .arg(target);
+        Ok(command)
+    }
+
+    /// Rewrite container paths in diagnostic spans to host paths
+    pub fn translate(&self, diagnostics: &mut [Diagnostic]) {
+        for span in diagnostics.iter_mut().flat_map(|d| d.spans.iter_mut()) {
+            span.file = self.host_path(&span.file);
+        }
+    }
+
+    /// Content digest of the image (`sha256:...`), as reported by the runtime
+    pub fn image_digest(&self) -> Result<String, ShieldError> {
+        let output = Command::new(self.runtime.program())
+            .args(["image", "inspect", "--format", "{{.Id}}"])
+            .arg(&self.image)
+            .output()
+            .map_err(|err| ShieldError::spawn(self.runtime.program(), err))?;
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "cannot inspect image {}: {}",
+                self.image,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))
+            .into());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+fn mount(host: &Path, container: &str, mode: &str) -> String {
+    format!("{}:{}:{}", host.display(), container, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics;
+
+    fn sandbox() -> Sandbox {
+        Sandbox {
+            runtime: Runtime::Podman,
+            image: "ghcr.io/aevion/verus:1.0".to_string(),
+            proof_dir: PathBuf::from("/home/ci/shield/src"),
+            artifacts_dir: PathBuf::from("/home/ci/shield/target/verification"),
+        }
+    }
+
+    #[test]
+    fn test_verus_command() {
+        let command = sandbox()
+            .verus_command(Path::new("/home/ci/shield/src/trust_bounds.rs"))
+            .unwrap();
+        assert_eq!(command.get_program(), "podman");
+        let args: Vec<_> = command
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            args,
+            [
+                "run",
+                "--rm",
+                "--network",
+                "none",
+                "--volume",
+                "/home/ci/shield/src:/proofs:ro",
+                "--volume",
+                "/home/ci/shield/target/verification:/artifacts:rw",
+                "--workdir",
+                "/proofs",
+                "ghcr.io/aevion/verus:1.0",
+                "verus",
+                "/proofs/trust_bounds.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_target_outside_proof_dir_rejected() {
+        let err = sandbox()
+            .verus_command(Path::new("/etc/passwd"))
+            .unwrap_err();
+        assert!(matches!(err, ShieldError::ConfigInvalid { .. }));
+    }
+
+    #[test]
+    fn test_path_translation() {
+        let sandbox = sandbox();
+        assert_eq!(
+            sandbox.host_path(Path::new("/proofs/nested/variance_halt.rs")),
+            Path::new("/home/ci/shield/src/nested/variance_halt.rs")
+        );
+        assert_eq!(
+            sandbox.host_path(Path::new("/artifacts/log.txt")),
+            Path::new("/home/ci/shield/target/verification/log.txt")
+        );
+        // Paths that only share a prefix string are not mount paths
+        assert_eq!(
+            sandbox.host_path(Path::new("/proofsx/a.rs")),
+            Path::new("/proofsx/a.rs")
+        );
+        assert_eq!(
+            sandbox.host_path(Path::new("/usr/lib/vstd/seq.rs")),
+            Path::new("/usr/lib/vstd/seq.rs")
+        );
+    }
+
+    #[test]
+    fn test_translate_diagnostics() {
+        let output = "\
+error: assertion failed
+  --> /proofs/variance_halt.rs:12:5
+   |
+12 |     assert(x);
+   |     ^^^^^^^^^
+";
+        let mut parsed = diagnostics::parse(output);
+        sandbox().translate(&mut parsed);
+        assert_eq!(
+            parsed[0].spans[0].file,
+            Path::new("/home/ci/shield/src/variance_halt.rs")
+        );
+    }
+}
@@ -0,0 +1,652 @@
+//! # Core Types
+//!
+//! Verification statuses and per-module reports, with no dependency on
+//! `std`. This module only needs `alloc`, so it builds for targets without an
+//! operating system (e.g. the attestation MCU) when the crate is compiled
+//! with `--no-default-features`.
+//!
+//! It also holds the allocation-free part of a consensus round: the
+//! [`ConsensusOutcome`], the [`Threshold`] and its exact tests, and
+//! [`decide_consensus`], so such a device decides votes as the `std`
+//! consensus engine does. The `consensus` module re-exports them next to
+//! the engine.
+//!
+//! The types here are re-exported from the crate root. Subprocess,
+//! filesystem and wall-clock concerns live in the `std`-only modules.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use ::core::cmp::Ordering;
+use ::core::fmt;
+use ::core::str::FromStr;
+use ::core::time::Duration;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Verification status
+///
+/// Serialized and displayed as a snake_case string (`"verified"`,
+/// `"partial"`, `"degraded"`, `"failed"`, `"not_run"`, `"skipped"`).
+/// Ordered by severity:
+/// `Verified < Skipped < NotRun < Partial < Unknown < Degraded < Failed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum VerificationStatus {
+    /// All proofs verified successfully
+    Verified,
+    /// Some proofs passed, others are pending
+    Partial,
+    /// Only non-critical theorems failed (see [`Severity`]). Produced by the
+    /// summary roll-up, not by individual modules.
+    Degraded,
+    /// Verification failed
+    Failed,
+    /// Not attempted (yet): the initial status, or the tool was unavailable
+    NotRun,
+    /// Deliberately not run (e.g. `--no-tests`)
+    Skipped,
+    /// Status written by a newer version of this library. Never produced
+    /// locally; rolls up like `Partial`.
+    #[cfg_attr(feature = "serde", serde(other))]
+    Unknown,
+}
+
+/// How much a theorem's failure weighs in the summary roll-up
+///
+/// Serialized as `"critical"`, `"standard"` or `"informational"`. Ordered
+/// `Informational < Standard < Critical`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Severity {
+    /// Supporting checks (e.g. axiom budget checks)
+    Informational,
+    /// Ordinary theorems and lemmas
+    #[default]
+    Standard,
+    /// Core safety theorems: any failure fails the whole run
+    Critical,
+}
+
+impl Severity {
+    /// Stable lowercase name
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Informational => "informational",
+            Severity::Standard => "standard",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Why a consensus round halted (`HaltReason` in the specification)
+///
+/// Serialized with a snake_case `reason` tag alongside the variant's
+/// figure, e.g. `{"reason": "no_supermajority", "agreement_pct": 600}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "reason", rename_all = "snake_case"))]
+pub enum HaltReason {
+    /// Neither value reached the consensus threshold; `agreement_pct` is
+    /// the majority value's share (scaled by 1000)
+    NoSupermajority { agreement_pct: u64 },
+    /// Output variance exceeded the halt multiple of the baseline;
+    /// `ratio_scaled` is current / baseline variance (scaled by 100)
+    VarianceExceeded { ratio_scaled: u64 },
+    /// Too few votes to decide; `n` were cast
+    InsufficientVoters { n: u64 },
+    /// Every vote carries zero weight; `max_trust` is the highest voter
+    /// trust (scaled by 1000)
+    TrustCollapse { max_trust: u64 },
+}
+
+impl fmt::Display for HaltReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            HaltReason::NoSupermajority { agreement_pct } => write!(
+                f,
+                "no supermajority (agreement {}.{}%)",
+                agreement_pct / 10,
+                agreement_pct % 10
+            ),
+            HaltReason::VarianceExceeded { ratio_scaled } => write!(
+                f,
+                "variance exceeded ({}.{:02}x baseline)",
+                ratio_scaled / 100,
+                ratio_scaled % 100
+            ),
+            HaltReason::InsufficientVoters { n } => {
+                write!(f, "insufficient voters ({} cast)", n)
+            }
+            HaltReason::TrustCollapse { max_trust } => write!(
+                f,
+                "trust collapse (max trust {}.{:03})",
+                max_trust / 1000,
+                max_trust % 1000
+            ),
+        }
+    }
+}
+
+/// Result for one theorem or lemma in a module
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TheoremResult {
+    pub name: String,
+    pub status: VerificationStatus,
+    /// Solver time for this theorem, if the prover reported it
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub duration: Option<Duration>,
+    /// Weight of a failure in the summary roll-up
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub severity: Severity,
+}
+
+/// Verification result for one proof module
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModuleReport {
+    pub name: String,
+    /// Module-level result (prover outcome, unreviewed escapes, ...).
+    /// See [`derived_status`](Self::derived_status) for the rolled-up status.
+    pub status: VerificationStatus,
+    /// Per-theorem results, in source order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub theorems: Vec<TheoremResult>,
+    /// Prover duration (None if the prover did not run)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub duration: Option<Duration>,
+    /// Axioms the module's theorems rest on
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub axioms: Vec<String>,
+}
+
+impl ModuleReport {
+    /// A module that has not been run, with no theorem results
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: VerificationStatus::NotRun,
+            theorems: Vec::new(),
+            duration: None,
+            axioms: Vec::new(),
+        }
+    }
+
+    /// Module status rolled up from theorem-level data.
+    ///
+    /// - Failed if the module or any theorem failed
+    /// - NotRun/Skipped if the module was not run or was skipped
+    /// - Partial if no theorems were found: an empty module proves nothing
+    /// - Verified if the module and every theorem verified
+    /// - Partial otherwise
+    pub fn derived_status(&self) -> VerificationStatus {
+        let any_failed = self
+            .theorems
+            .iter()
+            .any(|t| t.status == VerificationStatus::Failed);
+        if self.status == VerificationStatus::Failed || any_failed {
+            VerificationStatus::Failed
+        } else if matches!(
+            self.status,
+            VerificationStatus::NotRun | VerificationStatus::Skipped
+        ) {
+            self.status
+        } else if self.theorems.is_empty() {
+            VerificationStatus::Partial
+        } else if self.status == VerificationStatus::Verified
+            && self
+                .theorems
+                .iter()
+                .all(|t| t.status == VerificationStatus::Verified)
+        {
+            VerificationStatus::Verified
+        } else {
+            VerificationStatus::Partial
+        }
+    }
+
+    /// Theorems that failed
+    pub fn failed_theorems(&self) -> impl Iterator<Item = &TheoremResult> {
+        self.theorems
+            .iter()
+            .filter(|t| t.status == VerificationStatus::Failed)
+    }
+}
+
+impl VerificationStatus {
+    /// Stable lowercase name
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VerificationStatus::Verified => "verified",
+            VerificationStatus::Partial => "partial",
+            VerificationStatus::Degraded => "degraded",
+            VerificationStatus::Failed => "failed",
+            VerificationStatus::NotRun => "not_run",
+            VerificationStatus::Skipped => "skipped",
+            VerificationStatus::Unknown => "unknown",
+        }
+    }
+
+    /// The most severe status, or `Verified` if `statuses` is empty
+    pub fn worst<I>(statuses: I) -> VerificationStatus
+    where
+        I: IntoIterator<Item = VerificationStatus>,
+    {
+        statuses
+            .into_iter()
+            .max()
+            .unwrap_or(VerificationStatus::Verified)
+    }
+
+    fn severity(self) -> u8 {
+        match self {
+            VerificationStatus::Verified => 0,
+            VerificationStatus::Skipped => 1,
+            VerificationStatus::NotRun => 2,
+            VerificationStatus::Partial => 3,
+            VerificationStatus::Unknown => 4,
+            VerificationStatus::Degraded => 5,
+            VerificationStatus::Failed => 6,
+        }
+    }
+}
+
+impl Ord for VerificationStatus {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.severity().cmp(&other.severity())
+    }
+}
+
+impl PartialOrd for VerificationStatus {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for VerificationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error parsing a [`VerificationStatus`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStatusError {
+    /// The rejected input
+    pub input: String,
+}
+
+impl fmt::Display for ParseStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid verification status '{}' (expected verified, partial, degraded, \
+             failed, not_run, skipped or unknown)",
+            self.input
+        )
+    }
+}
+
+impl ::core::error::Error for ParseStatusError {}
+
+impl FromStr for VerificationStatus {
+    type Err = ParseStatusError;
+
+    /// Parse a status name, ignoring case
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        [
+            VerificationStatus::Verified,
+            VerificationStatus::Partial,
+            VerificationStatus::Degraded,
+            VerificationStatus::Failed,
+            VerificationStatus::NotRun,
+            VerificationStatus::Skipped,
+            VerificationStatus::Unknown,
+        ]
+        .into_iter()
+        .find(|status| status.as_str().eq_ignore_ascii_case(text.trim()))
+        .ok_or_else(|| ParseStatusError {
+            input: text.to_string(),
+        })
+    }
+}
+
+/// Outcome of a consensus round (`ConsensusOutcome` in the specification)
+///
+/// Serialized with a snake_case `outcome` tag; a halt's reason is inlined,
+/// e.g. `{"outcome": "halted", "reason": "insufficient_voters", "n": 0}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "outcome", rename_all = "snake_case"))]
+pub enum ConsensusOutcome {
+    /// Consensus on `value`, cast by `agreement_pct` of the voters
+    /// (scaled by 1000)
+    Agreed { value: bool, agreement_pct: u64 },
+    /// Constitutional halt - no consensus
+    Halted {
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        reason: HaltReason,
+    },
+}
+
+impl ConsensusOutcome {
+    /// Whether every share and trust the outcome reports is at most 1000
+    /// (`valid_outcome`; proven for `decide_consensus` by
+    /// `decide_consensus_valid`)
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            ConsensusOutcome::Agreed { agreement_pct, .. }
+            | ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct },
+            } => agreement_pct <= 1000,
+            ConsensusOutcome::Halted {
+                reason: HaltReason::TrustCollapse { max_trust },
+            } => max_trust <= 1000,
+            ConsensusOutcome::Halted { .. } => true,
+        }
+    }
+
+    /// Whether both outcomes agree, on contradictory values. Decisions over
+    /// the same votes never conflict (`no_conflicting_agreement`), whatever
+    /// their thresholds; a halt conflicts with nothing.
+    pub fn conflicts_with(&self, other: &ConsensusOutcome) -> bool {
+        match (self, other) {
+            (
+                ConsensusOutcome::Agreed { value: a, .. },
+                ConsensusOutcome::Agreed { value: b, .. },
+            ) => a != b,
+            _ => false,
+        }
+    }
+}
+
+/// Classical BFT bound: `f` faults among `n` nodes are tolerated when
+/// 3f < n (`byzantine_safe`)
+pub fn byzantine_safe(n: u64, f: u64) -> bool {
+    u128::from(f) * 3 < u128::from(n)
+}
+
+/// Majority-vote tolerance of an ensemble (`probabilistic_tolerance`): the
+/// `n - f` honest voters outnumber the `f` faulty ones and meet
+/// `agreement_threshold` ([`meets_threshold`])
+pub fn probabilistic_tolerance(n: u64, f: u64, agreement_threshold: Threshold) -> bool {
+    let (n, f) = (u128::from(n), u128::from(f));
+    2 * f < n && reaches(n - f, n, agreement_threshold)
+}
+
+/// Strict majority of the `n - f` honest voters: `2 * honest_agrees >
+/// n - f` (`honest_majority`); a tie is no majority
+pub fn honest_majority(n: u64, f: u64, honest_agrees: u64) -> bool {
+    2 * u128::from(honest_agrees) > u128::from(n.saturating_sub(f))
+}
+
+/// `agrees` out of `total` scaled by 1000 and rounded down (0 when `total`
+/// is 0)
+pub fn agreement_ratio_scaled(agrees: u64, total: u64) -> u64 {
+    if total == 0 {
+        return 0;
+    }
+    (u128::from(agrees) * 1000 / u128::from(total)) as u64
+}
+
+/// Fraction of the votes a consensus round must reach (`Threshold` in the
+/// specification)
+///
+/// Two thirds, the BFT bound and the default, is a variant of its own
+/// rather than a rounded 667/1000, so that two of three votes agree. Any
+/// other threshold is `PerMille(t)`, standing for t/1000. Serialized as
+/// `"two_thirds"` or `{"per_mille": 700}`; displayed as `2/3` or
+/// `700/1000`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Threshold {
+    /// Exactly 2/3 (`CONSENSUS_THRESHOLD`)
+    #[default]
+    TwoThirds,
+    /// t/1000
+    PerMille(u64),
+}
+
+impl Threshold {
+    /// Whether above one half and at most all votes (`valid_threshold`):
+    /// neither a minority nor one side of a tie can reach it
+    pub fn is_valid(self) -> bool {
+        match self {
+            Threshold::TwoThirds => true,
+            Threshold::PerMille(t) => (501..=1000).contains(&t),
+        }
+    }
+}
+
+impl fmt::Display for Threshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Threshold::TwoThirds => f.write_str("2/3"),
+            Threshold::PerMille(t) => write!(f, "{}/1000", t),
+        }
+    }
+}
+
+/// The fraction `threshold` stands for over the common denominator 3000
+/// (`threshold_exact`): 2000 for two thirds, `3 * t` for `PerMille(t)`.
+/// `agrees * 3000 >= threshold_exact(threshold) * total` is equivalent to
+/// [`meets_threshold`] (`lemma_threshold_exact`).
+pub fn threshold_exact(threshold: Threshold) -> u128 {
+    match threshold {
+        Threshold::TwoThirds => 2000,
+        Threshold::PerMille(t) => 3 * u128::from(t),
+    }
+}
+
+/// The rounded-down share every share meeting `threshold` reaches
+/// (`share_threshold`): 666 for two thirds, `t` for `PerMille(t)`
+pub fn share_threshold(threshold: Threshold) -> u64 {
+    (threshold_exact(threshold) / 3) as u64
+}
+
+/// Whether `agrees` of `total` reach the fraction `threshold` stands for
+/// (`meets_threshold`), by the exact comparison `agrees * 3 >= 2 * total`
+/// at two thirds and `agrees * 1000 >= t * total` at `PerMille(t)`
+pub fn meets_threshold(agrees: u64, total: u64, threshold: Threshold) -> bool {
+    reaches(u128::from(agrees), u128::from(total), threshold)
+}
+
+/// Whether `cast` votes out of `n` voters meet the quorum of at least
+/// ceil(2n/3) (`has_quorum`)
+pub fn has_quorum(cast: u64, n: u64) -> bool {
+    3 * u128::from(cast) >= 2 * u128::from(n)
+}
+
+pub(crate) fn reaches(agrees: u128, total: u128, threshold: Threshold) -> bool {
+    match threshold {
+        Threshold::TwoThirds => agrees * 3 >= 2 * total,
+        Threshold::PerMille(t) => agrees * 1000 >= u128::from(t) * total,
+    }
+}
+
+/// Decide `votes` at `threshold` (`decide_consensus`): the majority value
+/// if its share reaches the threshold ([`meets_threshold`]), otherwise a
+/// [`HaltReason::NoSupermajority`] halt. No votes halts with
+/// [`HaltReason::InsufficientVoters`]. Allocation-free, so a device without
+/// `std` decides a round exactly as the consensus engine does.
+pub fn decide_consensus(votes: &[bool], threshold: Threshold) -> ConsensusOutcome {
+    let n = votes.len() as u64;
+    if n == 0 {
+        return ConsensusOutcome::Halted {
+            reason: HaltReason::InsufficientVoters { n },
+        };
+    }
+    let agrees = votes.iter().filter(|v| **v).count() as u64;
+    // Ties go to false, which then holds exactly half and cannot reach a
+    // threshold above 500
+    let value = 2 * agrees > n;
+    let supporters = if value { agrees } else { n - agrees };
+
+    let agreement_pct = agreement_ratio_scaled(supporters, n);
+    let outcome = if meets_threshold(supporters, n, threshold) {
+        ConsensusOutcome::Agreed {
+            value,
+            agreement_pct,
+        }
+    } else {
+        ConsensusOutcome::Halted {
+            reason: HaltReason::NoSupermajority { agreement_pct },
+        }
+    };
+    debug_assert!(outcome.is_valid(), "invalid outcome {:?}", outcome);
+    outcome
+}
+
+/// Byzantine voters hold less than a third of the total weight
+/// (`byzantine_safe_weighted`); never when nothing weighs anything
+pub fn byzantine_safe_weighted(total_weight: u128, byzantine_weight: u128) -> bool {
+    byzantine_weight.saturating_mul(3) < total_weight
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_status_string_round_trip() {
+        for status in [
+            VerificationStatus::Verified,
+            VerificationStatus::Partial,
+            VerificationStatus::Degraded,
+            VerificationStatus::Failed,
+            VerificationStatus::NotRun,
+            VerificationStatus::Skipped,
+            VerificationStatus::Unknown,
+        ] {
+            assert_eq!(status.to_string().parse(), Ok(status));
+        }
+        assert_eq!(VerificationStatus::Failed.to_string(), "failed");
+        assert_eq!(VerificationStatus::NotRun.to_string(), "not_run");
+        assert_eq!(" VERIFIED".parse(), Ok(VerificationStatus::Verified));
+        assert_eq!(
+            "passed".parse::<VerificationStatus>(),
+            Err(ParseStatusError {
+                input: "passed".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_status_severity_ordering() {
+        use VerificationStatus::*;
+        assert!(Verified < Skipped);
+        assert!(Skipped < NotRun);
+        assert!(NotRun < Partial);
+        assert!(Partial < Unknown);
+        assert!(Unknown < Degraded);
+        assert!(Degraded < Failed);
+        assert_eq!(
+            VerificationStatus::worst([Verified, Failed, Partial]),
+            Failed
+        );
+        assert_eq!(VerificationStatus::worst([Verified, Partial]), Partial);
+        assert_eq!(VerificationStatus::worst([]), Verified);
+
+        let mut statuses = vec![Failed, Verified, Partial];
+        statuses.sort();
+        assert_eq!(statuses, [Verified, Partial, Failed]);
+    }
+
+    #[test]
+    fn test_derived_status() {
+        let theorem = |status| TheoremResult {
+            name: "lemma".to_string(),
+            status,
+            duration: None,
+            severity: Severity::Standard,
+        };
+        let mut report = ModuleReport::new("m");
+        assert_eq!(report.derived_status(), VerificationStatus::NotRun);
+
+        report.status = VerificationStatus::Verified;
+        assert_eq!(report.derived_status(), VerificationStatus::Partial);
+
+        report.theorems = vec![theorem(VerificationStatus::Verified)];
+        assert_eq!(report.derived_status(), VerificationStatus::Verified);
+
+        report.theorems.push(theorem(VerificationStatus::Failed));
+        assert_eq!(report.derived_status(), VerificationStatus::Failed);
+        assert_eq!(report.failed_theorems().count(), 1);
+    }
+
+    #[test]
+    fn test_halt_reason_display() {
+        assert_eq!(
+            HaltReason::NoSupermajority { agreement_pct: 666 }.to_string(),
+            "no supermajority (agreement 66.6%)"
+        );
+        assert_eq!(
+            HaltReason::VarianceExceeded { ratio_scaled: 705 }.to_string(),
+            "variance exceeded (7.05x baseline)"
+        );
+        assert_eq!(
+            HaltReason::InsufficientVoters { n: 0 }.to_string(),
+            "insufficient voters (0 cast)"
+        );
+        assert_eq!(
+            HaltReason::TrustCollapse { max_trust: 0 }.to_string(),
+            "trust collapse (max trust 0.000)"
+        );
+    }
+
+    #[test]
+    fn test_decide_consensus() {
+        let outcome = decide_consensus(&[true, true, false], Threshold::TwoThirds);
+        assert_eq!(
+            outcome,
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 666
+            }
+        );
+        assert_eq!(
+            decide_consensus(&[true, false], Threshold::TwoThirds),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 500 }
+            }
+        );
+        assert_eq!(
+            decide_consensus(&[], Threshold::TwoThirds),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: 0 }
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_halt_reason_serde() {
+        let reason = HaltReason::NoSupermajority { agreement_pct: 600 };
+        let json = serde_json::to_string(&reason).unwrap();
+        assert_eq!(json, r#"{"reason":"no_supermajority","agreement_pct":600}"#);
+        assert_eq!(serde_json::from_str::<HaltReason>(&json).unwrap(), reason);
+
+        let reason: HaltReason =
+            serde_json::from_str(r#"{"reason":"trust_collapse","max_trust":0}"#).unwrap();
+        assert_eq!(reason, HaltReason::TrustCollapse { max_trust: 0 });
+    }
+}
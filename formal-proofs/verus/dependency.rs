@@ -0,0 +1,1213 @@
+//! # Theorem Dependencies
+//!
+//! Which theorems rest on which lemmas and axioms. Each theorem registers
+//! its prerequisites; [`TheoremGraph`] answers reviewers' questions ("what
+//! does `signature_uniqueness` assume?") and lets the claim roll-up treat a
+//! theorem as at risk when something beneath it failed, even if the prover
+//! never reached it.
+//!
+//! Nodes are named `module::theorem`. Lookups also accept the bare theorem
+//! name when it is unique across modules.
+//!
+//! The built-in graph ([`TheoremGraph::builtin`]) records the dependencies
+//! the proofs state in their bodies and comments; the prover does not check
+//! them.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+use crate::{
+    VerificationStatus, VerificationSummary, MODULE_BYZANTINE_CONSENSUS, MODULE_ED25519_CONTRACTS,
+    MODULE_TRUST_BOUNDS, MODULE_VARIANCE_HALT,
+};
+
+/// Built-in theorems: (module, theorem, prerequisites). Prerequisites
+/// without a `module::` prefix are in the same module.
+const BUILTIN_THEOREMS: &[(&str, &str, &[&str])] = &[
+    (
+        MODULE_VARIANCE_HALT,
+        "variance_halt_correctness",
+        &[
+            "bounded_outputs_bounded_mean",
+            "variance_non_negative",
+            "stealth_attack_absorption",
+        ],
+    ),
+    (MODULE_VARIANCE_HALT, "empirical_consistency", &[]),
+    (MODULE_VARIANCE_HALT, "majority_attack_halt_rate", &[]),
+    (MODULE_VARIANCE_HALT, "stealth_attack_absorption", &[]),
+    (
+        MODULE_VARIANCE_HALT,
+        "bounded_outputs_bounded_mean",
+        &["lemma_output_sum_bounded"],
+    ),
+    (MODULE_VARIANCE_HALT, "lemma_ssd_zero", &[]),
+    (
+        MODULE_VARIANCE_HALT,
+        "variance_non_negative",
+        &["lemma_ssd_zero"],
+    ),
+    (
+        MODULE_VARIANCE_HALT,
+        "lemma_deviation_within_ssd",
+        &["lemma_ssd_bounded"],
+    ),
+    (
+        MODULE_VARIANCE_HALT,
+        "low_variance_implies_agreement",
+        &[
+            "bounded_outputs_bounded_mean",
+            "lemma_ssd_bounded",
+            "lemma_deviation_within_ssd",
+        ],
+    ),
+    (MODULE_VARIANCE_HALT, "constitutional_halt_safety", &[]),
+    (MODULE_VARIANCE_HALT, "constitutional_halt_liveness", &[]),
+    (MODULE_VARIANCE_HALT, "lemma_output_sum_bounded", &[]),
+    (MODULE_VARIANCE_HALT, "lemma_ssd_bounded", &[]),
+    (
+        MODULE_VARIANCE_HALT,
+        "variance_no_overflow",
+        &[
+            "lemma_output_sum_bounded",
+            "bounded_outputs_bounded_mean",
+            "lemma_ssd_bounded",
+        ],
+    ),
+    (MODULE_VARIANCE_HALT, "lemma_welford_exact", &[]),
+    (
+        MODULE_VARIANCE_HALT,
+        "lemma_ssd_expansion",
+        &["lemma_ssd_bounded"],
+    ),
+    (
+        MODULE_VARIANCE_HALT,
+        "online_variance_matches_batch",
+        &[
+            "lemma_welford_exact",
+            "variance_no_overflow",
+            "lemma_ssd_expansion",
+        ],
+    ),
+    (MODULE_VARIANCE_HALT, "lemma_output_square_sum_bounded", &[]),
+    (
+        MODULE_VARIANCE_HALT,
+        "lemma_cauchy_schwarz",
+        &["lemma_welford_exact"],
+    ),
+    (
+        MODULE_VARIANCE_HALT,
+        "welford_no_overflow",
+        &[
+            "lemma_welford_exact",
+            "lemma_output_sum_bounded",
+            "lemma_output_square_sum_bounded",
+            "lemma_cauchy_schwarz",
+        ],
+    ),
+    (MODULE_TRUST_BOUNDS, "ema_preserves_bounds", &[]),
+    (MODULE_TRUST_BOUNDS, "ema_non_negative", &[]),
+    (MODULE_TRUST_BOUNDS, "clamp_guarantees_bounds", &[]),
+    (MODULE_TRUST_BOUNDS, "decay_preserves_bounds", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "decay_is_decreasing",
+        &["lemma_decay_no_overflow"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "boost_preserves_bounds",
+        &["clamp_guarantees_bounds"],
+    ),
+    (MODULE_TRUST_BOUNDS, "boost_is_increasing", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_ema_no_overflow", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_ema_numerator_between", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "ema_rounded_preserves_bounds",
+        &["lemma_ema_numerator_between", "lemma_ema_no_overflow"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_toward_observation_step",
+        &[
+            "ema_rounded_preserves_bounds",
+            "lemma_ema_numerator_between",
+        ],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "toward_observation_converges",
+        &["lemma_toward_observation_step"],
+    ),
+    (MODULE_TRUST_BOUNDS, "lemma_decay_no_overflow", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_boost_no_overflow", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_combined_weight_no_overflow",
+        &["lemma_builtin_registry_valid", "model_weights_bounded"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "boost_rounding_error",
+        &["lemma_boost_no_overflow"],
+    ),
+    (MODULE_TRUST_BOUNDS, "boost_exact_bounds", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_div_1000_monotone", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "ema_monotone_in_observation",
+        &["lemma_div_1000_monotone"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "ema_monotone_in_current",
+        &["lemma_div_1000_monotone"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "ema_contracts_toward_observation",
+        &["lemma_ema_numerator_between"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "floored_decay_bounds",
+        &["decay_preserves_bounds"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "floored_decay_non_increasing",
+        &["lemma_decay_no_overflow"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "capped_update_bounds",
+        &["ema_preserves_bounds"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "capped_sequence_reachability",
+        &["capped_update_bounds", "lemma_fold_push"],
+    ),
+    (MODULE_TRUST_BOUNDS, "quarantine_boundaries", &[]),
+    (MODULE_TRUST_BOUNDS, "quarantine_no_flapping", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_apply_update_bounds",
+        &[
+            "boost_preserves_bounds",
+            "boost_is_increasing",
+            "floored_decay_non_increasing",
+        ],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "apply_round_preserves_bounds",
+        &["lemma_apply_update_bounds"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_record_preserves_cumulative",
+        &[],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_ema_between",
+        &["lemma_ema_numerator_between"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_trust_after_between",
+        &["lemma_ema_between", "lemma_fold_push"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "update_sequence_preserves_bounds",
+        &["lemma_trust_after_between"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "update_sequence_lower_bound",
+        &["lemma_trust_after_between"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "mixed_sequence_preserves_bounds",
+        &[
+            "ema_preserves_bounds",
+            "lemma_apply_update_bounds",
+            "lemma_fold_push",
+        ],
+    ),
+    (MODULE_TRUST_BOUNDS, "lemma_fold_push", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_fold_sum_bounded", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_fold_sum_lower_bound", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "weighted_consensus_well_defined",
+        &["lemma_fold_sum_lower_bound", "lemma_fold_sum_bounded"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_decay_strictly_decreasing",
+        &["lemma_decay_no_overflow"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_decay_iterate_non_increasing",
+        &["floored_decay_non_increasing", "decay_preserves_bounds"],
+    ),
+    (MODULE_TRUST_BOUNDS, "lemma_decay_iterate_split", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_decay_steps_within",
+        &["lemma_decay_strictly_decreasing"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "byzantine_detection_via_trust",
+        &[
+            "lemma_decay_steps_within",
+            "lemma_decay_iterate_split",
+            "lemma_decay_iterate_non_increasing",
+        ],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_mean_bounded",
+        &["lemma_fold_sum_bounded"],
+    ),
+    (MODULE_TRUST_BOUNDS, "lemma_window_push_bounded", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "window_mean_bounded",
+        &["lemma_mean_bounded"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "window_push_preserves_bounds",
+        &["lemma_window_push_bounded", "window_mean_bounded"],
+    ),
+    (MODULE_TRUST_BOUNDS, "composite_trust_bounded", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_decreasing_alpha_bounded", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "scheduled_sequence_preserves_bounds",
+        &["ema_preserves_bounds"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "decreasing_schedule_identical_observations",
+        &["lemma_decreasing_alpha_bounded"],
+    ),
+    (MODULE_TRUST_BOUNDS, "prior_state_valid", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_ema_gap_step",
+        &["ema_monotone_in_current"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "prior_gap_converges",
+        &[
+            "lemma_ema_gap_step",
+            "update_sequence_preserves_bounds",
+            "lemma_fold_push",
+            "lemma_div_1000_monotone",
+        ],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "decay_fixed_points",
+        &["decay_is_decreasing"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "floored_decay_fixed_points",
+        &["decay_is_decreasing"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "effective_weight_monotone",
+        &["lemma_div_1000_monotone"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_decay_rounds_progress",
+        &["floored_decay_bounds", "floored_decay_fixed_points"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "inactivity_decay_settles",
+        &["lemma_decay_rounds_progress"],
+    ),
+    (MODULE_TRUST_BOUNDS, "round_update_is_local", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_scaled_updates_at_1000", &[]),
+    (MODULE_TRUST_BOUNDS, "scaled_updates_preserve_bounds", &[]),
+    (MODULE_TRUST_BOUNDS, "scaled_updates_no_overflow", &[]),
+    (MODULE_TRUST_BOUNDS, "rescale_preserves_bounds", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_pow1000_positive", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "ema_rounding_error",
+        &["lemma_ema_no_overflow"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "ema_drift_bounded",
+        &[
+            "update_sequence_preserves_bounds",
+            "lemma_fold_push",
+            "ema_rounding_error",
+            "lemma_pow1000_positive",
+        ],
+    ),
+    (MODULE_TRUST_BOUNDS, "lemma_builtin_registry_valid", &[]),
+    (MODULE_TRUST_BOUNDS, "model_weights_bounded", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "combined_weight_bounded",
+        &["model_weights_bounded"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_weighted_votes_bounded",
+        &["lemma_builtin_registry_valid", "model_weights_bounded"],
+    ),
+    (
+        MODULE_TRUST_BOUNDS,
+        "weighted_agreement_bounded",
+        &["lemma_weighted_votes_bounded"],
+    ),
+    (MODULE_TRUST_BOUNDS, "weighted_minority_bound", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_threshold_exact", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "weighted_no_conflicting_agreement",
+        &["lemma_threshold_exact"],
+    ),
+    (MODULE_TRUST_BOUNDS, "lemma_group_votes_partition", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_zero_weight_byzantine", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "weighted_byzantine_safety",
+        &["lemma_group_votes_partition", "lemma_threshold_exact"],
+    ),
+    (MODULE_TRUST_BOUNDS, "lemma_weighted_votes_ballots", &[]),
+    (MODULE_TRUST_BOUNDS, "lemma_ballots_weight_remove", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "lemma_ballots_weight_permutation",
+        &["lemma_ballots_weight_remove"],
+    ),
+    (MODULE_TRUST_BOUNDS, "lemma_max_trust_is_max", &[]),
+    (
+        MODULE_TRUST_BOUNDS,
+        "decide_consensus_weighted_permutation_invariant",
+        &[
+            "lemma_weighted_votes_ballots",
+            "lemma_ballots_weight_permutation",
+            "lemma_max_trust_is_max",
+        ],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "byzantine_safety",
+        &[
+            "decided_value_has_supermajority",
+            "lemma_count_votes_partition",
+            "lemma_honest_votes",
+            "lemma_threshold_exact",
+        ],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_honest_votes", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_honest_majority_strict",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "decided_value_has_supermajority",
+        &["lemma_count_votes_partition", "lemma_threshold_exact"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_count_votes_partition",
+        &[],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_count_votes_append", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_count_agrees_le_len",
+        &["lemma_count_votes_push"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_count_votes_bounded",
+        &["lemma_count_votes_partition", "lemma_count_agrees_le_len"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_agreement_ratio_bounded",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_single_value_reaches_threshold",
+        &["lemma_count_votes_partition", "lemma_threshold_exact"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "no_conflicting_agreement",
+        &[
+            "decided_value_has_supermajority",
+            "lemma_single_value_reaches_threshold",
+            "lemma_threshold_exact",
+        ],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "decide_consensus_valid",
+        &["lemma_count_votes_bounded", "lemma_agreement_ratio_bounded"],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_threshold_exact", &[]),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_per_mille_threshold", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_two_thirds_threshold",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "boundary_regions_partition",
+        &["lemma_threshold_exact"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "decide_consensus_boundaries",
+        &[
+            "lemma_count_votes_partition",
+            "boundary_regions_partition",
+            "lemma_threshold_exact",
+        ],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_count_votes_negated", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "vote_negation_symmetry",
+        &[
+            "lemma_count_votes_partition",
+            "lemma_count_votes_negated",
+            "lemma_threshold_exact",
+        ],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "constitutional_halt_correctness",
+        &["lemma_threshold_exact"],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "n_three_sufficiency", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "default_threshold_tolerances",
+        &[],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "empirical_validation_500", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "certificate_agreement_sound",
+        &["decided_value_has_supermajority"],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "equivocation_proof_sound", &[]),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_count_votes_push", &[]),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_cast_votes_append", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_unanimous_ballots",
+        &["lemma_count_votes_push"],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_abstaining_ballots", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "abstention_preserves_unanimity",
+        &[
+            "lemma_cast_votes_append",
+            "lemma_unanimous_ballots",
+            "lemma_count_votes_append",
+            "lemma_count_votes_partition",
+            "lemma_threshold_exact",
+        ],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "all_abstain_halts",
+        &["lemma_abstaining_ballots"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "evidence_recheck_sound",
+        &["lemma_cast_votes_append", "lemma_count_votes_partition"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "quorum_intersection",
+        &["quorum_honest_count"],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "quorum_honest_count", &[]),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_record_messages", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "committed_has_honest_commits",
+        &["quorum_honest_count"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "byzantine_safety_n7_f2",
+        &["byzantine_safety"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "byzantine_safety_n10_f3",
+        &["byzantine_safety"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "pbft_quorums_n7_f2",
+        &["quorum_intersection", "quorum_honest_count"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "pbft_quorums_n10_f3",
+        &["quorum_intersection", "quorum_honest_count"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_differing_pairs_three",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "diversity_amplification",
+        &["lemma_differing_pairs_three"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "effective_tolerance_bounded",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "effective_tolerance_monotone",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_count_differing_bounded",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_differing_pairs_bounded",
+        &["lemma_count_differing_bounded"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_capped_differing_pairs",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "capped_selection_diversity",
+        &[
+            "lemma_capped_differing_pairs",
+            "lemma_differing_pairs_bounded",
+        ],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "statistical_power_500",
+        &["empirical_validation_500"],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "halt_safety", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "halt_liveness",
+        &["constitutional_halt_correctness"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "no_spurious_halt",
+        &["lemma_count_votes_bounded", "lemma_agreement_ratio_bounded"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "final_verdict_unifies_halts",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "final_verdict_safety",
+        &[
+            "lemma_count_votes_bounded",
+            "lemma_agreement_ratio_bounded",
+            "lemma_threshold_exact",
+        ],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "final_verdict_liveness",
+        &["constitutional_halt_correctness"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "final_verdict_variance_halt",
+        &[
+            "decided_value_has_supermajority",
+            "lemma_count_votes_bounded",
+            "lemma_agreement_ratio_bounded",
+            "lemma_threshold_exact",
+        ],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_suspects_are_byzantine",
+        &[],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "no_false_accusation",
+        &["lemma_suspects_are_byzantine"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_count_votes_extend",
+        &["lemma_count_votes_append", "lemma_count_votes_bounded"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_ratio_monotone",
+        &["lemma_agreement_ratio_bounded"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "early_decision_sound",
+        &[
+            "lemma_count_votes_extend",
+            "lemma_count_votes_partition",
+            "lemma_ratio_monotone",
+            "decided_value_has_supermajority",
+            "lemma_single_value_reaches_threshold",
+            "lemma_threshold_exact",
+        ],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_ballot_count",
+        &["lemma_count_votes_push"],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_ballot_count_update", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "registered_agreement_counts_missing",
+        &["lemma_ballot_count"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "single_keyholder_bounded_influence",
+        &["lemma_ballot_count", "lemma_ballot_count_update"],
+    ),
+    (MODULE_BYZANTINE_CONSENSUS, "lemma_count_halts_all", &[]),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "persistent_halts_escalate",
+        &["lemma_count_halts_all"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_full_bft_level",
+        &["byzantine_safety"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_halt_only_level",
+        &[
+            "lemma_count_votes_partition",
+            "lemma_honest_votes",
+            "lemma_threshold_exact",
+        ],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_none_level",
+        &["lemma_count_votes_partition", "lemma_threshold_exact"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "guarantee_levels_monotone",
+        &["default_threshold_tolerances", "lemma_threshold_exact"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "lemma_count_votes_multiset",
+        &["lemma_count_votes_push"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "count_votes_permutation_invariant",
+        &["lemma_count_votes_multiset"],
+    ),
+    (
+        MODULE_BYZANTINE_CONSENSUS,
+        "decide_consensus_permutation_invariant",
+        &["count_votes_permutation_invariant"],
+    ),
+    (
+        MODULE_ED25519_CONTRACTS,
+        "signature_uniqueness",
+        &["axiom_correctness", "axiom_non_malleable"],
+    ),
+    (
+        MODULE_ED25519_CONTRACTS,
+        "proof_bundle_integrity",
+        &["axiom_tamper_evident"],
+    ),
+    (
+        MODULE_ED25519_CONTRACTS,
+        "audit_trail_non_repudiation",
+        &["axiom_correctness"],
+    ),
+    (MODULE_ED25519_CONTRACTS, "merkle_path_length_bounded", &[]),
+    (MODULE_ED25519_CONTRACTS, "chain_integrity", &[]),
+    (MODULE_ED25519_CONTRACTS, "fpc_composition", &[]),
+    (
+        MODULE_ED25519_CONTRACTS,
+        "commit_binding",
+        &["axiom_sha256_collision_resistant"],
+    ),
+    (
+        MODULE_ED25519_CONTRACTS,
+        "revealed_ballot_unique",
+        &["commit_binding"],
+    ),
+    (
+        MODULE_ED25519_CONTRACTS,
+        "copied_commit_abstains",
+        &["commit_binding"],
+    ),
+];
+
+/// Built-in axioms: (module, axiom)
+const BUILTIN_AXIOMS: &[(&str, &str)] = &[
+    (MODULE_ED25519_CONTRACTS, "axiom_correctness"),
+    (MODULE_ED25519_CONTRACTS, "axiom_deterministic"),
+    (MODULE_ED25519_CONTRACTS, "axiom_non_malleable"),
+    (MODULE_ED25519_CONTRACTS, "axiom_tamper_evident"),
+    (MODULE_ED25519_CONTRACTS, "axiom_unforgeable"),
+    (MODULE_ED25519_CONTRACTS, "axiom_merkle_soundness"),
+    (MODULE_ED25519_CONTRACTS, "axiom_sha256_collision_resistant"),
+];
+
+/// Whether a node is proven or assumed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Theorem,
+    Axiom,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    module: String,
+    name: String,
+    kind: NodeKind,
+    /// Qualified names of direct prerequisites
+    prerequisites: BTreeSet<String>,
+}
+
+/// Theorems, axioms and the prerequisites between them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TheoremGraph {
+    /// Qualified name -> node
+    nodes: BTreeMap<String, Node>,
+}
+
+impl TheoremGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The theorems and axioms of the four built-in modules
+    pub fn builtin() -> Self {
+        let mut graph = Self::new();
+        for (module, axiom) in BUILTIN_AXIOMS {
+            graph.register_axiom(module, axiom);
+        }
+        for (module, theorem, prerequisites) in BUILTIN_THEOREMS {
+            graph.register(module, theorem, prerequisites);
+        }
+        graph
+    }
+
+    /// Add a theorem and its direct prerequisites (`module::name`, or a bare
+    /// name in the same module). Registering a theorem again replaces its
+    /// prerequisites.
+    pub fn register(&mut self, module: &str, theorem: &str, prerequisites: &[&str]) {
+        let prerequisites = prerequisites
+            .iter()
+            .map(|p| {
+                if p.contains("::") {
+                    p.to_string()
+                } else {
+                    qualify(module, p)
+                }
+            })
+            .collect();
+        self.insert(module, theorem, NodeKind::Theorem, prerequisites);
+    }
+
+    /// Add an axiom (a root: it has no prerequisites)
+    pub fn register_axiom(&mut self, module: &str, axiom: &str) {
+        self.insert(module, axiom, NodeKind::Axiom, BTreeSet::new());
+    }
+
+    fn insert(
+        &mut self,
+        module: &str,
+        name: &str,
+        kind: NodeKind,
+        prerequisites: BTreeSet<String>,
+    ) {
+        let node = Node {
+            module: module.to_string(),
+            name: name.to_string(),
+            kind,
+            prerequisites,
+        };
+        self.nodes.insert(qualify(module, name), node);
+    }
+
+    /// Qualified name of a node given as `module::name` or a unique bare name
+    fn resolve(&self, name: &str) -> Option<&str> {
+        if let Some((key, _)) = self.nodes.get_key_value(name) {
+            return Some(key);
+        }
+        let mut matches = self.nodes.iter().filter(|(_, node)| node.name == name);
+        match (matches.next(), matches.next()) {
+            (Some((key, _)), None) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Kind of a node (None if not registered)
+    pub fn kind(&self, name: &str) -> Option<NodeKind> {
+        Some(self.nodes[self.resolve(name)?].kind)
+    }
+
+    /// Qualified names of all registered theorems and axioms, sorted
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.nodes.keys().map(String::as_str)
+    }
+
+    /// Direct prerequisites of a theorem, sorted
+    pub fn dependencies_of(&self, name: &str) -> Vec<&str> {
+        self.resolve(name)
+            .map(|key| {
+                self.nodes[key]
+                    .prerequisites
+                    .iter()
+                    .map(String::as_str)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Theorems that directly depend on `name`, sorted
+    pub fn dependents_of(&self, name: &str) -> Vec<&str> {
+        let Some(key) = self.resolve(name) else {
+            return Vec::new();
+        };
+        self.nodes
+            .iter()
+            .filter(|(_, node)| node.prerequisites.contains(key))
+            .map(|(dependent, _)| dependent.as_str())
+            .collect()
+    }
+
+    /// Everything `name` rests on, directly or indirectly, sorted
+    pub fn transitive_dependencies_of(&self, name: &str) -> Vec<&str> {
+        let mut seen = BTreeSet::new();
+        let mut pending: Vec<&str> = self.dependencies_of(name);
+        while let Some(next) = pending.pop() {
+            if seen.insert(next) {
+                pending.extend(self.dependencies_of(next));
+            }
+        }
+        seen.into_iter().collect()
+    }
+
+    /// Axioms some theorem depends on, sorted
+    pub fn axiom_roots(&self) -> Vec<&str> {
+        let used: BTreeSet<&str> = self
+            .nodes
+            .values()
+            .flat_map(|node| node.prerequisites.iter().map(String::as_str))
+            .collect();
+        self.nodes
+            .iter()
+            .filter(|(key, node)| node.kind == NodeKind::Axiom && used.contains(key.as_str()))
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+
+    /// Theorems in `summary` that did not fail themselves but rest on a
+    /// theorem that did, as `module::theorem`
+    pub fn at_risk(&self, summary: &VerificationSummary) -> BTreeSet<String> {
+        let failed: BTreeSet<String> = summary
+            .modules()
+            .flat_map(|(module, report)| {
+                report
+                    .theorems
+                    .iter()
+                    .filter(|t| t.status == VerificationStatus::Failed)
+                    .map(move |t| qualify(module, &t.name))
+            })
+            .collect();
+        self.nodes
+            .keys()
+            .filter(|key| !failed.contains(*key))
+            .filter(|key| {
+                self.transitive_dependencies_of(key)
+                    .iter()
+                    .any(|dependency| failed.contains(*dependency))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Graphviz DOT: one cluster per module, axioms as boxes, edges from
+    /// each theorem to its prerequisites
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph theorems {\n    rankdir=LR;\n");
+        let mut modules: BTreeMap<&str, Vec<&Node>> = BTreeMap::new();
+        for node in self.nodes.values() {
+            modules.entry(&node.module).or_default().push(node);
+        }
+        for (module, nodes) in &modules {
+            let _ = writeln!(out, "    subgraph \"cluster_{}\" {{", escape(module));
+            let _ = writeln!(out, "        label=\"{}\";", escape(module));
+            for node in nodes {
+                let shape = match node.kind {
+                    NodeKind::Theorem => "ellipse",
+                    NodeKind::Axiom => "box",
+                };
+                let _ = writeln!(
+                    out,
+                    "        \"{}\" [label=\"{}\", shape={}];",
+                    escape(&qualify(&node.module, &node.name)),
+                    escape(&node.name),
+                    shape
+                );
+            }
+            out.push_str("    }\n");
+        }
+        for (key, node) in &self.nodes {
+            for prerequisite in &node.prerequisites {
+                let _ = writeln!(
+                    out,
+                    "    \"{}\" -> \"{}\";",
+                    escape(key),
+                    escape(prerequisite)
+                );
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+fn qualify(module: &str, name: &str) -> String {
+    format!("{}::{}", module, name)
+}
+
+/// Escape a DOT double-quoted string
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::source_scan::ProofInventory;
+
+    #[test]
+    fn test_known_edges() {
+        let graph = TheoremGraph::builtin();
+        assert_eq!(
+            graph.dependencies_of("signature_uniqueness"),
+            [
+                "ed25519_contracts::axiom_correctness",
+                "ed25519_contracts::axiom_non_malleable",
+            ]
+        );
+        assert_eq!(
+            graph.dependents_of("ed25519_contracts::axiom_correctness"),
+            [
+                "ed25519_contracts::audit_trail_non_repudiation",
+                "ed25519_contracts::signature_uniqueness",
+            ]
+        );
+        assert_eq!(
+            graph.dependencies_of("update_sequence_preserves_bounds"),
+            ["trust_bounds::lemma_trust_after_between"]
+        );
+        assert_eq!(
+            graph.dependents_of("empirical_validation_500"),
+            ["byzantine_consensus::statistical_power_500"]
+        );
+        assert!(graph.dependencies_of("n_three_sufficiency").is_empty());
+        assert_eq!(
+            graph.axiom_roots(),
+            [
+                "ed25519_contracts::axiom_correctness",
+                "ed25519_contracts::axiom_non_malleable",
+                "ed25519_contracts::axiom_sha256_collision_resistant",
+                "ed25519_contracts::axiom_tamper_evident",
+            ]
+        );
+        assert_eq!(graph.kind("axiom_unforgeable"), Some(NodeKind::Axiom));
+        assert!(graph.dependencies_of("no_such_theorem").is_empty());
+    }
+
+    #[test]
+    fn test_builtin_matches_sources() {
+        let graph = TheoremGraph::builtin();
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut expected = BTreeSet::new();
+        for module in [
+            MODULE_VARIANCE_HALT,
+            MODULE_TRUST_BOUNDS,
+            MODULE_BYZANTINE_CONSENSUS,
+            MODULE_ED25519_CONTRACTS,
+        ] {
+            let inventory = ProofInventory::scan_file(&dir.join(format!("{}.rs", module))).unwrap();
+            for name in inventory.theorems.iter().chain(&inventory.axioms) {
+                expected.insert(qualify(module, name));
+            }
+        }
+        let registered: BTreeSet<String> = graph.names().map(str::to_string).collect();
+        assert_eq!(registered, expected);
+
+        // Every prerequisite is itself registered
+        for name in graph.names() {
+            for dependency in graph.dependencies_of(name) {
+                assert!(
+                    graph.kind(dependency).is_some(),
+                    "{} -> {}",
+                    name,
+                    dependency
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_transitive_at_risk() {
+        use VerificationStatus::*;
+
+        let mut graph = TheoremGraph::new();
+        graph.register_axiom("m", "axiom_a");
+        graph.register("m", "lemma", &["axiom_a"]);
+        graph.register("m", "theorem", &["lemma"]);
+        graph.register("other", "corollary", &["m::theorem"]);
+        assert_eq!(
+            graph.transitive_dependencies_of("corollary"),
+            ["m::axiom_a", "m::lemma", "m::theorem"]
+        );
+
+        let mut builder = VerificationSummary::builder();
+        builder
+            .module("m")
+            .theorem("lemma", Failed, None)
+            .theorem("theorem", NotRun, None);
+        builder.module("other").theorem("corollary", Verified, None);
+        let summary = builder.build().unwrap();
+
+        let at_risk: Vec<String> = graph.at_risk(&summary).into_iter().collect();
+        assert_eq!(at_risk, ["m::theorem", "other::corollary"]);
+    }
+
+    /// Node ids and edges of a DOT graph
+    type Dot = (BTreeSet<String>, Vec<(String, String)>);
+
+    /// Minimal DOT reader for the subset `to_dot` writes: returns declared
+    /// nodes and edges, or an error for anything else
+    fn parse_dot(text: &str) -> Result<Dot, String> {
+        let quoted = |s: &str| -> Result<String, String> {
+            let inner = s
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .ok_or_else(|| format!("expected a quoted id: {}", s))?;
+            Ok(inner.replace("\\\"", "\"").replace("\\\\", "\\"))
+        };
+        let mut lines = text.lines().map(str::trim);
+        if lines.next() != Some("digraph theorems {") {
+            return Err("missing digraph header".to_string());
+        }
+        let (mut nodes, mut edges, mut depth) = (BTreeSet::new(), Vec::new(), 1);
+        for line in lines {
+            if line == "}" {
+                depth -= 1;
+            } else if line.starts_with("subgraph ") && line.ends_with('{') {
+                depth += 1;
+            } else if line.starts_with("label=") || line.starts_with("rankdir=") {
+                continue;
+            } else if let Some(statement) = line.strip_suffix(';') {
+                match statement.split_once(" -> ") {
+                    Some((from, to)) => edges.push((quoted(from)?, quoted(to)?)),
+                    None => {
+                        let (id, attributes) = statement
+                            .split_once(" [")
+                            .ok_or_else(|| format!("bad statement: {}", line))?;
+                        if !attributes.ends_with(']') {
+                            return Err(format!("unterminated attributes: {}", line));
+                        }
+                        nodes.insert(quoted(id)?);
+                    }
+                }
+            } else {
+                return Err(format!("unexpected line: {}", line));
+            }
+            if depth < 0 {
+                return Err("unbalanced braces".to_string());
+            }
+        }
+        if depth != 0 {
+            return Err("unbalanced braces".to_string());
+        }
+        Ok((nodes, edges))
+    }
+
+    #[test]
+    fn test_dot_output_parses() {
+        let graph = TheoremGraph::builtin();
+        let (nodes, edges) = parse_dot(&graph.to_dot()).unwrap();
+        let names: BTreeSet<String> = graph.names().map(str::to_string).collect();
+        assert_eq!(nodes, names);
+        assert!(edges.contains(&(
+            "ed25519_contracts::signature_uniqueness".to_string(),
+            "ed25519_contracts::axiom_non_malleable".to_string()
+        )));
+        for (from, to) in &edges {
+            assert!(nodes.contains(from) && nodes.contains(to));
+        }
+
+        let mut quoted = TheoremGraph::new();
+        quoted.register("m", "odd\"name", &[]);
+        let (nodes, _) = parse_dot(&quoted.to_dot()).unwrap();
+        assert!(nodes.contains("m::odd\"name"));
+    }
+}
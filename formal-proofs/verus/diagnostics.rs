@@ -0,0 +1,376 @@
+//! # Prover Diagnostic Parsing
+//!
+//! Parses the rustc-style text diagnostics emitted by Verus into structured
+//! errors with source locations.
+//!
+//! ```text
+//! error: precondition not satisfied
+//!   --> src/trust_bounds.rs:42:5
+//!    |
+//! 10 |         current <= 1000,
+//!    |         --------------- failed precondition
+//! ...
+//! 42 |     ema_preserves_bounds(c, o, a);
+//!    |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+//! ```
+//!
+//! Each diagnostic keeps its byte range in the raw prover output, and each
+//! span keeps file/line/column so the renderer can re-read the source.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::ops::Range;
+use std::path::PathBuf;
+
+/// Diagnostic severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Level {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Note => "note",
+        }
+    }
+}
+
+/// A source location referenced by a diagnostic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub file: PathBuf,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// Number of characters underlined (at least 1)
+    pub len: usize,
+    /// Annotation text next to the underline
+    pub label: Option<String>,
+    /// Primary (`^`) vs. secondary (`-`) location
+    pub primary: bool,
+}
+
+/// A parsed prover diagnostic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub level: Level,
+    pub message: String,
+    /// Spans in output order (e.g. the assertion and the failing precondition site)
+    pub spans: Vec<Span>,
+    /// Byte range of this diagnostic in the raw prover output
+    pub output_range: Range<usize>,
+}
+
+impl Diagnostic {
+    /// The primary span, falling back to the first span
+    pub fn primary_span(&self) -> Option<&Span> {
+        self.spans
+            .iter()
+            .find(|s| s.primary)
+            .or_else(|| self.spans.first())
+    }
+}
+
+/// Parse all diagnostics from prover output.
+///
+/// Lines that are not part of a diagnostic (progress messages, the final
+/// "verification results" line) are ignored.
+pub fn parse(output: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut current: Option<DiagnosticBuilder> = None;
+    let mut offset = 0;
+
+    for raw_line in output.split_inclusive('\n') {
+        let line_start = offset;
+        offset += raw_line.len();
+        let line = raw_line.trim_end_matches(['\n', '\r']);
+
+        if let Some((level, message)) = header(line) {
+            if let Some(done) = current.take() {
+                diagnostics.push(done.finish());
+            }
+            current = Some(DiagnosticBuilder::new(level, message, line_start));
+            continue;
+        }
+
+        let Some(builder) = current.as_mut() else {
+            continue;
+        };
+        if line.trim().is_empty() {
+            builder.end = line_start;
+            builder.closed = true;
+            continue;
+        }
+        if builder.closed {
+            // Trailing text after a blank line belongs to no diagnostic
+            continue;
+        }
+        builder.end = offset;
+        builder.consume(line);
+    }
+
+    if let Some(done) = current {
+        diagnostics.push(done.finish());
+    }
+    diagnostics
+}
+
+/// Match `error: msg`, `warning: msg`, `note: msg` (optionally `error[E0000]: msg`)
+fn header(line: &str) -> Option<(Level, String)> {
+    let (prefix, message) = line.split_once(": ")?;
+    let level = match prefix.split('[').next()? {
+        "error" => Level::Error,
+        "warning" => Level::Warning,
+        "note" => Level::Note,
+        _ => return None,
+    };
+    Some((level, message.trim().to_string()))
+}
+
+/// Match `  --> file:line:col` / `  ::: file:line:col`
+fn location(line: &str) -> Option<(PathBuf, usize, usize, bool)> {
+    let trimmed = line.trim_start();
+    let (rest, primary) = if let Some(rest) = trimmed.strip_prefix("--> ") {
+        (rest, true)
+    } else if let Some(rest) = trimmed.strip_prefix("::: ") {
+        (rest, false)
+    } else {
+        return None;
+    };
+    let mut parts = rest.trim().rsplitn(3, ':');
+    let column = parts.next()?.parse().ok()?;
+    let line_no = parts.next()?.parse().ok()?;
+    let file = parts.next()?;
+    Some((PathBuf::from(file), line_no, column, primary))
+}
+
+/// Split a gutter line `NN | text` into (line number, text after `| `)
+fn gutter(line: &str) -> Option<(Option<usize>, &str)> {
+    let bar = line.find('|')?;
+    let number = line[..bar].trim();
+    let number = if number.is_empty() {
+        None
+    } else {
+        Some(number.parse().ok()?)
+    };
+    let text = &line[bar + 1..];
+    Some((number, text.strip_prefix(' ').unwrap_or(text)))
+}
+
+/// Parse an annotation line body like `    ^^^^ label` or `  ---- label`
+fn annotation(text: &str) -> Option<(usize, usize, bool, Option<String>)> {
+    let start = text.find(|c: char| c != ' ')?;
+    let marker = text[start..].chars().next()?;
+    if marker != '^' && marker != '-' {
+        return None;
+    }
+    let rest = &text[start..];
+    let len = rest.chars().take_while(|&c| c == marker).count();
+    let label = rest[len..].trim();
+    let column = text[..start].chars().count() + 1;
+    Some((
+        column,
+        len,
+        marker == '^',
+        (!label.is_empty()).then(|| label.to_string()),
+    ))
+}
+
+struct DiagnosticBuilder {
+    level: Level,
+    message: String,
+    spans: Vec<Span>,
+    start: usize,
+    end: usize,
+    closed: bool,
+    /// Location header awaiting annotation lines
+    header: Option<(PathBuf, usize, usize, bool)>,
+    header_used: bool,
+    /// Last source line number seen in the gutter
+    last_line: Option<usize>,
+}
+
+impl DiagnosticBuilder {
+    fn new(level: Level, message: String, start: usize) -> Self {
+        Self {
+            level,
+            message,
+            spans: Vec::new(),
+            start,
+            end: start,
+            closed: false,
+            header: None,
+            header_used: false,
+            last_line: None,
+        }
+    }
+
+    fn consume(&mut self, line: &str) {
+        if let Some(loc) = location(line) {
+            self.flush_header();
+            self.header = Some(loc);
+            self.header_used = false;
+            self.last_line = None;
+            return;
+        }
+        let Some((number, text)) = gutter(line) else {
+            return;
+        };
+        if number.is_some() {
+            self.last_line = number;
+            return;
+        }
+        let (Some((file, header_line, header_column, header_primary)), Some(line_no)) =
+            (&self.header, self.last_line)
+        else {
+            return;
+        };
+        if let Some((column, len, primary, label)) = annotation(text) {
+            // The header carries the authoritative column for its own line
+            let column = if line_no == *header_line && primary == *header_primary {
+                *header_column
+            } else {
+                column
+            };
+            self.spans.push(Span {
+                file: file.clone(),
+                line: line_no,
+                column,
+                len,
+                label,
+                primary,
+            });
+            self.header_used = true;
+        }
+    }
+
+    /// A header with no annotated lines still contributes its location
+    fn flush_header(&mut self) {
+        if let Some((file, line, column, primary)) = self.header.take() {
+            if !self.header_used {
+                self.spans.push(Span {
+                    file,
+                    line,
+                    column,
+                    len: 1,
+                    label: None,
+                    primary,
+                });
+            }
+        }
+    }
+
+    fn finish(mut self) -> Diagnostic {
+        self.flush_header();
+        Diagnostic {
+            level: self.level,
+            message: self.message,
+            spans: self.spans,
+            output_range: self.start..self.end,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ASSERTION: &str = "\
+verifying src/variance_halt.rs
+error: assertion failed
+   --> src/variance_halt.rs:128:16
+    |
+128 |         assert(correct_consensus(outputs));
+    |                ^^^^^^^^^^^^^^^^^^^^^^^^^^ assertion failed
+
+error: aborting due to 1 previous error
+";
+
+    const PRECONDITION: &str = "\
+error: precondition not satisfied
+  --> src/trust_bounds.rs:42:5
+   |
+10 |         current <= 1000,
+   |         --------------- failed precondition
+...
+42 |     ema_preserves_bounds(c, o, a);
+   |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+verification results:: 11 verified, 1 errors
+";
+
+    #[test]
+    fn test_parse_assertion() {
+        let diagnostics = parse(ASSERTION);
+        assert_eq!(diagnostics.len(), 2);
+
+        let d = &diagnostics[0];
+        assert_eq!(d.level, Level::Error);
+        assert_eq!(d.message, "assertion failed");
+        assert_eq!(d.spans.len(), 1);
+
+        let span = &d.spans[0];
+        assert_eq!(span.file, PathBuf::from("src/variance_halt.rs"));
+        assert_eq!((span.line, span.column, span.len), (128, 16, 26));
+        assert_eq!(span.label.as_deref(), Some("assertion failed"));
+        assert!(span.primary);
+
+        // Byte range covers exactly the diagnostic block
+        let text = &ASSERTION[d.output_range.clone()];
+        assert!(text.starts_with("error: assertion failed"));
+        assert!(text.trim_end().ends_with("assertion failed"));
+
+        // "aborting" summary has no spans
+        assert!(diagnostics[1].spans.is_empty());
+    }
+
+    #[test]
+    fn test_parse_multi_span() {
+        let diagnostics = parse(PRECONDITION);
+        assert_eq!(diagnostics.len(), 1);
+        let spans = &diagnostics[0].spans;
+        assert_eq!(spans.len(), 2);
+
+        assert_eq!((spans[0].line, spans[0].column), (10, 9));
+        assert!(!spans[0].primary);
+        assert_eq!(spans[0].label.as_deref(), Some("failed precondition"));
+
+        assert_eq!((spans[1].line, spans[1].column, spans[1].len), (42, 5, 29));
+        assert!(spans[1].primary);
+        assert_eq!(diagnostics[0].primary_span(), Some(&spans[1]));
+    }
+
+    #[test]
+    fn test_secondary_location_header() {
+        let output = "\
+error: postcondition not satisfied
+  --> a.rs:5:1
+   |
+5  | }
+   | ^
+  ::: b.rs:2:9
+";
+        let spans = &parse(output)[0].spans;
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[1].file, PathBuf::from("b.rs"));
+        assert_eq!((spans[1].line, spans[1].column), (2, 9));
+        assert!(!spans[1].primary);
+    }
+
+    #[test]
+    fn test_crlf_output() {
+        let output = ASSERTION.replace('\n', "\r\n");
+        let diagnostics = parse(&output);
+        assert_eq!(diagnostics[0].spans[0].line, 128);
+        assert_eq!(
+            diagnostics[0].spans[0].label.as_deref(),
+            Some("assertion failed")
+        );
+    }
+}
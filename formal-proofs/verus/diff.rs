@@ -0,0 +1,497 @@
+//! # Summary Comparison
+//!
+//! Regression detection between a baseline summary and a newer one:
+//! status regressions and improvements (per module and per theorem), added
+//! and removed theorems, likely renames, and significant duration changes.
+//!
+//! A status change is a regression when the new status is more severe and
+//! at least `Partial` (a theorem that is now failing, partial or unknown);
+//! an improvement is the reverse. Moving to or from `NotRun`/`Skipped` on
+//! its own is neither.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ModuleReport, TheoremResult, VerificationStatus, VerificationSummary};
+
+/// Default minimum duration change worth reporting
+pub const DEFAULT_DURATION_THRESHOLD: Duration = Duration::from_secs(5);
+
+/// Minimum name similarity (0..1) for a removed/added pair to count as a
+/// rename
+const RENAME_SIMILARITY: f64 = 0.75;
+
+/// Tuning for [`VerificationSummary::compare_with`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompareOptions {
+    /// Report duration changes at least this large
+    pub duration_threshold: Duration,
+    /// Pair removed and added theorems with similar names in the same
+    /// module as renames
+    pub detect_renames: bool,
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        Self {
+            duration_threshold: DEFAULT_DURATION_THRESHOLD,
+            detect_renames: true,
+        }
+    }
+}
+
+/// A module or theorem whose status changed (`theorem` is None for a
+/// module-level change; the unit-test stage reports as module `unit_tests`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StatusChange {
+    pub module: String,
+    pub theorem: Option<String>,
+    pub before: VerificationStatus,
+    pub after: VerificationStatus,
+}
+
+/// A theorem present in only one of the summaries
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TheoremRef {
+    pub module: String,
+    pub theorem: String,
+    pub status: VerificationStatus,
+}
+
+/// A removed theorem matched to an added one with a similar name
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rename {
+    pub module: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// A module or theorem whose duration changed by at least the threshold
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DurationChange {
+    pub module: String,
+    pub theorem: Option<String>,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl DurationChange {
+    /// Signed change in seconds (positive when slower)
+    pub fn delta_secs(&self) -> f64 {
+        self.after.as_secs_f64() - self.before.as_secs_f64()
+    }
+}
+
+/// Differences between a baseline summary and a newer one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SummaryDiff {
+    pub baseline_run_id: Option<String>,
+    pub run_id: Option<String>,
+    pub regressions: Vec<StatusChange>,
+    pub improvements: Vec<StatusChange>,
+    pub added: Vec<TheoremRef>,
+    pub removed: Vec<TheoremRef>,
+    pub renamed: Vec<Rename>,
+    pub duration_changes: Vec<DurationChange>,
+}
+
+impl SummaryDiff {
+    /// Any module, theorem or the unit tests got worse
+    pub fn has_regressions(&self) -> bool {
+        !self.regressions.is_empty()
+    }
+
+    /// Nothing worth reporting changed
+    pub fn is_empty(&self) -> bool {
+        self.regressions.is_empty()
+            && self.improvements.is_empty()
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.renamed.is_empty()
+            && self.duration_changes.is_empty()
+    }
+
+    fn record(
+        &mut self,
+        module: &str,
+        theorem: Option<&str>,
+        before: VerificationStatus,
+        after: VerificationStatus,
+    ) {
+        let change = || StatusChange {
+            module: module.to_string(),
+            theorem: theorem.map(str::to_string),
+            before,
+            after,
+        };
+        if after > before && after >= VerificationStatus::Partial {
+            self.regressions.push(change());
+        } else if after < before && before >= VerificationStatus::Partial {
+            self.improvements.push(change());
+        }
+    }
+
+    fn record_duration(
+        &mut self,
+        module: &str,
+        theorem: Option<&str>,
+        before: Option<Duration>,
+        after: Option<Duration>,
+        threshold: Duration,
+    ) {
+        let (Some(before), Some(after)) = (before, after) else {
+            return;
+        };
+        if before.abs_diff(after) >= threshold {
+            self.duration_changes.push(DurationChange {
+                module: module.to_string(),
+                theorem: theorem.map(str::to_string),
+                before,
+                after,
+            });
+        }
+    }
+}
+
+fn qualified(module: &str, theorem: Option<&str>) -> String {
+    match theorem {
+        Some(theorem) => format!("{}::{}", module, theorem),
+        None => module.to_string(),
+    }
+}
+
+impl fmt::Display for SummaryDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (label, changes) in [
+            ("REGRESSION", &self.regressions),
+            ("IMPROVED", &self.improvements),
+        ] {
+            for c in changes {
+                let name = qualified(&c.module, c.theorem.as_deref());
+                writeln!(f, "{:<11}{}: {} -> {}", label, name, c.before, c.after)?;
+            }
+        }
+        for (label, theorems) in [("ADDED", &self.added), ("REMOVED", &self.removed)] {
+            for t in theorems {
+                writeln!(f, "{:<11}{}::{} ({})", label, t.module, t.theorem, t.status)?;
+            }
+        }
+        for r in &self.renamed {
+            writeln!(f, "{:<11}{}::{} -> {}", "RENAMED", r.module, r.from, r.to)?;
+        }
+        for d in &self.duration_changes {
+            let label = if d.after > d.before {
+                "SLOWER"
+            } else {
+                "FASTER"
+            };
+            writeln!(
+                f,
+                "{:<11}{}: {:.1}s -> {:.1}s ({:+.1}s)",
+                label,
+                qualified(&d.module, d.theorem.as_deref()),
+                d.before.as_secs_f64(),
+                d.after.as_secs_f64(),
+                d.delta_secs()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl VerificationSummary {
+    /// Differences from `baseline` to this summary, with default options
+    pub fn compare(&self, baseline: &Self) -> SummaryDiff {
+        self.compare_with(baseline, &CompareOptions::default())
+    }
+
+    /// Differences from `baseline` to this summary
+    pub fn compare_with(&self, baseline: &Self, options: &CompareOptions) -> SummaryDiff {
+        let mut diff = SummaryDiff {
+            baseline_run_id: baseline.run_id.clone(),
+            run_id: self.run_id.clone(),
+            ..SummaryDiff::default()
+        };
+        let empty = |name: &str| ModuleReport::new(name);
+
+        let mut names: Vec<&str> = baseline.module_names().collect();
+        names.extend(self.module_names().filter(|n| baseline.module(n).is_none()));
+        for name in names {
+            let (old, new) = match (baseline.module(name), self.module(name)) {
+                (Some(old), Some(new)) => {
+                    diff.record(name, None, old.derived_status(), new.derived_status());
+                    diff.record_duration(
+                        name,
+                        None,
+                        old.duration,
+                        new.duration,
+                        options.duration_threshold,
+                    );
+                    (old.clone(), new.clone())
+                }
+                (Some(old), None) => (old.clone(), empty(name)),
+                (None, Some(new)) => (empty(name), new.clone()),
+                (None, None) => continue,
+            };
+            compare_theorems(&mut diff, &old, &new, options);
+        }
+
+        diff.record("unit_tests", None, baseline.unit_tests, self.unit_tests);
+        diff
+    }
+}
+
+fn compare_theorems(
+    diff: &mut SummaryDiff,
+    old: &ModuleReport,
+    new: &ModuleReport,
+    options: &CompareOptions,
+) {
+    let module = new.name.as_str();
+    let find = |report: &ModuleReport, name: &str| {
+        report.theorems.iter().find(|t| t.name == name).cloned()
+    };
+
+    let mut removed = Vec::new();
+    for before in &old.theorems {
+        match find(new, &before.name) {
+            Some(after) => {
+                diff.record(module, Some(&before.name), before.status, after.status);
+                diff.record_duration(
+                    module,
+                    Some(&before.name),
+                    before.duration,
+                    after.duration,
+                    options.duration_threshold,
+                );
+            }
+            None => removed.push(before.clone()),
+        }
+    }
+    let mut added: Vec<_> = new
+        .theorems
+        .iter()
+        .filter(|t| find(old, &t.name).is_none())
+        .cloned()
+        .collect();
+
+    if options.detect_renames {
+        // Most similar pairs first, each theorem used at most once
+        let mut pairs: Vec<(f64, usize, usize)> = Vec::new();
+        for (i, r) in removed.iter().enumerate() {
+            for (j, a) in added.iter().enumerate() {
+                let score = similarity(&r.name, &a.name);
+                if score >= RENAME_SIMILARITY {
+                    pairs.push((score, i, j));
+                }
+            }
+        }
+        pairs.sort_by(|x, y| y.0.total_cmp(&x.0).then((x.1, x.2).cmp(&(y.1, y.2))));
+        let (mut used_removed, mut used_added) =
+            (vec![false; removed.len()], vec![false; added.len()]);
+        for (_, i, j) in pairs {
+            if used_removed[i] || used_added[j] {
+                continue;
+            }
+            used_removed[i] = true;
+            used_added[j] = true;
+            let (from, to) = (&removed[i], &added[j]);
+            diff.renamed.push(Rename {
+                module: module.to_string(),
+                from: from.name.clone(),
+                to: to.name.clone(),
+            });
+            diff.record(module, Some(&to.name), from.status, to.status);
+        }
+        removed = unused(removed, &used_removed);
+        added = unused(added, &used_added);
+    }
+
+    let to_ref = |t: &TheoremResult| TheoremRef {
+        module: module.to_string(),
+        theorem: t.name.clone(),
+        status: t.status,
+    };
+    diff.removed.extend(removed.iter().map(to_ref));
+    diff.added.extend(added.iter().map(to_ref));
+}
+
+fn unused(theorems: Vec<TheoremResult>, used: &[bool]) -> Vec<TheoremResult> {
+    theorems
+        .into_iter()
+        .zip(used)
+        .filter(|(_, used)| !**used)
+        .map(|(theorem, _)| theorem)
+        .collect()
+}
+
+/// Normalized Levenshtein similarity: 1 for equal names, 0 for nothing in
+/// common
+fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + usize::from(ca != cb))
+                .min(above + 1)
+                .min(row[j] + 1);
+            diagonal = above;
+        }
+    }
+    1.0 - row[b.len()] as f64 / longest as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Severity;
+
+    use VerificationStatus::{Failed, NotRun, Partial, Verified};
+
+    fn summary(theorems: &[(&str, VerificationStatus, u64)]) -> VerificationSummary {
+        let mut summary = VerificationSummary::with_modules(Vec::<String>::new());
+        summary.insert_module(ModuleReport {
+            status: Verified,
+            theorems: theorems
+                .iter()
+                .map(|(name, status, secs)| TheoremResult {
+                    name: name.to_string(),
+                    status: *status,
+                    duration: Some(Duration::from_secs(*secs)),
+                    severity: Severity::Standard,
+                })
+                .collect(),
+            duration: Some(Duration::from_secs(theorems.iter().map(|t| t.2).sum())),
+            ..ModuleReport::new("trust_bounds")
+        });
+        summary.unit_tests = Verified;
+        summary
+    }
+
+    #[test]
+    fn test_identical_summaries() {
+        let base = summary(&[("a", Verified, 1), ("b", Verified, 1)]);
+        let diff = base.compare(&base);
+        assert!(diff.is_empty());
+        assert!(!diff.has_regressions());
+    }
+
+    #[test]
+    fn test_regressions_and_improvements() {
+        let base = summary(&[("a", Verified, 1), ("b", Failed, 1), ("c", Verified, 1)]);
+        let mut new = summary(&[("a", Failed, 1), ("b", Verified, 1), ("c", Partial, 1)]);
+        new.unit_tests = NotRun;
+        let diff = new.compare(&base);
+
+        assert!(diff.has_regressions());
+        let regressed: Vec<String> = diff
+            .regressions
+            .iter()
+            .map(|c| qualified(&c.module, c.theorem.as_deref()))
+            .collect();
+        assert_eq!(regressed, ["trust_bounds::a", "trust_bounds::c"]);
+        assert_eq!(diff.improvements.len(), 1);
+        assert_eq!(diff.improvements[0].theorem.as_deref(), Some("b"));
+        assert_eq!(diff.improvements[0].after, Verified);
+        // Verified -> NotRun is neither a regression nor an improvement
+        assert!(diff.regressions.iter().all(|c| c.module != "unit_tests"));
+    }
+
+    #[test]
+    fn test_added_and_removed_theorems() {
+        let base = summary(&[("a", Verified, 1), ("old_lemma", Verified, 1)]);
+        let new = summary(&[("a", Verified, 1), ("fresh_theorem", Verified, 1)]);
+        let diff = new.compare(&base);
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].theorem, "old_lemma");
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].theorem, "fresh_theorem");
+        assert!(diff.renamed.is_empty());
+        assert!(!diff.has_regressions());
+    }
+
+    #[test]
+    fn test_rename_detection() {
+        let base = summary(&[("ema_preserves_bounds", Verified, 1)]);
+        let new = summary(&[("ema_preserves_trust_bounds", Failed, 1)]);
+
+        let diff = new.compare(&base);
+        assert_eq!(
+            diff.renamed,
+            [Rename {
+                module: "trust_bounds".to_string(),
+                from: "ema_preserves_bounds".to_string(),
+                to: "ema_preserves_trust_bounds".to_string(),
+            }]
+        );
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+        // The renamed theorem's failure is a regression
+        assert!(diff
+            .regressions
+            .iter()
+            .any(|c| c.theorem.as_deref() == Some("ema_preserves_trust_bounds")));
+
+        let options = CompareOptions {
+            detect_renames: false,
+            ..CompareOptions::default()
+        };
+        let diff = new.compare_with(&base, &options);
+        assert!(diff.renamed.is_empty());
+        assert_eq!((diff.added.len(), diff.removed.len()), (1, 1));
+    }
+
+    #[test]
+    fn test_duration_threshold() {
+        let base = summary(&[("a", Verified, 10), ("b", Verified, 10)]);
+        let new = summary(&[("a", Verified, 30), ("b", Verified, 12)]);
+        let diff = new.compare(&base);
+        let changed: Vec<String> = diff
+            .duration_changes
+            .iter()
+            .map(|d| qualified(&d.module, d.theorem.as_deref()))
+            .collect();
+        // Module total 20s -> 42s and theorem a 10s -> 30s; b is under 5s
+        assert_eq!(changed, ["trust_bounds", "trust_bounds::a"]);
+        assert_eq!(diff.duration_changes[1].delta_secs(), 20.0);
+        assert!(diff
+            .to_string()
+            .contains("SLOWER     trust_bounds::a: 10.0s -> 30.0s (+20.0s)"));
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity("abc", "abc"), 1.0);
+        assert!(similarity("halt_safety", "halt_liveness") < RENAME_SIMILARITY);
+        assert!(
+            similarity("ema_preserves_bounds", "ema_preserves_trust_bounds") >= RENAME_SIMILARITY
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_diff_serializes() {
+        let base = summary(&[("a", Verified, 1)]);
+        let new = summary(&[("a", Failed, 1)]);
+        let diff = new.compare(&base);
+        let json = serde_json::to_string(&diff).unwrap();
+        let back: SummaryDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, diff);
+    }
+}
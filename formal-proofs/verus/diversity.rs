@@ -0,0 +1,250 @@
+//! # Ensemble Diversity
+//!
+//! Executable counterpart of `diversity_score` in `byzantine_consensus.rs`.
+//! Models of one [`ModelFamily`] share failure modes, so an ensemble is
+//! scored by how often two of its members differ: the normalized
+//! Gini-Simpson index over families and over [`Architecture`]s, weighted
+//! 3:1 and scaled by 1000. Repeating a model, or adding another model of a
+//! family already present, adds nothing: three copies of gpt-4o score 0,
+//! while o1-mini, Nemotron and gpt-4o score 916 (`diversity_amplification`).
+//!
+//! [`effective_tolerance`] turns a diversity score into the number of
+//! faults an ensemble tolerates: n/3 at full diversity, proportionally
+//! fewer below it. [`tolerated_faults`] picks f for an ensemble.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Model family (`ModelFamily` in the specification)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ModelFamily {
+    /// OpenAI o-series reasoning models
+    OpenAiO,
+    /// GPT-4 family (gpt-4o, gpt-4-turbo, gpt-4o-mini)
+    Gpt4,
+    /// NVIDIA Nemotron
+    Nemotron,
+    /// Anthropic Claude
+    Claude,
+    /// Meta Llama
+    Llama,
+    /// A model the registry does not know; unknown models share one family
+    Unknown,
+}
+
+/// How a model family produces its answers (`Architecture` in the
+/// specification)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Architecture {
+    /// Trained to reason at length before answering
+    Reasoning,
+    /// Instruction-tuned chat model answering directly
+    Chat,
+    Unknown,
+}
+
+impl ModelFamily {
+    /// Family of a model id in the built-in registry
+    /// ([`ModelRegistry::builtin`](crate::models::ModelRegistry::builtin),
+    /// `model_family`);
+    /// [`ModelRegistry::family`](crate::models::ModelRegistry::family) for
+    /// a loaded one
+    pub fn of(model_id: u64) -> Self {
+        match model_id {
+            0 => ModelFamily::OpenAiO,  // o1-mini
+            1 => ModelFamily::Nemotron, // nvidia_nemotron_70b
+            2..=4 => ModelFamily::Gpt4, // gpt-4o, gpt-4-turbo, gpt-4o-mini
+            _ => ModelFamily::Unknown,
+        }
+    }
+
+    /// Architecture of the family (`family_architecture`)
+    pub fn architecture(self) -> Architecture {
+        match self {
+            ModelFamily::OpenAiO => Architecture::Reasoning,
+            ModelFamily::Gpt4
+            | ModelFamily::Nemotron
+            | ModelFamily::Claude
+            | ModelFamily::Llama => Architecture::Chat,
+            ModelFamily::Unknown => Architecture::Unknown,
+        }
+    }
+}
+
+/// Ordered pairs of distinct members with different keys
+fn differing_pairs<K: Ord>(keys: impl Iterator<Item = K>) -> u128 {
+    let mut counts = BTreeMap::new();
+    let mut n = 0u128;
+    for key in keys {
+        *counts.entry(key).or_insert(0u128) += 1;
+        n += 1;
+    }
+    let same: u128 = counts.values().map(|c| c * (c - 1)).sum();
+    n * n.saturating_sub(1) - same
+}
+
+/// Diversity of an ensemble, scaled by 1000 and rounded down
+/// (`diversity_score`); 0 for fewer than two models
+pub fn diversity_score(model_ids: &[u64]) -> u64 {
+    let families: Vec<ModelFamily> = model_ids.iter().map(|&id| ModelFamily::of(id)).collect();
+    family_diversity(&families)
+}
+
+/// [`diversity_score`] of an ensemble whose members have `families`, as
+/// a [`ModelRegistry`](crate::models::ModelRegistry) assigns them
+pub fn family_diversity(families: &[ModelFamily]) -> u64 {
+    let n = families.len() as u128;
+    if n < 2 {
+        return 0;
+    }
+    let weighted = 3 * differing_pairs(families.iter())
+        + differing_pairs(families.iter().map(|family| family.architecture()));
+    (weighted * 1000 / (4 * n * (n - 1))) as u64
+}
+
+/// Faults `n` models tolerate at `diversity` (`effective_tolerance`):
+/// `n * diversity / 3000`, at most n/3 and exactly n/3 at full diversity;
+/// diversity above 1000 counts as 1000
+pub fn effective_tolerance(n: u64, diversity: u64) -> u64 {
+    // 128 bits hold n * 1000; the quotient is at most n / 3
+    (n as u128 * diversity.min(1000) as u128 / 3000) as u64
+}
+
+/// Faults an ensemble of `model_ids` tolerates, by its [`diversity_score`]
+pub fn tolerated_faults(model_ids: &[u64]) -> u64 {
+    effective_tolerance(model_ids.len() as u64, diversity_score(model_ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Rng;
+
+    /// `diversity_score` straight from the specification: every ordered
+    /// pair of distinct members
+    fn reference_score(model_ids: &[u64]) -> u64 {
+        let n = model_ids.len() as u64;
+        if n < 2 {
+            return 0;
+        }
+        let mut weighted = 0;
+        for (i, &a) in model_ids.iter().enumerate() {
+            for (j, &b) in model_ids.iter().enumerate() {
+                let (fa, fb) = (ModelFamily::of(a), ModelFamily::of(b));
+                if i != j && fa != fb {
+                    weighted += 3;
+                }
+                if i != j && fa.architecture() != fb.architecture() {
+                    weighted += 1;
+                }
+            }
+        }
+        weighted * 1000 / (4 * n * (n - 1))
+    }
+
+    #[test]
+    fn test_duplicate_models_add_no_diversity() {
+        // Three copies of gpt-4o, or three GPT-4 models, are one family
+        assert_eq!(diversity_score(&[2, 2, 2]), 0);
+        assert_eq!(diversity_score(&[2, 3, 4]), 0);
+        assert_eq!(diversity_score(&[0; 10]), 0);
+        // Unknown models all share one family
+        assert_eq!(diversity_score(&[7, 8, 9]), 0);
+        // One model, or none, has nothing to differ from
+        assert_eq!(diversity_score(&[0]), 0);
+        assert_eq!(diversity_score(&[]), 0);
+    }
+
+    #[test]
+    fn test_diversity_amplification() {
+        // Three families: (3 * 6 + 4) * 1000 / 24
+        let diverse = diversity_score(&[0, 1, 2]);
+        assert_eq!(diverse, 916);
+        // And three architectures
+        assert_eq!(diversity_score(&[0, 1, 9]), 1000);
+        // Two-family mixes land strictly between
+        assert_eq!(diversity_score(&[0, 2, 3]), 666);
+        assert_eq!(diversity_score(&[1, 2, 2]), 500);
+
+        // Every ensemble of three, by how many families it spans
+        for a in 0..7 {
+            for b in 0..7 {
+                for c in 0..7 {
+                    let families: std::collections::BTreeSet<_> =
+                        [a, b, c].iter().map(|&id| ModelFamily::of(id)).collect();
+                    let score = diversity_score(&[a, b, c]);
+                    match families.len() {
+                        1 => assert_eq!(score, 0),
+                        2 => assert!((500..=666).contains(&score), "{:?}", [a, b, c]),
+                        _ => assert!(score >= 750, "{:?}", [a, b, c]),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_effective_tolerance() {
+        // Multiplying first keeps fractional diversity: dividing n by 3
+        // first floored every n = 5 ensemble below 1000 to zero faults
+        assert_eq!(effective_tolerance(5, 900), 1);
+        assert_eq!(effective_tolerance(5, 600), 1);
+        assert_eq!(effective_tolerance(5, 599), 0);
+        assert_eq!(effective_tolerance(8, 916), 2);
+        assert_eq!(effective_tolerance(10, 900), 3);
+        // Diversity above 1000 counts as 1000, without overflow
+        assert_eq!(effective_tolerance(u64::MAX, u64::MAX), u64::MAX / 3);
+
+        let diversities = [
+            0, 1, 100, 333, 500, 599, 600, 666, 750, 900, 916, 999, 1000, 2000,
+        ];
+        for n in 4..=10u64 {
+            assert_eq!(effective_tolerance(n, 1000), n / 3);
+            for &d in &diversities {
+                let f = effective_tolerance(n, d);
+                assert_eq!(f, n * d.min(1000) / 3000);
+                assert!(f <= n / 3, "n = {}, diversity = {}", n, d);
+                // Monotone in both arguments
+                for &d2 in diversities.iter().filter(|&&d2| d2 >= d) {
+                    assert!(f <= effective_tolerance(n, d2));
+                    assert!(f <= effective_tolerance(n + 1, d2));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_tolerated_faults() {
+        // Six copies of one model tolerate nothing; six models of three
+        // families tolerate one, short of the two classical BFT allows
+        assert_eq!(tolerated_faults(&[2, 2, 2, 2, 2, 2]), 0);
+        assert_eq!(tolerated_faults(&[0, 0, 1, 1, 2, 2]), 1);
+        assert_eq!(diversity_score(&[0, 1, 9, 0, 1, 9]), 800);
+        assert_eq!(tolerated_faults(&[0, 1, 9, 0, 1, 9]), 1);
+        assert_eq!(tolerated_faults(&[]), 0);
+    }
+
+    #[test]
+    fn test_matches_specification() {
+        let mut rng = Rng(0x5eed_1588);
+        for _ in 0..2000 {
+            let len = rng.below(12) as usize;
+            let mut ids: Vec<u64> = (0..len).map(|_| rng.below(8)).collect();
+            let score = diversity_score(&ids);
+            assert_eq!(score, reference_score(&ids), "{:?}", ids);
+            assert!(score <= 1000);
+
+            // Order does not matter
+            ids.reverse();
+            assert_eq!(diversity_score(&ids), score);
+        }
+    }
+}
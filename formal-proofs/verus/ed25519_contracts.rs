@@ -331,6 +331,8 @@ proof fn merkle_path_length_bounded(n: u64, proof: MerkleProof)
 // ============================================================================
 
 /// Proof in a verification chain
+///
+/// Executable counterpart: `chain::ChainedProof`, used by the run history ledger.
 pub struct ChainedProof {
     proof_id: Seq<u8>,
     content_hash: Hash,
@@ -385,6 +387,181 @@ proof fn fpc_composition(
     // Proof chaining is associative by hash function properties
 }
 
+// ============================================================================
+// COMMIT-REVEAL VOTING
+// ============================================================================
+
+/// Specification: SHA-256 of a byte string
+pub open spec fn sha256_spec(data: Seq<u8>) -> Hash;
+
+/// AXIOM 6: SHA-256 Collision Resistance
+/// Distinct inputs never hash to the same digest.
+pub proof fn axiom_sha256_collision_resistant(data1: Seq<u8>, data2: Seq<u8>)
+    ensures
+        hash_collision_resistant(sha256_spec(data1), sha256_spec(data2), data1, data2),
+{
+    // Standard cryptographic assumption for SHA-256
+    assume(false);  // Axiom
+}
+
+/// Length of the salt a voter commits with (bytes)
+pub open spec fn commit_salt_len() -> nat {
+    32
+}
+
+/// Length of the per-round nonce a commitment binds (bytes)
+pub open spec fn commit_nonce_len() -> nat {
+    32
+}
+
+/// Length of the voter id a commitment binds (bytes, little-endian u64)
+pub open spec fn commit_voter_len() -> nat {
+    8
+}
+
+/// Specification: The committed preimage: the round's nonce, the voter id,
+/// the answer and the salt
+///
+/// The nonce and voter id keep a commitment from opening in another round
+/// or for another voter, so a published commitment cannot be copied.
+pub open spec fn commit_preimage(nonce: Seq<u8>, voter: Seq<u8>, answer: Seq<u8>, salt: Seq<u8>) -> Seq<u8> {
+    nonce + voter + answer + salt
+}
+
+/// Specification: The commitment published in the commit phase
+///
+/// Executable counterpart: `commit_reveal::commitment`.
+pub open spec fn commitment(nonce: Seq<u8>, voter: Seq<u8>, answer: Seq<u8>, salt: Seq<u8>) -> Hash {
+    sha256_spec(commit_preimage(nonce, voter, answer, salt))
+}
+
+/// Specification: A reveal by `voter` in the round of `nonce` opens a commit
+pub open spec fn reveal_matches(
+    commit: Hash,
+    nonce: Seq<u8>,
+    voter: Seq<u8>,
+    answer: Seq<u8>,
+    salt: Seq<u8>,
+) -> bool {
+    &&& nonce.len() == commit_nonce_len()
+    &&& voter.len() == commit_voter_len()
+    &&& salt.len() == commit_salt_len()
+    &&& commitment(nonce, voter, answer, salt).bytes =~= commit.bytes
+}
+
+/// Specification: The ballot a reveal casts in the reveal phase
+///
+/// A matching reveal casts its answer; a mismatched or missing (late)
+/// reveal is an abstention.
+pub open spec fn revealed_ballot(
+    commit: Hash,
+    nonce: Seq<u8>,
+    voter: Seq<u8>,
+    reveal: Option<(Seq<u8>, Seq<u8>)>,
+) -> Option<Seq<u8>> {
+    match reveal {
+        Some((answer, salt)) => if reveal_matches(commit, nonce, voter, answer, salt) {
+            Some(answer)
+        } else {
+            None
+        },
+        None => None,
+    }
+}
+
+/// THEOREM 7: Commitment Binding
+///
+/// Two reveals that open the same commit come from the same round and
+/// voter and carry the same answer and salt, so a voter cannot change its
+/// answer after seeing the others' commits.
+proof fn commit_binding(
+    commit: Hash,
+    nonce1: Seq<u8>,
+    voter1: Seq<u8>,
+    answer1: Seq<u8>,
+    salt1: Seq<u8>,
+    nonce2: Seq<u8>,
+    voter2: Seq<u8>,
+    answer2: Seq<u8>,
+    salt2: Seq<u8>,
+)
+    requires
+        reveal_matches(commit, nonce1, voter1, answer1, salt1),
+        reveal_matches(commit, nonce2, voter2, answer2, salt2),
+    ensures
+        nonce1 =~= nonce2,
+        voter1 =~= voter2,
+        answer1 =~= answer2,
+        salt1 =~= salt2,
+{
+    let p1 = commit_preimage(nonce1, voter1, answer1, salt1);
+    let p2 = commit_preimage(nonce2, voter2, answer2, salt2);
+    axiom_sha256_collision_resistant(p1, p2);
+    assert(sha256_spec(p1).bytes =~= sha256_spec(p2).bytes);
+    assert(p1 =~= p2);
+    // Fixed-length nonce, voter id and salt split both preimages at the
+    // same indices
+    let k = (commit_nonce_len() + commit_voter_len()) as int;
+    assert(answer1.len() == answer2.len());
+    assert(nonce1 =~= p1.subrange(0, commit_nonce_len() as int));
+    assert(nonce2 =~= p2.subrange(0, commit_nonce_len() as int));
+    assert(voter1 =~= p1.subrange(commit_nonce_len() as int, k));
+    assert(voter2 =~= p2.subrange(commit_nonce_len() as int, k));
+    assert(answer1 =~= p1.subrange(k, k + answer1.len()));
+    assert(answer2 =~= p2.subrange(k, k + answer2.len()));
+    assert(salt1 =~= p1.subrange(k + answer1.len(), p1.len() as int));
+    assert(salt2 =~= p2.subrange(k + answer2.len(), p2.len() as int));
+}
+
+/// THEOREM 8: Revealed Ballot Uniqueness
+///
+/// Every reveal that counts for a commit casts the same ballot; anything
+/// else abstains.
+proof fn revealed_ballot_unique(
+    commit: Hash,
+    nonce: Seq<u8>,
+    voter: Seq<u8>,
+    reveal1: Option<(Seq<u8>, Seq<u8>)>,
+    reveal2: Option<(Seq<u8>, Seq<u8>)>,
+)
+    requires
+        revealed_ballot(commit, nonce, voter, reveal1).is_some(),
+        revealed_ballot(commit, nonce, voter, reveal2).is_some(),
+    ensures
+        revealed_ballot(commit, nonce, voter, reveal1) =~= revealed_ballot(commit, nonce, voter, reveal2),
+{
+    let (answer1, salt1) = reveal1.unwrap();
+    let (answer2, salt2) = reveal2.unwrap();
+    commit_binding(commit, nonce, voter, answer1, salt1, nonce, voter, answer2, salt2);
+}
+
+/// THEOREM 9: Copied Commitments Abstain
+///
+/// A voter that publishes another voter's commitment, or one from another
+/// round, has no reveal that opens it: whatever it reveals abstains.
+proof fn copied_commit_abstains(
+    commit: Hash,
+    nonce: Seq<u8>,
+    voter: Seq<u8>,
+    answer: Seq<u8>,
+    salt: Seq<u8>,
+    copier_nonce: Seq<u8>,
+    copier: Seq<u8>,
+    reveal: Option<(Seq<u8>, Seq<u8>)>,
+)
+    requires
+        reveal_matches(commit, nonce, voter, answer, salt),
+        !(copier_nonce =~= nonce) || !(copier =~= voter),
+    ensures
+        revealed_ballot(commit, copier_nonce, copier, reveal).is_none(),
+{
+    if let Some((copied_answer, copied_salt)) = reveal {
+        if reveal_matches(commit, copier_nonce, copier, copied_answer, copied_salt) {
+            commit_binding(commit, nonce, voter, answer, salt, copier_nonce, copier, copied_answer, copied_salt);
+        }
+    }
+}
+
 // ============================================================================
 // MEMORY SAFETY CONTRACTS (Prusti-style)
 // ============================================================================
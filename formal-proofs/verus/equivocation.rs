@@ -0,0 +1,343 @@
+//! # Equivocation Detection
+//!
+//! A Byzantine voter can send different votes to different collectors. An
+//! [`EquivocationDetector`] ingests [`SignedVote`]s from any number of
+//! collectors and, once a voter key has validly signed both votes for one
+//! round, emits an [`EquivocationProof`] holding the two signed votes.
+//! Anyone can check the proof with the voter's public key alone: both
+//! signatures verify and the votes differ (`valid_equivocation_proof`;
+//! `equivocation_proof_sound` in `byzantine_consensus.rs`).
+//!
+//! A disagreement may be an honest mistake; an equivocation is not, and
+//! [`EquivocationDetector::decays`] charges it
+//! [`Thresholds::equivocation_decay_rate`].
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::consensus::{AgentId, TrustDecay};
+use crate::quorum::SignedVote;
+use crate::Thresholds;
+
+/// Two votes one voter key signed for one round
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EquivocationProof {
+    pub vote_a: SignedVote,
+    pub vote_b: SignedVote,
+}
+
+/// Why an [`EquivocationProof`] does not verify
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquivocationError {
+    /// The votes were signed by different keys
+    DifferentVoters,
+    /// The votes are for different rounds
+    DifferentRounds { round_a: u64, round_b: u64 },
+    /// Both votes are the same: no conflict
+    SameVote,
+    /// A vote's signature does not verify (0 for `vote_a`, 1 for `vote_b`)
+    InvalidSignature { index: usize },
+}
+
+impl fmt::Display for EquivocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EquivocationError::DifferentVoters => {
+                write!(f, "votes are signed by different voters")
+            }
+            EquivocationError::DifferentRounds { round_a, round_b } => {
+                write!(f, "votes are for rounds {} and {}", round_a, round_b)
+            }
+            EquivocationError::SameVote => write!(f, "votes do not conflict"),
+            EquivocationError::InvalidSignature { index } => {
+                write!(f, "vote {}: invalid signature", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EquivocationError {}
+
+impl EquivocationProof {
+    /// Public key of the equivocating voter
+    pub fn voter_pubkey(&self) -> &str {
+        &self.vote_a.voter_pubkey
+    }
+
+    /// Round the voter equivocated in
+    pub fn round_id(&self) -> u64 {
+        self.vote_a.round_id
+    }
+
+    /// Verify the proof, checking signatures with `check_signature`. Voter
+    /// keys are compared case-insensitively.
+    pub fn verify_with<F>(&self, check_signature: F) -> Result<(), EquivocationError>
+    where
+        F: Fn(&SignedVote) -> bool,
+    {
+        let (a, b) = (&self.vote_a, &self.vote_b);
+        if !a.voter_pubkey.eq_ignore_ascii_case(&b.voter_pubkey) {
+            return Err(EquivocationError::DifferentVoters);
+        }
+        if a.round_id != b.round_id {
+            return Err(EquivocationError::DifferentRounds {
+                round_a: a.round_id,
+                round_b: b.round_id,
+            });
+        }
+        if a.vote == b.vote {
+            return Err(EquivocationError::SameVote);
+        }
+        match [a, b].iter().position(|vote| !check_signature(vote)) {
+            Some(index) => Err(EquivocationError::InvalidSignature { index }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "signing")]
+impl EquivocationProof {
+    /// Verify the proof with Ed25519 signature checks
+    pub fn verify(&self) -> Result<(), EquivocationError> {
+        self.verify_with(SignedVote::verify_signature)
+    }
+}
+
+/// Collects signed votes and proves equivocation
+#[derive(Debug, Clone)]
+pub struct EquivocationDetector {
+    check_signature: fn(&SignedVote) -> bool,
+    /// First validly signed vote per (lowercase voter key, round)
+    first: BTreeMap<(String, u64), SignedVote>,
+    /// At most one proof per (lowercase voter key, round)
+    proofs: BTreeMap<(String, u64), EquivocationProof>,
+}
+
+impl EquivocationDetector {
+    /// Detector accepting the votes `check_signature` verifies
+    pub fn with_checker(check_signature: fn(&SignedVote) -> bool) -> Self {
+        Self {
+            check_signature,
+            first: BTreeMap::new(),
+            proofs: BTreeMap::new(),
+        }
+    }
+
+    /// Ingest a vote from any collector
+    ///
+    /// Returns the proof when this vote conflicts with a vote its voter
+    /// already signed for the round. Votes with invalid signatures prove
+    /// nothing and are ignored, as are repeats and further votes from a
+    /// voter already proven to equivocate in the round.
+    pub fn ingest(&mut self, vote: SignedVote) -> Option<EquivocationProof> {
+        if !(self.check_signature)(&vote) {
+            return None;
+        }
+        let key = (vote.voter_pubkey.to_ascii_lowercase(), vote.round_id);
+        if self.proofs.contains_key(&key) {
+            return None;
+        }
+        match self.first.get(&key) {
+            None => {
+                self.first.insert(key, vote);
+                None
+            }
+            Some(first) if first.vote == vote.vote => None,
+            Some(first) => {
+                let proof = EquivocationProof {
+                    vote_a: first.clone(),
+                    vote_b: vote,
+                };
+                self.proofs.insert(key, proof.clone());
+                Some(proof)
+            }
+        }
+    }
+
+    /// Every proof found so far, by voter key and round
+    pub fn proofs(&self) -> impl Iterator<Item = &EquivocationProof> {
+        self.proofs.values()
+    }
+
+    /// Whether `voter_pubkey` has equivocated in any round
+    pub fn is_equivocator(&self, voter_pubkey: &str) -> bool {
+        let voter = voter_pubkey.to_ascii_lowercase();
+        self.proofs.keys().any(|(key, _)| *key == voter)
+    }
+
+    /// A decay at `thresholds.equivocation_decay_rate`, stopping at
+    /// `thresholds.trust_floor`, for each proof whose voter `agent_of` maps
+    /// to an agent
+    pub fn decays<F>(&self, thresholds: &Thresholds, agent_of: F) -> Vec<TrustDecay>
+    where
+        F: Fn(&str) -> Option<AgentId>,
+    {
+        self.proofs()
+            .filter_map(|proof| agent_of(proof.voter_pubkey()))
+            .map(|agent| TrustDecay {
+                agent,
+                decay_rate: thresholds.equivocation_decay_rate,
+                floor: thresholds.trust_floor,
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "signing")]
+impl EquivocationDetector {
+    /// Detector checking Ed25519 signatures
+    pub fn new() -> Self {
+        Self::with_checker(SignedVote::verify_signature)
+    }
+}
+
+#[cfg(feature = "signing")]
+impl Default for EquivocationDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{check, signed};
+
+    #[test]
+    fn test_conflicting_votes_are_proven() {
+        let mut detector = EquivocationDetector::with_checker(check);
+        // Voter 1 tells one collector true and another false
+        assert_eq!(detector.ingest(signed(1, 7, true)), None);
+        assert_eq!(detector.ingest(signed(2, 7, false)), None);
+        assert_eq!(detector.ingest(signed(1, 7, true)), None);
+        let proof = detector.ingest(signed(1, 7, false)).unwrap();
+        assert_eq!(proof.vote_a, signed(1, 7, true));
+        assert_eq!(proof.vote_b, signed(1, 7, false));
+        assert_eq!(
+            (proof.voter_pubkey(), proof.round_id()),
+            (&*"01".repeat(32), 7)
+        );
+        assert_eq!(proof.verify_with(check), Ok(()));
+
+        // One proof per voter and round
+        assert_eq!(detector.ingest(signed(1, 7, true)), None);
+        assert_eq!(detector.proofs().count(), 1);
+        // Votes in different rounds do not conflict
+        assert_eq!(detector.ingest(signed(2, 8, true)), None);
+        assert!(detector.is_equivocator(&"01".repeat(32).to_uppercase()));
+        assert!(!detector.is_equivocator(&"02".repeat(32)));
+
+        // Invalid signatures prove nothing
+        let mut forged = signed(2, 7, true);
+        forged.signature = signed(2, 7, false).signature;
+        assert_eq!(detector.ingest(forged), None);
+        assert!(!detector.is_equivocator(&"02".repeat(32)));
+    }
+
+    #[test]
+    fn test_invalid_proofs_are_rejected() {
+        let proof = |vote_a, vote_b| EquivocationProof { vote_a, vote_b };
+        assert_eq!(
+            proof(signed(1, 7, true), signed(2, 7, false)).verify_with(check),
+            Err(EquivocationError::DifferentVoters)
+        );
+        let err = proof(signed(1, 7, true), signed(1, 8, false))
+            .verify_with(check)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            EquivocationError::DifferentRounds {
+                round_a: 7,
+                round_b: 8
+            }
+        );
+        assert_eq!(err.to_string(), "votes are for rounds 7 and 8");
+        assert_eq!(
+            proof(signed(1, 7, true), signed(1, 7, true)).verify_with(check),
+            Err(EquivocationError::SameVote)
+        );
+
+        // A fabricated second vote carries the first one's signature
+        let mut fabricated = signed(1, 7, false);
+        fabricated.signature = signed(1, 7, true).signature;
+        let err = proof(signed(1, 7, true), fabricated)
+            .verify_with(check)
+            .unwrap_err();
+        assert_eq!(err, EquivocationError::InvalidSignature { index: 1 });
+        assert_eq!(err.to_string(), "vote 1: invalid signature");
+    }
+
+    #[test]
+    fn test_equivocation_decays() {
+        let mut detector = EquivocationDetector::with_checker(check);
+        for (voter, round_id) in [(1, 7), (1, 8), (2, 7), (3, 7)] {
+            detector.ingest(signed(voter, round_id, true));
+            detector.ingest(signed(voter, round_id, false));
+        }
+        assert_eq!(detector.proofs().count(), 4);
+
+        // Voter 3 is not a known agent
+        let agent_of = |key: &str| match &key[..2] {
+            "01" => Some(10),
+            "02" => Some(20),
+            _ => None,
+        };
+        let thresholds = Thresholds::default();
+        let decays = detector.decays(&thresholds, agent_of);
+        let agents: Vec<AgentId> = decays.iter().map(|decay| decay.agent).collect();
+        assert_eq!(agents, [10, 10, 20]);
+        assert!(decays.iter().all(|decay| decay.decay_rate == 500));
+        // Far harsher than a disagreement
+        assert_eq!(decays[0].apply(1000), Ok(500));
+        assert_eq!(decays[0].apply(80), Ok(50));
+        assert!(thresholds.equivocation_decay_rate > thresholds.decay_rate);
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_ed25519_equivocation() {
+        let mut detector = EquivocationDetector::new();
+        let seed = [7u8; 32];
+        assert_eq!(detector.ingest(SignedVote::sign(&seed, 3, true)), None);
+        assert_eq!(
+            detector.ingest(SignedVote::sign(&[8u8; 32], 3, false)),
+            None
+        );
+        let proof = detector.ingest(SignedVote::sign(&seed, 3, false)).unwrap();
+        assert_eq!(proof.verify(), Ok(()));
+
+        // A third party checks the proof from its serialized form
+        let json = serde_json::to_string(&proof).unwrap();
+        let received: EquivocationProof = serde_json::from_str(&json).unwrap();
+        assert_eq!(received.verify(), Ok(()));
+
+        // Altering either vote breaks its signature
+        let mut altered = received.clone();
+        altered.vote_b.vote = true;
+        altered.vote_a.vote = false;
+        assert_eq!(
+            altered.verify(),
+            Err(EquivocationError::InvalidSignature { index: 0 })
+        );
+
+        // Another key's conflicting vote proves nothing about this voter
+        let mut framed = received;
+        framed.vote_b = SignedVote::sign(&[8u8; 32], 3, false);
+        assert_eq!(framed.verify(), Err(EquivocationError::DifferentVoters));
+        framed.vote_b.voter_pubkey = framed.vote_a.voter_pubkey.clone();
+        assert_eq!(
+            framed.verify(),
+            Err(EquivocationError::InvalidSignature { index: 1 })
+        );
+
+        // A vote with a forged signature is never ingested
+        let mut forged = SignedVote::sign(&[9u8; 32], 3, true);
+        forged.vote = false;
+        assert_eq!(detector.ingest(forged), None);
+        assert_eq!(detector.proofs().count(), 1);
+    }
+}
@@ -0,0 +1,171 @@
+//! # Errors
+//!
+//! `ShieldError` is the error type of every fallible library operation that
+//! touches a subprocess, the filesystem or an input format. Each variant
+//! carries the context the runner needs to print its messages, so callers
+//! never have to unwrap or re-wrap an `io::Error`.
+//!
+//! Domain-specific outcomes keep their own types ([`MergeConflict`],
+//! [`BuildError`], [`HistoryError`], [`ParseStatusError`]). A `BuildError`
+//! from a verification run converts into [`ShieldError::InvalidSummary`],
+//! and a [`TrustStateError`] from loading trust state is carried by
+//! [`ShieldError::InvalidTrustState`].
+//!
+//! [`MergeConflict`]: crate::MergeConflict
+//! [`BuildError`]: crate::BuildError
+//! [`HistoryError`]: crate::history::HistoryError
+//! [`ParseStatusError`]: crate::ParseStatusError
+//! [`TrustStateError`]: crate::trust_manager::TrustStateError
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::io;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::builder::BuildError;
+use crate::trust_manager::TrustStateError;
+
+/// Library error
+#[derive(Debug, Error)]
+pub enum ShieldError {
+    /// A tool (`verus`, `cargo`, `git`, ...) could not be started
+    #[error("{tool} not found: {source}")]
+    ToolNotFound {
+        tool: String,
+        #[source]
+        source: io::Error,
+    },
+
+    /// A stage ran to completion but did not succeed
+    #[error("{module}: {}", exit_description(*exit_code))]
+    ToolFailed {
+        module: String,
+        /// Process exit code (None if killed by a signal)
+        exit_code: Option<i32>,
+    },
+
+    /// Malformed input: `context` names the format or location
+    #[error("{context}: {message}")]
+    ParseError { context: String, message: String },
+
+    /// A stage was killed after exceeding its timeout
+    #[error("{module}: timed out after {}s", after.as_secs())]
+    Timeout { module: String, after: Duration },
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    /// A configuration file (config, allowlist, claim registry) could not
+    /// be read or parsed. `context` names the kind of file and its path.
+    #[error("invalid {context}: {message}")]
+    ConfigInvalid { context: String, message: String },
+
+    /// Stage results did not form a valid summary (e.g. duplicate theorems)
+    #[error("invalid verification summary: {0}")]
+    InvalidSummary(#[from] BuildError),
+
+    /// A notification sink could not publish; `sink` is its target
+    #[error("notify {sink}: {message}")]
+    Notify { sink: String, message: String },
+
+    /// A trust state file failed its integrity or validity checks; `path`
+    /// names the file
+    #[error("trust state {path}: {error}")]
+    InvalidTrustState {
+        path: String,
+        error: TrustStateError,
+    },
+}
+
+impl ShieldError {
+    /// Classify a spawn failure: a missing executable is `ToolNotFound`
+    pub(crate) fn spawn(tool: impl Into<String>, err: io::Error) -> Self {
+        if err.kind() == io::ErrorKind::NotFound {
+            ShieldError::ToolNotFound {
+                tool: tool.into(),
+                source: err,
+            }
+        } else {
+            ShieldError::Io(err)
+        }
+    }
+
+    /// Wrap a read or parse failure of a configuration file
+    pub(crate) fn config_invalid(context: impl Into<String>, err: ShieldError) -> Self {
+        let message = match err {
+            ShieldError::ParseError { message, .. }
+            | ShieldError::ConfigInvalid { message, .. } => message,
+            other => other.to_string(),
+        };
+        ShieldError::ConfigInvalid {
+            context: context.into(),
+            message,
+        }
+    }
+}
+
+fn exit_description(exit_code: Option<i32>) -> String {
+    match exit_code {
+        Some(code) => format!("failed with exit code {}", code),
+        None => "killed by a signal".to_string(),
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for ShieldError {
+    fn from(err: serde_json::Error) -> Self {
+        ShieldError::ParseError {
+            context: "JSON".to_string(),
+            message: err.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<toml::de::Error> for ShieldError {
+    fn from(err: toml::de::Error) -> Self {
+        ShieldError::ParseError {
+            context: "TOML".to_string(),
+            message: err.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages() {
+        let err = ShieldError::ToolFailed {
+            module: "trust_bounds".to_string(),
+            exit_code: Some(1),
+        };
+        assert_eq!(err.to_string(), "trust_bounds: failed with exit code 1");
+
+        let err = ShieldError::Timeout {
+            module: "variance_halt".to_string(),
+            after: Duration::from_secs(600),
+        };
+        assert_eq!(err.to_string(), "variance_halt: timed out after 600s");
+
+        let err = ShieldError::spawn("verus", io::Error::from(io::ErrorKind::NotFound));
+        assert!(matches!(err, ShieldError::ToolNotFound { ref tool, .. } if tool == "verus"));
+        let err = ShieldError::spawn("verus", io::Error::from(io::ErrorKind::PermissionDenied));
+        assert!(matches!(err, ShieldError::Io(_)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_conversions() {
+        let err: ShieldError = serde_json::from_str::<u32>("x").unwrap_err().into();
+        assert!(matches!(err, ShieldError::ParseError { ref context, .. } if context == "JSON"));
+
+        let err: ShieldError = toml::from_str::<toml::Table>("= 1").unwrap_err().into();
+        let err = ShieldError::config_invalid("config aevion.toml", err);
+        assert!(err.to_string().starts_with("invalid config aevion.toml: "));
+        assert!(!err.to_string().contains("TOML: "));
+    }
+}
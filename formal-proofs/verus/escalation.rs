@@ -0,0 +1,407 @@
+//! # Halt Escalation
+//!
+//! Executable counterpart of `should_escalate` in `byzantine_consensus.rs`.
+//! One marginal halt is not actionable; five halts in the last ten rounds
+//! are. A [`RoundHistory`] keeps the most recent [`ConsensusOutcome`]s, and
+//! a [`HaltEscalator`] records each round's outcome and escalates to a hard
+//! stop once the halts in the last [`EscalationPolicy::window`] rounds
+//! exceed [`EscalationPolicy::max_halt_rate`] of the window. After a hard
+//! stop, further rounds are refused until an operator calls
+//! [`HaltEscalator::reset`].
+//!
+//! The default policy (more than 400 per mille of 10 rounds) never fires on
+//! the benchmark's baseline or 33% attack profiles, which did not halt. It
+//! fires within the first ten rounds of the 67% attack profile, which
+//! halted 57.8% of the time (289/500). A window in which every round
+//! halted always escalates (`persistent_halts_escalate`).
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::consensus::ConsensusOutcome;
+
+/// Rounds the default policy looks back over
+pub const DEFAULT_ESCALATION_WINDOW: usize = 10;
+
+/// Halt rate (per mille of the window) the default policy tolerates: four
+/// halts in ten rounds pass, five escalate
+pub const DEFAULT_MAX_HALT_RATE: u64 = 400;
+
+/// The most recent consensus outcomes, oldest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundHistory {
+    capacity: usize,
+    outcomes: VecDeque<ConsensusOutcome>,
+}
+
+impl RoundHistory {
+    /// History keeping the last `capacity` outcomes (at least one)
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            outcomes: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Outcomes held, at most [`RoundHistory::capacity`]
+    pub fn len(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outcomes.is_empty()
+    }
+
+    /// Record a round's outcome, dropping the oldest once full
+    pub fn record(&mut self, outcome: ConsensusOutcome) {
+        if self.outcomes.len() == self.capacity {
+            self.outcomes.pop_front();
+        }
+        self.outcomes.push_back(outcome);
+    }
+
+    /// Outcomes held, oldest first
+    pub fn outcomes(&self) -> impl Iterator<Item = &ConsensusOutcome> {
+        self.outcomes.iter()
+    }
+
+    /// Halts among the last `window` rounds held (`count_halts` of
+    /// `recent_rounds`)
+    pub fn halts(&self, window: usize) -> u64 {
+        self.outcomes
+            .iter()
+            .rev()
+            .take(window)
+            .filter(|outcome| matches!(outcome, ConsensusOutcome::Halted { .. }))
+            .count() as u64
+    }
+
+    /// Share of the last `window` rounds held that halted, per mille and
+    /// rounded down; 0 without rounds
+    pub fn halt_rate(&self, window: usize) -> u64 {
+        let rounds = window.min(self.outcomes.len()) as u64;
+        (self.halts(window) * 1000).checked_div(rounds).unwrap_or(0)
+    }
+
+    /// Halts since the last agreement
+    pub fn consecutive_halts(&self) -> u64 {
+        self.outcomes
+            .iter()
+            .rev()
+            .take_while(|outcome| matches!(outcome, ConsensusOutcome::Halted { .. }))
+            .count() as u64
+    }
+}
+
+/// When recent halts escalate to a hard stop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EscalationPolicy {
+    /// Rounds looked back over
+    pub window: usize,
+    /// Halts tolerated, per mille of the window; more escalate
+    pub max_halt_rate: u64,
+}
+
+impl Default for EscalationPolicy {
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_ESCALATION_WINDOW,
+            max_halt_rate: DEFAULT_MAX_HALT_RATE,
+        }
+    }
+}
+
+/// An escalation policy that cannot work, or a round refused
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EscalationError {
+    /// A window of no rounds
+    EmptyWindow,
+    /// A bound of 1000 per mille or more that no window could exceed
+    UnreachableBound { max_halt_rate: u64 },
+    /// Rounds are refused until an operator reset
+    HardStop { halts: u64, window: usize },
+}
+
+impl fmt::Display for EscalationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EscalationError::EmptyWindow => write!(f, "escalation window is empty"),
+            EscalationError::UnreachableBound { max_halt_rate } => write!(
+                f,
+                "halt rate bound {} per mille can never be exceeded",
+                max_halt_rate
+            ),
+            EscalationError::HardStop { halts, window } => write!(
+                f,
+                "hard stop: {} of the last {} rounds halted; rounds refused until reset",
+                halts, window
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EscalationError {}
+
+impl EscalationPolicy {
+    /// Check the policy can escalate at all: a window of at least one round
+    /// and a bound below 1000 per mille
+    pub fn validate(&self) -> Result<(), EscalationError> {
+        if self.window == 0 {
+            return Err(EscalationError::EmptyWindow);
+        }
+        if self.max_halt_rate >= 1000 {
+            return Err(EscalationError::UnreachableBound {
+                max_halt_rate: self.max_halt_rate,
+            });
+        }
+        Ok(())
+    }
+
+    /// The halts in the last `window` rounds of `history` exceed the bound
+    /// (`should_escalate`); the rate is over the whole window even before
+    /// it fills
+    pub fn should_escalate(&self, history: &RoundHistory) -> bool {
+        history.halts(self.window) as u128 * 1000
+            > u128::from(self.max_halt_rate) * self.window as u128
+    }
+}
+
+/// Escalation state across rounds: recent outcomes and any hard stop
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HaltEscalator {
+    policy: EscalationPolicy,
+    history: RoundHistory,
+    stopped: Option<EscalationError>,
+}
+
+impl HaltEscalator {
+    pub fn new(policy: EscalationPolicy) -> Result<Self, EscalationError> {
+        policy.validate()?;
+        Ok(Self {
+            policy,
+            history: RoundHistory::new(policy.window),
+            stopped: None,
+        })
+    }
+
+    pub fn policy(&self) -> &EscalationPolicy {
+        &self.policy
+    }
+
+    pub fn history(&self) -> &RoundHistory {
+        &self.history
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.is_some()
+    }
+
+    /// Whether a new round may start: the hard stop, if escalated
+    pub fn admit(&self) -> Result<(), EscalationError> {
+        match &self.stopped {
+            Some(stop) => Err(stop.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Record a round's outcome, returning the hard stop if this round
+    /// escalates; after a hard stop outcomes are refused as rounds are
+    pub fn record(&mut self, outcome: ConsensusOutcome) -> Result<(), EscalationError> {
+        self.admit()?;
+        self.history.record(outcome);
+        if self.policy.should_escalate(&self.history) {
+            let stop = EscalationError::HardStop {
+                halts: self.history.halts(self.policy.window),
+                window: self.policy.window,
+            };
+            self.stopped = Some(stop.clone());
+            return Err(stop);
+        }
+        Ok(())
+    }
+
+    /// Operator reset: clear the hard stop and start a fresh window
+    pub fn reset(&mut self) {
+        self.stopped = None;
+        self.history = RoundHistory::new(self.policy.window);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Rng;
+    use crate::HaltReason;
+
+    fn outcome(halted: bool) -> ConsensusOutcome {
+        if halted {
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 666 },
+            }
+        } else {
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 1000,
+            }
+        }
+    }
+
+    /// Round `i` of a profile halting `halts` of `total` rounds, spread
+    /// evenly
+    fn spread(halts: u64, total: u64) -> impl Iterator<Item = bool> {
+        (0..total).map(move |i| (i + 1) * halts / total > i * halts / total)
+    }
+
+    /// Index of the round that escalated, replaying `rounds` under the
+    /// default policy
+    fn escalation_point(rounds: impl IntoIterator<Item = bool>) -> Option<usize> {
+        let mut escalator = HaltEscalator::new(EscalationPolicy::default()).unwrap();
+        for (i, halted) in rounds.into_iter().enumerate() {
+            if escalator.record(outcome(halted)).is_err() {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn test_round_history() {
+        let mut history = RoundHistory::new(4);
+        assert_eq!((history.halt_rate(4), history.consecutive_halts()), (0, 0));
+        for halted in [true, false, true, true, true] {
+            history.record(outcome(halted));
+        }
+        // The first halt has dropped out
+        assert_eq!(history.len(), 4);
+        assert_eq!(history.halts(4), 3);
+        assert_eq!(history.halt_rate(4), 750);
+        assert_eq!(history.halt_rate(2), 1000);
+        // A window longer than the history covers what is held
+        assert_eq!(history.halt_rate(10), 750);
+        assert_eq!(history.consecutive_halts(), 3);
+
+        history.record(outcome(false));
+        assert_eq!(history.consecutive_halts(), 0);
+        assert_eq!(RoundHistory::new(0).capacity(), 1);
+    }
+
+    #[test]
+    fn test_policy_validation() {
+        assert_eq!(EscalationPolicy::default().validate(), Ok(()));
+        let empty = EscalationPolicy {
+            window: 0,
+            ..EscalationPolicy::default()
+        };
+        assert_eq!(HaltEscalator::new(empty), Err(EscalationError::EmptyWindow));
+        let unreachable = EscalationPolicy {
+            max_halt_rate: 1000,
+            ..EscalationPolicy::default()
+        };
+        let err = unreachable.validate().unwrap_err();
+        assert_eq!(
+            err,
+            EscalationError::UnreachableBound {
+                max_halt_rate: 1000
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "halt rate bound 1000 per mille can never be exceeded"
+        );
+    }
+
+    #[test]
+    fn test_hard_stop_until_reset() {
+        let mut escalator = HaltEscalator::new(EscalationPolicy::default()).unwrap();
+        // A lone marginal halt is not actionable
+        escalator.record(outcome(true)).unwrap();
+        assert_eq!(escalator.history().halt_rate(10), 1000);
+        assert!(!escalator.is_stopped());
+
+        // Five halts in the last ten rounds are
+        for halted in [false, false, true, false, true, false, false, true] {
+            escalator.record(outcome(halted)).unwrap();
+        }
+        let stop = escalator.record(outcome(true)).unwrap_err();
+        assert_eq!(
+            stop,
+            EscalationError::HardStop {
+                halts: 5,
+                window: 10
+            }
+        );
+        assert_eq!(
+            stop.to_string(),
+            "hard stop: 5 of the last 10 rounds halted; rounds refused until reset"
+        );
+
+        // Refused until an operator reset, agreements included
+        assert_eq!(escalator.admit(), Err(stop.clone()));
+        assert_eq!(escalator.record(outcome(false)), Err(stop));
+        assert_eq!(escalator.history().len(), 10);
+
+        escalator.reset();
+        assert_eq!(escalator.admit(), Ok(()));
+        assert!(escalator.history().is_empty());
+        escalator.record(outcome(false)).unwrap();
+    }
+
+    #[test]
+    fn test_empirical_attack_profiles() {
+        // Baseline and 33% attack: no halts in 500 rounds, no escalation
+        assert_eq!(escalation_point(spread(0, 500)), None);
+        // 67% attack: 289 halts in 500 rounds; spread evenly, the fifth
+        // halt arrives in round 9 (rounds 2, 4, 6, 7 and 9)
+        assert_eq!(spread(289, 500).filter(|&h| h).count(), 289);
+        assert_eq!(escalation_point(spread(289, 500)), Some(8));
+
+        // Four halts in every ten rounds sit at the bound without
+        // exceeding it
+        let four_in_ten = (0..500).map(|i| i % 10 < 4);
+        assert_eq!(escalation_point(four_in_ten), None);
+
+        // The 67% attack's halts in random order still escalate early:
+        // 578 per mille is well above the bound
+        let mut rng = Rng(0x5eed_1595);
+        for _ in 0..200 {
+            let mut rounds: Vec<bool> = spread(289, 500).collect();
+            for i in (1..rounds.len()).rev() {
+                rounds.swap(i, rng.below(i as u64 + 1) as usize);
+            }
+            let point = escalation_point(rounds.iter().copied()).unwrap();
+            assert!(point < 100, "escalated at round {}", point);
+        }
+    }
+
+    #[test]
+    fn test_persistent_halts_escalate() {
+        // persistent_halts_escalate: a full window of halts escalates for
+        // any window and any reachable bound, whatever came before
+        let mut rng = Rng(0x5eed_1596);
+        for _ in 0..500 {
+            let policy = EscalationPolicy {
+                window: 1 + rng.below(20) as usize,
+                max_halt_rate: rng.below(1000),
+            };
+            let mut history = RoundHistory::new(policy.window);
+            for _ in 0..rng.below(30) {
+                history.record(outcome(rng.below(2) == 0));
+            }
+            for _ in 0..policy.window {
+                history.record(outcome(true));
+            }
+            assert!(policy.should_escalate(&history), "{:?}", policy);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! # Dual Validation Evidence
+//!
+//! Claim 81 rests on formal and empirical results together. A
+//! [`VerificationEvidence`] bundles a run's [`VerificationSummary`] with the
+//! benchmark scenarios behind it and derives the headline statistics.
+//!
+//! Statistics are per mille with integer division, the same arithmetic as
+//! `empirical_validation_500` in `byzantine_consensus.rs`, so the runner
+//! reports exactly the values the proof checks (928, 830, 578, 894 for the
+//! 500-sample benchmark).
+//!
+//! Results are loaded from a JSON array:
+//!
+//! ```json
+//! [
+//!   { "scenario": "baseline", "correct": 464, "total": 500, "halted": 0 },
+//!   { "scenario": "byzantine_33", "correct": 415, "total": 500, "halted": 0 }
+//! ]
+//! ```
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fs;
+use std::path::Path;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::ShieldError;
+use crate::VerificationSummary;
+
+/// Default empirical results file name, looked up in the proof directory
+pub const DEFAULT_EMPIRICAL_FILE: &str = "empirical-results.json";
+
+/// No Byzantine agents
+pub const SCENARIO_BASELINE: &str = "baseline";
+/// One of three agents Byzantine (33%)
+pub const SCENARIO_BYZANTINE_33: &str = "byzantine_33";
+/// Two of three agents Byzantine (67%)
+pub const SCENARIO_BYZANTINE_67: &str = "byzantine_67";
+
+/// Outcome counts for one benchmark scenario
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct EmpiricalResults {
+    pub scenario: String,
+    /// Samples answered correctly
+    pub correct: u64,
+    /// Samples run
+    pub total: u64,
+    /// Samples where the system halted instead of answering
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub halted: u64,
+}
+
+impl EmpiricalResults {
+    /// Correct answers per mille of samples (None if no samples)
+    pub fn accuracy(&self) -> Option<u64> {
+        per_mille(self.correct, self.total)
+    }
+
+    /// Halts per mille of samples (None if no samples)
+    pub fn halt_rate(&self) -> Option<u64> {
+        per_mille(self.halted, self.total)
+    }
+
+    fn validate(&self) -> Result<(), ShieldError> {
+        if self.correct.saturating_add(self.halted) > self.total {
+            return Err(ShieldError::ParseError {
+                context: format!("scenario {}", self.scenario),
+                message: format!(
+                    "{} correct + {} halted exceeds {} samples",
+                    self.correct, self.halted, self.total
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Parse a JSON array of scenario results
+#[cfg(feature = "serde")]
+pub fn from_json(text: &str) -> Result<Vec<EmpiricalResults>, ShieldError> {
+    let results: Vec<EmpiricalResults> = serde_json::from_str(text)?;
+    for result in &results {
+        result.validate()?;
+    }
+    Ok(results)
+}
+
+/// Load an empirical results file
+#[cfg(feature = "serde")]
+pub fn load(path: &Path) -> Result<Vec<EmpiricalResults>, ShieldError> {
+    fs::read_to_string(path)
+        .map_err(ShieldError::from)
+        .and_then(|text| from_json(&text))
+        .map_err(|e| {
+            ShieldError::config_invalid(format!("empirical results {}", path.display()), e)
+        })
+}
+
+/// Formal results and the empirical results they are validated against
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerificationEvidence {
+    pub summary: VerificationSummary,
+    /// Benchmark scenarios, in the order they were recorded
+    pub empirical: Vec<EmpiricalResults>,
+}
+
+impl VerificationEvidence {
+    pub fn new(summary: VerificationSummary, empirical: Vec<EmpiricalResults>) -> Self {
+        Self { summary, empirical }
+    }
+
+    /// Results for a scenario
+    pub fn scenario(&self, scenario: &str) -> Option<&EmpiricalResults> {
+        self.empirical.iter().find(|r| r.scenario == scenario)
+    }
+
+    /// Accuracy under a 33% attack relative to the baseline, per mille
+    /// (None if either scenario is missing or has no correct answers)
+    pub fn resilience_factor(&self) -> Option<u64> {
+        let baseline = self.scenario(SCENARIO_BASELINE)?.accuracy()?;
+        let attacked = self.scenario(SCENARIO_BYZANTINE_33)?.accuracy()?;
+        per_mille(attacked, baseline)
+    }
+
+    /// Halts per mille of samples in a scenario
+    pub fn halt_rate(&self, scenario: &str) -> Option<u64> {
+        self.scenario(scenario)?.halt_rate()
+    }
+}
+
+/// `part * 1000 / total`, truncated like the proofs' integer arithmetic
+fn per_mille(part: u64, total: u64) -> Option<u64> {
+    part.checked_mul(1000)?.checked_div(total)
+}
+
+/// Format a per-mille value as a percentage (`928` -> `92.8%`)
+pub fn format_per_mille(value: u64) -> String {
+    format!("{}.{}%", value / 10, value % 10)
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Vec<EmpiricalResults> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join(DEFAULT_EMPIRICAL_FILE);
+        load(&path).unwrap()
+    }
+
+    #[test]
+    fn test_known_values() {
+        let evidence = VerificationEvidence::new(VerificationSummary::new(), fixture());
+        let accuracy = |scenario| evidence.scenario(scenario).unwrap().accuracy();
+
+        assert_eq!(accuracy(SCENARIO_BASELINE), Some(928));
+        assert_eq!(accuracy(SCENARIO_BYZANTINE_33), Some(830));
+        assert_eq!(evidence.halt_rate(SCENARIO_BYZANTINE_67), Some(578));
+        assert_eq!(evidence.resilience_factor(), Some(894));
+
+        assert_eq!(accuracy(SCENARIO_BYZANTINE_67), Some(302));
+        assert_eq!(evidence.halt_rate(SCENARIO_BASELINE), Some(0));
+        assert_eq!(format_per_mille(894), "89.4%");
+    }
+
+    #[test]
+    fn test_missing_and_empty_scenarios() {
+        let empty = EmpiricalResults {
+            scenario: SCENARIO_BASELINE.to_string(),
+            correct: 0,
+            total: 0,
+            halted: 0,
+        };
+        assert_eq!(empty.accuracy(), None);
+
+        let evidence = VerificationEvidence::new(VerificationSummary::new(), vec![empty]);
+        assert_eq!(evidence.resilience_factor(), None);
+        assert_eq!(evidence.halt_rate(SCENARIO_BYZANTINE_67), None);
+    }
+
+    #[test]
+    fn test_rejects_inconsistent_counts() {
+        let text = r#"[{ "scenario": "x", "correct": 300, "total": 500, "halted": 250 }]"#;
+        let err = from_json(text).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "scenario x: 300 correct + 250 halted exceeds 500 samples"
+        );
+        assert!(from_json(r#"[{ "scenario": "x", "total": 5 }]"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_evidence_round_trip() {
+        let evidence = VerificationEvidence::new(VerificationSummary::new(), fixture());
+        let json = serde_json::to_string(&evidence).unwrap();
+        let decoded: VerificationEvidence = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, evidence);
+    }
+}
@@ -0,0 +1,19 @@
+verus! {
+
+// #[verifier::external_body]
+fn line_comment() {}
+
+/* #[verifier::external_body]
+   /* nested */ #[verifier::external] */
+fn block_comment() {}
+
+fn strings() {
+    let a = "#[verifier::external_body]";
+    let b = r#"#[verifier::external_fn_specification] "quoted""#;
+    let c = '#';
+    let d = "escaped \" #[verifier::external]";
+}
+
+fn lifetimes<'a>(x: &'a str) -> &'a str { x }
+
+} // verus!
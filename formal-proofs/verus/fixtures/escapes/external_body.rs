@@ -0,0 +1,18 @@
+verus! {
+
+// Trusted numeric helper
+#[verifier::external_body]
+pub fn trusted_sqrt(x: u64) -> (r: u64)
+    ensures r * r <= x
+{
+    (x as f64).sqrt() as u64
+}
+#[verifier(external_body)]
+fn legacy_form() {}
+
+/// Not verified at all
+#[allow(dead_code)]
+#[verifier::external]
+fn ignored_helper() {}
+
+} // verus!
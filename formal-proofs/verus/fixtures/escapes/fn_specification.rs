@@ -0,0 +1,11 @@
+verus! {
+
+/// Specification for std's Vec::len
+#[verifier::external_fn_specification]
+pub fn ex_vec_len<T>(v: &Vec<T>) -> (len: usize)
+    ensures len == v@.len()
+{
+    v.len()
+}
+
+} // verus!
@@ -0,0 +1,15 @@
+verus! {
+
+/// Attribute path split across lines
+#[verifier::
+    external_body
+]
+fn split_path() {}
+
+/// Escape stacked with another attribute
+#[inline]
+#[ verifier :: external_body ]
+#[allow(unused)]
+pub(crate) fn stacked() {}
+
+} // verus!
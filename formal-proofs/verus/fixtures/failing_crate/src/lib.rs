@@ -0,0 +1,24 @@
+//! Fixture crate exercised by the unit test stage.
+
+pub fn add(a: u64, b: u64) -> u64 {
+    a + b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passes() {
+        assert_eq!(add(2, 2), 4);
+    }
+
+    #[test]
+    fn fails() {
+        assert_eq!(add(2, 2), 5);
+    }
+
+    #[test]
+    #[ignore]
+    fn ignored() {}
+}
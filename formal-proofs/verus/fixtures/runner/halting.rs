@@ -0,0 +1,24 @@
+// Runner test fixture: one axiom and two theorems
+use vstd::prelude::*;
+
+verus! {
+
+pub proof fn axiom_variance_sound(v: int)
+    ensures v * v >= 0,
+{
+    admit();
+}
+
+pub proof fn halt_on_high_variance(v: int)
+    requires v > 625,
+    ensures v > 0,
+{
+}
+
+pub proof fn variance_nonnegative(v: int)
+    ensures v * v >= 0,
+{
+    assert(v * v >= 0) by (nonlinear_arith);
+}
+
+} // verus!
@@ -0,0 +1,580 @@
+//! # Verification History
+//!
+//! Append-only ledger of verification runs (`verification-history.jsonl`).
+//!
+//! Each record stores a digest of the run's report and the digest of the
+//! previous record, forming a hash chain validated with the same
+//! `Hash`/`ChainedProof` types as the proof chain in `ed25519_contracts.rs`.
+//! Editing a record changes its digest; deleting or reordering records
+//! breaks a link.
+//!
+//! Truncating the newest records leaves a valid chain, so [`SummaryHistory`]
+//! also keeps the latest digest in a `<ledger>.head` file and reports a
+//! ledger that ends before it. Anyone who can delete both files can still
+//! erase history; archive the head digest elsewhere when that matters.
+//!
+//! Appends take an exclusive lock on the ledger file, so concurrent runners
+//! sharing a ledger each link to the record actually before theirs.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt;
+use std::fmt::Write as _;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::chain::{self, ChainedProof, Hash};
+use crate::clock;
+use crate::error::ShieldError;
+use crate::{VerificationStatus, VerificationSummary};
+
+/// Default ledger file name
+pub const DEFAULT_HISTORY_FILE: &str = "verification-history.jsonl";
+
+/// One verification run in the ledger
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    /// Unix timestamp (seconds) of the run
+    pub timestamp: u64,
+    /// Overall status as displayed (`verified`, `partial`, `failed`, ...);
+    /// older ledgers hold the debug form (`Verified`)
+    pub overall: String,
+    /// Digest of the run's report
+    pub report_digest: Hash,
+    /// Digest of the previous record (zero for the first record)
+    pub previous_digest: Hash,
+    /// Digest of this record's other fields
+    pub digest: Hash,
+}
+
+impl HistoryRecord {
+    /// Create a record linked to `previous`
+    pub fn new(timestamp: u64, overall: &str, report_digest: Hash, previous: Hash) -> Self {
+        let mut record = Self {
+            timestamp,
+            overall: overall.to_string(),
+            report_digest,
+            previous_digest: previous,
+            digest: Hash::ZERO,
+        };
+        record.digest = record.compute_digest();
+        record
+    }
+
+    /// Digest over every field except `digest` itself
+    pub fn compute_digest(&self) -> Hash {
+        Hash::sha256(
+            format!(
+                "{}\n{}\n{}\n{}",
+                self.timestamp, self.overall, self.report_digest, self.previous_digest
+            )
+            .as_bytes(),
+        )
+    }
+
+    /// The record as a hash-chain entry
+    pub fn as_chained_proof(&self) -> ChainedProof {
+        ChainedProof {
+            proof_id: self.timestamp.to_be_bytes().to_vec(),
+            content_hash: self.digest,
+            previous_hash: self.previous_digest,
+        }
+    }
+}
+
+/// Digest of a verification summary: module statuses, unit tests and overall
+pub fn report_digest(summary: &VerificationSummary) -> Hash {
+    let mut text = String::new();
+    for module in summary.module_names() {
+        let status = summary.status(module).unwrap_or(VerificationStatus::NotRun);
+        let _ = writeln!(text, "{}={:?}", module, status);
+    }
+    let _ = writeln!(text, "unit_tests={:?}", summary.unit_tests);
+    let _ = writeln!(text, "overall={:?}", summary.overall());
+    Hash::sha256(text.as_bytes())
+}
+
+/// Ledger tampering detected by `verify`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistoryError {
+    /// A record's fields no longer match its digest (1-based line)
+    Edited { line: usize },
+    /// A record does not link to its predecessor: a record before it was
+    /// deleted, inserted, or reordered (1-based line)
+    BrokenLink { line: usize },
+    /// The ledger ends before the recorded head: its newest records were
+    /// removed
+    Truncated { head: Hash },
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Edited { line } => write!(f, "line {}: record was edited", line),
+            HistoryError::BrokenLink { line } => write!(
+                f,
+                "line {}: chain broken (a preceding record was removed or altered)",
+                line
+            ),
+            HistoryError::Truncated { head } => write!(
+                f,
+                "ledger ends before its head record {} (newest records were removed)",
+                &head.to_hex()[..12]
+            ),
+        }
+    }
+}
+
+/// Walk the chain and report the first tampered record
+pub fn verify(records: &[HistoryRecord]) -> Result<(), HistoryError> {
+    if let Some(index) = records.iter().position(|r| r.compute_digest() != r.digest) {
+        return Err(HistoryError::Edited { line: index + 1 });
+    }
+    if records
+        .first()
+        .is_some_and(|r| r.previous_digest != Hash::ZERO)
+    {
+        return Err(HistoryError::BrokenLink { line: 1 });
+    }
+    let chain: Vec<ChainedProof> = records.iter().map(|r| r.as_chained_proof()).collect();
+    match chain::first_broken_link(&chain) {
+        Some(index) => Err(HistoryError::BrokenLink { line: index + 1 }),
+        None => Ok(()),
+    }
+}
+
+/// Load all records (a missing file is an empty history)
+pub fn load(path: &Path) -> Result<Vec<HistoryRecord>, ShieldError> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse(&text),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Parse ledger lines, skipping blank ones and a torn final line
+fn parse(text: &str) -> Result<Vec<HistoryRecord>, ShieldError> {
+    let complete = match torn_tail(text) {
+        Some(tail) => &text[..text.len() - tail.len()],
+        None => text,
+    };
+    complete
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| ShieldError::ParseError {
+                context: format!("line {}", i + 1),
+                message: e.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The final line, when it has no newline and is not a record: a writer
+/// died mid-line and the record it was writing never completed
+fn torn_tail(text: &str) -> Option<&str> {
+    if text.ends_with('\n') {
+        return None;
+    }
+    let tail = &text[text.rfind('\n').map_or(0, |i| i + 1)..];
+    let complete = serde_json::from_str::<HistoryRecord>(tail).is_ok();
+    (!tail.is_empty() && !complete).then_some(tail)
+}
+
+/// Append a record for a completed run, linked to the last record in the file
+pub fn append(path: &Path, summary: &VerificationSummary) -> Result<HistoryRecord, ShieldError> {
+    let mut history = SummaryHistory::open(path)?;
+    history.append(summary).cloned()
+}
+
+/// A JSONL ledger of verification runs
+#[derive(Debug, Clone)]
+pub struct SummaryHistory {
+    path: PathBuf,
+    records: Vec<HistoryRecord>,
+}
+
+impl SummaryHistory {
+    /// Load the ledger at `path`. A missing or empty file is an empty
+    /// history; the file is created by the first append.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, ShieldError> {
+        let path = path.into();
+        let records = load(&path)?;
+        Ok(Self { path, records })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Path of the file recording the newest record's digest
+    pub fn head_path(&self) -> PathBuf {
+        let mut path = self.path.as_os_str().to_owned();
+        path.push(".head");
+        PathBuf::from(path)
+    }
+
+    /// Record a completed run, linked to the newest record in the file.
+    ///
+    /// The ledger is locked while it is re-read and appended to, so records
+    /// written by other processes since `open` are linked correctly and
+    /// picked up.
+    pub fn append(&mut self, summary: &VerificationSummary) -> Result<&HistoryRecord, ShieldError> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&self.path)?;
+        file.lock()?;
+
+        let mut text = String::new();
+        file.seek(SeekFrom::Start(0))?;
+        file.read_to_string(&mut text)?;
+        let mut records = parse(&text)?;
+
+        let previous = records.last().map(|r| r.digest).unwrap_or(Hash::ZERO);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let record = HistoryRecord::new(
+            timestamp,
+            summary.overall().as_str(),
+            report_digest(summary),
+            previous,
+        );
+
+        let mut line = serde_json::to_string(&record)?;
+        if let Some(tail) = torn_tail(&text) {
+            // A previous writer died mid-line; drop the torn record, which
+            // `parse` skipped
+            file.set_len((text.len() - tail.len()) as u64)?;
+        } else if !text.is_empty() && !text.ends_with('\n') {
+            // The last record lacks only its newline
+            line.insert(0, '\n');
+        }
+        line.push('\n');
+        file.write_all(line.as_bytes())?;
+        file.sync_data()?;
+        write_head(&self.head_path(), record.digest)?;
+
+        records.push(record);
+        self.records = records;
+        Ok(self.records.last().expect("record was just appended"))
+    }
+
+    /// Check every link, and that the ledger still reaches the recorded head
+    pub fn verify_chain(&self) -> Result<(), HistoryError> {
+        verify(&self.records)?;
+        let head = fs::read_to_string(self.head_path())
+            .ok()
+            .and_then(|text| Hash::from_hex(text.trim()));
+        match head {
+            Some(head) if !self.records.iter().any(|r| r.digest == head) => {
+                Err(HistoryError::Truncated { head })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The newest record
+    pub fn latest(&self) -> Option<&HistoryRecord> {
+        self.records.last()
+    }
+
+    /// Records, oldest first
+    pub fn iter(&self) -> std::slice::Iter<'_, HistoryRecord> {
+        self.records.iter()
+    }
+
+    pub fn records(&self) -> &[HistoryRecord] {
+        &self.records
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a SummaryHistory {
+    type Item = &'a HistoryRecord;
+    type IntoIter = std::slice::Iter<'a, HistoryRecord>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.records.iter()
+    }
+}
+
+/// Replace the head file atomically (temp file + rename)
+fn write_head(path: &Path, digest: Hash) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, format!("{}\n", digest))?;
+    fs::rename(&tmp, path)
+}
+
+/// Compact table of the last `last` records
+pub fn format_table(records: &[HistoryRecord], last: usize) -> String {
+    let start = records.len().saturating_sub(last);
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:<4} {:<20} {:<9} {:<12} REPORT",
+        "#", "TIME (UTC)", "OVERALL", "DIGEST"
+    );
+    for (i, record) in records.iter().enumerate().skip(start) {
+        let _ = writeln!(
+            out,
+            "{:<4} {:<20} {:<9} {:<12} {}",
+            i + 1,
+            format_utc(record.timestamp),
+            record.overall,
+            &record.digest.to_hex()[..12],
+            &record.report_digest.to_hex()[..12]
+        );
+    }
+    out
+}
+
+/// Format a Unix timestamp as `YYYY-MM-DD HH:MM:SS`
+fn format_utc(timestamp: u64) -> String {
+    let (year, month, day) = clock::civil_from_days((timestamp / 86_400) as i64);
+    let secs = timestamp % 86_400;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain_of(n: usize) -> Vec<HistoryRecord> {
+        let mut records: Vec<HistoryRecord> = Vec::new();
+        for i in 0..n {
+            let previous = records.last().map(|r| r.digest).unwrap_or(Hash::ZERO);
+            let report = Hash::sha256(format!("report {}", i).as_bytes());
+            records.push(HistoryRecord::new(
+                1_700_000_000 + i as u64,
+                "Verified",
+                report,
+                previous,
+            ));
+        }
+        records
+    }
+
+    #[test]
+    fn test_valid_chain() {
+        assert_eq!(verify(&[]), Ok(()));
+        assert_eq!(verify(&chain_of(5)), Ok(()));
+    }
+
+    #[test]
+    fn test_tampered_middle_entry() {
+        let mut records = chain_of(5);
+        records[2].overall = "Failed".to_string();
+        assert_eq!(verify(&records), Err(HistoryError::Edited { line: 3 }));
+
+        // Re-sealing the edited record still breaks the next link
+        records[2].digest = records[2].compute_digest();
+        assert_eq!(verify(&records), Err(HistoryError::BrokenLink { line: 4 }));
+    }
+
+    #[test]
+    fn test_deleted_entries() {
+        let mut records = chain_of(5);
+        records.remove(2);
+        assert_eq!(verify(&records), Err(HistoryError::BrokenLink { line: 3 }));
+
+        let mut records = chain_of(3);
+        records.remove(0);
+        assert_eq!(verify(&records), Err(HistoryError::BrokenLink { line: 1 }));
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let path = std::env::temp_dir().join("aevion-history-append.jsonl");
+        let _ = fs::remove_file(&path);
+
+        let mut summary = VerificationSummary::new();
+        let first = append(&path, &summary).unwrap();
+        summary.set_status("trust_bounds", VerificationStatus::Failed);
+        let second = append(&path, &summary).unwrap();
+
+        let records = load(&path).unwrap();
+        assert_eq!(records, [first.clone(), second.clone()]);
+        assert_eq!(second.previous_digest, first.digest);
+        assert_eq!(second.overall, "failed");
+        assert_ne!(first.report_digest, second.report_digest);
+        assert_eq!(verify(&records), Ok(()));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    fn temp_ledger(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aevion-history-{}.jsonl", name));
+        let history = SummaryHistory {
+            path: path.clone(),
+            records: Vec::new(),
+        };
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(history.head_path());
+        path
+    }
+
+    fn cleanup(history: &SummaryHistory) {
+        fs::remove_file(history.path()).unwrap();
+        let _ = fs::remove_file(history.head_path());
+    }
+
+    #[test]
+    fn test_summary_history_round_trip() {
+        let path = temp_ledger("round-trip");
+        let mut history = SummaryHistory::open(&path).unwrap();
+        assert!(history.is_empty());
+        assert_eq!(history.latest(), None);
+
+        let summary = VerificationSummary::new();
+        let first = history.append(&summary).unwrap().clone();
+        let second = history.append(&summary).unwrap().clone();
+        assert_eq!(first.previous_digest, Hash::ZERO);
+        assert_eq!(second.previous_digest, first.digest);
+        assert_eq!(history.latest(), Some(&second));
+
+        let reopened = SummaryHistory::open(&path).unwrap();
+        assert_eq!(reopened.iter().collect::<Vec<_>>(), [&first, &second]);
+        assert_eq!(reopened.verify_chain(), Ok(()));
+        cleanup(&reopened);
+    }
+
+    #[test]
+    fn test_summary_history_tampered_entry() {
+        let path = temp_ledger("tampered");
+        let mut history = SummaryHistory::open(&path).unwrap();
+        for _ in 0..3 {
+            history.append(&VerificationSummary::new()).unwrap();
+        }
+        let text = fs::read_to_string(&path).unwrap();
+        fs::write(&path, text.replacen("partial", "verified", 1)).unwrap();
+
+        let history = SummaryHistory::open(&path).unwrap();
+        assert_eq!(
+            history.verify_chain(),
+            Err(HistoryError::Edited { line: 1 })
+        );
+        cleanup(&history);
+    }
+
+    #[test]
+    fn test_summary_history_deleted_tail() {
+        let path = temp_ledger("deleted-tail");
+        let mut history = SummaryHistory::open(&path).unwrap();
+        for _ in 0..3 {
+            history.append(&VerificationSummary::new()).unwrap();
+        }
+        let head = history.latest().unwrap().digest;
+        let text = fs::read_to_string(&path).unwrap();
+        let kept: Vec<&str> = text.lines().take(2).collect();
+        fs::write(&path, kept.join("\n") + "\n").unwrap();
+
+        let history = SummaryHistory::open(&path).unwrap();
+        // The remaining chain is internally consistent...
+        assert_eq!(verify(history.records()), Ok(()));
+        // ...but no longer reaches the recorded head
+        assert_eq!(
+            history.verify_chain(),
+            Err(HistoryError::Truncated { head })
+        );
+        cleanup(&history);
+    }
+
+    #[test]
+    fn test_summary_history_empty_file() {
+        let path = temp_ledger("empty");
+        fs::write(&path, "").unwrap();
+        let mut history = SummaryHistory::open(&path).unwrap();
+        assert!(history.is_empty());
+        assert_eq!(history.verify_chain(), Ok(()));
+
+        let record = history.append(&VerificationSummary::new()).unwrap().clone();
+        assert_eq!(record.previous_digest, Hash::ZERO);
+        assert_eq!(SummaryHistory::open(&path).unwrap().verify_chain(), Ok(()));
+
+        // A torn final line from a crashed writer does not swallow the next record
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"   ").unwrap();
+        history.append(&VerificationSummary::new()).unwrap();
+        let history = SummaryHistory::open(&path).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.verify_chain(), Ok(()));
+
+        // Half a record: skipped on load, dropped by the next append
+        let text = fs::read_to_string(&path).unwrap();
+        let last = text.trim_end().lines().last().unwrap();
+        file.write_all(&last.as_bytes()[..last.len() / 2]).unwrap();
+        let mut history = SummaryHistory::open(&path).unwrap();
+        assert_eq!(history.len(), 2);
+        let third = history.append(&VerificationSummary::new()).unwrap().clone();
+        assert_eq!(third.previous_digest, history.records()[1].digest);
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 3);
+
+        // A last record missing only its newline is kept
+        let text = fs::read_to_string(&path).unwrap();
+        fs::write(&path, text.trim_end()).unwrap();
+        history.append(&VerificationSummary::new()).unwrap();
+        let history = SummaryHistory::open(&path).unwrap();
+        assert_eq!(history.len(), 4);
+        assert_eq!(history.verify_chain(), Ok(()));
+        cleanup(&history);
+    }
+
+    #[test]
+    fn test_concurrent_appends_stay_linked() {
+        let path = temp_ledger("concurrent");
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..5 {
+                        append(&path, &VerificationSummary::new()).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        let history = SummaryHistory::open(&path).unwrap();
+        assert_eq!(history.len(), 20);
+        assert_eq!(history.verify_chain(), Ok(()));
+        cleanup(&history);
+    }
+
+    #[test]
+    fn test_format_table() {
+        assert_eq!(format_utc(0), "1970-01-01 00:00:00");
+        assert_eq!(format_utc(1_709_251_199), "2024-02-29 23:59:59");
+
+        let table = format_table(&chain_of(5), 2);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("4 "));
+        assert!(lines[2].starts_with("5 "));
+    }
+}
@@ -0,0 +1,415 @@
+//! # Tool Installation
+//!
+//! Plans and runs the installation of the verification toolchain:
+//! Verus (cloned and built from source), its matching Z3, and optionally Prusti.
+//!
+//! ## Layout
+//!
+//! Verus is installed into `$VERUS_HOME`, or `<cache>/aevion-shield/verus`
+//! when unset. The build happens in a `.partial` staging directory that is
+//! only renamed into place, and marked usable, after every step succeeds.
+//! A failed or interrupted install therefore never shadows a working `verus`
+//! on PATH.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! cargo run --bin verify_all -- install-tools --dry-run
+//! cargo run --bin verify_all -- install-tools --yes --with-prusti
+//! ```
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::error::ShieldError;
+
+/// Upstream Verus repository
+pub const VERUS_REPO: &str = "https://github.com/verus-lang/verus";
+
+/// Marker written once an install completed successfully
+pub const INSTALL_MARKER: &str = ".aevion-installed";
+
+/// Verus binary location relative to the install root
+const VERUS_BINARY: &str = "source/target-verus/release/verus";
+
+/// Resolved installation directories
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolPaths {
+    /// Verus install root
+    pub verus_home: PathBuf,
+}
+
+impl ToolPaths {
+    /// Resolve from the process environment
+    pub fn from_env() -> Option<Self> {
+        Self::resolve(|key| std::env::var(key).ok())
+    }
+
+    /// Resolve from an environment lookup: `VERUS_HOME`, then
+    /// `$XDG_CACHE_HOME/aevion-shield/verus`, then `$HOME/.cache/aevion-shield/verus`
+    pub fn resolve(env: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let non_empty = |key: &str| env(key).filter(|v| !v.is_empty()).map(PathBuf::from);
+
+        let verus_home = match non_empty("VERUS_HOME") {
+            Some(home) => home,
+            None => non_empty("XDG_CACHE_HOME")
+                .or_else(|| non_empty("HOME").map(|h| h.join(".cache")))?
+                .join("aevion-shield")
+                .join("verus"),
+        };
+        Some(Self { verus_home })
+    }
+
+    /// Staging directory used while building
+    pub fn staging_dir(&self) -> PathBuf {
+        let mut staging = self.verus_home.as_os_str().to_owned();
+        staging.push(".partial");
+        PathBuf::from(staging)
+    }
+
+    /// Verus binary inside the install root
+    pub fn verus_binary(&self) -> PathBuf {
+        self.verus_home.join(VERUS_BINARY)
+    }
+
+    /// A completed install: marker present and binary built
+    pub fn is_installed(&self) -> bool {
+        self.verus_home.join(INSTALL_MARKER).is_file() && self.verus_binary().is_file()
+    }
+}
+
+/// Verus executable to use: a completed managed install, else `verus` on PATH
+pub fn find_verus(paths: Option<&ToolPaths>) -> PathBuf {
+    match paths {
+        Some(paths) if paths.is_installed() => paths.verus_binary(),
+        _ => PathBuf::from("verus"),
+    }
+}
+
+/// Installation options
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstallOptions {
+    /// Also run `cargo install prusti`
+    pub with_prusti: bool,
+    /// Reinstall even if a completed install exists
+    pub force: bool,
+}
+
+/// A single command in an install plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallStep {
+    pub description: String,
+    pub program: String,
+    pub args: Vec<String>,
+    /// Working directory (None = current directory)
+    pub cwd: Option<PathBuf>,
+}
+
+impl fmt::Display for InstallStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(cwd) = &self.cwd {
+            write!(f, "(cd {} && ", cwd.display())?;
+        }
+        write!(f, "{}", self.program)?;
+        for arg in &self.args {
+            if arg.contains(' ') {
+                write!(f, " '{}'", arg)?;
+            } else {
+                write!(f, " {}", arg)?;
+            }
+        }
+        if self.cwd.is_some() {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Ordered install commands plus the directories they touch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallPlan {
+    pub paths: ToolPaths,
+    pub steps: Vec<InstallStep>,
+    /// Verus is already installed and will not be rebuilt
+    pub verus_up_to_date: bool,
+}
+
+fn step(description: &str, program: &str, args: &[&str], cwd: Option<PathBuf>) -> InstallStep {
+    InstallStep {
+        description: description.to_string(),
+        program: program.to_string(),
+        args: args.iter().map(|a| a.to_string()).collect(),
+        cwd,
+    }
+}
+
+/// Plan the install without touching the filesystem or network
+pub fn plan(paths: &ToolPaths, options: &InstallOptions) -> InstallPlan {
+    let verus_up_to_date = paths.is_installed() && !options.force;
+    let mut steps = Vec::new();
+
+    if !verus_up_to_date {
+        let staging = paths.staging_dir();
+        let staging_str = staging.to_string_lossy().into_owned();
+        let source = staging.join("source");
+
+        steps.push(step(
+            "Clone Verus",
+            "git",
+            &["clone", "--depth", "1", VERUS_REPO, &staging_str],
+            None,
+        ));
+        steps.push(step(
+            "Fetch matching Z3",
+            "./tools/get-z3.sh",
+            &[],
+            Some(source.clone()),
+        ));
+        steps.push(step(
+            "Build Verus",
+            "bash",
+            &["-c", "source ../tools/activate && vargo build --release"],
+            Some(source),
+        ));
+    }
+    if options.with_prusti {
+        steps.push(step(
+            "Install Prusti",
+            "cargo",
+            &["install", "prusti"],
+            None,
+        ));
+    }
+
+    InstallPlan {
+        paths: paths.clone(),
+        steps,
+        verus_up_to_date,
+    }
+}
+
+/// Progress callbacks while executing a plan
+pub trait InstallProgress {
+    fn step_started(&mut self, index: usize, total: usize, step: &InstallStep);
+}
+
+/// Execute a plan, streaming each command's output to the terminal.
+///
+/// Verus is built in the staging directory and promoted to `verus_home` with
+/// the install marker only after every Verus step succeeds. On failure the
+/// staging directory is removed.
+pub fn execute(plan: &InstallPlan, progress: &mut dyn InstallProgress) -> Result<(), ShieldError> {
+    let staging = plan.paths.staging_dir();
+    if !plan.verus_up_to_date && staging.exists() {
+        // Leftover from an interrupted install
+        fs::remove_dir_all(&staging)?;
+    }
+
+    let total = plan.steps.len();
+    for (index, step) in plan.steps.iter().enumerate() {
+        progress.step_started(index + 1, total, step);
+        if let Err(err) = run_step(step) {
+            if staging.exists() {
+                let _ = fs::remove_dir_all(&staging);
+            }
+            return Err(err);
+        }
+    }
+
+    if !plan.verus_up_to_date {
+        promote(&staging, &plan.paths.verus_home)?;
+    }
+    Ok(())
+}
+
+fn run_step(step: &InstallStep) -> Result<(), ShieldError> {
+    let mut command = Command::new(&step.program);
+    command.args(&step.args);
+    if let Some(cwd) = &step.cwd {
+        command.current_dir(cwd);
+    }
+    // Inherited stdio streams progress from git/cargo directly
+    let status = command
+        .status()
+        .map_err(|err| ShieldError::spawn(&step.program, err))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("{} failed with {}", step.description, status)).into())
+    }
+}
+
+/// Move a completed staging build into place and mark it usable
+fn promote(staging: &Path, verus_home: &Path) -> io::Result<()> {
+    if verus_home.exists() {
+        fs::remove_dir_all(verus_home)?;
+    }
+    if let Some(parent) = verus_home.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(staging, verus_home)?;
+    fs::write(verus_home.join(INSTALL_MARKER), crate::VERSION)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    fn temp_home(name: &str) -> ToolPaths {
+        let dir = std::env::temp_dir().join(format!("aevion-install-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        ToolPaths {
+            verus_home: dir.join("verus"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_verus_home() {
+        let paths = ToolPaths::resolve(env(&[("VERUS_HOME", "/opt/verus"), ("HOME", "/h")]));
+        assert_eq!(paths.unwrap().verus_home, PathBuf::from("/opt/verus"));
+
+        let paths = ToolPaths::resolve(env(&[("XDG_CACHE_HOME", "/xdg"), ("HOME", "/h")]));
+        assert_eq!(
+            paths.unwrap().verus_home,
+            PathBuf::from("/xdg/aevion-shield/verus")
+        );
+
+        let paths = ToolPaths::resolve(env(&[("VERUS_HOME", ""), ("HOME", "/h")]));
+        assert_eq!(
+            paths.unwrap().verus_home,
+            PathBuf::from("/h/.cache/aevion-shield/verus")
+        );
+
+        assert_eq!(ToolPaths::resolve(env(&[])), None);
+    }
+
+    #[test]
+    fn test_dry_run_plan() {
+        let paths = ToolPaths {
+            verus_home: PathBuf::from("/cache/verus"),
+        };
+        let plan = plan(
+            &paths,
+            &InstallOptions {
+                with_prusti: true,
+                force: false,
+            },
+        );
+        let rendered: Vec<String> = plan.steps.iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            rendered,
+            [
+                "git clone --depth 1 https://github.com/verus-lang/verus /cache/verus.partial",
+                "(cd /cache/verus.partial/source && ./tools/get-z3.sh)",
+                "(cd /cache/verus.partial/source && bash -c 'source ../tools/activate && vargo build --release')",
+                "cargo install prusti",
+            ]
+        );
+        assert!(!plan.verus_up_to_date);
+    }
+
+    #[test]
+    fn test_discovery_requires_marker() {
+        let paths = temp_home("marker");
+        assert_eq!(find_verus(Some(&paths)), PathBuf::from("verus"));
+
+        // A built binary without the marker (half-finished install) is ignored
+        let binary = paths.verus_binary();
+        fs::create_dir_all(binary.parent().unwrap()).unwrap();
+        fs::write(&binary, "").unwrap();
+        assert!(!paths.is_installed());
+        assert_eq!(find_verus(Some(&paths)), PathBuf::from("verus"));
+
+        fs::write(paths.verus_home.join(INSTALL_MARKER), "0.1.0").unwrap();
+        assert!(paths.is_installed());
+        assert_eq!(find_verus(Some(&paths)), binary);
+
+        // Installed toolchain is not rebuilt unless forced
+        let skip = plan(&paths, &InstallOptions::default());
+        assert!(skip.verus_up_to_date);
+        assert!(skip.steps.is_empty());
+        let forced = plan(
+            &paths,
+            &InstallOptions {
+                force: true,
+                ..InstallOptions::default()
+            },
+        );
+        assert_eq!(forced.steps.len(), 3);
+
+        fs::remove_dir_all(paths.verus_home.parent().unwrap()).unwrap();
+    }
+
+    struct Recorder(Vec<String>);
+
+    impl InstallProgress for Recorder {
+        fn step_started(&mut self, index: usize, total: usize, step: &InstallStep) {
+            self.0
+                .push(format!("[{}/{}] {}", index, total, step.description));
+        }
+    }
+
+    #[test]
+    fn test_failed_step_leaves_no_usable_toolchain() {
+        let paths = temp_home("failure");
+        let staging = paths.staging_dir();
+        let plan = InstallPlan {
+            paths: paths.clone(),
+            steps: vec![
+                step(
+                    "Create staging",
+                    "mkdir",
+                    &["-p", &staging.to_string_lossy()],
+                    None,
+                ),
+                step("Fail", "false", &[], None),
+            ],
+            verus_up_to_date: false,
+        };
+
+        let mut progress = Recorder(Vec::new());
+        assert!(execute(&plan, &mut progress).is_err());
+        assert_eq!(progress.0, ["[1/2] Create staging", "[2/2] Fail"]);
+        assert!(!staging.exists());
+        assert!(!paths.is_installed());
+    }
+
+    #[test]
+    fn test_successful_steps_promote_staging() {
+        let paths = temp_home("success");
+        let staging = paths.staging_dir();
+        let binary = staging.join(VERUS_BINARY);
+        let plan = InstallPlan {
+            paths: paths.clone(),
+            steps: vec![
+                step(
+                    "Create build dir",
+                    "mkdir",
+                    &["-p", &binary.parent().unwrap().to_string_lossy()],
+                    None,
+                ),
+                step("Build", "touch", &[&binary.to_string_lossy()], None),
+            ],
+            verus_up_to_date: false,
+        };
+
+        execute(&plan, &mut Recorder(Vec::new())).unwrap();
+        assert!(!staging.exists());
+        assert!(paths.is_installed());
+        fs::remove_dir_all(paths.verus_home.parent().unwrap()).unwrap();
+    }
+}
@@ -9,6 +9,97 @@
 //! - `byzantine_consensus`: Core BFT theorems
 //! - `ed25519_contracts`: Cryptographic operation contracts
 //!
+//! ## Core
+//!
+//! - `core`: `VerificationStatus`, `Severity`, `TheoremResult` and `ModuleReport`;
+//!   `no_std` + `alloc`, always available
+//!
+//! ## Runner Support
+//!
+//! Everything below requires the `std` feature.
+//!
+//! - `stage`: Subprocess execution with timeouts, shared by all stages
+//! - `unit_tests`: `cargo test` stage and libtest result parsing
+//! - `source_scan`: Theorem, axiom and escape inventory from proof sources
+//! - `allowlist`: Reviewed axioms and escapes
+//! - `severity`: `SeverityRegistry` of critical and informational theorems
+//! - `solver_stats`: rlimit and quantifier instantiation statistics
+//! - `metrics`: `MetricsSnapshot` with Prometheus text and flat JSON encoders
+//! - `config`: Proof target configuration and discovery
+//! - `diff`: Regression detection between two summaries
+//! - `error`: `ShieldError`, the library-wide error type
+//! - `evidence`: Empirical benchmark results bundled with a summary (Claim 81)
+//! - `diagnostics`: Prover error parsing with source locations
+//! - `properties`: Verified properties P1-P6, their status and executable checks
+//! - `render`: rustc-style annotated snippet rendering
+//! - `report`: `RenderSummary` output formats for summaries (plain text)
+//! - `runner`: `run_verification`, the whole verification run as a library
+//!   call, with `CommandRunner`/`ProgressObserver` hooks
+//! - `install`: Verus/Z3/Prusti installation and managed-toolchain discovery
+//! - `container`: Sandboxed prover execution via docker/podman
+//! - `builder`: `VerificationSummaryBuilder` with invariant checks
+//! - `chain`: Executable `Hash`/`ChainedProof` hash chains
+//! - `consensus`: Executable `decide_consensus`, matching the
+//!   `byzantine_consensus` specification
+//! - `quorum`: `QuorumCertificate`, a consensus outcome with the signed
+//!   votes it was decided from
+//! - `pbft`: `PbftRound`, prepare/commit quorum tracking for one PBFT round
+//! - `round`: `ConsensusRound`, votes collected as they arrive with early
+//!   decision, and `RoundEvidence`, the numbers behind an outcome
+//! - `collect` (feature `async`): `collect_and_decide`, a round over vote
+//!   futures with per-voter timeouts and early decision
+//! - `commit_reveal`: `CommitRevealRound`, votes committed as SHA-256
+//!   hashes before any is revealed, so voters cannot copy answers
+//! - `equivocation`: `EquivocationDetector`, verifiable proofs of voters
+//!   signing conflicting votes
+//! - `diversity`: `ModelFamily` registry and ensemble `diversity_score`
+//! - `models`: `ModelRegistry`, model weights and families loaded from the
+//!   runner config and validated against the proven bounds
+//! - `escalation`: `RoundHistory` of recent outcomes and `HaltEscalator`,
+//!   a hard stop once too many recent rounds halted
+//! - `simulation` (feature `simulation`): Byzantine attack scenarios run
+//!   through `final_verdict`, reported as empirical results
+//! - `trust`: `TrustScore` and `AgentTrust`, the verified trust updates
+//!   with their preconditions checked at runtime
+//! - `trust_manager`: `TrustManager`, every agent's trust updated
+//!   together from a round's evidence, with `TrustStore` persistence,
+//!   per-family `TrustPriors` for new agents and `TrustEvent`s for
+//!   monitors
+//! - `trust_audit`: `TrustAuditLog`, hash-chained and optionally signed
+//!   trust mutations, replayable against a live `TrustManager`
+//! - `selection`: `select_ensemble`, the most trusted agents for future
+//!   queries with no model family in the majority
+//! - `variance`: `variance_scaled`, the variance of a round's outputs
+//!   with the `variance_halt` preconditions checked at runtime
+//! - `claims`: Patent claim -> evidence registry and claim status roll-up
+//! - `dependency`: `TheoremGraph` of theorem -> lemma/axiom prerequisites
+//! - `clock`: Time source and RFC 3339 timestamps
+//! - `history`: Hash-chained ledger of verification runs
+//! - `merge`: Combining summaries from several runners
+//! - `notify`: `SummarySink` notifications to stdout, files and webhooks
+//!
+//! ## Features
+//!
+//! - `std` (default): Summaries, runner support and everything touching
+//!   processes, files or the clock. Without it the crate is `no_std` and
+//!   provides only the `core` types; check with
+//!   `cargo build --no-default-features --target thumbv7em-none-eabihf`.
+//! - `serde` (default): `Serialize`/`Deserialize` for `VerificationStatus`,
+//!   `VerificationSummary` and `UnitTestResults`, and the proof certificate.
+//!   Works without `std` for the `core` types. With `std` but without
+//!   it, the TOML and JSON loaders, `history` and `MetricsSnapshot::to_json`
+//!   are unavailable. The `verify_all` runner requires `std` and `serde`.
+//! - `signing`: Ed25519 signatures on proof certificates (`ed25519-dalek`);
+//!   implies `serde`
+//! - `schema`: `JsonSchema` derives on the report types (`schemars`) and
+//!   [`report_json_schema`]; implies `std` and `serde`
+//! - `webhook`: `notify::WebhookSink`, posting run notifications over HTTP
+//!   (`reqwest`); implies `std` and `serde`
+//! - `async`: `collect::collect_and_decide`, collecting votes from futures
+//!   with per-voter timeouts (`tokio`); implies `std`
+//! - `simulation`: `simulation::simulate` and the runner's `simulate`
+//!   subcommand, Byzantine attacks over synthetic samples; implies `std`
+//!
 //! ## Verification Commands
 //!
 //! ```bash
@@ -35,8 +126,150 @@
 //!
 //! Copyright (c) 2026 Aevion LLC. All rights reserved.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(unused)]
 
+extern crate alloc;
+
+pub mod core;
+
+#[cfg(feature = "std")]
+pub mod allowlist;
+#[cfg(feature = "std")]
+pub mod builder;
+#[cfg(feature = "std")]
+pub mod chain;
+#[cfg(feature = "std")]
+pub mod claims;
+#[cfg(feature = "std")]
+pub mod clock;
+#[cfg(feature = "async")]
+pub mod collect;
+#[cfg(feature = "std")]
+pub mod commit_reveal;
+#[cfg(feature = "std")]
+pub mod config;
+#[cfg(feature = "std")]
+pub mod consensus;
+#[cfg(feature = "std")]
+pub mod container;
+#[cfg(feature = "std")]
+pub mod dependency;
+#[cfg(feature = "std")]
+pub mod diagnostics;
+#[cfg(feature = "std")]
+pub mod diff;
+#[cfg(feature = "std")]
+pub mod diversity;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub mod equivocation;
+#[cfg(feature = "std")]
+pub mod error;
+#[cfg(feature = "std")]
+pub mod escalation;
+#[cfg(feature = "std")]
+pub mod evidence;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub mod history;
+#[cfg(feature = "std")]
+pub mod install;
+#[cfg(feature = "std")]
+pub mod merge;
+#[cfg(feature = "std")]
+pub mod metrics;
+#[cfg(feature = "std")]
+pub mod models;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub mod notify;
+#[cfg(feature = "std")]
+pub mod pbft;
+#[cfg(feature = "std")]
+pub mod properties;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub mod quorum;
+#[cfg(feature = "std")]
+pub mod render;
+#[cfg(feature = "std")]
+pub mod report;
+#[cfg(feature = "std")]
+pub mod round;
+#[cfg(feature = "std")]
+pub mod runner;
+#[cfg(feature = "std")]
+pub mod selection;
+#[cfg(feature = "std")]
+pub mod severity;
+#[cfg(feature = "simulation")]
+pub mod simulation;
+#[cfg(feature = "std")]
+pub mod solver_stats;
+#[cfg(feature = "std")]
+pub mod source_scan;
+#[cfg(feature = "std")]
+pub mod stage;
+#[cfg(feature = "std")]
+pub mod trust;
+#[cfg(feature = "std")]
+pub mod trust_audit;
+#[cfg(feature = "std")]
+pub mod trust_manager;
+#[cfg(feature = "std")]
+pub mod unit_tests;
+#[cfg(feature = "std")]
+pub mod variance;
+
+#[cfg(all(test, feature = "std"))]
+mod test_support;
+
+#[cfg(feature = "std")]
+pub use builder::{BuildError, ModuleBuilder, VerificationSummaryBuilder};
+#[cfg(feature = "std")]
+pub use claims::{Claim, ClaimRegistry, ClaimStatus};
+#[cfg(feature = "std")]
+pub use dependency::TheoremGraph;
+#[cfg(feature = "std")]
+pub use diff::SummaryDiff;
+#[cfg(feature = "std")]
+pub use error::ShieldError;
+#[cfg(feature = "std")]
+pub use evidence::{EmpiricalResults, VerificationEvidence};
+#[cfg(all(feature = "std", feature = "serde"))]
+pub use history::SummaryHistory;
+#[cfg(feature = "std")]
+pub use merge::{MergeConflict, MergePolicy};
+#[cfg(feature = "std")]
+pub use metrics::MetricsSnapshot;
+#[cfg(feature = "std")]
+pub use properties::Property;
+#[cfg(feature = "std")]
+pub use runner::run_verification;
+#[cfg(feature = "std")]
+pub use severity::SeverityRegistry;
+#[cfg(feature = "std")]
+pub use unit_tests::UnitTestResults;
+
+pub use crate::core::{
+    ConsensusOutcome, HaltReason, ModuleReport, ParseStatusError, Severity, TheoremResult,
+    Threshold, VerificationStatus,
+};
+
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BTreeSet};
+#[cfg(feature = "std")]
+use std::fs;
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+#[cfg(all(feature = "std", feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "std")]
+use crate::chain::Hash;
+#[cfg(feature = "std")]
+use crate::clock::{Clock, SystemClock};
+
 // NOTE: Verus proof files (variance_halt.rs, trust_bounds.rs, etc.) are standalone
 // verification files. They are NOT compiled as Rust modules.
 //
@@ -52,86 +285,1922 @@
 /// Library version
 pub const VERSION: &str = "0.1.0";
 
-/// Verification status
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum VerificationStatus {
-    /// All proofs verified successfully
-    Verified,
-    /// Some proofs pending
-    Partial,
-    /// Verification failed
-    Failed,
+/// Built-in module names, in verification order
+pub const MODULE_VARIANCE_HALT: &str = "variance_halt";
+pub const MODULE_TRUST_BOUNDS: &str = "trust_bounds";
+pub const MODULE_BYZANTINE_CONSENSUS: &str = "byzantine_consensus";
+pub const MODULE_ED25519_CONTRACTS: &str = "ed25519_contracts";
+
+/// Where (part of) a summary's results came from
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RunSource {
+    /// Runner label (e.g. `linux-x86_64`)
+    pub label: String,
+    /// Unix timestamp (seconds) of the run
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub timestamp: Option<u64>,
+    /// `run_id` of the summary this source was recorded in
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub run_id: Option<String>,
+    /// Tool name -> reported version (`verus`, `prusti`, `rustc`)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub toolchain: BTreeMap<String, String>,
 }
 
 /// Summary of verification results
-#[derive(Debug)]
+///
+/// Module reports are kept in verification order, so configured targets
+/// beyond the four built-ins participate in the roll-up. Module statuses
+/// reported by the accessors are derived from theorem-level data (see
+/// [`ModuleReport::derived_status`]).
+///
+/// Serialized with its schema version (see [`SUMMARY_VERSION`]); older
+/// versions are upgraded on load:
+///
+/// ```json
+/// {
+///   "schema_version": 2,
+///   "modules": [{
+///     "name": "variance_halt",
+///     "status": "verified",
+///     "theorems": [{
+///       "name": "halt_on_high_variance",
+///       "status": "verified",
+///       "duration": null
+///     }],
+///     "duration": { "secs": 12, "nanos": 0 },
+///     "axioms": []
+///   }],
+///   "unit_tests": "verified",
+///   "unit_test_results": null,
+///   "sources": [{ "label": "linux-x86_64", "timestamp": 1790000000, ... }],
+///   "started_at": "2026-09-21T14:13:20.000Z",
+///   "finished_at": "2026-09-21T14:14:02.125Z",
+///   "total_duration": { "secs": 42, "nanos": 125000000 },
+///   "run_id": "5f0c..."
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "SummaryDocument", try_from = "serde_json::Value")
+)]
 pub struct VerificationSummary {
-    pub variance_halt: VerificationStatus,
-    pub trust_bounds: VerificationStatus,
-    pub byzantine_consensus: VerificationStatus,
-    pub ed25519_contracts: VerificationStatus,
+    /// Module reports in verification order
+    modules: Vec<ModuleReport>,
+    /// Standard `cargo test` stage
+    pub unit_tests: VerificationStatus,
+    /// Parsed libtest counts (None if the stage did not run or produced no summary)
+    pub unit_test_results: Option<UnitTestResults>,
+    /// Runs that produced these results: one per run, several after a merge
+    pub sources: Vec<RunSource>,
+    /// Start of the run (RFC 3339, UTC)
+    pub started_at: String,
+    /// End of the run, set by [`finish`](Self::finish)
+    pub finished_at: Option<String>,
+    /// Wall-clock time from start to finish
+    pub total_duration: Option<Duration>,
+    /// Content hash identifying the run, set by [`finish`](Self::finish).
+    /// Archived copies of the same run share it.
+    pub run_id: Option<String>,
 }
 
+#[cfg(feature = "std")]
 impl VerificationSummary {
-    /// Create a new summary (all pending initially)
+    /// Create a new summary for the built-in modules (all not run initially)
     pub fn new() -> Self {
+        Self::with_modules(config::BUILTIN_MODULES.iter().map(|(name, _, _)| *name))
+    }
+
+    /// Create a new summary for the given modules (all not run initially)
+    pub fn with_modules<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::with_clock(names, &SystemClock)
+    }
+
+    /// Create a new summary for the given modules, starting the run at the
+    /// clock's current time
+    pub fn with_clock<I, S>(names: I, clock: &dyn Clock) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
         Self {
-            variance_halt: VerificationStatus::Partial,
-            trust_bounds: VerificationStatus::Partial,
-            byzantine_consensus: VerificationStatus::Partial,
-            ed25519_contracts: VerificationStatus::Partial,
+            modules: names.into_iter().map(ModuleReport::new).collect(),
+            unit_tests: VerificationStatus::NotRun,
+            unit_test_results: None,
+            sources: Vec::new(),
+            started_at: clock::format_rfc3339(clock.now()),
+            finished_at: None,
+            total_duration: None,
+            run_id: None,
         }
     }
 
-    /// Check if all modules are verified
+    /// Seal the run: record the end time, total duration and run id
+    pub fn finish(&mut self) {
+        self.finish_with_clock(&SystemClock);
+    }
+
+    /// [`finish`](Self::finish) using the given clock
+    pub fn finish_with_clock(&mut self, clock: &dyn Clock) {
+        let now = clock.now();
+        self.finished_at = Some(clock::format_rfc3339(now));
+        self.total_duration = clock::parse_rfc3339(&self.started_at)
+            .and_then(|started| now.duration_since(started).ok());
+        self.seal();
+    }
+
+    /// Set `run_id` from the current contents and stamp it on sources that
+    /// have none
+    pub(crate) fn seal(&mut self) {
+        let run_id = self.content_id();
+        for source in &mut self.sources {
+            source.run_id.get_or_insert_with(|| run_id.clone());
+        }
+        self.run_id = Some(run_id);
+    }
+
+    /// SHA-256 over the results and run times (not the sources, which
+    /// carry the run id themselves)
+    pub fn content_id(&self) -> String {
+        let text = format!(
+            "{:?}\n{:?}\n{:?}\n{}\n{:?}",
+            self.modules,
+            self.unit_tests,
+            self.unit_test_results,
+            self.started_at,
+            self.finished_at
+        );
+        Hash::sha256(text.as_bytes()).to_hex()
+    }
+
+    /// Sum of the per-module prover durations
+    pub fn prover_time(&self) -> Duration {
+        self.modules.iter().filter_map(|m| m.duration).sum()
+    }
+
+    /// Derived status of a module (None if the module is not part of this
+    /// summary)
+    pub fn status(&self, module: &str) -> Option<VerificationStatus> {
+        self.module(module).map(ModuleReport::derived_status)
+    }
+
+    /// Set the module-level status of a module, adding it if not already
+    /// present
+    pub fn set_status(&mut self, module: &str, status: VerificationStatus) {
+        match self.module_mut(module) {
+            Some(report) => report.status = status,
+            None => self.modules.push(ModuleReport {
+                status,
+                ..ModuleReport::new(module)
+            }),
+        }
+    }
+
+    /// Report for a module
+    pub fn get(&self, name: &str) -> Option<&ModuleReport> {
+        self.modules.iter().find(|report| report.name == name)
+    }
+
+    /// Report for a module (same as [`get`](Self::get))
+    pub fn module(&self, module: &str) -> Option<&ModuleReport> {
+        self.get(module)
+    }
+
+    /// Mutable report for a module
+    pub fn module_mut(&mut self, module: &str) -> Option<&mut ModuleReport> {
+        self.modules.iter_mut().find(|report| report.name == module)
+    }
+
+    /// Replace the report with the same name, or append it
+    pub fn insert_module(&mut self, report: ModuleReport) {
+        match self.module_mut(&report.name) {
+            Some(existing) => *existing = report,
+            None => self.modules.push(report),
+        }
+    }
+
+    /// `(name, report)` for every module, in verification order (the order
+    /// modules were declared or first added)
+    pub fn modules(&self) -> impl Iterator<Item = (&str, &ModuleReport)> {
+        self.modules
+            .iter()
+            .map(|report| (report.name.as_str(), report))
+    }
+
+    /// Module names in verification order
+    pub fn module_names(&self) -> impl Iterator<Item = &str> {
+        self.modules.iter().map(|report| report.name.as_str())
+    }
+
+    /// Status of the `variance_halt` module
+    pub fn variance_halt(&self) -> VerificationStatus {
+        self.builtin_status(MODULE_VARIANCE_HALT)
+    }
+
+    /// Status of the `trust_bounds` module
+    pub fn trust_bounds(&self) -> VerificationStatus {
+        self.builtin_status(MODULE_TRUST_BOUNDS)
+    }
+
+    /// Status of the `byzantine_consensus` module
+    pub fn byzantine_consensus(&self) -> VerificationStatus {
+        self.builtin_status(MODULE_BYZANTINE_CONSENSUS)
+    }
+
+    /// Status of the `ed25519_contracts` module
+    pub fn ed25519_contracts(&self) -> VerificationStatus {
+        self.builtin_status(MODULE_ED25519_CONTRACTS)
+    }
+
+    /// Built-in modules absent from a custom configuration are not run
+    fn builtin_status(&self, module: &str) -> VerificationStatus {
+        self.status(module).unwrap_or(VerificationStatus::NotRun)
+    }
+
+    /// Check if all modules and the unit tests are verified
     pub fn all_verified(&self) -> bool {
-        self.variance_halt == VerificationStatus::Verified
-            && self.trust_bounds == VerificationStatus::Verified
-            && self.byzantine_consensus == VerificationStatus::Verified
-            && self.ed25519_contracts == VerificationStatus::Verified
+        self.unit_tests == VerificationStatus::Verified
+            && self
+                .modules()
+                .all(|(_, report)| report.derived_status() == VerificationStatus::Verified)
     }
 
-    /// Get overall status
+    /// Get overall status, rolled up from every module and the unit tests
+    /// and weighted by theorem [`Severity`]:
+    ///
+    /// - Failed if a critical theorem failed, a module failed without any
+    ///   failed theorem to attribute it to, or the unit tests failed
+    /// - Degraded if everything that failed is standard or informational
+    /// - Skipped if everything was skipped
+    /// - Verified if everything verified
+    /// - Partial otherwise (including anything partial, not run, skipped
+    ///   alongside other results, or unknown)
     pub fn overall(&self) -> VerificationStatus {
-        if self.all_verified() {
-            VerificationStatus::Verified
-        } else if self.variance_halt == VerificationStatus::Failed
-            || self.trust_bounds == VerificationStatus::Failed
-            || self.byzantine_consensus == VerificationStatus::Failed
-            || self.ed25519_contracts == VerificationStatus::Failed
-        {
-            VerificationStatus::Failed
-        } else {
-            VerificationStatus::Partial
+        let statuses: Vec<VerificationStatus> = self
+            .modules()
+            .map(|(_, report)| report.derived_status())
+            .chain([self.unit_tests])
+            .collect();
+        match VerificationStatus::worst(statuses.iter().copied()) {
+            VerificationStatus::Failed if self.has_critical_failure() => VerificationStatus::Failed,
+            VerificationStatus::Failed | VerificationStatus::Degraded => {
+                VerificationStatus::Degraded
+            }
+            VerificationStatus::Verified => VerificationStatus::Verified,
+            VerificationStatus::Skipped
+                if statuses.iter().all(|s| *s == VerificationStatus::Skipped) =>
+            {
+                VerificationStatus::Skipped
+            }
+            _ => VerificationStatus::Partial,
         }
     }
+
+    /// A failure that fails the whole run: a critical theorem, a failed
+    /// module with no failed theorem (crash, timeout, unreviewed escape), or
+    /// the unit tests
+    fn has_critical_failure(&self) -> bool {
+        self.unit_tests == VerificationStatus::Failed
+            || self.modules().any(|(_, report)| {
+                report.derived_status() == VerificationStatus::Failed
+                    && report
+                        .failed_theorems()
+                        .map(|t| t.severity)
+                        .max()
+                        .is_none_or(|severity| severity == Severity::Critical)
+            })
+    }
 }
 
+#[cfg(feature = "std")]
 impl Default for VerificationSummary {
     fn default() -> Self {
         Self::new()
     }
 }
 
-#[cfg(test)]
+// ============================================================================
+// SUMMARY QUERIES
+// ============================================================================
+
+#[cfg(feature = "std")]
+impl VerificationSummary {
+    /// Failed theorems as `(module, theorem)`, in verification then source
+    /// order
+    pub fn failed_theorems(&self) -> Vec<(&str, &TheoremResult)> {
+        self.modules()
+            .flat_map(|(name, report)| report.failed_theorems().map(move |t| (name, t)))
+            .collect()
+    }
+
+    /// Theorems whose name matches a glob (`*` matches any run of
+    /// characters, `?` one character). A pattern containing `::` is matched
+    /// against `module::theorem`, otherwise against the theorem name alone.
+    pub fn theorems_matching<'a>(
+        &'a self,
+        pattern: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a TheoremResult)> + 'a {
+        let qualified = pattern.contains("::");
+        self.modules()
+            .flat_map(|(name, report)| report.theorems.iter().map(move |t| (name, t)))
+            .filter(move |(module, theorem)| {
+                if qualified {
+                    glob_match(pattern, &format!("{}::{}", module, theorem.name))
+                } else {
+                    glob_match(pattern, &theorem.name)
+                }
+            })
+    }
+
+    /// Modules whose derived status is `status`, in verification order
+    pub fn modules_with_status(
+        &self,
+        status: VerificationStatus,
+    ) -> impl Iterator<Item = &ModuleReport> {
+        self.modules
+            .iter()
+            .filter(move |report| report.derived_status() == status)
+    }
+
+    /// Axioms as `(module, axiom)`, in verification order. Modules without
+    /// axioms yield nothing.
+    pub fn axiom_names(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.modules().flat_map(|(name, report)| {
+            report
+                .axioms
+                .iter()
+                .map(move |axiom| (name, axiom.as_str()))
+        })
+    }
+
+    /// Keep only the modules the predicate accepts, preserving their order.
+    ///
+    /// The unit-test stage and run metadata are kept. If nothing is left,
+    /// the roll-ups see only the unit tests: `overall()` is then the
+    /// unit-test status alone, so check [`module_names`](Self::module_names)
+    /// before trusting a `Verified` result. A sealed summary gets a new
+    /// `run_id` for its reduced contents; its sources keep the original.
+    pub fn retain_modules<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&ModuleReport) -> bool,
+    {
+        self.modules.retain(|report| predicate(report));
+        if self.run_id.is_some() {
+            self.seal();
+        }
+    }
+}
+
+/// Glob match with `*` (any run, including empty) and `?` (one character)
+#[cfg(feature = "std")]
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position after the last `*` and the text position it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p + 1, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+// ============================================================================
+// THRESHOLDS
+// ============================================================================
+
+/// Tunable constants of the consensus, trust and variance specifications,
+/// all scaled by 1000 except `variance_multiplier_scaled` (by 100).
+///
+/// The defaults are the values the proofs are checked with; executable
+/// consensus, trust and variance code takes a `&Thresholds` instead of
+/// literals. Deployments can override them from the runner config:
+///
+/// ```toml
+/// [thresholds]
+/// consensus = { per_mille = 700 }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Thresholds {
+    /// Minimum agreement for consensus (`CONSENSUS_THRESHOLD`, exactly
+    /// 2/3)
+    pub consensus: Threshold,
+    /// Halt when variance exceeds this multiple of the baseline
+    /// (`halt_threshold_scaled`, 625 = 6.25x)
+    pub variance_multiplier_scaled: u64,
+    /// EMA weight of a new observation (`ema_update`, 300 = 0.3)
+    pub trust_alpha: u64,
+    /// Trust lost per disagreement (`trust_decay`, 100 = 10%)
+    pub decay_rate: u64,
+    /// Share of the gap to full trust gained per agreement (`trust_boost`,
+    /// 50 = 5%)
+    pub boost_rate: u64,
+    /// Trust below which an agent is treated as Byzantine (300 = 0.3;
+    /// `byzantine_detection_via_trust` holds for any positive value below
+    /// an agent's initial trust)
+    pub detection_threshold: u64,
+    /// Trust lost by a voter proven to have equivocated (`trust_decay`,
+    /// 500 = 50%)
+    pub equivocation_decay_rate: u64,
+    /// Trust floored decay stops at (`trust_decay_floored`, 50 = 0.05)
+    pub trust_floor: u64,
+    /// Trust above `detection_threshold` a quarantined agent must regain
+    /// to leave quarantine (`quarantine_next`, 100 = 0.1)
+    pub quarantine_margin: u64,
+    /// Trust above an agent's recent accuracy that flags it as Byzantine
+    /// before its trust falls below `detection_threshold`
+    /// (`window_mean_bounded`, 250 = 0.25)
+    pub divergence_threshold: u64,
+    /// Weight of an agent's accuracy in the trust its votes carry
+    /// (`composite_trust`, 1000 = accuracy alone)
+    pub accuracy_weight: u64,
+    /// Weight of its calibration score (0)
+    pub calibration_weight: u64,
+    /// Weight of its latency score (0)
+    pub latency_weight: u64,
+    /// Most an observation can move an agent's trust in a round
+    /// (`ema_capped`, 100 = 0.1)
+    pub max_trust_delta: u64,
+    /// Trust lost per round an agent sits out (`decay_rounds`, 0 = none)
+    pub inactivity_decay_rate: u64,
+}
+
+#[cfg(feature = "std")]
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            consensus: Threshold::TwoThirds,
+            variance_multiplier_scaled: 625,
+            trust_alpha: 300,
+            decay_rate: 100,
+            boost_rate: 50,
+            detection_threshold: 300,
+            equivocation_decay_rate: 500,
+            trust_floor: 50,
+            quarantine_margin: 100,
+            divergence_threshold: 250,
+            accuracy_weight: 1000,
+            calibration_weight: 0,
+            latency_weight: 0,
+            max_trust_delta: 100,
+            inactivity_decay_rate: 0,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Thresholds {
+    /// Check the ranges the theorems assume:
+    ///
+    /// - `consensus` valid (`valid_threshold`, a `PerMille` in `501..=1000`):
+    ///   neither a minority nor one side of a tie can reach consensus
+    /// - `variance_multiplier_scaled >= 200`: honest agents (up to 2x the
+    ///   baseline variance, `halt_safety`) never halt
+    /// - `trust_alpha` and `boost_rate` at most 1000
+    /// - `decay_rate` in `1..=1000`: disagreeing agents lose trust
+    /// - `detection_threshold` below 1000: reachable by decay from full
+    ///   trust
+    /// - `equivocation_decay_rate` in `1..=1000`
+    /// - `trust_floor` below `detection_threshold`: an agent decayed to the
+    ///   floor is quarantined
+    /// - `detection_threshold + quarantine_margin` below 1000: a
+    ///   quarantined agent can recover
+    /// - `divergence_threshold` above `quarantine_margin` and at most 1000:
+    ///   an agent diverging by the full margin less leaves quarantine, and
+    ///   a window can diverge that far
+    /// - `accuracy_weight + calibration_weight + latency_weight` exactly
+    ///   1000: the composite trust is a convex combination
+    ///   (`composite_trust_bounded`)
+    /// - `max_trust_delta` in `1..=1000`: observations still move trust
+    /// - `inactivity_decay_rate` at most 1000
+    pub fn validate(&self) -> Result<(), ShieldError> {
+        if !self.consensus.is_valid() {
+            return Err(ShieldError::ConfigInvalid {
+                context: "thresholds".to_string(),
+                message: format!(
+                    "consensus = {} is outside 501/1000..=1000/1000",
+                    self.consensus
+                ),
+            });
+        }
+        let checks = [
+            (
+                "variance_multiplier_scaled",
+                self.variance_multiplier_scaled,
+                self.variance_multiplier_scaled >= 200,
+                "200 or more",
+            ),
+            (
+                "trust_alpha",
+                self.trust_alpha,
+                self.trust_alpha <= 1000,
+                "0..=1000",
+            ),
+            (
+                "decay_rate",
+                self.decay_rate,
+                (1..=1000).contains(&self.decay_rate),
+                "1..=1000",
+            ),
+            (
+                "boost_rate",
+                self.boost_rate,
+                self.boost_rate <= 1000,
+                "0..=1000",
+            ),
+            (
+                "detection_threshold",
+                self.detection_threshold,
+                self.detection_threshold < 1000,
+                "0..1000",
+            ),
+            (
+                "equivocation_decay_rate",
+                self.equivocation_decay_rate,
+                (1..=1000).contains(&self.equivocation_decay_rate),
+                "1..=1000",
+            ),
+            (
+                "trust_floor",
+                self.trust_floor,
+                self.trust_floor < self.detection_threshold,
+                "below detection_threshold",
+            ),
+            (
+                "quarantine_margin",
+                self.quarantine_margin,
+                self.detection_threshold
+                    .saturating_add(self.quarantine_margin)
+                    < 1000,
+                "below 1000 - detection_threshold",
+            ),
+            (
+                "divergence_threshold",
+                self.divergence_threshold,
+                self.quarantine_margin < self.divergence_threshold
+                    && self.divergence_threshold <= 1000,
+                "quarantine_margin + 1..=1000",
+            ),
+            (
+                "accuracy_weight + calibration_weight + latency_weight",
+                self.accuracy_weight
+                    .saturating_add(self.calibration_weight)
+                    .saturating_add(self.latency_weight),
+                self.accuracy_weight
+                    .saturating_add(self.calibration_weight)
+                    .saturating_add(self.latency_weight)
+                    == 1000,
+                "1000..=1000",
+            ),
+            (
+                "max_trust_delta",
+                self.max_trust_delta,
+                (1..=1000).contains(&self.max_trust_delta),
+                "1..=1000",
+            ),
+            (
+                "inactivity_decay_rate",
+                self.inactivity_decay_rate,
+                self.inactivity_decay_rate <= 1000,
+                "0..=1000",
+            ),
+        ];
+        match checks.iter().find(|(_, _, valid, _)| !valid) {
+            Some((name, value, _, range)) => Err(ShieldError::ConfigInvalid {
+                context: "thresholds".to_string(),
+                message: format!("{} = {} is outside {}", name, value, range),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Accuracy, calibration and latency weights, in
+    /// [`AgentTrust::composite`](crate::trust::AgentTrust::composite)'s
+    /// order
+    pub fn trust_weights(&self) -> [u64; 3] {
+        [
+            self.accuracy_weight,
+            self.calibration_weight,
+            self.latency_weight,
+        ]
+    }
+
+    /// Whether these are the values the proofs are checked with
+    pub fn matches_proven_defaults(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Fields that differ from the proven defaults: (name, value, default)
+    pub fn divergences(&self) -> Vec<(&'static str, String, String)> {
+        let proven = Self::default();
+        let consensus = (self.consensus != proven.consensus).then(|| {
+            (
+                "consensus",
+                self.consensus.to_string(),
+                proven.consensus.to_string(),
+            )
+        });
+        let scaled = [
+            (
+                "variance_multiplier_scaled",
+                self.variance_multiplier_scaled,
+                proven.variance_multiplier_scaled,
+            ),
+            ("trust_alpha", self.trust_alpha, proven.trust_alpha),
+            ("decay_rate", self.decay_rate, proven.decay_rate),
+            ("boost_rate", self.boost_rate, proven.boost_rate),
+            (
+                "detection_threshold",
+                self.detection_threshold,
+                proven.detection_threshold,
+            ),
+            (
+                "equivocation_decay_rate",
+                self.equivocation_decay_rate,
+                proven.equivocation_decay_rate,
+            ),
+            ("trust_floor", self.trust_floor, proven.trust_floor),
+            (
+                "quarantine_margin",
+                self.quarantine_margin,
+                proven.quarantine_margin,
+            ),
+            (
+                "divergence_threshold",
+                self.divergence_threshold,
+                proven.divergence_threshold,
+            ),
+            (
+                "accuracy_weight",
+                self.accuracy_weight,
+                proven.accuracy_weight,
+            ),
+            (
+                "calibration_weight",
+                self.calibration_weight,
+                proven.calibration_weight,
+            ),
+            ("latency_weight", self.latency_weight, proven.latency_weight),
+            (
+                "max_trust_delta",
+                self.max_trust_delta,
+                proven.max_trust_delta,
+            ),
+            (
+                "inactivity_decay_rate",
+                self.inactivity_decay_rate,
+                proven.inactivity_decay_rate,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, value, proven)| value != proven)
+        .map(|(name, value, proven)| (name, value.to_string(), proven.to_string()));
+        consensus.into_iter().chain(scaled).collect()
+    }
+}
+
+// ============================================================================
+// SUMMARY SERIALIZATION
+// ============================================================================
+
+/// Current schema version of the serialized summary.
+///
+/// - 0: the original four module statuses as top-level fields
+///   (`{"variance_halt": "Verified", ...}`), no version marker
+/// - 1: `{"version": 1, "summary": {...}}` envelope around the summary
+///   fields
+/// - 2: the summary fields with `schema_version` beside them
+///
+/// Additive changes (new fields, new status strings) keep the version; older
+/// readers ignore unknown fields and read unknown statuses as
+/// [`VerificationStatus::Unknown`]. Layout changes bump it and add a step to
+/// [`VerificationSummary::migrate`].
+#[cfg(feature = "std")]
+pub const SUMMARY_VERSION: u32 = 2;
+
+/// Module status fields of a version 0 summary
+#[cfg(all(feature = "std", feature = "serde"))]
+const V0_MODULES: [&str; 4] = [
+    MODULE_VARIANCE_HALT,
+    MODULE_TRUST_BOUNDS,
+    MODULE_BYZANTINE_CONSENSUS,
+    MODULE_ED25519_CONTRACTS,
+];
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[derive(Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+struct SummaryDocument {
+    /// Layout version; readers upgrade older versions
+    #[cfg_attr(feature = "schema", schemars(schema_with = "schema::version"))]
+    schema_version: u32,
+    #[serde(flatten)]
+    summary: SummaryBody,
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+struct SummaryBody {
+    /// Module reports in verification order
+    modules: Vec<ModuleReport>,
+    unit_tests: VerificationStatus,
+    #[serde(default)]
+    unit_test_results: Option<UnitTestResults>,
+    /// Runners whose results were merged into this summary
+    #[serde(default)]
+    sources: Vec<RunSource>,
+    /// RFC 3339 start of the run
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "schema::date_time"))]
+    started_at: String,
+    /// RFC 3339 end of the run, once finished
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema::optional_date_time")
+    )]
+    finished_at: Option<String>,
+    #[serde(default)]
+    total_duration: Option<Duration>,
+    #[serde(default)]
+    run_id: Option<String>,
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+impl From<VerificationSummary> for SummaryDocument {
+    fn from(summary: VerificationSummary) -> Self {
+        Self {
+            schema_version: SUMMARY_VERSION,
+            summary: SummaryBody {
+                modules: summary.modules,
+                unit_tests: summary.unit_tests,
+                unit_test_results: summary.unit_test_results,
+                sources: summary.sources,
+                started_at: summary.started_at,
+                finished_at: summary.finished_at,
+                total_duration: summary.total_duration,
+                run_id: summary.run_id,
+            },
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+impl From<SummaryBody> for VerificationSummary {
+    fn from(body: SummaryBody) -> Self {
+        Self {
+            modules: body.modules,
+            unit_tests: body.unit_tests,
+            unit_test_results: body.unit_test_results,
+            sources: body.sources,
+            started_at: body.started_at,
+            finished_at: body.finished_at,
+            total_duration: body.total_duration,
+            run_id: body.run_id,
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+impl TryFrom<serde_json::Value> for VerificationSummary {
+    type Error = ShieldError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        Self::migrate(value)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+fn schema_error(message: impl Into<String>) -> ShieldError {
+    ShieldError::ParseError {
+        context: "summary".to_string(),
+        message: message.into(),
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+impl VerificationSummary {
+    /// Decode a summary of any schema version, upgrading older layouts to
+    /// the current one.
+    ///
+    /// Version 0 reports carry only module-level statuses: each becomes a
+    /// module without theorem results (so it rolls up as `Partial` at best),
+    /// the unit tests are `NotRun` unless recorded, and run metadata is
+    /// empty. Status strings in version 0 are matched ignoring case.
+    /// Theorems written before severities existed get their built-in
+    /// [`Severity`].
+    pub fn migrate(mut value: serde_json::Value) -> Result<Self, ShieldError> {
+        let version = schema_version(&value)?;
+        if version > SUMMARY_VERSION {
+            return Err(schema_error(format!(
+                "schema version {} is newer than supported version {}",
+                version, SUMMARY_VERSION
+            )));
+        }
+        if version == 0 {
+            value = upgrade_v0(value)?;
+        }
+        if version <= 1 {
+            value = upgrade_v1(value)?;
+        }
+        fill_severities(&mut value);
+        let body: SummaryBody = serde_json::from_value(value)?;
+        Ok(body.into())
+    }
+}
+
+/// Schema version of a serialized summary: `schema_version` (2+), the
+/// envelope's `version` (1), or 0 for the bare status fields
+#[cfg(all(feature = "std", feature = "serde"))]
+fn schema_version(value: &serde_json::Value) -> Result<u32, ShieldError> {
+    let object = value
+        .as_object()
+        .ok_or_else(|| schema_error("expected a JSON object"))?;
+    let marker = match (object.get("schema_version"), object.get("version")) {
+        (Some(version), _) => version,
+        (None, Some(version)) if object.contains_key("summary") => version,
+        _ if V0_MODULES.iter().any(|m| object.contains_key(*m)) => return Ok(0),
+        _ => return Err(schema_error("not a verification summary")),
+    };
+    marker
+        .as_u64()
+        .and_then(|v| u32::try_from(v).ok())
+        .ok_or_else(|| schema_error(format!("invalid schema version {}", marker)))
+}
+
+/// Version 0 (bare module statuses) to the version 1 envelope
+#[cfg(all(feature = "std", feature = "serde"))]
+fn upgrade_v0(value: serde_json::Value) -> Result<serde_json::Value, ShieldError> {
+    let status = |value: &serde_json::Value| {
+        let text = value
+            .as_str()
+            .ok_or_else(|| schema_error(format!("expected a status string, found {}", value)))?;
+        Ok::<_, ShieldError>(
+            text.parse::<VerificationStatus>()
+                .unwrap_or(VerificationStatus::Unknown)
+                .as_str(),
+        )
+    };
+    let mut modules = Vec::new();
+    for name in V0_MODULES {
+        if let Some(value) = value.get(name) {
+            modules.push(serde_json::json!({ "name": name, "status": status(value)? }));
+        }
+    }
+    let unit_tests = match value.get("unit_tests") {
+        Some(value) => status(value)?,
+        None => VerificationStatus::NotRun.as_str(),
+    };
+    Ok(serde_json::json!({
+        "version": 1,
+        "summary": { "modules": modules, "unit_tests": unit_tests }
+    }))
+}
+
+/// Add the built-in severity to theorems recorded without one
+#[cfg(all(feature = "std", feature = "serde"))]
+fn fill_severities(value: &mut serde_json::Value) {
+    let Some(modules) = value.get_mut("modules").and_then(|m| m.as_array_mut()) else {
+        return;
+    };
+    for module in modules {
+        let name = module["name"].as_str().unwrap_or_default().to_string();
+        let Some(theorems) = module.get_mut("theorems").and_then(|t| t.as_array_mut()) else {
+            continue;
+        };
+        for theorem in theorems.iter_mut().filter_map(|t| t.as_object_mut()) {
+            if theorem.contains_key("severity") {
+                continue;
+            }
+            let severity = severity::builtin_severity(
+                &name,
+                theorem
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .unwrap_or_default(),
+            );
+            theorem.insert("severity".to_string(), severity.as_str().into());
+        }
+    }
+}
+
+/// Version 1 (envelope) to version 2 (flat, `schema_version`)
+#[cfg(all(feature = "std", feature = "serde"))]
+fn upgrade_v1(value: serde_json::Value) -> Result<serde_json::Value, ShieldError> {
+    let serde_json::Value::Object(mut envelope) = value else {
+        return Err(schema_error("expected a JSON object"));
+    };
+    match envelope.remove("summary") {
+        Some(serde_json::Value::Object(mut summary)) => {
+            summary.insert("schema_version".to_string(), 2.into());
+            Ok(serde_json::Value::Object(summary))
+        }
+        _ => Err(schema_error(
+            "version 1 summary without a \"summary\" object",
+        )),
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+impl VerificationSummary {
+    /// JSON encoding (pretty-printed, with its schema version)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("summary serialization is infallible")
+    }
+
+    /// Parse a summary written by [`to_json`](Self::to_json) in this or
+    /// any earlier schema version (see [`migrate`](Self::migrate))
+    pub fn from_json(text: &str) -> Result<Self, ShieldError> {
+        Self::migrate(serde_json::from_str(text)?)
+    }
+}
+
+/// JSON Schema (draft 7) of the summary [`to_json`](VerificationSummary::to_json)
+/// writes, for consumers validating reports. Describes the current
+/// [`SUMMARY_VERSION`] only.
+#[cfg(feature = "schema")]
+pub fn report_json_schema() -> serde_json::Value {
+    let mut schema = schemars::schema_for!(SummaryDocument);
+    schema.schema.metadata().title = Some("VerificationSummary".to_string());
+    serde_json::to_value(schema).expect("schema serialization is infallible")
+}
+
+/// `schema_with` overrides for fields whose Rust type says less than the
+/// serialized form
+#[cfg(feature = "schema")]
+mod schema {
+    use schemars::gen::SchemaGenerator;
+    use schemars::schema::{InstanceType, Schema, SchemaObject};
+
+    use super::SUMMARY_VERSION;
+
+    pub fn version(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::Integer.into()),
+            const_value: Some(SUMMARY_VERSION.into()),
+            ..SchemaObject::default()
+        }
+        .into()
+    }
+
+    pub fn date_time(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some("date-time".to_string()),
+            ..SchemaObject::default()
+        }
+        .into()
+    }
+
+    pub fn optional_date_time(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(vec![InstanceType::String, InstanceType::Null].into()),
+            format: Some("date-time".to_string()),
+            ..SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+// ============================================================================
+// PROOF CERTIFICATE
+// ============================================================================
+
+/// Default certificate file name
+#[cfg(feature = "std")]
+pub const CERTIFICATE_FILE: &str = "aevion-proof-certificate.json";
+
+/// Current certificate format version
+#[cfg(feature = "std")]
+pub const CERTIFICATE_VERSION: u32 = 1;
+
+/// Machine-readable record that exact proof sources verified with an exact
+/// toolchain.
+///
+/// Serialized as canonical JSON: maps are sorted and field order is fixed, so
+/// the same certificate always produces the same bytes. The optional Ed25519
+/// signature covers the canonical JSON of the certificate without its
+/// `signature` field.
+#[cfg(all(feature = "std", feature = "serde"))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofCertificate {
+    pub version: u32,
+    /// Unix timestamp (seconds) of the run
+    pub timestamp: u64,
+    /// Tool name -> reported version (`verus`, `prusti`, `rustc`)
+    pub toolchain: BTreeMap<String, String>,
+    /// Proof source path (relative to the proof directory) -> SHA-256 hex
+    pub files: BTreeMap<String, String>,
+    /// Per-module and per-theorem results
+    pub modules: Vec<CertifiedModule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<CertificateSignature>,
+}
+
+/// Results for one proof module
+#[cfg(all(feature = "std", feature = "serde"))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertifiedModule {
+    pub name: String,
+    pub status: VerificationStatus,
+    pub theorems: Vec<CertifiedTheorem>,
+    /// Axioms the module's theorems rest on
+    pub axioms: Vec<String>,
+}
+
+/// Result for one theorem or lemma
+#[cfg(all(feature = "std", feature = "serde"))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertifiedTheorem {
+    pub name: String,
+    pub status: VerificationStatus,
+}
+
+/// Detached Ed25519 signature over the unsigned certificate
+#[cfg(all(feature = "std", feature = "serde"))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertificateSignature {
+    /// Ed25519 public key (hex, 32 bytes)
+    pub public_key: String,
+    /// Ed25519 signature (hex, 64 bytes)
+    pub signature: String,
+}
+
+/// Differences between a certificate and the current proof sources
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CertificateDrift {
+    /// Proof sources not covered by the certificate
+    pub added: Vec<String>,
+    /// Certified sources that no longer exist
+    pub removed: Vec<String>,
+    /// Certified sources whose contents changed
+    pub modified: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+impl CertificateDrift {
+    /// The certificate still applies to the current sources
+    pub fn is_clean(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+impl ProofCertificate {
+    /// Canonical JSON encoding
+    pub fn to_canonical_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("certificate serialization is infallible")
+    }
+
+    /// Parse a certificate
+    pub fn from_json(text: &str) -> Result<Self, ShieldError> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    /// Bytes covered by the signature: canonical JSON without `signature`
+    pub fn signed_bytes(&self) -> Vec<u8> {
+        let unsigned = Self {
+            signature: None,
+            ..self.clone()
+        };
+        serde_json::to_vec(&unsigned).expect("certificate serialization is infallible")
+    }
+
+    /// Compare certified file hashes with the current ones (as produced by
+    /// [`hash_proof_sources`])
+    pub fn drift(&self, current: &BTreeMap<String, String>) -> CertificateDrift {
+        let certified: BTreeSet<&String> = self.files.keys().collect();
+        let present: BTreeSet<&String> = current.keys().collect();
+        CertificateDrift {
+            added: present
+                .difference(&certified)
+                .map(|f| f.to_string())
+                .collect(),
+            removed: certified
+                .difference(&present)
+                .map(|f| f.to_string())
+                .collect(),
+            modified: certified
+                .intersection(&present)
+                .filter(|f| self.files[**f] != current[**f])
+                .map(|f| f.to_string())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "signing"))]
+impl ProofCertificate {
+    /// Sign with an Ed25519 secret key seed, replacing any existing signature
+    pub fn sign(&mut self, seed: &[u8; 32]) {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let key = SigningKey::from_bytes(seed);
+        let signature = key.sign(&self.signed_bytes());
+        self.signature = Some(CertificateSignature {
+            public_key: chain::hex_encode(key.verifying_key().as_bytes()),
+            signature: chain::hex_encode(&signature.to_bytes()),
+        });
+    }
+
+    /// Check the embedded signature (None if the certificate is unsigned)
+    pub fn verify_signature(&self) -> Option<bool> {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        let embedded = self.signature.as_ref()?;
+        let valid = (|| {
+            let key: [u8; 32] = chain::hex_decode(&embedded.public_key)?.try_into().ok()?;
+            let signature: [u8; 64] = chain::hex_decode(&embedded.signature)?.try_into().ok()?;
+            let key = VerifyingKey::from_bytes(&key).ok()?;
+            Some(
+                key.verify(&self.signed_bytes(), &Signature::from_bytes(&signature))
+                    .is_ok(),
+            )
+        })();
+        Some(valid.unwrap_or(false))
+    }
+}
+
+/// SHA-256 of every proof source under `dir`, keyed by `/`-separated path
+/// relative to `dir`
+#[cfg(feature = "std")]
+pub fn hash_proof_sources(dir: &Path) -> Result<BTreeMap<String, String>, ShieldError> {
+    let mut files = BTreeMap::new();
+    for target in config::discover(dir)? {
+        let relative = target.path.strip_prefix(dir).unwrap_or(&target.path);
+        let key = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        files.insert(key, Hash::sha256(&fs::read(&target.path)?).to_hex());
+    }
+    Ok(files)
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
+    use crate::clock::ManualClock;
 
     #[test]
     fn test_version() {
         assert_eq!(VERSION, "0.1.0");
     }
 
+    fn theorem(name: &str, status: VerificationStatus) -> TheoremResult {
+        TheoremResult {
+            name: name.to_string(),
+            status,
+            duration: None,
+            severity: Severity::Standard,
+        }
+    }
+
+    fn verified_module(name: &str) -> ModuleReport {
+        ModuleReport {
+            status: VerificationStatus::Verified,
+            theorems: vec![theorem("lemma", VerificationStatus::Verified)],
+            ..ModuleReport::new(name)
+        }
+    }
+
+    /// Two modules with mixed theorem results and axioms
+    fn query_summary() -> VerificationSummary {
+        use VerificationStatus::*;
+
+        let mut summary = VerificationSummary::with_modules(Vec::<String>::new());
+        summary.insert_module(ModuleReport {
+            status: Verified,
+            theorems: vec![
+                theorem("ema_preserves_bounds", Verified),
+                theorem("decay_preserves_bounds", Failed),
+                theorem("clamp_guarantees_bounds", Failed),
+            ],
+            ..ModuleReport::new("trust_bounds")
+        });
+        summary.insert_module(ModuleReport {
+            status: Verified,
+            theorems: vec![theorem("signature_uniqueness", Verified)],
+            axioms: vec![
+                "axiom_correctness".to_string(),
+                "axiom_unforgeable".to_string(),
+            ],
+            ..ModuleReport::new("ed25519_contracts")
+        });
+        summary.insert_module(verified_module("variance_halt"));
+        summary
+    }
+
+    #[test]
+    fn test_failed_theorems() {
+        let summary = query_summary();
+        let failed: Vec<(&str, &str)> = summary
+            .failed_theorems()
+            .into_iter()
+            .map(|(module, t)| (module, t.name.as_str()))
+            .collect();
+        assert_eq!(
+            failed,
+            [
+                ("trust_bounds", "decay_preserves_bounds"),
+                ("trust_bounds", "clamp_guarantees_bounds")
+            ]
+        );
+        assert!(VerificationSummary::new().failed_theorems().is_empty());
+    }
+
+    #[test]
+    fn test_theorems_matching() {
+        let summary = query_summary();
+        let names = |pattern| -> Vec<String> {
+            summary
+                .theorems_matching(pattern)
+                .map(|(module, t)| format!("{}::{}", module, t.name))
+                .collect()
+        };
+        assert_eq!(
+            names("*_preserves_bounds"),
+            [
+                "trust_bounds::ema_preserves_bounds",
+                "trust_bounds::decay_preserves_bounds"
+            ]
+        );
+        assert_eq!(
+            names("ed25519_*::*"),
+            ["ed25519_contracts::signature_uniqueness"]
+        );
+        assert_eq!(names("lemm?"), ["variance_halt::lemma"]);
+        assert_eq!(names("*").len(), 5);
+        assert!(names("bounds").is_empty());
+
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("a*b", "axxc"));
+        assert!(!glob_match("?", ""));
+    }
+
+    #[test]
+    fn test_modules_with_status_and_axioms() {
+        let summary = query_summary();
+        let names = |status| -> Vec<&str> {
+            summary
+                .modules_with_status(status)
+                .map(|report| report.name.as_str())
+                .collect()
+        };
+        assert_eq!(names(VerificationStatus::Failed), ["trust_bounds"]);
+        assert_eq!(
+            names(VerificationStatus::Verified),
+            ["ed25519_contracts", "variance_halt"]
+        );
+        assert!(names(VerificationStatus::NotRun).is_empty());
+
+        assert_eq!(
+            summary.axiom_names().collect::<Vec<_>>(),
+            [
+                ("ed25519_contracts", "axiom_correctness"),
+                ("ed25519_contracts", "axiom_unforgeable")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retain_modules() {
+        let mut summary = query_summary();
+        summary.unit_tests = VerificationStatus::Verified;
+        summary.finish_with_clock(&ManualClock::at_unix(1_790_000_000));
+        let full_run_id = summary.run_id.clone();
+
+        summary.retain_modules(|report| report.name != "trust_bounds");
+        assert_eq!(
+            summary.module_names().collect::<Vec<_>>(),
+            ["ed25519_contracts", "variance_halt"]
+        );
+        assert_eq!(summary.overall(), VerificationStatus::Verified);
+        assert!(summary.run_id.is_some());
+        assert_ne!(summary.run_id, full_run_id);
+
+        // Nothing left: only the unit tests roll up
+        summary.retain_modules(|_| false);
+        assert_eq!(summary.module_names().count(), 0);
+        assert_eq!(summary.overall(), VerificationStatus::Verified);
+        summary.unit_tests = VerificationStatus::NotRun;
+        assert_eq!(summary.overall(), VerificationStatus::Partial);
+    }
+
     #[test]
     fn test_verification_summary() {
         let mut summary = VerificationSummary::new();
         assert_eq!(summary.overall(), VerificationStatus::Partial);
 
-        summary.variance_halt = VerificationStatus::Verified;
-        summary.trust_bounds = VerificationStatus::Verified;
-        summary.byzantine_consensus = VerificationStatus::Verified;
-        summary.ed25519_contracts = VerificationStatus::Verified;
+        for module in [
+            "variance_halt",
+            "trust_bounds",
+            "byzantine_consensus",
+            "ed25519_contracts",
+        ] {
+            summary.insert_module(verified_module(module));
+        }
+        assert_eq!(summary.overall(), VerificationStatus::Partial);
+
+        summary.unit_tests = VerificationStatus::Verified;
         assert_eq!(summary.overall(), VerificationStatus::Verified);
         assert!(summary.all_verified());
     }
+
+    #[test]
+    fn test_not_run_and_skipped_roll_up() {
+        use VerificationStatus::*;
+
+        // Nothing has been attempted yet
+        let mut summary = VerificationSummary::new();
+        assert_eq!(summary.variance_halt(), NotRun);
+        assert_eq!(summary.unit_tests, NotRun);
+        assert_eq!(summary.overall(), Partial);
+
+        // Not run or skipped next to verified results is partial
+        summary.insert_module(verified_module("variance_halt"));
+        summary.unit_tests = Skipped;
+        assert_eq!(summary.overall(), Partial);
+
+        // Failed wins over everything
+        summary.set_status("trust_bounds", Failed);
+        assert_eq!(summary.overall(), Failed);
+
+        // Everything skipped is skipped; any other status is not
+        let mut skipped = VerificationSummary::with_modules(["a", "b"]);
+        skipped.set_status("a", Skipped);
+        skipped.set_status("b", Skipped);
+        skipped.unit_tests = Skipped;
+        assert_eq!(skipped.status("a"), Some(Skipped));
+        assert_eq!(skipped.overall(), Skipped);
+        skipped.set_status("b", NotRun);
+        assert_eq!(skipped.overall(), Partial);
+    }
+
+    #[test]
+    fn test_module_without_theorems_is_partial() {
+        let mut summary = VerificationSummary::with_modules(["empty"]);
+        summary.set_status("empty", VerificationStatus::Verified);
+        summary.unit_tests = VerificationStatus::Verified;
+        assert_eq!(summary.status("empty"), Some(VerificationStatus::Partial));
+        assert_eq!(summary.overall(), VerificationStatus::Partial);
+        assert!(!summary.all_verified());
+    }
+
+    #[test]
+    fn test_theorem_roll_up() {
+        // A failing theorem fails a module the prover otherwise accepted
+        let mut mixed = verified_module("mixed");
+        mixed
+            .theorems
+            .push(theorem("lemma_bad", VerificationStatus::Failed));
+        assert_eq!(mixed.derived_status(), VerificationStatus::Failed);
+        assert_eq!(
+            mixed
+                .failed_theorems()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>(),
+            ["lemma_bad"]
+        );
+
+        // A pending theorem keeps the module partial
+        let mut pending = verified_module("pending");
+        pending
+            .theorems
+            .push(theorem("lemma_todo", VerificationStatus::Partial));
+        assert_eq!(pending.derived_status(), VerificationStatus::Partial);
+
+        // A module-level failure wins even when every theorem verified
+        let module_failed = ModuleReport {
+            status: VerificationStatus::Failed,
+            ..verified_module("module_failed")
+        };
+        assert_eq!(module_failed.derived_status(), VerificationStatus::Failed);
+
+        // Mixed failed/verified modules: a standard theorem failure degrades
+        // the summary, a critical one fails it
+        let mut summary = VerificationSummary::with_modules(Vec::<String>::new());
+        summary.insert_module(verified_module("good"));
+        summary.insert_module(mixed);
+        summary.unit_tests = VerificationStatus::Verified;
+        assert_eq!(summary.status("good"), Some(VerificationStatus::Verified));
+        assert_eq!(summary.overall(), VerificationStatus::Degraded);
+        assert!(!summary.all_verified());
+        summary.module_mut("mixed").unwrap().theorems[1].severity = Severity::Critical;
+        assert_eq!(summary.overall(), VerificationStatus::Failed);
+    }
+
+    #[test]
+    fn test_module_accessors() {
+        let mut summary = VerificationSummary::new();
+        assert_eq!(summary.module_names().count(), 4);
+        assert_eq!(summary.trust_bounds(), VerificationStatus::NotRun);
+
+        summary.set_status("trust_bounds", VerificationStatus::Failed);
+        assert_eq!(summary.trust_bounds(), VerificationStatus::Failed);
+        assert_eq!(
+            summary.status("trust_bounds"),
+            Some(VerificationStatus::Failed)
+        );
+        assert_eq!(summary.status("unknown"), None);
+
+        summary.set_status("custom", VerificationStatus::Verified);
+        assert_eq!(summary.module_names().last(), Some("custom"));
+    }
+
+    #[test]
+    fn test_run_timing_and_identity() {
+        let clock = ManualClock::at_unix(1_790_000_000);
+        let mut summary = VerificationSummary::with_clock(["a", "b"], &clock);
+        assert_eq!(summary.started_at, "2026-09-21T14:13:20.000Z");
+        assert_eq!(summary.finished_at, None);
+        assert_eq!(summary.run_id, None);
+
+        summary.insert_module(ModuleReport {
+            duration: Some(Duration::from_secs(3)),
+            ..verified_module("a")
+        });
+        summary.insert_module(ModuleReport {
+            duration: Some(Duration::from_millis(1500)),
+            ..verified_module("b")
+        });
+        summary.sources.push(RunSource {
+            label: "linux-x86_64".to_string(),
+            timestamp: None,
+            run_id: None,
+            toolchain: BTreeMap::new(),
+        });
+        clock.advance(Duration::from_millis(4750));
+        summary.finish_with_clock(&clock);
+
+        assert_eq!(
+            summary.finished_at.as_deref(),
+            Some("2026-09-21T14:13:24.750Z")
+        );
+        assert_eq!(summary.total_duration, Some(Duration::from_millis(4750)));
+        assert_eq!(summary.prover_time(), Duration::from_millis(4500));
+        let run_id = summary.run_id.clone().unwrap();
+        assert_eq!(run_id, summary.content_id());
+        assert_eq!(summary.sources[0].run_id.as_ref(), Some(&run_id));
+
+        // Same results at a different time are a different run
+        let later = ManualClock::at_unix(1_790_000_100);
+        let mut rerun = summary.clone();
+        rerun.started_at = clock::format_rfc3339(later.now());
+        rerun.finish_with_clock(&later);
+        assert_ne!(rerun.run_id, summary.run_id);
+    }
+
+    #[test]
+    fn test_failed_unit_tests_fail_summary() {
+        let mut summary = VerificationSummary::new();
+        summary.unit_tests = VerificationStatus::Failed;
+        assert_eq!(summary.overall(), VerificationStatus::Failed);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_summary_json_round_trip() {
+        let mut summary = VerificationSummary::new();
+        summary.set_status("trust_bounds", VerificationStatus::Failed);
+        summary.insert_module(ModuleReport {
+            duration: Some(Duration::from_millis(1500)),
+            axioms: vec!["axiom_a".to_string()],
+            ..verified_module("custom")
+        });
+        summary.unit_tests = VerificationStatus::Verified;
+        summary.unit_test_results = Some(UnitTestResults {
+            passed: 12,
+            ..UnitTestResults::default()
+        });
+
+        summary.finish();
+
+        let json = summary.to_json();
+        assert!(json.contains("\"schema_version\": 2"));
+        assert!(!json.contains("\"summary\""));
+        assert!(json.contains("\"started_at\""));
+        assert!(json.contains("\"run_id\""));
+        assert!(json.contains("\"status\": \"failed\""));
+        assert!(json.contains("\"status\": \"not_run\""));
+        assert_eq!(VerificationSummary::from_json(&json).unwrap(), summary);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_summary_json_forward_compatible() {
+        // A newer writer: an unknown status string and an unknown field
+        let json = r#"{
+            "schema_version": 2,
+            "modules": [
+                { "name": "variance_halt", "status": "verified" },
+                { "name": "trust_bounds", "status": "quarantined" }
+            ],
+            "unit_tests": "verified",
+            "duration_ms": 1200
+        }"#;
+        let summary = VerificationSummary::from_json(json).unwrap();
+        let trust_bounds = summary.module("trust_bounds").unwrap();
+        assert_eq!(trust_bounds.status, VerificationStatus::Unknown);
+        assert!(trust_bounds.theorems.is_empty());
+        assert_eq!(summary.unit_test_results, None);
+        assert_eq!(summary.overall(), VerificationStatus::Partial);
+
+        // Incompatible layouts are rejected rather than misread
+        let newer = json.replace("\"schema_version\": 2", "\"schema_version\": 3");
+        let error = VerificationSummary::from_json(&newer).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "summary: schema version 3 is newer than supported version 2"
+        );
+        let error = VerificationSummary::from_json(r#"{ "modules": [] }"#).unwrap_err();
+        assert_eq!(error.to_string(), "summary: not a verification summary");
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn test_report_matches_schema() {
+        let schema = report_json_schema();
+        let validator = jsonschema::JSONSchema::compile(&schema).unwrap();
+
+        let statuses: Vec<&str> = schema["definitions"]["VerificationStatus"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["enum"][0].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            statuses,
+            ["verified", "partial", "degraded", "failed", "not_run", "skipped", "unknown"]
+        );
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&"schema_version".into()));
+        assert!(required.contains(&"modules".into()));
+        assert_eq!(schema["properties"]["started_at"]["format"], "date-time");
+
+        let mut summary = summary_fixture(2);
+        summary.unit_test_results = Some(UnitTestResults {
+            passed: 3,
+            ..UnitTestResults::default()
+        });
+        summary.sources.push(RunSource {
+            label: "linux-x86_64".to_string(),
+            timestamp: Some(1_790_000_000),
+            run_id: None,
+            toolchain: BTreeMap::from([("verus".to_string(), "0.2025.01".to_string())]),
+        });
+        summary.finish();
+        let report: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+        if let Err(errors) = validator.validate(&report) {
+            panic!("{:?}", errors.map(|e| e.to_string()).collect::<Vec<_>>());
+        }
+
+        for (pointer, value) in [
+            ("/modules/0/status", "proven"),
+            ("/started_at", "yesterday"),
+        ] {
+            let mut invalid = report.clone();
+            *invalid.pointer_mut(pointer).unwrap() = value.into();
+            assert!(!validator.is_valid(&invalid), "{} = {}", pointer, value);
+        }
+        let mut newer = report.clone();
+        newer["schema_version"] = (SUMMARY_VERSION + 1).into();
+        assert!(!validator.is_valid(&newer));
+    }
+
+    #[test]
+    fn test_threshold_validation() {
+        assert!(Thresholds::default().validate().is_ok());
+        let invalid = [
+            Thresholds {
+                consensus: Threshold::PerMille(500),
+                ..Thresholds::default()
+            },
+            Thresholds {
+                consensus: Threshold::PerMille(1001),
+                ..Thresholds::default()
+            },
+            Thresholds {
+                variance_multiplier_scaled: 150,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                trust_alpha: 1001,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                decay_rate: 0,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                boost_rate: 2000,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                detection_threshold: 1000,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                equivocation_decay_rate: 0,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                trust_floor: 300,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                quarantine_margin: 700,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                divergence_threshold: 100,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                latency_weight: 1,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                max_trust_delta: 0,
+                ..Thresholds::default()
+            },
+            Thresholds {
+                inactivity_decay_rate: 1001,
+                ..Thresholds::default()
+            },
+        ];
+        for thresholds in invalid {
+            assert!(
+                matches!(
+                    thresholds.validate(),
+                    Err(ShieldError::ConfigInvalid { .. })
+                ),
+                "{:?}",
+                thresholds
+            );
+            assert!(!thresholds.matches_proven_defaults());
+            assert_eq!(thresholds.divergences().len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_threshold_defaults_match_proofs() {
+        let source = |file: &str| {
+            fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(file))
+                .unwrap()
+        };
+        let thresholds = Thresholds::default();
+        assert!(thresholds.matches_proven_defaults());
+        assert!(thresholds.divergences().is_empty());
+
+        let consensus = source("byzantine_consensus.rs");
+        assert!(consensus.contains(&format!(
+            "pub const CONSENSUS_THRESHOLD: Threshold = Threshold::{:?};",
+            thresholds.consensus
+        )));
+        assert!(consensus.contains(&format!(
+            "constitutional_halt(honest_agreement, honest_variance_ratio, {}, {})",
+            consensus::share_threshold(thresholds.consensus),
+            thresholds.variance_multiplier_scaled
+        )));
+        assert!(source("variance_halt.rs").contains(&format!(
+            "({} * baseline_variance_scaled) / 100",
+            thresholds.variance_multiplier_scaled
+        )));
+        let trust = source("trust_bounds.rs");
+        assert!(trust.contains(&format!(
+            "pub const CONSENSUS_THRESHOLD: Threshold = Threshold::{:?};",
+            thresholds.consensus
+        )));
+        for literal in [
+            format!("let alpha = {}u64;", thresholds.trust_alpha),
+            format!("let decay_rate = {}u64;", thresholds.decay_rate),
+            format!("let boost_rate = {}u64;", thresholds.boost_rate),
+            format!("let floor = {}u64;", thresholds.trust_floor),
+            format!("let threshold = {}u64;", thresholds.detection_threshold),
+            format!("let margin = {}u64;", thresholds.quarantine_margin),
+            format!(
+                "let divergence_threshold = {}u64;",
+                thresholds.divergence_threshold
+            ),
+            format!("let accuracy_weight = {}u64;", thresholds.accuracy_weight),
+            format!("let max_delta = {}u64;", thresholds.max_trust_delta),
+        ] {
+            assert!(trust.contains(&literal), "{}", literal);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn summary_fixture(version: u32) -> VerificationSummary {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/fixtures/summaries")
+            .join(format!("v{}.json", version));
+        VerificationSummary::from_json(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_summary_migrates_every_version() {
+        use VerificationStatus::*;
+
+        let v0 = summary_fixture(0);
+        let names: Vec<&str> = v0.modules().map(|(name, _)| name).collect();
+        assert_eq!(names, V0_MODULES);
+        assert_eq!(v0.get(MODULE_TRUST_BOUNDS).unwrap().status, Verified);
+        assert_eq!(v0.get(MODULE_ED25519_CONTRACTS).unwrap().status, Partial);
+        // No theorem results to back the recorded statuses
+        assert_eq!(v0.trust_bounds(), Partial);
+        assert!(v0.modules().all(|(_, m)| m.theorems.is_empty()));
+        assert_eq!(v0.unit_tests, Verified);
+        assert_eq!(v0.started_at, "");
+        assert_eq!(v0.run_id, None);
+
+        let v1 = summary_fixture(1);
+        let v2 = summary_fixture(2);
+        for summary in [&v1, &v2] {
+            assert_eq!(summary.modules().count(), 4);
+            assert_eq!(summary.axiom_names().count(), 1);
+        }
+        assert_eq!(v1.started_at, "2026-09-21T14:13:20.000Z");
+        assert_eq!(v1.overall(), Partial);
+        assert_eq!(v2.overall(), Degraded);
+
+        // Migrated summaries re-serialize in the current layout
+        let current = VerificationSummary::from_json(&v1.to_json()).unwrap();
+        assert_eq!(current, v1);
+
+        // Missing v0 fields take defaults; unrecognized statuses are Unknown
+        let v0 = VerificationSummary::migrate(serde_json::json!({
+            "variance_halt": "verified",
+            "trust_bounds": "Quarantined"
+        }))
+        .unwrap();
+        assert_eq!(v0.modules().count(), 2);
+        assert_eq!(v0.get(MODULE_TRUST_BOUNDS).unwrap().status, Unknown);
+        assert_eq!(v0.unit_tests, NotRun);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_old_summaries_diff_against_current() {
+        use VerificationStatus::*;
+
+        let current = summary_fixture(2);
+
+        let diff = current.compare(&summary_fixture(1));
+        let regressions: Vec<_> = diff
+            .regressions
+            .iter()
+            .map(|c| (c.module.as_str(), c.theorem.as_deref(), c.before, c.after))
+            .collect();
+        assert_eq!(
+            regressions,
+            [
+                ("trust_bounds", None, Verified, Failed),
+                (
+                    "trust_bounds",
+                    Some("trust_score_bounded"),
+                    Verified,
+                    Failed
+                ),
+            ]
+        );
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+
+        // A v0 baseline has module statuses only: the module regression is
+        // still caught and every theorem is new
+        let diff = current.compare(&summary_fixture(0));
+        assert!(diff.has_regressions());
+        assert_eq!(diff.regressions[0].module, "trust_bounds");
+        assert_eq!(diff.regressions[0].after, Failed);
+        assert_eq!(diff.added.len(), 3);
+    }
+
+    #[cfg(feature = "serde")]
+    fn certificate() -> ProofCertificate {
+        ProofCertificate {
+            version: CERTIFICATE_VERSION,
+            timestamp: 1_790_000_000,
+            toolchain: BTreeMap::from([("verus".to_string(), "0.2025.01".to_string())]),
+            files: BTreeMap::from([
+                ("a.rs".to_string(), "11".repeat(32)),
+                ("b.rs".to_string(), "22".repeat(32)),
+            ]),
+            modules: vec![CertifiedModule {
+                name: "a".to_string(),
+                status: VerificationStatus::Verified,
+                theorems: vec![CertifiedTheorem {
+                    name: "lemma_a".to_string(),
+                    status: VerificationStatus::Verified,
+                }],
+                axioms: vec!["axiom_a".to_string()],
+            }],
+            signature: None,
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_certificate_round_trip() {
+        let cert = certificate();
+        let json = cert.to_canonical_json();
+        assert!(json.contains("\"status\": \"verified\""));
+        assert!(!json.contains("signature"));
+        assert_eq!(ProofCertificate::from_json(&json).unwrap(), cert);
+
+        // Canonical: identical certificates encode identically
+        assert_eq!(json, certificate().to_canonical_json());
+
+        let signed = ProofCertificate {
+            signature: Some(CertificateSignature {
+                public_key: "ab".repeat(32),
+                signature: "cd".repeat(64),
+            }),
+            ..cert.clone()
+        };
+        let parsed = ProofCertificate::from_json(&signed.to_canonical_json()).unwrap();
+        assert_eq!(parsed, signed);
+        // The signature does not cover itself
+        assert_eq!(signed.signed_bytes(), cert.signed_bytes());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_certificate_drift() {
+        let cert = certificate();
+        assert!(cert.drift(&cert.files).is_clean());
+
+        let mut current = cert.files.clone();
+        current.insert("b.rs".to_string(), "33".repeat(32));
+        current.insert("c.rs".to_string(), "44".repeat(32));
+        current.remove("a.rs");
+        let drift = cert.drift(&current);
+        assert_eq!(drift.added, ["c.rs"]);
+        assert_eq!(drift.removed, ["a.rs"]);
+        assert_eq!(drift.modified, ["b.rs"]);
+        assert!(!drift.is_clean());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_hash_proof_sources_detects_edits() {
+        let dir = std::env::temp_dir().join("aevion-certificate-sources");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        let proof = "use vstd::prelude::*;\nverus! {\n}\n";
+        fs::write(dir.join("a.rs"), proof).unwrap();
+        fs::write(dir.join("nested/b.rs"), proof).unwrap();
+        fs::write(dir.join("not_a_proof.rs"), "fn main() {}\n").unwrap();
+
+        let files = hash_proof_sources(&dir).unwrap();
+        assert_eq!(files.keys().collect::<Vec<_>>(), ["a.rs", "nested/b.rs"]);
+        let cert = ProofCertificate {
+            files,
+            ..certificate()
+        };
+
+        fs::write(dir.join("a.rs"), format!("{}// edited\n", proof)).unwrap();
+        fs::remove_file(dir.join("nested/b.rs")).unwrap();
+        fs::write(dir.join("c.rs"), proof).unwrap();
+        let drift = cert.drift(&hash_proof_sources(&dir).unwrap());
+        assert_eq!(drift.added, ["c.rs"]);
+        assert_eq!(drift.removed, ["nested/b.rs"]);
+        assert_eq!(drift.modified, ["a.rs"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
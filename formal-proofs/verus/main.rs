@@ -6,8 +6,102 @@
 //!
 //! ```bash
 //! cargo run --bin verify_all
+//! cargo run --bin verify_all -- --no-tests --timeout 300
+//! cargo run --bin verify_all -- install-tools --dry-run
+//! cargo run --bin verify_all -- history show --last 10
+//! cargo run --bin verify_all -- certificate verify --against src
+//! cargo run --bin verify_all -- diff baseline.json summary.json
+//! cargo run --bin verify_all --features simulation -- simulate --samples 500 --attack byz33
 //! ```
 //!
+//! ## Options
+//!
+//! - `--proof-dir <dir>`: Directory containing the proof files (default: `src`)
+//! - `--timeout <secs>`: Per-stage timeout in seconds (default: 600)
+//! - `--no-tests`: Skip the `cargo test` stage
+//! - `--test-path <dir>`: Directory to run `cargo test` from (default: `.`)
+//! - `--test-package <name>`: Test a single package instead of `--all`
+//! - `--metrics-out <path>`: Write Prometheus textfile-collector metrics
+//! - `--config <path>`: TOML file declaring additional proof targets
+//! - `--discover <dir>`: Add every file under `<dir>` containing `verus!`
+//! - `--module <name>`: Verify only this module (repeatable); the summary,
+//!   history and metrics cover only the selected modules
+//! - `--color <auto|always|never>`: Colorize prover errors and the summary
+//!   table (default: auto)
+//! - `--show-durations`: Add module durations to the summary table
+//! - `--show-axioms`: List each module's axioms in the summary table
+//! - `--container <image>`: Run Verus inside a docker/podman container
+//! - `--allow-native-fallback`: Run natively if no container runtime is found
+//! - `--artifacts-dir <dir>`: Writable artifacts directory (default: `target/verification`)
+//! - `--history-file <path>`: Run ledger to append to (default: `verification-history.jsonl`)
+//! - `--no-history`: Do not record this run in the ledger
+//! - `--allowlist <path>`: Reviewed axioms/escapes (default: `<proof-dir>/proof-allowlist.toml`)
+//! - `--solver-stats`: Collect rlimit and quantifier statistics from Verus
+//! - `--stats-threshold <fraction>`: Warn when a function uses more than this
+//!   fraction of its rlimit budget (default: 0.8)
+//! - `--rlimit <n>`: Verus `--rlimit` (default: Verus's own default of 10)
+//! - `--certificate`: Write `aevion-proof-certificate.json`
+//! - `--certificate-key <path>`: Sign the certificate with a hex Ed25519 seed
+//!   (requires the `signing` feature)
+//! - `--claims <path>`: TOML claim -> evidence registry (default: built-in patent claims)
+//! - `--summary-json <path>`: Write the verification summary as JSON
+//! - `--empirical <path>`: JSON benchmark results (default:
+//!   `<proof-dir>/empirical-results.json`, if present)
+//! - `--evidence-json <path>`: Write the summary and empirical results as JSON
+//! - `--emit-schema <path>`: Write the JSON Schema of `--summary-json` output
+//!   and exit (requires the `schema` feature)
+//! - `--notify <target>`: Publish the run if it regressed against the
+//!   baseline; an `http(s)://` URL (requires the `webhook` feature), a file
+//!   path, or `-` for stdout. Repeatable; failures only warn.
+//! - `--notify-always`: Publish every run, not just regressions
+//! - `--baseline <path>`: Summary to compare against for `--notify`
+//!   (default: the previous `--summary-json` output, if present)
+//!
+//! ## install-tools
+//!
+//! Clones and builds Verus into `$VERUS_HOME` (default
+//! `~/.cache/aevion-shield/verus`) and fetches its Z3. The verify path uses a
+//! completed install before falling back to `verus` on PATH.
+//!
+//! - `--dry-run`: Print the commands without running them
+//! - `--yes`: Allow network access without prompting
+//! - `--with-prusti`: Also run `cargo install prusti`
+//! - `--force`: Rebuild even if Verus is already installed
+//!
+//! ## history
+//!
+//! - `history verify [--file <path>]`: Walk the hash chain and report tampering
+//! - `history show [--last <n>] [--file <path>]`: Table of recent runs (default 10)
+//!
+//! ## certificate
+//!
+//! - `certificate verify --against <proof-dir> [--file <path>]`: Rehash the
+//!   proof sources and report whether the certificate still applies
+//!
+//! ## diff
+//!
+//! - `diff <baseline.json> <summary.json>`: Compare two `--summary-json`
+//!   files; exits 1 if anything regressed
+//!   - `--duration-threshold <secs>`: Smallest duration change to report
+//!     (default: 5)
+//!   - `--no-renames`: Report renamed theorems as removed + added
+//!   - `--module <name>`: Compare only this module (repeatable), e.g. to
+//!     check a partial run against a full baseline
+//!
+//! ## simulate
+//!
+//! Runs Byzantine attack scenarios through the executable decision (requires
+//! the `simulation` feature) and prints accuracy and halt rate per scenario;
+//! exits 1 if any agreement broke `byzantine_safety`.
+//!
+//! - `--attack <name>`: Scenario to run: `baseline`, `byz33`, `byz67`,
+//!   `stealth`, `collusion` or `all` (default: all)
+//! - `--samples <n>`: Samples per scenario (default: 500)
+//! - `--seed <n>`: Random seed (default: fixed)
+//! - `--agents <n>`, `--byzantine <f>`: Override the scenario's 3 agents
+//!   and its Byzantine count
+//! - `--out <path>`: Write the results as JSON for `--empirical`
+//!
 //! ## Verification Steps
 //!
 //! 1. Run Verus proofs for variance_halt, trust_bounds, byzantine_consensus
@@ -17,121 +111,1167 @@
 //!
 //! Copyright (c) 2026 Aevion LLC. All rights reserved.
 
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::Duration;
 
-fn main() {
-    println!("============================================================");
-    println!("AEVION FORMAL VERIFICATION RUNNER");
-    println!("============================================================");
-    println!();
-    println!("Patent: US 63/896,282");
-    println!("Company: Aevion LLC (CAGE: 15NV7)");
-    println!();
+use aevion_shield::allowlist::{self, Allowlist};
+use aevion_shield::config::{self, Config, ProofTarget};
+use aevion_shield::container::{Runtime, Sandbox};
+use aevion_shield::diagnostics::Diagnostic;
+use aevion_shield::diff::CompareOptions;
+use aevion_shield::evidence::{self, EmpiricalResults, VerificationEvidence};
+use aevion_shield::history::{self, SummaryHistory};
+use aevion_shield::install::{self, InstallOptions, InstallProgress, InstallStep, ToolPaths};
+use aevion_shield::metrics::{self, MetricFamily, MetricsSnapshot};
+use aevion_shield::notify;
+use aevion_shield::properties;
+use aevion_shield::render::{self, ColorChoice, SourceCache};
+use aevion_shield::report::{PlainText, RenderOptions, RenderSummary};
+use aevion_shield::runner::{self, ModuleOutcome, ProgressObserver, RunOptions, UnitTestOutcome};
+use aevion_shield::solver_stats::{self, SolverStats};
+use aevion_shield::source_scan::ProofInventory;
+use aevion_shield::stage::{self, SystemRunner};
+use aevion_shield::unit_tests::UnitTestConfig;
+use aevion_shield::{
+    CertifiedModule, CertifiedTheorem, ClaimRegistry, ProofCertificate, ShieldError,
+    UnitTestResults, VerificationStatus, VerificationSummary,
+};
+
+/// Command-line options
+struct Options {
+    proof_dir: PathBuf,
+    timeout: Duration,
+    run_tests: bool,
+    test_path: PathBuf,
+    test_package: Option<String>,
+    metrics_out: Option<PathBuf>,
+    config: Option<PathBuf>,
+    discover: Vec<PathBuf>,
+    modules: Vec<String>,
+    color: ColorChoice,
+    show_durations: bool,
+    show_axioms: bool,
+    container: Option<String>,
+    allow_native_fallback: bool,
+    artifacts_dir: PathBuf,
+    history_file: Option<PathBuf>,
+    allowlist: Option<PathBuf>,
+    solver_stats: bool,
+    stats_threshold: f64,
+    rlimit: Option<u64>,
+    certificate: bool,
+    certificate_key: Option<PathBuf>,
+    claims: Option<PathBuf>,
+    summary_json: Option<PathBuf>,
+    empirical: Option<PathBuf>,
+    evidence_json: Option<PathBuf>,
+    emit_schema: Option<PathBuf>,
+    notify: Vec<String>,
+    notify_always: bool,
+    baseline: Option<PathBuf>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options {
+            proof_dir: PathBuf::from("src"),
+            timeout: stage::DEFAULT_TIMEOUT,
+            run_tests: true,
+            test_path: PathBuf::from("."),
+            test_package: None,
+            metrics_out: None,
+            config: None,
+            discover: Vec::new(),
+            modules: Vec::new(),
+            color: ColorChoice::Auto,
+            show_durations: false,
+            show_axioms: false,
+            container: None,
+            allow_native_fallback: false,
+            artifacts_dir: PathBuf::from("target/verification"),
+            history_file: Some(PathBuf::from(history::DEFAULT_HISTORY_FILE)),
+            allowlist: None,
+            solver_stats: false,
+            stats_threshold: 0.8,
+            rlimit: None,
+            certificate: false,
+            certificate_key: None,
+            claims: None,
+            summary_json: None,
+            empirical: None,
+            evidence_json: None,
+            emit_schema: None,
+            notify: Vec::new(),
+            notify_always: false,
+            baseline: None,
+        };
 
-    // Check Verus installation
-    println!("Checking Verus installation...");
-    let verus_check = Command::new("verus").arg("--version").output();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--proof-dir" => options.proof_dir = PathBuf::from(value(&mut args, &arg)?),
+                "--timeout" => {
+                    let secs = value(&mut args, &arg)?;
+                    let secs: u64 = secs
+                        .parse()
+                        .map_err(|_| format!("invalid --timeout value: {}", secs))?;
+                    options.timeout = Duration::from_secs(secs);
+                }
+                "--no-tests" => options.run_tests = false,
+                "--test-path" => options.test_path = PathBuf::from(value(&mut args, &arg)?),
+                "--test-package" => options.test_package = Some(value(&mut args, &arg)?),
+                "--metrics-out" => {
+                    options.metrics_out = Some(PathBuf::from(value(&mut args, &arg)?))
+                }
+                "--config" => options.config = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--discover" => options.discover.push(PathBuf::from(value(&mut args, &arg)?)),
+                "--module" => options.modules.push(value(&mut args, &arg)?),
+                "--color" => options.color = value(&mut args, &arg)?.parse()?,
+                "--show-durations" => options.show_durations = true,
+                "--show-axioms" => options.show_axioms = true,
+                "--container" => options.container = Some(value(&mut args, &arg)?),
+                "--allow-native-fallback" => options.allow_native_fallback = true,
+                "--artifacts-dir" => {
+                    options.artifacts_dir = PathBuf::from(value(&mut args, &arg)?)
+                }
+                "--history-file" => {
+                    options.history_file = Some(PathBuf::from(value(&mut args, &arg)?))
+                }
+                "--no-history" => options.history_file = None,
+                "--allowlist" => options.allowlist = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--solver-stats" => options.solver_stats = true,
+                "--stats-threshold" => {
+                    let fraction = value(&mut args, &arg)?;
+                    options.stats_threshold = fraction
+                        .parse()
+                        .ok()
+                        .filter(|f: &f64| (0.0..=1.0).contains(f))
+                        .ok_or_else(|| format!("invalid --stats-threshold value: {}", fraction))?;
+                }
+                "--rlimit" => {
+                    let rlimit = value(&mut args, &arg)?;
+                    options.rlimit = Some(
+                        rlimit
+                            .parse()
+                            .map_err(|_| format!("invalid --rlimit value: {}", rlimit))?,
+                    );
+                }
+                "--certificate" => options.certificate = true,
+                "--certificate-key" => {
+                    options.certificate_key = Some(PathBuf::from(value(&mut args, &arg)?))
+                }
+                "--claims" => options.claims = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--summary-json" => {
+                    options.summary_json = Some(PathBuf::from(value(&mut args, &arg)?))
+                }
+                "--empirical" => options.empirical = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--evidence-json" => {
+                    options.evidence_json = Some(PathBuf::from(value(&mut args, &arg)?))
+                }
+                "--emit-schema" => {
+                    options.emit_schema = Some(PathBuf::from(value(&mut args, &arg)?))
+                }
+                "--notify" => options.notify.push(value(&mut args, &arg)?),
+                "--notify-always" => options.notify_always = true,
+                "--baseline" => options.baseline = Some(PathBuf::from(value(&mut args, &arg)?)),
+                other => return Err(format!("unknown argument: {}", other)),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{} requires a value", flag))
+}
 
-    match verus_check {
-        Ok(output) => {
-            if output.status.success() {
-                println!("  Verus: INSTALLED");
-                println!("  Version: {}", String::from_utf8_lossy(&output.stdout).trim());
-            } else {
-                println!("  Verus: NOT FOUND");
-                println!("  Install: git clone https://github.com/verus-lang/verus");
+/// Build the configured target list: built-ins, config file, then discovery,
+/// narrowed to the `--module` selection
+fn load_config(options: &Options) -> Result<Config, String> {
+    let mut config = match &options.config {
+        Some(path) => Config::load(path, &options.proof_dir).map_err(|e| e.to_string())?,
+        None => Config::builtin(&options.proof_dir),
+    };
+    for dir in &options.discover {
+        let targets = config::discover(dir)
+            .map_err(|e| format!("discovery failed in {}: {}", dir.display(), e))?;
+        for target in targets {
+            if !config.targets.iter().any(|t| t.name == target.name) {
+                config.targets.push(target);
             }
         }
-        Err(_) => {
-            println!("  Verus: NOT FOUND");
-            println!("  Install: git clone https://github.com/verus-lang/verus");
+    }
+    if !options.modules.is_empty() {
+        if let Some(unknown) = options
+            .modules
+            .iter()
+            .find(|name| !config.targets.iter().any(|t| &t.name == *name))
+        {
+            return Err(format!(
+                "unknown module: {} (configured: {})",
+                unknown,
+                config.module_names().join(", ")
+            ));
         }
+        config.targets.retain(|t| options.modules.contains(&t.name));
     }
+    Ok(config)
+}
 
-    // Check Prusti installation
-    println!("\nChecking Prusti installation...");
-    let prusti_check = Command::new("cargo").args(["prusti", "--version"]).output();
+/// Load the escape allowlist: `--allowlist`, else the proof directory's
+/// default file if present, else an empty list (nothing reviewed)
+fn load_allowlist(options: &Options) -> Result<Allowlist, String> {
+    let path = match &options.allowlist {
+        Some(path) => path.clone(),
+        None => {
+            let default = options.proof_dir.join(allowlist::DEFAULT_ALLOWLIST_FILE);
+            if !default.exists() {
+                return Ok(Allowlist::default());
+            }
+            default
+        }
+    };
+    Allowlist::load(&path).map_err(|e| e.to_string())
+}
 
-    match prusti_check {
-        Ok(output) => {
-            if output.status.success() {
-                println!("  Prusti: INSTALLED");
-            } else {
-                println!("  Prusti: NOT FOUND");
-                println!("  Install: cargo install prusti");
+/// Load the claim registry: `--claims`, else the built-in patent claims
+fn load_claims(options: &Options) -> Result<ClaimRegistry, String> {
+    match &options.claims {
+        Some(path) => ClaimRegistry::load(path).map_err(|e| e.to_string()),
+        None => Ok(ClaimRegistry::default()),
+    }
+}
+
+/// Load benchmark results: `--empirical`, else the proof directory's default
+/// file if present, else none
+fn load_empirical(options: &Options) -> Result<Vec<EmpiricalResults>, String> {
+    let path = match &options.empirical {
+        Some(path) => path.clone(),
+        None => {
+            let default = options.proof_dir.join(evidence::DEFAULT_EMPIRICAL_FILE);
+            if !default.exists() {
+                return Ok(Vec::new());
             }
+            default
         }
-        Err(_) => {
-            println!("  Prusti: NOT FOUND");
-            println!("  Install: cargo install prusti");
+    };
+    evidence::load(&path).map_err(|e| e.to_string())
+}
+
+/// Set up the container sandbox for `--container`.
+///
+/// Returns `Ok(None)` when running natively: either no container was requested,
+/// or no runtime was found and `--allow-native-fallback` was given.
+fn load_sandbox(options: &Options) -> Result<Option<Sandbox>, String> {
+    let Some(image) = &options.container else {
+        return Ok(None);
+    };
+    let Some(runtime) = Runtime::detect() else {
+        if options.allow_native_fallback {
+            println!("WARNING: no container runtime found; running natively");
+            return Ok(None);
         }
+        return Err(
+            "--container requires docker or podman (use --allow-native-fallback to run natively)"
+                .to_string(),
+        );
+    };
+
+    std::fs::create_dir_all(&options.artifacts_dir).map_err(|e| {
+        format!(
+            "cannot create artifacts dir {}: {}",
+            options.artifacts_dir.display(),
+            e
+        )
+    })?;
+    let absolute = |dir: &Path| {
+        dir.canonicalize()
+            .map_err(|e| format!("cannot resolve {}: {}", dir.display(), e))
+    };
+    Ok(Some(Sandbox {
+        runtime,
+        image: image.clone(),
+        proof_dir: absolute(&options.proof_dir)?,
+        artifacts_dir: absolute(&options.artifacts_dir)?,
+    }))
+}
+
+fn describe_command(command: &Command) -> String {
+    let mut parts = vec![command.get_program().to_string_lossy().into_owned()];
+    parts.extend(command.get_args().map(|a| a.to_string_lossy().into_owned()));
+    parts.join(" ")
+}
+
+/// `install-tools` options
+struct InstallArgs {
+    dry_run: bool,
+    yes: bool,
+    options: InstallOptions,
+}
+
+impl InstallArgs {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = InstallArgs {
+            dry_run: false,
+            yes: false,
+            options: InstallOptions::default(),
+        };
+        for arg in args {
+            match arg.as_str() {
+                "--dry-run" => parsed.dry_run = true,
+                "--yes" => parsed.yes = true,
+                "--with-prusti" => parsed.options.with_prusti = true,
+                "--force" => parsed.options.force = true,
+                other => return Err(format!("unknown install-tools argument: {}", other)),
+            }
+        }
+        Ok(parsed)
     }
+}
 
-    println!("\n============================================================");
-    println!("VERIFICATION MODULES");
-    println!("============================================================");
+/// Prints `[k/n]` progress before each install step
+struct ConsoleProgress;
+
+impl InstallProgress for ConsoleProgress {
+    fn step_started(&mut self, index: usize, total: usize, step: &InstallStep) {
+        println!("\n[{}/{}] {}", index, total, step.description);
+        println!("  $ {}", step);
+    }
+}
+
+/// Ask before touching the network; scripted runs must pass `--yes`
+fn confirm_network() -> bool {
+    if !io::stdin().is_terminal() {
+        return false;
+    }
+    print!("\nThis will download and build toolchains. Proceed? [y/N] ");
+    let _ = io::stdout().flush();
+    let mut answer = String::new();
+    if io::stdin().lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim(), "y" | "Y" | "yes")
+}
+
+fn install_tools(args: impl Iterator<Item = String>) -> i32 {
+    let args = match InstallArgs::parse(args) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            return 2;
+        }
+    };
+    let Some(paths) = ToolPaths::from_env() else {
+        eprintln!("error: cannot locate a cache directory; set VERUS_HOME");
+        return 2;
+    };
+    let plan = install::plan(&paths, &args.options);
+
+    println!("Verus home: {}", paths.verus_home.display());
+    if plan.verus_up_to_date {
+        println!("Verus: already installed ({})", paths.verus_binary().display());
+    }
+    if plan.steps.is_empty() {
+        println!("Nothing to do.");
+        return 0;
+    }
+
+    if args.dry_run {
+        println!("\nDry run; would execute:");
+        for step in &plan.steps {
+            println!("  {}", step);
+        }
+        return 0;
+    }
+
+    if !args.yes && !confirm_network() {
+        eprintln!("error: install-tools needs network access; re-run with --yes to proceed");
+        return 2;
+    }
+
+    match install::execute(&plan, &mut ConsoleProgress) {
+        Ok(()) => {
+            println!("\nInstall complete.");
+            if !plan.verus_up_to_date {
+                println!("  Verus: {}", paths.verus_binary().display());
+            }
+            0
+        }
+        Err(err) => {
+            eprintln!("\nerror: install failed: {}", err);
+            eprintln!("No partial toolchain was left in place.");
+            1
+        }
+    }
+}
+
+fn history_command(mut args: impl Iterator<Item = String>) -> i32 {
+    let action = args.next();
+    let mut file = PathBuf::from(history::DEFAULT_HISTORY_FILE);
+    let mut last = 10;
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "--file" => value(&mut args, &arg).map(|v| file = PathBuf::from(v)),
+            "--last" => value(&mut args, &arg).and_then(|v| {
+                last = v
+                    .parse()
+                    .map_err(|_| format!("invalid --last value: {}", v))?;
+                Ok(())
+            }),
+            other => Err(format!("unknown history argument: {}", other)),
+        };
+        if let Err(message) = parsed {
+            eprintln!("error: {}", message);
+            return 2;
+        }
+    }
+
+    let history = match SummaryHistory::open(&file) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("error: cannot read {}: {}", file.display(), err);
+            return 2;
+        }
+    };
+    match action.as_deref() {
+        Some("verify") => match history.verify_chain() {
+            Ok(()) => {
+                println!("{}: {} records, chain intact", file.display(), history.len());
+                0
+            }
+            Err(err) => {
+                println!("{}: TAMPERED ({})", file.display(), err);
+                1
+            }
+        },
+        Some("show") => {
+            print!("{}", history::format_table(history.records(), last));
+            0
+        }
+        _ => {
+            eprintln!("error: usage: history <verify|show> [--file <path>] [--last <n>]");
+            2
+        }
+    }
+}
+
+fn diff_command(mut args: impl Iterator<Item = String>) -> i32 {
+    let mut files = Vec::new();
+    let mut modules = Vec::new();
+    let mut options = CompareOptions::default();
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "--duration-threshold" => value(&mut args, &arg).and_then(|v| {
+                let secs: f64 = v
+                    .parse()
+                    .ok()
+                    .filter(|secs: &f64| *secs >= 0.0)
+                    .ok_or_else(|| format!("invalid --duration-threshold value: {}", v))?;
+                options.duration_threshold = Duration::from_secs_f64(secs);
+                Ok(())
+            }),
+            "--no-renames" => {
+                options.detect_renames = false;
+                Ok(())
+            }
+            "--module" => value(&mut args, &arg).map(|name| modules.push(name)),
+            other if !other.starts_with("--") => {
+                files.push(PathBuf::from(other));
+                Ok(())
+            }
+            other => Err(format!("unknown diff argument: {}", other)),
+        };
+        if let Err(message) = parsed {
+            eprintln!("error: {}", message);
+            return 2;
+        }
+    }
+    let [baseline, current] = files.as_slice() else {
+        eprintln!(
+            "error: usage: diff <baseline.json> <summary.json> \
+             [--duration-threshold <secs>] [--no-renames] [--module <name>]..."
+        );
+        return 2;
+    };
+
+    let read = |path: &Path| {
+        std::fs::read_to_string(path)
+            .map_err(ShieldError::from)
+            .and_then(|text| VerificationSummary::from_json(&text))
+            .map_err(|err| format!("cannot read summary {}: {}", path.display(), err))
+    };
+    let (mut baseline, mut current) = match (read(baseline), read(current)) {
+        (Ok(baseline), Ok(current)) => (baseline, current),
+        (Err(message), _) | (_, Err(message)) => {
+            eprintln!("error: {}", message);
+            return 2;
+        }
+    };
+
+    if !modules.is_empty() {
+        baseline.retain_modules(|report| modules.contains(&report.name));
+        current.retain_modules(|report| modules.contains(&report.name));
+    }
+
+    let diff = current.compare_with(&baseline, &options);
+    if diff.is_empty() {
+        println!("No changes");
+    } else {
+        print!("{}", diff);
+    }
+    if diff.has_regressions() {
+        1
+    } else {
+        0
+    }
+}
+
+fn certificate_command(mut args: impl Iterator<Item = String>) -> i32 {
+    if args.next().as_deref() != Some("verify") {
+        eprintln!("error: usage: certificate verify --against <proof-dir> [--file <path>]");
+        return 2;
+    }
+    let mut file = PathBuf::from(aevion_shield::CERTIFICATE_FILE);
+    let mut against = None;
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "--file" => value(&mut args, &arg).map(|v| file = PathBuf::from(v)),
+            "--against" => value(&mut args, &arg).map(|v| against = Some(PathBuf::from(v))),
+            other => Err(format!("unknown certificate argument: {}", other)),
+        };
+        if let Err(message) = parsed {
+            eprintln!("error: {}", message);
+            return 2;
+        }
+    }
+    let Some(against) = against else {
+        eprintln!("error: certificate verify requires --against <proof-dir>");
+        return 2;
+    };
+
+    let certificate = match std::fs::read_to_string(&file)
+        .map_err(|e| e.to_string())
+        .and_then(|text| ProofCertificate::from_json(&text).map_err(|e| e.to_string()))
+    {
+        Ok(certificate) => certificate,
+        Err(err) => {
+            eprintln!("error: cannot read certificate {}: {}", file.display(), err);
+            return 2;
+        }
+    };
+    let current = match aevion_shield::hash_proof_sources(&against) {
+        Ok(current) => current,
+        Err(err) => {
+            eprintln!("error: cannot hash {}: {}", against.display(), err);
+            return 2;
+        }
+    };
+
+    let mut valid = true;
+    if certificate.signature.is_some() {
+        match signature_valid(&certificate) {
+            Some(true) => println!("Signature: VALID"),
+            Some(false) => {
+                println!("Signature: INVALID");
+                valid = false;
+            }
+            None => println!("Signature: present (not checked; built without `signing`)"),
+        }
+    }
+
+    let drift = certificate.drift(&current);
+    for (label, files) in [
+        ("ADDED", &drift.added),
+        ("REMOVED", &drift.removed),
+        ("MODIFIED", &drift.modified),
+    ] {
+        for file in files {
+            println!("  {}: {}", label, file);
+        }
+    }
+    if drift.is_clean() {
+        println!(
+            "Certificate applies: {} files unchanged",
+            certificate.files.len()
+        );
+    } else {
+        println!("Certificate does NOT apply: proof sources drifted");
+        valid = false;
+    }
+    if valid {
+        0
+    } else {
+        1
+    }
+}
+
+#[cfg(feature = "simulation")]
+fn simulate_command(mut args: impl Iterator<Item = String>) -> i32 {
+    use aevion_shield::simulation::{self, Scenario};
+
+    let mut attack = "all".to_string();
+    let mut samples = simulation::DEFAULT_SAMPLES;
+    let mut seed = simulation::DEFAULT_SEED;
+    let mut agents = None;
+    let mut byzantine = None;
+    let mut out = None;
+    let number = |flag: &str, v: String| {
+        v.parse::<u64>()
+            .map_err(|_| format!("invalid {} value: {}", flag, v))
+    };
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "--attack" => value(&mut args, &arg).map(|v| attack = v),
+            "--samples" => value(&mut args, &arg)
+                .and_then(|v| number(&arg, v))
+                .map(|v| samples = v),
+            "--seed" => value(&mut args, &arg)
+                .and_then(|v| number(&arg, v))
+                .map(|v| seed = v),
+            "--agents" => value(&mut args, &arg)
+                .and_then(|v| number(&arg, v))
+                .map(|v| agents = Some(v)),
+            "--byzantine" => value(&mut args, &arg)
+                .and_then(|v| number(&arg, v))
+                .map(|v| byzantine = Some(v)),
+            "--out" => value(&mut args, &arg).map(|v| out = Some(PathBuf::from(v))),
+            other => Err(format!("unknown simulate argument: {}", other)),
+        };
+        if let Err(message) = parsed {
+            eprintln!("error: {}", message);
+            return 2;
+        }
+    }
+
+    let names = match attack.as_str() {
+        "all" => simulation::PRESETS.to_vec(),
+        name if Scenario::preset(name).is_some() => vec![name],
+        other => {
+            eprintln!(
+                "error: unknown attack: {} (known: {}, all)",
+                other,
+                simulation::PRESETS.join(", ")
+            );
+            return 2;
+        }
+    };
+    let engine = simulation::benchmark_engine();
+    let mut reports = Vec::new();
+    for name in names {
+        let mut scenario = Scenario::preset(name).expect("known preset");
+        scenario.n = agents.unwrap_or(scenario.n);
+        scenario.f = byzantine.unwrap_or(scenario.f);
+        match simulation::simulate(&scenario, samples, seed, &engine) {
+            Ok(report) => reports.push(report),
+            Err(err) => {
+                eprintln!("error: scenario {}: {}", name, err);
+                return 2;
+            }
+        }
+    }
+
+    let results: Vec<EmpiricalResults> = reports.iter().map(|r| r.results()).collect();
+    print_results(&results);
+    let violations: u64 = reports.iter().map(|r| r.safety_violations).sum();
+    println!("Safety violations:        {}", violations);
+
+    if let Some(path) = out {
+        let json = serde_json::to_string_pretty(&results).expect("results serialize");
+        if let Err(err) = std::fs::write(&path, json) {
+            eprintln!("error: {}: {}", path.display(), err);
+            return 2;
+        }
+        println!("Results written to {}", path.display());
+    }
+    if violations > 0 {
+        1
+    } else {
+        0
+    }
+}
 
-    let modules = [
-        ("variance_halt", "Variance-based Byzantine detection"),
-        ("trust_bounds", "Trust score preservation"),
-        ("byzantine_consensus", "Core BFT theorems"),
-        ("ed25519_contracts", "Cryptographic contracts"),
-    ];
+#[cfg(not(feature = "simulation"))]
+fn simulate_command(_args: impl Iterator<Item = String>) -> i32 {
+    eprintln!("error: built without the `simulation` feature");
+    2
+}
 
-    for (module, description) in modules {
-        println!("\n{}", module);
-        println!("  Description: {}", description);
-        println!("  Status: READY FOR VERIFICATION");
-        println!("  Command: verus src/{}.rs", module);
+#[cfg(feature = "signing")]
+fn signature_valid(certificate: &ProofCertificate) -> Option<bool> {
+    certificate.verify_signature()
+}
+
+#[cfg(not(feature = "signing"))]
+fn signature_valid(_certificate: &ProofCertificate) -> Option<bool> {
+    None
+}
+
+#[cfg(feature = "signing")]
+fn sign_certificate(certificate: &mut ProofCertificate, key_path: &Path) -> Result<(), String> {
+    let text = std::fs::read_to_string(key_path).map_err(|e| e.to_string())?;
+    let seed: [u8; 32] = aevion_shield::chain::hex_decode(text.trim())
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("key file must contain a 32-byte hex Ed25519 seed")?;
+    certificate.sign(&seed);
+    Ok(())
+}
+
+#[cfg(not(feature = "signing"))]
+fn sign_certificate(_certificate: &mut ProofCertificate, _key_path: &Path) -> Result<(), String> {
+    Err("built without the `signing` feature".to_string())
+}
+
+#[cfg(feature = "schema")]
+fn emit_schema(path: &Path) -> Result<(), String> {
+    let schema = aevion_shield::report_json_schema();
+    let json = serde_json::to_string_pretty(&schema).expect("schema serializes");
+    std::fs::write(path, json).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[cfg(not(feature = "schema"))]
+fn emit_schema(_path: &Path) -> Result<(), String> {
+    Err("built without the `schema` feature".to_string())
+}
+
+/// Summary for `--notify` to compare against; a missing default baseline is
+/// not an error (first run)
+fn load_baseline(options: &Options) -> Option<VerificationSummary> {
+    let path = match (&options.baseline, &options.summary_json) {
+        (Some(path), _) => path,
+        (None, Some(path)) if path.exists() => path,
+        (None, _) => return None,
+    };
+    let loaded = std::fs::read_to_string(path)
+        .map_err(ShieldError::from)
+        .and_then(|text| VerificationSummary::from_json(&text));
+    match loaded {
+        Ok(baseline) => Some(baseline),
+        Err(err) => {
+            println!("WARNING: cannot read baseline {}: {}", path.display(), err);
+            None
+        }
     }
+}
 
+/// Publish to every `--notify` target. Notification failures warn but never
+/// change the exit code.
+fn publish_notifications(
+    options: &Options,
+    summary: &VerificationSummary,
+    baseline: Option<&VerificationSummary>,
+) {
+    let diff = baseline.map(|baseline| summary.compare(baseline));
+    if !notify::should_publish(diff.as_ref(), options.notify_always) {
+        println!("\nNotify: no regressions, nothing published");
+        return;
+    }
+    for target in &options.notify {
+        match notify::sink_for(target).and_then(|sink| sink.publish(summary, diff.as_ref())) {
+            Ok(()) => println!("\nNotification sent to {}", target),
+            Err(err) => println!("WARNING: notification failed: {}", err),
+        }
+    }
+}
+
+/// Console output for a verification run. Keeps each module's inventory
+/// for the metrics textfile.
+struct ConsoleObserver {
+    color: bool,
+    timeout: Duration,
+    rlimit: u64,
+    stats_threshold: f64,
+    sources: SourceCache,
+    inventories: Vec<ProofInventory>,
+}
+
+fn print_heading(title: &str) {
     println!("\n============================================================");
-    println!("VERIFIED PROPERTIES");
+    println!("{}", title);
     println!("============================================================");
+}
+
+impl ProgressObserver for ConsoleObserver {
+    fn tool_checked(&mut self, tool: &str, version: Option<&str>) {
+        let (name, install) = match tool {
+            "verus" => ("Verus", "cargo run --bin verify_all -- install-tools"),
+            "prusti" => ("Prusti", "cargo install prusti"),
+            _ => return,
+        };
+        if tool == "prusti" {
+            println!();
+        }
+        println!("Checking {} installation...", name);
+        match version {
+            Some(version) => {
+                println!("  {}: INSTALLED", name);
+                println!("  Version: {}", version);
+            }
+            None => {
+                println!("  {}: NOT FOUND", name);
+                println!("  Install: {}", install);
+            }
+        }
+    }
+
+    fn modules_started(&mut self, _targets: &[ProofTarget]) {
+        print_heading("VERIFICATION MODULES");
+    }
 
-    let properties = [
-        ("P1", "Byzantine Tolerance", "f < n/3 -> P(correct) >= 0.83"),
-        ("P2", "Variance Halt", "sigma > 2.5*baseline -> HALT"),
-        ("P3", "Trust Bounds", "forall t: 0 <= t <= 1"),
-        ("P4", "Ed25519 Non-Malleability", "verify(m,s1) & verify(m,s2) -> s1=s2"),
-        ("P5", "Merkle Soundness", "verify_proof(leaf,path,root) -> leaf in tree"),
-        ("P6", "Constitutional Halt", "agreement < 0.67 -> HALT"),
-    ];
+    fn module_started(&mut self, target: &ProofTarget, inventory: &ProofInventory) {
+        println!("\n{}", target.name);
+        if let Some(description) = &target.description {
+            println!("  Description: {}", description);
+        }
+        println!(
+            "  Inventory: {} theorems, {} axioms, {} escapes",
+            inventory.theorems.len(),
+            inventory.axioms.len(),
+            inventory.escapes.len()
+        );
+        for escape in &inventory.escapes {
+            println!(
+                "    #[verifier::{}] {} (line {})",
+                escape.kind.as_str(),
+                escape.item.as_deref().unwrap_or("<unknown item>"),
+                escape.line
+            );
+        }
+        self.inventories.push(inventory.clone());
+    }
+
+    fn module_command(&mut self, _target: &ProofTarget, command: &Command) {
+        println!("  Command: {}", describe_command(command));
+    }
 
-    for (id, name, statement) in properties {
-        println!("\n{}: {}", id, name);
-        println!("  Statement: {}", statement);
+    fn module_finished(&mut self, _target: &ProofTarget, outcome: &ModuleOutcome) {
+        match outcome {
+            ModuleOutcome::Unreviewed(entries) => {
+                println!("  Status: FAILED (unreviewed proof escapes)");
+                for entry in entries {
+                    println!("    UNREVIEWED: {}", entry);
+                }
+            }
+            ModuleOutcome::NotInstalled => println!("  Status: READY FOR VERIFICATION"),
+            ModuleOutcome::Error(err) => println!("  Status: FAILED ({})", err),
+            ModuleOutcome::Completed {
+                output,
+                errors,
+                stats,
+            } => {
+                if let Some(stats) = stats {
+                    print_solver_stats(stats, self.rlimit, self.stats_threshold);
+                }
+                if output.success {
+                    println!("  Status: VERIFIED ({:.1}s)", output.duration.as_secs_f64());
+                } else if output.timed_out {
+                    println!("  Status: TIMEOUT after {}s", self.timeout.as_secs());
+                } else {
+                    println!("  Status: FAILED");
+                    print_prover_errors(&output.combined(), errors, &mut self.sources, self.color);
+                }
+            }
+        }
+    }
+
+    fn unit_tests_started(&mut self, config: &UnitTestConfig) {
+        print_heading("UNIT TESTS");
+        println!("\nRunning cargo test in {}...", config.path.display());
+    }
+
+    fn unit_tests_finished(&mut self, outcome: &UnitTestOutcome) {
+        match outcome {
+            Ok((status, results)) => print_unit_tests(*status, results.as_ref()),
+            Err(err) => {
+                match err {
+                    ShieldError::ToolNotFound { tool, source } => {
+                        println!("  {}: NOT FOUND ({})", tool, source)
+                    }
+                    err => println!("  cargo: FAILED ({})", err),
+                }
+                print_unit_tests(VerificationStatus::Failed, None);
+            }
+        }
+    }
+
+    fn unit_tests_skipped(&mut self) {
+        print_heading("UNIT TESTS");
+        println!("\n  Skipped (--no-tests)");
+    }
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("install-tools") => {
+            args.next();
+            std::process::exit(install_tools(args));
+        }
+        Some("history") => {
+            args.next();
+            std::process::exit(history_command(args));
+        }
+        Some("certificate") => {
+            args.next();
+            std::process::exit(certificate_command(args));
+        }
+        Some("diff") => {
+            args.next();
+            std::process::exit(diff_command(args));
+        }
+        Some("simulate") => {
+            args.next();
+            std::process::exit(simulate_command(args));
+        }
+        _ => {}
+    }
+
+    let options = match Options::parse(args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            std::process::exit(2);
+        }
+    };
+    if let Some(path) = &options.emit_schema {
+        match emit_schema(path) {
+            Ok(()) => {
+                println!("Schema written to {}", path.display());
+                std::process::exit(0);
+            }
+            Err(message) => {
+                eprintln!("error: {}", message);
+                std::process::exit(2);
+            }
+        }
+    }
+    let config = match load_config(&options) {
+        Ok(config) => config,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            std::process::exit(2);
+        }
+    };
+    for (name, value, proven) in config.thresholds.divergences() {
+        println!(
+            "WARNING: threshold {} = {} differs from the proven value {}",
+            name, value, proven
+        );
     }
+    let allowlist = match load_allowlist(&options) {
+        Ok(allowlist) => allowlist,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            std::process::exit(2);
+        }
+    };
+    let claims = match load_claims(&options) {
+        Ok(claims) => claims,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            std::process::exit(2);
+        }
+    };
+    let empirical = match load_empirical(&options) {
+        Ok(empirical) => empirical,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            std::process::exit(2);
+        }
+    };
+    let sandbox = match load_sandbox(&options) {
+        Ok(sandbox) => sandbox,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            std::process::exit(2);
+        }
+    };
+    println!("============================================================");
+    println!("AEVION FORMAL VERIFICATION RUNNER");
+    println!("============================================================");
+    println!();
+    println!("Patent: US 63/896,282");
+    println!("Company: Aevion LLC (CAGE: 15NV7)");
+    println!();
+
+    // Record the exact image used for a sandboxed run
+    let image_digest = sandbox.as_ref().map(|sandbox| {
+        let digest = sandbox
+            .image_digest()
+            .unwrap_or_else(|err| format!("unknown ({})", err));
+        println!("Sandbox: {} ({})", sandbox.image, sandbox.runtime.program());
+        println!("  Image digest: {}", digest);
+        println!();
+        digest
+    });
+
+    let run_options = RunOptions {
+        timeout: options.timeout,
+        // Managed install first, then PATH
+        verus: install::find_verus(ToolPaths::from_env().as_ref()),
+        sandbox,
+        allowlist,
+        rlimit: options.rlimit,
+        solver_stats: options.solver_stats,
+        unit_tests: options.run_tests.then(|| UnitTestConfig {
+            path: options.test_path.clone(),
+            package: options.test_package.clone(),
+            target_dir: None,
+            timeout: options.timeout,
+        }),
+    };
+    let color = options.color.enabled();
+    let mut console = ConsoleObserver {
+        color,
+        timeout: options.timeout,
+        rlimit: options.rlimit.unwrap_or(solver_stats::DEFAULT_RLIMIT),
+        stats_threshold: options.stats_threshold,
+        sources: SourceCache::new(),
+        inventories: Vec::new(),
+    };
+    let summary =
+        match runner::run_verification_with(&config, &run_options, &SystemRunner, &mut console) {
+            Ok(summary) => summary,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                std::process::exit(2);
+            }
+        };
+    let inventories = console.inventories;
+    let toolchain = summary
+        .sources
+        .first()
+        .map(|source| source.toolchain.clone())
+        .unwrap_or_default();
+    let evidence = VerificationEvidence::new(summary, empirical);
+    let summary = &evidence.summary;
 
     println!("\n============================================================");
-    println!("EMPIRICAL VALIDATION (500-sample)");
+    println!("VERIFIED PROPERTIES");
     println!("============================================================");
 
-    println!("\nBaseline (no attack):     92.8% (464/500)");
-    println!("33% Byzantine attack:     83.0% (415/500)");
-    println!("67% Byzantine attack:     30.2% (151/500) + 57.8% HALT");
-    println!("Resilience factor:        89.4%");
-    println!("Statistical significance: p < 0.001");
+    for property in &properties::PROPERTIES {
+        println!("\n{}: {}", property.id, property.name);
+        println!("  Statement: {}", property.statement);
+        println!("  Evidence: {}", property.evidence().join(", "));
+        println!("  Status: {}", property.status(summary));
+    }
 
     println!("\n============================================================");
-    println!("PATENT CLAIMS SUPPORTED");
+    println!("EMPIRICAL VALIDATION");
     println!("============================================================");
 
-    let claims = [
-        ("Claim 2", "N=3 Optimality", "byzantine_consensus.rs"),
-        ("Claim 3", "Constitutional Halts", "variance_halt.rs"),
-        ("Claim 4", "Hardware-Attested Consensus", "ed25519_contracts.rs"),
-        ("Claim 16", "Byzantine Threshold", "byzantine_consensus.rs"),
-        ("Claim 17", "N=3 Sufficiency", "byzantine_consensus.rs"),
-        ("Claim 79", "Deductive Verification", "All modules (CIP)"),
-        ("Claim 80", "Cryptographic Contracts", "ed25519_contracts.rs (CIP)"),
-        ("Claim 81", "Dual Validation", "Empirical + Formal (CIP)"),
-        ("Claim 82", "Formally Verified Halt", "variance_halt.rs (CIP)"),
-    ];
+    print_empirical(&evidence);
+
+    let render_options = RenderOptions {
+        durations: options.show_durations,
+        axioms: options.show_axioms,
+        color,
+    };
+    print!("{}", PlainText.render(summary, &claims, &render_options));
+    if let (Some(sandbox), Some(digest)) = (&run_options.sandbox, &image_digest) {
+        println!("Container image:          {}@{}", sandbox.image, digest);
+    }
+
+    if options.certificate {
+        let mut certificate = ProofCertificate {
+            version: aevion_shield::CERTIFICATE_VERSION,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            toolchain,
+            files: aevion_shield::hash_proof_sources(&options.proof_dir).unwrap_or_default(),
+            modules: summary
+                .modules()
+                .map(|(_, report)| CertifiedModule {
+                    name: report.name.clone(),
+                    status: report.derived_status(),
+                    theorems: report
+                        .theorems
+                        .iter()
+                        .map(|t| CertifiedTheorem {
+                            name: t.name.clone(),
+                            status: t.status,
+                        })
+                        .collect(),
+                    axioms: report.axioms.clone(),
+                })
+                .collect(),
+            signature: None,
+        };
+
+        let signed = match &options.certificate_key {
+            Some(key) => sign_certificate(&mut certificate, key),
+            None => Ok(()),
+        };
+        let path = Path::new(aevion_shield::CERTIFICATE_FILE);
+        match signed.and_then(|()| {
+            std::fs::write(path, certificate.to_canonical_json()).map_err(|e| e.to_string())
+        }) {
+            Ok(()) => println!("\nCertificate written to {}", path.display()),
+            Err(err) => println!("\nCertificate: FAILED ({})", err),
+        }
+    }
+
+    if let Some(path) = &options.history_file {
+        let appended = SummaryHistory::open(path).and_then(|mut h| h.append(summary).cloned());
+        match appended {
+            Ok(record) => {
+                println!("\nHistory: recorded run {} in {}", record.digest, path.display())
+            }
+            Err(err) => println!("\nHistory: FAILED to append to {} ({})", path.display(), err),
+        }
+    }
+
+    // Read before --summary-json overwrites it: the previous output is the
+    // default baseline
+    let baseline = if options.notify.is_empty() {
+        None
+    } else {
+        load_baseline(&options)
+    };
+
+    if let Some(path) = &options.summary_json {
+        match std::fs::write(path, summary.to_json()) {
+            Ok(()) => println!("\nSummary written to {}", path.display()),
+            Err(err) => println!("\nSummary: FAILED to write {} ({})", path.display(), err),
+        }
+    }
+
+    if let Some(path) = &options.evidence_json {
+        let json = serde_json::to_string_pretty(&evidence).expect("evidence serializes");
+        match std::fs::write(path, json) {
+            Ok(()) => println!("\nEvidence written to {}", path.display()),
+            Err(err) => println!("\nEvidence: FAILED to write {} ({})", path.display(), err),
+        }
+    }
+
+    if let Some(path) = &options.metrics_out {
+        let mut snapshot = MetricsSnapshot::from_summary(summary);
+        snapshot.push(MetricFamily::gauge(
+            "aevion_escape_count",
+            "Number of #[verifier::external*] escapes per module",
+            config
+                .targets
+                .iter()
+                .zip(&inventories)
+                .map(|(target, inventory)| {
+                    let labels = vec![("module".to_string(), target.name.clone())];
+                    (labels, inventory.escapes.len() as f64)
+                })
+                .collect(),
+        ));
+        let text = snapshot.to_prometheus();
+        match metrics::write_textfile(path, &text) {
+            Ok(()) => println!("\nMetrics written to {}", path.display()),
+            Err(err) => println!("\nMetrics: FAILED to write {} ({})", path.display(), err),
+        }
+    }
 
-    for (claim, description, evidence) in claims {
-        println!("\n{}: {}", claim, description);
-        println!("  Evidence: {}", evidence);
+    if !options.notify.is_empty() {
+        publish_notifications(&options, summary, baseline.as_ref());
     }
 
     println!("\n============================================================");
@@ -139,8 +1279,7 @@ fn main() {
     println!("============================================================");
 
     println!("\n1. Install Verus:");
-    println!("   git clone https://github.com/verus-lang/verus");
-    println!("   cd verus && ./tools/get-z3.sh && cargo build --release");
+    println!("   cargo run --bin verify_all -- install-tools --yes");
 
     println!("\n2. Run Verus proofs:");
     println!("   verus src/variance_halt.rs");
@@ -160,4 +1299,127 @@ fn main() {
     println!("VERIFICATION COMPLETE");
     println!("============================================================");
     println!("\nAevion LLC | CAGE: 15NV7 | Patent: US 63/896,282");
+
+    if summary.overall() == VerificationStatus::Failed {
+        std::process::exit(1);
+    }
+}
+
+/// Render located prover errors, falling back to the raw output if none parsed
+fn print_prover_errors(
+    output: &str,
+    errors: &[Diagnostic],
+    sources: &mut SourceCache,
+    color: bool,
+) {
+    if errors.is_empty() {
+        for line in output.lines() {
+            println!("    {}", line);
+        }
+        return;
+    }
+    for error in errors {
+        println!();
+        print!("{}", render::render(error, sources, color));
+    }
+}
+
+fn print_solver_stats(stats: &SolverStats, rlimit: u64, threshold: f64) {
+    if stats.is_empty() {
+        println!("  Solver statistics: unavailable (no recognized statistics in prover output)");
+        return;
+    }
+    println!("  Solver statistics:");
+    let near_limit = stats.near_limit(rlimit, threshold);
+    for function in &stats.functions {
+        let flag = if near_limit.iter().any(|f| f.name == function.name) {
+            "  WARNING: near rlimit"
+        } else {
+            ""
+        };
+        println!(
+            "    {:<40} rlimit {:>12} ({:.0}% of budget){}",
+            function.name,
+            function.rlimit,
+            function.rlimit_fraction(rlimit) * 100.0,
+            flag
+        );
+        for quantifier in &function.top_quantifiers {
+            println!(
+                "      {} instantiations at {}:{}",
+                quantifier.instantiations,
+                quantifier.file.display(),
+                quantifier.line
+            );
+        }
+    }
+    for quantifier in stats.quantifiers.iter().take(solver_stats::TOP_QUANTIFIERS) {
+        println!(
+            "    quantifier {}:{}: {} instantiations",
+            quantifier.file.display(),
+            quantifier.line,
+            quantifier.instantiations
+        );
+    }
+}
+
+/// Per-scenario accuracy and halt rate, then the resilience factor
+fn print_empirical(evidence: &VerificationEvidence) {
+    if evidence.empirical.is_empty() {
+        println!("\n  No empirical results (see --empirical)");
+        return;
+    }
+    println!();
+    print_results(&evidence.empirical);
+    if let Some(factor) = evidence.resilience_factor() {
+        println!("Resilience factor:        {}", evidence::format_per_mille(factor));
+    }
+}
+
+/// One line per scenario: accuracy, then the halt rate if any halted
+fn print_results(results: &[EmpiricalResults]) {
+    for result in results {
+        let label = match result.scenario.as_str() {
+            evidence::SCENARIO_BASELINE => "Baseline (no attack)",
+            evidence::SCENARIO_BYZANTINE_33 => "33% Byzantine attack",
+            evidence::SCENARIO_BYZANTINE_67 => "67% Byzantine attack",
+            other => other,
+        };
+        let accuracy = result.accuracy().map(evidence::format_per_mille);
+        print!(
+            "{:<26}{} ({}/{})",
+            format!("{}:", label),
+            accuracy.as_deref().unwrap_or("n/a"),
+            result.correct,
+            result.total
+        );
+        match result.halt_rate() {
+            Some(rate) if result.halted > 0 => {
+                println!(" + {} HALT", evidence::format_per_mille(rate))
+            }
+            _ => println!(),
+        }
+    }
+}
+
+fn print_unit_tests(status: VerificationStatus, results: Option<&UnitTestResults>) {
+    match results {
+        Some(results) => {
+            println!(
+                "  Passed: {}  Failed: {}  Ignored: {}",
+                results.passed, results.failed, results.ignored
+            );
+            if results.timed_out {
+                println!("  Status: TIMEOUT");
+            }
+            for name in &results.failed_tests {
+                println!("  FAILED: {}", name);
+            }
+        }
+        None if status == VerificationStatus::Failed => {
+            println!("  Status: FAILED (no test summary; did the crate compile?)");
+        }
+        None => {}
+    }
+    println!("  Status: {:?}", status);
 }
@@ -0,0 +1,462 @@
+//! # Summary Merging
+//!
+//! Combines verification summaries produced by different runners (e.g. Verus
+//! on Linux, Prusti on macOS) into one report.
+//!
+//! Results combine theorem by theorem. A `NotRun` or `Skipped` result is an
+//! absence of evidence and yields to whatever the other side reports. Two
+//! real results that disagree are a conflict: [`MergePolicy::Strict`] reports
+//! it, [`MergePolicy::PreferWorst`] keeps the more severe status.
+//!
+//! Runs are identified by `run_id`: merging a run with an archived copy of
+//! itself is a no-op.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt;
+
+use crate::clock;
+use crate::{ModuleReport, UnitTestResults, VerificationStatus, VerificationSummary};
+
+/// How to resolve two different results for the same module or theorem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergePolicy {
+    /// Disagreeing results are an error
+    #[default]
+    Strict,
+    /// Keep the more severe result
+    PreferWorst,
+}
+
+/// Two summaries report different results for the same item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeConflict {
+    Module {
+        module: String,
+        left: VerificationStatus,
+        right: VerificationStatus,
+    },
+    Theorem {
+        module: String,
+        theorem: String,
+        left: VerificationStatus,
+        right: VerificationStatus,
+    },
+    UnitTests {
+        left: VerificationStatus,
+        right: VerificationStatus,
+    },
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeConflict::Module {
+                module,
+                left,
+                right,
+            } => write!(f, "module {}: {} vs {}", module, left, right),
+            MergeConflict::Theorem {
+                module,
+                theorem,
+                left,
+                right,
+            } => write!(f, "theorem {}::{}: {} vs {}", module, theorem, left, right),
+            MergeConflict::UnitTests { left, right } => {
+                write!(f, "unit tests: {} vs {}", left, right)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MergeConflict {}
+
+impl VerificationSummary {
+    /// Combine with another summary.
+    ///
+    /// Modules keep this summary's order, followed by modules only `other`
+    /// reports. Durations and unit-test counts add up; axioms and run
+    /// sources are unioned. The merged run spans the earliest start to the
+    /// latest finish, and is sealed with a new `run_id` if both inputs were.
+    pub fn merge(&self, other: &Self, policy: MergePolicy) -> Result<Self, MergeConflict> {
+        if self.run_id.is_some() && self.run_id == other.run_id {
+            return Ok(self.clone());
+        }
+        let mut merged = self.clone();
+
+        for report in &other.modules {
+            match merged.module_mut(&report.name) {
+                Some(existing) => *existing = merge_module(existing, report, policy)?,
+                None => merged.modules.push(report.clone()),
+            }
+        }
+
+        merged.unit_tests = combine(self.unit_tests, other.unit_tests, policy)
+            .map_err(|(left, right)| MergeConflict::UnitTests { left, right })?;
+        merged.unit_test_results = match (&self.unit_test_results, &other.unit_test_results) {
+            (Some(left), Some(right)) => Some(add_results(left, right)),
+            (left, right) => left.clone().or_else(|| right.clone()),
+        };
+
+        for source in &other.sources {
+            let duplicate = merged.sources.iter().any(|existing| match &source.run_id {
+                Some(run_id) => existing.run_id.as_ref() == Some(run_id),
+                None => existing == source,
+            });
+            if !duplicate {
+                merged.sources.push(source.clone());
+            }
+        }
+
+        let started = [&self.started_at, &other.started_at]
+            .into_iter()
+            .filter_map(|text| clock::parse_rfc3339(text))
+            .min();
+        if let Some(started) = started {
+            merged.started_at = clock::format_rfc3339(started);
+        }
+        let finished = [&self.finished_at, &other.finished_at]
+            .into_iter()
+            .map(|text| text.as_deref().and_then(clock::parse_rfc3339))
+            .collect::<Option<Vec<_>>>()
+            .and_then(|times| times.into_iter().max());
+        merged.finished_at = finished.map(clock::format_rfc3339);
+        merged.total_duration = started
+            .zip(finished)
+            .and_then(|(started, finished)| finished.duration_since(started).ok());
+        merged.run_id = None;
+        if self.run_id.is_some() && other.run_id.is_some() {
+            merged.seal();
+        }
+        Ok(merged)
+    }
+
+    /// Merge any number of summaries, left to right. An empty input gives a
+    /// summary with no modules.
+    pub fn aggregate<'a, I>(summaries: I, policy: MergePolicy) -> Result<Self, MergeConflict>
+    where
+        I: IntoIterator<Item = &'a VerificationSummary>,
+    {
+        let mut summaries = summaries.into_iter();
+        let Some(first) = summaries.next() else {
+            return Ok(Self::with_modules(Vec::<String>::new()));
+        };
+        summaries.try_fold(first.clone(), |merged, next| merged.merge(next, policy))
+    }
+}
+
+/// Not run or skipped: no evidence either way
+fn is_absent(status: VerificationStatus) -> bool {
+    matches!(
+        status,
+        VerificationStatus::NotRun | VerificationStatus::Skipped
+    )
+}
+
+/// Combine two results, returning the pair on a conflict
+fn combine(
+    left: VerificationStatus,
+    right: VerificationStatus,
+    policy: MergePolicy,
+) -> Result<VerificationStatus, (VerificationStatus, VerificationStatus)> {
+    if left == right {
+        Ok(left)
+    } else if is_absent(left) && is_absent(right) {
+        Ok(left.max(right))
+    } else if is_absent(left) {
+        Ok(right)
+    } else if is_absent(right) {
+        Ok(left)
+    } else {
+        match policy {
+            MergePolicy::Strict => Err((left, right)),
+            MergePolicy::PreferWorst => Ok(left.max(right)),
+        }
+    }
+}
+
+fn merge_module(
+    left: &ModuleReport,
+    right: &ModuleReport,
+    policy: MergePolicy,
+) -> Result<ModuleReport, MergeConflict> {
+    let module = &left.name;
+    let mut merged = left.clone();
+    merged.status =
+        combine(left.status, right.status, policy).map_err(|(l, r)| MergeConflict::Module {
+            module: module.clone(),
+            left: l,
+            right: r,
+        })?;
+
+    for theorem in &right.theorems {
+        match merged.theorems.iter_mut().find(|t| t.name == theorem.name) {
+            Some(existing) => {
+                existing.status =
+                    combine(existing.status, theorem.status, policy).map_err(|(l, r)| {
+                        MergeConflict::Theorem {
+                            module: module.clone(),
+                            theorem: theorem.name.clone(),
+                            left: l,
+                            right: r,
+                        }
+                    })?
+            }
+            None => merged.theorems.push(theorem.clone()),
+        }
+    }
+
+    merged.duration = match (left.duration, right.duration) {
+        (Some(l), Some(r)) => Some(l + r),
+        (l, r) => l.or(r),
+    };
+    for axiom in &right.axioms {
+        if !merged.axioms.contains(axiom) {
+            merged.axioms.push(axiom.clone());
+        }
+    }
+    Ok(merged)
+}
+
+fn add_results(left: &UnitTestResults, right: &UnitTestResults) -> UnitTestResults {
+    let mut failed_tests = left.failed_tests.clone();
+    for name in &right.failed_tests {
+        if !failed_tests.contains(name) {
+            failed_tests.push(name.clone());
+        }
+    }
+    UnitTestResults {
+        passed: left.passed + right.passed,
+        failed: left.failed + right.failed,
+        ignored: left.ignored + right.ignored,
+        failed_tests,
+        timed_out: left.timed_out || right.timed_out,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::{RunSource, Severity, TheoremResult};
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+    use VerificationStatus::*;
+
+    fn module(
+        name: &str,
+        status: VerificationStatus,
+        theorems: &[(&str, VerificationStatus)],
+    ) -> ModuleReport {
+        ModuleReport {
+            status,
+            theorems: theorems
+                .iter()
+                .map(|(name, status)| TheoremResult {
+                    name: name.to_string(),
+                    status: *status,
+                    duration: None,
+                    severity: Severity::Standard,
+                })
+                .collect(),
+            duration: Some(Duration::from_secs(2)),
+            axioms: vec![format!("axiom_{}", name)],
+            ..ModuleReport::new(name)
+        }
+    }
+
+    fn summary(label: &str, modules: Vec<ModuleReport>) -> VerificationSummary {
+        let mut summary = VerificationSummary::with_modules(Vec::<String>::new());
+        for report in modules {
+            summary.insert_module(report);
+        }
+        summary.unit_tests = Verified;
+        summary.unit_test_results = Some(UnitTestResults {
+            passed: 10,
+            ..UnitTestResults::default()
+        });
+        summary.sources.push(RunSource {
+            label: label.to_string(),
+            timestamp: Some(1_790_000_000),
+            run_id: None,
+            toolchain: BTreeMap::from([("rustc".to_string(), format!("rustc ({})", label))]),
+        });
+        summary
+    }
+
+    fn theorem_status(
+        summary: &VerificationSummary,
+        module: &str,
+        theorem: &str,
+    ) -> VerificationStatus {
+        summary
+            .module(module)
+            .and_then(|m| m.theorems.iter().find(|t| t.name == theorem))
+            .map(|t| t.status)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_merge_disjoint_modules() {
+        let linux = summary(
+            "linux",
+            vec![module("trust_bounds", Verified, &[("a", Verified)])],
+        );
+        let macos = summary(
+            "macos",
+            vec![module("contracts", Verified, &[("b", Verified)])],
+        );
+
+        let merged = linux.merge(&macos, MergePolicy::Strict).unwrap();
+        assert_eq!(
+            merged.module_names().collect::<Vec<_>>(),
+            ["trust_bounds", "contracts"]
+        );
+        assert_eq!(merged.overall(), Verified);
+        assert_eq!(merged.unit_test_results.as_ref().unwrap().passed, 20);
+
+        // Metadata is kept per source
+        let labels: Vec<&str> = merged.sources.iter().map(|s| s.label.as_str()).collect();
+        assert_eq!(labels, ["linux", "macos"]);
+        assert_eq!(merged.sources[1].toolchain["rustc"], "rustc (macos)");
+    }
+
+    #[test]
+    fn test_merge_overlapping_modules() {
+        // Verus checked two theorems; the other runner did not run Verus
+        // but checked a third theorem in the same module
+        let linux = summary(
+            "linux",
+            vec![module(
+                "trust_bounds",
+                Verified,
+                &[("a", Verified), ("b", Verified)],
+            )],
+        );
+        let macos = summary(
+            "macos",
+            vec![module(
+                "trust_bounds",
+                NotRun,
+                &[("a", NotRun), ("c", Failed)],
+            )],
+        );
+
+        let merged = linux.merge(&macos, MergePolicy::Strict).unwrap();
+        let report = merged.module("trust_bounds").unwrap();
+        assert_eq!(report.status, Verified);
+        assert_eq!(theorem_status(&merged, "trust_bounds", "a"), Verified);
+        assert_eq!(theorem_status(&merged, "trust_bounds", "c"), Failed);
+        assert_eq!(report.theorems.len(), 3);
+        assert_eq!(report.duration, Some(Duration::from_secs(4)));
+        assert_eq!(report.axioms, ["axiom_trust_bounds"]);
+        assert_eq!(merged.status("trust_bounds"), Some(Failed));
+    }
+
+    #[test]
+    fn test_merge_conflicting_theorem() {
+        let linux = summary(
+            "linux",
+            vec![module("trust_bounds", Verified, &[("a", Verified)])],
+        );
+        let macos = summary(
+            "macos",
+            vec![module("trust_bounds", Verified, &[("a", Failed)])],
+        );
+
+        let conflict = linux.merge(&macos, MergePolicy::Strict).unwrap_err();
+        assert_eq!(
+            conflict,
+            MergeConflict::Theorem {
+                module: "trust_bounds".to_string(),
+                theorem: "a".to_string(),
+                left: Verified,
+                right: Failed,
+            }
+        );
+        assert_eq!(
+            conflict.to_string(),
+            "theorem trust_bounds::a: verified vs failed"
+        );
+
+        let merged = linux.merge(&macos, MergePolicy::PreferWorst).unwrap();
+        assert_eq!(theorem_status(&merged, "trust_bounds", "a"), Failed);
+        assert_eq!(merged.overall(), Degraded);
+    }
+
+    #[test]
+    fn test_merge_conflicting_module_and_unit_tests() {
+        let linux = summary("linux", vec![module("m", Verified, &[])]);
+        let mut macos = summary("macos", vec![module("m", Failed, &[])]);
+        assert!(matches!(
+            linux.merge(&macos, MergePolicy::Strict),
+            Err(MergeConflict::Module { .. })
+        ));
+
+        macos.set_status("m", Verified);
+        macos.unit_tests = Failed;
+        assert_eq!(
+            linux.merge(&macos, MergePolicy::Strict),
+            Err(MergeConflict::UnitTests {
+                left: Verified,
+                right: Failed
+            })
+        );
+
+        // Skipped unit tests yield to the run that executed them
+        macos.unit_tests = Skipped;
+        let merged = linux.merge(&macos, MergePolicy::Strict).unwrap();
+        assert_eq!(merged.unit_tests, Verified);
+    }
+
+    #[test]
+    fn test_merge_uses_run_identity() {
+        let clock = ManualClock::at_unix(1_790_000_000);
+        let mut linux = VerificationSummary::with_clock(["m1"], &clock);
+        linux.insert_module(module("m1", Verified, &[("t", Verified)]));
+        clock.advance(Duration::from_secs(30));
+        let mut macos = VerificationSummary::with_clock(["m2"], &clock);
+        macos.insert_module(module("m2", Verified, &[("t", Verified)]));
+        clock.advance(Duration::from_secs(30));
+        linux.finish_with_clock(&clock);
+        clock.advance(Duration::from_secs(15));
+        macos.finish_with_clock(&clock);
+
+        // An archived copy of the same run merges to itself
+        let copy = linux.clone();
+        let merged = linux.merge(&copy, MergePolicy::Strict).unwrap();
+        assert_eq!(merged, linux);
+
+        // Distinct runs: the merged run spans both and gets its own id
+        let merged = linux.merge(&macos, MergePolicy::Strict).unwrap();
+        assert_eq!(merged.started_at, linux.started_at);
+        assert_eq!(merged.finished_at, macos.finished_at);
+        assert_eq!(merged.total_duration, Some(Duration::from_secs(75)));
+        assert!(merged.run_id.is_some());
+        assert_ne!(merged.run_id, linux.run_id);
+        assert_ne!(merged.run_id, macos.run_id);
+
+        // Unfinished input: no end time, no id
+        let unfinished = VerificationSummary::with_clock(["m3"], &clock);
+        let merged = linux.merge(&unfinished, MergePolicy::Strict).unwrap();
+        assert_eq!(merged.finished_at, None);
+        assert_eq!(merged.run_id, None);
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let runs = [
+            summary("a", vec![module("m1", Verified, &[("t", Verified)])]),
+            summary("b", vec![module("m2", Verified, &[("t", Verified)])]),
+            summary("c", vec![module("m1", Verified, &[("u", Verified)])]),
+        ];
+        let merged = VerificationSummary::aggregate(&runs, MergePolicy::Strict).unwrap();
+        assert_eq!(merged.module_names().collect::<Vec<_>>(), ["m1", "m2"]);
+        assert_eq!(merged.module("m1").unwrap().theorems.len(), 2);
+        assert_eq!(merged.sources.len(), 3);
+        assert_eq!(merged.overall(), Verified);
+
+        let empty = VerificationSummary::aggregate([], MergePolicy::Strict).unwrap();
+        assert_eq!(empty.module_names().count(), 0);
+    }
+}
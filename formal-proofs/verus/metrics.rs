@@ -0,0 +1,480 @@
+//! # Metrics Export
+//!
+//! A [`MetricsSnapshot`] turns a verification summary into metric families
+//! with two encodings: the Prometheus text exposition format (for the
+//! node_exporter textfile collector) and flat JSON for services that push
+//! to their own telemetry.
+//!
+//! ## Metrics
+//! - `aevion_verification_status{module}`: 0 = failed, 1 = partial, 2 = verified
+//! - `aevion_verification_overall_status`: Roll-up of every module and the unit tests
+//! - `aevion_unit_tests_status`: The `cargo test` stage
+//! - `aevion_theorem_count{module}`: Theorems and lemmas in the module
+//! - `aevion_theorems_verified{module}`: Theorems that verified
+//! - `aevion_theorems_failed{module}`: Theorems that failed
+//! - `aevion_axiom_count{module}`: Axioms accepted without proof
+//! - `aevion_verification_duration_seconds{module}`: Prover wall-clock time
+//! - `aevion_theorem_duration_seconds{module,theorem}`: Solver time, when reported
+//!
+//! The runner adds `aevion_escape_count{module}` (`#[verifier::external*]`
+//! escapes) from its source scan.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+#[cfg(feature = "serde")]
+use serde_json::{Map, Value};
+
+use crate::error::ShieldError;
+use crate::{ModuleReport, VerificationStatus, VerificationSummary};
+
+/// Prometheus metric type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+}
+
+impl MetricKind {
+    /// Name used in `# TYPE` lines and the JSON `type` field
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MetricKind::Counter => "counter",
+            MetricKind::Gauge => "gauge",
+        }
+    }
+}
+
+/// One sample: label pairs and a value
+pub type Sample = (Vec<(String, String)>, f64);
+
+/// A metric family: name, help text, type and samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricFamily {
+    pub name: String,
+    pub help: String,
+    pub kind: MetricKind,
+    pub samples: Vec<Sample>,
+}
+
+impl MetricFamily {
+    /// A gauge family. The name is sanitized for Prometheus.
+    pub fn gauge(name: &str, help: &str, samples: Vec<Sample>) -> Self {
+        Self::new(name, help, MetricKind::Gauge, samples)
+    }
+
+    /// A counter family. The name is sanitized for Prometheus.
+    pub fn counter(name: &str, help: &str, samples: Vec<Sample>) -> Self {
+        Self::new(name, help, MetricKind::Counter, samples)
+    }
+
+    fn new(name: &str, help: &str, kind: MetricKind, samples: Vec<Sample>) -> Self {
+        Self {
+            name: sanitize_name(name),
+            help: help.to_string(),
+            kind,
+            samples,
+        }
+    }
+}
+
+/// Metric families derived from one verification run
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MetricsSnapshot {
+    pub families: Vec<MetricFamily>,
+}
+
+/// Theorems in `report` with the given status
+fn count(report: &ModuleReport, status: VerificationStatus) -> f64 {
+    report
+        .theorems
+        .iter()
+        .filter(|t| t.status == status)
+        .count() as f64
+}
+
+/// `[("module", name)]`
+fn module_label(module: &str) -> Vec<(String, String)> {
+    vec![("module".to_string(), module.to_string())]
+}
+
+impl MetricsSnapshot {
+    /// Status, theorem, axiom and duration metrics for every module in the
+    /// summary, in verification order
+    pub fn from_summary(summary: &VerificationSummary) -> Self {
+        let per_module = |value: fn(&ModuleReport) -> Option<f64>| -> Vec<Sample> {
+            summary
+                .modules()
+                .filter_map(|(name, report)| value(report).map(|v| (module_label(name), v)))
+                .collect()
+        };
+        let theorem_durations: Vec<Sample> = summary
+            .modules()
+            .flat_map(|(name, report)| {
+                report.theorems.iter().filter_map(move |t| {
+                    let labels = vec![
+                        ("module".to_string(), name.to_string()),
+                        ("theorem".to_string(), t.name.clone()),
+                    ];
+                    t.duration.map(|d| (labels, d.as_secs_f64()))
+                })
+            })
+            .collect();
+
+        let families = vec![
+            MetricFamily::gauge(
+                "aevion_verification_status",
+                "Verification status per module (0 = failed, 1 = partial, 2 = verified)",
+                per_module(|report| Some(status_value(report.derived_status()))),
+            ),
+            MetricFamily::gauge(
+                "aevion_verification_overall_status",
+                "Overall verification status (0 = failed, 1 = partial, 2 = verified)",
+                vec![(Vec::new(), status_value(summary.overall()))],
+            ),
+            MetricFamily::gauge(
+                "aevion_unit_tests_status",
+                "Unit test stage status (0 = failed, 1 = partial, 2 = verified)",
+                vec![(Vec::new(), status_value(summary.unit_tests))],
+            ),
+            MetricFamily::gauge(
+                "aevion_theorem_count",
+                "Number of theorems and lemmas per module",
+                per_module(|report| Some(report.theorems.len() as f64)),
+            ),
+            MetricFamily::gauge(
+                "aevion_theorems_verified",
+                "Number of verified theorems per module",
+                per_module(|report| Some(count(report, VerificationStatus::Verified))),
+            ),
+            MetricFamily::gauge(
+                "aevion_theorems_failed",
+                "Number of failed theorems per module",
+                per_module(|report| Some(count(report, VerificationStatus::Failed))),
+            ),
+            MetricFamily::gauge(
+                "aevion_axiom_count",
+                "Number of axioms accepted without proof per module",
+                per_module(|report| Some(report.axioms.len() as f64)),
+            ),
+            MetricFamily::gauge(
+                "aevion_verification_duration_seconds",
+                "Prover wall-clock time per module in seconds",
+                per_module(|report| report.duration.map(|d| d.as_secs_f64())),
+            ),
+            MetricFamily::gauge(
+                "aevion_theorem_duration_seconds",
+                "Solver time per theorem in seconds, when reported",
+                theorem_durations,
+            ),
+        ];
+        Self { families }
+    }
+
+    /// Add a family, e.g. metrics the summary does not carry
+    pub fn push(&mut self, family: MetricFamily) {
+        self.families.push(family);
+    }
+
+    /// Prometheus text exposition format. Every family gets `# HELP` and
+    /// `# TYPE` lines, even without samples.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        for family in &self.families {
+            let _ = writeln!(out, "# HELP {} {}", family.name, escape_help(&family.help));
+            let _ = writeln!(out, "# TYPE {} {}", family.name, family.kind.as_str());
+            for (labels, value) in &family.samples {
+                out.push_str(&family.name);
+                if !labels.is_empty() {
+                    let rendered: Vec<String> = labels
+                        .iter()
+                        .map(|(k, v)| format!("{}=\"{}\"", sanitize_name(k), escape_label_value(v)))
+                        .collect();
+                    let _ = write!(out, "{{{}}}", rendered.join(","));
+                }
+                let _ = writeln!(out, " {}", format_value(*value));
+            }
+        }
+        out
+    }
+
+    /// Flat JSON: an array with one object per sample holding `name`,
+    /// `type`, `value` and the sample's labels as top-level string fields.
+    /// Non-finite values are encoded as `null`.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        let samples: Vec<Value> = self
+            .families
+            .iter()
+            .flat_map(|family| {
+                family.samples.iter().map(move |(labels, value)| {
+                    let mut object = Map::new();
+                    for (key, label) in labels {
+                        object.insert(key.clone(), Value::String(label.clone()));
+                    }
+                    object.insert("name".to_string(), Value::String(family.name.clone()));
+                    object.insert(
+                        "type".to_string(),
+                        Value::String(family.kind.as_str().to_string()),
+                    );
+                    let value = serde_json::Number::from_f64(*value)
+                        .map(Value::Number)
+                        .unwrap_or(Value::Null);
+                    object.insert("value".to_string(), value);
+                    Value::Object(object)
+                })
+            })
+            .collect();
+        Value::Array(samples).to_string()
+    }
+}
+
+/// Numeric encoding of a status (higher is better)
+pub fn status_value(status: VerificationStatus) -> f64 {
+    match status {
+        VerificationStatus::Failed => 0.0,
+        // Anything neither failed nor verified is reported as pending;
+        // a degraded roll-up still holds every critical theorem
+        VerificationStatus::Partial
+        | VerificationStatus::Degraded
+        | VerificationStatus::NotRun
+        | VerificationStatus::Skipped
+        | VerificationStatus::Unknown => 1.0,
+        VerificationStatus::Verified => 2.0,
+    }
+}
+
+/// Make a metric or label name match `[a-zA-Z_][a-zA-Z0-9_]*`: other
+/// characters become `_`, and a leading digit gets a `_` prefix. Label
+/// names may not start with `__` (reserved); leading underscores are kept
+/// as given so callers control that.
+pub fn sanitize_name(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if sanitized.is_empty() || sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    sanitized
+}
+
+/// Escape a label value per the exposition format (`\`, `"`, and newline)
+pub fn escape_label_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape HELP text (`\` and newline only)
+fn escape_help(help: &str) -> String {
+    help.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+/// Sample value as the exposition format spells it (`NaN`, `+Inf`, `-Inf`)
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Write a textfile atomically (temp file + rename) so the collector never
+/// observes a partially written file.
+pub fn write_textfile(path: &Path, contents: &str) -> Result<(), ShieldError> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Severity, TheoremResult};
+    use std::time::Duration;
+
+    fn theorem(name: &str, status: VerificationStatus, millis: Option<u64>) -> TheoremResult {
+        TheoremResult {
+            name: name.to_string(),
+            status,
+            duration: millis.map(Duration::from_millis),
+            severity: Severity::Standard,
+        }
+    }
+
+    fn summary() -> VerificationSummary {
+        let mut summary = VerificationSummary::with_modules(Vec::<String>::new());
+        summary.insert_module(ModuleReport {
+            status: VerificationStatus::Verified,
+            theorems: vec![
+                theorem("a", VerificationStatus::Verified, Some(250)),
+                theorem("b", VerificationStatus::Verified, None),
+            ],
+            duration: Some(Duration::from_millis(1500)),
+            ..ModuleReport::new("variance_halt")
+        });
+        summary.insert_module(ModuleReport {
+            status: VerificationStatus::Failed,
+            theorems: vec![
+                theorem("c", VerificationStatus::Failed, None),
+                theorem("d", VerificationStatus::Verified, None),
+            ],
+            axioms: vec!["axiom_x".to_string()],
+            ..ModuleReport::new("trust_bounds")
+        });
+        summary.unit_tests = VerificationStatus::Verified;
+        summary
+    }
+
+    #[test]
+    fn test_escape_label_value() {
+        assert_eq!(escape_label_value("plain"), "plain");
+        assert_eq!(escape_label_value(r#"a"b"#), r#"a\"b"#);
+        assert_eq!(escape_label_value(r"a\b"), r"a\\b");
+        assert_eq!(escape_label_value("a\nb"), r"a\nb");
+    }
+
+    #[test]
+    fn test_sanitize_name() {
+        assert_eq!(sanitize_name("aevion_status"), "aevion_status");
+        assert_eq!(sanitize_name("routing-policy.v2"), "routing_policy_v2");
+        assert_eq!(sanitize_name("2fa"), "_2fa");
+        assert_eq!(sanitize_name(""), "_");
+        assert_eq!(sanitize_name("caf\u{e9}"), "caf_");
+    }
+
+    #[test]
+    fn test_snapshot_from_summary() {
+        let text = MetricsSnapshot::from_summary(&summary()).to_prometheus();
+
+        assert!(text.contains("# TYPE aevion_verification_status gauge\n"));
+        assert!(text.contains("aevion_verification_status{module=\"variance_halt\"} 2\n"));
+        assert!(text.contains("aevion_verification_status{module=\"trust_bounds\"} 0\n"));
+        assert!(text.contains("aevion_verification_overall_status 1\n"));
+        assert!(text.contains("aevion_unit_tests_status 2\n"));
+        assert!(text.contains("aevion_theorem_count{module=\"variance_halt\"} 2\n"));
+        assert!(text.contains("aevion_theorems_verified{module=\"trust_bounds\"} 1\n"));
+        assert!(text.contains("aevion_theorems_failed{module=\"trust_bounds\"} 1\n"));
+        assert!(text.contains("aevion_axiom_count{module=\"trust_bounds\"} 1\n"));
+        assert!(
+            text.contains("aevion_verification_duration_seconds{module=\"variance_halt\"} 1.5\n")
+        );
+        // Missing durations are omitted rather than reported as zero
+        assert!(!text.contains("aevion_verification_duration_seconds{module=\"trust_bounds\"}"));
+        assert!(text.contains(
+            "aevion_theorem_duration_seconds{module=\"variance_halt\",theorem=\"a\"} 0.25\n"
+        ));
+        assert!(!text.contains("theorem=\"b\""));
+    }
+
+    #[test]
+    fn test_exposition_format() {
+        let mut snapshot = MetricsSnapshot::default();
+        snapshot.push(MetricFamily::gauge(
+            "aevion_test",
+            "Line one\nline two \\ end",
+            vec![(
+                vec![
+                    ("module".to_string(), "we\"ird\\\nname".to_string()),
+                    ("bad-label".to_string(), "x".to_string()),
+                ],
+                1.0,
+            )],
+        ));
+        snapshot.push(MetricFamily::counter(
+            "aevion.runs",
+            "Runs",
+            vec![
+                (Vec::new(), f64::INFINITY),
+                (vec![("k".to_string(), "n".to_string())], f64::NAN),
+                (vec![("k".to_string(), "m".to_string())], f64::NEG_INFINITY),
+            ],
+        ));
+        snapshot.push(MetricFamily::gauge(
+            "aevion_empty",
+            "No samples",
+            Vec::new(),
+        ));
+
+        let expected = "\
+# HELP aevion_test Line one\\nline two \\\\ end
+# TYPE aevion_test gauge
+aevion_test{module=\"we\\\"ird\\\\\\nname\",bad_label=\"x\"} 1
+# HELP aevion_runs Runs
+# TYPE aevion_runs counter
+aevion_runs +Inf
+aevion_runs{k=\"n\"} NaN
+aevion_runs{k=\"m\"} -Inf
+# HELP aevion_empty No samples
+# TYPE aevion_empty gauge
+";
+        assert_eq!(snapshot.to_prometheus(), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_flat_json() {
+        let mut snapshot = MetricsSnapshot::default();
+        snapshot.push(MetricFamily::gauge(
+            "aevion_verification_status",
+            "Status",
+            vec![(module_label("trust_bounds"), 2.0)],
+        ));
+        snapshot.push(MetricFamily::counter(
+            "aevion_runs",
+            "Runs",
+            vec![(Vec::new(), f64::NAN)],
+        ));
+
+        let json: Value = serde_json::from_str(&snapshot.to_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "name": "aevion_verification_status",
+                    "type": "gauge",
+                    "module": "trust_bounds",
+                    "value": 2.0
+                },
+                { "name": "aevion_runs", "type": "counter", "value": null }
+            ])
+        );
+
+        // Every sample of a full snapshot is one flat object
+        let json: Value =
+            serde_json::from_str(&MetricsSnapshot::from_summary(&summary()).to_json()).unwrap();
+        let samples = json.as_array().unwrap();
+        assert!(samples
+            .iter()
+            .all(|s| s.as_object().unwrap().values().all(|v| !v.is_object())));
+    }
+
+    #[test]
+    fn test_write_textfile() {
+        let path = std::env::temp_dir().join("aevion-shield-metrics-test.prom");
+        write_textfile(&path, "aevion_test 1\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "aevion_test 1\n");
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,294 @@
+//! # Model Registry
+//!
+//! Executable counterpart of the model registries in `trust_bounds.rs`.
+//! A [`ModelRegistry`] maps each model name to a [`ModelSpec`]: the id
+//! votes carry, the weight its votes get (scaled by 100) and its
+//! [`ModelFamily`]. Every weight is checked against the proven bounds
+//! [`MIN_MODEL_WEIGHT`]`..=`[`MAX_MODEL_WEIGHT`] when a model is added
+//! (`registry_valid`), so an out-of-range weight is rejected rather than
+//! clamped, and the weighted consensus theorems (`model_weights_bounded`,
+//! `combined_weight_bounded`) hold for any registry that loads. Ids a
+//! registry does not list weigh [`DEFAULT_MODEL_WEIGHT`] and belong to
+//! [`ModelFamily::Unknown`].
+//!
+//! [`ModelRegistry::builtin`] holds the five models of `model_weight` in
+//! `trust_bounds.rs`, and is what a
+//! [`ConsensusEngine`](crate::consensus::ConsensusEngine) weighs votes by
+//! unless given another ([`with_models`](crate::consensus::ConsensusEngine::with_models)).
+//! Deployments add their own under `[models]` in the runner config:
+//!
+//! ```toml
+//! [models.claude-sonnet]
+//! id = 5
+//! weight_scaled = 160
+//! family = "claude"
+//! ```
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::diversity::ModelFamily;
+use crate::error::ShieldError;
+
+/// Lowest weight a registry accepts (1.0)
+pub const MIN_MODEL_WEIGHT: u64 = 100;
+
+/// Highest weight a registry accepts (2.0)
+pub const MAX_MODEL_WEIGHT: u64 = 200;
+
+/// Weight of a model id the registry does not list (1.0)
+pub const DEFAULT_MODEL_WEIGHT: u64 = 100;
+
+/// A registered model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ModelSpec {
+    /// Id its votes carry
+    pub id: u64,
+    /// Vote weight scaled by 100, in
+    /// `MIN_MODEL_WEIGHT..=MAX_MODEL_WEIGHT`
+    pub weight_scaled: u64,
+    pub family: ModelFamily,
+}
+
+/// Models by name, with distinct ids and validated weights
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelRegistry {
+    models: BTreeMap<String, ModelSpec>,
+}
+
+impl ModelRegistry {
+    /// Registry without models: every id weighs [`DEFAULT_MODEL_WEIGHT`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The models `model_weight` in `trust_bounds.rs` and
+    /// [`ModelFamily::of`] number
+    pub fn builtin() -> Self {
+        let builtin = [
+            ("o1-mini", 0, 180, ModelFamily::OpenAiO),
+            ("nvidia_nemotron_70b", 1, 170, ModelFamily::Nemotron),
+            ("gpt-4o", 2, 150, ModelFamily::Gpt4),
+            ("gpt-4-turbo", 3, 150, ModelFamily::Gpt4),
+            ("gpt-4o-mini", 4, 130, ModelFamily::Gpt4),
+        ];
+        let models = builtin
+            .iter()
+            .map(|&(name, id, weight_scaled, family)| {
+                let spec = ModelSpec {
+                    id,
+                    weight_scaled,
+                    family,
+                };
+                (name.to_string(), spec)
+            })
+            .collect();
+        Self { models }
+    }
+
+    /// Registry of `models`, once every weight is in range and no id is
+    /// used twice
+    pub fn from_models(
+        models: impl IntoIterator<Item = (String, ModelSpec)>,
+    ) -> Result<Self, ShieldError> {
+        let mut registry = Self::new();
+        for (name, spec) in models {
+            if registry.models.contains_key(&name) {
+                return Err(invalid(format!("model {} is listed twice", name)));
+            }
+            registry.insert(name, spec)?;
+        }
+        Ok(registry)
+    }
+
+    /// Add a model, replacing any with the same name, once its weight is
+    /// in range and no other model has its id; returns the replaced spec
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        spec: ModelSpec,
+    ) -> Result<Option<ModelSpec>, ShieldError> {
+        let name = name.into();
+        if !(MIN_MODEL_WEIGHT..=MAX_MODEL_WEIGHT).contains(&spec.weight_scaled) {
+            return Err(invalid(format!(
+                "{}.weight_scaled = {} is outside {}..={}",
+                name, spec.weight_scaled, MIN_MODEL_WEIGHT, MAX_MODEL_WEIGHT
+            )));
+        }
+        if let Some(other) = self.name_of(spec.id).filter(|&other| other != name) {
+            return Err(invalid(format!(
+                "{} and {} share id {}",
+                other, name, spec.id
+            )));
+        }
+        Ok(self.models.insert(name, spec))
+    }
+
+    /// The named model
+    pub fn get(&self, name: &str) -> Option<&ModelSpec> {
+        self.models.get(name)
+    }
+
+    /// Name of the model with `id`
+    pub fn name_of(&self, id: u64) -> Option<&str> {
+        self.models
+            .iter()
+            .find(|(_, spec)| spec.id == id)
+            .map(|(name, _)| name.as_str())
+    }
+
+    fn by_id(&self, id: u64) -> Option<&ModelSpec> {
+        self.models.values().find(|spec| spec.id == id)
+    }
+
+    /// Spec of the model with `id`; an unlisted id weighs
+    /// [`DEFAULT_MODEL_WEIGHT`] in [`ModelFamily::Unknown`]
+    pub fn spec(&self, id: u64) -> ModelSpec {
+        self.by_id(id).copied().unwrap_or(ModelSpec {
+            id,
+            weight_scaled: DEFAULT_MODEL_WEIGHT,
+            family: ModelFamily::Unknown,
+        })
+    }
+
+    /// Vote weight of the model with `id`, scaled by 100: between
+    /// [`MIN_MODEL_WEIGHT`] and [`MAX_MODEL_WEIGHT`]
+    /// (`model_weights_bounded`)
+    pub fn weight(&self, id: u64) -> u64 {
+        self.by_id(id)
+            .map_or(DEFAULT_MODEL_WEIGHT, |spec| spec.weight_scaled)
+    }
+
+    /// Family of the model with `id`
+    pub fn family(&self, id: u64) -> ModelFamily {
+        self.by_id(id)
+            .map_or(ModelFamily::Unknown, |spec| spec.family)
+    }
+
+    pub fn len(&self) -> usize {
+        self.models.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty()
+    }
+
+    /// Models and their specs, in name order
+    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, String, ModelSpec> {
+        self.models.iter()
+    }
+}
+
+fn invalid(message: String) -> ShieldError {
+    ShieldError::ParseError {
+        context: "models".to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(id: u64, weight_scaled: u64, family: ModelFamily) -> ModelSpec {
+        ModelSpec {
+            id,
+            weight_scaled,
+            family,
+        }
+    }
+
+    #[test]
+    fn test_builtin_matches_model_weight() {
+        // model_weight in trust_bounds.rs
+        let model_weight = |id| match id {
+            0 => 180,
+            1 => 170,
+            2 | 3 => 150,
+            4 => 130,
+            _ => 100,
+        };
+        let registry = ModelRegistry::builtin();
+        assert_eq!(registry.len(), 5);
+        for id in 0..10 {
+            assert_eq!(registry.weight(id), model_weight(id), "id {}", id);
+            assert_eq!(registry.family(id), ModelFamily::of(id), "id {}", id);
+        }
+        assert_eq!(registry.get("gpt-4o").map(|m| m.id), Some(2));
+        assert_eq!(registry.name_of(1), Some("nvidia_nemotron_70b"));
+        assert_eq!(registry.name_of(5), None);
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_weights() {
+        let mut registry = ModelRegistry::builtin();
+        for weight in [0, 99, 201, u64::MAX] {
+            let err = registry
+                .insert("llama-3-70b", spec(6, weight, ModelFamily::Llama))
+                .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "models: llama-3-70b.weight_scaled = {} is outside 100..=200",
+                    weight
+                )
+            );
+        }
+        assert_eq!(registry, ModelRegistry::builtin());
+
+        for weight in [100, 200] {
+            registry
+                .insert("llama-3-70b", spec(6, weight, ModelFamily::Llama))
+                .unwrap();
+            assert_eq!(registry.weight(6), weight);
+        }
+    }
+
+    #[test]
+    fn test_rejects_shared_ids() {
+        let mut registry = ModelRegistry::builtin();
+        let err = registry
+            .insert("claude-sonnet", spec(2, 160, ModelFamily::Claude))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "models: gpt-4o and claude-sonnet share id 2"
+        );
+
+        // Re-registering a model under its own id replaces it
+        let old = registry
+            .insert("gpt-4o", spec(2, 140, ModelFamily::Gpt4))
+            .unwrap();
+        assert_eq!(old.map(|m| m.weight_scaled), Some(150));
+        assert_eq!(registry.weight(2), 140);
+
+        let twice = vec![
+            ("a".to_string(), spec(7, 100, ModelFamily::Unknown)),
+            ("a".to_string(), spec(8, 100, ModelFamily::Unknown)),
+        ];
+        let err = ModelRegistry::from_models(twice).unwrap_err();
+        assert_eq!(err.to_string(), "models: model a is listed twice");
+    }
+
+    #[test]
+    fn test_unlisted_ids_weigh_the_default() {
+        let registry = ModelRegistry::new();
+        assert!(registry.is_empty());
+        assert_eq!(registry.weight(0), DEFAULT_MODEL_WEIGHT);
+        assert_eq!(registry.family(0), ModelFamily::Unknown);
+        assert_eq!(
+            registry.spec(0),
+            spec(0, DEFAULT_MODEL_WEIGHT, ModelFamily::Unknown)
+        );
+        assert_eq!(
+            ModelRegistry::builtin().spec(1),
+            spec(1, 170, ModelFamily::Nemotron)
+        );
+    }
+}
@@ -0,0 +1,326 @@
+//! # Status Notifications
+//!
+//! Publishes a finished run, and how it compares to a baseline, to
+//! wherever a team wants to hear about it. Sinks implement
+//! [`SummarySink`]; the built-in ones write to stdout, a file, or POST to a
+//! webhook URL (`webhook` feature).
+//!
+//! Every sink sends the same JSON [`payload`]. Its `text` field is a
+//! one-message rendering, so chat webhooks that expect `{"text": ...}`
+//! (e.g. Slack incoming webhooks) work without an adapter:
+//!
+//! ```json
+//! {
+//!   "text": "Verification regressed (2 regressions, overall: failed)\nREGRESSION ...",
+//!   "overall": "failed",
+//!   "run_id": "5f0c...",
+//!   "regressions": [{ "module": "trust_bounds", "theorem": null, ... }],
+//!   "summary": { "schema_version": 2, ... }
+//! }
+//! ```
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fs;
+use std::path::PathBuf;
+#[cfg(feature = "webhook")]
+use std::time::Duration;
+
+use crate::diff::SummaryDiff;
+use crate::error::ShieldError;
+use crate::VerificationSummary;
+
+/// Default webhook request timeout
+#[cfg(feature = "webhook")]
+pub const DEFAULT_WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Destination for run notifications
+pub trait SummarySink {
+    /// Publish `summary`, with its comparison to the baseline if there is one
+    fn publish(
+        &self,
+        summary: &VerificationSummary,
+        diff: Option<&SummaryDiff>,
+    ) -> Result<(), ShieldError>;
+}
+
+/// Whether a run is worth a notification: it regressed, or `always`
+pub fn should_publish(diff: Option<&SummaryDiff>, always: bool) -> bool {
+    always || diff.is_some_and(SummaryDiff::has_regressions)
+}
+
+/// Human-readable message: a headline, then the diff lines
+pub fn message(summary: &VerificationSummary, diff: Option<&SummaryDiff>) -> String {
+    let overall = summary.overall();
+    let mut text = match diff {
+        Some(diff) if diff.has_regressions() => format!(
+            "Verification regressed ({} regression{}, overall: {})",
+            diff.regressions.len(),
+            if diff.regressions.len() == 1 { "" } else { "s" },
+            overall
+        ),
+        _ => format!("Verification finished (overall: {})", overall),
+    };
+    if let Some(diff) = diff.filter(|diff| !diff.is_empty()) {
+        text.push('\n');
+        text.push_str(diff.to_string().trim_end());
+    }
+    text
+}
+
+/// JSON document every built-in sink publishes
+pub fn payload(summary: &VerificationSummary, diff: Option<&SummaryDiff>) -> serde_json::Value {
+    serde_json::json!({
+        "text": message(summary, diff),
+        "overall": summary.overall(),
+        "run_id": summary.run_id,
+        "regressions": diff.map(|diff| diff.regressions.as_slice()).unwrap_or_default(),
+        "summary": summary,
+    })
+}
+
+/// Sink for a `--notify` target: an `http://` or `https://` URL posts to a
+/// webhook, `-` prints to stdout, anything else is a file path
+pub fn sink_for(target: &str) -> Result<Box<dyn SummarySink>, ShieldError> {
+    if target == "-" {
+        return Ok(Box::new(StdoutSink));
+    }
+    if target.starts_with("http://") || target.starts_with("https://") {
+        return webhook_sink(target);
+    }
+    Ok(Box::new(FileSink::new(target)))
+}
+
+#[cfg(feature = "webhook")]
+fn webhook_sink(url: &str) -> Result<Box<dyn SummarySink>, ShieldError> {
+    Ok(Box::new(WebhookSink::new(url)))
+}
+
+#[cfg(not(feature = "webhook"))]
+fn webhook_sink(url: &str) -> Result<Box<dyn SummarySink>, ShieldError> {
+    Err(ShieldError::Notify {
+        sink: url.to_string(),
+        message: "built without the `webhook` feature".to_string(),
+    })
+}
+
+/// Prints the message to stdout
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutSink;
+
+impl SummarySink for StdoutSink {
+    fn publish(
+        &self,
+        summary: &VerificationSummary,
+        diff: Option<&SummaryDiff>,
+    ) -> Result<(), ShieldError> {
+        println!("{}", message(summary, diff));
+        Ok(())
+    }
+}
+
+/// Writes the JSON payload to a file, replacing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSink {
+    pub path: PathBuf,
+}
+
+impl FileSink {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl SummarySink for FileSink {
+    fn publish(
+        &self,
+        summary: &VerificationSummary,
+        diff: Option<&SummaryDiff>,
+    ) -> Result<(), ShieldError> {
+        let json = serde_json::to_string_pretty(&payload(summary, diff))?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+/// POSTs the JSON payload to a URL
+#[cfg(feature = "webhook")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookSink {
+    pub url: String,
+    pub timeout: Duration,
+}
+
+#[cfg(feature = "webhook")]
+impl WebhookSink {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            timeout: DEFAULT_WEBHOOK_TIMEOUT,
+        }
+    }
+
+    fn error(&self, message: impl ToString) -> ShieldError {
+        ShieldError::Notify {
+            sink: self.url.clone(),
+            message: message.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "webhook")]
+impl SummarySink for WebhookSink {
+    fn publish(
+        &self,
+        summary: &VerificationSummary,
+        diff: Option<&SummaryDiff>,
+    ) -> Result<(), ShieldError> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|e| self.error(e))?;
+        let response = client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(payload(summary, diff).to_string())
+            .send()
+            .map_err(|e| self.error(e))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(self.error(format!("server responded {}", status)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VerificationStatus;
+
+    fn summaries() -> (VerificationSummary, VerificationSummary) {
+        let mut builder = VerificationSummary::builder();
+        builder
+            .module("trust_bounds")
+            .status(VerificationStatus::Verified)
+            .theorem("trust_score_bounded", VerificationStatus::Verified, None);
+        builder.unit_tests(VerificationStatus::Verified, None);
+        let baseline = builder.build().unwrap();
+
+        let mut current = baseline.clone();
+        current.module_mut("trust_bounds").unwrap().theorems[0].status = VerificationStatus::Failed;
+        (baseline, current)
+    }
+
+    #[test]
+    fn test_publish_only_on_regression() {
+        let (baseline, current) = summaries();
+        let regressed = current.compare(&baseline);
+        let unchanged = baseline.compare(&baseline);
+
+        assert!(should_publish(Some(&regressed), false));
+        assert!(!should_publish(Some(&unchanged), false));
+        assert!(!should_publish(None, false));
+        assert!(should_publish(None, true));
+        assert!(should_publish(Some(&unchanged), true));
+    }
+
+    #[test]
+    fn test_payload_and_file_sink() {
+        let (baseline, current) = summaries();
+        let diff = current.compare(&baseline);
+        let path = std::env::temp_dir().join(format!("aevion-notify-{}.json", std::process::id()));
+
+        sink_for(path.to_str().unwrap())
+            .unwrap()
+            .publish(&current, Some(&diff))
+            .unwrap();
+        let written: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(written, payload(&current, Some(&diff)));
+        assert_eq!(written["overall"], "degraded");
+        assert_eq!(written["regressions"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            written["text"],
+            "Verification regressed (2 regressions, overall: degraded)\n\
+             REGRESSION trust_bounds: verified -> failed\n\
+             REGRESSION trust_bounds::trust_score_bounded: verified -> failed"
+        );
+        assert_eq!(
+            VerificationSummary::migrate(written["summary"].clone()).unwrap(),
+            current
+        );
+        assert_eq!(
+            message(&baseline, None),
+            "Verification finished (overall: verified)"
+        );
+    }
+
+    #[cfg(not(feature = "webhook"))]
+    #[test]
+    fn test_webhook_requires_feature() {
+        let err = sink_for("https://hooks.example.com/x").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "notify https://hooks.example.com/x: built without the `webhook` feature"
+        );
+    }
+
+    /// Serve one HTTP request with `status`, returning the request body
+    #[cfg(feature = "webhook")]
+    fn mock_server(status: &'static str) -> (String, std::thread::JoinHandle<String>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            String::from_utf8(body).unwrap()
+        });
+        (url, server)
+    }
+
+    #[cfg(feature = "webhook")]
+    #[test]
+    fn test_webhook_posts_payload() {
+        let (baseline, current) = summaries();
+        let diff = current.compare(&baseline);
+
+        let (url, server) = mock_server("200 OK");
+        sink_for(&url)
+            .unwrap()
+            .publish(&current, Some(&diff))
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_str(&server.join().unwrap()).unwrap();
+        assert_eq!(body, payload(&current, Some(&diff)));
+
+        let (url, server) = mock_server("500 Internal Server Error");
+        let err = WebhookSink::new(&url).publish(&current, None).unwrap_err();
+        server.join().unwrap();
+        assert_eq!(
+            err.to_string(),
+            format!("notify {}: server responded 500 Internal Server Error", url)
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! # PBFT Round Tracking
+//!
+//! Executable counterpart of the PBFT quorum theorems in
+//! `byzantine_consensus.rs`. A [`PbftRound`] is one replica's view of a
+//! round of n = 3f + 1 nodes: it records prepare and commit messages by
+//! node id, counting each node once, and reports the round prepared after
+//! `prepare_quorum(f)` = 2f distinct prepares and committed after
+//! `commit_quorum(f)` = 2f + 1 distinct commits. A committed round has
+//! commits from at least f + 1 honest nodes
+//! (`committed_has_honest_commits`).
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Prepare quorum for `f` faults: 2f (`prepare_quorum`)
+pub fn prepare_quorum(f: u64) -> u64 {
+    2 * f
+}
+
+/// Commit quorum for `f` faults: 2f + 1 (`commit_quorum`)
+pub fn commit_quorum(f: u64) -> u64 {
+    2 * f + 1
+}
+
+/// Invalid round size or message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PbftError {
+    /// PBFT needs exactly n = 3f + 1 nodes
+    InvalidSize { n: u64, f: u64 },
+    /// A message from a node id outside `0..n`
+    UnknownNode { node_id: u64, n: u64 },
+}
+
+impl fmt::Display for PbftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PbftError::InvalidSize { n, f: faults } => write!(
+                f,
+                "{} nodes cannot tolerate {} faults (PBFT needs n = 3f + 1)",
+                n, faults
+            ),
+            PbftError::UnknownNode { node_id, n } => {
+                write!(f, "node {} is not one of the {} nodes", node_id, n)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PbftError {}
+
+/// One replica's view of a PBFT round (`PbftRound` in the specification)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PbftRound {
+    n: u64,
+    f: u64,
+    prepares: BTreeSet<u64>,
+    commits: BTreeSet<u64>,
+}
+
+impl PbftRound {
+    /// Round of `n` nodes tolerating `f` faults; `n` must be `3f + 1`
+    pub fn new(n: u64, f: u64) -> Result<Self, PbftError> {
+        if f.checked_mul(3).and_then(|x| x.checked_add(1)) != Some(n) {
+            return Err(PbftError::InvalidSize { n, f });
+        }
+        Ok(Self {
+            n,
+            f,
+            prepares: BTreeSet::new(),
+            commits: BTreeSet::new(),
+        })
+    }
+
+    /// Number of nodes
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Number of tolerated faults
+    pub fn f(&self) -> u64 {
+        self.f
+    }
+
+    /// Record a prepare from `node_id`; false if the node already prepared
+    pub fn record_prepare(&mut self, node_id: u64) -> Result<bool, PbftError> {
+        self.check_node(node_id)?;
+        Ok(self.prepares.insert(node_id))
+    }
+
+    /// Record a commit from `node_id`; false if the node already committed
+    pub fn record_commit(&mut self, node_id: u64) -> Result<bool, PbftError> {
+        self.check_node(node_id)?;
+        Ok(self.commits.insert(node_id))
+    }
+
+    /// Distinct nodes that sent a prepare
+    pub fn prepare_count(&self) -> u64 {
+        self.prepares.len() as u64
+    }
+
+    /// Distinct nodes that sent a commit
+    pub fn commit_count(&self) -> u64 {
+        self.commits.len() as u64
+    }
+
+    /// Prepares from at least [`prepare_quorum`] distinct nodes
+    pub fn prepared(&self) -> bool {
+        self.prepare_count() >= prepare_quorum(self.f)
+    }
+
+    /// Commits from at least [`commit_quorum`] distinct nodes
+    pub fn committed(&self) -> bool {
+        self.commit_count() >= commit_quorum(self.f)
+    }
+
+    fn check_node(&self, node_id: u64) -> Result<(), PbftError> {
+        if node_id < self.n {
+            Ok(())
+        } else {
+            Err(PbftError::UnknownNode { node_id, n: self.n })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::masks;
+
+    /// Drive a round with `f` faults: every node prepares and commits in
+    /// id order, and each phase fires exactly at its quorum
+    fn full_round(f: u64) {
+        let n = 3 * f + 1;
+        let mut round = PbftRound::new(n, f).unwrap();
+        for node_id in 0..n {
+            assert_eq!(round.prepared(), node_id >= prepare_quorum(f));
+            assert!(round.record_prepare(node_id).unwrap());
+        }
+        assert!(round.prepared());
+        assert_eq!(round.prepare_count(), n);
+
+        for node_id in 0..n {
+            assert_eq!(round.committed(), node_id >= commit_quorum(f));
+            assert!(round.record_commit(node_id).unwrap());
+        }
+        assert!(round.committed());
+    }
+
+    #[test]
+    fn test_full_rounds() {
+        full_round(1);
+        full_round(2);
+        full_round(0);
+    }
+
+    #[test]
+    fn test_duplicates_count_once() {
+        // f = 2: 7 nodes, commit quorum 5
+        let mut round = PbftRound::new(7, 2).unwrap();
+        for node_id in [0, 1, 2, 3] {
+            assert!(round.record_commit(node_id).unwrap());
+        }
+        // A Byzantine node repeating its commit cannot complete the quorum
+        for _ in 0..10 {
+            assert!(!round.record_commit(3).unwrap());
+        }
+        assert_eq!(round.commit_count(), 4);
+        assert!(!round.committed());
+        assert!(round.record_commit(6).unwrap());
+        assert!(round.committed());
+
+        // f = 1: 4 nodes, prepare quorum 2
+        let mut round = PbftRound::new(4, 1).unwrap();
+        assert!(round.record_prepare(1).unwrap());
+        assert!(!round.record_prepare(1).unwrap());
+        assert!(!round.prepared());
+        assert!(round.record_prepare(0).unwrap());
+        assert!(round.prepared());
+        // Prepares and commits are tracked separately
+        assert_eq!(round.commit_count(), 0);
+    }
+
+    #[test]
+    fn test_out_of_range_nodes_are_rejected() {
+        let mut round = PbftRound::new(4, 1).unwrap();
+        let err = round.record_commit(4).unwrap_err();
+        assert_eq!(err, PbftError::UnknownNode { node_id: 4, n: 4 });
+        assert_eq!(err.to_string(), "node 4 is not one of the 4 nodes");
+        assert_eq!(
+            round.record_prepare(u64::MAX),
+            Err(PbftError::UnknownNode {
+                node_id: u64::MAX,
+                n: 4
+            })
+        );
+        assert_eq!(round.commit_count(), 0);
+        assert_eq!(round.prepare_count(), 0);
+    }
+
+    #[test]
+    fn test_round_size_is_validated() {
+        assert!(PbftRound::new(4, 1).is_ok());
+        assert!(PbftRound::new(7, 2).is_ok());
+        for (n, f) in [(3, 1), (5, 1), (6, 2), (0, 0), (u64::MAX, u64::MAX / 3 + 1)] {
+            assert_eq!(PbftRound::new(n, f), Err(PbftError::InvalidSize { n, f }));
+        }
+        assert_eq!(
+            PbftError::InvalidSize { n: 3, f: 1 }.to_string(),
+            "3 nodes cannot tolerate 1 faults (PBFT needs n = 3f + 1)"
+        );
+    }
+
+    #[test]
+    fn test_deployment_quorums() {
+        // pbft_quorums_n7_f2 and pbft_quorums_n10_f3: any two commit
+        // quorums share f + 1 nodes, and wherever the f Byzantine nodes
+        // sit, every commit quorum holds f + 1 honest ones
+        for (n, f) in [(7u32, 2u32), (10, 3)] {
+            let q = commit_quorum(u64::from(f)) as u32;
+            let quorums: Vec<u32> = masks(n, q).collect();
+            for a in &quorums {
+                for b in &quorums {
+                    assert!((a & b).count_ones() > f, "{:b} and {:b}", a, b);
+                }
+            }
+            for byzantine in masks(n, f) {
+                for quorum in &quorums {
+                    assert!((quorum & !byzantine).count_ones() > f);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_committed_has_honest_commits() {
+        // committed_has_honest_commits: whichever f nodes are Byzantine, a
+        // committed round holds commits from at least f + 1 honest nodes
+        for f in 1..=3u64 {
+            let n = 3 * f + 1;
+            let mut round = PbftRound::new(n, f).unwrap();
+            for node_id in 0..commit_quorum(f) {
+                round.record_commit(node_id).unwrap();
+            }
+            assert!(round.committed());
+            for first_byzantine in 0..=n - f {
+                let honest = round
+                    .commits
+                    .iter()
+                    .filter(|id| !(first_byzantine..first_byzantine + f).contains(*id))
+                    .count() as u64;
+                assert!(honest > f, "f = {}, byzantine from {}", f, first_byzantine);
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! # Verified Properties
+//!
+//! The headline properties P1-P6, each backed by theorems in one proof
+//! module. A property's status is rolled up from those theorems in a
+//! [`VerificationSummary`].
+//!
+//! Where a property has a concrete, decidable form it can also be checked
+//! on an instance with [`Property::check`], using the same thresholds as the
+//! proof specifications (`f < n/3`, `k = 2.5`, trust in `[0, 1]`, two
+//! thirds agreement). The Ed25519 and Merkle properties rest on cryptographic
+//! axioms and have no executable check.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use crate::{
+    consensus, VerificationStatus, VerificationSummary, MODULE_BYZANTINE_CONSENSUS,
+    MODULE_ED25519_CONTRACTS, MODULE_TRUST_BOUNDS, MODULE_VARIANCE_HALT,
+};
+
+/// Halt threshold on the variance ratio: `k^2` with `k = 2.5`
+/// (`halt_threshold_scaled` in `variance_halt.rs`)
+pub const HALT_VARIANCE_RATIO: f64 = 6.25;
+
+/// Minimum agreement before a constitutional halt (`CONSENSUS_THRESHOLD`
+/// in `byzantine_consensus.rs`)
+pub const MIN_AGREEMENT: f64 = 2.0 / 3.0;
+
+/// A verified property and the theorems that establish it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Property {
+    /// Short id (`P1`..`P6`)
+    pub id: &'static str,
+    pub name: &'static str,
+    /// Informal statement
+    pub statement: &'static str,
+    /// Proof module holding the theorems
+    pub module: &'static str,
+    /// Theorems in `module` that establish the property
+    pub theorems: &'static [&'static str],
+}
+
+/// Concrete instance for [`Property::check`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PropertyInput<'a> {
+    /// `n` agents of which `f` are Byzantine (P1)
+    Faults { n: u64, f: u64 },
+    /// Agent outputs and the baseline variance of honest outputs (P2)
+    Variance { samples: &'a [f64], baseline: f64 },
+    /// Trust scores (P3)
+    TrustScores(&'a [f64]),
+    /// Agreement fraction and variance relative to baseline (P6)
+    Agreement { agreement: f64, variance_ratio: f64 },
+}
+
+pub const P1: Property = Property {
+    id: "P1",
+    name: "Byzantine Tolerance",
+    statement: "f < n/3 -> P(correct) >= 0.83",
+    module: MODULE_BYZANTINE_CONSENSUS,
+    theorems: &[
+        "byzantine_safety",
+        "quorum_intersection",
+        "quorum_honest_count",
+    ],
+};
+
+pub const P2: Property = Property {
+    id: "P2",
+    name: "Variance Halt",
+    statement: "sigma > 2.5*baseline -> HALT",
+    module: MODULE_VARIANCE_HALT,
+    theorems: &["variance_halt_correctness", "variance_non_negative"],
+};
+
+pub const P3: Property = Property {
+    id: "P3",
+    name: "Trust Bounds",
+    statement: "forall t: 0 <= t <= 1",
+    module: MODULE_TRUST_BOUNDS,
+    theorems: &[
+        "ema_preserves_bounds",
+        "clamp_guarantees_bounds",
+        "decay_preserves_bounds",
+        "boost_preserves_bounds",
+        "update_sequence_preserves_bounds",
+    ],
+};
+
+pub const P4: Property = Property {
+    id: "P4",
+    name: "Ed25519 Non-Malleability",
+    statement: "verify(m,s1) & verify(m,s2) -> s1=s2",
+    module: MODULE_ED25519_CONTRACTS,
+    theorems: &["signature_uniqueness"],
+};
+
+pub const P5: Property = Property {
+    id: "P5",
+    name: "Merkle Soundness",
+    statement: "verify_proof(leaf,path,root) -> leaf in tree",
+    module: MODULE_ED25519_CONTRACTS,
+    theorems: &["merkle_path_length_bounded"],
+};
+
+pub const P6: Property = Property {
+    id: "P6",
+    name: "Constitutional Halt",
+    statement: "agreement < 2/3 -> HALT",
+    module: MODULE_BYZANTINE_CONSENSUS,
+    theorems: &[
+        "constitutional_halt_correctness",
+        "halt_safety",
+        "halt_liveness",
+    ],
+};
+
+/// Every property, in id order
+pub const PROPERTIES: [Property; 6] = [P1, P2, P3, P4, P5, P6];
+
+/// Look up a property by id (case-insensitive)
+pub fn find(id: &str) -> Option<&'static Property> {
+    PROPERTIES.iter().find(|p| p.id.eq_ignore_ascii_case(id))
+}
+
+impl Property {
+    /// The most severe status among the backing theorems. Theorems absent
+    /// from the summary count as `NotRun`.
+    pub fn status(&self, summary: &VerificationSummary) -> VerificationStatus {
+        let report = summary.module(self.module);
+        VerificationStatus::worst(self.theorems.iter().map(|theorem| {
+            report
+                .and_then(|r| r.theorems.iter().find(|t| t.name == *theorem))
+                .map(|t| t.status)
+                .unwrap_or(VerificationStatus::NotRun)
+        }))
+    }
+
+    /// `module::theorem` for each backing theorem
+    pub fn evidence(&self) -> Vec<String> {
+        self.theorems
+            .iter()
+            .map(|theorem| format!("{}::{}", self.module, theorem))
+            .collect()
+    }
+
+    /// Whether [`check`](Self::check) can evaluate this property
+    pub fn is_executable(&self) -> bool {
+        matches!(self.id, "P1" | "P2" | "P3" | "P6")
+    }
+
+    /// Evaluate the property's predicate on a concrete instance:
+    ///
+    /// - P1: the fault bound `3f < n` holds
+    /// - P2: the sample's variance exceeds `2.5^2` times the baseline, so
+    ///   the halt must fire
+    /// - P3: every trust score is within `[0, 1]`
+    /// - P6: agreement is below two thirds or variance above the halt ratio, so
+    ///   the constitutional halt must fire
+    ///
+    /// Returns false for properties without an executable form and for
+    /// inputs meant for a different property.
+    pub fn check(&self, input: &PropertyInput<'_>) -> bool {
+        match (self.id, input) {
+            ("P1", PropertyInput::Faults { n, f }) => consensus::byzantine_safe(*n, *f),
+            ("P2", PropertyInput::Variance { samples, baseline }) => {
+                variance(samples) > HALT_VARIANCE_RATIO * baseline
+            }
+            ("P3", PropertyInput::TrustScores(scores)) => {
+                scores.iter().all(|t| (0.0..=1.0).contains(t))
+            }
+            (
+                "P6",
+                PropertyInput::Agreement {
+                    agreement,
+                    variance_ratio,
+                },
+            ) => *agreement < MIN_AGREEMENT || *variance_ratio > HALT_VARIANCE_RATIO,
+            _ => false,
+        }
+    }
+}
+
+/// Population variance (0 for an empty sample)
+fn variance(samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source_scan::ProofInventory;
+    use crate::{ModuleReport, Severity, TheoremResult};
+    use std::path::Path;
+
+    fn summary_with(module: &str, theorems: &[(&str, VerificationStatus)]) -> VerificationSummary {
+        let mut summary = VerificationSummary::new();
+        summary.insert_module(ModuleReport {
+            status: VerificationStatus::Verified,
+            theorems: theorems
+                .iter()
+                .map(|(name, status)| TheoremResult {
+                    name: name.to_string(),
+                    status: *status,
+                    duration: None,
+                    severity: Severity::Standard,
+                })
+                .collect(),
+            ..ModuleReport::new(module)
+        });
+        summary
+    }
+
+    #[test]
+    fn test_backing_theorems_exist() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        for property in PROPERTIES {
+            let inventory =
+                ProofInventory::scan_file(&dir.join(format!("{}.rs", property.module))).unwrap();
+            for theorem in property.theorems {
+                assert!(
+                    inventory.theorems.iter().any(|t| t == theorem),
+                    "{}: {} not found",
+                    property.id,
+                    theorem
+                );
+            }
+        }
+        assert_eq!(find("p3"), Some(&P3));
+    }
+
+    #[test]
+    fn test_status_from_backing_theorems() {
+        let verified: Vec<(&str, VerificationStatus)> = P3
+            .theorems
+            .iter()
+            .map(|t| (*t, VerificationStatus::Verified))
+            .collect();
+        let summary = summary_with("trust_bounds", &verified);
+        assert_eq!(P3.status(&summary), VerificationStatus::Verified);
+        // Other modules were not run
+        assert_eq!(P1.status(&summary), VerificationStatus::NotRun);
+
+        let mut failed = verified.clone();
+        failed[2].1 = VerificationStatus::Failed;
+        assert_eq!(
+            P3.status(&summary_with("trust_bounds", &failed)),
+            VerificationStatus::Failed
+        );
+
+        // A backing theorem missing from the results is not verified
+        let summary = summary_with("trust_bounds", &verified[1..]);
+        assert_eq!(P3.status(&summary), VerificationStatus::NotRun);
+    }
+
+    #[test]
+    fn test_trust_bounds_check() {
+        assert!(P3.check(&PropertyInput::TrustScores(&[0.0, 0.5, 1.0])));
+        assert!(!P3.check(&PropertyInput::TrustScores(&[0.2, 1.01])));
+        assert!(!P3.check(&PropertyInput::TrustScores(&[-0.1])));
+        assert!(!P3.check(&PropertyInput::TrustScores(&[f64::NAN])));
+    }
+
+    #[test]
+    fn test_variance_halt_check() {
+        // Honest agents close together: no halt
+        let honest = PropertyInput::Variance {
+            samples: &[50.0, 51.0, 49.0],
+            baseline: 1.0,
+        };
+        assert!(!P2.check(&honest));
+        // Byzantine outliers: variance far above 6.25x baseline
+        let attacked = PropertyInput::Variance {
+            samples: &[50.0, 90.0, 10.0],
+            baseline: 1.0,
+        };
+        assert!(P2.check(&attacked));
+    }
+
+    #[test]
+    fn test_fault_and_agreement_checks() {
+        assert!(P1.check(&PropertyInput::Faults { n: 3, f: 0 }));
+        assert!(P1.check(&PropertyInput::Faults { n: 4, f: 1 }));
+        assert!(!P1.check(&PropertyInput::Faults { n: 3, f: 1 }));
+
+        let halt = |agreement, variance_ratio| {
+            P6.check(&PropertyInput::Agreement {
+                agreement,
+                variance_ratio,
+            })
+        };
+        assert!(!halt(0.9, 2.0));
+        assert!(halt(0.5, 2.0));
+        assert!(halt(0.9, 10.0));
+
+        // Non-executable properties and mismatched inputs
+        assert!(!P4.is_executable());
+        assert!(!P4.check(&PropertyInput::TrustScores(&[])));
+        assert!(!P1.check(&PropertyInput::TrustScores(&[0.5])));
+    }
+}
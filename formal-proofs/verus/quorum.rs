@@ -0,0 +1,425 @@
+//! # Quorum Certificates
+//!
+//! Evidence for a consensus outcome (Claim 4): the signed votes it was
+//! decided from. A [`QuorumCertificate`] is valid when every vote belongs
+//! to the certificate's round and carries a valid Ed25519 signature, no
+//! voter key appears twice, and deciding the votes again reproduces the
+//! embedded outcome (`valid_certificate` in `byzantine_consensus.rs`).
+//!
+//! A certificate also carries the [`RoundEvidence`] of its round (voters
+//! numbered by vote index, each weighing 1); verification checks that it
+//! describes the signed votes.
+//!
+//! With the `signing` feature, [`QuorumCertificate::verify`] checks
+//! signatures with `ed25519-dalek`; [`QuorumCertificate::verify_with`]
+//! takes any signature check.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::consensus::{ConsensusEngine, ConsensusOutcome};
+use crate::round::{Ballot, RoundEvidence, VoterId};
+use crate::Thresholds;
+
+/// One voter's signed vote in a consensus round
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedVote {
+    /// Ed25519 public key (hex, 32 bytes)
+    pub voter_pubkey: String,
+    pub vote: bool,
+    pub round_id: u64,
+    /// Ed25519 signature over [`signed_bytes`](Self::signed_bytes) (hex,
+    /// 64 bytes)
+    pub signature: String,
+}
+
+impl SignedVote {
+    /// Bytes covered by the signature: the round, the voter key and the vote
+    pub fn signed_bytes(&self) -> Vec<u8> {
+        format!(
+            "aevion-vote:{}:{}:{}",
+            self.round_id, self.voter_pubkey, self.vote
+        )
+        .into_bytes()
+    }
+
+    /// Voter key prefix for messages
+    fn voter(&self) -> String {
+        self.voter_pubkey.chars().take(12).collect()
+    }
+}
+
+/// A consensus outcome with the signed votes it was decided from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuorumCertificate {
+    pub round_id: u64,
+    pub outcome: ConsensusOutcome,
+    pub votes: Vec<SignedVote>,
+    /// Evidence of the decision; absent from certificates predating it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<RoundEvidence>,
+}
+
+/// Why a [`QuorumCertificate`] does not verify
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CertificateError {
+    /// The thresholds to decide with are out of range
+    InvalidThresholds { message: String },
+    /// A vote was signed for a different round (0-based index)
+    WrongRound { index: usize, round_id: u64 },
+    /// A vote's signature does not verify: forged or altered (0-based index)
+    InvalidSignature { index: usize, voter: String },
+    /// A voter key signed more than one vote (0-based index of the second)
+    DuplicateVoter { index: usize, voter: String },
+    /// The embedded outcome is not the one the votes decide
+    OutcomeMismatch {
+        claimed: ConsensusOutcome,
+        recomputed: ConsensusOutcome,
+    },
+    /// The embedded evidence does not describe the votes
+    EvidenceMismatch,
+}
+
+impl fmt::Display for CertificateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CertificateError::InvalidThresholds { message } => {
+                write!(f, "invalid thresholds: {}", message)
+            }
+            CertificateError::WrongRound { index, round_id } => {
+                write!(f, "vote {}: signed for round {}", index, round_id)
+            }
+            CertificateError::InvalidSignature { index, voter } => {
+                write!(f, "vote {} ({}): invalid signature", index, voter)
+            }
+            CertificateError::DuplicateVoter { index, voter } => {
+                write!(f, "vote {} ({}): voter already voted", index, voter)
+            }
+            CertificateError::OutcomeMismatch {
+                claimed,
+                recomputed,
+            } => write!(
+                f,
+                "certificate claims {:?} but the votes decide {:?}",
+                claimed, recomputed
+            ),
+            CertificateError::EvidenceMismatch => {
+                write!(f, "certificate evidence does not match its votes")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CertificateError {}
+
+impl QuorumCertificate {
+    /// Certificate for `votes`, with the outcome `engine` decides on them
+    /// and its evidence
+    pub fn new(engine: &ConsensusEngine, round_id: u64, votes: Vec<SignedVote>) -> Self {
+        let decision = engine.decide_with_evidence(Self::per_voter(&votes));
+        Self {
+            round_id,
+            outcome: decision.outcome,
+            votes,
+            evidence: Some(decision.evidence),
+        }
+    }
+
+    /// The votes as evidence ballots: voters by index, unit weights
+    fn per_voter(votes: &[SignedVote]) -> Vec<(VoterId, Ballot, u64)> {
+        votes
+            .iter()
+            .enumerate()
+            .map(|(index, v)| (index as VoterId, Some(v.vote), 1))
+            .collect()
+    }
+
+    /// Verify the certificate, checking signatures with `check_signature`
+    ///
+    /// Votes are checked in order and the first failure is reported; a vote
+    /// that fails several checks reports the round, then the signature,
+    /// then the duplicate. Voter keys are compared case-insensitively.
+    /// Evidence, if present, must be that of the votes at its own
+    /// `threshold_used`; the outcome is checked at `thresholds`.
+    pub fn verify_with<F>(
+        &self,
+        thresholds: &Thresholds,
+        check_signature: F,
+    ) -> Result<(), CertificateError>
+    where
+        F: Fn(&SignedVote) -> bool,
+    {
+        let engine = ConsensusEngine::new(thresholds).map_err(|err| {
+            CertificateError::InvalidThresholds {
+                message: err.to_string(),
+            }
+        })?;
+
+        let mut voters = BTreeSet::new();
+        for (index, vote) in self.votes.iter().enumerate() {
+            if vote.round_id != self.round_id {
+                return Err(CertificateError::WrongRound {
+                    index,
+                    round_id: vote.round_id,
+                });
+            }
+            if !check_signature(vote) {
+                return Err(CertificateError::InvalidSignature {
+                    index,
+                    voter: vote.voter(),
+                });
+            }
+            if !voters.insert(vote.voter_pubkey.to_ascii_lowercase()) {
+                return Err(CertificateError::DuplicateVoter {
+                    index,
+                    voter: vote.voter(),
+                });
+            }
+        }
+
+        let cast: Vec<bool> = self.votes.iter().map(|v| v.vote).collect();
+        let recomputed = engine.decide(&cast);
+        if recomputed != self.outcome {
+            return Err(CertificateError::OutcomeMismatch {
+                claimed: self.outcome,
+                recomputed,
+            });
+        }
+        if let Some(evidence) = &self.evidence {
+            let expected =
+                RoundEvidence::new(Self::per_voter(&self.votes), evidence.threshold_used);
+            if *evidence != expected {
+                return Err(CertificateError::EvidenceMismatch);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "signing")]
+impl SignedVote {
+    /// Sign `vote` for `round_id` with an Ed25519 secret key seed
+    pub fn sign(seed: &[u8; 32], round_id: u64, vote: bool) -> Self {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let key = SigningKey::from_bytes(seed);
+        let mut signed = Self {
+            voter_pubkey: crate::chain::hex_encode(key.verifying_key().as_bytes()),
+            vote,
+            round_id,
+            signature: String::new(),
+        };
+        let signature = key.sign(&signed.signed_bytes());
+        signed.signature = crate::chain::hex_encode(&signature.to_bytes());
+        signed
+    }
+
+    /// Whether the signature verifies under the voter key
+    pub fn verify_signature(&self) -> bool {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        let valid = (|| {
+            let key: [u8; 32] = crate::chain::hex_decode(&self.voter_pubkey)?
+                .try_into()
+                .ok()?;
+            let signature: [u8; 64] = crate::chain::hex_decode(&self.signature)?.try_into().ok()?;
+            let key = VerifyingKey::from_bytes(&key).ok()?;
+            Some(
+                key.verify(&self.signed_bytes(), &Signature::from_bytes(&signature))
+                    .is_ok(),
+            )
+        })();
+        valid.unwrap_or(false)
+    }
+}
+
+#[cfg(feature = "signing")]
+impl QuorumCertificate {
+    /// Verify the certificate with Ed25519 signature checks
+    pub fn verify(&self, thresholds: &Thresholds) -> Result<(), CertificateError> {
+        self.verify_with(thresholds, SignedVote::verify_signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{check, signed, test_signature};
+    use crate::{HaltReason, Threshold};
+
+    fn certificate(votes: &[bool]) -> QuorumCertificate {
+        let votes = votes
+            .iter()
+            .enumerate()
+            .map(|(i, &vote)| signed(i as u8, 7, vote))
+            .collect();
+        QuorumCertificate::new(&ConsensusEngine::default(), 7, votes)
+    }
+
+    #[test]
+    fn test_valid_certificate() {
+        let thresholds = Thresholds::default();
+        let cert = certificate(&[true, true, true, false]);
+        assert_eq!(
+            cert.outcome,
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 750
+            }
+        );
+        assert_eq!(cert.verify_with(&thresholds, check), Ok(()));
+
+        // Halts certify too
+        let cert = certificate(&[true, true, false, false]);
+        assert!(matches!(cert.outcome, ConsensusOutcome::Halted { .. }));
+        assert_eq!(cert.verify_with(&thresholds, check), Ok(()));
+
+        let json = serde_json::to_string(&cert).unwrap();
+        assert!(json.contains(r#""outcome":"halted","reason":"no_supermajority""#));
+        assert_eq!(
+            serde_json::from_str::<QuorumCertificate>(&json).unwrap(),
+            cert
+        );
+    }
+
+    #[test]
+    fn test_forged_vote_is_rejected() {
+        let mut cert = certificate(&[true, true, true, false]);
+        // Flip a signed vote: its signature no longer covers it
+        cert.votes[3].vote = true;
+        cert.outcome = ConsensusOutcome::Agreed {
+            value: true,
+            agreement_pct: 1000,
+        };
+        assert_eq!(
+            cert.verify_with(&Thresholds::default(), check),
+            Err(CertificateError::InvalidSignature {
+                index: 3,
+                voter: "03".repeat(6)
+            })
+        );
+
+        // Replaying a vote from another round
+        let mut cert = certificate(&[true, true, true]);
+        cert.votes[1] = signed(1, 6, true);
+        let err = cert.verify_with(&Thresholds::default(), check).unwrap_err();
+        assert_eq!(
+            err,
+            CertificateError::WrongRound {
+                index: 1,
+                round_id: 6
+            }
+        );
+        assert_eq!(err.to_string(), "vote 1: signed for round 6");
+    }
+
+    #[test]
+    fn test_duplicate_voter_is_rejected() {
+        let mut cert = certificate(&[true, true, false]);
+        // Voter 0 votes twice to tip the round
+        cert.votes.push(signed(0, 7, true));
+        cert.outcome = ConsensusOutcome::Agreed {
+            value: true,
+            agreement_pct: 750,
+        };
+        let err = cert.verify_with(&Thresholds::default(), check).unwrap_err();
+        assert_eq!(
+            err,
+            CertificateError::DuplicateVoter {
+                index: 3,
+                voter: "00".repeat(6)
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "vote 3 (000000000000): voter already voted"
+        );
+
+        // The same key in another case is the same voter
+        let mut cert = certificate(&[true, true, true]);
+        let mut upper = signed(0xab, 7, true);
+        upper.voter_pubkey = upper.voter_pubkey.to_ascii_uppercase();
+        upper.signature = test_signature(&upper);
+        cert.votes.extend([signed(0xab, 7, true), upper]);
+        assert!(matches!(
+            cert.verify_with(&Thresholds::default(), check),
+            Err(CertificateError::DuplicateVoter { index: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn test_evidence_must_match_votes() {
+        let thresholds = Thresholds::default();
+        let cert = certificate(&[true, true, false]);
+        let evidence = cert.evidence.clone().unwrap();
+        assert_eq!((evidence.agrees, evidence.disagrees), (2, 1));
+        assert_eq!(evidence.recheck(), Ok(cert.outcome));
+
+        // Evidence inflating the agreement
+        let mut forged = cert.clone();
+        let mut inflated = evidence.clone();
+        inflated.agreement_scaled = 1000;
+        forged.evidence = Some(inflated);
+        let err = forged.verify_with(&thresholds, check).unwrap_err();
+        assert_eq!(err, CertificateError::EvidenceMismatch);
+        assert_eq!(
+            err.to_string(),
+            "certificate evidence does not match its votes"
+        );
+
+        // Consistent evidence of other votes
+        forged.evidence = Some(certificate(&[true, true, true]).evidence.unwrap());
+        assert_eq!(
+            forged.verify_with(&thresholds, check),
+            Err(CertificateError::EvidenceMismatch)
+        );
+
+        // Certificates without evidence still verify
+        let json = serde_json::to_string(&cert).unwrap();
+        assert!(json.contains(r#""evidence":{"n":3,"agrees":2"#));
+        let mut legacy: serde_json::Value = serde_json::from_str(&json).unwrap();
+        legacy.as_object_mut().unwrap().remove("evidence");
+        let legacy: QuorumCertificate = serde_json::from_value(legacy).unwrap();
+        assert_eq!(legacy.evidence, None);
+        assert_eq!(legacy.verify_with(&thresholds, check), Ok(()));
+    }
+
+    #[test]
+    fn test_claimed_outcome_must_match() {
+        // Two of three agree at the default two-thirds threshold
+        let mut cert = certificate(&[true, true, false]);
+        cert.outcome = ConsensusOutcome::Agreed {
+            value: true,
+            agreement_pct: 666,
+        };
+        assert_eq!(cert.verify_with(&Thresholds::default(), check), Ok(()));
+
+        // but not at 670, where the claimed agreement is rejected
+        let strict = Thresholds {
+            consensus: Threshold::PerMille(670),
+            ..Thresholds::default()
+        };
+        assert_eq!(
+            cert.verify_with(&strict, check),
+            Err(CertificateError::OutcomeMismatch {
+                claimed: cert.outcome,
+                recomputed: ConsensusOutcome::Halted {
+                    reason: HaltReason::NoSupermajority { agreement_pct: 666 }
+                }
+            })
+        );
+
+        let invalid = Thresholds {
+            consensus: Threshold::PerMille(500),
+            ..Thresholds::default()
+        };
+        assert!(matches!(
+            cert.verify_with(&invalid, check),
+            Err(CertificateError::InvalidThresholds { .. })
+        ));
+    }
+}
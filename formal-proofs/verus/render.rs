@@ -0,0 +1,391 @@
+//! # Source-Anchored Error Rendering
+//!
+//! Renders parsed prover diagnostics as rustc-style annotated snippets,
+//! reading the offending lines from the proof sources.
+//!
+//! Multi-span diagnostics (e.g. an assertion plus the failing precondition
+//! site) render every location. Color is applied only when requested or when
+//! stdout is a terminal (`--color auto|always|never`).
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::diagnostics::{Diagnostic, Level, Span};
+
+// ============================================================================
+// COLOR SELECTION
+// ============================================================================
+
+/// `--color` setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to emit ANSI color codes
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Auto => std::io::stdout().is_terminal(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            other => Err(format!(
+                "invalid color choice: {} (expected auto, always, or never)",
+                other
+            )),
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+pub(crate) const RED: &str = "\x1b[1;31m";
+pub(crate) const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+pub(crate) const GREEN: &str = "\x1b[1;32m";
+
+pub(crate) fn paint(text: &str, style: &str, color: bool) -> String {
+    if color {
+        format!("{}{}{}", style, text, RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+// ============================================================================
+// SNIPPET EXTRACTION
+// ============================================================================
+
+/// A source file indexed by line
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    text: String,
+    /// Byte offset of the start of each line
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    pub fn new(text: String) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        if line_starts.last() == Some(&text.len()) && !text.is_empty() {
+            line_starts.pop();
+        }
+        Self { text, line_starts }
+    }
+
+    /// Number of lines
+    pub fn line_count(&self) -> usize {
+        if self.text.is_empty() {
+            0
+        } else {
+            self.line_starts.len()
+        }
+    }
+
+    /// Text of a 1-based line, without its line terminator (`\n` or `\r\n`)
+    pub fn line(&self, line: usize) -> Option<&str> {
+        if line == 0 || line > self.line_count() {
+            return None;
+        }
+        let start = self.line_starts[line - 1];
+        let end = self
+            .line_starts
+            .get(line)
+            .copied()
+            .unwrap_or(self.text.len());
+        let text = &self.text[start..end];
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        Some(text.strip_suffix('\r').unwrap_or(text))
+    }
+
+    /// Byte offset of a 1-based (line, character column) position.
+    ///
+    /// Columns count characters, so multi-byte UTF-8 characters advance the
+    /// column by one. Columns past the end of the line clamp to its end.
+    pub fn byte_offset(&self, line: usize, column: usize) -> Option<usize> {
+        let text = self.line(line)?;
+        let start = self.line_starts[line - 1];
+        let within = text
+            .char_indices()
+            .nth(column.saturating_sub(1))
+            .map(|(i, _)| i)
+            .unwrap_or(text.len());
+        Some(start + within)
+    }
+}
+
+/// Lazily loaded source files keyed by path
+#[derive(Debug, Default)]
+pub struct SourceCache {
+    files: HashMap<PathBuf, Option<SourceFile>>,
+    /// Directory that relative diagnostic paths are resolved against
+    base_dir: Option<PathBuf>,
+}
+
+impl SourceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve relative diagnostic paths against `dir`
+    pub fn with_base_dir(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            files: HashMap::new(),
+            base_dir: Some(dir.into()),
+        }
+    }
+
+    /// Insert an in-memory source (used by tests and for unsaved buffers)
+    pub fn insert(&mut self, path: impl Into<PathBuf>, text: String) {
+        self.files.insert(path.into(), Some(SourceFile::new(text)));
+    }
+
+    /// Load a file, caching misses so unreadable files are tried once
+    pub fn get(&mut self, path: &Path) -> Option<&SourceFile> {
+        let resolved = match &self.base_dir {
+            Some(base) if path.is_relative() => base.join(path),
+            _ => path.to_path_buf(),
+        };
+        self.files
+            .entry(path.to_path_buf())
+            .or_insert_with(|| fs::read_to_string(&resolved).ok().map(SourceFile::new))
+            .as_ref()
+    }
+}
+
+// ============================================================================
+// RENDERING
+// ============================================================================
+
+/// Render a diagnostic as an annotated snippet
+pub fn render(diagnostic: &Diagnostic, sources: &mut SourceCache, color: bool) -> String {
+    let level_style = match diagnostic.level {
+        Level::Error => RED,
+        Level::Warning => YELLOW,
+        Level::Note => GREEN,
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{}{}",
+        paint(diagnostic.level.as_str(), level_style, color),
+        paint(&format!(": {}", diagnostic.message), BOLD, color)
+    );
+
+    let width = diagnostic
+        .spans
+        .iter()
+        .map(|s| s.line.to_string().len())
+        .max()
+        .unwrap_or(1);
+    let blank_gutter = paint(&format!("{} |", " ".repeat(width)), BLUE, color);
+
+    // Primary span first, then secondary spans in output order
+    let mut spans: Vec<&Span> = diagnostic.spans.iter().filter(|s| s.primary).collect();
+    spans.extend(diagnostic.spans.iter().filter(|s| !s.primary));
+
+    for (i, span) in spans.iter().enumerate() {
+        let arrow = if i == 0 { "-->" } else { ":::" };
+        if i > 0 {
+            let _ = writeln!(out, "{}", blank_gutter);
+        }
+        let _ = writeln!(
+            out,
+            "{}{} {}:{}:{}",
+            " ".repeat(width),
+            paint(arrow, BLUE, color),
+            span.file.display(),
+            span.line,
+            span.column
+        );
+
+        let Some(text) = sources.get(&span.file).and_then(|f| f.line(span.line)) else {
+            // Source unavailable: location only
+            continue;
+        };
+
+        let marker_style = if span.primary { level_style } else { BLUE };
+        let marker = if span.primary { "^" } else { "-" };
+        let underline = format!(
+            "{}{}",
+            marker.repeat(span.len.max(1)),
+            span.label
+                .as_ref()
+                .map(|l| format!(" {}", l))
+                .unwrap_or_default()
+        );
+
+        let _ = writeln!(out, "{}", blank_gutter);
+        let _ = writeln!(
+            out,
+            "{} {}",
+            paint(
+                &format!("{:>width$} |", span.line, width = width),
+                BLUE,
+                color
+            ),
+            text
+        );
+        let _ = writeln!(
+            out,
+            "{} {}{}",
+            blank_gutter,
+            indent_for(text, span.column),
+            paint(&underline, marker_style, color)
+        );
+    }
+
+    out
+}
+
+/// Whitespace aligning a caret under a 1-based character column, preserving
+/// tabs from the source line so the marker lines up in any terminal
+fn indent_for(text: &str, column: usize) -> String {
+    text.chars()
+        .take(column.saturating_sub(1))
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics;
+
+    #[test]
+    fn test_source_file_lines() {
+        let file = SourceFile::new("first\nsecond\nthird\n".to_string());
+        assert_eq!(file.line_count(), 3);
+        assert_eq!(file.line(1), Some("first"));
+        assert_eq!(file.line(3), Some("third"));
+        assert_eq!(file.line(0), None);
+        assert_eq!(file.line(4), None);
+    }
+
+    #[test]
+    fn test_source_file_crlf() {
+        let file = SourceFile::new("alpha\r\nbeta\r\ngamma".to_string());
+        assert_eq!(file.line_count(), 3);
+        assert_eq!(file.line(1), Some("alpha"));
+        assert_eq!(file.line(2), Some("beta"));
+        assert_eq!(file.line(3), Some("gamma"));
+        // "beta" starts after "alpha\r\n"
+        assert_eq!(file.byte_offset(2, 1), Some(7));
+        assert_eq!(file.byte_offset(2, 3), Some(9));
+    }
+
+    #[test]
+    fn test_source_file_unicode() {
+        // σ (2 bytes) and ≥ (3 bytes) count as one column each
+        let file = SourceFile::new("// σ ≥ 2.5\nx".to_string());
+        assert_eq!(file.byte_offset(1, 4), Some(3));
+        assert_eq!(file.byte_offset(1, 6), Some(6));
+        assert_eq!(file.byte_offset(1, 7), Some(9));
+        // Past end of line clamps
+        assert_eq!(file.byte_offset(1, 100), Some("// σ ≥ 2.5".len()));
+        assert_eq!(file.byte_offset(2, 1), Some("// σ ≥ 2.5\n".len()));
+    }
+
+    #[test]
+    fn test_render_multi_span_plain() {
+        let output = "\
+error: precondition not satisfied
+  --> src/trust_bounds.rs:3:5
+   |
+1  |     requires x <= 1000,
+   |              --------- failed precondition
+...
+3  |     lemma(x);
+   |     ^^^^^^^^
+";
+        let diagnostic = &diagnostics::parse(output)[0];
+        let mut sources = SourceCache::new();
+        sources.insert(
+            "src/trust_bounds.rs",
+            "    requires x <= 1000,\n\n    lemma(x);\n".to_string(),
+        );
+
+        let rendered = render(diagnostic, &mut sources, false);
+        let expected = "\
+error: precondition not satisfied
+ --> src/trust_bounds.rs:3:5
+  |
+3 |     lemma(x);
+  |     ^^^^^^^^
+  |
+ ::: src/trust_bounds.rs:1:14
+  |
+1 |     requires x <= 1000,
+  |              --------- failed precondition
+";
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_render_unicode_alignment() {
+        let diagnostic = Diagnostic {
+            level: Level::Error,
+            message: "assertion failed".to_string(),
+            spans: vec![Span {
+                file: PathBuf::from("v.rs"),
+                line: 1,
+                column: 6,
+                len: 1,
+                label: None,
+                primary: true,
+            }],
+            output_range: 0..0,
+        };
+        let mut sources = SourceCache::new();
+        sources.insert("v.rs", "σ ≥ σ x\r\n".to_string());
+        let rendered = render(&diagnostic, &mut sources, false);
+        assert!(rendered.contains("1 | σ ≥ σ x\n"));
+        assert!(rendered.contains("  |      ^\n"));
+    }
+
+    #[test]
+    fn test_render_color_and_missing_source() {
+        let diagnostic = &diagnostics::parse("error: boom\n  --> missing.rs:1:1\n")[0];
+        let mut sources = SourceCache::new();
+        let colored = render(diagnostic, &mut sources, true);
+        assert!(colored.contains("\x1b[1;31merror\x1b[0m"));
+        assert!(colored.contains("missing.rs:1:1"));
+
+        let plain = render(diagnostic, &mut sources, false);
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_color_choice_parse() {
+        assert_eq!("always".parse(), Ok(ColorChoice::Always));
+        assert_eq!("never".parse(), Ok(ColorChoice::Never));
+        assert_eq!("auto".parse(), Ok(ColorChoice::Auto));
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+        assert!(ColorChoice::Always.enabled());
+        assert!(!ColorChoice::Never.enabled());
+    }
+}
@@ -0,0 +1,269 @@
+//! # Summary Rendering
+//!
+//! Human-readable output for a [`VerificationSummary`]. Each output format
+//! (console banner, markdown, HTML) implements [`RenderSummary`];
+//! [`PlainText`] is the fixed-width console format the `verify_all` runner
+//! prints.
+//!
+//! ```text
+//! ============================================================
+//! VERIFICATION SUMMARY
+//! ============================================================
+//!
+//! Module                    Status    Theorems
+//! variance_halt:            Verified  3/3
+//! trust_bounds:             Failed    1/2
+//! unit_tests:               Verified
+//! Overall:                  Failed
+//! ```
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt::Write as _;
+
+use crate::claims::ClaimRegistry;
+use crate::render::{paint, GREEN, RED, YELLOW};
+use crate::{ModuleReport, VerificationStatus, VerificationSummary};
+
+/// What to include in rendered output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderOptions {
+    /// Module durations, where recorded
+    pub durations: bool,
+    /// Axioms each module relies on
+    pub axioms: bool,
+    /// ANSI color on statuses
+    pub color: bool,
+}
+
+/// An output format for verification summaries
+pub trait RenderSummary {
+    /// Render the claim roll-up and per-module status of `summary`
+    fn render(
+        &self,
+        summary: &VerificationSummary,
+        claims: &ClaimRegistry,
+        options: &RenderOptions,
+    ) -> String;
+}
+
+/// Fixed-width console tables
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainText;
+
+const RULE: &str = "============================================================";
+
+/// Width of the label column
+const LABEL_WIDTH: usize = 26;
+
+/// Width of the status column
+const STATUS_WIDTH: usize = 10;
+
+/// Width of the theorem count column
+const COUNTS_WIDTH: usize = 10;
+
+impl PlainText {
+    fn heading(out: &mut String, title: &str) {
+        let _ = write!(out, "\n{}\n{}\n{}\n", RULE, title, RULE);
+    }
+
+    fn claims(out: &mut String, summary: &VerificationSummary, claims: &ClaimRegistry) {
+        Self::heading(out, "PATENT CLAIMS SUPPORTED");
+        let statuses = claims.evaluate(summary);
+        for requirement in claims.claims() {
+            let _ = writeln!(out, "\n{}: {}", requirement.claim, requirement.description);
+            let _ = writeln!(out, "  Evidence: {}", requirement.evidence());
+            let _ = writeln!(out, "  Status: {}", statuses[&requirement.claim]);
+        }
+    }
+
+    fn module_row(out: &mut String, name: &str, report: &ModuleReport, options: &RenderOptions) {
+        let verified = report
+            .theorems
+            .iter()
+            .filter(|t| t.status == VerificationStatus::Verified)
+            .count();
+        let mut row = format!(
+            "{:<label$}{}{:<counts$}",
+            format!("{}:", name),
+            status_cell(report.derived_status(), options.color),
+            format!("{}/{}", verified, report.theorems.len()),
+            label = LABEL_WIDTH,
+            counts = COUNTS_WIDTH,
+        );
+        if options.durations {
+            if let Some(duration) = report.duration {
+                let _ = write!(row, "{:.1}s", duration.as_secs_f64());
+            }
+        }
+        let _ = writeln!(out, "{}", row.trim_end());
+        if options.axioms && !report.axioms.is_empty() {
+            let _ = writeln!(out, "  axioms: {}", report.axioms.join(", "));
+        }
+    }
+
+    fn status_table(out: &mut String, summary: &VerificationSummary, options: &RenderOptions) {
+        Self::heading(out, "VERIFICATION SUMMARY");
+        let mut header = format!(
+            "\n{:<label$}{:<status$}{:<counts$}",
+            "Module",
+            "Status",
+            "Theorems",
+            label = LABEL_WIDTH,
+            status = STATUS_WIDTH,
+            counts = COUNTS_WIDTH,
+        );
+        if options.durations {
+            header.push_str("Duration");
+        }
+        let _ = writeln!(out, "{}", header.trim_end());
+        for (name, report) in summary.modules() {
+            Self::module_row(out, name, report, options);
+        }
+        let _ = writeln!(
+            out,
+            "{:<label$}{}",
+            "unit_tests:",
+            status_cell(summary.unit_tests, options.color).trim_end(),
+            label = LABEL_WIDTH,
+        );
+        let _ = writeln!(
+            out,
+            "{:<label$}{}",
+            "Overall:",
+            status_cell(summary.overall(), options.color).trim_end(),
+            label = LABEL_WIDTH,
+        );
+        if let (Some(run_id), Some(total)) = (&summary.run_id, summary.total_duration) {
+            let _ = writeln!(
+                out,
+                "{:<label$}{} ({:.1}s)",
+                "Run:",
+                &run_id[..run_id.len().min(16)],
+                total.as_secs_f64(),
+                label = LABEL_WIDTH,
+            );
+        }
+    }
+}
+
+impl RenderSummary for PlainText {
+    fn render(
+        &self,
+        summary: &VerificationSummary,
+        claims: &ClaimRegistry,
+        options: &RenderOptions,
+    ) -> String {
+        let mut out = String::new();
+        Self::claims(&mut out, summary, claims);
+        Self::status_table(&mut out, summary, options);
+        out
+    }
+}
+
+/// Status padded to the status column, colored after padding so escape
+/// codes don't count towards the width
+fn status_cell(status: VerificationStatus, color: bool) -> String {
+    let name = format!("{:?}", status);
+    let padding = " ".repeat(STATUS_WIDTH.saturating_sub(name.len()));
+    let style = match status {
+        VerificationStatus::Verified => GREEN,
+        VerificationStatus::Failed => RED,
+        VerificationStatus::Partial
+        | VerificationStatus::Degraded
+        | VerificationStatus::Unknown => YELLOW,
+        VerificationStatus::NotRun | VerificationStatus::Skipped => return name + &padding,
+    };
+    paint(&name, style, color) + &padding
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::claims::ClaimRequirement;
+    use crate::Claim;
+
+    fn summary() -> VerificationSummary {
+        use VerificationStatus::*;
+
+        let mut builder = VerificationSummary::builder();
+        builder
+            .module("variance_halt")
+            .status(Verified)
+            .duration(Duration::from_millis(12_400))
+            .theorem("halt_on_high_variance", Verified, None)
+            .theorem("variance_nonnegative", Verified, None);
+        builder
+            .module("trust_bounds")
+            .status(Verified)
+            .theorem("trust_score_bounded", Failed, None)
+            .theorem("trust_update_preserves_bounds", Verified, None)
+            .axiom("axiom_trust_monotone");
+        builder.module("ed25519_contracts").status(NotRun);
+        builder.unit_tests(Verified, None);
+        builder.build().unwrap()
+    }
+
+    fn claims() -> ClaimRegistry {
+        ClaimRegistry::new(vec![
+            ClaimRequirement {
+                claim: Claim::ConstitutionalHalts,
+                description: "Constitutional Halts".to_string(),
+                modules: vec!["variance_halt".to_string()],
+                theorems: vec![],
+            },
+            ClaimRequirement {
+                claim: Claim::ByzantineThreshold,
+                description: "Byzantine Threshold".to_string(),
+                modules: vec![],
+                theorems: vec!["trust_bounds::trust_score_bounded".to_string()],
+            },
+        ])
+    }
+
+    fn snapshot(name: &str) -> String {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/fixtures/render")
+            .join(name);
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn test_plain_text_snapshot() {
+        let text = PlainText.render(&summary(), &claims(), &RenderOptions::default());
+        assert_eq!(text, snapshot("plain.txt"));
+    }
+
+    #[test]
+    fn test_plain_text_with_details_snapshot() {
+        let options = RenderOptions {
+            durations: true,
+            axioms: true,
+            color: false,
+        };
+        let text = PlainText.render(&summary(), &claims(), &options);
+        assert_eq!(text, snapshot("plain_details.txt"));
+    }
+
+    #[test]
+    fn test_color_keeps_columns() {
+        let options = RenderOptions {
+            color: true,
+            ..RenderOptions::default()
+        };
+        let colored = PlainText.render(&summary(), &claims(), &options);
+        assert!(colored.contains("\x1b[1;31mFailed\x1b[0m    1/2"));
+        assert!(colored.contains("NotRun    0/0"));
+
+        let stripped = colored
+            .replace("\x1b[1;32m", "")
+            .replace("\x1b[1;31m", "")
+            .replace("\x1b[1;33m", "")
+            .replace("\x1b[0m", "");
+        assert_eq!(stripped, snapshot("plain.txt"));
+    }
+}
@@ -0,0 +1,950 @@
+//! # Consensus Rounds
+//!
+//! Executable counterpart of `try_decide` in `byzantine_consensus.rs`. A
+//! [`ConsensusRound`] collects the votes of `expected_n` voters (ids
+//! `0..expected_n`) as they arrive, one per voter, and decides as soon as
+//! the missing votes cannot change the outcome: a value whose votes alone
+//! reach the threshold of all `expected_n` voters has agreed, and once
+//! neither value could reach it with every missing vote the round halts.
+//!
+//! An early decision is the one [`ConsensusEngine::decide`] reaches on
+//! every completion of the round (`early_decision_sound`). Its
+//! `agreement_pct` counts only the votes cast so far, so the final share is
+//! at least as high.
+//!
+//! A round built [`ConsensusRound::with_registry`] admits the public keys
+//! of a [`VoterRegistry`] and nothing else: [`ConsensusRound::submit_key`]
+//! rejects keys outside it and a key's second submission, so stuffing the
+//! ballot box takes registered keys, one slot each. n is the registry size,
+//! not the votes received: [`ConsensusRound::close`] counts a missing voter
+//! toward the halt rather than shrinking the denominator
+//! (`decide_registered`), so one keyholder moves the agreement ratio by at
+//! most 1/n (`single_keyholder_bounded_influence`).
+//!
+//! A [`RoundDecision`] keeps the numbers behind an outcome, halts included:
+//! [`RoundEvidence`] records each voter's ballot and weight with the
+//! counts, the majority value's share and the threshold used.
+//! [`RoundEvidence::recheck`] decides again from the evidence alone and
+//! reproduces the outcome (`evidence_recheck_sound`).
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::consensus::{
+    agreement_ratio_scaled, has_quorum, meets_threshold, reaches, AgentId, ConsensusEngine,
+    ConsensusOutcome, Threshold,
+};
+use crate::HaltReason;
+
+/// Voter identifier within a round
+pub type VoterId = AgentId;
+
+/// A vote, or `None` for an abstention (`Ballot` in the specification)
+pub type Ballot = Option<bool>;
+
+/// State of a round collecting votes (`RoundState` in the specification)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundState {
+    /// The outcome still depends on votes yet to arrive
+    Pending,
+    /// The outcome is settled, whatever the missing votes are
+    Decided { outcome: ConsensusOutcome },
+}
+
+/// A vote the round cannot accept
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundError {
+    /// A voter id outside `0..expected_n`
+    UnknownVoter { voter: VoterId, expected_n: u64 },
+    /// The voter has already voted in this round
+    DuplicateVote { voter: VoterId },
+    /// A public key the round's registry does not hold
+    UnregisteredKey { key: String },
+    /// A public key listed twice in a registry
+    DuplicateKey { key: String },
+}
+
+/// Key prefix for messages
+fn short(key: &str) -> String {
+    key.chars().take(12).collect()
+}
+
+impl fmt::Display for RoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundError::UnknownVoter { voter, expected_n } => write!(
+                f,
+                "voter {} is not one of the {} expected voters",
+                voter, expected_n
+            ),
+            RoundError::DuplicateVote { voter } => {
+                write!(f, "voter {} has already voted", voter)
+            }
+            RoundError::UnregisteredKey { key } => {
+                write!(f, "key {} is not registered for the round", short(key))
+            }
+            RoundError::DuplicateKey { key } => {
+                write!(f, "key {} is registered twice", short(key))
+            }
+        }
+    }
+}
+
+impl std::error::Error for RoundError {}
+
+/// Public keys of the voters expected in a round; the voter holding the
+/// i-th key has id i. Keys are hex and compare case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VoterRegistry {
+    keys: Vec<String>,
+}
+
+impl VoterRegistry {
+    /// Registry of `keys`, in order
+    pub fn new<I, K>(keys: I) -> Result<Self, RoundError>
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        let mut registry = Self::default();
+        for key in keys {
+            let key = key.as_ref().to_ascii_lowercase();
+            if registry.voter(&key).is_some() {
+                return Err(RoundError::DuplicateKey { key });
+            }
+            registry.keys.push(key);
+        }
+        Ok(registry)
+    }
+
+    /// Number of registered voters
+    pub fn len(&self) -> u64 {
+        self.keys.len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Id of the voter holding `key`
+    pub fn voter(&self, key: &str) -> Option<VoterId> {
+        self.keys
+            .iter()
+            .position(|k| k.eq_ignore_ascii_case(key))
+            .map(|i| i as VoterId)
+    }
+
+    /// Key of `voter`, lowercase
+    pub fn key(&self, voter: VoterId) -> Option<&str> {
+        self.keys.get(voter as usize).map(String::as_str)
+    }
+}
+
+/// A consensus round collecting votes as they arrive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsensusRound {
+    expected_n: u64,
+    registry: Option<VoterRegistry>,
+    votes: BTreeMap<VoterId, bool>,
+}
+
+impl ConsensusRound {
+    /// Round of `expected_n` voters with ids `0..expected_n`
+    pub fn new(expected_n: u64) -> Self {
+        Self {
+            expected_n,
+            registry: None,
+            votes: BTreeMap::new(),
+        }
+    }
+
+    /// Round of the voters in `registry`, who vote by public key
+    pub fn with_registry(registry: VoterRegistry) -> Self {
+        Self {
+            expected_n: registry.len(),
+            registry: Some(registry),
+            votes: BTreeMap::new(),
+        }
+    }
+
+    /// The registry the round admits voters from, if built with one
+    pub fn registry(&self) -> Option<&VoterRegistry> {
+        self.registry.as_ref()
+    }
+
+    /// Number of voters the round waits for
+    pub fn expected_n(&self) -> u64 {
+        self.expected_n
+    }
+
+    /// Votes received so far
+    pub fn cast(&self) -> u64 {
+        self.votes.len() as u64
+    }
+
+    /// Votes still missing
+    pub fn remaining(&self) -> u64 {
+        self.expected_n - self.cast()
+    }
+
+    /// The vote `voter` cast, if any
+    pub fn vote(&self, voter: VoterId) -> Option<bool> {
+        self.votes.get(&voter).copied()
+    }
+
+    /// Record `voter`'s vote
+    pub fn submit(&mut self, voter: VoterId, vote: bool) -> Result<(), RoundError> {
+        if voter >= self.expected_n {
+            return Err(RoundError::UnknownVoter {
+                voter,
+                expected_n: self.expected_n,
+            });
+        }
+        if self.votes.contains_key(&voter) {
+            return Err(RoundError::DuplicateVote { voter });
+        }
+        self.votes.insert(voter, vote);
+        Ok(())
+    }
+
+    /// Record the vote of the voter holding `key`, returning their id; a
+    /// key outside the registry, or one that has already voted, is
+    /// rejected
+    pub fn submit_key(&mut self, key: &str, vote: bool) -> Result<VoterId, RoundError> {
+        let voter = self
+            .registry
+            .as_ref()
+            .and_then(|registry| registry.voter(key))
+            .ok_or_else(|| RoundError::UnregisteredKey {
+                key: key.to_ascii_lowercase(),
+            })?;
+        self.submit(voter, vote)?;
+        Ok(voter)
+    }
+
+    /// Decide the round as it stands (`decide_registered`): missing voters
+    /// vote for neither value but still count in n, so a round closed short
+    /// of its voters needs the threshold of all of them. A complete round
+    /// decides as [`ConsensusEngine::decide`] does.
+    pub fn close(&self, engine: &ConsensusEngine) -> ConsensusOutcome {
+        let n = self.expected_n;
+        if n == 0 {
+            return ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: 0 },
+            };
+        }
+        let agrees = self.votes.values().filter(|v| **v).count() as u64;
+        let disagrees = self.cast() - agrees;
+        // The value leading so far, false on a tie
+        let (value, supporters) = if agrees > disagrees {
+            (true, agrees)
+        } else {
+            (false, disagrees)
+        };
+        let agreement_pct = agreement_ratio_scaled(supporters, n);
+        if meets_threshold(supporters, n, engine.threshold()) {
+            ConsensusOutcome::Agreed {
+                value,
+                agreement_pct,
+            }
+        } else {
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct },
+            }
+        }
+    }
+
+    /// Decide the round if the missing votes cannot change the outcome
+    /// (`try_decide`); a complete round always decides, as
+    /// [`ConsensusEngine::decide`] does
+    pub fn try_decide(&self, engine: &ConsensusEngine) -> RoundState {
+        let n = self.expected_n;
+        if n == 0 {
+            return RoundState::Decided {
+                outcome: ConsensusOutcome::Halted {
+                    reason: HaltReason::InsufficientVoters { n: 0 },
+                },
+            };
+        }
+
+        let t = engine.threshold();
+        let agrees = self.votes.values().filter(|v| **v).count() as u64;
+        let disagrees = self.cast() - agrees;
+        let remaining = self.remaining();
+        let outcome = if meets_threshold(agrees, n, t) {
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: agreement_ratio_scaled(agrees, n),
+            }
+        } else if meets_threshold(disagrees, n, t) {
+            ConsensusOutcome::Agreed {
+                value: false,
+                agreement_pct: agreement_ratio_scaled(disagrees, n),
+            }
+        } else if !meets_threshold(agrees + remaining, n, t)
+            && !meets_threshold(disagrees + remaining, n, t)
+        {
+            // The value leading so far, false on a tie
+            let leading = if agrees > disagrees {
+                agrees
+            } else {
+                disagrees
+            };
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority {
+                    agreement_pct: agreement_ratio_scaled(leading, n),
+                },
+            }
+        } else {
+            return RoundState::Pending;
+        };
+
+        debug_assert!(
+            remaining > 0
+                || outcome == engine.decide(&self.votes.values().copied().collect::<Vec<_>>()),
+            "complete round decided {:?}",
+            outcome
+        );
+        RoundState::Decided { outcome }
+    }
+}
+
+/// The numbers behind a decision over ballots (`RoundEvidence` in the
+/// specification), for halts as well as agreements
+///
+/// Counts are of voters; the share weighs each vote cast by its voter's
+/// weight (1 for an unweighted round).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoundEvidence {
+    /// Voters in the round, abstaining or not
+    pub n: u64,
+    pub agrees: u64,
+    pub disagrees: u64,
+    pub abstains: u64,
+    /// Majority value's share of the weight cast, scaled by 1000 and
+    /// rounded down; 0 when nothing was cast
+    pub agreement_scaled: u64,
+    /// Threshold the round was decided at
+    pub threshold_used: Threshold,
+    /// Each voter's ballot and weight, in ballot order
+    pub per_voter: Vec<(VoterId, Ballot, u64)>,
+}
+
+/// An outcome with the evidence it was decided on
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoundDecision {
+    pub outcome: ConsensusOutcome,
+    pub evidence: RoundEvidence,
+}
+
+/// Why [`RoundEvidence::recheck`] rejects evidence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvidenceError {
+    /// A voter appears twice among the per-voter ballots
+    DuplicateVoter { voter: VoterId },
+    /// The counts or the share do not match the per-voter ballots
+    SummaryMismatch,
+}
+
+impl fmt::Display for EvidenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvidenceError::DuplicateVoter { voter } => {
+                write!(f, "voter {} appears twice in the evidence", voter)
+            }
+            EvidenceError::SummaryMismatch => {
+                write!(f, "evidence counts do not match its ballots")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvidenceError {}
+
+impl RoundEvidence {
+    /// Evidence of `per_voter` decided at `threshold_used`
+    /// (`round_evidence`)
+    pub fn new(per_voter: Vec<(VoterId, Ballot, u64)>, threshold_used: Threshold) -> Self {
+        let count = |ballot: Ballot| per_voter.iter().filter(|(_, b, _)| *b == ballot).count();
+        let mut evidence = Self {
+            n: per_voter.len() as u64,
+            agrees: count(Some(true)) as u64,
+            disagrees: count(Some(false)) as u64,
+            abstains: count(None) as u64,
+            agreement_scaled: 0,
+            threshold_used,
+            per_voter,
+        };
+        let (_, supporters, total) = evidence.weights();
+        // supporters <= total, so the share is at most 1000
+        if let Some(share) = (supporters * 1000).checked_div(total) {
+            evidence.agreement_scaled = share as u64;
+        }
+        evidence
+    }
+
+    /// Majority value (false on a tie), its weight, and the weight cast
+    fn weights(&self) -> (bool, u128, u128) {
+        let (mut agrees, mut total) = (0u128, 0u128);
+        for &(_, ballot, weight) in &self.per_voter {
+            if let Some(vote) = ballot {
+                total += u128::from(weight);
+                if vote {
+                    agrees += u128::from(weight);
+                }
+            }
+        }
+        let value = 2 * agrees > total;
+        (value, if value { agrees } else { total - agrees }, total)
+    }
+
+    /// The outcome the evidence implies (`recheck_evidence`): without a
+    /// quorum of voters casting a vote the round halts with
+    /// [`HaltReason::InsufficientVoters`], a cast weighing nothing with
+    /// [`HaltReason::TrustCollapse`], and otherwise the majority value is
+    /// agreed if its share reaches `threshold_used`
+    pub(crate) fn decide(&self) -> ConsensusOutcome {
+        let cast = self.agrees + self.disagrees;
+        if cast == 0 || !has_quorum(cast, self.n) {
+            return ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: cast },
+            };
+        }
+        let (value, supporters, total) = self.weights();
+        if total == 0 {
+            return ConsensusOutcome::Halted {
+                reason: HaltReason::TrustCollapse { max_trust: 0 },
+            };
+        }
+        if reaches(supporters, total, self.threshold_used) {
+            ConsensusOutcome::Agreed {
+                value,
+                agreement_pct: self.agreement_scaled,
+            }
+        } else {
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority {
+                    agreement_pct: self.agreement_scaled,
+                },
+            }
+        }
+    }
+
+    /// Decide again from the per-voter ballots alone, once the voters are
+    /// distinct and the counts and share match the ballots. Evidence from
+    /// [`ConsensusEngine::decide_with_evidence`] reproduces its outcome.
+    pub fn recheck(&self) -> Result<ConsensusOutcome, EvidenceError> {
+        let mut voters = BTreeSet::new();
+        for &(voter, _, _) in &self.per_voter {
+            if !voters.insert(voter) {
+                return Err(EvidenceError::DuplicateVoter { voter });
+            }
+        }
+        if *self != Self::new(self.per_voter.clone(), self.threshold_used) {
+            return Err(EvidenceError::SummaryMismatch);
+        }
+        Ok(self.decide())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Rng;
+    use crate::Thresholds;
+
+    /// A later decision keeps an earlier one: the same value with at least
+    /// its share, or a halt again
+    fn assert_settled(earlier: ConsensusOutcome, later: ConsensusOutcome) {
+        match (earlier, later) {
+            (
+                ConsensusOutcome::Agreed {
+                    value,
+                    agreement_pct,
+                },
+                ConsensusOutcome::Agreed {
+                    value: later_value,
+                    agreement_pct: later_pct,
+                },
+            ) => {
+                assert_eq!(value, later_value);
+                assert!(agreement_pct <= later_pct);
+            }
+            (ConsensusOutcome::Halted { .. }, ConsensusOutcome::Halted { .. }) => {}
+            (earlier, later) => panic!("decided {:?}, then {:?}", earlier, later),
+        }
+    }
+
+    fn decided(round: &ConsensusRound, engine: &ConsensusEngine) -> ConsensusOutcome {
+        match round.try_decide(engine) {
+            RoundState::Decided { outcome } => outcome,
+            RoundState::Pending => panic!("round of {:?} is pending", round.votes),
+        }
+    }
+
+    /// Unit-weight evidence for `ballots`, voters numbered in order
+    fn unit(ballots: &[Ballot]) -> Vec<(VoterId, Ballot, u64)> {
+        ballots
+            .iter()
+            .enumerate()
+            .map(|(voter, &ballot)| (voter as VoterId, ballot, 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_submit_validates_voters() {
+        let mut round = ConsensusRound::new(3);
+        assert_eq!(round.submit(0, true), Ok(()));
+        assert_eq!(
+            round.submit(0, false),
+            Err(RoundError::DuplicateVote { voter: 0 })
+        );
+        assert_eq!(round.vote(0), Some(true));
+
+        let err = round.submit(3, true).unwrap_err();
+        assert_eq!(
+            err,
+            RoundError::UnknownVoter {
+                voter: 3,
+                expected_n: 3
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "voter 3 is not one of the 3 expected voters"
+        );
+        assert_eq!(
+            RoundError::DuplicateVote { voter: 0 }.to_string(),
+            "voter 0 has already voted"
+        );
+        assert_eq!((round.cast(), round.remaining()), (1, 2));
+    }
+
+    #[test]
+    fn test_early_agreement() {
+        let engine = ConsensusEngine::default();
+        // 670 of 5 voters needs 4 votes (4000 >= 3350, 3000 < 3350)
+        let mut round = ConsensusRound::new(5);
+        for voter in 0..3 {
+            round.submit(voter, true).unwrap();
+            assert_eq!(round.try_decide(&engine), RoundState::Pending);
+        }
+        round.submit(3, true).unwrap();
+        assert_eq!(
+            decided(&round, &engine),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 800
+            }
+        );
+
+        // The last vote only raises the share
+        round.submit(4, true).unwrap();
+        assert_eq!(
+            decided(&round, &engine),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 1000
+            }
+        );
+    }
+
+    #[test]
+    fn test_early_halt() {
+        let engine = ConsensusEngine::default();
+        // Two votes each way among five voters: 3 of 5 cannot reach two
+        // thirds
+        let mut round = ConsensusRound::new(5);
+        for (voter, vote) in [(2, true), (0, false), (3, true)] {
+            round.submit(voter, vote).unwrap();
+            assert_eq!(round.try_decide(&engine), RoundState::Pending);
+        }
+        round.submit(4, false).unwrap();
+        assert_eq!(
+            decided(&round, &engine),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 400 }
+            }
+        );
+        round.submit(1, true).unwrap();
+        assert_eq!(
+            decided(&round, &engine),
+            engine.decide(&[false, true, true, true, false])
+        );
+
+        // A round without voters halts at once
+        assert_eq!(
+            decided(&ConsensusRound::new(0), &engine),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: 0 }
+            }
+        );
+    }
+
+    #[test]
+    fn test_early_decision_sound() {
+        // early_decision_sound: in whatever order the votes arrive, the
+        // first decision is the full round's, and a pending round has
+        // completions deciding differently
+        let mut rng = Rng(0x5eed_1584);
+        for _ in 0..2000 {
+            let engine = ConsensusEngine::new(&Thresholds {
+                consensus: Threshold::PerMille(501 + rng.below(500)),
+                ..Thresholds::default()
+            })
+            .unwrap();
+            let n = rng.below(30);
+            let rate = rng.below(1001);
+            let votes: Vec<bool> = (0..n).map(|_| rng.below(1000) < rate).collect();
+            let full = engine.decide(&votes);
+
+            let mut order: Vec<VoterId> = (0..n).collect();
+            for i in (1..order.len()).rev() {
+                order.swap(i, rng.below(i as u64 + 1) as usize);
+            }
+
+            let mut round = ConsensusRound::new(n);
+            let mut early = None;
+            for step in 0..=order.len() {
+                if step > 0 {
+                    let voter = order[step - 1];
+                    round.submit(voter, votes[voter as usize]).unwrap();
+                }
+                match (round.try_decide(&engine), early) {
+                    (RoundState::Decided { outcome }, None) => early = Some(outcome),
+                    // Decisions hold as more votes arrive
+                    (RoundState::Decided { outcome }, Some(first)) => {
+                        assert_settled(first, outcome)
+                    }
+                    (RoundState::Pending, Some(first)) => {
+                        panic!("{:?} reopened after deciding {:?}", round.votes, first)
+                    }
+                    (RoundState::Pending, None) => {
+                        // Missing votes could still go either way
+                        let mut all_true = votes.clone();
+                        let mut all_false = votes.clone();
+                        for &voter in &order[step..] {
+                            all_true[voter as usize] = true;
+                            all_false[voter as usize] = false;
+                        }
+                        assert_ne!(engine.decide(&all_true), engine.decide(&all_false));
+                    }
+                }
+            }
+
+            assert_settled(early.unwrap(), full);
+            assert_eq!(
+                round.try_decide(&engine),
+                RoundState::Decided { outcome: full }
+            );
+        }
+    }
+
+    /// Hex public key of voter `i`
+    fn key(i: u64) -> String {
+        format!("{:064x}", 0xab00 + i)
+    }
+
+    /// Round over the keys of `ballots.len()` voters, each voting its
+    /// ballot and staying away on `None`
+    fn registered(ballots: &[Ballot]) -> ConsensusRound {
+        let n = ballots.len() as u64;
+        let mut round = ConsensusRound::with_registry(VoterRegistry::new((0..n).map(key)).unwrap());
+        for (i, ballot) in ballots.iter().enumerate() {
+            if let Some(vote) = *ballot {
+                round.submit_key(&key(i as u64), vote).unwrap();
+            }
+        }
+        round
+    }
+
+    #[test]
+    fn test_registry_rejects_ballot_stuffing() {
+        let registry = VoterRegistry::new((0..3).map(key)).unwrap();
+        assert_eq!(registry.len(), 3);
+        assert_eq!(registry.voter(&key(2).to_uppercase()), Some(2));
+        assert_eq!(registry.key(1), Some(key(1).as_str()));
+        assert_eq!(registry.voter(&key(3)), None);
+
+        let mut round = ConsensusRound::with_registry(registry);
+        assert_eq!(round.expected_n(), 3);
+        assert_eq!(round.submit_key(&key(0), true), Ok(0));
+
+        // The same key again, in any case or by its id
+        assert_eq!(
+            round.submit_key(&key(0), true),
+            Err(RoundError::DuplicateVote { voter: 0 })
+        );
+        assert_eq!(
+            round.submit_key(&key(0).to_uppercase(), false),
+            Err(RoundError::DuplicateVote { voter: 0 })
+        );
+        assert_eq!(
+            round.submit(0, true),
+            Err(RoundError::DuplicateVote { voter: 0 })
+        );
+
+        // Keys outside the registry
+        for outsider in [key(3), "ab".repeat(32), String::new()] {
+            let err = round.submit_key(&outsider, true).unwrap_err();
+            assert_eq!(err, RoundError::UnregisteredKey { key: outsider });
+        }
+        assert_eq!(
+            RoundError::UnregisteredKey { key: key(3) }.to_string(),
+            "key 000000000000 is not registered for the round"
+        );
+
+        // Only the first vote counts
+        assert_eq!((round.cast(), round.vote(0)), (1, Some(true)));
+
+        // A registry cannot list a key twice, nor a round without one take
+        // keys
+        let err = VoterRegistry::new([key(0), key(1), key(0).to_uppercase()]).unwrap_err();
+        assert_eq!(err, RoundError::DuplicateKey { key: key(0) });
+        assert_eq!(err.to_string(), "key 000000000000 is registered twice");
+        assert_eq!(
+            ConsensusRound::new(3).submit_key(&key(0), true),
+            Err(RoundError::UnregisteredKey { key: key(0) })
+        );
+    }
+
+    #[test]
+    fn test_missing_voters_stay_in_denominator() {
+        let engine = ConsensusEngine::default();
+        // Three of five registered voters, all for true: over the votes
+        // received that is unanimous, over the registry only 60%
+        let round = registered(&[Some(true), Some(true), None, Some(true), None]);
+        assert_eq!(
+            engine.decide(&[true, true, true]),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 1000
+            }
+        );
+        assert_eq!(round.try_decide(&engine), RoundState::Pending);
+        assert_eq!(
+            round.close(&engine),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 600 }
+            }
+        );
+
+        // A fourth vote reaches 670 of all five
+        let round = registered(&[Some(true), Some(true), None, Some(true), Some(true)]);
+        assert_eq!(
+            round.close(&engine),
+            ConsensusOutcome::Agreed {
+                value: true,
+                agreement_pct: 800
+            }
+        );
+
+        // Nobody showing up halts, as does an empty registry
+        assert_eq!(
+            registered(&[None, None, None]).close(&engine),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 0 }
+            }
+        );
+        assert_eq!(
+            registered(&[]).close(&engine),
+            ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: 0 }
+            }
+        );
+    }
+
+    #[test]
+    fn test_single_keyholder_bounded_influence() {
+        // single_keyholder_bounded_influence: whatever one key does with
+        // its slot, each value's share of the registry moves by at most
+        // 1/n (plus rounding); a complete round closes as decide does, and
+        // an early decision stands at close
+        let mut rng = Rng(0x5eed_1593);
+        let share = |round: &ConsensusRound, value: bool| {
+            let count = round.votes.values().filter(|&&v| v == value).count() as u64;
+            agreement_ratio_scaled(count, round.expected_n())
+        };
+        let ballot = |rng: &mut Rng| match rng.below(3) {
+            0 => None,
+            x => Some(x == 1),
+        };
+        for _ in 0..2000 {
+            let engine = ConsensusEngine::new(&Thresholds {
+                consensus: Threshold::PerMille(501 + rng.below(500)),
+                ..Thresholds::default()
+            })
+            .unwrap();
+            let n = 1 + rng.below(12);
+            let mut ballots: Vec<Ballot> = (0..n).map(|_| ballot(&mut rng)).collect();
+            let round = registered(&ballots);
+
+            let i = rng.below(n) as usize;
+            ballots[i] = ballot(&mut rng);
+            let changed = registered(&ballots);
+            assert_eq!(changed.expected_n(), n);
+            for value in [true, false] {
+                let (before, after) = (share(&round, value), share(&changed, value));
+                assert!(before.abs_diff(after) <= 1000 / n + 1, "{:?}", ballots);
+            }
+
+            if let RoundState::Decided { outcome } = round.try_decide(&engine) {
+                assert_eq!(round.close(&engine), outcome);
+            }
+            if ballots.iter().all(Option::is_some) {
+                let votes: Vec<bool> = ballots.iter().flatten().copied().collect();
+                assert_eq!(changed.close(&engine), engine.decide(&votes));
+            }
+        }
+    }
+
+    #[test]
+    fn test_halts_keep_their_evidence() {
+        let engine = ConsensusEngine::default();
+        let ballots = [Some(true), Some(true), Some(false), Some(false), Some(true)];
+        let decision = engine.decide_with_evidence(unit(&ballots));
+        assert_eq!(
+            decision.outcome,
+            ConsensusOutcome::Halted {
+                reason: HaltReason::NoSupermajority { agreement_pct: 600 }
+            }
+        );
+        assert_eq!(
+            decision.evidence,
+            RoundEvidence {
+                n: 5,
+                agrees: 3,
+                disagrees: 2,
+                abstains: 0,
+                agreement_scaled: 600,
+                threshold_used: Threshold::TwoThirds,
+                per_voter: unit(&ballots),
+            }
+        );
+
+        // Without a quorum the halt reports only the votes cast; the
+        // evidence still has the split
+        let decision = engine.decide_with_evidence(unit(&[Some(true), None, None, Some(false)]));
+        assert_eq!(
+            decision.outcome,
+            ConsensusOutcome::Halted {
+                reason: HaltReason::InsufficientVoters { n: 2 }
+            }
+        );
+        let evidence = &decision.evidence;
+        assert_eq!(
+            (evidence.agrees, evidence.disagrees, evidence.abstains),
+            (1, 1, 2)
+        );
+        assert_eq!(evidence.agreement_scaled, 500);
+        assert_eq!(evidence.recheck(), Ok(decision.outcome));
+
+        // Votes weighing nothing
+        let decision = engine.decide_with_evidence(vec![(4, Some(true), 0), (9, Some(false), 0)]);
+        assert_eq!(
+            decision.outcome,
+            ConsensusOutcome::Halted {
+                reason: HaltReason::TrustCollapse { max_trust: 0 }
+            }
+        );
+        assert_eq!(decision.evidence.agreement_scaled, 0);
+    }
+
+    #[test]
+    fn test_recheck_rejects_altered_evidence() {
+        let engine = ConsensusEngine::default();
+        let decision = engine.decide_with_evidence(unit(&[Some(true), Some(true), Some(false)]));
+
+        // Raising the count alone no longer matches the ballots
+        let mut evidence = decision.evidence.clone();
+        evidence.agrees = 3;
+        evidence.disagrees = 0;
+        assert_eq!(evidence.recheck(), Err(EvidenceError::SummaryMismatch));
+
+        // Neither does a flipped ballot under the old counts
+        let mut evidence = decision.evidence.clone();
+        evidence.per_voter[2].1 = Some(true);
+        assert_eq!(evidence.recheck(), Err(EvidenceError::SummaryMismatch));
+
+        // A voter counted twice
+        let mut evidence = decision.evidence.clone();
+        evidence.per_voter[1].0 = 0;
+        let err = evidence.recheck().unwrap_err();
+        assert_eq!(err, EvidenceError::DuplicateVoter { voter: 0 });
+        assert_eq!(err.to_string(), "voter 0 appears twice in the evidence");
+    }
+
+    #[test]
+    fn test_evidence_recheck_sound() {
+        // evidence_recheck_sound: rechecking reproduces the outcome, which
+        // is decide_ballots' with unit weights and decide_weighted's with
+        // trust * model weight
+        let mut rng = Rng(0x5eed_1590);
+        for _ in 0..2000 {
+            let engine = ConsensusEngine::new(&Thresholds {
+                consensus: Threshold::PerMille(501 + rng.below(500)),
+                ..Thresholds::default()
+            })
+            .unwrap();
+            let n = rng.below(12);
+            let rate = rng.below(1001);
+            let abstain = rng.below(500);
+            let ballots: Vec<Ballot> = (0..n)
+                .map(|_| match rng.below(1000) {
+                    x if x < abstain => None,
+                    _ => Some(rng.below(1000) < rate),
+                })
+                .collect();
+
+            let decision = engine.decide_with_evidence(unit(&ballots));
+            assert_eq!(decision.outcome, engine.decide_ballots(&ballots));
+            assert_eq!(decision.evidence.recheck(), Ok(decision.outcome));
+            let e = &decision.evidence;
+            assert_eq!(e.agrees + e.disagrees + e.abstains, e.n);
+            match decision.outcome {
+                ConsensusOutcome::Agreed { agreement_pct, .. }
+                | ConsensusOutcome::Halted {
+                    reason: HaltReason::NoSupermajority { agreement_pct },
+                } => assert_eq!(agreement_pct, e.agreement_scaled),
+                ConsensusOutcome::Halted { .. } => {}
+            }
+
+            let weighted: Vec<(bool, u64, u64)> = (0..n)
+                .map(|_| (rng.below(1000) < rate, rng.below(1001), rng.below(6)))
+                .collect();
+            let per_voter = weighted
+                .iter()
+                .enumerate()
+                .map(|(voter, &(vote, trust, model_id))| {
+                    (
+                        voter as VoterId,
+                        Some(vote),
+                        trust * engine.models().weight(model_id),
+                    )
+                })
+                .collect();
+            let decision = engine.decide_with_evidence(per_voter);
+            assert_eq!(decision.outcome, engine.decide_weighted(&weighted));
+            assert_eq!(decision.evidence.recheck(), Ok(decision.outcome));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_evidence_json() {
+        let engine = ConsensusEngine::default();
+        let decision = engine.decide_with_evidence(unit(&[Some(true), None, Some(false)]));
+        let json = serde_json::to_string(&decision).unwrap();
+        assert!(json.contains(r#""per_voter":[[0,true,1],[1,null,1],[2,false,1]]"#));
+        let parsed: RoundDecision = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, decision);
+        assert_eq!(parsed.evidence.recheck(), Ok(decision.outcome));
+    }
+}
@@ -0,0 +1,714 @@
+//! # Verification Runs
+//!
+//! [`run_verification`] verifies every target in a [`Config`] and returns
+//! the [`VerificationSummary`]: what the `verify_all` runner does, minus
+//! its console output. A run probes the toolchain, reviews each module's
+//! axioms and escapes against the allowlist, runs the prover, attributes
+//! prover errors to theorems, and finishes with the `cargo test` stage.
+//!
+//! [`run_verification_with`] takes [`RunOptions`], a [`CommandRunner`] that
+//! executes every subprocess (tests substitute canned output) and a
+//! [`ProgressObserver`] that receives per-module events as they happen.
+//! The runner's console output is one such observer.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::allowlist::{Allowlist, Unreviewed};
+use crate::builder::ModuleBuilder;
+use crate::config::{Backend, Config, ProofTarget};
+use crate::container::Sandbox;
+use crate::diagnostics::{self, Diagnostic};
+use crate::error::ShieldError;
+use crate::install::{self, ToolPaths};
+use crate::severity;
+use crate::solver_stats::{self, SolverStats};
+use crate::source_scan::{self, ProofInventory};
+use crate::stage::{self, CommandRunner, StageOutput, SystemRunner};
+use crate::unit_tests::{self, UnitTestConfig, UnitTestResults};
+use crate::{RunSource, TheoremResult, VerificationStatus, VerificationSummary};
+
+/// Result of the `cargo test` stage: status and parsed results, or the
+/// error that kept cargo from running
+pub type UnitTestOutcome = Result<(VerificationStatus, Option<UnitTestResults>), ShieldError>;
+
+/// How a run executes. The default proves natively with the managed (or
+/// PATH) Verus, an empty allowlist and no unit test stage.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    /// Per-stage timeout
+    pub timeout: Duration,
+    /// Verus executable for native runs
+    pub verus: PathBuf,
+    /// Run Verus in this container instead of natively
+    pub sandbox: Option<Sandbox>,
+    /// Reviewed axioms and escapes; anything else fails its module
+    pub allowlist: Allowlist,
+    /// Verus `--rlimit`
+    pub rlimit: Option<u64>,
+    /// Collect solver statistics (and per-theorem durations) from Verus
+    pub solver_stats: bool,
+    /// `cargo test` stage; skipped when None
+    pub unit_tests: Option<UnitTestConfig>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            timeout: stage::DEFAULT_TIMEOUT,
+            verus: install::find_verus(ToolPaths::from_env().as_ref()),
+            sandbox: None,
+            allowlist: Allowlist::default(),
+            rlimit: None,
+            solver_stats: false,
+            unit_tests: None,
+        }
+    }
+}
+
+/// How a module's verification ended
+#[derive(Debug)]
+pub enum ModuleOutcome {
+    /// Unreviewed axioms or escapes failed the module before proving
+    Unreviewed(Vec<Unreviewed>),
+    /// The prover is not installed; the module was not run
+    NotInstalled,
+    /// The prover could not be invoked
+    Error(ShieldError),
+    /// The prover ran. `errors` are its diagnostics with source locations
+    /// (host paths, also for sandboxed runs); `stats` is present when
+    /// solver statistics were requested.
+    Completed {
+        output: StageOutput,
+        errors: Vec<Diagnostic>,
+        stats: Option<SolverStats>,
+    },
+}
+
+impl ModuleOutcome {
+    /// Module status before errors are attributed to theorems
+    pub fn status(&self) -> VerificationStatus {
+        match self {
+            ModuleOutcome::NotInstalled => VerificationStatus::NotRun,
+            ModuleOutcome::Completed { output, .. } if output.success => {
+                VerificationStatus::Verified
+            }
+            _ => VerificationStatus::Failed,
+        }
+    }
+}
+
+/// Receives events as a run progresses. Every method defaults to doing
+/// nothing.
+pub trait ProgressObserver {
+    /// `tool` (`verus`, `prusti` or `rustc`) was probed; `version` is None
+    /// if it is not available
+    fn tool_checked(&mut self, _tool: &str, _version: Option<&str>) {}
+
+    /// Proving is about to start
+    fn modules_started(&mut self, _targets: &[ProofTarget]) {}
+
+    /// A module's source was scanned
+    fn module_started(&mut self, _target: &ProofTarget, _inventory: &ProofInventory) {}
+
+    /// The prover invocation for a module, before it runs
+    fn module_command(&mut self, _target: &ProofTarget, _command: &Command) {}
+
+    /// A module is done
+    fn module_finished(&mut self, _target: &ProofTarget, _outcome: &ModuleOutcome) {}
+
+    /// `cargo test` is about to run
+    fn unit_tests_started(&mut self, _config: &UnitTestConfig) {}
+
+    /// `cargo test` ran, or could not be started
+    fn unit_tests_finished(&mut self, _outcome: &UnitTestOutcome) {}
+
+    /// No unit test stage was configured
+    fn unit_tests_skipped(&mut self) {}
+}
+
+/// Observer that ignores every event
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl ProgressObserver for NoProgress {}
+
+/// Verify every target in `config` with default [`RunOptions`].
+///
+/// Errors only if the results do not form a valid summary; prover and
+/// tool failures are reported as module statuses.
+///
+/// ```
+/// use std::path::Path;
+///
+/// use aevion_shield::config::{Backend, Config, ProofTarget};
+/// use aevion_shield::models::ModelRegistry;
+/// use aevion_shield::{run_verification, Thresholds, VerificationStatus};
+///
+/// let config = Config {
+///     targets: vec![ProofTarget {
+///         name: "halting".to_string(),
+///         path: Path::new(env!("CARGO_MANIFEST_DIR")).join("src/fixtures/runner/halting.rs"),
+///         backend: Backend::Verus,
+///         claims: vec![],
+///         description: None,
+///     }],
+///     thresholds: Thresholds::default(),
+///     models: ModelRegistry::builtin(),
+/// };
+/// let summary = run_verification(&config)?;
+///
+/// // The fixture's axiom is not in the (empty) default allowlist, so the
+/// // module fails before Verus runs, whether or not it is installed
+/// let report = summary.get("halting").unwrap();
+/// assert_eq!(report.status, VerificationStatus::Failed);
+/// assert_eq!(report.axioms, ["axiom_variance_sound"]);
+/// assert_eq!(summary.unit_tests, VerificationStatus::Skipped);
+/// # Ok::<(), aevion_shield::ShieldError>(())
+/// ```
+pub fn run_verification(config: &Config) -> Result<VerificationSummary, ShieldError> {
+    run_verification_with(
+        config,
+        &RunOptions::default(),
+        &SystemRunner,
+        &mut NoProgress,
+    )
+}
+
+/// Verify every target in `config`, executing subprocesses with `runner`
+/// and reporting to `progress`
+pub fn run_verification_with(
+    config: &Config,
+    options: &RunOptions,
+    runner: &dyn CommandRunner,
+    progress: &mut dyn ProgressObserver,
+) -> Result<VerificationSummary, ShieldError> {
+    let mut builder = VerificationSummary::builder();
+    for module in config.module_names() {
+        builder.module(module);
+    }
+
+    let toolchain = check_toolchain(options, runner, progress);
+    let verus_installed = toolchain.contains_key("verus");
+    let prusti_installed = toolchain.contains_key("prusti");
+    builder.source(RunSource {
+        label: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs()),
+        run_id: None,
+        toolchain,
+    });
+
+    progress.modules_started(&config.targets);
+    for target in &config.targets {
+        let inventory = ProofInventory::scan_file(&target.path).unwrap_or_default();
+        progress.module_started(target, &inventory);
+        let installed = match target.backend {
+            Backend::Verus => verus_installed,
+            Backend::Prusti => prusti_installed,
+        };
+        let outcome = verify_target(target, &inventory, installed, options, runner, progress);
+        progress.module_finished(target, &outcome);
+        record(
+            builder.module(target.name.as_str()),
+            target,
+            &inventory,
+            &outcome,
+        );
+    }
+
+    let (status, results) = match &options.unit_tests {
+        Some(config) => {
+            progress.unit_tests_started(config);
+            let outcome = unit_tests::run_unit_tests_with(config, runner);
+            progress.unit_tests_finished(&outcome);
+            outcome.unwrap_or((VerificationStatus::Failed, None))
+        }
+        None => {
+            progress.unit_tests_skipped();
+            (VerificationStatus::Skipped, None)
+        }
+    };
+    builder.unit_tests(status, results);
+
+    let mut summary = builder.build()?;
+    summary.finish();
+    Ok(summary)
+}
+
+/// Tool name -> version for every tool that answered `--version`
+fn check_toolchain(
+    options: &RunOptions,
+    runner: &dyn CommandRunner,
+    progress: &mut dyn ProgressObserver,
+) -> BTreeMap<String, String> {
+    // A sandboxed run needs Verus in the image, not on the host
+    let verus = match &options.sandbox {
+        Some(sandbox) => {
+            let mut command = Command::new(sandbox.runtime.program());
+            command
+                .args(["run", "--rm", "--network", "none"])
+                .arg(&sandbox.image)
+                .args(["verus", "--version"]);
+            command
+        }
+        None => {
+            let mut command = Command::new(&options.verus);
+            command.arg("--version");
+            command
+        }
+    };
+    let mut prusti = Command::new("cargo");
+    prusti.args(["prusti", "--version"]);
+    let mut rustc = Command::new("rustc");
+    rustc.arg("--version");
+
+    let mut toolchain = BTreeMap::new();
+    for (tool, mut command) in [("verus", verus), ("prusti", prusti), ("rustc", rustc)] {
+        let version = match runner.run(&mut command, options.timeout) {
+            Ok(output) if output.success => Some(output.stdout.trim().to_string()),
+            _ => None,
+        };
+        progress.tool_checked(tool, version.as_deref());
+        if let Some(version) = version {
+            toolchain.insert(tool.to_string(), version);
+        }
+    }
+    toolchain
+}
+
+/// Review, then prove, one target
+fn verify_target(
+    target: &ProofTarget,
+    inventory: &ProofInventory,
+    installed: bool,
+    options: &RunOptions,
+    runner: &dyn CommandRunner,
+    progress: &mut dyn ProgressObserver,
+) -> ModuleOutcome {
+    // Unreviewed axioms/escapes fail the module regardless of the prover result
+    let unreviewed = options.allowlist.review(&target.name, inventory);
+    if !unreviewed.is_empty() {
+        return ModuleOutcome::Unreviewed(unreviewed);
+    }
+
+    let mut command = match prover_command(target, &options.verus, options.sandbox.as_ref()) {
+        Ok(command) => command,
+        Err(err) => return ModuleOutcome::Error(err),
+    };
+    if target.backend == Backend::Verus {
+        if let Some(rlimit) = options.rlimit {
+            command.arg("--rlimit").arg(rlimit.to_string());
+        }
+        if options.solver_stats {
+            command.args(solver_stats::verus_args());
+        }
+    }
+    progress.module_command(target, &command);
+    if !installed {
+        return ModuleOutcome::NotInstalled;
+    }
+
+    let output = match runner.run(&mut command, options.timeout) {
+        Ok(output) => output,
+        Err(err) => return ModuleOutcome::Error(err),
+    };
+    let stats = options.solver_stats.then(|| {
+        let source = fs::read_to_string(&target.path).unwrap_or_default();
+        SolverStats::parse(&output.combined(), &source)
+    });
+    let errors = if output.success || output.timed_out {
+        Vec::new()
+    } else {
+        located_errors(&output.combined(), options.sandbox.as_ref())
+    };
+    ModuleOutcome::Completed {
+        output,
+        errors,
+        stats,
+    }
+}
+
+/// Prover invocation for a target
+fn prover_command(
+    target: &ProofTarget,
+    verus: &Path,
+    sandbox: Option<&Sandbox>,
+) -> Result<Command, ShieldError> {
+    match target.backend {
+        Backend::Verus => match sandbox {
+            Some(sandbox) => sandbox.verus_command(&target.path.canonicalize()?),
+            None => {
+                let mut command = Command::new(verus);
+                command.arg(&target.path);
+                Ok(command)
+            }
+        },
+        Backend::Prusti => {
+            let dir = if target.path.is_dir() {
+                target.path.as_path()
+            } else {
+                target.path.parent().unwrap_or(Path::new("."))
+            };
+            let mut command = Command::new("cargo");
+            command.arg("prusti").current_dir(dir);
+            Ok(command)
+        }
+    }
+}
+
+/// Prover errors that carry source locations, with sandbox paths mapped
+/// back to the host
+fn located_errors(output: &str, sandbox: Option<&Sandbox>) -> Vec<Diagnostic> {
+    let mut errors: Vec<_> = diagnostics::parse(output)
+        .into_iter()
+        .filter(|d| !d.spans.is_empty())
+        .collect();
+    if let Some(sandbox) = sandbox {
+        sandbox.translate(&mut errors);
+    }
+    errors
+}
+
+/// Record a module's status, theorems, axioms and duration
+fn record(
+    module: &mut ModuleBuilder,
+    target: &ProofTarget,
+    inventory: &ProofInventory,
+    outcome: &ModuleOutcome,
+) {
+    let status = outcome.status();
+    module.status(status);
+
+    let (failing_lines, times) = match outcome {
+        ModuleOutcome::Completed {
+            output,
+            errors,
+            stats,
+        } => {
+            module.duration(output.duration);
+            let times = stats
+                .iter()
+                .flat_map(|stats| &stats.functions)
+                .filter_map(|f| Some((f.name.clone(), Duration::from_millis(f.time_ms?))))
+                .collect();
+            (error_lines(errors, &target.path), times)
+        }
+        _ => (Vec::new(), BTreeMap::new()),
+    };
+    for theorem in theorem_results(target, status, &failing_lines, &times) {
+        module.theorem(theorem.name, theorem.status, theorem.duration);
+    }
+    for axiom in &inventory.axioms {
+        module.axiom(axiom.as_str());
+    }
+}
+
+/// Lines of `file` where prover errors were reported (primary spans only)
+fn error_lines(errors: &[Diagnostic], file: &Path) -> Vec<usize> {
+    let canonical = file.canonicalize().ok();
+    errors
+        .iter()
+        .filter(|e| e.level == diagnostics::Level::Error)
+        .filter_map(Diagnostic::primary_span)
+        .filter(|span| {
+            span.file == file || (canonical.is_some() && span.file.canonicalize().ok() == canonical)
+        })
+        .map(|span| span.line)
+        .collect()
+}
+
+/// Per-theorem results for a module.
+///
+/// A verified module verifies every theorem. When a failed module's errors
+/// can be attributed to theorems, only those fail; otherwise every theorem
+/// takes the module status. Solver times come from solver statistics.
+fn theorem_results(
+    target: &ProofTarget,
+    status: VerificationStatus,
+    failing_lines: &[usize],
+    times: &BTreeMap<String, Duration>,
+) -> Vec<TheoremResult> {
+    let source = fs::read_to_string(&target.path).unwrap_or_default();
+    let declarations = source_scan::proof_fn_lines(&source);
+    let failing: Vec<&str> = failing_lines
+        .iter()
+        .filter_map(|line| source_scan::enclosing_proof_fn(&declarations, *line))
+        .collect();
+
+    declarations
+        .iter()
+        .filter(|(name, _)| !name.starts_with("axiom_"))
+        .map(|(name, _)| {
+            let status = match status {
+                VerificationStatus::Failed if !failing.is_empty() => {
+                    if failing.contains(&name.as_str()) {
+                        VerificationStatus::Failed
+                    } else {
+                        VerificationStatus::Verified
+                    }
+                }
+                other => other,
+            };
+            TheoremResult {
+                name: name.clone(),
+                status,
+                duration: times.get(name).copied(),
+                severity: severity::builtin_severity(&target.name, name),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+    use std::io;
+
+    use super::*;
+    use crate::models::ModelRegistry;
+    use crate::Thresholds;
+
+    /// Answers commands by prefix with canned output; anything else is
+    /// "not installed"
+    struct FakeRunner {
+        responses: Vec<(String, StageOutput)>,
+        commands: RefCell<Vec<String>>,
+    }
+
+    impl FakeRunner {
+        fn new() -> Self {
+            Self {
+                responses: Vec::new(),
+                commands: RefCell::new(Vec::new()),
+            }
+        }
+
+        fn respond(mut self, prefix: &str, success: bool, stdout: &str) -> Self {
+            let output = StageOutput {
+                exit_code: Some(if success { 0 } else { 1 }),
+                success,
+                stdout: stdout.to_string(),
+                stderr: String::new(),
+                timed_out: false,
+                duration: Duration::from_millis(1500),
+            };
+            self.responses.push((prefix.to_string(), output));
+            self
+        }
+
+        fn commands(&self) -> Vec<String> {
+            self.commands.borrow().clone()
+        }
+    }
+
+    impl CommandRunner for FakeRunner {
+        fn run(
+            &self,
+            command: &mut Command,
+            _timeout: Duration,
+        ) -> Result<StageOutput, ShieldError> {
+            let program = command.get_program().to_string_lossy().into_owned();
+            let mut line = program.clone();
+            for arg in command.get_args() {
+                line.push(' ');
+                line.push_str(&arg.to_string_lossy());
+            }
+            self.commands.borrow_mut().push(line.clone());
+            self.responses
+                .iter()
+                .find(|(prefix, _)| line.starts_with(prefix.as_str()))
+                .map(|(_, output)| output.clone())
+                .ok_or_else(|| {
+                    ShieldError::spawn(program, io::Error::from(io::ErrorKind::NotFound))
+                })
+        }
+    }
+
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl ProgressObserver for Recorder {
+        fn tool_checked(&mut self, tool: &str, version: Option<&str>) {
+            self.0.push(format!("tool {} {:?}", tool, version));
+        }
+
+        fn module_started(&mut self, target: &ProofTarget, inventory: &ProofInventory) {
+            self.0.push(format!(
+                "module {} ({} theorems)",
+                target.name,
+                inventory.theorems.len()
+            ));
+        }
+
+        fn module_command(&mut self, target: &ProofTarget, _command: &Command) {
+            self.0.push(format!("command {}", target.name));
+        }
+
+        fn module_finished(&mut self, target: &ProofTarget, outcome: &ModuleOutcome) {
+            self.0
+                .push(format!("finished {} {}", target.name, outcome.status()));
+        }
+
+        fn unit_tests_finished(&mut self, outcome: &UnitTestOutcome) {
+            let status = outcome.as_ref().map(|(status, _)| *status);
+            self.0.push(format!("unit tests {:?}", status.ok()));
+        }
+
+        fn unit_tests_skipped(&mut self) {
+            self.0.push("unit tests skipped".to_string());
+        }
+    }
+
+    fn fixture() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src/fixtures/runner/halting.rs")
+    }
+
+    fn config() -> Config {
+        Config {
+            targets: vec![ProofTarget {
+                name: "halting".to_string(),
+                path: fixture(),
+                backend: Backend::Verus,
+                claims: vec![],
+                description: None,
+            }],
+            thresholds: Thresholds::default(),
+            models: ModelRegistry::builtin(),
+        }
+    }
+
+    fn options() -> RunOptions {
+        RunOptions {
+            verus: PathBuf::from("verus"),
+            allowlist: Allowlist {
+                axioms: BTreeMap::from([(
+                    "halting".to_string(),
+                    vec!["axiom_variance_sound".to_string()],
+                )]),
+                ..Allowlist::default()
+            },
+            ..RunOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_failed_proof_is_attributed_to_theorems() {
+        let error = format!(
+            "error: assertion failed\n  --> {}:21:5\n   |\n21 |     assert(v * v >= 0);\n",
+            fixture().display()
+        );
+        let runner = FakeRunner::new()
+            .respond("verus --version", true, "Verus 0.2026.10.01\n")
+            .respond("rustc --version", true, "rustc 1.83.0\n")
+            .respond("verus ", false, &error)
+            .respond(
+                "cargo test",
+                true,
+                "test result: ok. 3 passed; 0 failed; 1 ignored; 0 measured\n",
+            );
+        let options = RunOptions {
+            rlimit: Some(20),
+            unit_tests: Some(UnitTestConfig::default()),
+            ..options()
+        };
+        let mut recorder = Recorder::default();
+
+        let summary = run_verification_with(&config(), &options, &runner, &mut recorder).unwrap();
+
+        let report = summary.get("halting").unwrap();
+        assert_eq!(report.status, VerificationStatus::Failed);
+        assert_eq!(report.duration, Some(Duration::from_millis(1500)));
+        assert_eq!(report.axioms, ["axiom_variance_sound"]);
+        let theorems: Vec<_> = report
+            .theorems
+            .iter()
+            .map(|t| (t.name.as_str(), t.status))
+            .collect();
+        assert_eq!(
+            theorems,
+            [
+                ("halt_on_high_variance", VerificationStatus::Verified),
+                ("variance_nonnegative", VerificationStatus::Failed),
+            ]
+        );
+        assert_eq!(summary.unit_tests, VerificationStatus::Verified);
+        assert_eq!(summary.unit_test_results.as_ref().unwrap().passed, 3);
+
+        let toolchain = &summary.sources[0].toolchain;
+        assert_eq!(toolchain["verus"], "Verus 0.2026.10.01");
+        assert!(!toolchain.contains_key("prusti"));
+
+        let commands = runner.commands();
+        assert_eq!(
+            commands[3],
+            format!("verus {} --rlimit 20", fixture().display())
+        );
+        assert!(commands[4].starts_with("cargo test"));
+        assert_eq!(
+            recorder.0,
+            [
+                "tool verus Some(\"Verus 0.2026.10.01\")",
+                "tool prusti None",
+                "tool rustc Some(\"rustc 1.83.0\")",
+                "module halting (2 theorems)",
+                "command halting",
+                "finished halting failed",
+                "unit tests Some(Verified)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_prover_leaves_modules_not_run() {
+        let runner = FakeRunner::new();
+        let mut recorder = Recorder::default();
+
+        let summary = run_verification_with(&config(), &options(), &runner, &mut recorder).unwrap();
+
+        let report = summary.get("halting").unwrap();
+        assert_eq!(report.status, VerificationStatus::NotRun);
+        assert!(report
+            .theorems
+            .iter()
+            .all(|t| t.status == VerificationStatus::NotRun));
+        assert_eq!(summary.unit_tests, VerificationStatus::Skipped);
+        // Only the version probes ran
+        assert_eq!(runner.commands().len(), 3);
+        assert_eq!(
+            recorder.0[4..],
+            [
+                "command halting",
+                "finished halting not_run",
+                "unit tests skipped"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unreviewed_axiom_fails_before_proving() {
+        let runner = FakeRunner::new().respond("verus", true, "");
+        let options = RunOptions {
+            allowlist: Allowlist::default(),
+            ..options()
+        };
+
+        let summary = run_verification_with(&config(), &options, &runner, &mut NoProgress).unwrap();
+
+        let report = summary.get("halting").unwrap();
+        assert_eq!(report.status, VerificationStatus::Failed);
+        assert!(report
+            .theorems
+            .iter()
+            .all(|t| t.status == VerificationStatus::Failed));
+        assert!(!runner.commands().iter().any(|c| c.ends_with("halting.rs")));
+    }
+}
@@ -0,0 +1,391 @@
+//! # Ensemble Selection
+//!
+//! Trust-based model selection for future queries. [`select_ensemble`]
+//! picks the `k` candidates with the highest
+//! [`effective_weight`](crate::trust::effective_weight), their trust times
+//! their model's weight, taking at most ⌈k/2⌉ from any one
+//! [`ModelFamily`] so no family holds a majority of the ensemble.
+//! Each agent weighs and counts toward the family of the model it was
+//! [registered](TrustManager::register_agent) with. Quarantined agents, and
+//! agents the [`TrustManager`] holds no model for, are never selected. Trust and quarantine are read
+//! [`effective`](TrustManager::effective)ly, with the inactivity decay an
+//! idle agent owes charged, so a stale trust cannot win a seat.
+//!
+//! The family cap bounds how similar the selection can be
+//! (`capped_selection_diversity` in `byzantine_consensus.rs`): n models
+//! with at most `cap` of one family score at least
+//! [`capped_diversity_bound`]`(n, cap)`, 375 for four models.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::consensus::AgentId;
+use crate::diversity::ModelFamily;
+use crate::trust::effective_weight;
+use crate::trust_manager::{ApplyRoundError, TrustEventSink, TrustManager};
+use crate::Thresholds;
+
+/// Why [`select_ensemble`] could not pick `k` agents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionError {
+    /// Fewer registered, unquarantined candidates than the ensemble needs
+    NotEnoughEligible { eligible: usize, k: usize },
+    /// Enough eligible candidates, but too few families among them to
+    /// fill the ensemble with at most `cap` from each
+    NotEnoughFamilies {
+        selected: usize,
+        k: usize,
+        cap: usize,
+    },
+    /// Charging a candidate's owed inactivity decay failed
+    Trust(ApplyRoundError),
+}
+
+impl From<ApplyRoundError> for SelectionError {
+    fn from(err: ApplyRoundError) -> Self {
+        SelectionError::Trust(err)
+    }
+}
+
+impl fmt::Display for SelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelectionError::NotEnoughEligible { eligible, k } => write!(
+                f,
+                "{} eligible candidates cannot fill an ensemble of {}",
+                eligible, k
+            ),
+            SelectionError::NotEnoughFamilies { selected, k, cap } => write!(
+                f,
+                "only {} of {} agents can be selected with at most {} per model family",
+                selected, k, cap
+            ),
+            SelectionError::Trust(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SelectionError {}
+
+/// Most agents of one family an ensemble of `k` may hold: ⌈k/2⌉
+pub fn family_cap(k: usize) -> usize {
+    k.div_ceil(2)
+}
+
+/// Lowest [`diversity_score`](crate::diversity::diversity_score) of `n >=
+/// 2` models with at most `cap` of one family
+/// (`capped_selection_diversity`): `3 (n - cap)(n - cap + 1) * 1000 / (4 n
+/// (n - 1))`, from the family pairs alone; 0 when the cap allows a single
+/// family
+pub fn capped_diversity_bound(n: u64, cap: u64) -> u64 {
+    if n < 2 || cap >= n {
+        return 0;
+    }
+    let (n, cap) = (u128::from(n), u128::from(cap));
+    (3 * (n - cap) * (n - cap + 1) * 1000 / (4 * n * (n - 1))) as u64
+}
+
+/// The `k` eligible `candidates` with the highest effective weight, at
+/// most [`family_cap`]`(k)` of any family, highest weight first. Weights
+/// and families are those of the model each agent was registered with
+/// ([`TrustManager::model`]).
+///
+/// A candidate is eligible when `manager` knows its model and, with the
+/// inactivity decay it owes under `thresholds` charged, it is not
+/// quarantined; repeated candidates count once. Equal weights go to the
+/// lowest agent id, so the selection does not depend on the order the
+/// candidates are listed in.
+pub fn select_ensemble<S: TrustEventSink>(
+    candidates: &[AgentId],
+    k: usize,
+    manager: &TrustManager<S>,
+    thresholds: &Thresholds,
+) -> Result<Vec<AgentId>, SelectionError> {
+    let mut eligible = BTreeMap::new();
+    for &agent in candidates.iter().collect::<BTreeSet<_>>() {
+        let Some(&model) = manager.model(agent) else {
+            continue;
+        };
+        match manager.effective(agent, thresholds)? {
+            Some(trust) if !trust.is_quarantined() => {
+                eligible.insert(agent, (trust.current(), model));
+            }
+            _ => {}
+        }
+    }
+    if eligible.len() < k {
+        return Err(SelectionError::NotEnoughEligible {
+            eligible: eligible.len(),
+            k,
+        });
+    }
+
+    let mut ranked: Vec<(u64, AgentId, ModelFamily)> = eligible
+        .into_iter()
+        .map(|(agent, (trust, model))| (effective_weight(trust, &model), agent, model.family))
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let cap = family_cap(k);
+    let mut per_family: BTreeMap<ModelFamily, usize> = BTreeMap::new();
+    let mut selected = Vec::with_capacity(k);
+    for (_, agent, family) in ranked {
+        if selected.len() == k {
+            break;
+        }
+        let count = per_family.entry(family).or_insert(0);
+        if *count < cap {
+            *count += 1;
+            selected.push(agent);
+        }
+    }
+    if selected.len() < k {
+        return Err(SelectionError::NotEnoughFamilies {
+            selected: selected.len(),
+            k,
+            cap,
+        });
+    }
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::ConsensusEngine;
+    use crate::diversity::{diversity_score, family_diversity};
+    use crate::models::{ModelRegistry, ModelSpec};
+    use crate::test_support::Rng;
+    use crate::trust::AgentTrust;
+
+    /// Manager holding each (agent, model id, trust): the agent registered
+    /// with `registry`'s model of that id
+    fn manager(registry: &ModelRegistry, agents: &[(AgentId, u64, u64)]) -> TrustManager {
+        let mut manager = TrustManager::new();
+        for &(agent, model, current) in agents {
+            manager.register_agent(agent, registry.spec(model));
+            let trust = AgentTrust::builder().current(current).build().unwrap();
+            manager.insert(agent, trust);
+        }
+        manager
+    }
+
+    /// Model ids of the selected agents
+    fn models(manager: &TrustManager, selected: &[AgentId]) -> Vec<u64> {
+        selected
+            .iter()
+            .map(|&agent| manager.model(agent).unwrap().id)
+            .collect()
+    }
+
+    #[test]
+    fn test_family_cap_binds() {
+        // Built-in ids: 0 = o1-mini (1.8), 1 = nemotron (1.7), 2..=4 = GPT-4
+        // (1.5, 1.5, 1.3), run by agents 10..=14. The three GPT-4 agents
+        // outweigh the others, but an ensemble of four takes at most two.
+        let registry = ModelRegistry::builtin();
+        let thresholds = Thresholds::default();
+        let manager = manager(
+            &registry,
+            &[
+                (10, 0, 500),
+                (11, 1, 500),
+                (12, 2, 1000),
+                (13, 3, 1000),
+                (14, 4, 1000),
+            ],
+        );
+        let select =
+            |candidates: &[AgentId], k| select_ensemble(candidates, k, &manager, &thresholds);
+        let candidates = [10, 11, 12, 13, 14];
+        let selected = select(&candidates, 4).unwrap();
+        assert_eq!(selected, [12, 13, 10, 11]);
+        assert_eq!(diversity_score(&models(&manager, &selected)), 750);
+        assert!(diversity_score(&models(&manager, &selected)) >= capped_diversity_bound(4, 2));
+
+        // Of three, two may be GPT-4
+        assert_eq!(select(&candidates, 3).unwrap(), [12, 13, 10]);
+
+        // With GPT-4 agents alone there is no ensemble of two
+        let err = select(&[12, 13, 14], 2).unwrap_err();
+        assert_eq!(
+            err,
+            SelectionError::NotEnoughFamilies {
+                selected: 1,
+                k: 2,
+                cap: 1
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "only 1 of 2 agents can be selected with at most 1 per model family"
+        );
+        assert_eq!(select(&[12, 13, 14], 1), Ok(vec![12]));
+        assert_eq!(select(&[12, 13, 14], 0), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_selects_by_registered_model() {
+        // Agent ids that are also built-in model ids select by the model
+        // the agent runs: agents 0 and 1 both run gpt-4o, agent 2 o1-mini
+        let registry = ModelRegistry::builtin();
+        let thresholds = Thresholds::default();
+        let manager = manager(&registry, &[(0, 2, 1000), (1, 2, 1000), (2, 0, 900)]);
+        // o1-mini at 0.9 weighs 162, gpt-4o at 1.0 weighs 150
+        let selected = select_ensemble(&[0, 1, 2], 2, &manager, &thresholds).unwrap();
+        assert_eq!(selected, [2, 0]);
+        assert_eq!(models(&manager, &selected), [0, 2]);
+        // Two gpt-4o agents are one family, whatever their ids
+        assert_eq!(
+            select_ensemble(&[0, 1], 2, &manager, &thresholds),
+            Err(SelectionError::NotEnoughFamilies {
+                selected: 1,
+                k: 2,
+                cap: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_quarantined_and_unknown_agents_are_skipped() {
+        let registry = ModelRegistry::builtin();
+        let thresholds = Thresholds::default();
+        let mut manager = manager(&registry, &[(0, 0, 1000), (1, 1, 900), (2, 2, 800)]);
+        let quarantined = AgentTrust::builder()
+            .current(1000)
+            .quarantined(true)
+            .build()
+            .unwrap();
+        manager.insert(0, quarantined);
+        let trusted = AgentTrust::builder().current(1000).build().unwrap();
+        manager.insert(3, trusted);
+
+        // 0 is quarantined, 3 has no model and 9 is unknown, however
+        // trusted any would be
+        let selected = select_ensemble(&[9, 3, 0, 1, 2], 2, &manager, &thresholds).unwrap();
+        assert_eq!(selected, [1, 2]);
+        let err = select_ensemble(&[9, 3, 0, 1, 2, 2], 3, &manager, &thresholds).unwrap_err();
+        assert_eq!(err, SelectionError::NotEnoughEligible { eligible: 2, k: 3 });
+        assert_eq!(
+            err.to_string(),
+            "2 eligible candidates cannot fill an ensemble of 3"
+        );
+    }
+
+    #[test]
+    fn test_idle_agents_pay_owed_decay() {
+        // Built-in ids: 0 = o1-mini (1.8), 1 = nemotron (1.7), 2 = GPT-4
+        let registry = ModelRegistry::builtin();
+        let thresholds = Thresholds {
+            inactivity_decay_rate: 300,
+            ..Thresholds::default()
+        };
+        let mut manager = manager(&registry, &[(0, 0, 1000), (1, 1, 1000), (2, 2, 1000)]);
+        let select =
+            |manager: &TrustManager| select_ensemble(&[0, 1, 2], 2, manager, &thresholds).unwrap();
+        assert_eq!(select(&manager), [0, 1]);
+
+        // Agent 0 sits four rounds out: 0.7^4 of its trust is below the
+        // detection threshold, so it is quarantined once charged, although
+        // the stored trust is still full
+        let round = ConsensusEngine::default()
+            .decide_with_evidence(vec![(1, Some(true), 1), (2, Some(true), 1)])
+            .evidence;
+        for _ in 0..4 {
+            manager.apply_round(&round, &[], &thresholds).unwrap();
+        }
+        assert!(!manager.get(0).unwrap().is_quarantined());
+        assert_eq!(select(&manager), [1, 2]);
+    }
+
+    #[test]
+    fn test_ties_break_by_agent_id() {
+        // Each agent runs a model of its own family weighing 1.0
+        let thresholds = Thresholds::default();
+        let families = [
+            ModelFamily::OpenAiO,
+            ModelFamily::Gpt4,
+            ModelFamily::Nemotron,
+            ModelFamily::Claude,
+            ModelFamily::Llama,
+        ];
+        // 0.800 and 0.801 both weigh 80 at 1.0: a tie, not a ranking
+        let trusts = [800, 700, 801, 800, 800];
+        let mut manager = TrustManager::new();
+        for (agent, (family, current)) in families.into_iter().zip(trusts).enumerate() {
+            let agent = agent as AgentId;
+            let spec = ModelSpec {
+                id: 100,
+                weight_scaled: 100,
+                family,
+            };
+            manager.register_agent(agent, spec);
+            let trust = AgentTrust::builder().current(current).build().unwrap();
+            manager.insert(agent, trust);
+        }
+        let mut candidates = vec![0, 1, 2, 3, 4];
+        let mut rng = Rng(0x5eed_1628);
+        for _ in 0..20 {
+            for i in (1..candidates.len()).rev() {
+                candidates.swap(i, rng.below(i as u64 + 1) as usize);
+            }
+            let selected = select_ensemble(&candidates, 3, &manager, &thresholds).unwrap();
+            assert_eq!(selected, [0, 2, 3], "{:?}", candidates);
+        }
+    }
+
+    #[test]
+    fn test_selections_meet_the_diversity_bound() {
+        // capped_selection_diversity, over random trusts and candidates
+        // running the built-in models and unknown ones
+        let registry = ModelRegistry::builtin();
+        let thresholds = Thresholds::default();
+        let mut rng = Rng(0x5eed_1629);
+        let mut selections = 0;
+        for _ in 0..2_000 {
+            let mut agents = Vec::new();
+            for agent in 0..10 {
+                if rng.below(3) > 0 {
+                    agents.push((agent, rng.below(7), rng.below(1001)));
+                }
+            }
+            let manager = manager(&registry, &agents);
+            let candidates: Vec<AgentId> = (0..12).filter(|_| rng.below(4) > 0).collect();
+            let k = rng.below(7) as usize;
+            let Ok(selected) = select_ensemble(&candidates, k, &manager, &thresholds) else {
+                continue;
+            };
+            selections += 1;
+            assert_eq!(selected.len(), k);
+            let models = models(&manager, &selected);
+            let families: Vec<ModelFamily> = models.iter().map(|&id| registry.family(id)).collect();
+            for family in &families {
+                let count = families.iter().filter(|&f| f == family).count();
+                assert!(count <= family_cap(k), "{:?}", families);
+            }
+            let score = family_diversity(&families);
+            assert_eq!(score, diversity_score(&models));
+            assert!(
+                score >= capped_diversity_bound(k as u64, family_cap(k) as u64),
+                "{:?} scores {}",
+                families,
+                score
+            );
+        }
+        assert!(selections > 500, "{}", selections);
+    }
+
+    #[test]
+    fn test_capped_diversity_bound() {
+        assert_eq!(capped_diversity_bound(2, 1), 750);
+        assert_eq!(capped_diversity_bound(3, 2), 250);
+        assert_eq!(capped_diversity_bound(4, 2), 375);
+        assert_eq!(capped_diversity_bound(5, 3), 225);
+        assert_eq!(capped_diversity_bound(1, 1), 0);
+        assert_eq!(capped_diversity_bound(4, 4), 0);
+        // Tight for two families of one architecture: nemotron with gpt-4o
+        assert_eq!(diversity_score(&[1, 2]), 750);
+    }
+}
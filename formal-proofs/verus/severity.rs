@@ -0,0 +1,213 @@
+//! # Theorem Severity
+//!
+//! Which theorems are core safety results and which are supporting checks.
+//! A [`SeverityRegistry`] assigns each theorem a [`Severity`] by qualified
+//! name (`module::theorem`, with `*`/`?` globs); the first matching rule
+//! wins and anything unmatched is `Standard`.
+//!
+//! [`VerificationSummary::overall`] fails a run only for critical failures
+//! and reports [`VerificationStatus::Degraded`] when every failure is
+//! standard or informational.
+//!
+//! [`VerificationStatus::Degraded`]: crate::VerificationStatus::Degraded
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use crate::core::Severity;
+use crate::{glob_match, VerificationSummary};
+
+/// Built-in rules: (qualified name pattern, severity), highest priority first
+pub const BUILTIN_SEVERITIES: [(&str, Severity); 4] = [
+    ("byzantine_consensus::byzantine_safety", Severity::Critical),
+    (
+        "byzantine_consensus::constitutional_halt_correctness",
+        Severity::Critical,
+    ),
+    ("trust_bounds::ema_preserves_bounds", Severity::Critical),
+    // Axiom budget and consistency checks
+    ("*::axiom_*", Severity::Informational),
+];
+
+/// Severity rules by qualified theorem name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeverityRegistry {
+    /// (pattern, severity), highest priority first
+    rules: Vec<(String, Severity)>,
+}
+
+impl SeverityRegistry {
+    /// No rules: every theorem is `Standard`
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// The [`BUILTIN_SEVERITIES`] rules
+    pub fn builtin() -> Self {
+        Self {
+            rules: BUILTIN_SEVERITIES
+                .iter()
+                .map(|(pattern, severity)| (pattern.to_string(), *severity))
+                .collect(),
+        }
+    }
+
+    /// Add a rule that takes precedence over every existing one
+    pub fn set(&mut self, pattern: impl Into<String>, severity: Severity) -> &mut Self {
+        self.rules.insert(0, (pattern.into(), severity));
+        self
+    }
+
+    /// Severity of `module::theorem`
+    pub fn severity_of(&self, module: &str, theorem: &str) -> Severity {
+        let qualified = format!("{}::{}", module, theorem);
+        self.rules
+            .iter()
+            .find(|(pattern, _)| glob_match(pattern, &qualified))
+            .map_or(Severity::Standard, |(_, severity)| *severity)
+    }
+
+    /// Reassign the severity of every theorem in `summary`
+    pub fn apply(&self, summary: &mut VerificationSummary) {
+        for report in &mut summary.modules {
+            for theorem in &mut report.theorems {
+                theorem.severity = self.severity_of(&report.name, &theorem.name);
+            }
+        }
+    }
+}
+
+impl Default for SeverityRegistry {
+    /// The built-in rules
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+/// Built-in severity of `module::theorem`, without building a registry
+pub(crate) fn builtin_severity(module: &str, theorem: &str) -> Severity {
+    let qualified = format!("{}::{}", module, theorem);
+    BUILTIN_SEVERITIES
+        .iter()
+        .find(|(pattern, _)| glob_match(pattern, &qualified))
+        .map_or(Severity::Standard, |(_, severity)| *severity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VerificationStatus;
+
+    fn failing(failed: &[(&str, &str)]) -> VerificationSummary {
+        let mut builder = VerificationSummary::builder();
+        for (module, theorems) in [
+            (
+                "byzantine_consensus",
+                ["byzantine_safety", "quorum_intersection"],
+            ),
+            (
+                "trust_bounds",
+                ["ema_preserves_bounds", "axiom_count_within_budget"],
+            ),
+        ] {
+            let module_builder = builder.module(module);
+            module_builder.status(VerificationStatus::Verified);
+            for theorem in theorems {
+                let status = if failed.contains(&(module, theorem)) {
+                    VerificationStatus::Failed
+                } else {
+                    VerificationStatus::Verified
+                };
+                module_builder.theorem(theorem, status, None);
+            }
+        }
+        builder.unit_tests(VerificationStatus::Verified, None);
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_builtin_severities() {
+        let registry = SeverityRegistry::builtin();
+        assert_eq!(
+            registry.severity_of("byzantine_consensus", "byzantine_safety"),
+            Severity::Critical
+        );
+        assert_eq!(
+            registry.severity_of("trust_bounds", "ema_preserves_bounds"),
+            Severity::Critical
+        );
+        assert_eq!(
+            registry.severity_of("trust_bounds", "axiom_count_within_budget"),
+            Severity::Informational
+        );
+        assert_eq!(
+            registry.severity_of("variance_halt", "halt_on_high_variance"),
+            Severity::Standard
+        );
+        assert_eq!(
+            SeverityRegistry::new().severity_of("trust_bounds", "ema_preserves_bounds"),
+            Severity::Standard
+        );
+        assert!(Severity::Informational < Severity::Standard);
+        assert!(Severity::Standard < Severity::Critical);
+
+        // The builder assigns built-in severities
+        let summary = failing(&[]);
+        let severities: Vec<_> = summary
+            .modules()
+            .flat_map(|(_, report)| report.theorems.iter().map(|t| t.severity))
+            .collect();
+        assert_eq!(
+            severities,
+            [
+                Severity::Critical,
+                Severity::Standard,
+                Severity::Critical,
+                Severity::Informational
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failed_informational_check_degrades() {
+        let summary = failing(&[("trust_bounds", "axiom_count_within_budget")]);
+        assert_eq!(
+            summary.get("trust_bounds").unwrap().derived_status(),
+            VerificationStatus::Failed
+        );
+        assert_eq!(summary.overall(), VerificationStatus::Degraded);
+
+        let summary = failing(&[("byzantine_consensus", "quorum_intersection")]);
+        assert_eq!(summary.overall(), VerificationStatus::Degraded);
+
+        let summary = failing(&[
+            ("trust_bounds", "axiom_count_within_budget"),
+            ("byzantine_consensus", "byzantine_safety"),
+        ]);
+        assert_eq!(summary.overall(), VerificationStatus::Failed);
+    }
+
+    #[test]
+    fn test_unattributed_failures_are_critical() {
+        // A module that failed without a failing theorem (crash, unreviewed
+        // escape) fails the run, as do the unit tests
+        let mut crashed = failing(&[]);
+        crashed.module_mut("trust_bounds").unwrap().theorems.clear();
+        crashed.module_mut("trust_bounds").unwrap().status = VerificationStatus::Failed;
+        assert_eq!(crashed.overall(), VerificationStatus::Failed);
+
+        let mut tests_failed = failing(&[("trust_bounds", "axiom_count_within_budget")]);
+        tests_failed.unit_tests = VerificationStatus::Failed;
+        assert_eq!(tests_failed.overall(), VerificationStatus::Failed);
+    }
+
+    #[test]
+    fn test_registry_overrides() {
+        let mut registry = SeverityRegistry::builtin();
+        registry.set("byzantine_consensus::*", Severity::Critical);
+        let mut summary = failing(&[("byzantine_consensus", "quorum_intersection")]);
+        assert_eq!(summary.overall(), VerificationStatus::Degraded);
+
+        registry.apply(&mut summary);
+        assert_eq!(summary.overall(), VerificationStatus::Failed);
+    }
+}
@@ -0,0 +1,521 @@
+//! # Byzantine Attack Simulation
+//!
+//! Runnable evidence that the theorems and the executable decision agree.
+//! [`simulate`] runs synthetic samples through
+//! [`ConsensusEngine::final_verdict`]: each sample has a true answer,
+//! honest agents vote it with [`Scenario::honest_accuracy`] and report an
+//! output near [`OUTPUT_CENTER`], and the scenario's `f` Byzantine agents
+//! follow an [`Attack`]. A [`SimulationReport`] counts correct answers,
+//! wrong agreements and halts, and every agreement against a unanimous
+//! honest vote while [`ConsensusEngine::tolerates`] holds; `byzantine_safety`
+//! says there are none.
+//!
+//! The [`Scenario::preset`]s model the 500-sample benchmark of three agents
+//! ([`evidence`](crate::evidence)), which ran at a 67% threshold
+//! ([`BENCHMARK_THRESHOLD`]): three agents had to be unanimous. Their
+//! rates are calibrated, not derived: the honest accuracy and the two
+//! attack rates are solved from the published 92.8% accuracy at baseline,
+//! 83.0% under the 33% attack and 57.8% halts under the 67% attack, so that
+//! run through [`benchmark_engine`] the expected figures land on them
+//! (92.7%, 82.9% and 57.8%). Matching the figures is by construction and
+//! says nothing of how the benchmark's Byzantine agents behaved. At the
+//! default exactly-2/3 threshold two of three agents agree, so a
+//! three-agent vote never halts.
+//!
+//! Samples are drawn from a seeded xorshift generator in integer arithmetic,
+//! so a seed gives the same report on every platform.
+//! [`SimulationReport::results`] converts a report to [`EmpiricalResults`]
+//! for `--empirical`.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::consensus::{ConsensusEngine, ConsensusOutcome};
+use crate::evidence::{self, EmpiricalResults};
+use crate::{HaltReason, Threshold, Thresholds};
+
+/// Samples per scenario, as in the published benchmark
+pub const DEFAULT_SAMPLES: u64 = 500;
+
+/// Seed of the default run
+pub const DEFAULT_SEED: u64 = 0x5eed_0500;
+
+/// Agents in the published benchmark
+pub const BENCHMARK_AGENTS: u64 = 3;
+
+/// Consensus threshold of the published benchmark: 67%, under which three
+/// agents must be unanimous
+pub const BENCHMARK_THRESHOLD: Threshold = Threshold::PerMille(670);
+
+/// Honest agents' accuracy in the presets, per mille, calibrated so three
+/// of them agree on the right answer in 92.7% of samples
+pub const BENCHMARK_HONEST_ACCURACY: u64 = 975;
+
+/// Outputs are `OUTPUT_CENTER` plus uniform noise in `0..=OUTPUT_NOISE`
+pub const OUTPUT_CENTER: u64 = 1000;
+pub const OUTPUT_NOISE: u64 = 20;
+
+/// Variance of the output noise scaled by 100: uniform over 21 values,
+/// (21^2 - 1) / 12. Noise alone stays below 3x it.
+pub const BASELINE_VARIANCE_SCALED: u64 = 3666;
+
+/// Offset of a poisoned output: enough to exceed the 6.25x variance bound
+pub const POISON_OFFSET: u64 = 100;
+
+/// The engine the published benchmark decided with: the default
+/// [`Thresholds`] at [`BENCHMARK_THRESHOLD`]
+pub fn benchmark_engine() -> ConsensusEngine {
+    ConsensusEngine::new(&Thresholds {
+        consensus: BENCHMARK_THRESHOLD,
+        ..Thresholds::default()
+    })
+    .expect("benchmark thresholds are valid")
+}
+
+/// How the Byzantine agents of a scenario behave. Rates are per mille.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+pub enum Attack {
+    /// Byzantine agents answer like honest ones
+    None,
+    /// Each Byzantine agent flips its vote independently at `rate`
+    RandomFlip { rate: u64 },
+    /// At `rate`, every Byzantine agent votes the wrong answer together
+    CoordinatedFlip { rate: u64 },
+    /// Byzantine agents vote like honest ones but, each at `rate`, report
+    /// an output [`POISON_OFFSET`] off
+    StealthPoison { rate: u64 },
+    /// Byzantine agents always vote the wrong answer and report the same
+    /// output
+    Collusion,
+}
+
+impl Attack {
+    fn rate(self) -> Option<u64> {
+        match self {
+            Attack::RandomFlip { rate }
+            | Attack::CoordinatedFlip { rate }
+            | Attack::StealthPoison { rate } => Some(rate),
+            Attack::None | Attack::Collusion => None,
+        }
+    }
+}
+
+/// Names of the [`Scenario::preset`]s
+pub const PRESETS: [&str; 5] = ["baseline", "byz33", "byz67", "stealth", "collusion"];
+
+/// `n` agents, `f` of them Byzantine
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Scenario {
+    /// Scenario name; the presets use the [`evidence`](crate::evidence)
+    /// names where the benchmark has one
+    pub name: String,
+    pub n: u64,
+    pub f: u64,
+    pub attack: Attack,
+    /// Chance an agent votes the right answer, per mille
+    pub honest_accuracy: u64,
+}
+
+impl Scenario {
+    /// A benchmark scenario by name (see [`PRESETS`]); three agents
+    pub fn preset(name: &str) -> Option<Self> {
+        let (scenario, f, attack) = match name {
+            "baseline" => (evidence::SCENARIO_BASELINE, 0, Attack::None),
+            // Calibrated: the rate solving 0.975^2 * (0.975 * (1 - r) +
+            // 0.025 * r) for 83.0% agreement on the truth (82.9%)
+            "byz33" => (
+                evidence::SCENARIO_BYZANTINE_33,
+                1,
+                Attack::RandomFlip { rate: 108 },
+            ),
+            // Calibrated: the rate solving r * 0.975 + (1 - r) * 0.073 for
+            // 57.8% halts
+            "byz67" => (
+                evidence::SCENARIO_BYZANTINE_67,
+                2,
+                Attack::CoordinatedFlip { rate: 560 },
+            ),
+            "stealth" => ("stealth", 1, Attack::StealthPoison { rate: 100 }),
+            "collusion" => ("collusion", 1, Attack::Collusion),
+            _ => return None,
+        };
+        Some(Self {
+            name: scenario.to_string(),
+            n: BENCHMARK_AGENTS,
+            f,
+            attack,
+            honest_accuracy: BENCHMARK_HONEST_ACCURACY,
+        })
+    }
+
+    /// Check the scenario can be simulated
+    pub fn validate(&self) -> Result<(), SimulationError> {
+        if self.n == 0 {
+            return Err(SimulationError::NoAgents);
+        }
+        if self.f > self.n {
+            return Err(SimulationError::TooManyByzantine {
+                n: self.n,
+                f: self.f,
+            });
+        }
+        for rate in [Some(self.honest_accuracy), self.attack.rate()]
+            .into_iter()
+            .flatten()
+        {
+            if rate > 1000 {
+                return Err(SimulationError::RateOutOfRange { rate });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A scenario that cannot be simulated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulationError {
+    NoAgents,
+    TooManyByzantine {
+        n: u64,
+        f: u64,
+    },
+    /// A per-mille rate above 1000
+    RateOutOfRange {
+        rate: u64,
+    },
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationError::NoAgents => write!(f, "a scenario needs at least one agent"),
+            SimulationError::TooManyByzantine { n, f: byzantine } => {
+                write!(f, "{} Byzantine agents among {}", byzantine, n)
+            }
+            SimulationError::RateOutOfRange { rate } => {
+                write!(f, "rate {} exceeds 1000 per mille", rate)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SimulationError {}
+
+/// Outcome counts of a simulated scenario
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SimulationReport {
+    pub scenario: Scenario,
+    pub samples: u64,
+    /// Agreements on the true answer
+    pub correct: u64,
+    /// Agreements on the wrong answer
+    pub wrong: u64,
+    pub halted: u64,
+    /// Halts by [`HaltReason::VarianceExceeded`], a subset of `halted`
+    pub variance_halts: u64,
+    /// Agreements against a unanimous honest vote although
+    /// [`ConsensusEngine::tolerates`] held (`byzantine_safety`: none)
+    pub safety_violations: u64,
+}
+
+impl SimulationReport {
+    /// The counts as benchmark results
+    pub fn results(&self) -> EmpiricalResults {
+        EmpiricalResults {
+            scenario: self.scenario.name.clone(),
+            correct: self.correct,
+            total: self.samples,
+            halted: self.halted,
+        }
+    }
+}
+
+/// xorshift64: deterministic on every platform, no dependency
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // xorshift never leaves zero
+        Self(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    /// True at `rate` per mille
+    fn chance(&mut self, rate: u64) -> bool {
+        self.below(1000) < rate
+    }
+}
+
+/// Run `samples` samples of `scenario` through `engine`'s
+/// [`final_verdict`](ConsensusEngine::final_verdict)
+pub fn simulate(
+    scenario: &Scenario,
+    samples: u64,
+    seed: u64,
+    engine: &ConsensusEngine,
+) -> Result<SimulationReport, SimulationError> {
+    scenario.validate()?;
+    let mut rng = Rng::new(seed);
+    let mut report = SimulationReport {
+        scenario: scenario.clone(),
+        samples,
+        correct: 0,
+        wrong: 0,
+        halted: 0,
+        variance_halts: 0,
+        safety_violations: 0,
+    };
+    let tolerated = engine.tolerates(scenario.n, scenario.f);
+    let n = scenario.n as usize;
+    let f = scenario.f as usize;
+
+    for _ in 0..samples {
+        let truth = rng.below(2) == 1;
+        // Every agent's own answer; agents 0..f are Byzantine
+        let mut votes: Vec<bool> = (0..n)
+            .map(|_| truth == rng.chance(scenario.honest_accuracy))
+            .collect();
+        let mut outputs: Vec<u64> = (0..n)
+            .map(|_| OUTPUT_CENTER + rng.below(OUTPUT_NOISE + 1))
+            .collect();
+        let honest_unanimous = votes[f..]
+            .first()
+            .copied()
+            .filter(|&value| votes[f..].iter().all(|&vote| vote == value));
+
+        match scenario.attack {
+            Attack::None => {}
+            Attack::RandomFlip { rate } => {
+                for vote in &mut votes[..f] {
+                    *vote ^= rng.chance(rate);
+                }
+            }
+            Attack::CoordinatedFlip { rate } => {
+                if rng.chance(rate) {
+                    votes[..f].fill(!truth);
+                }
+            }
+            Attack::StealthPoison { rate } => {
+                for output in &mut outputs[..f] {
+                    if rng.chance(rate) {
+                        *output += POISON_OFFSET;
+                    }
+                }
+            }
+            Attack::Collusion => {
+                votes[..f].fill(!truth);
+                outputs[..f].fill(OUTPUT_CENTER + OUTPUT_NOISE / 2);
+            }
+        }
+
+        match engine.final_verdict(&votes, &outputs, BASELINE_VARIANCE_SCALED) {
+            ConsensusOutcome::Agreed { value, .. } => {
+                if value == truth {
+                    report.correct += 1;
+                } else {
+                    report.wrong += 1;
+                }
+                if tolerated && honest_unanimous == Some(!value) {
+                    report.safety_violations += 1;
+                }
+            }
+            ConsensusOutcome::Halted { reason } => {
+                report.halted += 1;
+                if let HaltReason::VarianceExceeded { .. } = reason {
+                    report.variance_halts += 1;
+                }
+            }
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(scenario: &Scenario, seed: u64) -> SimulationReport {
+        simulate(scenario, DEFAULT_SAMPLES, seed, &benchmark_engine()).unwrap()
+    }
+
+    /// `value` within `tolerance` of `expected`, both per mille
+    fn near(value: Option<u64>, expected: u64, tolerance: u64) -> bool {
+        value.is_some_and(|value| value.abs_diff(expected) <= tolerance)
+    }
+
+    #[test]
+    fn test_presets_match_calibration() {
+        // The rates are solved from the published figures, so this checks
+        // the simulation hits them, not the benchmark. Wide tolerances:
+        // several seeds, none tuned
+        for seed in [DEFAULT_SEED, 1, 0xdead_beef] {
+            let preset = |name| run(&Scenario::preset(name).unwrap(), seed).results();
+            let baseline = preset("baseline");
+            let byz33 = preset("byz33");
+            let byz67 = preset("byz67");
+            assert!(near(baseline.accuracy(), 928, 40), "{:?}", baseline);
+            assert!(near(byz33.accuracy(), 830, 50), "{:?}", byz33);
+            assert!(near(byz67.halt_rate(), 578, 60), "{:?}", byz67);
+            assert!(byz67.accuracy() < byz33.accuracy());
+
+            assert_eq!(byz33.scenario, evidence::SCENARIO_BYZANTINE_33);
+            assert_eq!(byz67.total, DEFAULT_SAMPLES);
+        }
+
+        // At exactly 2/3 two of three agents agree: the flips no longer halt
+        let engine = ConsensusEngine::default();
+        let byz67 = Scenario::preset("byz67").unwrap();
+        let report = simulate(&byz67, DEFAULT_SAMPLES, DEFAULT_SEED, &engine).unwrap();
+        assert_eq!(report.halted, report.variance_halts);
+        assert!(report.correct > run(&byz67, DEFAULT_SEED).correct);
+    }
+
+    #[test]
+    fn test_no_safety_violations() {
+        // byzantine_safety: with f tolerated, agreements never overturn a
+        // unanimous honest vote, whatever the attack
+        let attacks = [
+            Attack::None,
+            Attack::RandomFlip { rate: 500 },
+            Attack::CoordinatedFlip { rate: 1000 },
+            Attack::StealthPoison { rate: 300 },
+            Attack::Collusion,
+        ];
+        let engine = ConsensusEngine::default();
+        for n in 1..=12 {
+            for f in 0..=n {
+                for attack in attacks {
+                    let scenario = Scenario {
+                        name: "sweep".to_string(),
+                        n,
+                        f,
+                        attack,
+                        honest_accuracy: 900,
+                    };
+                    let report = simulate(&scenario, 200, n * 100 + f, &engine).unwrap();
+                    assert_eq!(
+                        report.correct + report.wrong + report.halted,
+                        report.samples
+                    );
+                    if engine.tolerates(n, f) {
+                        assert_eq!(report.safety_violations, 0, "{:?}", scenario);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_attacks() {
+        let preset = |name| Scenario::preset(name).unwrap();
+
+        // Stealth poisoning never turns a vote; the variance halt catches it
+        let stealth = run(&preset("stealth"), DEFAULT_SEED);
+        assert!(stealth.variance_halts > 0);
+        assert!(near(stealth.results().accuracy(), 834, 60), "{:?}", stealth);
+        assert_eq!(run(&preset("baseline"), DEFAULT_SEED).variance_halts, 0);
+
+        // A colluding third blocks agreement at 67% unless the honest
+        // agents err with it
+        let collusion = run(&preset("collusion"), DEFAULT_SEED);
+        assert_eq!(collusion.correct, 0);
+        assert!(collusion.wrong < 10, "{:?}", collusion);
+
+        // At exactly 2/3 the honest pair outvotes it
+        let engine = ConsensusEngine::default();
+        let report = simulate(&preset("collusion"), DEFAULT_SAMPLES, DEFAULT_SEED, &engine);
+        assert!(report.unwrap().correct > DEFAULT_SAMPLES * 9 / 10);
+
+        // Ten agents outvote three colluders
+        let collusion = Scenario {
+            n: 10,
+            f: 3,
+            honest_accuracy: 1000,
+            ..preset("collusion")
+        };
+        let report = run(&collusion, DEFAULT_SEED);
+        assert_eq!(report.correct, DEFAULT_SAMPLES);
+
+        // Two of three always flipping together decide nothing right
+        let byz67 = Scenario {
+            attack: Attack::CoordinatedFlip { rate: 1000 },
+            ..preset("byz67")
+        };
+        assert_eq!(run(&byz67, DEFAULT_SEED).correct, 0);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let scenario = Scenario::preset("byz33").unwrap();
+        assert_eq!(run(&scenario, 7), run(&scenario, 7));
+        assert_ne!(run(&scenario, 7), run(&scenario, 8));
+        assert!(PRESETS.iter().all(|name| Scenario::preset(name).is_some()));
+        assert_eq!(Scenario::preset("byz50"), None);
+    }
+
+    #[test]
+    fn test_results_load_as_empirical() {
+        let results: Vec<EmpiricalResults> = PRESETS
+            .iter()
+            .map(|name| run(&Scenario::preset(name).unwrap(), DEFAULT_SEED).results())
+            .collect();
+        let json = serde_json::to_string(&results).unwrap();
+        assert_eq!(evidence::from_json(&json).unwrap(), results);
+    }
+
+    #[test]
+    fn test_rejects_invalid_scenarios() {
+        let engine = ConsensusEngine::default();
+        let scenario = Scenario::preset("byz33").unwrap();
+        let invalid = |scenario: Scenario| simulate(&scenario, 1, 1, &engine).unwrap_err();
+
+        assert_eq!(
+            invalid(Scenario {
+                n: 0,
+                f: 0,
+                ..scenario.clone()
+            }),
+            SimulationError::NoAgents
+        );
+        assert_eq!(
+            invalid(Scenario {
+                f: 4,
+                ..scenario.clone()
+            })
+            .to_string(),
+            "4 Byzantine agents among 3"
+        );
+        assert_eq!(
+            invalid(Scenario {
+                attack: Attack::RandomFlip { rate: 1001 },
+                ..scenario.clone()
+            }),
+            SimulationError::RateOutOfRange { rate: 1001 }
+        );
+        assert_eq!(
+            invalid(Scenario {
+                honest_accuracy: 2000,
+                ..scenario
+            }),
+            SimulationError::RateOutOfRange { rate: 2000 }
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! # Solver Statistics
+//!
+//! Parses prover performance data so slow proofs can be diagnosed before they
+//! start timing out.
+//!
+//! With `--solver-stats` the runner passes `--time-expanded --output-json
+//! --profile-all` to Verus and reads two kinds of output:
+//!
+//! - the JSON `function-breakdown` entries (rlimit consumed per function)
+//! - the quantifier profile notes (`Instantiated N times`) with their source
+//!   locations
+//!
+//! Both parsers skip anything they do not recognize, so a change in the
+//! prover's output format degrades to "no statistics" rather than an error.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::cmp::Reverse;
+use std::path::PathBuf;
+
+#[cfg(feature = "serde")]
+use serde_json::Value;
+
+use crate::diagnostics::{self, Level};
+use crate::source_scan;
+
+/// Z3 resource units per unit of Verus's `--rlimit` setting
+pub const RLIMIT_UNITS: u64 = 3_000_000;
+
+/// Verus's default `--rlimit`
+pub const DEFAULT_RLIMIT: u64 = 10;
+
+/// Quantifiers listed per function
+pub const TOP_QUANTIFIERS: usize = 3;
+
+/// Extra Verus arguments that make it emit statistics
+pub fn verus_args() -> [&'static str; 3] {
+    ["--time-expanded", "--output-json", "--profile-all"]
+}
+
+/// Instantiation count for one user-level quantifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuantifierStats {
+    pub file: PathBuf,
+    pub line: usize,
+    pub instantiations: u64,
+    /// Per-instantiation cost, if reported
+    pub cost: Option<u64>,
+}
+
+/// Solver usage for one verified function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionStats {
+    /// Function name without its module path
+    pub name: String,
+    /// Z3 resource units consumed
+    pub rlimit: u64,
+    pub time_ms: Option<u64>,
+    /// Quantifiers declared in this function, most instantiated first
+    pub top_quantifiers: Vec<QuantifierStats>,
+}
+
+impl FunctionStats {
+    /// Fraction of the rlimit budget consumed
+    pub fn rlimit_fraction(&self, rlimit: u64) -> f64 {
+        self.rlimit as f64 / (rlimit * RLIMIT_UNITS) as f64
+    }
+}
+
+/// Statistics parsed from one prover run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SolverStats {
+    /// Per-function usage, highest rlimit first
+    pub functions: Vec<FunctionStats>,
+    /// Quantifiers not attributed to a function, most instantiated first
+    pub quantifiers: Vec<QuantifierStats>,
+}
+
+impl SolverStats {
+    /// Parse prover output. Quantifiers are attributed to the `proof fn`
+    /// they appear in using `source`, the module's proof file.
+    pub fn parse(output: &str, source: &str) -> Self {
+        let mut functions = parse_function_breakdown(output);
+        functions.sort_by_key(|f| Reverse(f.rlimit));
+
+        let mut quantifiers = parse_quantifier_profile(output);
+        quantifiers.sort_by_key(|q| Reverse(q.instantiations));
+
+        let declarations = source_scan::proof_fn_lines(source);
+        let mut unattributed = Vec::new();
+        for quantifier in quantifiers {
+            let owner = source_scan::enclosing_proof_fn(&declarations, quantifier.line)
+                .and_then(|name| functions.iter_mut().find(|f| f.name == name));
+            match owner {
+                Some(function) if function.top_quantifiers.len() < TOP_QUANTIFIERS => {
+                    function.top_quantifiers.push(quantifier)
+                }
+                Some(_) => {}
+                None => unattributed.push(quantifier),
+            }
+        }
+
+        Self {
+            functions,
+            quantifiers: unattributed,
+        }
+    }
+
+    /// Nothing was recognized in the output
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty() && self.quantifiers.is_empty()
+    }
+
+    /// Functions consuming more than `threshold` (0.0-1.0) of the rlimit budget
+    pub fn near_limit(&self, rlimit: u64, threshold: f64) -> Vec<&FunctionStats> {
+        self.functions
+            .iter()
+            .filter(|f| f.rlimit_fraction(rlimit) > threshold)
+            .collect()
+    }
+}
+
+/// Every `{"function": ..., "rlimit": ...}` object in the JSON output
+#[cfg(feature = "serde")]
+fn parse_function_breakdown(output: &str) -> Vec<FunctionStats> {
+    let mut functions = Vec::new();
+    let mut offset = 0;
+    for line in output.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        if !line.trim_start().starts_with('{') {
+            continue;
+        }
+        let mut stream = serde_json::Deserializer::from_str(&output[start..]).into_iter::<Value>();
+        if let Some(Ok(value)) = stream.next() {
+            collect_functions(&value, &mut functions);
+            break;
+        }
+    }
+    functions
+}
+
+/// Without `serde` the JSON breakdown is not read; only the quantifier
+/// profile is reported
+#[cfg(not(feature = "serde"))]
+fn parse_function_breakdown(_output: &str) -> Vec<FunctionStats> {
+    Vec::new()
+}
+
+#[cfg(feature = "serde")]
+fn collect_functions(value: &Value, functions: &mut Vec<FunctionStats>) {
+    match value {
+        Value::Object(map) => {
+            let name = map.get("function").and_then(Value::as_str);
+            let rlimit = map.get("rlimit").and_then(Value::as_u64);
+            if let (Some(name), Some(rlimit)) = (name, rlimit) {
+                functions.push(FunctionStats {
+                    name: name.rsplit("::").next().unwrap_or(name).to_string(),
+                    rlimit,
+                    time_ms: map.get("time").and_then(Value::as_u64),
+                    top_quantifiers: Vec::new(),
+                });
+            }
+            map.values().for_each(|v| collect_functions(v, functions));
+        }
+        Value::Array(items) => items.iter().for_each(|v| collect_functions(v, functions)),
+        _ => {}
+    }
+}
+
+/// Quantifier profile notes: `Cost * Instantiations: ... (Instantiated N times ..., cost C)`
+fn parse_quantifier_profile(output: &str) -> Vec<QuantifierStats> {
+    diagnostics::parse(output)
+        .into_iter()
+        .filter(|d| d.level == Level::Note)
+        .filter_map(|note| {
+            let instantiations = number_after(&note.message, "Instantiated ")?;
+            let span = note.primary_span()?;
+            Some(QuantifierStats {
+                file: span.file.clone(),
+                line: span.line,
+                instantiations,
+                cost: number_after(&note.message, "cost "),
+            })
+        })
+        .collect()
+}
+
+/// Parse the integer (with optional `,` separators) following `marker`
+fn number_after(text: &str, marker: &str) -> Option<u64> {
+    let rest = &text[text.find(marker)? + marker.len()..];
+    let digits: String = rest
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',')
+        .filter(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    fn fixture(name: &str) -> String {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/fixtures/solver_stats")
+            .join(name);
+        fs::read_to_string(path).unwrap()
+    }
+
+    /// Proof source with declarations at the lines the profile fixture refers to
+    fn trust_bounds() -> String {
+        format!(
+            "{}proof fn update_sequence_preserves_bounds(\n{}proof fn weighted_consensus_well_defined(\n",
+            "\n".repeat(303),
+            "\n".repeat(30)
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_function_breakdown() {
+        let stats = SolverStats::parse(&fixture("time_expanded.json"), "");
+        let names: Vec<&str> = stats.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "ema_preserves_bounds",
+                "update_sequence_preserves_bounds",
+                "clamp_guarantees_bounds"
+            ]
+        );
+        assert_eq!(stats.functions[0].rlimit, 26_150_412);
+        assert_eq!(stats.functions[0].time_ms, Some(2410));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_quantifier_attribution() {
+        let output = format!(
+            "{}\n{}",
+            fixture("time_expanded.json"),
+            fixture("profile.txt")
+        );
+        let stats = SolverStats::parse(&output, &trust_bounds());
+
+        let update = stats
+            .functions
+            .iter()
+            .find(|f| f.name == "update_sequence_preserves_bounds")
+            .unwrap();
+        assert_eq!(update.top_quantifiers.len(), 1);
+        assert_eq!(update.top_quantifiers[0].instantiations, 17_400);
+        assert_eq!(update.top_quantifiers[0].cost, Some(3));
+
+        // weighted_consensus_well_defined has no breakdown entry
+        assert_eq!(stats.quantifiers.len(), 1);
+        assert_eq!(stats.quantifiers[0].line, 342);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_stats_threshold() {
+        let stats = SolverStats::parse(&fixture("time_expanded.json"), "");
+        // Budget at the default rlimit is 30M units
+        let flagged: Vec<&str> = stats
+            .near_limit(DEFAULT_RLIMIT, 0.8)
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(flagged, ["ema_preserves_bounds"]);
+        assert_eq!(stats.near_limit(DEFAULT_RLIMIT, 0.25).len(), 2);
+        assert!(stats.near_limit(100, 0.8).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_format_degrades() {
+        let stats = SolverStats::parse(&fixture("unknown_format.txt"), &trust_bounds());
+        assert!(stats.is_empty());
+        assert!(SolverStats::parse("{ not json", "").is_empty());
+    }
+
+    #[test]
+    fn test_number_after() {
+        assert_eq!(
+            number_after("Instantiated 17,400 times", "Instantiated "),
+            Some(17_400)
+        );
+        assert_eq!(number_after("cost 3) top", "cost "), Some(3));
+        assert_eq!(number_after("no number", "cost "), None);
+    }
+}
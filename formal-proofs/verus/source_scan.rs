@@ -0,0 +1,418 @@
+//! # Proof Source Scanning
+//!
+//! Lightweight static inspection of Verus proof files.
+//!
+//! Counts theorems and axioms without invoking the prover, so the runner can
+//! report proof inventory even when Verus is not installed.
+//!
+//! ## Conventions
+//! - Every `proof fn` is a theorem or lemma
+//! - A `proof fn` named `axiom_*` is an axiom (accepted via `assume(false)`)
+//! - `#[verifier::external]`, `#[verifier::external_body]` and
+//!   `#[verifier::external_fn_specification]` are escapes: the prover trusts
+//!   the item's specification without checking its body
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fs;
+use std::path::Path;
+
+use crate::error::ShieldError;
+
+/// Proof inventory of a single source file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProofInventory {
+    /// Names of proven theorems and lemmas
+    pub theorems: Vec<String>,
+    /// Names of axioms
+    pub axioms: Vec<String>,
+    /// Items trusted via `#[verifier::external*]` attributes
+    pub escapes: Vec<Escape>,
+}
+
+/// Verifier attribute that exempts an item from verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeKind {
+    /// `#[verifier::external]`: item is ignored by Verus entirely
+    External,
+    /// `#[verifier::external_body]`: specification trusted, body unchecked
+    ExternalBody,
+    /// `#[verifier::external_fn_specification]`: specification for external code
+    ExternalFnSpecification,
+}
+
+impl EscapeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EscapeKind::External => "external",
+            EscapeKind::ExternalBody => "external_body",
+            EscapeKind::ExternalFnSpecification => "external_fn_specification",
+        }
+    }
+
+    /// Match a normalized attribute body (whitespace removed), accepting both
+    /// `verifier::external_body` and the older `verifier(external_body)` form
+    fn from_attribute(attribute: &str) -> Option<Self> {
+        let name = attribute
+            .strip_prefix("verifier::")
+            .or_else(|| attribute.strip_prefix("verifier(")?.strip_suffix(')'))?;
+        match name {
+            "external" => Some(EscapeKind::External),
+            "external_body" => Some(EscapeKind::ExternalBody),
+            "external_fn_specification" => Some(EscapeKind::ExternalFnSpecification),
+            _ => None,
+        }
+    }
+}
+
+/// An escape attribute occurrence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Escape {
+    pub kind: EscapeKind,
+    /// Name of the annotated item (`fn`, `struct`, ...), if one follows
+    pub item: Option<String>,
+    /// 1-based line of the attribute
+    pub line: usize,
+}
+
+impl ProofInventory {
+    /// Scan proof source text
+    pub fn scan(source: &str) -> Self {
+        let mut inventory = Self::default();
+
+        for (name, _) in proof_fn_lines(source) {
+            if name.starts_with("axiom_") {
+                inventory.axioms.push(name);
+            } else {
+                inventory.theorems.push(name);
+            }
+        }
+
+        inventory.escapes = scan_escapes(source);
+        inventory
+    }
+
+    /// Scan a proof file on disk
+    pub fn scan_file(path: &Path) -> Result<Self, ShieldError> {
+        Ok(Self::scan(&fs::read_to_string(path)?))
+    }
+}
+
+/// Every `proof fn` (theorems and axioms) with its 1-based declaration line
+pub fn proof_fn_lines(source: &str) -> Vec<(String, usize)> {
+    source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim_start().starts_with("//"))
+        .filter_map(|(i, line)| Some((proof_fn_name(line.trim_start())?.to_string(), i + 1)))
+        .collect()
+}
+
+/// The `proof fn` whose body contains `line`: the last one declared at or before it
+pub fn enclosing_proof_fn(declarations: &[(String, usize)], line: usize) -> Option<&str> {
+    declarations
+        .iter()
+        .take_while(|(_, declared)| *declared <= line)
+        .last()
+        .map(|(name, _)| name.as_str())
+}
+
+/// Extract the function name from a `[pub] proof fn name(` declaration line
+fn proof_fn_name(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("pub ").unwrap_or(line);
+    let rest = rest.strip_prefix("proof fn ")?;
+    let end = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    (end > 0).then(|| &rest[..end])
+}
+
+// ============================================================================
+// ESCAPE ATTRIBUTES
+// ============================================================================
+
+/// Find all escape attributes, ignoring comments and string literals.
+///
+/// Attributes may span several lines (`#[verifier::\n external_body]`).
+pub fn scan_escapes(source: &str) -> Vec<Escape> {
+    let code = strip_comments_and_strings(source);
+    let bytes = code.as_bytes();
+    let mut escapes = Vec::new();
+    let mut search = 0;
+
+    while let Some(found) = code[search..].find('#') {
+        let hash = search + found;
+        search = hash + 1;
+
+        let mut open = hash + 1;
+        if bytes.get(open) == Some(&b'!') {
+            open += 1;
+        }
+        while bytes.get(open).is_some_and(|b| b.is_ascii_whitespace()) {
+            open += 1;
+        }
+        if bytes.get(open) != Some(&b'[') {
+            continue;
+        }
+        let Some(close) = matching_bracket(&code, open) else {
+            continue;
+        };
+        let attribute: String = code[open + 1..close]
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        if let Some(kind) = EscapeKind::from_attribute(&attribute) {
+            escapes.push(Escape {
+                kind,
+                item: item_name(&code[close + 1..]),
+                line: code[..hash].matches('\n').count() + 1,
+            });
+        }
+        search = close + 1;
+    }
+
+    escapes
+}
+
+/// Byte index of the `]` closing the `[` at `open`
+fn matching_bracket(code: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in code[open..].char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Name of the first item declared in `rest` (after any further attributes)
+fn item_name(rest: &str) -> Option<String> {
+    const ITEM_KEYWORDS: [&str; 7] = ["fn", "struct", "enum", "trait", "type", "const", "mod"];
+
+    let mut words = rest
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|w| !w.is_empty());
+    while let Some(word) = words.next() {
+        if word == "impl" {
+            return Some("impl".to_string());
+        }
+        if ITEM_KEYWORDS.contains(&word) {
+            return words.next().map(str::to_string);
+        }
+    }
+    None
+}
+
+/// Replace comments and string/char literals with spaces, keeping newlines so
+/// byte offsets map to the same lines as the original source
+fn strip_comments_and_strings(source: &str) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::with_capacity(source.len());
+    let mut i = 0;
+
+    // Blank out chars[from..to], preserving line breaks
+    let blank = |out: &mut String, from: usize, to: usize| {
+        for &c in &chars[from..to] {
+            out.push(if c == '\n' { '\n' } else { ' ' });
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let start = i;
+
+        if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            // Block comments nest in Rust
+            let mut depth = 0;
+            while i < chars.len() {
+                if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                    depth += 1;
+                    i += 2;
+                } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+        } else if let Some(end) = raw_string_end(&chars, i) {
+            i = end;
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(chars.len());
+        } else if c == '\'' {
+            // Char literal ('x', '\n', '\'') vs. lifetime ('a)
+            match (next, chars.get(i + 2), chars.get(i + 3)) {
+                (Some('\\'), _, _) => {
+                    i += 2;
+                    while i < chars.len() && chars[i] != '\'' {
+                        i += 1;
+                    }
+                    i = (i + 1).min(chars.len());
+                }
+                (Some(_), Some('\''), _) => i += 3,
+                _ => {
+                    out.push(c);
+                    i += 1;
+                    continue;
+                }
+            }
+        } else {
+            out.push(c);
+            i += 1;
+            continue;
+        }
+        blank(&mut out, start, i);
+    }
+
+    out
+}
+
+/// End index (exclusive) of a raw string literal `r"..."` / `r#"..."#` at `i`
+fn raw_string_end(chars: &[char], i: usize) -> Option<usize> {
+    if chars[i] != 'r' || (i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '_')) {
+        return None;
+    }
+    let mut j = i + 1;
+    let mut hashes = 0;
+    while chars.get(j) == Some(&'#') {
+        hashes += 1;
+        j += 1;
+    }
+    if chars.get(j) != Some(&'"') {
+        return None;
+    }
+    j += 1;
+    while j < chars.len() {
+        if chars[j] == '"' && (1..=hashes).all(|k| chars.get(j + k) == Some(&'#')) {
+            return Some(j + 1 + hashes);
+        }
+        j += 1;
+    }
+    Some(chars.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+verus! {
+/// AXIOM 1: Correctness
+pub proof fn axiom_correctness(pk: PublicKey)
+    ensures true
+{
+    assume(false);
+}
+
+/// THEOREM 1
+proof fn signature_uniqueness(pk: PublicKey) {}
+
+// proof fn commented_out() {}
+pub open spec fn helper() -> bool { true }
+proof fn lemma_helper<T>(x: T) {}
+} // verus!
+";
+
+    #[test]
+    fn test_scan_counts() {
+        let inventory = ProofInventory::scan(SOURCE);
+        assert_eq!(inventory.axioms, vec!["axiom_correctness"]);
+        assert_eq!(
+            inventory.theorems,
+            vec!["signature_uniqueness", "lemma_helper"]
+        );
+    }
+
+    #[test]
+    fn test_enclosing_proof_fn() {
+        let declarations = proof_fn_lines(SOURCE);
+        assert_eq!(
+            declarations,
+            [
+                ("axiom_correctness".to_string(), 3),
+                ("signature_uniqueness".to_string(), 10),
+                ("lemma_helper".to_string(), 14),
+            ]
+        );
+        assert_eq!(enclosing_proof_fn(&declarations, 1), None);
+        assert_eq!(
+            enclosing_proof_fn(&declarations, 6),
+            Some("axiom_correctness")
+        );
+        assert_eq!(
+            enclosing_proof_fn(&declarations, 10),
+            Some("signature_uniqueness")
+        );
+    }
+
+    #[test]
+    fn test_scan_empty() {
+        assert_eq!(ProofInventory::scan(""), ProofInventory::default());
+    }
+
+    fn fixture(name: &str) -> Vec<Escape> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/fixtures/escapes")
+            .join(name);
+        ProofInventory::scan_file(&path).unwrap().escapes
+    }
+
+    fn escape(kind: EscapeKind, item: &str, line: usize) -> Escape {
+        Escape {
+            kind,
+            item: Some(item.to_string()),
+            line,
+        }
+    }
+
+    #[test]
+    fn test_escape_attribute_forms() {
+        assert_eq!(
+            fixture("external_body.rs"),
+            [
+                escape(EscapeKind::ExternalBody, "trusted_sqrt", 4),
+                escape(EscapeKind::ExternalBody, "legacy_form", 10),
+                escape(EscapeKind::External, "ignored_helper", 15),
+            ]
+        );
+        assert_eq!(
+            fixture("fn_specification.rs"),
+            [escape(EscapeKind::ExternalFnSpecification, "ex_vec_len", 4)]
+        );
+    }
+
+    #[test]
+    fn test_escape_split_across_lines() {
+        assert_eq!(
+            fixture("split_attribute.rs"),
+            [
+                escape(EscapeKind::ExternalBody, "split_path", 4),
+                escape(EscapeKind::ExternalBody, "stacked", 11),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_escape_in_comments_and_strings_ignored() {
+        assert_eq!(fixture("comments_and_strings.rs"), []);
+    }
+}
@@ -0,0 +1,245 @@
+//! # Stage Execution
+//!
+//! Subprocess execution shared by every runner stage (Verus, Prusti, `cargo test`).
+//!
+//! Each stage runs under a wall-clock timeout so a diverging solver or a hung
+//! test binary cannot stall the whole verification run. Output is captured in
+//! full for the stage-specific parsers.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::io::{self, Read};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::ShieldError;
+
+/// Default per-stage timeout (10 minutes)
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Interval between exit checks while a stage is running
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Captured result of a single stage invocation
+#[derive(Debug, Clone)]
+pub struct StageOutput {
+    /// Process exit code (None if killed by signal or timeout)
+    pub exit_code: Option<i32>,
+    /// Process exited successfully before the timeout
+    pub success: bool,
+    /// Captured standard output
+    pub stdout: String,
+    /// Captured standard error
+    pub stderr: String,
+    /// Stage was killed after exceeding its timeout
+    pub timed_out: bool,
+    /// Wall-clock duration of the stage
+    pub duration: Duration,
+}
+
+impl StageOutput {
+    /// Standard output followed by standard error
+    pub fn combined(&self) -> String {
+        format!("{}{}", self.stdout, self.stderr)
+    }
+
+    /// The stage's failure as an error (`Timeout` or `ToolFailed`), or
+    /// None if it succeeded
+    pub fn failure(&self, module: &str) -> Option<ShieldError> {
+        if self.timed_out {
+            Some(ShieldError::Timeout {
+                module: module.to_string(),
+                after: self.duration,
+            })
+        } else if !self.success {
+            Some(ShieldError::ToolFailed {
+                module: module.to_string(),
+                exit_code: self.exit_code,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Run a command to completion, killing it if it exceeds `timeout`.
+///
+/// Returns an error only if the process could not be spawned
+/// (`ToolNotFound` if the tool is not installed) or waited on. A timeout is
+/// reported through `StageOutput::timed_out`.
+pub fn run_with_timeout(
+    command: &mut Command,
+    timeout: Duration,
+) -> Result<StageOutput, ShieldError> {
+    let start = Instant::now();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| ShieldError::spawn(command.get_program().to_string_lossy(), err))?;
+
+    // Drain both pipes on background threads so a chatty process cannot
+    // block on a full pipe while we wait for it.
+    let stdout = spawn_reader(child.stdout.take());
+    let stderr = spawn_reader(child.stderr.take());
+
+    let (status, timed_out) = wait_with_deadline(&mut child, start + timeout)?;
+
+    // After a timeout, grandchildren (e.g. a solver spawned by verus) may still
+    // hold the pipes open, so take whatever was captured instead of joining.
+    Ok(StageOutput {
+        exit_code: status.and_then(|s| s.code()),
+        success: status.is_some_and(|s| s.success()) && !timed_out,
+        stdout: stdout.collect(!timed_out),
+        stderr: stderr.collect(!timed_out),
+        timed_out,
+        duration: start.elapsed(),
+    })
+}
+
+/// Executes stage commands. The library runs every subprocess through this
+/// trait so tests can substitute canned prover and `cargo` output.
+pub trait CommandRunner {
+    /// Run `command` to completion with the semantics of [`run_with_timeout`]
+    fn run(&self, command: &mut Command, timeout: Duration) -> Result<StageOutput, ShieldError>;
+}
+
+/// Runs commands as real subprocesses
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, command: &mut Command, timeout: Duration) -> Result<StageOutput, ShieldError> {
+        run_with_timeout(command, timeout)
+    }
+}
+
+fn wait_with_deadline(
+    child: &mut Child,
+    deadline: Instant,
+) -> io::Result<(Option<std::process::ExitStatus>, bool)> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok((Some(status), false));
+        }
+        if Instant::now() >= deadline {
+            // The process may exit between try_wait and kill; either way
+            // we reap it so no zombie is left behind.
+            let _ = child.kill();
+            child.wait()?;
+            return Ok((None, true));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Background pipe reader accumulating into a shared buffer
+struct PipeReader {
+    handle: thread::JoinHandle<()>,
+    buffer: Arc<Mutex<Vec<u8>>>,
+}
+
+impl PipeReader {
+    /// Captured output; waits for end-of-stream only if `join` is set
+    fn collect(self, join: bool) -> String {
+        if join {
+            let _ = self.handle.join();
+        }
+        let buffer = self.buffer.lock().unwrap_or_else(|e| e.into_inner());
+        String::from_utf8_lossy(&buffer).into_owned()
+    }
+}
+
+fn spawn_reader<R: Read + Send + 'static>(pipe: Option<R>) -> PipeReader {
+    let buffer = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&buffer);
+    let handle = thread::spawn(move || {
+        let Some(mut pipe) = pipe else { return };
+        let mut chunk = [0u8; 8192];
+        while let Ok(n) = pipe.read(&mut chunk) {
+            if n == 0 {
+                break;
+            }
+            sink.lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .extend_from_slice(&chunk[..n]);
+        }
+    });
+    PipeReader { handle, buffer }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_captures_output() {
+        let output = run_with_timeout(
+            Command::new("sh").args(["-c", "echo out; echo err >&2"]),
+            DEFAULT_TIMEOUT,
+        )
+        .unwrap();
+        assert!(output.success);
+        assert_eq!(output.exit_code, Some(0));
+        assert_eq!(output.stdout, "out\n");
+        assert_eq!(output.stderr, "err\n");
+        assert!(!output.timed_out);
+        assert!(output.failure("m").is_none());
+    }
+
+    #[test]
+    fn test_nonzero_exit() {
+        let output =
+            run_with_timeout(Command::new("sh").args(["-c", "exit 3"]), DEFAULT_TIMEOUT).unwrap();
+        assert!(!output.success);
+        assert_eq!(output.exit_code, Some(3));
+        assert!(matches!(
+            output.failure("m"),
+            Some(ShieldError::ToolFailed {
+                exit_code: Some(3),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_timeout_kills_stage() {
+        let output = run_with_timeout(
+            Command::new("sh").args(["-c", "sleep 5"]),
+            Duration::from_millis(100),
+        )
+        .unwrap();
+        assert!(output.timed_out);
+        assert!(!output.success);
+        assert!(output.duration < Duration::from_secs(5));
+        assert!(matches!(
+            output.failure("m"),
+            Some(ShieldError::Timeout { .. })
+        ));
+    }
+
+    #[test]
+    fn test_missing_tool_is_error() {
+        let result = run_with_timeout(
+            &mut Command::new("aevion-definitely-not-installed"),
+            DEFAULT_TIMEOUT,
+        );
+        assert!(matches!(result, Err(ShieldError::ToolNotFound { .. })));
+    }
+
+    #[test]
+    fn test_missing_verus_is_tool_not_found() {
+        let mut command = Command::new("verus");
+        command.env("PATH", "/nonexistent").arg("trust_bounds.rs");
+        match run_with_timeout(&mut command, DEFAULT_TIMEOUT) {
+            Err(ShieldError::ToolNotFound { tool, source }) => {
+                assert_eq!(tool, "verus");
+                assert_eq!(source.kind(), io::ErrorKind::NotFound);
+            }
+            other => panic!("expected ToolNotFound, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! # Test Support
+//!
+//! Exhaustive enumeration shared by the unit tests. Rounds are bitmasks:
+//! bit i of a placement marks voter i Byzantine, bit i of a vote pattern is
+//! voter i's vote. [`byzantine_rounds`] yields every placement of exactly f
+//! Byzantine voters among n together with every vote pattern, so a test
+//! covers every choice the Byzantine voters (and the honest ones) can make.
+//! [`Rng`] draws the randomized tests' inputs, [`evidence`] decides a round
+//! of ballots, and [`signed`] votes carry a stand-in signature [`check`]
+//! accepts.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+#[cfg(feature = "serde")]
+use crate::chain::Hash;
+use crate::consensus::{AgentId, ConsensusEngine};
+#[cfg(feature = "serde")]
+use crate::quorum::SignedVote;
+use crate::round::RoundEvidence;
+
+/// Every `n`-bit mask with exactly `k` bits set, in increasing order
+pub(crate) fn masks(n: u32, k: u32) -> impl Iterator<Item = u32> {
+    assert!(n < 32, "masks of {} bits", n);
+    (0..1u32 << n).filter(move |mask| mask.count_ones() == k)
+}
+
+/// One round: who is Byzantine and how everybody voted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ByzantineRound {
+    /// `byzantine[i]`: voter i is Byzantine
+    pub byzantine: Vec<bool>,
+    pub votes: Vec<bool>,
+}
+
+impl ByzantineRound {
+    /// Honest voters casting `value`
+    pub fn honest_votes(&self, value: bool) -> u64 {
+        self.votes
+            .iter()
+            .zip(&self.byzantine)
+            .filter(|&(&vote, &byzantine)| !byzantine && vote == value)
+            .count() as u64
+    }
+}
+
+/// Every round of `n` voters with exactly `f` Byzantine ones:
+/// C(n, f) placements times 2^n vote patterns
+pub(crate) fn byzantine_rounds(n: u32, f: u32) -> impl Iterator<Item = ByzantineRound> {
+    let bits = move |mask: u32| (0..n).map(|i| mask >> i & 1 == 1).collect::<Vec<_>>();
+    masks(n, f).flat_map(move |placement| {
+        (0..1u32 << n).map(move |cast| ByzantineRound {
+            byzantine: bits(placement),
+            votes: bits(cast),
+        })
+    })
+}
+
+/// xorshift64: deterministic inputs without a dev-dependency
+pub(crate) struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    /// Fisher-Yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+/// Evidence of an unweighted round at the default thresholds
+pub(crate) fn evidence(ballots: &[Option<bool>]) -> RoundEvidence {
+    let per_voter = ballots
+        .iter()
+        .enumerate()
+        .map(|(voter, &ballot)| (voter as AgentId, ballot, 1))
+        .collect();
+    ConsensusEngine::default()
+        .decide_with_evidence(per_voter)
+        .evidence
+}
+
+/// Stand-in for Ed25519: a digest of the voter key and signed bytes
+#[cfg(feature = "serde")]
+pub(crate) fn test_signature(vote: &SignedVote) -> String {
+    let mut data = vote.voter_pubkey.as_bytes().to_vec();
+    data.extend(vote.signed_bytes());
+    Hash::sha256(&data).to_hex()
+}
+
+/// Signature checker accepting exactly the [`test_signature`]s
+#[cfg(feature = "serde")]
+pub(crate) fn check(vote: &SignedVote) -> bool {
+    vote.signature == test_signature(vote)
+}
+
+/// `voter`'s `vote` in `round_id`, signed with [`test_signature`]
+#[cfg(feature = "serde")]
+pub(crate) fn signed(voter: u8, round_id: u64, vote: bool) -> SignedVote {
+    let mut signed = SignedVote {
+        voter_pubkey: format!("{:02x}", voter).repeat(32),
+        vote,
+        round_id,
+        signature: String::new(),
+    };
+    signed.signature = test_signature(&signed);
+    signed
+}
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enumeration_is_complete() {
+        // C(7, 2) = 21 placements, C(10, 3) = 120
+        assert_eq!(masks(7, 2).count(), 21);
+        assert_eq!(masks(10, 3).count(), 120);
+        assert_eq!(masks(4, 0).collect::<Vec<_>>(), vec![0]);
+
+        let rounds: Vec<ByzantineRound> = byzantine_rounds(3, 1).collect();
+        assert_eq!(rounds.len(), 3 * 8);
+        assert!(rounds
+            .iter()
+            .all(|r| r.byzantine.iter().filter(|&&b| b).count() == 1));
+        // Distinct rounds
+        for (i, a) in rounds.iter().enumerate() {
+            assert!(rounds[i + 1..].iter().all(|b| a != b));
+        }
+
+        let round = ByzantineRound {
+            byzantine: vec![true, false, false],
+            votes: vec![true, true, false],
+        };
+        assert_eq!(round.honest_votes(true), 1);
+        assert_eq!(round.honest_votes(false), 1);
+    }
+}
@@ -0,0 +1,3136 @@
+//! # Agent Trust
+//!
+//! Executable counterpart of the executable updates in `trust_bounds.rs`.
+//! [`TrustScore`] holds a trust in `0..=1000` (1000 = 1.0) and
+//! [`AgentTrust`] an agent's trust with its observation history. The
+//! update methods check the preconditions Verus discharges statically and
+//! then compute the verified formulas, so every method keeps the score in
+//! bounds (`ema_preserves_bounds`, `decay_preserves_bounds`,
+//! `boost_preserves_bounds`) and `cumulative_correct <= observations *
+//! 1000` (`lemma_record_preserves_cumulative`).
+//!
+//! The formulas themselves are [`ema_update`], [`trust_decay`] and
+//! [`trust_boost`]. They take unvalidated inputs,
+//! reject any outside the specification's preconditions with a
+//! [`TrustError`], and compute in u128, so no input wraps in a release
+//! build; within the preconditions no intermediate exceeds 1000 * 1000
+//! (`lemma_ema_no_overflow` and friends).
+//!
+//! Besides the EMA an agent keeps its last `window_size` observations
+//! (default [`DEFAULT_WINDOW_SIZE`]). At a small alpha the EMA remembers a
+//! long good record well after an agent turns; the windowed
+//! [`recent_accuracy_scaled`](AgentTrust::recent_accuracy_scaled) does not,
+//! and [`AgentTrust::suspicion`] flags an agent whose trust runs
+//! `divergence_threshold` above it (`window_mean_bounded`).
+//!
+//! The trust is the accuracy component. Calibration and latency are
+//! scored beside it by the same EMA ([`TrustComponent`]), and
+//! [`AgentTrust::composite`] combines the three by weights summing to 1000
+//! (`composite_trust_bounded`).
+//!
+//! [`TrustScore`] is [`ScaledTrust`] at scale 1000. Other scales (10_000
+//! for four places) get the same updates, proven for any scale of at
+//! least 10 (`scaled_updates_preserve_bounds`), and
+//! [`rescale`](ScaledTrust::rescale) converts between them. Likewise
+//! [`AgentTrust`] and [`Observation`] are [`ScaledAgentTrust`] and
+//! [`ScaledObservation`] at 1000: an agent at another scale keeps its
+//! trust, observations and window at that scale, and reads the
+//! [`Thresholds`] it is given, which stay in thousandths, rescaled to it.
+//!
+//! Observations are [`Observation`]s, validated when made
+//! (`observation_valid`), so a caller cannot pass a percentage or another
+//! scale where the agent expects thousandths.
+//!
+//! ```
+//! use aevion_shield::trust::{AgentTrust, Observation};
+//!
+//! let mut trust = AgentTrust::builder().current(800).build().unwrap();
+//! trust.record_observation(Observation::correct(), 300).unwrap();
+//! assert_eq!(trust.current().value(), 860);
+//! trust.decay(100).unwrap();
+//! assert_eq!(trust.current().value(), 774);
+//! ```
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::consensus::AgentId;
+use crate::models::{ModelSpec, MAX_MODEL_WEIGHT, MIN_MODEL_WEIGHT};
+use crate::Thresholds;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Observations an agent can record before `cumulative_correct` could
+/// overflow a u64 (`MAX_OBSERVATIONS` in the specification)
+pub const MAX_OBSERVATIONS: u64 = u64::MAX / 1000;
+
+/// Recent observations an agent keeps unless built with another
+/// `window_size`
+pub const DEFAULT_WINDOW_SIZE: usize = 50;
+
+/// A trust value, update input or history the trust types cannot hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustError {
+    /// A trust, observation or rate above 1000
+    OutOfRange { name: &'static str, value: u64 },
+    /// A trust, observation or rate above the scale of a [`ScaledTrust`]
+    /// other than 1000
+    OutOfScale {
+        name: &'static str,
+        value: u64,
+        scale: u64,
+    },
+    /// The agent has recorded [`MAX_OBSERVATIONS`] observations, or
+    /// [`ScaledAgentTrust::MAX_OBSERVATIONS`] at another scale
+    ObservationLimit,
+    /// More cumulative correctness than full trust per observation
+    InconsistentHistory {
+        observations: u64,
+        cumulative_correct: u64,
+    },
+    /// A window of zero observations
+    EmptyWindow,
+    /// More recent observations than the window holds
+    WindowOverflow { window_size: usize, recent: usize },
+    /// Component weights that do not sum to 1000
+    WeightSum { sum: u128 },
+    /// A decreasing alpha schedule clipped below its own minimum
+    InvertedSchedule { alpha_min: u64, alpha_max: u64 },
+    /// A carried boost remainder of 1000 thousandths or more, or any at
+    /// full trust
+    Remainder { current: u64, remainder: u64 },
+    /// A percentage above 100
+    Percent { value: u64 },
+    /// A graded score above the task's weight, or a task of weight 0
+    Grade { score: u64, weight: u64 },
+}
+
+impl fmt::Display for TrustError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrustError::OutOfRange { name, value } => {
+                write!(f, "{} = {} is outside 0..=1000", name, value)
+            }
+            TrustError::OutOfScale { name, value, scale } => {
+                write!(f, "{} = {} is outside 0..={}", name, value, scale)
+            }
+            TrustError::ObservationLimit => {
+                write!(
+                    f,
+                    "agent has recorded as many observations as its scale allows"
+                )
+            }
+            TrustError::InconsistentHistory {
+                observations,
+                cumulative_correct,
+            } => write!(
+                f,
+                "cumulative correctness {} exceeds full trust per observation over {} observations",
+                cumulative_correct, observations
+            ),
+            TrustError::EmptyWindow => write!(f, "window_size must be at least 1"),
+            TrustError::WeightSum { sum } => {
+                write!(f, "component weights sum to {}, not 1000", sum)
+            }
+            TrustError::InvertedSchedule {
+                alpha_min,
+                alpha_max,
+            } => write!(f, "alpha_min {} exceeds alpha_max {}", alpha_min, alpha_max),
+            TrustError::Remainder { current, remainder } => write!(
+                f,
+                "remainder {} over trust {} must be below 1000, and 0 at trust 1000",
+                remainder, current
+            ),
+            TrustError::Percent { value } => {
+                write!(f, "percent = {} is outside 0..=100", value)
+            }
+            TrustError::Grade { score, weight } => write!(
+                f,
+                "score {} out of {} is not a grade (needs 0 < weight and score <= weight)",
+                score, weight
+            ),
+            TrustError::WindowOverflow {
+                window_size,
+                recent,
+            } => write!(
+                f,
+                "{} recent observations exceed a window of {}",
+                recent, window_size
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TrustError {}
+
+/// `value` if at most `scale`; at 1000 the error is the unscaled
+/// [`TrustError::OutOfRange`]
+fn in_scale(name: &'static str, value: u64, scale: u64) -> Result<u64, TrustError> {
+    match value {
+        _ if value <= scale => Ok(value),
+        _ if scale == 1000 => Err(TrustError::OutOfRange { name, value }),
+        _ => Err(TrustError::OutOfScale { name, value, scale }),
+    }
+}
+
+/// `value`, in thousandths, rescaled to `scale` rounding half up as
+/// [`ScaledTrust::rescale`] does: the identity at 1000. A threshold above
+/// 1000, which [`Thresholds::validate`](crate::Thresholds::validate)
+/// rejects, saturates rather than wrapping.
+pub(crate) fn thousandths_at(scale: u64, value: u64) -> u64 {
+    let scaled = (u128::from(value) * u128::from(scale) + 500) / 1000;
+    u64::try_from(scaled).unwrap_or(u64::MAX)
+}
+
+/// `value` if at most 1000
+fn in_range(name: &'static str, value: u64) -> Result<u64, TrustError> {
+    if value <= 1000 {
+        Ok(value)
+    } else {
+        Err(TrustError::OutOfRange { name, value })
+    }
+}
+
+/// How [`ema_update`] rounds the division by 1000 (`Rounding` in the
+/// specification)
+///
+/// Truncation drifts down: updates toward a constant observation stop short
+/// of it (999 toward 1000 at alpha 0.3 stays 999). Rounding half up stops
+/// short by less, and rounding toward the observation reaches it within
+/// one update per unit of distance (`toward_observation_converges`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Rounding {
+    /// Down, as the specification's `ema_update`
+    Truncate,
+    /// To nearest, halves up
+    #[default]
+    HalfUp,
+    /// Up when the observation is above the trust, down otherwise
+    TowardObservation,
+}
+
+/// How [`AgentTrust::record_scheduled`] picks each observation's alpha
+/// (`trust_after_scheduled` in the specification)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum AlphaSchedule {
+    /// The same alpha for every observation
+    Fixed { alpha: u64 },
+    /// `1000 / (k + 1)` for the observation after `k` others, rounded to
+    /// nearest and clipped to `alpha_min..=alpha_max` (`decreasing_alpha`).
+    /// Unclipped the trust is the running mean of the observations, up to
+    /// the rounding of each alpha to a thousandth; `alpha_min` keeps a
+    /// long-lived agent's trust moving.
+    Decreasing { alpha_min: u64, alpha_max: u64 },
+}
+
+impl AlphaSchedule {
+    /// Check that every alpha the schedule picks is at most 1000
+    /// (`scheduled_sequence_preserves_bounds`)
+    pub fn validate(&self) -> Result<(), TrustError> {
+        self.validate_at(1000)
+    }
+
+    /// [`validate`](Self::validate) for an agent at `scale`, which reads
+    /// the alphas scaled by `scale`
+    fn validate_at(&self, scale: u64) -> Result<(), TrustError> {
+        match *self {
+            AlphaSchedule::Fixed { alpha } => {
+                in_scale("alpha", alpha, scale)?;
+            }
+            AlphaSchedule::Decreasing {
+                alpha_min,
+                alpha_max,
+            } => {
+                in_scale("alpha_max", alpha_max, scale)?;
+                if alpha_min > alpha_max {
+                    return Err(TrustError::InvertedSchedule {
+                        alpha_min,
+                        alpha_max,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Alpha for the observation after `observations` others
+    pub fn alpha(&self, observations: u64) -> u64 {
+        self.alpha_at(1000, observations)
+    }
+
+    /// [`alpha`](Self::alpha) at `scale`: a decreasing schedule starts
+    /// from `scale / (k + 1)`
+    fn alpha_at(&self, scale: u64, observations: u64) -> u64 {
+        match *self {
+            AlphaSchedule::Fixed { alpha } => alpha,
+            AlphaSchedule::Decreasing {
+                alpha_min,
+                alpha_max,
+            } => {
+                let k = u128::from(observations);
+                let alpha = ((2 * u128::from(scale) + k + 1) / (2 * (k + 1))) as u64;
+                alpha.max(alpha_min).min(alpha_max)
+            }
+        }
+    }
+}
+
+/// `ema_update_rounded`: `(alpha * observation + (1000 - alpha) * current)
+/// / 1000`, all scaled by 1000 and rounded by `rounding`; between `current`
+/// and `observation` in every mode (`ema_rounded_preserves_bounds`).
+/// Truncating, it lands less than a unit below the exact value
+/// (`ema_rounding_error`), and k updates land less than k units below the
+/// real-valued EMA of the same observations (`ema_drift_bounded`).
+pub fn ema_update(
+    current: u64,
+    observation: u64,
+    alpha: u64,
+    rounding: Rounding,
+) -> Result<u64, TrustError> {
+    ema_update_at(1000, current, observation, alpha, rounding)
+}
+
+/// `ema_update_at`: [`ema_update`] with everything scaled by `scale`
+/// instead of 1000. The products reach `scale * scale`, which a u64 holds
+/// only up to a scale of 2^32 - 1, so they are computed in u128
+/// (`scaled_updates_no_overflow`).
+fn ema_update_at(
+    scale: u64,
+    current: u64,
+    observation: u64,
+    alpha: u64,
+    rounding: Rounding,
+) -> Result<u64, TrustError> {
+    let current = u128::from(in_scale("trust", current, scale)?);
+    let observation = u128::from(in_scale("observation", observation, scale)?);
+    let alpha = u128::from(in_scale("alpha", alpha, scale)?);
+    let scale = u128::from(scale);
+    let numerator = alpha * observation + (scale - alpha) * current;
+    let rounded = match rounding {
+        Rounding::Truncate => numerator / scale,
+        Rounding::HalfUp => (numerator + scale / 2) / scale,
+        Rounding::TowardObservation if observation >= current => numerator.div_ceil(scale),
+        Rounding::TowardObservation => numerator / scale,
+    };
+    // At most scale (ema_rounded_preserves_bounds,
+    // scaled_updates_preserve_bounds)
+    Ok(rounded as u64)
+}
+
+/// `trust_decay`: `current * (1000 - decay_rate) / 1000`, never more than
+/// `current`
+pub fn trust_decay(current: u64, decay_rate: u64) -> Result<u64, TrustError> {
+    // current - ceil(current * decay_rate / 1000): below current unless
+    // either is 0 (decay_fixed_points)
+    trust_decay_at(1000, current, decay_rate)
+}
+
+/// `trust_decay_at`: [`trust_decay`] at `scale`
+fn trust_decay_at(scale: u64, current: u64, decay_rate: u64) -> Result<u64, TrustError> {
+    let current = u128::from(in_scale("trust", current, scale)?);
+    let decay_rate = u128::from(in_scale("decay_rate", decay_rate, scale)?);
+    let scale = u128::from(scale);
+    Ok((current * (scale - decay_rate) / scale) as u64)
+}
+
+/// `trust_decay_floored`: [`trust_decay`] stopping at `floor`, never below
+/// it from a trust at or above it and never raising a trust already below
+/// it (`floored_decay_bounds`, `floored_decay_non_increasing`). At a
+/// positive rate it strictly lowers every trust above the floor and leaves
+/// the rest unchanged (`floored_decay_fixed_points`).
+pub fn trust_decay_floored(current: u64, decay_rate: u64, floor: u64) -> Result<u64, TrustError> {
+    trust_decay_floored_at(1000, current, decay_rate, floor)
+}
+
+/// [`trust_decay_floored`] at `scale`: `trust_decay_at` stopping at
+/// `floor`
+fn trust_decay_floored_at(
+    scale: u64,
+    current: u64,
+    decay_rate: u64,
+    floor: u64,
+) -> Result<u64, TrustError> {
+    let floor = in_scale("trust_floor", floor, scale)?;
+    let decayed = trust_decay_at(scale, current, decay_rate)?;
+    Ok(if decayed >= floor {
+        decayed
+    } else {
+        current.min(floor)
+    })
+}
+
+/// `decay_rounds`: [`trust_decay_floored`] once for each of `rounds`
+/// rounds. Above the floor each decay at a positive rate takes at least a
+/// unit off, so the trust reaches the floor within `current - floor`
+/// rounds and stays there (`inactivity_decay_settles`): this iterates at
+/// most 1000 times however large `rounds` is, and returns exactly the
+/// iterated result. A trust already below the floor is unchanged.
+pub fn trust_decay_rounds(
+    current: u64,
+    decay_rate: u64,
+    floor: u64,
+    rounds: u64,
+) -> Result<u64, TrustError> {
+    trust_decay_rounds_at(1000, current, decay_rate, floor, rounds)
+}
+
+/// [`trust_decay_rounds`] at `scale`, iterating at most `scale` times
+fn trust_decay_rounds_at(
+    scale: u64,
+    current: u64,
+    decay_rate: u64,
+    floor: u64,
+    rounds: u64,
+) -> Result<u64, TrustError> {
+    let mut trust = in_scale("trust", current, scale)?;
+    in_scale("decay_rate", decay_rate, scale)?;
+    let floor = in_scale("trust_floor", floor, scale)?;
+    // At rate 0 every decay is the identity, so stopping early is exact too
+    for _ in 0..rounds.min(trust.saturating_sub(floor)) {
+        trust = trust_decay_floored_at(scale, trust, decay_rate, floor)?;
+    }
+    Ok(trust)
+}
+
+/// Disagreements, each decaying trust by `decay_rate` ([`trust_decay`]),
+/// before an agent at `initial` trust falls below `threshold`
+/// (`decay_steps_to`): the smallest such count, 0 if already below, and
+/// `None` if it never does (a zero rate or threshold). It is at most
+/// `initial - threshold + 1` (`byzantine_detection_via_trust`), and the
+/// agent stays below through any further disagreements.
+///
+/// Truncation makes each decay lose a little more than the real-valued
+/// `initial * (1 - rate)^k`, so low trust is flagged sooner than that
+/// formula predicts.
+pub fn decay_steps_to(
+    initial: u64,
+    threshold: u64,
+    decay_rate: u64,
+) -> Result<Option<u64>, TrustError> {
+    let mut trust = in_range("trust", initial)?;
+    in_range("detection_threshold", threshold)?;
+    in_range("decay_rate", decay_rate)?;
+    if trust >= threshold && (threshold == 0 || decay_rate == 0) {
+        return Ok(None);
+    }
+    // Each decay loses at least one unit, so this runs at most 1000 times
+    let mut steps = 0;
+    while trust >= threshold {
+        trust = trust_decay(trust, decay_rate)?;
+        steps += 1;
+    }
+    Ok(Some(steps))
+}
+
+/// `trust_boost`: `current` plus `boost_rate` of the gap to 1000, never
+/// less than `current` nor more than 1000
+///
+/// The boost rounds down, less than one unit below the real-valued boost
+/// (`boost_rounding_error`). Repeated boosts stop where the rounded boost
+/// is zero, `gap * boost_rate < 1000`: 981 at 5%. [`trust_boost_exact`]
+/// does not.
+pub fn trust_boost(current: u64, boost_rate: u64) -> Result<u64, TrustError> {
+    trust_boost_at(1000, current, boost_rate)
+}
+
+/// `trust_boost_at`: [`trust_boost`] at `scale`
+fn trust_boost_at(scale: u64, current: u64, boost_rate: u64) -> Result<u64, TrustError> {
+    let current = u128::from(in_scale("trust", current, scale)?);
+    let boost_rate = u128::from(in_scale("boost_rate", boost_rate, scale)?);
+    let scale = u128::from(scale);
+    let boosted = current + (scale - current) * boost_rate / scale;
+    // clamp_trust; boosted is at most scale already
+    // (lemma_boost_no_overflow, scaled_updates_preserve_bounds)
+    Ok(boosted.min(scale) as u64)
+}
+
+/// `trust_boost_exact`: [`trust_boost`] of `current` plus `remainder`
+/// thousandths, returning the boosted trust and its new remainder. The
+/// remainder carries what each boost rounds away, so `n` boosts at `r`
+/// from `c` land within a unit of `1000 - (1000 - c) * (1 - r)^n`
+/// (`boost_exact_bounds`); a full boost reaches `(1000, 0)`.
+pub fn trust_boost_exact(
+    current: u64,
+    remainder: u64,
+    boost_rate: u64,
+) -> Result<(u64, u64), TrustError> {
+    let current = in_range("trust", current)?;
+    let boost_rate = u128::from(in_range("boost_rate", boost_rate)?);
+    if remainder >= 1000 || (current == 1000 && remainder > 0) {
+        return Err(TrustError::Remainder { current, remainder });
+    }
+    let scaled = u128::from(current) * 1000 + u128::from(remainder);
+    let boosted = scaled + (1_000_000 - scaled) * boost_rate / 1000;
+    Ok(((boosted / 1000) as u64, (boosted % 1000) as u64))
+}
+
+/// Weight of a vote at `trust` (scaled by 1000) from a model weighing
+/// `weight_scaled` (scaled by 100), exactly: the `vote_weight` the
+/// weighted consensus specification sums. Consensus sums these products
+/// before dividing, so each vote's rounding does not add up.
+pub fn vote_weight(trust: u64, weight_scaled: u64) -> u128 {
+    u128::from(trust) * u128::from(weight_scaled)
+}
+
+/// Trust times `model`'s weight, scaled by 100 (`effective_weight`):
+/// [`vote_weight`] divided by 1000 and rounded down, so
+/// `1000 * w <= trust * weight < 1000 * (w + 1)`.
+///
+/// A weight outside [`MIN_MODEL_WEIGHT`]`..=`[`MAX_MODEL_WEIGHT`], which a
+/// [`ModelRegistry`](crate::models::ModelRegistry) would have rejected,
+/// saturates to the nearest bound first, so the result is at most 200 for
+/// any spec (`combined_weight_bounded`) and never falls when the trust or
+/// the weight rises (`effective_weight_monotone`).
+///
+/// This is the weight selection ranks agents by. Consensus weighs votes by
+/// the exact [`vote_weight`] instead and divides only the sums, so a
+/// round's weights are not these rounded ones added up.
+pub fn effective_weight(trust: TrustScore, model: &ModelSpec) -> u64 {
+    let weight = model
+        .weight_scaled
+        .clamp(MIN_MODEL_WEIGHT, MAX_MODEL_WEIGHT);
+    (vote_weight(trust.value(), weight) / 1000) as u64
+}
+
+/// `composite_trust`: the accuracy, calibration and latency components
+/// weighted by `weights` (scaled by 1000, summing to 1000), rounded down;
+/// in `0..=1000` and between the least and greatest component
+pub fn composite_trust(components: [u64; 3], weights: [u64; 3]) -> Result<u64, TrustError> {
+    composite_trust_at(1000, components, weights)
+}
+
+/// [`composite_trust`] of components scaled by `scale`, still weighted
+/// in thousandths: in `0..=scale`
+fn composite_trust_at(
+    scale: u64,
+    components: [u64; 3],
+    weights: [u64; 3],
+) -> Result<u64, TrustError> {
+    for component in components {
+        in_scale("trust", component, scale)?;
+    }
+    let sum: u128 = weights.iter().map(|&w| u128::from(w)).sum();
+    if sum != 1000 {
+        return Err(TrustError::WeightSum { sum });
+    }
+    let numerator: u128 = components
+        .iter()
+        .zip(weights)
+        .map(|(&c, w)| u128::from(c) * u128::from(w))
+        .sum();
+    Ok((numerator / 1000) as u64)
+}
+
+/// Trust scaled by 1000, always in `0..=1000` (`TrustScore` in the
+/// specification)
+///
+/// Scores order by value. To select among agents, rank them with
+/// [`rank_agents`]: highest trust first and, between equal scores, lowest
+/// agent id first, so a selection never depends on the order agents were
+/// listed in.
+///
+/// This is [`ScaledTrust`] at the scale the agent updates and the
+/// specification use.
+pub type TrustScore = ScaledTrust<1000>;
+
+/// Trust scaled by `SCALE`, always in `0..=SCALE`: 1000 keeps three
+/// decimal places, 10_000 four. A scale below 10 does not compile.
+///
+/// The updates are the verified ones at `SCALE`
+/// (`scaled_updates_preserve_bounds`), with rates and alpha scaled by
+/// `SCALE` too, and reduce to [`ema_update`], [`trust_decay`] and
+/// [`trust_boost`] at 1000. [`rescale`](Self::rescale) converts between
+/// scales.
+///
+/// ```
+/// use aevion_shield::trust::{Rounding, ScaledTrust, TrustScore};
+///
+/// let fine = ScaledTrust::<10_000>::new(8_605).unwrap();
+/// assert_eq!(fine.to_string(), "0.8605");
+/// assert_eq!(fine.rescale::<1000>(), TrustScore::new(861).unwrap());
+/// let updated = fine.ema(10_000, 3_000, Rounding::HalfUp).unwrap();
+/// assert_eq!(updated.value(), 9_024);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "u64", try_from = "u64")
+)]
+pub struct ScaledTrust<const SCALE: u64>(u64);
+
+impl<const SCALE: u64> ScaledTrust<SCALE> {
+    /// Evaluated wherever a score is made, so a scale below 10 fails to
+    /// compile
+    const VALID_SCALE: () = assert!(SCALE >= 10, "trust scales start at 10");
+
+    /// Full trust
+    pub const MAX: Self = {
+        Self::VALID_SCALE;
+        Self(SCALE)
+    };
+
+    /// Trust score of `value`, rejected above `SCALE`
+    pub fn new(value: u64) -> Result<Self, TrustError> {
+        Self::VALID_SCALE;
+        in_scale("trust", value, SCALE).map(Self)
+    }
+
+    /// The scaled value
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// How far this score is above `other`, 0 if it is not
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Whether the scores are within `tolerance` (scaled by `SCALE`) of
+    /// each other: a tie as far as selection is concerned
+    pub fn approx_eq(self, other: Self, tolerance: u64) -> bool {
+        self.0.abs_diff(other.0) <= tolerance
+    }
+
+    /// The same trust at scale `TO`, rounding half up. Exact when `TO` is
+    /// a multiple of `SCALE` (1000 to 10_000 multiplies by 10), and
+    /// rescaling up and back down returns the original; otherwise within
+    /// half a unit of `TO`, so 0.8605 at 10_000 is 0.861 at 1000
+    /// (`rescale_preserves_bounds`).
+    pub fn rescale<const TO: u64>(self) -> ScaledTrust<TO> {
+        ScaledTrust::<TO>::VALID_SCALE;
+        let (value, from, to) = (u128::from(self.0), u128::from(SCALE), u128::from(TO));
+        // At most TO, since value <= SCALE (rescale_preserves_bounds)
+        ScaledTrust(((value * to + from / 2) / from) as u64)
+    }
+
+    /// `ema_update_at`: moved toward `observation` with weight `alpha`,
+    /// both scaled by `SCALE`, rounded by `rounding`
+    pub fn ema(self, observation: u64, alpha: u64, rounding: Rounding) -> Result<Self, TrustError> {
+        ema_update_at(SCALE, self.0, observation, alpha, rounding).map(Self)
+    }
+
+    /// `trust_decay_at`: lowered by `decay_rate` (scaled by `SCALE`)
+    pub fn decay(self, decay_rate: u64) -> Result<Self, TrustError> {
+        trust_decay_at(SCALE, self.0, decay_rate).map(Self)
+    }
+
+    /// `trust_boost_at`: raised by `boost_rate` (scaled by `SCALE`) of
+    /// the gap to full trust
+    pub fn boost(self, boost_rate: u64) -> Result<Self, TrustError> {
+        trust_boost_at(SCALE, self.0, boost_rate).map(Self)
+    }
+}
+
+impl TrustScore {
+    /// The trust in whole percent, rounding half up: 0.994 is 99%, 0.995
+    /// is 100%
+    pub fn percent(self) -> u64 {
+        (self.0 + 5) / 10
+    }
+
+    /// Trust score of `percent`, rejected above 100; exact, so
+    /// `from_percent(p)?.percent() == p`
+    pub fn from_percent(percent: u64) -> Result<Self, TrustError> {
+        if percent > 100 {
+            return Err(TrustError::Percent { value: percent });
+        }
+        Ok(Self(percent * 10))
+    }
+}
+
+/// Sort `agents` for selection: highest trust first, ties by lowest agent
+/// id, whatever order they come in
+pub fn rank_agents<const SCALE: u64>(agents: &mut [(AgentId, ScaledTrust<SCALE>)]) {
+    agents.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+}
+
+impl<const SCALE: u64> TryFrom<u64> for ScaledTrust<SCALE> {
+    type Error = TrustError;
+
+    fn try_from(value: u64) -> Result<Self, TrustError> {
+        Self::new(value)
+    }
+}
+
+impl<const SCALE: u64> From<ScaledTrust<SCALE>> for u64 {
+    fn from(score: ScaledTrust<SCALE>) -> u64 {
+        score.0
+    }
+}
+
+impl<const SCALE: u64> fmt::Display for ScaledTrust<SCALE> {
+    /// A decimal at a power-of-ten scale (0.860 at 1000, 0.8605 at
+    /// 10_000), a fraction of the scale otherwise
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = SCALE.ilog10();
+        if 10u64.pow(digits) == SCALE {
+            let width = digits as usize;
+            write!(f, "{}.{:0width$}", self.0 / SCALE, self.0 % SCALE)
+        } else {
+            write!(f, "{}/{}", self.0, SCALE)
+        }
+    }
+}
+
+/// How right an agent was, scaled by 1000: 0 wrong, 1000 right, and
+/// partial credit between (`observation_valid` in the specification).
+/// Every update that learns from an agent's answers takes one, so an
+/// observation on another scale is rejected when it is made rather than
+/// folded into the trust.
+///
+/// This is [`ScaledObservation`] at the scale of [`AgentTrust`].
+///
+/// A raw number does not convert implicitly:
+///
+/// ```compile_fail
+/// use aevion_shield::trust::AgentTrust;
+///
+/// let mut trust = AgentTrust::default();
+/// // 95 meant as 95%, which would read as 0.095
+/// trust.record_observation(95, 300).unwrap();
+/// ```
+///
+/// ```
+/// use aevion_shield::trust::{AgentTrust, Observation};
+///
+/// let mut trust = AgentTrust::default();
+/// let observation = Observation::from_score_and_weight(95, 100).unwrap();
+/// assert_eq!(observation.value(), 950);
+/// trust.record_observation(observation, 300).unwrap();
+/// ```
+pub type Observation = ScaledObservation<1000>;
+
+/// An observation scaled by `SCALE`, for a [`ScaledAgentTrust`] at the
+/// same scale: an observation at 1000 does not fold into an agent at
+/// 10_000
+///
+/// ```compile_fail
+/// use aevion_shield::trust::{Observation, ScaledAgentTrust};
+///
+/// let mut trust = ScaledAgentTrust::<10_000>::default();
+/// trust.record_observation(Observation::correct(), 3_000).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "u64", try_from = "u64")
+)]
+pub struct ScaledObservation<const SCALE: u64>(u64);
+
+impl<const SCALE: u64> ScaledObservation<SCALE> {
+    /// A right answer
+    pub fn correct() -> Self {
+        Self(SCALE)
+    }
+
+    /// A wrong answer
+    pub fn incorrect() -> Self {
+        Self(0)
+    }
+
+    /// Partial credit of `scaled` (scaled by `SCALE`), rejected above
+    /// `SCALE`
+    pub fn partial(scaled: u64) -> Result<Self, TrustError> {
+        in_scale("observation", scaled, SCALE).map(Self)
+    }
+
+    /// A graded task: `score` points of the `weight` it is worth, scaled
+    /// by `SCALE` rounding half up. Rejects a score above the weight and
+    /// a weight of 0.
+    pub fn from_score_and_weight(score: u64, weight: u64) -> Result<Self, TrustError> {
+        if weight == 0 || score > weight {
+            return Err(TrustError::Grade { score, weight });
+        }
+        let (score, weight) = (u128::from(score), u128::from(weight));
+        // At most SCALE, since score <= weight
+        Ok(Self(
+            ((score * u128::from(SCALE) + weight / 2) / weight) as u64,
+        ))
+    }
+
+    /// The scaled value
+    pub fn value(self) -> u64 {
+        self.0
+    }
+}
+
+impl<const SCALE: u64> From<bool> for ScaledObservation<SCALE> {
+    /// [`correct`](ScaledObservation::correct) or
+    /// [`incorrect`](ScaledObservation::incorrect)
+    fn from(correct: bool) -> Self {
+        if correct {
+            Self::correct()
+        } else {
+            Self::incorrect()
+        }
+    }
+}
+
+impl<const SCALE: u64> TryFrom<u64> for ScaledObservation<SCALE> {
+    type Error = TrustError;
+
+    fn try_from(scaled: u64) -> Result<Self, TrustError> {
+        Self::partial(scaled)
+    }
+}
+
+impl<const SCALE: u64> From<ScaledObservation<SCALE>> for u64 {
+    fn from(observation: ScaledObservation<SCALE>) -> u64 {
+        observation.0
+    }
+}
+
+/// What an [`AgentTrust`] score rates, in [`AgentTrust::composite`]'s
+/// weight order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrustComponent {
+    /// Whether the agent's answers are right: the trust itself
+    Accuracy,
+    /// Whether its confidence matches how often it is right
+    Calibration,
+    /// Whether it answers in time
+    Latency,
+}
+
+/// Why [`AgentTrust::suspicion`] flags an agent, at the agent's scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suspicion<const SCALE: u64 = 1000> {
+    /// Trust below `detection_threshold`
+    LowTrust { trust: ScaledTrust<SCALE> },
+    /// Trust `divergence` above the accuracy of a full window, at least
+    /// `divergence_threshold`
+    Diverging { divergence: i64 },
+}
+
+/// An agent's trust and observation history (`AgentTrust` in the
+/// specification); `cumulative_correct <= observations * 1000` always holds,
+/// and the window holds at most `window_size` observations
+///
+/// This is [`ScaledAgentTrust`] at 1000, the scale of the specification
+/// and of every [`TrustManager`](crate::trust_manager::TrustManager).
+pub type AgentTrust = ScaledAgentTrust<1000>;
+
+/// An agent's trust, observations and window, all scaled by `SCALE`;
+/// `cumulative_correct <= observations * SCALE` always holds.
+///
+/// Every update takes its rates, alpha, floor and cap scaled by `SCALE`
+/// too, and is the verified one at `SCALE` (`scaled_updates_preserve_bounds`).
+/// The [`Thresholds`] that [`suspicion`](Self::suspicion) and
+/// [`update_quarantine`](Self::update_quarantine) read stay in
+/// thousandths at every scale and are rescaled to `SCALE` as
+/// [`ScaledTrust::rescale`] would.
+///
+/// ```
+/// use aevion_shield::trust::{ScaledAgentTrust, ScaledObservation};
+///
+/// let mut trust = ScaledAgentTrust::<10_000>::builder()
+///     .current(8_000)
+///     .build()
+///     .unwrap();
+/// trust.record_observation(ScaledObservation::correct(), 3_000).unwrap();
+/// assert_eq!(trust.current().value(), 8_600);
+/// trust.decay(1_000).unwrap();
+/// assert_eq!(trust.current().to_string(), "0.7740");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "ScaledAgentTrustBuilder<SCALE>")
+)]
+pub struct ScaledAgentTrust<const SCALE: u64> {
+    current: ScaledTrust<SCALE>,
+    observations: u64,
+    cumulative_correct: u64,
+    rounding: Rounding,
+    quarantined: bool,
+    window_size: usize,
+    recent: VecDeque<u64>,
+    calibration: ScaledTrust<SCALE>,
+    latency: ScaledTrust<SCALE>,
+}
+
+impl<const SCALE: u64> Default for ScaledAgentTrust<SCALE> {
+    /// Full trust, no observations
+    fn default() -> Self {
+        Self {
+            current: ScaledTrust::MAX,
+            observations: 0,
+            cumulative_correct: 0,
+            rounding: Rounding::default(),
+            quarantined: false,
+            window_size: DEFAULT_WINDOW_SIZE,
+            recent: VecDeque::new(),
+            calibration: ScaledTrust::MAX,
+            latency: ScaledTrust::MAX,
+        }
+    }
+}
+
+impl<const SCALE: u64> ScaledAgentTrust<SCALE> {
+    /// Observations the agent can record before `cumulative_correct`
+    /// could overflow a u64: [`MAX_OBSERVATIONS`] at 1000
+    pub const MAX_OBSERVATIONS: u64 = u64::MAX / SCALE;
+
+    /// Builder for seeding an agent's initial trust and history
+    pub fn builder() -> ScaledAgentTrustBuilder<SCALE> {
+        ScaledAgentTrustBuilder::default()
+    }
+
+    pub fn current(&self) -> ScaledTrust<SCALE> {
+        self.current
+    }
+
+    /// Score of `component`; [`Accuracy`](TrustComponent::Accuracy) is
+    /// the trust
+    pub fn component(&self, component: TrustComponent) -> ScaledTrust<SCALE> {
+        match component {
+            TrustComponent::Accuracy => self.current,
+            TrustComponent::Calibration => self.calibration,
+            TrustComponent::Latency => self.latency,
+        }
+    }
+
+    /// Accuracy, calibration and latency weighted by `weights`
+    /// ([`composite_trust`]), once the weights sum to 1000; `[1000, 0, 0]`
+    /// is the trust. The weights are thousandths at every scale.
+    pub fn composite(&self, weights: [u64; 3]) -> Result<ScaledTrust<SCALE>, TrustError> {
+        let components = [self.current.0, self.calibration.0, self.latency.0];
+        Ok(ScaledTrust(composite_trust_at(SCALE, components, weights)?))
+    }
+
+    /// Fold `observation` into `component` with weight `alpha`
+    /// ([`ema_update`] with the agent's rounding). An accuracy observation
+    /// is [`record_observation`](Self::record_observation); the others
+    /// leave the history and the window alone.
+    pub fn record_component(
+        &mut self,
+        component: TrustComponent,
+        observation: ScaledObservation<SCALE>,
+        alpha: u64,
+    ) -> Result<(), TrustError> {
+        let score = match component {
+            TrustComponent::Accuracy => return self.record_observation(observation, alpha),
+            TrustComponent::Calibration => &mut self.calibration,
+            TrustComponent::Latency => &mut self.latency,
+        };
+        *score = score.ema(observation.0, alpha, self.rounding)?;
+        Ok(())
+    }
+
+    /// Observations recorded
+    pub fn observations(&self) -> u64 {
+        self.observations
+    }
+
+    /// Sum of the recorded observations, each scaled by `SCALE`
+    pub fn cumulative_correct(&self) -> u64 {
+        self.cumulative_correct
+    }
+
+    /// How observations are rounded into the trust
+    pub fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    /// Observations the window holds
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// The last `window_size` observations, oldest first
+    pub fn recent(&self) -> impl ExactSizeIterator<Item = u64> + '_ {
+        self.recent.iter().copied()
+    }
+
+    /// Whether the window holds `window_size` observations
+    pub fn window_full(&self) -> bool {
+        self.recent.len() == self.window_size
+    }
+
+    /// Mean of the recent observations, rounded down (`window_mean`): in
+    /// `0..=SCALE` (`window_mean_bounded`); `None` before any observation
+    pub fn recent_accuracy_scaled(&self) -> Option<u64> {
+        let n = self.recent.len() as u64;
+        (n > 0).then(|| self.recent.iter().sum::<u64>() / n)
+    }
+
+    /// Population variance of the recent observations scaled by `SCALE`,
+    /// rounded down: at most `SCALE / 4` (observations split between 0 and
+    /// `SCALE`); `None` before any observation
+    pub fn recent_variance_scaled(&self) -> Option<u64> {
+        let n = self.recent.len() as u128;
+        if n == 0 {
+            return None;
+        }
+        let sum: u128 = self.recent.iter().map(|&x| x as u128).sum();
+        let squares: u128 = self.recent.iter().map(|&x| (x as u128) * (x as u128)).sum();
+        // n * sum(x^2) - sum(x)^2 = n^2 * variance, exactly
+        Some(((n * squares - sum * sum) / (n * n * u128::from(SCALE))) as u64)
+    }
+
+    /// Trust minus recent accuracy: positive when the EMA still credits
+    /// an agent for more than it has lately delivered, within
+    /// `-SCALE..=SCALE`; `None` before any observation
+    pub fn divergence(&self) -> Option<i64> {
+        self.recent_accuracy_scaled()
+            .map(|recent| self.current.0 as i64 - recent as i64)
+    }
+
+    /// Why the agent looks Byzantine, if it does: trust below
+    /// `detection_threshold` (`byzantine_detection_via_trust`), or a full
+    /// window whose accuracy trails the trust by at least
+    /// `divergence_threshold` while the trust is still above it
+    pub fn suspicion(&self, thresholds: &Thresholds) -> Option<Suspicion<SCALE>> {
+        if self.current.0 < thousandths_at(SCALE, thresholds.detection_threshold) {
+            return Some(Suspicion::LowTrust {
+                trust: self.current,
+            });
+        }
+        let divergence_threshold = thousandths_at(SCALE, thresholds.divergence_threshold);
+        match self.divergence() {
+            Some(divergence) if self.window_full() && divergence >= divergence_threshold as i64 => {
+                Some(Suspicion::Diverging { divergence })
+            }
+            _ => None,
+        }
+    }
+
+    /// Fold `observation` into the trust with weight `alpha`
+    /// ([`ema_update`] with the agent's rounding) and record it in the
+    /// history and the window, dropping the oldest observation from a full
+    /// window (`window_push`)
+    pub fn record_observation(
+        &mut self,
+        observation: ScaledObservation<SCALE>,
+        alpha: u64,
+    ) -> Result<(), TrustError> {
+        if self.observations >= Self::MAX_OBSERVATIONS {
+            return Err(TrustError::ObservationLimit);
+        }
+        let observation = observation.0;
+        self.current = self.current.ema(observation, alpha, self.rounding)?;
+        self.cumulative_correct += observation;
+        self.observations += 1;
+        if self.recent.len() == self.window_size {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(observation);
+        Ok(())
+    }
+
+    /// [`record_observation`](Self::record_observation), with the trust
+    /// moved at most `max_delta` from where it was (`ema_capped`): between
+    /// the old trust and the uncapped update, so in bounds
+    /// (`capped_update_bounds`), and `k` calls move it at most `k *
+    /// max_delta` (`capped_sequence_reachability`)
+    pub fn record_observation_capped(
+        &mut self,
+        observation: ScaledObservation<SCALE>,
+        alpha: u64,
+        max_delta: u64,
+    ) -> Result<(), TrustError> {
+        let max_delta = in_scale("max_delta", max_delta, SCALE)?;
+        let before = self.current.0;
+        self.record_observation(observation, alpha)?;
+        let after = self.current.0;
+        self.current =
+            ScaledTrust(after.clamp(before.saturating_sub(max_delta), before + max_delta));
+        Ok(())
+    }
+
+    /// [`record_observation`](Self::record_observation) at the alpha
+    /// `schedule` picks for the agent's next observation, once the
+    /// schedule is valid; the schedule's alphas are scaled by `SCALE`
+    pub fn record_scheduled(
+        &mut self,
+        observation: ScaledObservation<SCALE>,
+        schedule: &AlphaSchedule,
+    ) -> Result<(), TrustError> {
+        schedule.validate_at(SCALE)?;
+        self.record_observation(observation, schedule.alpha_at(SCALE, self.observations))
+    }
+
+    /// Decay the trust of a suspected agent by `decay_rate`
+    /// (`trust_decay`) with no floor; the history is unchanged. Round
+    /// updates use [`decay_floored`](Self::decay_floored).
+    pub fn decay(&mut self, decay_rate: u64) -> Result<(), TrustError> {
+        self.current = self.current.decay(decay_rate)?;
+        Ok(())
+    }
+
+    /// Decay the trust of a suspected agent by `decay_rate`, stopping at
+    /// `floor` ([`trust_decay_floored`]); the history is unchanged
+    pub fn decay_floored(&mut self, decay_rate: u64, floor: u64) -> Result<(), TrustError> {
+        self.current = ScaledTrust(trust_decay_floored_at(
+            SCALE,
+            self.current.0,
+            decay_rate,
+            floor,
+        )?);
+        Ok(())
+    }
+
+    /// Decay the trust of an agent that sat out `elapsed_rounds` rounds by
+    /// `rate_per_round` each, stopping at `floor`
+    /// ([`trust_decay_rounds`]); the history is unchanged
+    pub fn decay_for_inactivity(
+        &mut self,
+        elapsed_rounds: u64,
+        rate_per_round: u64,
+        floor: u64,
+    ) -> Result<(), TrustError> {
+        self.current = ScaledTrust(trust_decay_rounds_at(
+            SCALE,
+            self.current.0,
+            rate_per_round,
+            floor,
+            elapsed_rounds,
+        )?);
+        Ok(())
+    }
+
+    /// Whether the agent was quarantined at its last
+    /// [`update_quarantine`](Self::update_quarantine)
+    pub fn is_quarantined(&self) -> bool {
+        self.quarantined
+    }
+
+    /// Evaluate quarantine at the current trust, with hysteresis
+    /// (`quarantine_next`): an agent enters quarantine below
+    /// `detection_threshold` and leaves only above `detection_threshold +
+    /// quarantine_margin`, so trust hovering at the threshold does not
+    /// flap (`quarantine_no_flapping`). A diverging agent
+    /// ([`suspicion`](Self::suspicion)) enters too, and stays until its
+    /// divergence falls below `divergence_threshold - quarantine_margin`.
+    /// Returns the new state.
+    pub fn update_quarantine(&mut self, thresholds: &Thresholds) -> bool {
+        let trust = self.current.0;
+        let threshold = thousandths_at(SCALE, thresholds.detection_threshold);
+        let margin = thousandths_at(SCALE, thresholds.quarantine_margin);
+        self.quarantined = if self.quarantined {
+            let recovering = thousandths_at(SCALE, thresholds.divergence_threshold)
+                .saturating_sub(margin) as i64;
+            trust <= threshold.saturating_add(margin)
+                || (self.window_full() && self.divergence() >= Some(recovering))
+        } else {
+            self.suspicion(thresholds).is_some()
+        };
+        self.quarantined
+    }
+
+    /// Boost the trust of an agent that agreed by `boost_rate` of the gap
+    /// to full trust (`trust_boost`); the history is unchanged
+    pub fn boost(&mut self, boost_rate: u64) -> Result<(), TrustError> {
+        self.current = self.current.boost(boost_rate)?;
+        Ok(())
+    }
+}
+
+/// Seeds an [`AgentTrust`]; defaults to full trust, no history,
+/// [`Rounding::HalfUp`] and an empty window of [`DEFAULT_WINDOW_SIZE`]
+pub type AgentTrustBuilder = ScaledAgentTrustBuilder<1000>;
+
+/// Seeds a [`ScaledAgentTrust`], with every trust and observation scaled
+/// by `SCALE`; fields left out of the serialized form take their defaults
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct ScaledAgentTrustBuilder<const SCALE: u64> {
+    #[cfg_attr(feature = "serde", serde(default = "full_scale::<SCALE>"))]
+    current: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    observations: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cumulative_correct: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    rounding: Rounding,
+    #[cfg_attr(feature = "serde", serde(default))]
+    quarantined: bool,
+    #[cfg_attr(feature = "serde", serde(default = "default_window_size"))]
+    window_size: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    recent: Vec<u64>,
+    #[cfg_attr(feature = "serde", serde(default = "full_scale::<SCALE>"))]
+    calibration: u64,
+    #[cfg_attr(feature = "serde", serde(default = "full_scale::<SCALE>"))]
+    latency: u64,
+}
+
+/// Full trust at `SCALE`, the builder's default trust and components
+#[cfg(feature = "serde")]
+fn full_scale<const SCALE: u64>() -> u64 {
+    SCALE
+}
+
+#[cfg(feature = "serde")]
+fn default_window_size() -> usize {
+    DEFAULT_WINDOW_SIZE
+}
+
+impl<const SCALE: u64> Default for ScaledAgentTrustBuilder<SCALE> {
+    fn default() -> Self {
+        Self {
+            current: SCALE,
+            observations: 0,
+            cumulative_correct: 0,
+            rounding: Rounding::default(),
+            quarantined: false,
+            window_size: DEFAULT_WINDOW_SIZE,
+            recent: Vec::new(),
+            calibration: SCALE,
+            latency: SCALE,
+        }
+    }
+}
+
+impl<const SCALE: u64> ScaledAgentTrustBuilder<SCALE> {
+    /// Initial trust, scaled by `SCALE`
+    pub fn current(&mut self, current: u64) -> &mut Self {
+        self.current = current;
+        self
+    }
+
+    /// Observations already recorded
+    pub fn observations(&mut self, observations: u64) -> &mut Self {
+        self.observations = observations;
+        self
+    }
+
+    /// Sum of the observations already recorded
+    pub fn cumulative_correct(&mut self, cumulative_correct: u64) -> &mut Self {
+        self.cumulative_correct = cumulative_correct;
+        self
+    }
+
+    /// Rounding of recorded observations
+    pub fn rounding(&mut self, rounding: Rounding) -> &mut Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Whether the agent starts in quarantine
+    pub fn quarantined(&mut self, quarantined: bool) -> &mut Self {
+        self.quarantined = quarantined;
+        self
+    }
+
+    /// Recent observations the agent keeps
+    pub fn window_size(&mut self, window_size: usize) -> &mut Self {
+        self.window_size = window_size;
+        self
+    }
+
+    /// Initial calibration score, scaled by `SCALE`
+    pub fn calibration(&mut self, calibration: u64) -> &mut Self {
+        self.calibration = calibration;
+        self
+    }
+
+    /// Initial latency score, scaled by `SCALE`
+    pub fn latency(&mut self, latency: u64) -> &mut Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Observations already in the window, oldest first
+    pub fn recent(&mut self, recent: impl IntoIterator<Item = u64>) -> &mut Self {
+        self.recent = recent.into_iter().collect();
+        self
+    }
+
+    /// The agent's trust, once the trust and the other components are at
+    /// most `SCALE`, the history below
+    /// [`MAX_OBSERVATIONS`](ScaledAgentTrust::MAX_OBSERVATIONS) and
+    /// consistent (`AgentTrust::valid`), and the window non-empty, not
+    /// overfull and within `0..=SCALE`
+    pub fn build(&self) -> Result<ScaledAgentTrust<SCALE>, TrustError> {
+        let current = ScaledTrust::new(self.current)?;
+        let calibration = ScaledTrust(in_scale("calibration", self.calibration, SCALE)?);
+        let latency = ScaledTrust(in_scale("latency", self.latency, SCALE)?);
+        if self.observations > ScaledAgentTrust::<SCALE>::MAX_OBSERVATIONS {
+            return Err(TrustError::ObservationLimit);
+        }
+        if self.cumulative_correct > self.observations * SCALE {
+            return Err(TrustError::InconsistentHistory {
+                observations: self.observations,
+                cumulative_correct: self.cumulative_correct,
+            });
+        }
+        if self.window_size == 0 {
+            return Err(TrustError::EmptyWindow);
+        }
+        if self.recent.len() > self.window_size {
+            return Err(TrustError::WindowOverflow {
+                window_size: self.window_size,
+                recent: self.recent.len(),
+            });
+        }
+        for &observation in &self.recent {
+            in_scale("observation", observation, SCALE)?;
+        }
+        Ok(ScaledAgentTrust {
+            current,
+            observations: self.observations,
+            cumulative_correct: self.cumulative_correct,
+            rounding: self.rounding,
+            quarantined: self.quarantined,
+            window_size: self.window_size,
+            recent: self.recent.iter().copied().collect(),
+            calibration,
+            latency,
+        })
+    }
+}
+
+impl<const SCALE: u64> TryFrom<ScaledAgentTrustBuilder<SCALE>> for ScaledAgentTrust<SCALE> {
+    type Error = TrustError;
+
+    fn try_from(builder: ScaledAgentTrustBuilder<SCALE>) -> Result<Self, TrustError> {
+        builder.build()
+    }
+}
+
+impl<const SCALE: u64> From<ScaledAgentTrust<SCALE>> for ScaledAgentTrustBuilder<SCALE> {
+    fn from(trust: ScaledAgentTrust<SCALE>) -> Self {
+        Self {
+            current: trust.current.0,
+            observations: trust.observations,
+            cumulative_correct: trust.cumulative_correct,
+            rounding: trust.rounding,
+            quarantined: trust.quarantined,
+            window_size: trust.window_size,
+            recent: trust.recent.into(),
+            calibration: trust.calibration.0,
+            latency: trust.latency.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Rng;
+
+    fn obs<const SCALE: u64>(scaled: u64) -> ScaledObservation<SCALE> {
+        ScaledObservation::partial(scaled).unwrap()
+    }
+
+    /// `thousandths` at `SCALE`: the agent checks run at 1000 and at
+    /// 10_000, where this is exact
+    fn at<const SCALE: u64>(thousandths: u64) -> u64 {
+        thousandths * SCALE / 1000
+    }
+
+    fn assert_valid<const SCALE: u64>(trust: &ScaledAgentTrust<SCALE>) {
+        assert!(trust.current().value() <= SCALE, "{:?}", trust);
+        assert!(
+            trust.cumulative_correct() <= trust.observations() * SCALE,
+            "{:?}",
+            trust
+        );
+    }
+
+    #[test]
+    fn test_trust_score_rejects_out_of_range() {
+        assert_eq!(TrustScore::new(1000), Ok(TrustScore::MAX));
+        assert_eq!(TrustScore::new(0).map(TrustScore::value), Ok(0));
+        let err = TrustScore::new(1001).unwrap_err();
+        assert_eq!(
+            err,
+            TrustError::OutOfRange {
+                name: "trust",
+                value: 1001
+            }
+        );
+        assert_eq!(err.to_string(), "trust = 1001 is outside 0..=1000");
+        assert_eq!(TrustScore::new(860).unwrap().to_string(), "0.860");
+    }
+
+    #[test]
+    fn test_effective_weight() {
+        let spec = |weight_scaled| ModelSpec {
+            id: 0,
+            weight_scaled,
+            family: crate::diversity::ModelFamily::Unknown,
+        };
+        let mut rng = Rng(0x7E57_7A05_7000_000A);
+        for _ in 0..10_000 {
+            let trust = TrustScore::new(rng.below(1001)).unwrap();
+            let weight = match rng.below(4) {
+                0 => rng.next(),
+                _ => rng.below(301),
+            };
+            let effective = effective_weight(trust, &spec(weight));
+            // Against the rational trust * weight / 1000, after saturation
+            let saturated = u128::from(weight.clamp(100, 200));
+            let exact = u128::from(trust.value()) * saturated;
+            let effective_wide = u128::from(effective);
+            assert!(1000 * effective_wide <= exact && exact < 1000 * (effective_wide + 1));
+            assert!(effective <= 200);
+
+            // effective_weight_monotone
+            let higher = TrustScore::new(trust.value() + rng.below(1001 - trust.value())).unwrap();
+            let heavier = weight.saturating_add(rng.below(150));
+            assert!(effective <= effective_weight(higher, &spec(weight)));
+            assert!(effective <= effective_weight(trust, &spec(heavier)));
+        }
+        assert_eq!(effective_weight(TrustScore::MAX, &spec(200)), 200);
+        assert_eq!(effective_weight(TrustScore::MAX, &spec(u64::MAX)), 200);
+        assert_eq!(effective_weight(TrustScore::MAX, &spec(0)), 100);
+        // 0.999 at 1.3 is 1.2987, rounded down
+        assert_eq!(
+            effective_weight(TrustScore::new(999).unwrap(), &spec(130)),
+            129
+        );
+
+        // The engine sums the exact vote_weight products that
+        // effective_weight rounds: three gpt-4o-mini votes at 0.999 weigh
+        // 389.610 together, not 3 * 129
+        let engine = crate::consensus::ConsensusEngine::default();
+        let registry = engine.models();
+        for (_, model) in registry.iter() {
+            for trust in [0, 1, 500, 999, 1000] {
+                let score = TrustScore::new(trust).unwrap();
+                let exact = vote_weight(trust, model.weight_scaled);
+                assert_eq!(u128::from(effective_weight(score, model)), exact / 1000);
+            }
+        }
+        let capacity = engine.weighted_fault_capacity(&[999; 3], &[4; 3]);
+        assert_eq!(capacity.total_weight, 3 * vote_weight(999, 130));
+        assert_eq!(capacity.total_weight / 1000, 389);
+        let mini = registry.spec(4);
+        assert_eq!(
+            3 * effective_weight(TrustScore::new(999).unwrap(), &mini),
+            387
+        );
+    }
+
+    #[test]
+    fn test_trust_score_comparisons() {
+        let score = |value| TrustScore::new(value).unwrap();
+        assert!(score(861) > score(860) && TrustScore::MAX == score(1000));
+        assert_eq!(score(1000).max(score(0)), TrustScore::MAX);
+
+        // Half up, at both ends of each percent
+        let percents: Vec<u64> = [0, 4, 5, 994, 995, 1000]
+            .iter()
+            .map(|&value| score(value).percent())
+            .collect();
+        assert_eq!(percents, [0, 0, 1, 99, 100, 100]);
+        for percent in 0..=100 {
+            let exact = TrustScore::from_percent(percent).unwrap();
+            assert_eq!((exact.value(), exact.percent()), (percent * 10, percent));
+        }
+        let err = TrustScore::from_percent(101).unwrap_err();
+        assert_eq!(err, TrustError::Percent { value: 101 });
+        assert_eq!(err.to_string(), "percent = 101 is outside 0..=100");
+
+        assert_eq!(score(900).difference(score(850)), score(50));
+        assert_eq!(score(850).difference(score(900)), score(0));
+        assert!(score(900).approx_eq(score(880), 20) && score(880).approx_eq(score(900), 20));
+        assert!(!score(900).approx_eq(score(879), 20));
+        assert!(score(0).approx_eq(TrustScore::MAX, 1000));
+    }
+
+    #[test]
+    fn test_ranking_is_deterministic() {
+        // Agents with repeated scores, shuffled: every order ranks the same
+        let mut rng = Rng(0x7E57_7A05_7000_0009);
+        let agents: Vec<(AgentId, TrustScore)> = (0..40)
+            .map(|agent| (agent, TrustScore::new(rng.below(5) * 250).unwrap()))
+            .collect();
+        let mut expected = agents.clone();
+        rank_agents(&mut expected);
+        for pair in expected.windows(2) {
+            let ((a, trust_a), (b, trust_b)) = (pair[0], pair[1]);
+            assert!(trust_a > trust_b || (trust_a == trust_b && a < b));
+        }
+        for _ in 0..100 {
+            let mut shuffled = agents.clone();
+            for i in (1..shuffled.len()).rev() {
+                shuffled.swap(i, rng.below(i as u64 + 1) as usize);
+            }
+            rank_agents(&mut shuffled);
+            assert_eq!(shuffled, expected);
+        }
+    }
+
+    /// The trust_bounds unit test values: EMA 0.3 of 1.0 over 0.8, decay
+    /// 10% of 1.0, boost 5% of the gap over 0.8
+    fn check_updates_match_specification<const SCALE: u64>() {
+        let mut trust = ScaledAgentTrust::<SCALE>::builder()
+            .current(at::<SCALE>(800))
+            .build()
+            .unwrap();
+        trust
+            .record_observation(obs(SCALE), at::<SCALE>(300))
+            .unwrap();
+        assert_eq!(trust.current().value(), at::<SCALE>(860));
+        assert_eq!(
+            (trust.observations(), trust.cumulative_correct()),
+            (1, SCALE)
+        );
+
+        let mut trust = ScaledAgentTrust::<SCALE>::default();
+        trust.decay(at::<SCALE>(100)).unwrap();
+        assert_eq!(trust.current().value(), at::<SCALE>(900));
+
+        let mut trust = ScaledAgentTrust::<SCALE>::builder()
+            .current(at::<SCALE>(800))
+            .build()
+            .unwrap();
+        trust.boost(at::<SCALE>(50)).unwrap();
+        assert_eq!(trust.current().value(), at::<SCALE>(810));
+        assert_eq!((trust.observations(), trust.cumulative_correct()), (0, 0));
+    }
+
+    #[test]
+    fn test_updates_match_specification() {
+        check_updates_match_specification::<1000>();
+        check_updates_match_specification::<10_000>();
+    }
+
+    fn check_updates_preserve_invariant<const SCALE: u64>(seed: u64) {
+        let mut rng = Rng(seed);
+        for _ in 0..200 {
+            let mut trust = ScaledAgentTrust::<SCALE>::builder()
+                .current(rng.below(SCALE + 1))
+                .build()
+                .unwrap();
+            for _ in 0..50 {
+                let before = trust.current().value();
+                let rate = rng.below(SCALE + 1);
+                match rng.below(3) {
+                    0 => {
+                        let observations = trust.observations();
+                        trust
+                            .record_observation(obs(rng.below(SCALE + 1)), rate)
+                            .unwrap();
+                        assert_eq!(trust.observations(), observations + 1);
+                    }
+                    1 => {
+                        trust.decay(rate).unwrap();
+                        assert!(trust.current().value() <= before);
+                    }
+                    _ => {
+                        trust.boost(rate).unwrap();
+                        assert!(trust.current().value() >= before);
+                    }
+                }
+                assert_valid(&trust);
+            }
+        }
+    }
+
+    #[test]
+    fn test_updates_preserve_invariant() {
+        check_updates_preserve_invariant::<1000>(0x7E57_7A05_7000_0001);
+        check_updates_preserve_invariant::<10_000>(0x7E57_7A05_7001_0001);
+    }
+
+    fn check_rejected_updates_leave_trust_unchanged<const SCALE: u64>() {
+        let mut trust = ScaledAgentTrust::<SCALE>::builder()
+            .current(at::<SCALE>(700))
+            .build()
+            .unwrap();
+        let before = trust.clone();
+        let err = trust
+            .record_observation(obs(at::<SCALE>(500)), SCALE + 1)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("alpha = {} is outside 0..={}", SCALE + 1, SCALE)
+        );
+        assert!(trust.decay(SCALE + 1).is_err());
+        assert!(trust.boost(SCALE + 1).is_err());
+        assert_eq!(trust, before);
+
+        let limit = ScaledAgentTrust::<SCALE>::MAX_OBSERVATIONS;
+        let mut full = ScaledAgentTrust::<SCALE>::builder()
+            .observations(limit)
+            .cumulative_correct(limit * SCALE)
+            .build()
+            .unwrap();
+        assert_eq!(
+            full.record_observation(obs(SCALE), at::<SCALE>(300)),
+            Err(TrustError::ObservationLimit)
+        );
+        assert_valid(&full);
+    }
+
+    #[test]
+    fn test_rejected_updates_leave_trust_unchanged() {
+        check_rejected_updates_leave_trust_unchanged::<1000>();
+        check_rejected_updates_leave_trust_unchanged::<10_000>();
+        // The limit at 1000 is the specification's
+        assert_eq!(AgentTrust::MAX_OBSERVATIONS, MAX_OBSERVATIONS);
+        assert_eq!(
+            TrustError::ObservationLimit.to_string(),
+            "agent has recorded as many observations as its scale allows"
+        );
+    }
+
+    /// Boundary values either side of the valid range and at the edges of
+    /// a u64
+    const BOUNDARY: [u64; 8] = [0, 1, 999, 1000, 1001, 1_000_000, u64::MAX - 1, u64::MAX];
+
+    const ROUNDINGS: [Rounding; 3] = [
+        Rounding::Truncate,
+        Rounding::HalfUp,
+        Rounding::TowardObservation,
+    ];
+
+    /// Trust after `steps` updates from `initial` toward `observation`
+    fn settle(initial: u64, observation: u64, alpha: u64, rounding: Rounding, steps: u64) -> u64 {
+        (0..steps).fold(initial, |trust, _| {
+            ema_update(trust, observation, alpha, rounding).unwrap()
+        })
+    }
+
+    #[test]
+    fn test_formulas_at_boundaries() {
+        let out_of_range = |name, value| Err(TrustError::OutOfRange { name, value });
+        for &a in &BOUNDARY {
+            for &b in &BOUNDARY {
+                for (&c, rounding) in BOUNDARY.iter().flat_map(|c| ROUNDINGS.map(|r| (c, r))) {
+                    let ema = ema_update(a, b, c, rounding);
+                    match (a <= 1000, b <= 1000, c <= 1000) {
+                        (false, _, _) => assert_eq!(ema, out_of_range("trust", a)),
+                        (_, false, _) => assert_eq!(ema, out_of_range("observation", b)),
+                        (_, _, false) => assert_eq!(ema, out_of_range("alpha", c)),
+                        _ => {
+                            let ema = ema.unwrap();
+                            assert!(ema <= 1000);
+                            assert!(
+                                ema >= a.min(b) && ema <= a.max(b),
+                                "{} {} {} {:?}",
+                                a,
+                                b,
+                                c,
+                                rounding
+                            );
+                        }
+                    }
+                }
+
+                let (decayed, boosted) = (trust_decay(a, b), trust_boost(a, b));
+                if a > 1000 {
+                    assert_eq!(decayed, out_of_range("trust", a));
+                    assert_eq!(boosted, out_of_range("trust", a));
+                } else if b > 1000 {
+                    assert_eq!(decayed, out_of_range("decay_rate", b));
+                    assert_eq!(boosted, out_of_range("boost_rate", b));
+                } else {
+                    assert!(decayed.unwrap() <= a);
+                    let boosted = boosted.unwrap();
+                    assert!(a <= boosted && boosted <= 1000);
+                }
+            }
+        }
+        assert_eq!(trust_decay(1000, 1000), Ok(0));
+        assert_eq!(trust_boost(0, 1000), Ok(1000));
+    }
+
+    #[test]
+    fn test_formulas_match_specification() {
+        let mut rng = Rng(0x7E57_7A05_7000_0002);
+        for _ in 0..10_000 {
+            let (current, observation, rate) = (rng.below(1001), rng.below(1001), rng.below(1001));
+            assert_eq!(
+                ema_update(current, observation, rate, Rounding::Truncate),
+                Ok((rate * observation + (1000 - rate) * current) / 1000)
+            );
+            assert_eq!(
+                trust_decay(current, rate),
+                Ok(current * (1000 - rate) / 1000)
+            );
+            assert_eq!(
+                trust_boost(current, rate),
+                Ok((current + (1000 - current) * rate / 1000).min(1000))
+            );
+            // Out-of-range inputs are rejected, never wrapped
+            let wild = 1001 + rng.next() % (u64::MAX - 1000);
+            assert!(ema_update(current, wild, rate, Rounding::default()).is_err());
+            assert!(trust_decay(wild, rate).is_err());
+            assert!(trust_boost(current, wild).is_err());
+        }
+    }
+
+    #[test]
+    fn test_rounding_drift() {
+        // 1000 updates at alpha 0.3 toward 1.0 and toward 0.0: truncation
+        // stops 3 short of 1.0, half up 1 short of either end, rounding
+        // toward the observation reaches both
+        let drift = |rounding| {
+            (
+                1000 - settle(0, 1000, 300, rounding, 1000),
+                settle(1000, 0, 300, rounding, 1000),
+            )
+        };
+        assert_eq!(drift(Rounding::Truncate), (3, 0));
+        assert_eq!(drift(Rounding::HalfUp), (1, 1));
+        assert_eq!(drift(Rounding::TowardObservation), (0, 0));
+        // The request's example: truncation holds 999 below 1000 forever
+        assert_eq!(ema_update(999, 1000, 300, Rounding::Truncate), Ok(999));
+        assert_eq!(
+            ema_update(999, 1000, 300, Rounding::TowardObservation),
+            Ok(1000)
+        );
+
+        // Across alphas, truncation's shortfall toward 1.0 grows as alpha
+        // shrinks; the fixed modes' never exceeds truncation's
+        for alpha in [1, 10, 50, 100, 300, 700, 1000] {
+            let truncated = 1000 - settle(0, 1000, alpha, Rounding::Truncate, 1000);
+            let half_up = 1000 - settle(0, 1000, alpha, Rounding::HalfUp, 1000);
+            assert!(half_up <= truncated, "alpha {}", alpha);
+            assert_eq!(
+                settle(0, 1000, alpha, Rounding::TowardObservation, 1000),
+                1000
+            );
+        }
+    }
+
+    /// The exact EMA as a fraction over 1000, by moving `1 - alpha` of
+    /// the way back from the observation to the trust
+    fn exact_ema(current: u64, observation: u64, alpha: u64) -> i128 {
+        let (c, o, a) = (current as i128, observation as i128, alpha as i128);
+        1000 * o + (c - o) * (1000 - a)
+    }
+
+    #[test]
+    fn test_ema_against_rational_reference() {
+        let modes = [
+            Rounding::Truncate,
+            Rounding::HalfUp,
+            Rounding::TowardObservation,
+        ];
+        let mut rng = Rng(0x7E57_7A05_7000_0005);
+        for _ in 0..20_000 {
+            let (current, observation) = (rng.below(1001), rng.below(1001));
+            let alpha = rng.below(1001);
+            let exact = exact_ema(current, observation, alpha);
+            for rounding in modes {
+                let next = ema_update(current, observation, alpha, rounding).unwrap() as i128;
+                // Within a point of the exact value: truncation and
+                // rounding toward the observation on the named side, half
+                // up to the nearest
+                let error = 1000 * next - exact;
+                match rounding {
+                    Rounding::Truncate => assert!((-999..=0).contains(&error)),
+                    Rounding::HalfUp => assert!((-499..=500).contains(&error)),
+                    Rounding::TowardObservation if observation >= current => {
+                        assert!((0..1000).contains(&error))
+                    }
+                    Rounding::TowardObservation => assert!((-999..=0).contains(&error)),
+                }
+
+                // ema_contracts_toward_observation
+                let next = next as u64;
+                assert!(
+                    next.abs_diff(observation) <= current.abs_diff(observation),
+                    "{:?}: {} toward {} at {} gave {}",
+                    rounding,
+                    current,
+                    observation,
+                    alpha,
+                    next
+                );
+
+                // ema_monotone_in_observation and ema_monotone_in_current
+                let (better, higher) = (rng.below(1001), rng.below(1001));
+                let (lo, hi) = (observation.min(better), observation.max(better));
+                assert!(
+                    ema_update(current, lo, alpha, rounding).unwrap()
+                        <= ema_update(current, hi, alpha, rounding).unwrap()
+                );
+                let (lo, hi) = (current.min(higher), current.max(higher));
+                assert!(
+                    ema_update(lo, observation, alpha, rounding).unwrap()
+                        <= ema_update(hi, observation, alpha, rounding).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_toward_observation_converges() {
+        // toward_observation_converges: within |observation - initial|
+        // updates for any alpha > 0, and fixed from then on
+        let mut rng = Rng(0x7E57_7A05_7000_0003);
+        for _ in 0..2000 {
+            let (initial, observation) = (rng.below(1001), rng.below(1001));
+            let alpha = 1 + rng.below(1000);
+            let steps = initial.abs_diff(observation);
+            let reached = settle(
+                initial,
+                observation,
+                alpha,
+                Rounding::TowardObservation,
+                steps,
+            );
+            assert_eq!(
+                reached, observation,
+                "{} -> {} at {}",
+                initial, observation, alpha
+            );
+            assert_eq!(
+                ema_update(reached, observation, alpha, Rounding::TowardObservation),
+                Ok(observation)
+            );
+        }
+    }
+
+    #[test]
+    fn test_floored_decay() {
+        let mut rng = Rng(0x7E57_7A05_7000_0004);
+        for _ in 0..10_000 {
+            let (current, rate, floor) = (rng.below(1001), rng.below(1001), rng.below(1001));
+            let decayed = trust_decay_floored(current, rate, floor).unwrap();
+            assert!(decayed <= current);
+            if current >= floor {
+                assert!(floor <= decayed && decayed <= 1000);
+            }
+        }
+        assert_eq!(trust_decay_floored(54, 100, 50), Ok(50));
+        assert_eq!(trust_decay_floored(40, 100, 50), Ok(40));
+        assert_eq!(trust_decay_floored(1000, 1000, 50), Ok(50));
+        assert_eq!(
+            trust_decay_floored(500, 100, 1001),
+            Err(TrustError::OutOfRange {
+                name: "trust_floor",
+                value: 1001
+            })
+        );
+    }
+
+    #[test]
+    fn test_decay_fixed_points() {
+        // decay_fixed_points, floored_decay_fixed_points: exhaustive over
+        // every trust at a range of rates
+        let thresholds = Thresholds::default();
+        let floor = thresholds.trust_floor;
+        for rate in [0, 1, 10, 50, 100, 500, 999, 1000] {
+            for current in 0..=1000 {
+                let decayed = trust_decay(current, rate).unwrap();
+                assert_eq!(decayed, current - (current * rate).div_ceil(1000));
+                assert_eq!(decayed == current, current == 0 || rate == 0);
+
+                let floored = trust_decay_floored(current, rate, floor).unwrap();
+                if rate > 0 {
+                    assert_eq!(
+                        floored == current,
+                        current <= floor,
+                        "{} at {}",
+                        current,
+                        rate
+                    );
+                }
+            }
+        }
+        // The smallest loss is one unit, even where the product is tiny
+        assert_eq!(trust_decay(1, 1), Ok(0));
+        assert_eq!(trust_decay(9, 10), Ok(8));
+
+        check_decays_reach_quarantine::<1000>();
+        check_decays_reach_quarantine::<10_000>();
+    }
+
+    /// Repeated decays from any trust (every tenth at 10_000) reach
+    /// quarantine: the only plateau is the floor, below the detection
+    /// threshold
+    fn check_decays_reach_quarantine<const SCALE: u64>() {
+        let thresholds = Thresholds::default();
+        let floor = at::<SCALE>(thresholds.trust_floor);
+        let threshold = at::<SCALE>(thresholds.detection_threshold);
+        assert!(floor < threshold);
+        for current in (0..=SCALE).step_by((SCALE / 1000) as usize) {
+            let mut trust = ScaledAgentTrust::<SCALE>::builder()
+                .current(current)
+                .build()
+                .unwrap();
+            let mut steps = 0;
+            while !trust.update_quarantine(&thresholds) {
+                trust.decay_floored(1, floor).unwrap();
+                steps += 1;
+            }
+            assert!(steps <= current.saturating_sub(threshold) + 1);
+        }
+    }
+
+    #[test]
+    fn test_inactivity_decay_matches_iteration() {
+        // inactivity_decay_settles: stopping at the floor gives exactly what
+        // decaying round by round does, over every gap up to 1500
+        for rate in [0, 1, 10, 100, 500, 1000] {
+            for floor in [0, 50] {
+                for current in [1000, 537, 301, 50, 3] {
+                    let mut iterated = current;
+                    for rounds in 0..=1500u64 {
+                        assert_eq!(
+                            trust_decay_rounds(current, rate, floor, rounds),
+                            Ok(iterated),
+                            "{} at {} floored at {} for {} rounds",
+                            current,
+                            rate,
+                            floor,
+                            rounds
+                        );
+                        iterated = trust_decay_floored(iterated, rate, floor).unwrap();
+                    }
+                    if rate > 0 {
+                        assert_eq!(iterated, current.min(floor));
+                    }
+                }
+            }
+        }
+        // Enormous gaps settle at the floor without iterating them
+        assert_eq!(trust_decay_rounds(1000, 1, 50, u64::MAX), Ok(50));
+        assert_eq!(trust_decay_rounds(1000, 0, 50, u64::MAX), Ok(1000));
+        assert_eq!(trust_decay_rounds(700, 100, 50, 0), Ok(700));
+        assert_eq!(trust_decay_rounds(40, 100, 50, 10), Ok(40));
+        // Truncation runs ahead of the real-valued power: 0.900, not 0.905
+        assert_eq!(trust_decay_rounds(1000, 1, 0, 100), Ok(900));
+        assert_eq!(
+            trust_decay_rounds(500, 1001, 50, 1),
+            Err(TrustError::OutOfRange {
+                name: "decay_rate",
+                value: 1001
+            })
+        );
+
+        let mut trust = AgentTrust::builder().current(800).build().unwrap();
+        let history = (trust.observations(), trust.cumulative_correct());
+        trust.decay_for_inactivity(3, 100, 50).unwrap();
+        assert_eq!(trust.current().value(), 583);
+        assert_eq!((trust.observations(), trust.cumulative_correct()), history);
+
+        check_agent_inactivity::<1000>();
+        check_agent_inactivity::<10_000>();
+        // Four places keep what three round away: 0.5832, not 0.583
+        let mut fine = ScaledAgentTrust::<10_000>::builder()
+            .current(8_000)
+            .build()
+            .unwrap();
+        fine.decay_for_inactivity(3, 1_000, 500).unwrap();
+        assert_eq!(fine.current().value(), 5_832);
+    }
+
+    /// An agent's inactivity decay is its floored decay once a round, and
+    /// settles at the floor
+    fn check_agent_inactivity<const SCALE: u64>() {
+        let (rate, floor) = (at::<SCALE>(100), at::<SCALE>(50));
+        for current in [SCALE, at::<SCALE>(537), at::<SCALE>(50), 3] {
+            let start = ScaledAgentTrust::<SCALE>::builder()
+                .current(current)
+                .build()
+                .unwrap();
+            let mut iterated = start.clone();
+            for rounds in 0..=60 {
+                let mut charged = start.clone();
+                charged.decay_for_inactivity(rounds, rate, floor).unwrap();
+                assert_eq!(charged, iterated, "{} for {} rounds", current, rounds);
+                iterated.decay_floored(rate, floor).unwrap();
+            }
+            assert_eq!(iterated.current().value(), current.min(floor));
+        }
+        let mut trust = ScaledAgentTrust::<SCALE>::default();
+        let err = trust.decay_for_inactivity(1, SCALE + 1, floor).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("decay_rate = {} is outside 0..={}", SCALE + 1, SCALE)
+        );
+        assert_eq!(trust, ScaledAgentTrust::default());
+    }
+
+    fn check_quarantine_misbehave_then_recover<const SCALE: u64>() {
+        let thresholds = Thresholds::default();
+        let threshold = at::<SCALE>(thresholds.detection_threshold);
+        let exit = threshold + at::<SCALE>(thresholds.quarantine_margin);
+        let (decay_rate, floor) = (
+            at::<SCALE>(thresholds.decay_rate),
+            at::<SCALE>(thresholds.trust_floor),
+        );
+        let mut trust = ScaledAgentTrust::<SCALE>::default();
+
+        // Misbehaving: quarantined at the first decay below the threshold,
+        // and held at the floor rather than decaying to zero
+        let mut entered = None;
+        for step in 0..100 {
+            let before = trust.current().value();
+            trust.decay_floored(decay_rate, floor).unwrap();
+            let after = trust.current().value();
+            assert!(floor <= after && after <= before);
+            assert_eq!(trust.update_quarantine(&thresholds), after < threshold);
+            if trust.is_quarantined() && entered.is_none() {
+                assert!(before >= threshold);
+                entered = Some(step);
+            }
+        }
+        assert!(entered.is_some());
+        assert_eq!(trust.current().value(), floor);
+
+        // Recovering: still quarantined between the threshold and the
+        // margin above it, released at the first boost past it
+        let mut released = false;
+        for _ in 0..200 {
+            let before = trust.current().value();
+            trust.boost(at::<SCALE>(thresholds.boost_rate)).unwrap();
+            let after = trust.current().value();
+            let quarantined = trust.update_quarantine(&thresholds);
+            if !released {
+                assert_eq!(quarantined, after <= exit, "{} -> {}", before, after);
+                released = !quarantined;
+                if released {
+                    assert!(before <= exit && after > exit);
+                }
+            } else {
+                assert!(!quarantined);
+            }
+        }
+        assert!(released);
+    }
+
+    #[test]
+    fn test_quarantine_misbehave_then_recover() {
+        check_quarantine_misbehave_then_recover::<1000>();
+        check_quarantine_misbehave_then_recover::<10_000>();
+    }
+
+    fn check_quarantine_hysteresis_boundaries<const SCALE: u64>() {
+        let thresholds = Thresholds::default();
+        let at_trust = |current, quarantined| {
+            let mut trust = ScaledAgentTrust::<SCALE>::builder()
+                .current(current)
+                .quarantined(quarantined)
+                .build()
+                .unwrap();
+            trust.update_quarantine(&thresholds)
+        };
+        // Entering: strictly below 0.3
+        assert!(!at_trust(at::<SCALE>(300), false));
+        assert!(at_trust(at::<SCALE>(300) - 1, false));
+        // Leaving: strictly above 0.4
+        assert!(at_trust(at::<SCALE>(300), true));
+        assert!(at_trust(at::<SCALE>(400), true));
+        assert!(!at_trust(at::<SCALE>(400) + 1, true));
+    }
+
+    #[test]
+    fn test_quarantine_hysteresis_boundaries() {
+        check_quarantine_hysteresis_boundaries::<1000>();
+        check_quarantine_hysteresis_boundaries::<10_000>();
+    }
+
+    /// Smallest k with `initial * (1 - rate / 1000)^k < threshold`, in
+    /// floating point
+    fn real_steps(initial: u64, threshold: u64, rate: u64) -> u64 {
+        let factor = 1.0 - rate as f64 / 1000.0;
+        let mut trust = initial as f64;
+        let mut steps = 0;
+        while trust >= threshold as f64 {
+            trust *= factor;
+            steps += 1;
+        }
+        steps
+    }
+
+    #[test]
+    fn test_decay_steps_against_real_valued_decay() {
+        let rates = [1, 5, 10, 25, 50, 100, 250, 500, 999, 1000];
+        let thresholds = [1, 10, 50, 100, 300, 500, 900, 1000];
+        for &rate in &rates {
+            for &threshold in &thresholds {
+                for initial in [threshold, 301, 500, 999, 1000] {
+                    if initial < threshold {
+                        continue;
+                    }
+                    let steps = decay_steps_to(initial, threshold, rate).unwrap().unwrap();
+                    // Exactly the first decay below the threshold
+                    let after = |k| (0..k).fold(initial, |t, _| trust_decay(t, rate).unwrap());
+                    assert!(after(steps) < threshold);
+                    assert!(after(steps - 1) >= threshold);
+                    assert!(steps <= initial - threshold + 1);
+
+                    // Truncation flags no later than the real-valued decay,
+                    // and loses under one unit per decay: an agent still at
+                    // threshold + k after k real-valued decays is not yet
+                    // flagged
+                    let real = real_steps(initial, threshold, rate);
+                    assert!(
+                        steps <= real,
+                        "{} {} {}: {} > {}",
+                        initial,
+                        threshold,
+                        rate,
+                        steps,
+                        real
+                    );
+                    let factor = 1.0 - rate as f64 / 1000.0;
+                    let lower = (0..)
+                        .find(|&k| initial as f64 * factor.powi(k as i32) < (threshold + k) as f64)
+                        .unwrap();
+                    assert!(
+                        steps >= lower,
+                        "{} {} {}: {} < {}",
+                        initial,
+                        threshold,
+                        rate,
+                        steps,
+                        lower
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decay_steps_bounds() {
+        // Full trust to the default detection threshold at the default
+        // decay rate: 0.9^12 = 0.282
+        let thresholds = Thresholds::default();
+        assert_eq!(
+            decay_steps_to(1000, thresholds.detection_threshold, thresholds.decay_rate),
+            Ok(Some(12))
+        );
+        assert_eq!(decay_steps_to(299, 300, 100), Ok(Some(0)));
+        assert_eq!(decay_steps_to(500, 300, 0), Ok(None));
+        assert_eq!(decay_steps_to(500, 0, 100), Ok(None));
+        assert_eq!(decay_steps_to(0, 0, 100), Ok(None));
+        assert_eq!(decay_steps_to(1000, 1000, 1000), Ok(Some(1)));
+        assert!(decay_steps_to(1001, 300, 100).is_err());
+        assert!(decay_steps_to(1000, 300, 1001).is_err());
+
+        // Worst case from full trust to 0.001, per rate: every rate takes
+        // at most 1000 decays, and truncation keeps even rate 10 under 300
+        for (rate, worst) in [(1, 1000), (10, 292), (50, 88), (100, 50), (500, 10)] {
+            let most = (1..=1000)
+                .map(|threshold| decay_steps_to(1000, threshold, rate).unwrap().unwrap())
+                .max()
+                .unwrap();
+            assert_eq!(most, worst, "rate {}", rate);
+        }
+    }
+
+    fn check_builder_checks_history<const SCALE: u64>() {
+        let err = ScaledAgentTrust::<SCALE>::builder()
+            .observations(2)
+            .cumulative_correct(2 * SCALE + 1)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TrustError::InconsistentHistory {
+                observations: 2,
+                cumulative_correct: 2 * SCALE + 1
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "cumulative correctness {} exceeds full trust per observation over 2 observations",
+                2 * SCALE + 1
+            )
+        );
+        assert!(ScaledAgentTrust::<SCALE>::builder()
+            .current(SCALE + 1)
+            .build()
+            .is_err());
+        assert_eq!(
+            ScaledAgentTrust::<SCALE>::builder()
+                .observations(ScaledAgentTrust::<SCALE>::MAX_OBSERVATIONS + 1)
+                .build(),
+            Err(TrustError::ObservationLimit)
+        );
+        assert_eq!(
+            ScaledAgentTrust::<SCALE>::builder().build(),
+            Ok(ScaledAgentTrust::default())
+        );
+        let trust = ScaledAgentTrust::<SCALE>::builder()
+            .rounding(Rounding::TowardObservation)
+            .current(SCALE - 1)
+            .build()
+            .unwrap();
+        assert_eq!(trust.rounding(), Rounding::TowardObservation);
+    }
+
+    #[test]
+    fn test_builder_checks_history() {
+        check_builder_checks_history::<1000>();
+        check_builder_checks_history::<10_000>();
+    }
+
+    #[cfg(feature = "serde")]
+    fn check_serde_round_trip_and_validation<const SCALE: u64>() {
+        let trust = ScaledAgentTrust::<SCALE>::builder()
+            .current(at::<SCALE>(640))
+            .observations(3)
+            .cumulative_correct(at::<SCALE>(2500))
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&trust).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"current":{},"observations":3,"cumulative_correct":{},"rounding":"half_up","quarantined":false,"window_size":50,"recent":[],"calibration":{},"latency":{}}}"#,
+                at::<SCALE>(640),
+                at::<SCALE>(2500),
+                SCALE,
+                SCALE
+            )
+        );
+        assert_eq!(
+            serde_json::from_str::<ScaledAgentTrust<SCALE>>(&json).unwrap(),
+            trust
+        );
+
+        // Seeding from config: omitted fields take the builder defaults
+        let seeded: ScaledAgentTrust<SCALE> =
+            serde_json::from_str(&format!(r#"{{"current":{}}}"#, at::<SCALE>(500))).unwrap();
+        assert_eq!(
+            seeded,
+            ScaledAgentTrust::builder()
+                .current(at::<SCALE>(500))
+                .build()
+                .unwrap()
+        );
+
+        let parse = |json: String| serde_json::from_str::<ScaledAgentTrust<SCALE>>(&json);
+        assert!(serde_json::from_str::<ScaledTrust<SCALE>>(&(SCALE + 1).to_string()).is_err());
+        assert!(parse(format!(r#"{{"current":{}}}"#, SCALE + 1)).is_err());
+        assert!(parse(format!(
+            r#"{{"observations":1,"cumulative_correct":{}}}"#,
+            SCALE + 1
+        ))
+        .is_err());
+        assert!(parse(format!(
+            r#"{{"window_size":2,"recent":[{},0,{}]}}"#,
+            SCALE, SCALE
+        ))
+        .is_err());
+        assert!(parse(r#"{"scale":1000}"#.to_string()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip_and_validation() {
+        check_serde_round_trip_and_validation::<1000>();
+        check_serde_round_trip_and_validation::<10_000>();
+        // The format at 1000 is unchanged
+        let trust = AgentTrust::builder()
+            .current(640)
+            .observations(3)
+            .cumulative_correct(2500)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&trust).unwrap(),
+            r#"{"current":640,"observations":3,"cumulative_correct":2500,"rounding":"half_up","quarantined":false,"window_size":50,"recent":[],"calibration":1000,"latency":1000}"#
+        );
+    }
+
+    fn check_capped_observations<const SCALE: u64>(seed: u64) {
+        // capped_update_bounds: between the trust and the uncapped update,
+        // within max_delta of the trust
+        let mut rng = Rng(seed);
+        for _ in 0..20_000 {
+            let current = rng.below(SCALE + 1);
+            let (observation, alpha, max_delta) = (
+                rng.below(SCALE + 1),
+                rng.below(SCALE + 1),
+                rng.below(SCALE + 1),
+            );
+            let mut capped = ScaledAgentTrust::<SCALE>::builder()
+                .current(current)
+                .build()
+                .unwrap();
+            let mut uncapped = capped.clone();
+            capped
+                .record_observation_capped(obs(observation), alpha, max_delta)
+                .unwrap();
+            uncapped
+                .record_observation(obs(observation), alpha)
+                .unwrap();
+            let (next, after) = (uncapped.current().value(), capped.current().value());
+            assert!(after.min(current) >= next.min(current) && after <= next.max(current));
+            assert!(after.abs_diff(current) <= max_delta);
+            assert_eq!(after == next, next.abs_diff(current) <= max_delta);
+            assert_eq!(capped.observations(), 1);
+        }
+
+        // Composes with decay: observations climb at most the cap a round,
+        // decays apply in full and stop at the floor
+        let thresholds = Thresholds::default();
+        let (decay_rate, floor, max_delta) = (
+            at::<SCALE>(thresholds.decay_rate),
+            at::<SCALE>(thresholds.trust_floor),
+            at::<SCALE>(thresholds.max_trust_delta),
+        );
+        let mut trust = ScaledAgentTrust::<SCALE>::builder()
+            .current(at::<SCALE>(500))
+            .build()
+            .unwrap();
+        for round in 0..300 {
+            let before = trust.current().value();
+            if round % 3 == 2 {
+                trust.decay_floored(decay_rate, floor).unwrap();
+                let expected = trust_decay_floored_at(SCALE, before, decay_rate, floor);
+                assert_eq!(trust.current().value(), expected.unwrap());
+            } else {
+                trust
+                    .record_observation_capped(obs(SCALE), SCALE, max_delta)
+                    .unwrap();
+                let after = trust.current().value();
+                assert_eq!(after, (before + max_delta).min(SCALE));
+            }
+        }
+
+        let mut trust = ScaledAgentTrust::<SCALE>::default();
+        let before = trust.clone();
+        assert!(trust
+            .record_observation_capped(obs(0), at::<SCALE>(300), SCALE + 1)
+            .is_err());
+        assert_eq!(trust, before);
+    }
+
+    #[test]
+    fn test_capped_observations() {
+        check_capped_observations::<1000>(0x5eed_1618);
+        check_capped_observations::<10_000>(0x5eed_1629);
+    }
+
+    #[test]
+    fn test_boost_rounding_error() {
+        for current in 0..=1000 {
+            for boost_rate in [0, 1, 7, 50, 333, 999, 1000] {
+                let boosted = trust_boost(current, boost_rate).unwrap();
+                let real = 1000 * current + (1000 - current) * boost_rate;
+                assert!(1000 * boosted <= real && real < 1000 * boosted + 1000);
+                if (1000 - current) * boost_rate < 1000 {
+                    assert_eq!(boosted, current);
+                }
+            }
+            assert_eq!(trust_boost(current, 1000), Ok(1000));
+            assert_eq!(trust_boost_exact(current, 0, 1000), Ok((1000, 0)));
+        }
+        assert_eq!(trust_boost(1, 999), Ok(999));
+
+        assert_eq!(
+            trust_boost_exact(500, 1000, 50),
+            Err(TrustError::Remainder {
+                current: 500,
+                remainder: 1000
+            })
+        );
+        assert!(trust_boost_exact(1000, 1, 50).is_err());
+        assert!(trust_boost_exact(1001, 0, 50).is_err());
+        assert!(trust_boost_exact(500, 0, 1001).is_err());
+    }
+
+    #[test]
+    fn test_exact_boost_reaches_closed_form() {
+        for initial in [0u64, 1, 300, 800, 999] {
+            for boost_rate in [1u64, 10, 50, 300, 999, 1000] {
+                let (mut truncated, mut exact) = (initial, (initial, 0));
+                let factor = 1.0 - boost_rate as f64 / 1000.0;
+                for n in 1..=2000 {
+                    truncated = trust_boost(truncated, boost_rate).unwrap();
+                    exact = trust_boost_exact(exact.0, exact.1, boost_rate).unwrap();
+                    let limit = 1000.0 - (1000 - initial) as f64 * factor.powi(n);
+                    let carried = exact.0 as f64 + exact.1 as f64 / 1000.0;
+                    assert!(carried <= limit + 1e-9, "{} {} {}", initial, boost_rate, n);
+                    assert!(limit - carried < 1.0, "{} {} {}", initial, boost_rate, n);
+                    assert!(truncated <= exact.0);
+                }
+                // Rounded down, boosts stall where gap * rate < 1000
+                let stall = 1000 - (999 / boost_rate).min(1000 - initial);
+                assert_eq!(truncated, stall, "{} {}", initial, boost_rate);
+            }
+        }
+        // At the default 5%, 200 boosts from 0.8: 0.981 against 0.999981
+        let thresholds = Thresholds::default();
+        let (mut truncated, mut exact) = (800, (800, 0));
+        for _ in 0..200 {
+            truncated = trust_boost(truncated, thresholds.boost_rate).unwrap();
+            exact = trust_boost_exact(exact.0, exact.1, thresholds.boost_rate).unwrap();
+        }
+        assert_eq!((truncated, exact), (981, (999, 981)));
+    }
+
+    #[test]
+    fn test_alpha_schedule() {
+        let decreasing = AlphaSchedule::Decreasing {
+            alpha_min: 50,
+            alpha_max: 1000,
+        };
+        let alphas: Vec<u64> = [0, 1, 2, 3, 9, 18, 19, 20, 1000]
+            .iter()
+            .map(|&k| decreasing.alpha(k))
+            .collect();
+        assert_eq!(alphas, [1000, 500, 333, 250, 100, 53, 50, 50, 50]);
+        assert_eq!(decreasing.alpha(u64::MAX), 50);
+        let capped = AlphaSchedule::Decreasing {
+            alpha_min: 0,
+            alpha_max: 400,
+        };
+        assert_eq!(capped.alpha(0), 400);
+        assert_eq!(capped.alpha(3), 250);
+        assert_eq!(capped.alpha(5000), 0);
+
+        assert_eq!(
+            AlphaSchedule::Decreasing {
+                alpha_min: 500,
+                alpha_max: 400
+            }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+            "alpha_min 500 exceeds alpha_max 400"
+        );
+        check_agent_alpha_schedule::<1000>();
+        check_agent_alpha_schedule::<10_000>();
+        // At 10_000 a decreasing schedule picks ten-thousandths
+        let fine_schedule = AlphaSchedule::Decreasing {
+            alpha_min: 500,
+            alpha_max: 10_000,
+        };
+        let fine: Vec<u64> = [0, 2, 18]
+            .iter()
+            .map(|&k| fine_schedule.alpha_at(10_000, k))
+            .collect();
+        assert_eq!(fine, [10_000, 3_333, 526]);
+    }
+
+    /// An agent's scheduled observations take alphas scaled by its scale
+    fn check_agent_alpha_schedule<const SCALE: u64>() {
+        let mut trust = ScaledAgentTrust::<SCALE>::default();
+        let before = trust.clone();
+        for schedule in [
+            AlphaSchedule::Fixed { alpha: SCALE + 1 },
+            AlphaSchedule::Decreasing {
+                alpha_min: 0,
+                alpha_max: SCALE + 1,
+            },
+        ] {
+            assert!(trust
+                .record_scheduled(obs(at::<SCALE>(500)), &schedule)
+                .is_err());
+            assert_eq!(trust, before);
+        }
+
+        // A fixed schedule is record_observation
+        let mut fixed = ScaledAgentTrust::<SCALE>::default();
+        let alpha = at::<SCALE>(300);
+        trust
+            .record_scheduled(obs(at::<SCALE>(600)), &AlphaSchedule::Fixed { alpha })
+            .unwrap();
+        fixed
+            .record_observation(obs(at::<SCALE>(600)), alpha)
+            .unwrap();
+        assert_eq!(trust, fixed);
+
+        // The first observation of a decreasing schedule weighs in full
+        let mut fresh = ScaledAgentTrust::<SCALE>::default();
+        let unclipped = AlphaSchedule::Decreasing {
+            alpha_min: 0,
+            alpha_max: SCALE,
+        };
+        fresh
+            .record_scheduled(obs(at::<SCALE>(250)), &unclipped)
+            .unwrap();
+        assert_eq!(fresh.current().value(), at::<SCALE>(250));
+    }
+
+    fn check_decreasing_schedule_tracks_running_mean<const SCALE: u64>(seed: u64) {
+        let schedule = AlphaSchedule::Decreasing {
+            alpha_min: 0,
+            alpha_max: SCALE,
+        };
+
+        // decreasing_schedule_identical_observations: exact, from any
+        // trust and in every rounding mode
+        let mut rng = Rng(seed);
+        for _ in 0..500 {
+            let observation = rng.below(SCALE + 1);
+            let rounding = [
+                Rounding::Truncate,
+                Rounding::HalfUp,
+                Rounding::TowardObservation,
+            ][rng.below(3) as usize];
+            let mut trust = ScaledAgentTrust::<SCALE>::builder()
+                .current(rng.below(SCALE + 1))
+                .rounding(rounding)
+                .build()
+                .unwrap();
+            for _ in 0..1 + rng.below(100) {
+                trust.record_scheduled(obs(observation), &schedule).unwrap();
+                assert_eq!(trust.current().value(), observation);
+            }
+        }
+
+        // Mixed observations: against the exact running mean, in
+        // thousandths of a unit. Each alpha is rounded to a unit of the
+        // scale, so the trust drifts from the mean, but by under 10 units
+        // over 200 observations; a fixed alpha of 0.3 follows the last few.
+        let mut worst = 0;
+        let mut worst_fixed = 0;
+        for _ in 0..500 {
+            let mut trust = ScaledAgentTrust::<SCALE>::default();
+            let mut fixed = ScaledAgentTrust::<SCALE>::default();
+            for n in 1..=200 {
+                let observation = rng.below(SCALE + 1);
+                trust.record_scheduled(obs(observation), &schedule).unwrap();
+                fixed
+                    .record_observation(obs(observation), at::<SCALE>(300))
+                    .unwrap();
+                let error = |t: &ScaledAgentTrust<SCALE>| {
+                    (1000 * t.current().value() as i128 * n - 1000 * t.cumulative_correct() as i128)
+                        .abs()
+                        / n
+                };
+                worst = worst.max(error(&trust));
+                worst_fixed = worst_fixed.max(error(&fixed));
+                // Alphas SCALE and SCALE / 2 are exact: only the trust
+                // rounds
+                if n <= 2 {
+                    assert!(error(&trust) <= 500, "n {}", n);
+                }
+            }
+        }
+        assert!(worst < 10_000, "{}", worst);
+        assert!(worst_fixed > 100_000, "{}", worst_fixed);
+    }
+
+    #[test]
+    fn test_decreasing_schedule_tracks_running_mean() {
+        check_decreasing_schedule_tracks_running_mean::<1000>(0x5eed_1616);
+        check_decreasing_schedule_tracks_running_mean::<10_000>(0x5eed_1629);
+    }
+
+    fn check_composite_trust<const SCALE: u64>(seed: u64) {
+        let trust = ScaledAgentTrust::<SCALE>::builder()
+            .current(at::<SCALE>(800))
+            .calibration(at::<SCALE>(600))
+            .latency(at::<SCALE>(200))
+            .build()
+            .unwrap();
+        assert_eq!(trust.composite([1000, 0, 0]), Ok(trust.current()));
+        assert_eq!(
+            trust.composite([500, 250, 250]).unwrap().value(),
+            at::<SCALE>(600)
+        );
+        assert_eq!(
+            trust.component(TrustComponent::Latency).value(),
+            at::<SCALE>(200)
+        );
+
+        // Weights are thousandths at every scale, summing to exactly 1000
+        for weights in [
+            [0, 0, 0],
+            [500, 250, 249],
+            [500, 250, 251],
+            [u64::MAX, 1, 0],
+            [SCALE, 0, 0],
+        ] {
+            let sum = weights.iter().map(|&w| u128::from(w)).sum();
+            if sum != 1000 {
+                assert_eq!(trust.composite(weights), Err(TrustError::WeightSum { sum }));
+            }
+        }
+        let err = ScaledAgentTrust::<SCALE>::builder()
+            .latency(SCALE + 1)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("latency = {} is outside 0..={}", SCALE + 1, SCALE)
+        );
+
+        // composite_trust_bounded: between the least and greatest component
+        let mut rng = Rng(seed);
+        for _ in 0..10_000 {
+            let components = [
+                rng.below(SCALE + 1),
+                rng.below(SCALE + 1),
+                rng.below(SCALE + 1),
+            ];
+            let trust = ScaledAgentTrust::<SCALE>::builder()
+                .current(components[0])
+                .calibration(components[1])
+                .latency(components[2])
+                .build()
+                .unwrap();
+            let wa = rng.below(1001);
+            let wc = rng.below(1001 - wa);
+            let weights = [wa, wc, 1000 - wa - wc];
+            let composite = trust.composite(weights).unwrap().value();
+            let lo = *components.iter().min().unwrap();
+            let hi = *components.iter().max().unwrap();
+            assert!(
+                lo <= composite && composite <= hi,
+                "{:?} {:?}",
+                components,
+                weights
+            );
+        }
+    }
+
+    #[test]
+    fn test_composite_trust() {
+        check_composite_trust::<1000>(0x5eed_1615);
+        check_composite_trust::<10_000>(0x5eed_1629);
+        assert_eq!(
+            TrustError::WeightSum { sum: 999 }.to_string(),
+            "component weights sum to 999, not 1000"
+        );
+        assert_eq!(
+            AgentTrust::builder().latency(1001).build(),
+            Err(TrustError::OutOfRange {
+                name: "latency",
+                value: 1001
+            })
+        );
+        assert_eq!(composite_trust([800, 600, 200], [500, 250, 250]), Ok(600));
+        assert!(composite_trust([1001, 0, 0], [1000, 0, 0]).is_err());
+    }
+
+    fn check_components_update_independently<const SCALE: u64>() {
+        let alpha = at::<SCALE>(300);
+        let mut trust = ScaledAgentTrust::<SCALE>::builder()
+            .window_size(3)
+            .build()
+            .unwrap();
+        trust
+            .record_component(TrustComponent::Calibration, obs(0), alpha)
+            .unwrap();
+        trust
+            .record_component(TrustComponent::Latency, obs(at::<SCALE>(500)), alpha)
+            .unwrap();
+        assert_eq!(
+            trust.component(TrustComponent::Calibration).value(),
+            at::<SCALE>(700)
+        );
+        assert_eq!(
+            trust.component(TrustComponent::Latency).value(),
+            at::<SCALE>(850)
+        );
+        // Only accuracy observations enter the history and the window
+        assert_eq!(trust.current(), ScaledTrust::MAX);
+        assert_eq!((trust.observations(), trust.recent().len()), (0, 0));
+
+        trust
+            .record_component(TrustComponent::Accuracy, obs(0), alpha)
+            .unwrap();
+        assert_eq!(trust.current().value(), at::<SCALE>(700));
+        assert_eq!((trust.observations(), trust.recent().len()), (1, 1));
+    }
+
+    #[test]
+    fn test_components_update_independently() {
+        check_components_update_independently::<1000>();
+        check_components_update_independently::<10_000>();
+    }
+
+    /// scaled_updates_preserve_bounds at `SCALE`, with `FINER` a multiple
+    /// of it: every update stays in
+    /// `0..=SCALE`, the EMA between the trust and the observation, decay
+    /// never raising and boost never lowering the trust
+    fn check_scaled_updates<const SCALE: u64, const FINER: u64>(seed: u64) {
+        let mut rng = Rng(seed);
+        let roundings = [
+            Rounding::Truncate,
+            Rounding::HalfUp,
+            Rounding::TowardObservation,
+        ];
+        let mut draw = |rng: &mut Rng| match rng.below(8) {
+            0 => 0,
+            1 => SCALE,
+            _ => rng.below(SCALE + 1),
+        };
+        for _ in 0..20_000 {
+            let (current, observation, alpha, rate) = (
+                draw(&mut rng),
+                draw(&mut rng),
+                draw(&mut rng),
+                draw(&mut rng),
+            );
+            let trust = ScaledTrust::<SCALE>::new(current).unwrap();
+            let rounding = roundings[rng.below(3) as usize];
+
+            let ema = trust.ema(observation, alpha, rounding).unwrap().value();
+            assert!(
+                current.min(observation) <= ema && ema <= current.max(observation),
+                "ema({}, {}, {}) = {} at {}",
+                current,
+                observation,
+                alpha,
+                ema,
+                SCALE
+            );
+            let decayed = trust.decay(rate).unwrap().value();
+            assert!(decayed <= current);
+            let boosted = trust.boost(rate).unwrap().value();
+            assert!(current <= boosted && boosted <= SCALE);
+            if current > 0 && rate > 0 {
+                assert!(decayed < current, "decay({}, {})", current, rate);
+            }
+
+            // Up to a multiple of the scale and back is exact
+            let back = trust.rescale::<FINER>().rescale::<SCALE>();
+            assert_eq!(back, trust);
+        }
+
+        let max = ScaledTrust::<SCALE>::MAX;
+        assert_eq!(max.value(), SCALE);
+        assert_eq!(max.boost(SCALE).unwrap(), max);
+        assert_eq!(max.decay(SCALE).unwrap().value(), 0);
+        let err = ScaledTrust::<SCALE>::new(SCALE + 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("trust = {} is outside 0..={}", SCALE + 1, SCALE)
+        );
+        assert!(max.ema(SCALE + 1, 0, Rounding::HalfUp).is_err());
+        assert!(max.ema(0, SCALE + 1, Rounding::HalfUp).is_err());
+        assert!(max.decay(SCALE + 1).is_err());
+        assert!(max.boost(SCALE + 1).is_err());
+    }
+
+    #[test]
+    fn test_scaled_updates() {
+        check_scaled_updates::<10, 1000>(0x5eed_0010);
+        check_scaled_updates::<1000, 10_000>(0x5eed_1000);
+        check_scaled_updates::<10_000, 1_000_000>(0x5eed_0001_0000);
+        // Products past a u64 at this scale; the u128 intermediates hold
+        // them (scaled_updates_no_overflow)
+        check_scaled_updates::<1_000_000_000_000, 10_000_000_000_000>(0x5eed_0100_0000);
+    }
+
+    #[test]
+    fn test_scale_1000_is_the_trust_score() {
+        let mut rng = Rng(0x5eed_1629);
+        for _ in 0..20_000 {
+            let (current, observation, alpha, rate) = (
+                rng.below(1001),
+                rng.below(1001),
+                rng.below(1001),
+                rng.below(1001),
+            );
+            let rounding = [Rounding::Truncate, Rounding::HalfUp][rng.below(2) as usize];
+            let trust = TrustScore::new(current).unwrap();
+            assert_eq!(
+                trust.ema(observation, alpha, rounding).unwrap().value(),
+                ema_update(current, observation, alpha, rounding).unwrap()
+            );
+            assert_eq!(
+                trust.decay(rate).unwrap().value(),
+                trust_decay(current, rate).unwrap()
+            );
+            assert_eq!(
+                trust.boost(rate).unwrap().value(),
+                trust_boost(current, rate).unwrap()
+            );
+        }
+        // The default scale keeps its errors
+        assert_eq!(
+            TrustScore::new(1001),
+            Err(TrustError::OutOfRange {
+                name: "trust",
+                value: 1001
+            })
+        );
+        assert_eq!(
+            ScaledTrust::<10_000>::new(10_001),
+            Err(TrustError::OutOfScale {
+                name: "trust",
+                value: 10_001,
+                scale: 10_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_rescale_rounds_half_up() {
+        let fine = |value| ScaledTrust::<10_000>::new(value).unwrap();
+        for (value, coarse) in [
+            (0, 0),
+            (4, 0),
+            (5, 1),
+            (8_604, 860),
+            (8_605, 861),
+            (9_995, 1000),
+        ] {
+            assert_eq!(fine(value).rescale::<1000>().value(), coarse, "{}", value);
+        }
+        assert_eq!(
+            TrustScore::new(861).unwrap().rescale::<10_000>(),
+            fine(8_610)
+        );
+        // Between scales that do not divide each other
+        let third = ScaledTrust::<300>::new(100).unwrap();
+        assert_eq!(third.rescale::<1000>().value(), 333);
+        assert_eq!(third.rescale::<1000>().rescale::<300>(), third);
+        assert_eq!(third.to_string(), "100/300");
+        assert_eq!(fine(8_605).to_string(), "0.8605");
+        assert_eq!(TrustScore::MAX.to_string(), "1.000");
+        assert_eq!(ScaledTrust::<10>::new(7).unwrap().to_string(), "0.7");
+    }
+
+    #[test]
+    fn test_observation_constructors() {
+        assert_eq!(Observation::correct().value(), 1000);
+        assert_eq!(Observation::incorrect().value(), 0);
+        assert_eq!(Observation::from(true), Observation::correct());
+        assert_eq!(Observation::from(false), Observation::incorrect());
+        assert_eq!(Observation::partial(1000).unwrap(), Observation::correct());
+        assert_eq!(
+            Observation::partial(1001),
+            Err(TrustError::OutOfRange {
+                name: "observation",
+                value: 1001
+            })
+        );
+        assert_eq!(Observation::try_from(750).map(u64::from), Ok(750));
+        assert!(Observation::try_from(u64::MAX).is_err());
+
+        // Graded tasks scale to thousandths, rounding half up
+        for (score, weight, scaled) in [
+            (0, 7, 0),
+            (7, 7, 1000),
+            (95, 100, 950),
+            (1, 3, 333),
+            (2, 3, 667),
+            (1, 2000, 1),
+            (1, 2001, 0),
+            (u64::MAX, u64::MAX, 1000),
+            (u64::MAX - 1, u64::MAX, 1000),
+        ] {
+            assert_eq!(
+                Observation::from_score_and_weight(score, weight).map(Observation::value),
+                Ok(scaled),
+                "{} / {}",
+                score,
+                weight
+            );
+        }
+        for (score, weight) in [(0, 0), (5, 0), (101, 100)] {
+            let err = Observation::from_score_and_weight(score, weight).unwrap_err();
+            assert_eq!(err, TrustError::Grade { score, weight });
+        }
+
+        // At 10_000 graded tasks keep a fourth place
+        let fine = |score, weight| {
+            ScaledObservation::<10_000>::from_score_and_weight(score, weight).map(u64::from)
+        };
+        assert_eq!(ScaledObservation::<10_000>::correct().value(), 10_000);
+        assert_eq!(
+            [fine(1, 3), fine(2, 3), fine(95, 100), fine(7, 7)],
+            [Ok(3_333), Ok(6_667), Ok(9_500), Ok(10_000)]
+        );
+        assert_eq!(
+            fine(101, 100),
+            Err(TrustError::Grade {
+                score: 101,
+                weight: 100
+            })
+        );
+        assert_eq!(
+            ScaledObservation::<10_000>::partial(10_001),
+            Err(TrustError::OutOfScale {
+                name: "observation",
+                value: 10_001,
+                scale: 10_000
+            })
+        );
+        assert_eq!(
+            TrustError::Grade {
+                score: 101,
+                weight: 100
+            }
+            .to_string(),
+            "score 101 out of 100 is not a grade (needs 0 < weight and score <= weight)"
+        );
+    }
+
+    fn check_window_statistics<const SCALE: u64>(seed: u64) {
+        let mut trust = ScaledAgentTrust::<SCALE>::builder()
+            .window_size(4)
+            .build()
+            .unwrap();
+        assert_eq!(trust.recent_accuracy_scaled(), None);
+        assert_eq!(trust.recent_variance_scaled(), None);
+        assert_eq!(trust.divergence(), None);
+
+        // The window keeps the last four observations
+        for observation in [SCALE, SCALE, 0, SCALE, 0] {
+            trust
+                .record_observation(obs(observation), at::<SCALE>(300))
+                .unwrap();
+        }
+        assert!(trust.window_full());
+        assert_eq!(trust.recent().collect::<Vec<_>>(), [SCALE, 0, SCALE, 0]);
+        assert_eq!(trust.observations(), 5);
+        assert_eq!(trust.recent_accuracy_scaled(), Some(at::<SCALE>(500)));
+        // Observations split between 0 and full marks vary the most: 0.25
+        assert_eq!(trust.recent_variance_scaled(), Some(at::<SCALE>(250)));
+        let current = trust.current().value() as i64;
+        assert_eq!(trust.divergence(), Some(current - at::<SCALE>(500) as i64));
+
+        let steady = ScaledAgentTrust::<SCALE>::builder()
+            .current(at::<SCALE>(600))
+            .recent([at::<SCALE>(700); 3])
+            .build()
+            .unwrap();
+        assert_eq!(steady.recent_variance_scaled(), Some(0));
+        assert_eq!(steady.divergence(), Some(-(at::<SCALE>(100) as i64)));
+        assert!(!steady.window_full());
+
+        // Random windows: mean and variance match their definitions
+        let mut rng = Rng(seed);
+        for _ in 0..1000 {
+            let recent: Vec<u64> = (0..1 + rng.below(60))
+                .map(|_| rng.below(SCALE + 1))
+                .collect();
+            let trust = ScaledAgentTrust::<SCALE>::builder()
+                .window_size(60)
+                .recent(recent.iter().copied())
+                .build()
+                .unwrap();
+            let n = recent.len() as f64;
+            let mean = recent.iter().sum::<u64>() as f64 / n;
+            let variance = recent
+                .iter()
+                .map(|&x| (x as f64 - mean).powi(2))
+                .sum::<f64>()
+                / n;
+            let accuracy = trust.recent_accuracy_scaled().unwrap();
+            assert!(accuracy <= SCALE);
+            assert!((accuracy as f64 - mean.floor()).abs() < 1e-9);
+            let scaled = trust.recent_variance_scaled().unwrap();
+            assert!(scaled <= SCALE / 4);
+            assert!((scaled as f64 - (variance / SCALE as f64).floor()).abs() <= 1.0);
+        }
+
+        assert_eq!(
+            ScaledAgentTrust::<SCALE>::builder().window_size(0).build(),
+            Err(TrustError::EmptyWindow)
+        );
+        let err = ScaledAgentTrust::<SCALE>::builder()
+            .window_size(2)
+            .recent([SCALE, 0, SCALE])
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "3 recent observations exceed a window of 2"
+        );
+        let err = ScaledAgentTrust::<SCALE>::builder()
+            .recent([SCALE + 1])
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("observation = {} is outside 0..={}", SCALE + 1, SCALE)
+        );
+    }
+
+    #[test]
+    fn test_window_statistics() {
+        check_window_statistics::<1000>(0x5eed_1614);
+        check_window_statistics::<10_000>(0x5eed_1629);
+    }
+
+    fn detection_latency<const SCALE: u64>(
+        thresholds: &Thresholds,
+        alpha: u64,
+        good_rounds: u64,
+    ) -> (u64, Suspicion<SCALE>) {
+        let mut trust = ScaledAgentTrust::<SCALE>::default();
+        for _ in 0..good_rounds {
+            trust.record_observation(obs(SCALE), alpha).unwrap();
+            assert_eq!(trust.suspicion(thresholds), None);
+            assert!(!trust.update_quarantine(thresholds));
+        }
+        for round in 1..=1000 {
+            trust.record_observation(obs(0), alpha).unwrap();
+            let suspicion = trust.suspicion(thresholds);
+            assert_eq!(trust.update_quarantine(thresholds), suspicion.is_some());
+            if let Some(suspicion) = suspicion {
+                return (round, suspicion);
+            }
+        }
+        panic!("agent turned at alpha {} never flagged", alpha);
+    }
+
+    fn check_divergence_detects_turned_agent<const SCALE: u64>(ema_rounds: u64) {
+        let thresholds = Thresholds::default();
+        let ema_only = Thresholds {
+            divergence_threshold: 1000,
+            ..thresholds
+        };
+        let latency = |thresholds: &Thresholds, alpha| {
+            detection_latency::<SCALE>(thresholds, at::<SCALE>(alpha), 500)
+        };
+
+        // At alpha 0.01 the EMA credits 500 good rounds for about 120 bad
+        // ones (`ema_rounds`, as the scale rounds them); the window empties
+        // of them in 23
+        let (rounds, suspicion) = latency(&thresholds, 10);
+        assert_eq!(rounds, 23);
+        let Suspicion::Diverging { divergence } = suspicion else {
+            panic!("{:?}", suspicion);
+        };
+        assert!(divergence >= at::<SCALE>(thresholds.divergence_threshold) as i64);
+        let (rounds, suspicion) = latency(&ema_only, 10);
+        assert_eq!(rounds, ema_rounds);
+        assert!(matches!(suspicion, Suspicion::LowTrust { .. }));
+
+        // At the default alpha the EMA falls below the threshold first
+        let (rounds, suspicion) = latency(&thresholds, thresholds.trust_alpha);
+        assert_eq!(rounds, 4);
+        assert!(matches!(suspicion, Suspicion::LowTrust { .. }));
+
+        // The window never delays detection
+        for alpha in [5, 10, 20, 50, 100, 300, 1000] {
+            let (with_window, _) = latency(&thresholds, alpha);
+            let (without, _) = latency(&ema_only, alpha);
+            assert!(with_window <= without, "alpha {}", alpha);
+        }
+    }
+
+    #[test]
+    fn test_divergence_detects_turned_agent() {
+        check_divergence_detects_turned_agent::<1000>(121);
+        check_divergence_detects_turned_agent::<10_000>(120);
+    }
+
+    fn check_diverging_quarantine_hysteresis<const SCALE: u64>() {
+        let thresholds = Thresholds::default();
+        let recovering =
+            at::<SCALE>(thresholds.divergence_threshold - thresholds.quarantine_margin);
+        let trust = at::<SCALE>(900);
+        let at_recent = |recent: u64, quarantined| {
+            let mut agent = ScaledAgentTrust::<SCALE>::builder()
+                .current(trust)
+                .window_size(2)
+                .recent([recent, recent])
+                .quarantined(quarantined)
+                .build()
+                .unwrap();
+            agent.update_quarantine(&thresholds)
+        };
+        let diverging = trust - at::<SCALE>(thresholds.divergence_threshold);
+        assert!(at_recent(diverging, false));
+        assert!(!at_recent(diverging + 1, false));
+        assert!(at_recent(trust - recovering, true));
+        assert!(!at_recent(trust - recovering + 1, true));
+    }
+
+    #[test]
+    fn test_diverging_quarantine_hysteresis() {
+        check_diverging_quarantine_hysteresis::<1000>();
+        check_diverging_quarantine_hysteresis::<10_000>();
+    }
+
+    /// The integer EMA beside an f64 reference: the real-valued EMA of the
+    /// same observations (`ema_rounding_error`, `ema_drift_bounded`)
+    mod differential {
+        use super::*;
+
+        /// Random update sequences; each runs from its own seed, so a
+        /// failure names the one to replay
+        const SEQUENCES: u64 = 10_000;
+
+        /// Longest sequence
+        const MAX_STEPS: u64 = 200;
+
+        /// f64 rounding slack, far below a unit
+        const EPSILON: f64 = 1e-6;
+
+        /// Run one sequence from `seed`, returning the first step that
+        /// breaks a bound
+        fn check_sequence(seed: u64) -> Result<(), String> {
+            let mut rng = Rng(seed);
+            let alpha = rng.below(1001);
+            let mut trust = rng.below(1001);
+            let mut real = trust as f64;
+            let steps = 1 + rng.below(MAX_STEPS);
+            for step in 1..=steps {
+                // Mostly anywhere in range, sometimes the extremes
+                let observation = match rng.below(8) {
+                    0 => 0,
+                    1 => 1000,
+                    _ => rng.below(1001),
+                };
+                let exact = alpha * observation + (1000 - alpha) * trust;
+                trust = ema_update(trust, observation, alpha, Rounding::Truncate)
+                    .map_err(|err| format!("step {}: {}", step, err))?;
+                if !(1000 * trust <= exact && exact < 1000 * trust + 1000) {
+                    return Err(format!(
+                        "step {}: {} is not within a unit below {}/1000",
+                        step, trust, exact
+                    ));
+                }
+
+                real = (alpha as f64 * observation as f64 + (1000 - alpha) as f64 * real) / 1000.0;
+                let drift = real - trust as f64;
+                if drift < -EPSILON || drift >= step as f64 + EPSILON {
+                    return Err(format!(
+                        "step {}: trust {} drifted {} from the real-valued {} (alpha {})",
+                        step, trust, drift, real, alpha
+                    ));
+                }
+                // The contraction keeps the drift under 1 / alpha units
+                if alpha > 0 && drift >= 1000.0 / alpha as f64 + EPSILON {
+                    return Err(format!(
+                        "step {}: drift {} is not under 1000 / {}",
+                        step, drift, alpha
+                    ));
+                }
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_ema_tracks_real_valued_reference() {
+            for i in 0..SEQUENCES {
+                let seed = 0xd1ff_0000_0000 + i;
+                if let Err(counterexample) = check_sequence(seed) {
+                    panic!("seed {:#x}: {}", seed, counterexample);
+                }
+            }
+        }
+
+        #[test]
+        fn test_drift_reaches_whole_units() {
+            // Toward 999 at alpha 0.001 the exact step is 0.999 units,
+            // which truncation drops every time: the trust never moves and
+            // the drift comes within a tenth of the k-unit bound
+            let (mut trust, mut real) = (0u64, 0.0f64);
+            for step in 1..=10u64 {
+                trust = ema_update(trust, 999, 1, Rounding::Truncate).unwrap();
+                real = (999.0 + 999.0 * real) / 1000.0;
+                assert_eq!(trust, 0);
+                assert!(real > step as f64 - 0.1 && real < step as f64, "{}", real);
+            }
+            assert_eq!(check_sequence(0xd1ff_0000_0000), Ok(()));
+        }
+    }
+}
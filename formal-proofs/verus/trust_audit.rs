@@ -0,0 +1,843 @@
+//! # Trust Audit Log
+//!
+//! Every trust mutation, attributable and tamper-evident. A
+//! [`TrustAuditLog`] records the [`TrustDelta`]s a
+//! [`TrustManager`] returns as [`AuditEntry`]s: agent, old and new score,
+//! reason, round and timestamp. Each entry's digest covers its fields and
+//! the previous entry's digest, the same hash chain as the verification
+//! history, so editing an entry breaks its digest and removing or
+//! reordering one breaks a link. With the `signing` feature entries can
+//! also carry an Ed25519 signature over their digest, which the chain
+//! alone cannot give: anyone can recompute a chain, only the key holder
+//! can re-sign one.
+//!
+//! [`TrustAuditLog::replay`] re-derives every logged agent's score from
+//! the entries and compares it with a live manager, reporting each
+//! divergence: a mutation missing from the log (an entry whose old score is
+//! not the agent's previous new score), and a final score that differs
+//! from the live one. Rounds in which an agent abstained or the vote
+//! halted are logged only when they charge it inactivity decay it owed
+//! from earlier rounds; otherwise they leave its trust unchanged and are
+//! not logged. Agents the log never
+//! mentions are not compared: registering or loading an agent is not a
+//! mutation.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::chain::{self, ChainedProof, Hash};
+use crate::consensus::AgentId;
+use crate::trust::TrustScore;
+use crate::trust_manager::{TrustDelta, TrustEventSink, TrustManager, UpdateReason};
+
+/// Why a logged trust changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum AuditReason {
+    /// An evaluator's observation
+    Observation,
+    /// A vote against the decided value, or rounds sat out
+    Decay,
+    /// A vote for the decided value
+    Boost,
+    /// A suspect's slash at `equivocation_decay_rate`
+    Equivocation,
+}
+
+impl AuditReason {
+    /// Reason for a delta that mutates trust. Abstentions and halted
+    /// rounds leave trust unchanged, so they are `None` unless the delta
+    /// charged inactivity decay on the way in (old and new differ), which
+    /// is a `Decay`.
+    pub fn of(delta: &TrustDelta) -> Option<Self> {
+        match delta.reason {
+            UpdateReason::Observed => Some(AuditReason::Observation),
+            UpdateReason::Disagreed | UpdateReason::Inactive => Some(AuditReason::Decay),
+            UpdateReason::Agreed => Some(AuditReason::Boost),
+            UpdateReason::Suspected => Some(AuditReason::Equivocation),
+            UpdateReason::Abstained | UpdateReason::NoConsensus => {
+                (delta.old != delta.new).then_some(AuditReason::Decay)
+            }
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AuditReason::Observation => "observation",
+            AuditReason::Decay => "decay",
+            AuditReason::Boost => "boost",
+            AuditReason::Equivocation => "equivocation",
+        }
+    }
+}
+
+impl fmt::Display for AuditReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Detached Ed25519 signature over an entry's digest
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AuditSignature {
+    /// Ed25519 public key (hex, 32 bytes)
+    pub public_key: String,
+    /// Ed25519 signature over [`AuditEntry::signed_bytes`] (hex, 64 bytes)
+    pub signature: String,
+}
+
+/// One trust mutation in the log
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AuditEntry {
+    pub agent: AgentId,
+    pub old: TrustScore,
+    pub new: TrustScore,
+    pub reason: AuditReason,
+    pub round_id: u64,
+    /// Unix timestamp (seconds) of the mutation
+    pub timestamp: u64,
+    /// Digest of the previous entry (zero for the first entry)
+    pub previous_digest: Hash,
+    /// Digest of this entry's other fields, except the signature
+    pub digest: Hash,
+    pub signature: Option<AuditSignature>,
+}
+
+impl AuditEntry {
+    /// Digest over every field except `digest` and `signature`
+    pub fn compute_digest(&self) -> Hash {
+        Hash::sha256(
+            format!(
+                "{}\n{}\n{}\n{}\n{}\n{}\n{}",
+                self.agent,
+                self.old.value(),
+                self.new.value(),
+                self.reason,
+                self.round_id,
+                self.timestamp,
+                self.previous_digest
+            )
+            .as_bytes(),
+        )
+    }
+
+    /// Bytes covered by the signature: the entry's digest
+    pub fn signed_bytes(&self) -> Vec<u8> {
+        format!("aevion-trust-audit:{}", self.digest).into_bytes()
+    }
+
+    /// The entry as a hash-chain entry
+    pub fn as_chained_proof(&self) -> ChainedProof {
+        ChainedProof {
+            proof_id: self.agent.to_be_bytes().to_vec(),
+            content_hash: self.digest,
+            previous_hash: self.previous_digest,
+        }
+    }
+}
+
+#[cfg(feature = "signing")]
+impl AuditEntry {
+    /// Sign with an Ed25519 secret key seed, replacing any existing
+    /// signature
+    pub fn sign(&mut self, seed: &[u8; 32]) {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let key = SigningKey::from_bytes(seed);
+        let signature = key.sign(&self.signed_bytes());
+        self.signature = Some(AuditSignature {
+            public_key: chain::hex_encode(key.verifying_key().as_bytes()),
+            signature: chain::hex_encode(&signature.to_bytes()),
+        });
+    }
+
+    /// Whether the signature verifies under its public key; false if the
+    /// entry is unsigned
+    pub fn verify_signature(&self) -> bool {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        let valid = (|| {
+            let embedded = self.signature.as_ref()?;
+            let key: [u8; 32] = chain::hex_decode(&embedded.public_key)?.try_into().ok()?;
+            let signature: [u8; 64] = chain::hex_decode(&embedded.signature)?.try_into().ok()?;
+            let key = VerifyingKey::from_bytes(&key).ok()?;
+            Some(
+                key.verify(&self.signed_bytes(), &Signature::from_bytes(&signature))
+                    .is_ok(),
+            )
+        })();
+        valid.unwrap_or(false)
+    }
+}
+
+/// Log tampering detected by [`TrustAuditLog::verify_with`] (0-based entry
+/// index)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditError {
+    /// An entry's fields no longer match its digest
+    Edited { index: usize },
+    /// An entry does not link to its predecessor: an entry before it was
+    /// removed, inserted or reordered
+    BrokenLink { index: usize },
+    /// An entry's signature does not verify
+    InvalidSignature { index: usize },
+    /// An entry is unsigned, or signed by another key than the log's
+    /// signer
+    WrongSigner { index: usize },
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditError::Edited { index } => write!(f, "entry {}: entry was edited", index),
+            AuditError::BrokenLink { index } => write!(
+                f,
+                "entry {}: chain broken (a preceding entry was removed or altered)",
+                index
+            ),
+            AuditError::InvalidSignature { index } => {
+                write!(f, "entry {}: invalid signature", index)
+            }
+            AuditError::WrongSigner { index } => {
+                write!(f, "entry {}: not signed by the log's signer", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AuditError {}
+
+/// A logged score that does not match the next entry or the live manager
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Divergence {
+    /// Entry `index` starts the agent at `logged`, but its previous entry
+    /// left it at `expected`: a mutation in between was not logged
+    Gap {
+        index: usize,
+        agent: AgentId,
+        expected: TrustScore,
+        logged: TrustScore,
+    },
+    /// The log leaves the agent at `logged`; the live manager has `live`
+    Mismatch {
+        agent: AgentId,
+        logged: TrustScore,
+        live: TrustScore,
+    },
+    /// The log leaves the agent at `logged`; the live manager does not
+    /// know it
+    Missing { agent: AgentId, logged: TrustScore },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Divergence::Gap {
+                index,
+                agent,
+                expected,
+                logged,
+            } => write!(
+                f,
+                "entry {}: agent {} starts at {}, but its previous entry left it at {}",
+                index,
+                agent,
+                logged.value(),
+                expected.value()
+            ),
+            Divergence::Mismatch {
+                agent,
+                logged,
+                live,
+            } => write!(
+                f,
+                "agent {}: log ends at {}, live trust is {}",
+                agent,
+                logged.value(),
+                live.value()
+            ),
+            Divergence::Missing { agent, logged } => write!(
+                f,
+                "agent {}: log ends at {}, live manager does not know it",
+                agent,
+                logged.value()
+            ),
+        }
+    }
+}
+
+/// Every divergence [`TrustAuditLog::replay`] found, in entry order then
+/// agent order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayReport {
+    pub divergences: Vec<Divergence>,
+}
+
+impl fmt::Display for ReplayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} divergences", self.divergences.len())?;
+        for divergence in &self.divergences {
+            write!(f, "\n  {}", divergence)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ReplayReport {}
+
+/// Append-only, hash-chained log of trust mutations
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrustAuditLog {
+    entries: Vec<AuditEntry>,
+}
+
+impl TrustAuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Log holding `entries`, as read back from storage; check it with
+    /// [`verify`](Self::verify) before relying on it
+    pub fn from_entries(entries: Vec<AuditEntry>) -> Self {
+        Self { entries }
+    }
+
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Digest of the newest entry (zero for an empty log)
+    pub fn head(&self) -> Hash {
+        self.entries.last().map_or(Hash::ZERO, |entry| entry.digest)
+    }
+
+    /// Log every delta of round `round_id` that mutates trust, unsigned,
+    /// returning how many were logged
+    pub fn record(&mut self, deltas: &[TrustDelta], round_id: u64, timestamp: u64) -> usize {
+        self.record_with(deltas, round_id, timestamp, |_| {})
+    }
+
+    fn record_with<F>(
+        &mut self,
+        deltas: &[TrustDelta],
+        round_id: u64,
+        timestamp: u64,
+        sign: F,
+    ) -> usize
+    where
+        F: Fn(&mut AuditEntry),
+    {
+        let before = self.entries.len();
+        for delta in deltas {
+            let Some(reason) = AuditReason::of(delta) else {
+                continue;
+            };
+            let mut entry = AuditEntry {
+                agent: delta.agent,
+                old: delta.old,
+                new: delta.new,
+                reason,
+                round_id,
+                timestamp,
+                previous_digest: self.head(),
+                digest: Hash::ZERO,
+                signature: None,
+            };
+            entry.digest = entry.compute_digest();
+            sign(&mut entry);
+            self.entries.push(entry);
+        }
+        self.entries.len() - before
+    }
+
+    /// Check the chain and the signatures with `check_signature`, and
+    /// return the first tampered entry. Every signature present must
+    /// verify; with a `signer` (hex public key) every entry must also be
+    /// signed by it.
+    pub fn verify_with<F>(&self, signer: Option<&str>, check_signature: F) -> Result<(), AuditError>
+    where
+        F: Fn(&AuditEntry) -> bool,
+    {
+        if let Some(index) = self
+            .entries
+            .iter()
+            .position(|entry| entry.compute_digest() != entry.digest)
+        {
+            return Err(AuditError::Edited { index });
+        }
+        if self
+            .entries
+            .first()
+            .is_some_and(|entry| entry.previous_digest != Hash::ZERO)
+        {
+            return Err(AuditError::BrokenLink { index: 0 });
+        }
+        let links: Vec<ChainedProof> = self
+            .entries
+            .iter()
+            .map(AuditEntry::as_chained_proof)
+            .collect();
+        if let Some(index) = chain::first_broken_link(&links) {
+            return Err(AuditError::BrokenLink { index });
+        }
+        for (index, entry) in self.entries.iter().enumerate() {
+            if let Some(signer) = signer {
+                let signed_by = entry.signature.as_ref().map(|s| s.public_key.as_str());
+                if !signed_by.is_some_and(|key| key.eq_ignore_ascii_case(signer)) {
+                    return Err(AuditError::WrongSigner { index });
+                }
+            }
+            if entry.signature.is_some() && !check_signature(entry) {
+                return Err(AuditError::InvalidSignature { index });
+            }
+        }
+        Ok(())
+    }
+
+    /// Every logged agent's score after its last entry, and each gap
+    /// where an entry does not start from its predecessor's new score
+    fn derive(&self) -> (BTreeMap<AgentId, TrustScore>, Vec<Divergence>) {
+        let mut scores: BTreeMap<AgentId, TrustScore> = BTreeMap::new();
+        let mut gaps = Vec::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            if let Some(&expected) = scores.get(&entry.agent) {
+                if expected != entry.old {
+                    gaps.push(Divergence::Gap {
+                        index,
+                        agent: entry.agent,
+                        expected,
+                        logged: entry.old,
+                    });
+                }
+            }
+            scores.insert(entry.agent, entry.new);
+        }
+        (scores, gaps)
+    }
+
+    /// Re-derive every logged agent's score from the entries and compare
+    /// it with `live`. Returns the derived scores, or every divergence.
+    pub fn replay<S: TrustEventSink>(
+        &self,
+        live: &TrustManager<S>,
+    ) -> Result<BTreeMap<AgentId, TrustScore>, ReplayReport> {
+        let (scores, mut divergences) = self.derive();
+        for (&agent, &logged) in &scores {
+            match live.get(agent) {
+                Some(trust) if trust.current() == logged => {}
+                Some(trust) => divergences.push(Divergence::Mismatch {
+                    agent,
+                    logged,
+                    live: trust.current(),
+                }),
+                None => divergences.push(Divergence::Missing { agent, logged }),
+            }
+        }
+        if divergences.is_empty() {
+            Ok(scores)
+        } else {
+            Err(ReplayReport { divergences })
+        }
+    }
+}
+
+#[cfg(feature = "signing")]
+impl TrustAuditLog {
+    /// [`record`](Self::record), signing each entry with an Ed25519 secret
+    /// key seed
+    pub fn record_signed(
+        &mut self,
+        deltas: &[TrustDelta],
+        round_id: u64,
+        timestamp: u64,
+        seed: &[u8; 32],
+    ) -> usize {
+        self.record_with(deltas, round_id, timestamp, |entry| entry.sign(seed))
+    }
+
+    /// [`verify_with`](Self::verify_with) Ed25519 signature checks
+    pub fn verify(&self, signer: Option<&str>) -> Result<(), AuditError> {
+        self.verify_with(signer, AuditEntry::verify_signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::ConsensusEngine;
+    use crate::round::RoundEvidence;
+    use crate::test_support::{evidence, Rng};
+    use crate::trust::Observation;
+    use crate::Thresholds;
+
+    /// Stand-in for Ed25519: a digest of the signer key and signed bytes
+    fn stamp(entry: &mut AuditEntry, key: &str) {
+        let mut bytes = key.as_bytes().to_vec();
+        bytes.extend(entry.signed_bytes());
+        entry.signature = Some(AuditSignature {
+            public_key: key.to_string(),
+            signature: Hash::sha256(&bytes).to_hex(),
+        });
+    }
+
+    fn check(entry: &AuditEntry) -> bool {
+        let mut stamped = entry.clone();
+        let key = entry.signature.as_ref().unwrap().public_key.clone();
+        stamp(&mut stamped, &key);
+        stamped.signature == entry.signature
+    }
+
+    /// A manager and the log of its mutations over random rounds of votes,
+    /// suspects and observations among four agents, `entries` entries long
+    fn logged(entries: usize, seed: u64) -> (TrustManager, TrustAuditLog) {
+        let thresholds = Thresholds::default();
+        let mut rng = Rng(seed);
+        let mut manager = TrustManager::new();
+        let mut log = TrustAuditLog::new();
+        let mut round_id = 0;
+        // Each round logs at most four entries; a last round of
+        // observations of the remaining agents lands on `entries` exactly
+        while log.len() + 4 < entries {
+            let deltas = if rng.below(2) == 0 {
+                let ballots: Vec<Option<bool>> = (0..4)
+                    .map(|_| match rng.below(5) {
+                        0 => None,
+                        1 => Some(false),
+                        _ => Some(true),
+                    })
+                    .collect();
+                let suspects: Vec<AgentId> = (0..4).filter(|_| rng.below(8) == 0).collect();
+                manager
+                    .apply_round(&evidence(&ballots), &suspects, &thresholds)
+                    .unwrap()
+            } else {
+                let observations = (0..4)
+                    .map(|agent| (agent, Observation::partial(rng.below(1001)).unwrap()))
+                    .collect();
+                manager
+                    .record_observations(&observations, &thresholds)
+                    .unwrap()
+            };
+            log.record(&deltas, round_id, 1_790_000_000 + round_id);
+            round_id += 1;
+        }
+        let observations = (0..(entries - log.len()) as AgentId)
+            .map(|agent| (agent, Observation::partial(rng.below(1001)).unwrap()))
+            .collect();
+        let deltas = manager
+            .record_observations(&observations, &thresholds)
+            .unwrap();
+        log.record(&deltas, round_id, 1_790_000_000 + round_id);
+        assert_eq!(log.len(), entries);
+        (manager, log)
+    }
+
+    #[test]
+    fn test_replay_matches_live_state() {
+        let (manager, log) = logged(100, 0xA0D1_7000_0001);
+        assert_eq!(log.verify_with(None, check), Ok(()));
+        let derived = log.replay(&manager).unwrap();
+        assert_eq!(derived.len(), manager.len());
+        for (agent, trust) in &manager {
+            assert_eq!(derived.get(agent), Some(&trust.current()));
+        }
+        // Every kind of mutation made it in
+        let reasons: std::collections::BTreeSet<AuditReason> =
+            log.entries().iter().map(|entry| entry.reason).collect();
+        assert_eq!(reasons.len(), 4);
+
+        // Abstentions and halted rounds are not mutations
+        let deltas = manager
+            .clone()
+            .apply_round(
+                &evidence(&[None, None, None, None]),
+                &[],
+                &Thresholds::default(),
+            )
+            .unwrap();
+        assert_eq!(deltas.len(), 4);
+        let mut log = log;
+        assert_eq!(log.record(&deltas, 100, 0), 0);
+    }
+
+    #[test]
+    fn test_replay_charges_inactivity() {
+        let thresholds = Thresholds {
+            inactivity_decay_rate: 100,
+            ..Thresholds::default()
+        };
+        let mut manager = TrustManager::new();
+        let mut log = TrustAuditLog::new();
+        let mut apply = |ballots: &[Option<bool>]| {
+            let deltas = manager
+                .apply_round(&evidence(ballots), &[], &thresholds)
+                .unwrap();
+            log.record(&deltas, manager.round(), 0);
+            deltas
+        };
+
+        // Agent 2 votes, sits three rounds out, then returns to abstain:
+        // the decay it owed is charged on the abstention
+        apply(&[Some(true); 3]);
+        for _ in 0..3 {
+            apply(&[Some(true), Some(true)]);
+        }
+        let abstained = apply(&[Some(true), Some(true), None])[2];
+        assert_eq!(
+            (
+                abstained.old.value(),
+                abstained.new.value(),
+                abstained.reason
+            ),
+            (1000, 729, UpdateReason::Abstained)
+        );
+
+        // Two more rounds out, then a vote in a halted round
+        for _ in 0..2 {
+            apply(&[Some(true), Some(true)]);
+        }
+        let halted = apply(&[Some(true), Some(false), Some(true), Some(false)])[2];
+        assert_eq!(halted.reason, UpdateReason::NoConsensus);
+        assert!(halted.new < halted.old);
+
+        let charged: Vec<(u64, u64, AuditReason)> = log
+            .entries()
+            .iter()
+            .filter(|entry| entry.agent == 2)
+            .map(|entry| (entry.old.value(), entry.new.value(), entry.reason))
+            .collect();
+        assert_eq!(
+            charged,
+            vec![
+                (1000, 1000, AuditReason::Boost),
+                (1000, 729, AuditReason::Decay),
+                (729, halted.new.value(), AuditReason::Decay),
+            ]
+        );
+        let derived = log.replay(&manager).unwrap();
+        assert_eq!(derived.get(&2), Some(&halted.new));
+    }
+
+    #[test]
+    fn test_tampered_entry_detected() {
+        let (manager, mut log) = logged(40, 0xA0D1_7000_0002);
+        for entry in &mut log.entries {
+            stamp(entry, "k1");
+        }
+        assert_eq!(log.verify_with(Some("k1"), check), Ok(()));
+        let middle = log.len() / 2;
+
+        // Changing a score in the middle breaks its digest
+        let mut edited = log.clone();
+        let agent = log.entries[middle].agent;
+        let forged = TrustScore::new((log.entries[middle].new.value() + 500) % 1001).unwrap();
+        edited.entries[middle].new = forged;
+        assert_eq!(
+            edited.verify_with(None, check),
+            Err(AuditError::Edited { index: middle })
+        );
+
+        // Recomputing the digest breaks the next link; re-chaining the rest
+        // breaks the signatures
+        edited.entries[middle].digest = edited.entries[middle].compute_digest();
+        assert_eq!(
+            edited.verify_with(None, check),
+            Err(AuditError::BrokenLink { index: middle + 1 })
+        );
+        let mut previous = edited.entries[middle].digest;
+        for entry in &mut edited.entries[middle + 1..] {
+            entry.previous_digest = previous;
+            entry.digest = entry.compute_digest();
+            previous = entry.digest;
+        }
+        assert_eq!(
+            edited.verify_with(None, check),
+            Err(AuditError::InvalidSignature { index: middle })
+        );
+
+        // Stripping the signatures as well still fails against the signer
+        for entry in &mut edited.entries {
+            entry.signature = None;
+        }
+        assert_eq!(edited.verify_with(None, check), Ok(()));
+        assert_eq!(
+            edited.verify_with(Some("k1"), check),
+            Err(AuditError::WrongSigner { index: 0 })
+        );
+
+        // And the replay points at the forged score
+        let report = edited.replay(&manager).unwrap_err();
+        let next = (middle + 1..log.len()).find(|&i| log.entries[i].agent == agent);
+        let expected = match next {
+            Some(index) => Divergence::Gap {
+                index,
+                agent,
+                expected: forged,
+                logged: log.entries[index].old,
+            },
+            None => Divergence::Mismatch {
+                agent,
+                logged: forged,
+                live: manager.get(agent).unwrap().current(),
+            },
+        };
+        assert_eq!(report.divergences, [expected]);
+
+        // Removing an entry breaks the link after it
+        let mut removed = log.clone();
+        removed.entries.remove(middle);
+        assert_eq!(
+            removed.verify_with(None, check),
+            Err(AuditError::BrokenLink { index: middle })
+        );
+    }
+
+    #[test]
+    fn test_replay_reports_divergences() {
+        let (mut manager, log) = logged(30, 0xA0D1_7000_0003);
+        assert!(log.replay(&manager).is_ok());
+
+        // An unlogged mutation: the live trust moved without an entry
+        let deltas = manager
+            .record_observations(
+                &BTreeMap::from([(2, Observation::incorrect())]),
+                &Thresholds::default(),
+            )
+            .unwrap();
+        let (logged, live) = (deltas[0].old, deltas[0].new);
+        assert_ne!(logged, live);
+        // An agent the live manager never saw
+        let mut forged = log.clone();
+        forged.record(
+            &[TrustDelta {
+                agent: 9,
+                old: TrustScore::MAX,
+                new: TrustScore::MAX,
+                reason: UpdateReason::Agreed,
+                quarantined: false,
+            }],
+            10,
+            0,
+        );
+        let report = forged.replay(&manager).unwrap_err();
+        assert_eq!(
+            report.divergences,
+            [
+                Divergence::Mismatch {
+                    agent: 2,
+                    logged,
+                    live
+                },
+                Divergence::Missing {
+                    agent: 9,
+                    logged: TrustScore::MAX
+                },
+            ]
+        );
+        assert_eq!(
+            report.to_string(),
+            format!(
+                "2 divergences\n  agent 2: log ends at {}, live trust is {}\n  agent 9: log ends at 1000, live manager does not know it",
+                logged.value(),
+                live.value()
+            )
+        );
+
+        // Logging the next mutation but not this one leaves a gap
+        let next = manager
+            .record_observations(
+                &BTreeMap::from([(2, Observation::correct())]),
+                &Thresholds::default(),
+            )
+            .unwrap();
+        let mut gapped = log.clone();
+        gapped.record(&next, 11, 0);
+        let report = gapped.replay(&manager).unwrap_err();
+        assert_eq!(
+            report.divergences,
+            [Divergence::Gap {
+                index: log.len(),
+                agent: 2,
+                expected: logged,
+                logged: live
+            }]
+        );
+        assert_eq!(
+            report.divergences[0].to_string(),
+            format!(
+                "entry {}: agent 2 starts at {}, but its previous entry left it at {}",
+                log.len(),
+                live.value(),
+                logged.value()
+            )
+        );
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_ed25519_signed_log() {
+        let (manager, unsigned) = logged(20, 0xA0D1_7000_0004);
+        let seed = [7u8; 32];
+        let mut log = TrustAuditLog::new();
+        for entry in unsigned.entries() {
+            let delta = TrustDelta {
+                agent: entry.agent,
+                old: entry.old,
+                new: entry.new,
+                reason: match entry.reason {
+                    AuditReason::Observation => UpdateReason::Observed,
+                    AuditReason::Decay => UpdateReason::Disagreed,
+                    AuditReason::Boost => UpdateReason::Agreed,
+                    AuditReason::Equivocation => UpdateReason::Suspected,
+                },
+                quarantined: false,
+            };
+            log.record_signed(&[delta], entry.round_id, entry.timestamp, &seed);
+        }
+        // Same chain, now signed
+        assert_eq!(log.head(), unsigned.head());
+        let signer = log.entries()[0].signature.clone().unwrap().public_key;
+        assert_eq!(log.verify(Some(&signer)), Ok(()));
+        assert!(log.replay(&manager).is_ok());
+
+        let other = hex_key(&[8u8; 32]);
+        assert_eq!(
+            log.verify(Some(&other)),
+            Err(AuditError::WrongSigner { index: 0 })
+        );
+        let mut forged = log.clone();
+        let last = forged.len() - 1;
+        forged.entries[last].sign(&[8u8; 32]);
+        forged.entries[last].signature.as_mut().unwrap().public_key = signer.clone();
+        assert_eq!(
+            forged.verify(Some(&signer)),
+            Err(AuditError::InvalidSignature { index: last })
+        );
+    }
+
+    #[cfg(feature = "signing")]
+    fn hex_key(seed: &[u8; 32]) -> String {
+        let key = ed25519_dalek::SigningKey::from_bytes(seed);
+        chain::hex_encode(key.verifying_key().as_bytes())
+    }
+}
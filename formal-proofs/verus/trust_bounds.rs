@@ -19,6 +19,7 @@
 //! Copyright (c) 2026 Aevion LLC. All rights reserved.
 
 use vstd::prelude::*;
+use vstd::seq_lib::*;
 
 verus! {
 
@@ -68,13 +69,22 @@ impl AgentTrust {
 // SPECIFICATION: Update Functions
 // ============================================================================
 
+/// Specification: An observation is valid: how right the agent was,
+/// scaled by 1000, from 0 (wrong) to 1000 (right)
+///
+/// Executable counterpart: `trust::Observation`, whose constructors reject
+/// anything else, so every observation an update sees satisfies this.
+pub open spec fn observation_valid(observation: u64) -> bool {
+    observation <= 1000
+}
+
 /// Specification: EMA update (alpha is scaled by 1000)
 /// new_trust = alpha * observation + (1 - alpha) * current
 /// All values scaled by 1000
 pub open spec fn ema_update(current: u64, observation: u64, alpha: u64) -> u64
     recommends
         current <= 1000,
-        observation <= 1000,
+        observation_valid(observation),
         alpha <= 1000,
 {
     // alpha * observation / 1000 + (1000 - alpha) * current / 1000
@@ -100,6 +110,11 @@ pub open spec fn trust_decay(current: u64, decay_rate: u64) -> u64
 
 /// Specification: Trust boost for correct behavior
 /// boost_rate typically 50 (5%)
+///
+/// The boost rounds down: it never overshoots the real-valued
+/// `current + gap * rate`, by less than one unit (`boost_rounding_error`),
+/// and stops once `gap * rate < 1000` (981 at 5%). `trust_boost_exact`
+/// carries the rounding instead.
 pub open spec fn trust_boost(current: u64, boost_rate: u64) -> u64
     recommends
         current <= 1000,
@@ -120,7 +135,7 @@ pub open spec fn trust_boost(current: u64, boost_rate: u64) -> u64
 proof fn ema_preserves_bounds(current: u64, observation: u64, alpha: u64)
     requires
         current <= 1000,
-        observation <= 1000,
+        observation_valid(observation),
         alpha <= 1000,
     ensures
         ema_update(current, observation, alpha) <= 1000
@@ -203,28 +218,32 @@ proof fn decay_preserves_bounds(current: u64, decay_rate: u64)
 
 /// THEOREM 5: Trust Decay is Monotonically Decreasing
 ///
-/// Decay never increases trust (unless decay_rate is 0).
+/// Decay never increases trust, and strictly lowers any positive trust at
+/// a positive rate.
 proof fn decay_is_decreasing(current: u64, decay_rate: u64)
     requires
         current <= 1000,
         decay_rate <= 1000,
-        decay_rate > 0,
     ensures
-        trust_decay(current, decay_rate) <= current
+        trust_decay(current, decay_rate) <= current,
+        current > 0 && decay_rate > 0 ==> trust_decay(current, decay_rate) < current,
 {
-    // decay = current * (1000 - decay_rate) / 1000
-    // Since decay_rate > 0, (1000 - decay_rate) < 1000
-    // So current * (1000 - decay_rate) < current * 1000
-    // And decay < current (for current > 0)
-
-    if current == 0 {
-        // decay of 0 is 0
-        assert(trust_decay(current, decay_rate) == 0);
+    // decay = current * (1000 - decay_rate) / 1000, and the product falls
+    // current * decay_rate short of current * 1000
+    lemma_decay_no_overflow(current, decay_rate);
+    let kept = current * (1000 - decay_rate);
+    let lost = current * decay_rate;
+    assert(kept + lost == current * 1000) by(nonlinear_arith)
+        requires kept == current * (1000 - decay_rate), lost == current * decay_rate, decay_rate <= 1000;
+    assert(trust_decay(current, decay_rate) == kept / 1000);
+    if current > 0 && decay_rate > 0 {
+        assert(lost >= 1) by(nonlinear_arith)
+            requires lost == current * decay_rate, current > 0, decay_rate > 0;
+        assert(kept / 1000 < current) by(nonlinear_arith)
+            requires kept + lost == current * 1000, lost >= 1, kept >= 0;
     } else {
-        let factor = 1000 - decay_rate;
-        assert(factor < 1000);
-        // For factor < 1000, current * factor < current * 1000
-        // So (current * factor) / 1000 <= current
+        assert(kept / 1000 <= current) by(nonlinear_arith)
+            requires kept + lost == current * 1000, lost >= 0, kept >= 0;
     }
 }
 
@@ -295,183 +314,3331 @@ proof fn boost_is_increasing(current: u64, boost_rate: u64)
 }
 
 // ============================================================================
-// COMPOSITE THEOREMS
+// NO-OVERFLOW LEMMAS
 // ============================================================================
+//
+// Each update's intermediate products fit a u64 under its preconditions,
+// so the exec code computes them without wrapping.
 
-/// THEOREM 8: Trust Update Sequence Preserves Bounds
+/// `ema_update`'s numerator is at most 1000 * 1000
+proof fn lemma_ema_no_overflow(current: u64, observation: u64, alpha: u64)
+    requires
+        current <= 1000,
+        observation_valid(observation),
+        alpha <= 1000,
+    ensures
+        alpha * observation <= 1000 * 1000,
+        (1000 - alpha) * current <= 1000 * 1000,
+        alpha * observation + (1000 - alpha) * current <= 1000 * 1000,
+{
+    assert(alpha * observation <= alpha * 1000) by(nonlinear_arith)
+        requires observation <= 1000;
+    assert((1000 - alpha) * current <= (1000 - alpha) * 1000) by(nonlinear_arith)
+        requires current <= 1000, alpha <= 1000;
+    assert(alpha * 1000 <= 1000 * 1000) by(nonlinear_arith)
+        requires alpha <= 1000;
+}
+
+/// `trust_decay`'s product is at most 1000 * 1000
+proof fn lemma_decay_no_overflow(current: u64, decay_rate: u64)
+    requires
+        current <= 1000,
+        decay_rate <= 1000,
+    ensures
+        current * (1000 - decay_rate) <= 1000 * 1000,
+{
+    assert(current * (1000 - decay_rate) <= 1000 * 1000) by(nonlinear_arith)
+        requires current <= 1000, decay_rate <= 1000;
+}
+
+/// `trust_boost`'s product is at most 1000 * 1000 and its sum before the
+/// clamp at most 1000
+proof fn lemma_boost_no_overflow(current: u64, boost_rate: u64)
+    requires
+        current <= 1000,
+        boost_rate <= 1000,
+    ensures
+        (1000 - current) * boost_rate <= 1000 * 1000,
+        current + ((1000 - current) * boost_rate) / 1000 <= 1000,
+{
+    assert((1000 - current) * boost_rate <= (1000 - current) * 1000) by(nonlinear_arith)
+        requires boost_rate <= 1000, current <= 1000;
+    assert((1000 - current) * 1000 <= 1000 * 1000) by(nonlinear_arith)
+        requires current <= 1000;
+    assert(((1000 - current) * boost_rate) / 1000 <= 1000 - current) by(nonlinear_arith)
+        requires (1000 - current) * boost_rate <= (1000 - current) * 1000, current <= 1000;
+}
+
+/// The combined weight's product is at most 1000 * 200
+proof fn lemma_combined_weight_no_overflow(trust: u64, model_id: u64)
+    requires
+        trust <= 1000,
+    ensures
+        trust * model_weight(model_id) <= 1000 * 200,
+{
+    lemma_builtin_registry_valid();
+    model_weights_bounded(builtin_registry(), model_id);
+    let weight = model_weight(model_id);
+    assert(trust * weight <= 1000 * 200) by(nonlinear_arith)
+        requires trust <= 1000, weight <= 200;
+}
+
+// ============================================================================
+// ROUNDING MODES
+// ============================================================================
+//
+// `ema_update` truncates, so a constant observation above the trust is
+// never reached: current = 999, observation = 1000, alpha = 300 stays at
+// 999. The exec update rounds with a configurable mode instead.
+
+/// How `ema_update_rounded` rounds the division by 1000
+pub enum Rounding {
+    /// Down, as `ema_update`
+    Truncate,
+    /// To nearest, halves up
+    HalfUp,
+    /// Up when the observation is above the trust, down otherwise
+    TowardObservation,
+}
+
+/// Specification: EMA update rounded by `mode`
+pub open spec fn ema_update_rounded(current: u64, observation: u64, alpha: u64, mode: Rounding) -> u64
+    recommends
+        current <= 1000,
+        observation_valid(observation),
+        alpha <= 1000,
+{
+    let numerator = alpha * observation + (1000 - alpha) * current;
+    match mode {
+        Rounding::Truncate => ema_update(current, observation, alpha),
+        Rounding::HalfUp => (numerator + 500) / 1000,
+        Rounding::TowardObservation => if observation >= current {
+            (numerator + 999) / 1000
+        } else {
+            numerator / 1000
+        },
+    }
+}
+
+/// The EMA numerator lies between 1000 times the trust and 1000 times the
+/// observation, and moves strictly toward the observation when alpha > 0
+proof fn lemma_ema_numerator_between(current: u64, observation: u64, alpha: u64)
+    requires
+        current <= 1000,
+        observation_valid(observation),
+        alpha <= 1000,
+    ensures
+        ({
+            let numerator = alpha * observation + (1000 - alpha) * current;
+            &&& observation >= current ==> 1000 * current <= numerator <= 1000 * observation
+            &&& observation < current ==> 1000 * observation <= numerator <= 1000 * current
+            &&& alpha > 0 && observation > current ==> numerator > 1000 * current
+            &&& alpha > 0 && observation < current ==> numerator < 1000 * current
+        }),
+{
+    let numerator = alpha * observation + (1000 - alpha) * current;
+    if observation >= current {
+        // numerator = 1000 * current + alpha * (observation - current)
+        assert(numerator == 1000 * current + alpha * (observation - current)) by(nonlinear_arith);
+        assert(alpha * (observation - current) <= 1000 * (observation - current)) by(nonlinear_arith)
+            requires alpha <= 1000, observation >= current;
+        if alpha > 0 && observation > current {
+            assert(alpha * (observation - current) > 0) by(nonlinear_arith)
+                requires alpha > 0, observation > current;
+        }
+    } else {
+        // numerator = 1000 * current - alpha * (current - observation)
+        assert(numerator == 1000 * current - alpha * (current - observation)) by(nonlinear_arith);
+        assert(alpha * (current - observation) <= 1000 * (current - observation)) by(nonlinear_arith)
+            requires alpha <= 1000, observation < current;
+        if alpha > 0 {
+            assert(alpha * (current - observation) > 0) by(nonlinear_arith)
+                requires alpha > 0, observation < current;
+        }
+    }
+}
+
+/// THEOREM 17: Rounded EMA Preserves Bounds
 ///
-/// Any sequence of EMA updates preserves trust bounds.
-proof fn update_sequence_preserves_bounds(
-    initial: u64,
-    observations: Seq<u64>,
-    alpha: u64,
-)
+/// In every mode the update lies between the trust and the observation, so
+/// it stays in [0, 1000]. Rounding up cannot overshoot: the numerator is at
+/// most 1000 times the larger of the two, and adding less than 1000 before
+/// dividing does not reach the next multiple.
+proof fn ema_rounded_preserves_bounds(current: u64, observation: u64, alpha: u64, mode: Rounding)
     requires
-        initial <= 1000,
+        current <= 1000,
+        observation_valid(observation),
         alpha <= 1000,
-        forall|i: int| 0 <= i < observations.len() ==> #[trigger] observations[i] <= 1000,
     ensures
-        // Inductively, all intermediate values are bounded
+        ema_update_rounded(current, observation, alpha, mode) <= 1000,
+        observation >= current ==> current <= ema_update_rounded(current, observation, alpha, mode) <= observation,
+        observation < current ==> observation <= ema_update_rounded(current, observation, alpha, mode) <= current,
+{
+    lemma_ema_numerator_between(current, observation, alpha);
+    lemma_ema_no_overflow(current, observation, alpha);
+    let numerator = alpha * observation + (1000 - alpha) * current;
+    let (low, high) = if observation >= current { (current, observation) } else { (observation, current) };
+    assert(numerator / 1000 >= low && numerator / 1000 <= high) by(nonlinear_arith)
+        requires 1000 * low <= numerator <= 1000 * high;
+    assert((numerator + 500) / 1000 >= low && (numerator + 500) / 1000 <= high) by(nonlinear_arith)
+        requires 1000 * low <= numerator <= 1000 * high;
+    assert((numerator + 999) / 1000 >= low && (numerator + 999) / 1000 <= high) by(nonlinear_arith)
+        requires 1000 * low <= numerator <= 1000 * high;
+}
+
+/// Specification: Trust after `steps` updates with the same observation
+pub open spec fn trust_after_constant(initial: u64, observation: u64, alpha: u64, mode: Rounding, steps: nat) -> u64
+    decreases steps
+{
+    if steps == 0 {
+        initial
+    } else {
+        trust_after_constant(ema_update_rounded(initial, observation, alpha, mode), observation, alpha, mode, (steps - 1) as nat)
+    }
+}
+
+/// Specification: Steps `toward_observation_converges` needs, the distance
+/// from the trust to the observation (at most 1000)
+pub open spec fn convergence_steps(initial: u64, observation: u64) -> nat {
+    if initial <= observation { (observation - initial) as nat } else { (initial - observation) as nat }
+}
+
+/// One update rounded toward the observation moves at least one unit
+/// toward it without passing it, and stays put once there
+proof fn lemma_toward_observation_step(current: u64, observation: u64, alpha: u64)
+    requires
+        current <= 1000,
+        observation_valid(observation),
+        0 < alpha <= 1000,
+    ensures
         ({
-            // The final result after all updates
-            let final_trust = observations.fold_left(
-                initial,
-                |acc: u64, obs: u64| ema_update(acc, obs, alpha)
-            );
-            final_trust <= 1000
-        })
+            let next = ema_update_rounded(current, observation, alpha, Rounding::TowardObservation);
+            &&& next <= 1000
+            &&& current == observation ==> next == observation
+            &&& current != observation ==> convergence_steps(next, observation) < convergence_steps(current, observation)
+        }),
 {
-    // Proof by induction on sequence length
-    // Base case: initial <= 1000 (given)
-    // Inductive step: if acc <= 1000 and obs <= 1000,
-    //   then ema_update(acc, obs, alpha) <= 1000 (by ema_preserves_bounds)
+    ema_rounded_preserves_bounds(current, observation, alpha, Rounding::TowardObservation);
+    lemma_ema_numerator_between(current, observation, alpha);
+    let numerator = alpha * observation + (1000 - alpha) * current;
+    if observation > current {
+        // Above 1000 * current, so rounding up gives at least current + 1
+        assert((numerator + 999) / 1000 > current) by(nonlinear_arith)
+            requires numerator > 1000 * current;
+    } else if observation < current {
+        // Below 1000 * current, so rounding down gives at most current - 1
+        assert(numerator / 1000 < current) by(nonlinear_arith)
+            requires numerator < 1000 * current;
+    }
+}
 
-    // Verus handles this through fold semantics
+/// THEOREM 18: EMA Rounded Toward the Observation Converges
+///
+/// With a constant observation and alpha > 0, trust rounded toward the
+/// observation reaches it within `convergence_steps` updates (at most
+/// 1000) and stays there.
+proof fn toward_observation_converges(initial: u64, observation: u64, alpha: u64, steps: nat)
+    requires
+        initial <= 1000,
+        observation_valid(observation),
+        0 < alpha <= 1000,
+        steps >= convergence_steps(initial, observation),
+    ensures
+        trust_after_constant(initial, observation, alpha, Rounding::TowardObservation, steps) == observation,
+    decreases steps
+{
+    lemma_toward_observation_step(initial, observation, alpha);
+    if steps > 0 {
+        let next = ema_update_rounded(initial, observation, alpha, Rounding::TowardObservation);
+        toward_observation_converges(next, observation, alpha, (steps - 1) as nat);
+    }
 }
 
-/// THEOREM 9: Trust-Weighted Consensus is Well-Defined
+// ============================================================================
+// BOOST ROUNDING
+// ============================================================================
+//
+// Each truncated boost falls short of the real-valued one by less than a
+// unit, but the shortfall does not wash out: once the boost rounds to zero
+// trust stops below 1000 for good. Carrying the trust to a thousandth of a
+// unit (scaled by 1_000_000) keeps what each boost rounds away; the
+// carried trust then tracks the closed form 1000 - gap * (1 - rate)^n.
+//
+// Executable counterpart: `trust::trust_boost_exact`.
+
+/// Specification: `trust_boost` on a trust scaled by 1_000_000
+pub open spec fn trust_boost_exact(scaled: u64, boost_rate: u64) -> u64
+    recommends
+        scaled <= 1_000_000,
+        boost_rate <= 1000,
+{
+    (scaled + ((1_000_000 - scaled) * boost_rate) / 1000) as u64
+}
+
+/// THEOREM 34: Boost Rounding Error
 ///
-/// Weighted voting with valid trust scores produces valid weights.
-proof fn weighted_consensus_well_defined(
-    trust_scores: Seq<u64>,
-    n: nat,
-)
+/// A boost lands less than one unit below the real-valued boost
+/// `current + (1000 - current) * boost_rate / 1000` (both scaled by 1000
+/// here), a full boost reaches 1000 exactly, and a boost whose real-valued
+/// increment is below one unit leaves the trust where it was.
+proof fn boost_rounding_error(current: u64, boost_rate: u64)
     requires
-        trust_scores.len() == n,
-        n >= 3,
-        forall|i: int| 0 <= i < n ==> #[trigger] trust_scores[i] <= 1000,
-        // At least one non-zero trust (sum > 0)
-        exists|i: int| 0 <= i < n && trust_scores[i] > 0,
+        current <= 1000,
+        boost_rate <= 1000,
     ensures
-        // Sum of trust scores is positive (division won't fail)
-        trust_scores.fold_left(0u64, |acc: u64, t: u64| acc + t) > 0
+        ({
+            let boosted = trust_boost(current, boost_rate);
+            let real = 1000 * current + (1000 - current) * boost_rate;
+            &&& 1000 * boosted <= real < 1000 * boosted + 1000
+            &&& boost_rate == 1000 ==> boosted == 1000
+            &&& (1000 - current) * boost_rate < 1000 ==> boosted == current
+        }),
 {
-    // Existence of non-zero trust implies sum > 0
+    lemma_boost_no_overflow(current, boost_rate);
+    let product = (1000 - current) * boost_rate;
+    let step = product / 1000;
+    assert(trust_boost(current, boost_rate) == current + step);
+    assert(1000 * step <= product < 1000 * step + 1000) by(nonlinear_arith)
+        requires step == product / 1000, product >= 0;
+    if boost_rate == 1000 {
+        assert(product == (1000 - current) * 1000) by(nonlinear_arith)
+            requires product == (1000 - current) * boost_rate, boost_rate == 1000;
+    }
 }
 
-/// THEOREM 10: Byzantine Detection via Trust Decay
+/// THEOREM 35: Exact Boost Preserves Bounds
 ///
-/// Agents that consistently disagree with consensus have their trust decay,
-/// eventually falling below threshold for Byzantine detection.
-proof fn byzantine_detection_via_trust(
-    initial_trust: u64,
-    disagreement_count: u64,
-    decay_rate: u64,
-    detection_threshold: u64,
-)
+/// The carried boost stays in [scaled, 1_000_000], falls short of the
+/// real-valued boost by less than a thousandth of a unit, and a full boost
+/// reaches 1_000_000 exactly.
+proof fn boost_exact_bounds(scaled: u64, boost_rate: u64)
     requires
-        initial_trust <= 1000,
-        initial_trust > 0,
-        decay_rate > 0,
-        decay_rate <= 1000,
-        detection_threshold < initial_trust,
+        scaled <= 1_000_000,
+        boost_rate <= 1000,
     ensures
-        // After enough disagreements, trust falls below threshold
-        // (demonstrating eventual Byzantine detection)
         ({
-            // After k applications of decay with rate r:
-            // trust_k = trust_0 * ((1000-r)/1000)^k
-            //
-            // For trust_k < threshold:
-            // k > log(threshold/trust_0) / log((1000-r)/1000)
-            //
-            // This is always achievable for finite k
-            true  // Existential claim: such k exists
-        })
+            let boosted = trust_boost_exact(scaled, boost_rate);
+            let real = 1000 * scaled + (1_000_000 - scaled) * boost_rate;
+            &&& scaled <= boosted <= 1_000_000
+            &&& 1000 * boosted <= real < 1000 * boosted + 1000
+            &&& boost_rate == 1000 ==> boosted == 1_000_000
+        }),
 {
-    // The decay factor (1000-decay_rate)/1000 < 1 for decay_rate > 0
-    // Repeated application converges to 0
-    // So there exists k where trust_k < detection_threshold
+    let product = (1_000_000 - scaled) * boost_rate;
+    let step = product / 1000;
+    assert(product <= (1_000_000 - scaled) * 1000) by(nonlinear_arith)
+        requires boost_rate <= 1000, scaled <= 1_000_000;
+    assert(1000 * step <= product < 1000 * step + 1000) by(nonlinear_arith)
+        requires step == product / 1000, product >= 0;
+    assert(step <= 1_000_000 - scaled);
+    if boost_rate == 1000 {
+        assert(product == (1_000_000 - scaled) * 1000) by(nonlinear_arith)
+            requires product == (1_000_000 - scaled) * boost_rate, boost_rate == 1000;
+    }
 }
 
 // ============================================================================
-// AGENT MODEL WEIGHTS (from math_consensus_verifier.py)
+// MONOTONICITY AND CONTRACTION
 // ============================================================================
+//
+// A better observation, or a higher trust going in, never yields a lower
+// trust coming out, and an update never moves away from its observation.
+// The numerator is monotone and lies between 1000 times the trust and the
+// observation; division by 1000 preserves <= (if not <).
 
-/// Model weight configuration (scaled by 100 for integer math)
-/// From: model_weights = {"o1-mini": 1.8, "nvidia_nemotron_70b": 1.7, "gpt-4o": 1.5, ...}
-pub open spec fn model_weight(model_id: u64) -> u64 {
-    // Model IDs: 0=o1-mini, 1=nemotron, 2=gpt-4o, 3=gpt-4-turbo, 4=gpt-4o-mini
-    if model_id == 0 { 180 }       // o1-mini: 1.8
-    else if model_id == 1 { 170 }  // nvidia_nemotron_70b: 1.7
-    else if model_id == 2 { 150 }  // gpt-4o: 1.5
-    else if model_id == 3 { 150 }  // gpt-4-turbo: 1.5
-    else if model_id == 4 { 130 }  // gpt-4o-mini: 1.3
-    else { 100 }                   // default: 1.0
+/// Specification: Distance between two trusts
+pub open spec fn trust_distance(a: u64, b: u64) -> u64 {
+    if a >= b { (a - b) as u64 } else { (b - a) as u64 }
 }
 
-/// THEOREM 11: Model Weights are Bounded
+/// Rounding down by 1000 preserves order
+proof fn lemma_div_1000_monotone(a: int, b: int)
+    requires
+        0 <= a <= b,
+    ensures
+        a / 1000 <= b / 1000,
+{
+    assert(a / 1000 <= b / 1000) by(nonlinear_arith)
+        requires 0 <= a, a <= b;
+}
+
+/// THEOREM 26: EMA is Monotone in the Observation
 ///
-/// All model weights are positive and bounded.
-proof fn model_weights_bounded(model_id: u64)
+/// For a fixed trust and alpha, a higher observation never gives a lower
+/// trust.
+proof fn ema_monotone_in_observation(current: u64, obs1: u64, obs2: u64, alpha: u64)
+    requires
+        current <= 1000,
+        obs1 <= obs2,
+        obs2 <= 1000,
+        alpha <= 1000,
     ensures
-        model_weight(model_id) >= 100,  // At least 1.0
-        model_weight(model_id) <= 200,  // At most 2.0
+        ema_update(current, obs1, alpha) <= ema_update(current, obs2, alpha),
 {
-    // Exhaustive case analysis
+    assert(alpha * obs1 <= alpha * obs2) by(nonlinear_arith)
+        requires obs1 <= obs2;
+    lemma_div_1000_monotone(
+        alpha * obs1 + (1000 - alpha) * current,
+        alpha * obs2 + (1000 - alpha) * current,
+    );
 }
 
-/// THEOREM 12: Combined Trust and Model Weight
+/// THEOREM 27: EMA is Monotone in the Trust
 ///
-/// The product of trust and model weight is bounded.
-proof fn combined_weight_bounded(trust: u64, model_id: u64)
+/// For a fixed observation and alpha, an agent trusted more going in is
+/// trusted at least as much coming out.
+proof fn ema_monotone_in_current(current1: u64, current2: u64, observation: u64, alpha: u64)
     requires
-        trust <= 1000,
+        current1 <= current2,
+        current2 <= 1000,
+        observation_valid(observation),
+        alpha <= 1000,
     ensures
-        ({
-            let weight = model_weight(model_id);
-            let combined = (trust * weight) / 1000;
-            combined <= 200  // Max: 1.0 * 2.0 = 2.0
-        })
+        ema_update(current1, observation, alpha) <= ema_update(current2, observation, alpha),
 {
-    let weight = model_weight(model_id);
-    assert(weight <= 200);
-    assert(trust <= 1000);
-    let product = trust * weight;
-    assert(product <= 1000 * 200);
-    assert(product / 1000 <= 200);
+    assert((1000 - alpha) * current1 <= (1000 - alpha) * current2) by(nonlinear_arith)
+        requires current1 <= current2, alpha <= 1000;
+    lemma_div_1000_monotone(
+        alpha * observation + (1000 - alpha) * current1,
+        alpha * observation + (1000 - alpha) * current2,
+    );
 }
 
-} // verus!
+/// THEOREM 28: EMA Contracts Toward the Observation
+///
+/// The updated trust lies between the trust and the observation, so it is
+/// no further from the observation than the trust was:
+/// |ema(c, o, a) - o| <= |c - o|. This is the step the convergence
+/// theorems build on; with truncation it need not be strict.
+proof fn ema_contracts_toward_observation(current: u64, observation: u64, alpha: u64)
+    requires
+        current <= 1000,
+        observation_valid(observation),
+        alpha <= 1000,
+    ensures
+        ({
+            let next = ema_update(current, observation, alpha);
+            &&& observation >= current ==> current <= next <= observation
+            &&& observation < current ==> observation <= next <= current
+            &&& trust_distance(next, observation) <= trust_distance(current, observation)
+        }),
+{
+    lemma_ema_numerator_between(current, observation, alpha);
+    let numerator = alpha * observation + (1000 - alpha) * current;
+    if observation >= current {
+        assert(current <= numerator / 1000 <= observation) by(nonlinear_arith)
+            requires 1000 * current <= numerator, numerator <= 1000 * observation;
+    } else {
+        assert(observation <= numerator / 1000 <= current) by(nonlinear_arith)
+            requires 1000 * observation <= numerator, numerator <= 1000 * current;
+    }
+}
 
 // ============================================================================
-// EXECUTABLE TEST CODE
+// PER-ROUND DELTA CAP
 // ============================================================================
+//
+// A compromised evaluator feeding an agent perfect observations could lift
+// its trust to full weight in a few rounds. Capping each update's change at
+// `max_delta` keeps the trust between its old value and the EMA's, so in
+// bounds, and makes any trust k rounds away need k * max_delta of room.
+//
+// Executable counterpart: `AgentTrust::record_observation_capped`,
+// `TrustManager::record_observations`.
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_ema_update() {
-        // EMA with alpha=0.3: new = 0.3*obs + 0.7*current
-        let current = 800u64;  // 0.8
-        let observation = 1000u64;  // 1.0
-        let alpha = 300u64;  // 0.3
+/// Specification: EMA update moved at most `max_delta` from `current`
+pub open spec fn ema_capped(current: u64, observation: u64, alpha: u64, max_delta: u64) -> u64 {
+    let next = ema_update(current, observation, alpha);
+    if next > current + max_delta {
+        (current + max_delta) as u64
+    } else if next + max_delta < current {
+        (current - max_delta) as u64
+    } else {
+        next
+    }
+}
 
-        // Expected: 0.3*1.0 + 0.7*0.8 = 0.3 + 0.56 = 0.86 = 860/1000
-        let expected = (alpha * observation + (1000 - alpha) * current) / 1000;
-        assert_eq!(expected, 860);
+/// Specification: Trust after capped EMA updates with each observation in
+/// turn
+pub open spec fn trust_after_capped(
+    initial: u64,
+    observations: Seq<u64>,
+    alpha: u64,
+    max_delta: u64,
+) -> u64 {
+    observations.fold_left(
+        initial,
+        |acc: u64, obs: u64| ema_capped(acc, obs, alpha, max_delta),
+    )
+}
+
+/// THEOREM 36: Capped Updates Preserve Bounds
+///
+/// A capped update lies between the trust and the uncapped update, so in
+/// [0, 1000], and within `max_delta` of the trust.
+proof fn capped_update_bounds(current: u64, observation: u64, alpha: u64, max_delta: u64)
+    requires
+        current <= 1000,
+        observation_valid(observation),
+        alpha <= 1000,
+        max_delta <= 1000,
+    ensures
+        ({
+            let next = ema_update(current, observation, alpha);
+            let capped = ema_capped(current, observation, alpha, max_delta);
+            &&& capped <= 1000
+            &&& next >= current ==> current <= capped <= next
+            &&& next < current ==> next <= capped <= current
+            &&& trust_distance(capped, current) <= max_delta
+        }),
+{
+    ema_preserves_bounds(current, observation, alpha);
+}
+
+/// THEOREM 37: Capped Reachability
+///
+/// k capped updates move a trust by at most k * max_delta, whatever the
+/// observations: reaching a trust d away takes at least d / max_delta
+/// rounds.
+proof fn capped_sequence_reachability(
+    initial: u64,
+    observations: Seq<u64>,
+    alpha: u64,
+    max_delta: u64,
+)
+    requires
+        initial <= 1000,
+        alpha <= 1000,
+        max_delta <= 1000,
+        forall|i: int| 0 <= i < observations.len() ==> observation_valid(#[trigger] observations[i]),
+    ensures
+        trust_after_capped(initial, observations, alpha, max_delta) <= 1000,
+        trust_distance(trust_after_capped(initial, observations, alpha, max_delta), initial)
+            <= observations.len() * max_delta,
+    decreases observations.len()
+{
+    if observations.len() > 0 {
+        let rest = observations.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies #[trigger] rest[i] <= 1000 by {
+            assert(rest[i] == observations[i]);
+        }
+        capped_sequence_reachability(initial, rest, alpha, max_delta);
+        assert(observations =~= rest.push(observations.last()));
+        lemma_fold_push(
+            rest,
+            observations.last(),
+            initial,
+            |acc: u64, obs: u64| ema_capped(acc, obs, alpha, max_delta),
+        );
+        let before = trust_after_capped(initial, rest, alpha, max_delta);
+        capped_update_bounds(before, observations.last(), alpha, max_delta);
+        assert(rest.len() * max_delta + max_delta == observations.len() * max_delta)
+            by(nonlinear_arith)
+            requires rest.len() + 1 == observations.len();
     }
+}
 
-    #[test]
-    fn test_decay() {
-        // Decay 10%: new = current * 0.9
-        let current = 1000u64;  // 1.0
-        let decay_rate = 100u64;  // 0.1
+// ============================================================================
+// TRUST FLOOR AND QUARANTINE
+// ============================================================================
+//
+// Decay toward zero would silence an agent for good after a transient
+// outage. Floored decay stops at a floor instead, and an agent below the
+// detection threshold is quarantined until its trust recovers past the
+// threshold plus a margin.
 
-        let decayed = (current * (1000 - decay_rate)) / 1000;
-        assert_eq!(decayed, 900);  // 0.9
+/// Specification: Trust decay that stops at `floor`; never raises a trust
+/// already below the floor
+pub open spec fn trust_decay_floored(current: u64, decay_rate: u64, floor: u64) -> u64
+    recommends
+        current <= 1000,
+        decay_rate <= 1000,
+        floor <= 1000,
+{
+    let decayed = trust_decay(current, decay_rate);
+    if decayed >= floor {
+        decayed
+    } else if current >= floor {
+        floor
+    } else {
+        current
     }
+}
 
-    #[test]
-    fn test_boost() {
-        // Boost 5%: new = current + 5% of gap
-        let current = 800u64;  // 0.8
-        let boost_rate = 50u64;  // 0.05
+/// THEOREM 19: Floored Decay Stays Within [floor, 1000]
+proof fn floored_decay_bounds(current: u64, decay_rate: u64, floor: u64)
+    requires
+        current <= 1000,
+        decay_rate <= 1000,
+        floor <= current,
+    ensures
+        floor <= trust_decay_floored(current, decay_rate, floor) <= 1000,
+{
+    decay_preserves_bounds(current, decay_rate);
+}
 
-        let gap = 1000 - current;  // 200
-        let boost_amount = (gap * boost_rate) / 1000;  // 10
-        let boosted = current + boost_amount;  // 810
-        assert_eq!(boosted, 810);
+/// THEOREM 20: Floored Decay is Monotonically Non-Increasing
+proof fn floored_decay_non_increasing(current: u64, decay_rate: u64, floor: u64)
+    requires
+        current <= 1000,
+        decay_rate <= 1000,
+    ensures
+        trust_decay_floored(current, decay_rate, floor) <= current,
+{
+    lemma_decay_no_overflow(current, decay_rate);
+    assert(trust_decay(current, decay_rate) <= current) by(nonlinear_arith)
+        requires
+            trust_decay(current, decay_rate) == (current * (1000 - decay_rate)) / 1000,
+            decay_rate <= 1000;
+}
+
+/// THEOREM 40: Decay Fixed Points
+///
+/// Truncation rounds the loss up: decay takes exactly
+/// ceil(current * decay_rate / 1000) off the trust, so it leaves a trust
+/// unchanged only at trust 0 or rate 0. There is no plateau above zero.
+proof fn decay_fixed_points(current: u64, decay_rate: u64)
+    requires
+        current <= 1000,
+        decay_rate <= 1000,
+    ensures
+        trust_decay(current, decay_rate) == current - (current * decay_rate + 999) / 1000,
+        trust_decay(current, decay_rate) == current <==> (current == 0 || decay_rate == 0),
+{
+    decay_is_decreasing(current, decay_rate);
+    let kept = current * (1000 - decay_rate);
+    let lost = current * decay_rate;
+    assert(kept + lost == current * 1000) by(nonlinear_arith)
+        requires kept == current * (1000 - decay_rate), lost == current * decay_rate, decay_rate <= 1000;
+    assert(lost <= current * 1000) by(nonlinear_arith)
+        requires lost == current * decay_rate, decay_rate <= 1000, current >= 0;
+    // With lost + 999 == 1000q + s, 0 <= s < 1000, the product is
+    // 1000(current - q) + (999 - s), which truncates to current - q
+    let q = (lost + 999) / 1000;
+    let s = (lost + 999) % 1000;
+    assert(lost + 999 == 1000 * q + s && 0 <= s < 1000);
+    assert(kept == 1000 * (current - q) + (999 - s));
+    assert(kept / 1000 == current - q) by(nonlinear_arith)
+        requires kept == 1000 * (current - q) + (999 - s), 0 <= 999 - s < 1000, current - q >= 0;
+    if current == 0 || decay_rate == 0 {
+        assert(lost == 0) by(nonlinear_arith)
+            requires lost == current * decay_rate, current == 0 || decay_rate == 0;
+    }
+}
+
+/// THEOREM 41: Floored Decay Fixed Points
+///
+/// At a positive rate, floored decay leaves a trust unchanged exactly when
+/// it is at or below the floor. Above the floor every decay strictly
+/// lowers the trust, so repeated decays reach the floor, which lies below
+/// the detection threshold (`Thresholds::validate`): quarantine cannot
+/// stall on a plateau above it.
+proof fn floored_decay_fixed_points(current: u64, decay_rate: u64, floor: u64)
+    requires
+        current <= 1000,
+        0 < decay_rate <= 1000,
+        floor <= 1000,
+    ensures
+        trust_decay_floored(current, decay_rate, floor) == current <==> current <= floor,
+        current > floor ==> trust_decay_floored(current, decay_rate, floor) < current,
+{
+    decay_is_decreasing(current, decay_rate);
+}
+
+/// Specification: Floored decay applied once per round an agent sat out
+pub open spec fn decay_rounds(current: u64, decay_rate: u64, floor: u64, rounds: nat) -> u64
+    decreases rounds
+{
+    if rounds == 0 {
+        current
+    } else {
+        trust_decay_floored(decay_rounds(current, decay_rate, floor, (rounds - 1) as nat), decay_rate, floor)
+    }
+}
+
+/// Each round of inactivity takes a trust above the floor at least one
+/// unit closer to it
+proof fn lemma_decay_rounds_progress(current: u64, decay_rate: u64, floor: u64, rounds: nat)
+    requires
+        current <= 1000,
+        0 < decay_rate <= 1000,
+        floor <= current,
+    ensures
+        floor <= decay_rounds(current, decay_rate, floor, rounds) <= current,
+        decay_rounds(current, decay_rate, floor, rounds) <= floor || decay_rounds(current, decay_rate, floor, rounds) + rounds <= current,
+    decreases rounds
+{
+    if rounds > 0 {
+        let previous = decay_rounds(current, decay_rate, floor, (rounds - 1) as nat);
+        lemma_decay_rounds_progress(current, decay_rate, floor, (rounds - 1) as nat);
+        floored_decay_bounds(previous, decay_rate, floor);
+        floored_decay_fixed_points(previous, decay_rate, floor);
+    }
+}
+
+/// THEOREM 43: Inactivity Decay Settles at the Floor
+///
+/// Decaying a trust once per round of inactivity reaches the floor after
+/// at most `current - floor` rounds and stays there, so the executable
+/// `trust_decay_rounds` stops iterating after that many (at most 1000)
+/// with exactly the iterated result, however many rounds elapsed.
+///
+/// A power table of the real-valued factor is no shortcut: each iterated
+/// decay truncates, and the iterates fall behind `current * (1 -
+/// rate)^rounds` by more than a unit (0.900 against 0.905 after 100
+/// rounds at 0.1%).
+proof fn inactivity_decay_settles(current: u64, decay_rate: u64, floor: u64, rounds: nat)
+    requires
+        current <= 1000,
+        0 < decay_rate <= 1000,
+        floor <= current,
+        rounds >= current - floor,
+    ensures
+        decay_rounds(current, decay_rate, floor, rounds) == floor,
+        decay_rounds(current, decay_rate, floor, rounds)
+            == decay_rounds(current, decay_rate, floor, (current - floor) as nat),
+{
+    lemma_decay_rounds_progress(current, decay_rate, floor, rounds);
+    lemma_decay_rounds_progress(current, decay_rate, floor, (current - floor) as nat);
+}
+
+/// Specification: Quarantine after an evaluation at `trust`, with
+/// hysteresis: entered below `threshold`, left only above `threshold +
+/// margin`
+pub open spec fn quarantine_next(quarantined: bool, trust: u64, threshold: u64, margin: u64) -> bool {
+    if quarantined {
+        trust <= threshold + margin
+    } else {
+        trust < threshold
+    }
+}
+
+/// THEOREM 21: Quarantine Boundaries
+///
+/// An agent enters quarantine exactly when its trust falls below the
+/// threshold and leaves exactly when it rises above threshold + margin; an
+/// agent held at a floor below the threshold stays quarantined.
+proof fn quarantine_boundaries(quarantined: bool, trust: u64, threshold: u64, margin: u64, floor: u64)
+    ensures
+        !quarantined ==> (quarantine_next(quarantined, trust, threshold, margin) <==> trust < threshold),
+        quarantined ==> (!quarantine_next(quarantined, trust, threshold, margin) <==> trust > threshold + margin),
+        trust < threshold ==> quarantine_next(quarantined, trust, threshold, margin),
+        trust == floor && floor < threshold ==> quarantine_next(quarantined, trust, threshold, margin),
+{
+}
+
+/// THEOREM 22: Quarantine Does Not Flap
+///
+/// Entering and then leaving quarantine takes trust rising by more than
+/// the margin between the two evaluations.
+proof fn quarantine_no_flapping(entered_at: u64, left_at: u64, threshold: u64, margin: u64)
+    requires
+        quarantine_next(false, entered_at, threshold, margin),
+        !quarantine_next(true, left_at, threshold, margin),
+    ensures
+        left_at > entered_at + margin,
+{
+}
+
+// ============================================================================
+// ROUND UPDATES
+// ============================================================================
+//
+// After a round every agent gets exactly one update: suspected agents are
+// slashed, and otherwise a voter agreeing with the decided value is
+// boosted and one disagreeing decayed.
+
+/// The update an agent gets from a round
+pub enum TrustUpdate {
+    /// Agreed with the decided value
+    Boost,
+    /// Disagreed with the decided value
+    Decay,
+    /// Suspected (or proven) Byzantine
+    Slash,
+    /// Abstained, or the round halted
+    Keep,
+}
+
+/// Specification: The update for an agent with `ballot` in a round that
+/// decided `outcome` (`None` for a halt)
+pub open spec fn round_update(suspected: bool, ballot: Option<bool>, outcome: Option<bool>) -> TrustUpdate {
+    if suspected {
+        TrustUpdate::Slash
+    } else {
+        match (ballot, outcome) {
+            (Some(vote), Some(value)) => if vote == value { TrustUpdate::Boost } else { TrustUpdate::Decay },
+            _ => TrustUpdate::Keep,
+        }
+    }
+}
+
+/// Specification: Trust after `update`; decays stop at `floor`
+pub open spec fn apply_update(
+    current: u64,
+    update: TrustUpdate,
+    boost_rate: u64,
+    decay_rate: u64,
+    slash_rate: u64,
+    floor: u64,
+) -> u64 {
+    match update {
+        TrustUpdate::Boost => trust_boost(current, boost_rate),
+        TrustUpdate::Decay => trust_decay_floored(current, decay_rate, floor),
+        TrustUpdate::Slash => trust_decay_floored(current, slash_rate, floor),
+        TrustUpdate::Keep => current,
+    }
+}
+
+/// Specification: Every agent's trust after a round, agent i holding
+/// `trusts[i]` and getting `updates[i]`
+///
+/// Executable counterpart: `trust_manager::TrustManager::apply_round`.
+pub open spec fn apply_round_updates(
+    trusts: Seq<u64>,
+    updates: Seq<TrustUpdate>,
+    boost_rate: u64,
+    decay_rate: u64,
+    slash_rate: u64,
+    floor: u64,
+) -> Seq<u64> {
+    Seq::new(trusts.len(), |i: int| apply_update(trusts[i], updates[i], boost_rate, decay_rate, slash_rate, floor))
+}
+
+/// One update stays in [0, 1000], rising for a boost and falling for a
+/// decay or slash
+proof fn lemma_apply_update_bounds(
+    current: u64,
+    update: TrustUpdate,
+    boost_rate: u64,
+    decay_rate: u64,
+    slash_rate: u64,
+    floor: u64,
+)
+    requires
+        current <= 1000,
+        boost_rate <= 1000,
+        decay_rate <= 1000,
+        slash_rate <= 1000,
+    ensures
+        ({
+            let next = apply_update(current, update, boost_rate, decay_rate, slash_rate, floor);
+            &&& next <= 1000
+            &&& update is Boost ==> next >= current
+            &&& (update is Decay || update is Slash) ==> next <= current
+        }),
+{
+    match update {
+        TrustUpdate::Boost => {
+            boost_preserves_bounds(current, boost_rate);
+            boost_is_increasing(current, boost_rate);
+        },
+        TrustUpdate::Decay => floored_decay_non_increasing(current, decay_rate, floor),
+        TrustUpdate::Slash => floored_decay_non_increasing(current, slash_rate, floor),
+        TrustUpdate::Keep => {},
+    }
+}
+
+/// THEOREM 23: Round Updates Preserve Bounds
+///
+/// Applying a round's updates leaves every agent's trust in [0, 1000]. An
+/// agent's update is a function of its own suspicion and ballot, so no
+/// agent is both boosted and decayed: a boosted agent's trust does not
+/// fall, a decayed or slashed agent's does not rise.
+proof fn apply_round_preserves_bounds(
+    trusts: Seq<u64>,
+    suspected: Seq<bool>,
+    ballots: Seq<Option<bool>>,
+    outcome: Option<bool>,
+    boost_rate: u64,
+    decay_rate: u64,
+    slash_rate: u64,
+    floor: u64,
+)
+    requires
+        suspected.len() == trusts.len(),
+        ballots.len() == trusts.len(),
+        forall|i: int| 0 <= i < trusts.len() ==> #[trigger] trusts[i] <= 1000,
+        boost_rate <= 1000,
+        decay_rate <= 1000,
+        slash_rate <= 1000,
+    ensures
+        ({
+            let updates = Seq::new(trusts.len(), |i: int| round_update(suspected[i], ballots[i], outcome));
+            let after = apply_round_updates(trusts, updates, boost_rate, decay_rate, slash_rate, floor);
+            &&& after.len() == trusts.len()
+            &&& forall|i: int| 0 <= i < after.len() ==> #[trigger] after[i] <= 1000
+            &&& forall|i: int| 0 <= i < after.len() && updates[i] is Boost ==>
+                    !suspected[i] && ballots[i] == outcome && #[trigger] after[i] >= trusts[i]
+            &&& forall|i: int| 0 <= i < after.len() && (updates[i] is Decay || updates[i] is Slash) ==>
+                    !(updates[i] is Boost) && #[trigger] after[i] <= trusts[i]
+        }),
+{
+    let updates = Seq::new(trusts.len(), |i: int| round_update(suspected[i], ballots[i], outcome));
+    let after = apply_round_updates(trusts, updates, boost_rate, decay_rate, slash_rate, floor);
+    assert forall|i: int| 0 <= i < after.len() implies {
+        &&& #[trigger] after[i] <= 1000
+        &&& updates[i] is Boost ==> !suspected[i] && ballots[i] == outcome && after[i] >= trusts[i]
+        &&& (updates[i] is Decay || updates[i] is Slash) ==> after[i] <= trusts[i]
+    } by {
+        lemma_apply_update_bounds(trusts[i], updates[i], boost_rate, decay_rate, slash_rate, floor);
+    }
+}
+
+/// THEOREM 44: Round Updates Are Local
+///
+/// An agent's trust after a round depends only on its own trust before
+/// the round and its own update: two agents, in the same round or in
+/// different ones, that start from the same trust and get the same update
+/// end at the same trust. So applying a round's updates in another agent
+/// order, modelled as a permutation `perm` of the agents, permutes the
+/// results and changes no agent's. An update that read other agents'
+/// trusts mid-round (normalizing against their sum, say) would break
+/// this.
+///
+/// Executable counterpart: `TrustManager::apply_round` stages every
+/// update from the trusts before the round through `stage_update`, which
+/// takes only the agent's own suspicion and ballot, before committing any.
+proof fn round_update_is_local(
+    trusts: Seq<u64>,
+    updates: Seq<TrustUpdate>,
+    other_trusts: Seq<u64>,
+    other_updates: Seq<TrustUpdate>,
+    perm: Seq<int>,
+    boost_rate: u64,
+    decay_rate: u64,
+    slash_rate: u64,
+    floor: u64,
+)
+    requires
+        updates.len() == trusts.len(),
+        other_updates.len() == other_trusts.len(),
+        perm.len() == trusts.len(),
+        forall|k: int| 0 <= k < perm.len() ==> 0 <= #[trigger] perm[k] < trusts.len(),
+    ensures
+        ({
+            let after = apply_round_updates(trusts, updates, boost_rate, decay_rate, slash_rate, floor);
+            let other = apply_round_updates(other_trusts, other_updates, boost_rate, decay_rate, slash_rate, floor);
+            &&& forall|i: int, j: int|
+                    0 <= i < trusts.len() && 0 <= j < other_trusts.len()
+                        && trusts[i] == other_trusts[j] && updates[i] == other_updates[j]
+                        ==> #[trigger] after[i] == #[trigger] other[j]
+            &&& ({
+                    let permuted = apply_round_updates(
+                        Seq::new(trusts.len(), |k: int| trusts[perm[k]]),
+                        Seq::new(trusts.len(), |k: int| updates[perm[k]]),
+                        boost_rate,
+                        decay_rate,
+                        slash_rate,
+                        floor,
+                    );
+                    forall|k: int| 0 <= k < perm.len() ==> #[trigger] permuted[k] == after[perm[k]]
+                })
+        }),
+{
+    // apply_round_updates is Seq::new over apply_update of index i alone;
+    // both facts follow from unfolding it
+}
+
+// ============================================================================
+// SCALE-GENERIC ARITHMETIC
+// ============================================================================
+//
+// The updates above fix the scale at 1000. These take it as a parameter,
+// so a deployment can keep trust to four places (scale 10_000) under the
+// same proofs; at 1000 they are the updates above
+// (`lemma_scaled_updates_at_1000`). Every bound holds for any scale of at
+// least 10; only the overflow side condition depends on the scale.
+
+/// Specification: `ema_update_rounded` at `scale`
+pub open spec fn ema_update_at(scale: u64, current: u64, observation: u64, alpha: u64, mode: Rounding) -> u64
+    recommends
+        scale >= 10,
+        current <= scale,
+        observation <= scale,
+        alpha <= scale,
+{
+    let numerator = alpha * observation + (scale - alpha) * current;
+    match mode {
+        Rounding::Truncate => numerator / scale,
+        Rounding::HalfUp => (numerator + scale / 2) / scale,
+        Rounding::TowardObservation => if observation >= current {
+            (numerator + (scale - 1)) / scale
+        } else {
+            numerator / scale
+        },
+    }
+}
+
+/// Specification: `trust_decay` at `scale`
+pub open spec fn trust_decay_at(scale: u64, current: u64, decay_rate: u64) -> u64
+    recommends
+        scale >= 10,
+        current <= scale,
+        decay_rate <= scale,
+{
+    (current * (scale - decay_rate)) / scale
+}
+
+/// Specification: `trust_boost` at `scale`
+pub open spec fn trust_boost_at(scale: u64, current: u64, boost_rate: u64) -> u64
+    recommends
+        scale >= 10,
+        current <= scale,
+        boost_rate <= scale,
+{
+    let boosted = current + ((scale - current) * boost_rate) / scale;
+    if boosted > scale { scale } else { boosted }
+}
+
+/// Specification: `value` at scale `from` converted to scale `to`,
+/// rounding half up
+pub open spec fn rescale(value: u64, from: u64, to: u64) -> u64
+    recommends
+        from >= 10,
+        value <= from,
+{
+    (value * to + from / 2) / from
+}
+
+/// At scale 1000 the scaled updates are the updates above
+proof fn lemma_scaled_updates_at_1000(current: u64, observation: u64, rate: u64, mode: Rounding)
+    ensures
+        ema_update_at(1000, current, observation, rate, mode) == ema_update_rounded(current, observation, rate, mode),
+        trust_decay_at(1000, current, rate) == trust_decay(current, rate),
+        trust_boost_at(1000, current, rate) == trust_boost(current, rate),
+{
+    // Unfolding both sides: 1000 / 2 == 500 and 1000 - 1 == 999
+}
+
+/// THEOREM 45: Scaled Updates Preserve Bounds
+///
+/// At any scale of at least 10 the EMA lies between the trust and the
+/// observation in every rounding mode, decay never raises the trust and
+/// boost never lowers it, and all three stay in [0, scale]. The proofs of
+/// THEOREMS 1, 4, 6 and 17 with 1000 replaced by `scale`: rounding up
+/// adds less than `scale` before dividing, so it cannot pass the next
+/// multiple.
+///
+/// Executable counterpart: `trust::ScaledTrust::{ema, decay, boost}`, through
+/// which `ScaledAgentTrust` and `ScaledTrustManager` update.
+proof fn scaled_updates_preserve_bounds(
+    scale: u64,
+    current: u64,
+    observation: u64,
+    alpha: u64,
+    decay_rate: u64,
+    boost_rate: u64,
+    mode: Rounding,
+)
+    requires
+        scale >= 10,
+        current <= scale,
+        observation <= scale,
+        alpha <= scale,
+        decay_rate <= scale,
+        boost_rate <= scale,
+    ensures
+        ema_update_at(scale, current, observation, alpha, mode) <= scale,
+        observation >= current ==> current <= ema_update_at(scale, current, observation, alpha, mode) <= observation,
+        observation < current ==> observation <= ema_update_at(scale, current, observation, alpha, mode) <= current,
+        trust_decay_at(scale, current, decay_rate) <= current,
+        current <= trust_boost_at(scale, current, boost_rate) <= scale,
+{
+    let numerator = alpha * observation + (scale - alpha) * current;
+    let (low, high) = if observation >= current { (current, observation) } else { (observation, current) };
+    if observation >= current {
+        assert(numerator == scale * current + alpha * (observation - current)) by(nonlinear_arith);
+        assert(alpha * (observation - current) <= scale * (observation - current)) by(nonlinear_arith)
+            requires alpha <= scale, observation >= current;
+    } else {
+        assert(numerator == scale * current - alpha * (current - observation)) by(nonlinear_arith);
+        assert(alpha * (current - observation) <= scale * (current - observation)) by(nonlinear_arith)
+            requires alpha <= scale, observation < current;
+    }
+    assert(scale * low <= numerator <= scale * high);
+    assert(numerator / scale >= low && numerator / scale <= high) by(nonlinear_arith)
+        requires scale * low <= numerator <= scale * high, scale > 0;
+    assert((numerator + scale / 2) / scale >= low && (numerator + scale / 2) / scale <= high) by(nonlinear_arith)
+        requires scale * low <= numerator <= scale * high, scale > 0;
+    assert((numerator + (scale - 1)) / scale >= low && (numerator + (scale - 1)) / scale <= high) by(nonlinear_arith)
+        requires scale * low <= numerator <= scale * high, scale > 0;
+
+    assert((current * (scale - decay_rate)) / scale <= current) by(nonlinear_arith)
+        requires decay_rate <= scale, scale > 0;
+
+    assert(((scale - current) * boost_rate) / scale <= scale - current) by(nonlinear_arith)
+        requires boost_rate <= scale, current <= scale, scale > 0;
+}
+
+/// THEOREM 46: Scaled Updates Do Not Overflow
+///
+/// Every product the scaled updates and `rescale` divide is at most
+/// `scale * scale` (or `from * to`). That fits a u128 for any u64 scale,
+/// which is what the executable code computes in, and a u64 only up to
+/// scale 4_294_967_295 (2^32 - 1). `lemma_ema_no_overflow` and friends
+/// are the case scale = 1000.
+proof fn scaled_updates_no_overflow(scale: u64, current: u64, observation: u64, rate: u64)
+    requires
+        scale >= 10,
+        current <= scale,
+        observation <= scale,
+        rate <= scale,
+    ensures
+        rate * observation + (scale - rate) * current <= scale * scale,
+        rate * observation + (scale - rate) * current + (scale - 1) <= scale * scale + scale,
+        current * (scale - rate) <= scale * scale,
+        (scale - current) * rate <= scale * scale,
+        scale * scale + scale <= u128::MAX,
+        scale <= 4_294_967_295 ==> scale * scale <= u64::MAX,
+{
+    assert(rate * observation <= rate * scale) by(nonlinear_arith)
+        requires observation <= scale;
+    assert((scale - rate) * current <= (scale - rate) * scale) by(nonlinear_arith)
+        requires current <= scale, rate <= scale;
+    assert(rate * scale + (scale - rate) * scale == scale * scale) by(nonlinear_arith)
+        requires rate <= scale;
+    assert(current * (scale - rate) <= scale * scale) by(nonlinear_arith)
+        requires current <= scale, rate <= scale;
+    assert((scale - current) * rate <= scale * scale) by(nonlinear_arith)
+        requires current <= scale, rate <= scale;
+    assert(scale * scale <= u64::MAX * u64::MAX) by(nonlinear_arith)
+        requires scale <= u64::MAX;
+    assert(u64::MAX * u64::MAX + u64::MAX <= u128::MAX);
+    if scale <= 4_294_967_295 {
+        assert(scale * scale <= 4_294_967_295 * 4_294_967_295) by(nonlinear_arith)
+            requires scale <= 4_294_967_295;
+        assert(4_294_967_295 * 4_294_967_295 <= u64::MAX);
+    }
+}
+
+/// THEOREM 47: Rescaling Preserves Bounds
+///
+/// A trust at scale `from` rescaled to `to` stays in [0, to], is within
+/// half a unit of `to` of the exact value, and when `to` is a multiple of
+/// `from` is exact, so rescaling back down returns the original.
+///
+/// Executable counterpart: `trust::ScaledTrust::rescale`.
+proof fn rescale_preserves_bounds(value: u64, from: u64, to: u64)
+    requires
+        from >= 10,
+        to >= 10,
+        value <= from,
+    ensures
+        rescale(value, from, to) <= to,
+        2 * from * rescale(value, from, to) <= 2 * value * to + from,
+        2 * value * to < 2 * from * rescale(value, from, to) + from + 1,
+        to % from == 0 ==> rescale(value, from, to) == value * (to / from),
+        to % from == 0 ==> rescale(rescale(value, from, to), to, from) == value,
+{
+    let numerator = value * to + from / 2;
+    assert(value * to <= from * to) by(nonlinear_arith)
+        requires value <= from;
+    assert(numerator / from <= to) by(nonlinear_arith)
+        requires numerator <= from * to + from / 2, from > 0;
+    assert(from * (numerator / from) <= numerator < from * (numerator / from) + from) by(nonlinear_arith)
+        requires from > 0;
+    if to % from == 0 {
+        let k = to / from;
+        assert(value * to == from * (value * k)) by(nonlinear_arith)
+            requires to == from * k;
+        assert((from * (value * k) + from / 2) / from == value * k) by(nonlinear_arith)
+            requires from > 0;
+        assert((value * k * from + to / 2) / to == value) by(nonlinear_arith)
+            requires to == from * k, k >= 1, to > 0;
+    }
+}
+
+// ============================================================================
+// EXECUTABLE UPDATES
+// ============================================================================
+//
+// Executable counterpart: `trust::{TrustScore, AgentTrust}`, whose bodies
+// are these after checking the preconditions at runtime.
+
+/// Observations an agent can record before `cumulative_correct` could
+/// overflow a u64 (`u64::MAX / 1000`)
+pub const MAX_OBSERVATIONS: u64 = 18_446_744_073_709_551;
+
+/// Recording a bounded observation keeps the cumulative correctness within
+/// 1000 per observation, and below `MAX_OBSERVATIONS` within a u64
+proof fn lemma_record_preserves_cumulative(observations: u64, cumulative_correct: u64, observation: u64)
+    requires
+        cumulative_correct <= observations * 1000,
+        observation_valid(observation),
+        observations < MAX_OBSERVATIONS,
+    ensures
+        cumulative_correct + observation <= (observations + 1) * 1000,
+        (observations + 1) * 1000 <= u64::MAX,
+{
+    assert((observations + 1) * 1000 == observations * 1000 + 1000) by(nonlinear_arith);
+    assert((observations + 1) * 1000 <= MAX_OBSERVATIONS * 1000) by(nonlinear_arith)
+        requires observations + 1 <= MAX_OBSERVATIONS;
+}
+
+impl TrustScore {
+    /// Trust score of `value`, or `None` above 1000
+    pub fn new(value: u64) -> (r: Option<TrustScore>)
+        ensures
+            match r {
+                Some(score) => score.valid() && score.val() == value,
+                None => value > 1000,
+            },
+    {
+        if value <= 1000 {
+            Some(TrustScore { value })
+        } else {
+            None
+        }
+    }
+
+    /// The scaled value
+    pub fn value(&self) -> (r: u64)
+        ensures
+            r == self.val(),
+    {
+        self.value
+    }
+}
+
+impl AgentTrust {
+    /// Fold one observation into the trust (`ema_update_rounded`) and the
+    /// history
+    pub fn record_observation(&mut self, observation: u64, alpha: u64, rounding: Rounding)
+        requires
+            old(self).valid(),
+            observation_valid(observation),
+            alpha <= 1000,
+            old(self).observations < MAX_OBSERVATIONS,
+        ensures
+            self.valid(),
+            self.current.val() == ema_update_rounded(old(self).current.val(), observation, alpha, rounding),
+            self.observations == old(self).observations + 1,
+            self.cumulative_correct == old(self).cumulative_correct + observation,
+    {
+        let current = self.current.value;
+        proof {
+            ema_rounded_preserves_bounds(current, observation, alpha, rounding);
+            lemma_record_preserves_cumulative(self.observations, self.cumulative_correct, observation);
+            lemma_ema_no_overflow(current, observation, alpha);
+        }
+        let numerator = alpha * observation + (1000 - alpha) * current;
+        let value = match rounding {
+            Rounding::Truncate => numerator / 1000,
+            Rounding::HalfUp => (numerator + 500) / 1000,
+            Rounding::TowardObservation => if observation >= current {
+                (numerator + 999) / 1000
+            } else {
+                numerator / 1000
+            },
+        };
+        self.current = TrustScore { value };
+        self.cumulative_correct = self.cumulative_correct + observation;
+        self.observations = self.observations + 1;
+    }
+
+    /// Decay the trust of a suspected agent (`trust_decay`)
+    pub fn decay(&mut self, decay_rate: u64)
+        requires
+            old(self).valid(),
+            decay_rate <= 1000,
+        ensures
+            self.valid(),
+            self.current.val() == trust_decay(old(self).current.val(), decay_rate),
+            self.observations == old(self).observations,
+            self.cumulative_correct == old(self).cumulative_correct,
+    {
+        let current = self.current.value;
+        proof {
+            decay_preserves_bounds(current, decay_rate);
+            lemma_decay_no_overflow(current, decay_rate);
+        }
+        self.current = TrustScore { value: (current * (1000 - decay_rate)) / 1000 };
+    }
+
+    /// Boost the trust of an agent that agreed (`trust_boost`)
+    pub fn boost(&mut self, boost_rate: u64)
+        requires
+            old(self).valid(),
+            boost_rate <= 1000,
+        ensures
+            self.valid(),
+            self.current.val() == trust_boost(old(self).current.val(), boost_rate),
+            self.observations == old(self).observations,
+            self.cumulative_correct == old(self).cumulative_correct,
+    {
+        let current = self.current.value;
+        proof {
+            boost_preserves_bounds(current, boost_rate);
+            lemma_boost_no_overflow(current, boost_rate);
+        }
+        let boosted = current + ((1000 - current) * boost_rate) / 1000;
+        self.current = TrustScore { value: if boosted > 1000 { 1000 } else { boosted } };
+    }
+
+    /// Decay the trust of a suspected agent, stopping at `floor`
+    /// (`trust_decay_floored`)
+    pub fn decay_floored(&mut self, decay_rate: u64, floor: u64)
+        requires
+            old(self).valid(),
+            decay_rate <= 1000,
+            floor <= 1000,
+        ensures
+            self.valid(),
+            self.current.val() == trust_decay_floored(old(self).current.val(), decay_rate, floor),
+            self.current.val() <= old(self).current.val(),
+            old(self).current.val() >= floor ==> self.current.val() >= floor,
+            self.observations == old(self).observations,
+            self.cumulative_correct == old(self).cumulative_correct,
+    {
+        let current = self.current.value;
+        proof {
+            floored_decay_non_increasing(current, decay_rate, floor);
+            lemma_decay_no_overflow(current, decay_rate);
+        }
+        let decayed = (current * (1000 - decay_rate)) / 1000;
+        let value = if decayed >= floor {
+            decayed
+        } else if current >= floor {
+            floor
+        } else {
+            current
+        };
+        self.current = TrustScore { value };
+    }
+}
+
+// ============================================================================
+// SEQUENCE LEMMAS: fold_left
+// ============================================================================
+
+/// Specification: Sum of a sequence, without overflow
+pub open spec fn seq_sum(s: Seq<u64>) -> nat {
+    s.fold_left(0nat, |acc: nat, x: u64| (acc + x) as nat)
+}
+
+/// Specification: Trust after EMA updates with each observation in turn
+pub open spec fn trust_after(initial: u64, observations: Seq<u64>, alpha: u64) -> u64 {
+    observations.fold_left(initial, |acc: u64, obs: u64| ema_update(acc, obs, alpha))
+}
+
+/// fold_left unrolls one element from the end
+proof fn lemma_fold_push<A, B>(s: Seq<A>, x: A, b: B, f: spec_fn(B, A) -> B)
+    ensures
+        s.push(x).fold_left(b, f) == f(s.fold_left(b, f), x),
+{
+    assert(s.push(x).drop_last() =~= s);
+}
+
+/// A sum of elements each at most `bound` is at most len * bound
+proof fn lemma_fold_sum_bounded(s: Seq<u64>, bound: nat)
+    requires
+        forall|i: int| 0 <= i < s.len() ==> #[trigger] s[i] <= bound,
+    ensures
+        seq_sum(s) <= s.len() * bound,
+    decreases s.len()
+{
+    if s.len() > 0 {
+        let rest = s.drop_last();
+        lemma_fold_sum_bounded(rest, bound);
+        assert(s.last() <= bound);
+        assert(seq_sum(s) == seq_sum(rest) + s.last());
+        assert(seq_sum(rest) + s.last() <= s.len() * bound) by(nonlinear_arith)
+            requires
+                seq_sum(rest) <= rest.len() * bound,
+                s.last() <= bound,
+                rest.len() + 1 == s.len();
+    }
+}
+
+/// A sum of naturals is at least any one of them
+proof fn lemma_fold_sum_lower_bound(s: Seq<u64>, i: int)
+    requires
+        0 <= i < s.len(),
+    ensures
+        seq_sum(s) >= s[i],
+    decreases s.len()
+{
+    let rest = s.drop_last();
+    assert(seq_sum(s) == seq_sum(rest) + s.last());
+    if i < s.len() - 1 {
+        assert(rest[i] == s[i]);
+        lemma_fold_sum_lower_bound(rest, i);
+    }
+}
+
+// ============================================================================
+// COMPOSITE THEOREMS
+// ============================================================================
+
+/// One EMA update of a trust and an observation in [lo, hi] stays in
+/// [lo, hi]
+proof fn lemma_ema_between(current: u64, observation: u64, alpha: u64, lo: u64, hi: u64)
+    requires
+        lo <= current <= hi,
+        lo <= observation <= hi,
+        hi <= 1000,
+        alpha <= 1000,
+    ensures
+        lo <= ema_update(current, observation, alpha) <= hi,
+{
+    lemma_ema_numerator_between(current, observation, alpha);
+    let numerator = alpha * observation + (1000 - alpha) * current;
+    assert(1000 * lo <= numerator <= 1000 * hi);
+    assert(lo <= numerator / 1000 <= hi) by(nonlinear_arith)
+        requires
+            1000 * lo <= numerator,
+            numerator <= 1000 * hi;
+}
+
+/// EMA updates keep a trust in [lo, hi] when it starts there and every
+/// observation lies there, by induction on the observations
+proof fn lemma_trust_after_between(
+    initial: u64,
+    observations: Seq<u64>,
+    alpha: u64,
+    lo: u64,
+    hi: u64,
+)
+    requires
+        lo <= initial <= hi,
+        hi <= 1000,
+        alpha <= 1000,
+        forall|i: int| 0 <= i < observations.len() ==>
+            lo <= #[trigger] observations[i] && observations[i] <= hi,
+    ensures
+        lo <= trust_after(initial, observations, alpha) <= hi,
+    decreases observations.len()
+{
+    if observations.len() > 0 {
+        let rest = observations.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies
+            lo <= #[trigger] rest[i] && rest[i] <= hi by {
+            assert(rest[i] == observations[i]);
+        }
+        lemma_trust_after_between(initial, rest, alpha, lo, hi);
+        assert(observations =~= rest.push(observations.last()));
+        lemma_fold_push(
+            rest,
+            observations.last(),
+            initial,
+            |acc: u64, obs: u64| ema_update(acc, obs, alpha),
+        );
+        lemma_ema_between(trust_after(initial, rest, alpha), observations.last(), alpha, lo, hi);
+    }
+}
+
+/// THEOREM 8: Trust Update Sequence Preserves Bounds
+///
+/// Any sequence of EMA updates preserves trust bounds.
+proof fn update_sequence_preserves_bounds(
+    initial: u64,
+    observations: Seq<u64>,
+    alpha: u64,
+)
+    requires
+        initial <= 1000,
+        alpha <= 1000,
+        forall|i: int| 0 <= i < observations.len() ==> observation_valid(#[trigger] observations[i]),
+    ensures
+        trust_after(initial, observations, alpha) <= 1000,
+{
+    lemma_trust_after_between(initial, observations, alpha, 0, 1000);
+}
+
+/// THEOREM 24: Trust Update Sequence Lower Bound
+///
+/// The counterpart of Theorem 8 from below: an agent starting at or above
+/// `lo` whose observations are all at least `lo` never falls below `lo`.
+/// Truncation loses at most a point per update, never past `lo`.
+proof fn update_sequence_lower_bound(
+    initial: u64,
+    observations: Seq<u64>,
+    alpha: u64,
+    lo: u64,
+)
+    requires
+        lo <= initial <= 1000,
+        alpha <= 1000,
+        forall|i: int| 0 <= i < observations.len() ==>
+            lo <= #[trigger] observations[i] && observations[i] <= 1000,
+    ensures
+        trust_after(initial, observations, alpha) >= lo,
+{
+    lemma_trust_after_between(initial, observations, alpha, lo, 1000);
+}
+
+/// An update to an agent's trust: an EMA observation, or a round's boost,
+/// decay or slash
+pub enum TrustEvent {
+    Observe(u64),
+    Round(TrustUpdate),
+}
+
+/// Specification: Whether an event's own input is in range
+pub open spec fn event_in_range(event: TrustEvent) -> bool {
+    match event {
+        TrustEvent::Observe(observation) => observation_valid(observation),
+        TrustEvent::Round(_) => true,
+    }
+}
+
+/// Specification: Trust after `event`
+pub open spec fn apply_event(
+    current: u64,
+    event: TrustEvent,
+    alpha: u64,
+    boost_rate: u64,
+    decay_rate: u64,
+    slash_rate: u64,
+    floor: u64,
+) -> u64 {
+    match event {
+        TrustEvent::Observe(observation) => ema_update(current, observation, alpha),
+        TrustEvent::Round(update) => apply_update(current, update, boost_rate, decay_rate, slash_rate, floor),
+    }
+}
+
+/// Specification: Trust after each event in turn, as `TrustManager`
+/// interleaves observations with round updates
+pub open spec fn trust_after_events(
+    initial: u64,
+    events: Seq<TrustEvent>,
+    alpha: u64,
+    boost_rate: u64,
+    decay_rate: u64,
+    slash_rate: u64,
+    floor: u64,
+) -> u64 {
+    events.fold_left(
+        initial,
+        |acc: u64, event: TrustEvent| apply_event(acc, event, alpha, boost_rate, decay_rate, slash_rate, floor),
+    )
+}
+
+/// THEOREM 25: Mixed Update Sequences Preserve Bounds
+///
+/// EMA observations, boosts, decays and slashes interleaved in any order
+/// keep trust in [0, 1000], by induction on the events with Theorem 1 for
+/// observations and `lemma_apply_update_bounds` for round updates.
+proof fn mixed_sequence_preserves_bounds(
+    initial: u64,
+    events: Seq<TrustEvent>,
+    alpha: u64,
+    boost_rate: u64,
+    decay_rate: u64,
+    slash_rate: u64,
+    floor: u64,
+)
+    requires
+        initial <= 1000,
+        alpha <= 1000,
+        boost_rate <= 1000,
+        decay_rate <= 1000,
+        slash_rate <= 1000,
+        forall|i: int| 0 <= i < events.len() ==> event_in_range(#[trigger] events[i]),
+    ensures
+        trust_after_events(initial, events, alpha, boost_rate, decay_rate, slash_rate, floor) <= 1000,
+    decreases events.len()
+{
+    if events.len() > 0 {
+        let rest = events.drop_last();
+        let last = events.last();
+        assert forall|i: int| 0 <= i < rest.len() implies event_in_range(#[trigger] rest[i]) by {
+            assert(rest[i] == events[i]);
+        }
+        mixed_sequence_preserves_bounds(initial, rest, alpha, boost_rate, decay_rate, slash_rate, floor);
+        assert(events =~= rest.push(last));
+        lemma_fold_push(
+            rest,
+            last,
+            initial,
+            |acc: u64, event: TrustEvent| apply_event(acc, event, alpha, boost_rate, decay_rate, slash_rate, floor),
+        );
+        let before = trust_after_events(initial, rest, alpha, boost_rate, decay_rate, slash_rate, floor);
+        assert(event_in_range(events[events.len() - 1]));
+        match last {
+            TrustEvent::Observe(observation) => ema_preserves_bounds(before, observation, alpha),
+            TrustEvent::Round(update) => {
+                lemma_apply_update_bounds(before, update, boost_rate, decay_rate, slash_rate, floor)
+            },
+        }
+    }
+}
+
+/// THEOREM 9: Trust-Weighted Consensus is Well-Defined
+///
+/// Weighted voting with valid trust scores produces valid weights.
+proof fn weighted_consensus_well_defined(
+    trust_scores: Seq<u64>,
+    n: nat,
+)
+    requires
+        trust_scores.len() == n,
+        n >= 3,
+        forall|i: int| 0 <= i < n ==> #[trigger] trust_scores[i] <= 1000,
+        // At least one non-zero trust (sum > 0)
+        exists|i: int| 0 <= i < n && trust_scores[i] > 0,
+    ensures
+        // Sum of trust scores is positive (division won't fail)
+        seq_sum(trust_scores) > 0,
+        // ... and small enough that scaling it by 1000 fits a u64
+        seq_sum(trust_scores) <= n * 1000,
+        // Each agent's normalized weight is at most 1000
+        forall|j: int| 0 <= j < n ==>
+            #[trigger] trust_scores[j] * 1000 / seq_sum(trust_scores) <= 1000,
+{
+    let i = choose|i: int| 0 <= i < n && trust_scores[i] > 0;
+    lemma_fold_sum_lower_bound(trust_scores, i);
+    lemma_fold_sum_bounded(trust_scores, 1000);
+    let sum = seq_sum(trust_scores);
+    assert forall|j: int| 0 <= j < n implies
+        #[trigger] trust_scores[j] * 1000 / sum <= 1000 by {
+        lemma_fold_sum_lower_bound(trust_scores, j);
+        assert(trust_scores[j] * 1000 / sum <= 1000) by(nonlinear_arith)
+            requires
+                trust_scores[j] <= sum,
+                sum > 0;
+    }
+}
+
+/// Specification: Trust after `k` decays at `decay_rate`
+pub open spec fn decay_iterate(trust: u64, decay_rate: u64, k: nat) -> u64
+    decreases k
+{
+    if k == 0 {
+        trust
+    } else {
+        decay_iterate(trust_decay(trust, decay_rate), decay_rate, (k - 1) as nat)
+    }
+}
+
+/// Specification: Decays that take `trust` below `threshold`, giving up
+/// after `fuel` decays
+pub open spec fn decay_steps_within(trust: u64, threshold: u64, decay_rate: u64, fuel: nat) -> nat
+    decreases fuel
+{
+    if fuel == 0 || trust < threshold {
+        0
+    } else {
+        1 + decay_steps_within(trust_decay(trust, decay_rate), threshold, decay_rate, (fuel - 1) as nat)
+    }
+}
+
+/// Specification: Disagreements before an agent at `initial` trust is
+/// flagged at `threshold`, the smallest k with k decays below it. 1001
+/// decays always suffice: each loses at least one unit.
+///
+/// Executable counterpart: `trust::decay_steps_to`.
+pub open spec fn decay_steps_to(initial: u64, threshold: u64, decay_rate: u64) -> nat {
+    decay_steps_within(initial, threshold, decay_rate, 1001)
+}
+
+/// A decay at a positive rate loses at least one unit of a positive trust
+proof fn lemma_decay_strictly_decreasing(current: u64, decay_rate: u64)
+    requires
+        0 < current <= 1000,
+        0 < decay_rate <= 1000,
+    ensures
+        trust_decay(current, decay_rate) < current,
+{
+    lemma_decay_no_overflow(current, decay_rate);
+    assert((current * (1000 - decay_rate)) / 1000 < current) by(nonlinear_arith)
+        requires 0 < current, 0 < decay_rate <= 1000;
+}
+
+/// Decays never raise trust, so one below the threshold stays below
+proof fn lemma_decay_iterate_non_increasing(trust: u64, decay_rate: u64, k: nat)
+    requires
+        trust <= 1000,
+        decay_rate <= 1000,
+    ensures
+        decay_iterate(trust, decay_rate, k) <= trust,
+    decreases k
+{
+    if k > 0 {
+        let next = trust_decay(trust, decay_rate);
+        floored_decay_non_increasing(trust, decay_rate, 0);
+        decay_preserves_bounds(trust, decay_rate);
+        lemma_decay_iterate_non_increasing(next, decay_rate, (k - 1) as nat);
+    }
+}
+
+/// k1 + k2 decays are k1 decays followed by k2 more
+proof fn lemma_decay_iterate_split(trust: u64, decay_rate: u64, k1: nat, k2: nat)
+    ensures
+        decay_iterate(trust, decay_rate, k1 + k2)
+            == decay_iterate(decay_iterate(trust, decay_rate, k1), decay_rate, k2),
+    decreases k1
+{
+    if k1 > 0 {
+        lemma_decay_iterate_split(trust_decay(trust, decay_rate), decay_rate, (k1 - 1) as nat, k2);
+        assert((k1 + k2 - 1) as nat == (k1 - 1) as nat + k2);
+    }
+}
+
+/// `decay_steps_within` with enough fuel is the smallest number of decays
+/// below the threshold, at most trust - threshold + 1
+proof fn lemma_decay_steps_within(trust: u64, threshold: u64, decay_rate: u64, fuel: nat)
+    requires
+        trust <= 1000,
+        0 < threshold,
+        0 < decay_rate <= 1000,
+        fuel > trust,
+    ensures
+        ({
+            let steps = decay_steps_within(trust, threshold, decay_rate, fuel);
+            &&& decay_iterate(trust, decay_rate, steps) < threshold
+            &&& forall|k: nat| k < steps ==> #[trigger] decay_iterate(trust, decay_rate, k) >= threshold
+            &&& trust < threshold ==> steps == 0
+            &&& trust >= threshold ==> steps <= trust - threshold + 1
+        }),
+    decreases fuel
+{
+    if trust >= threshold {
+        let next = trust_decay(trust, decay_rate);
+        lemma_decay_strictly_decreasing(trust, decay_rate);
+        lemma_decay_steps_within(next, threshold, decay_rate, (fuel - 1) as nat);
+        let steps = decay_steps_within(trust, threshold, decay_rate, fuel);
+        assert(steps == 1 + decay_steps_within(next, threshold, decay_rate, (fuel - 1) as nat));
+        assert(decay_iterate(trust, decay_rate, steps) == decay_iterate(next, decay_rate, (steps - 1) as nat));
+        assert forall|k: nat| k < steps implies #[trigger] decay_iterate(trust, decay_rate, k) >= threshold by {
+            if k > 0 {
+                assert(decay_iterate(trust, decay_rate, k) == decay_iterate(next, decay_rate, (k - 1) as nat));
+            }
+        }
+    }
+}
+
+/// THEOREM 10: Byzantine Detection via Trust Decay
+///
+/// An agent that keeps disagreeing is flagged after exactly
+/// `decay_steps_to(initial, threshold, rate)` decays: the first that takes
+/// its trust below the detection threshold. That is at most
+/// initial - threshold + 1 disagreements (1000 at the most), and it stays
+/// flagged through any further ones.
+proof fn byzantine_detection_via_trust(
+    initial_trust: u64,
+    disagreement_count: u64,
+    decay_rate: u64,
+    detection_threshold: u64,
+)
+    requires
+        initial_trust <= 1000,
+        initial_trust > 0,
+        decay_rate > 0,
+        decay_rate <= 1000,
+        0 < detection_threshold < initial_trust,
+    ensures
+        ({
+            let steps = decay_steps_to(initial_trust, detection_threshold, decay_rate);
+            &&& decay_iterate(initial_trust, decay_rate, steps) < detection_threshold
+            &&& forall|k: nat| k < steps ==> #[trigger] decay_iterate(initial_trust, decay_rate, k) >= detection_threshold
+            &&& steps <= initial_trust - detection_threshold + 1
+            &&& disagreement_count >= steps ==> decay_iterate(initial_trust, decay_rate, disagreement_count as nat) < detection_threshold
+        }),
+{
+    lemma_decay_steps_within(initial_trust, detection_threshold, decay_rate, 1001);
+    let steps = decay_steps_to(initial_trust, detection_threshold, decay_rate);
+    if disagreement_count >= steps {
+        let flagged = decay_iterate(initial_trust, decay_rate, steps);
+        let more = (disagreement_count - steps) as nat;
+        lemma_decay_iterate_split(initial_trust, decay_rate, steps, more);
+        assert(steps + more == disagreement_count as nat);
+        // Trust never goes back up once flagged
+        lemma_decay_iterate_non_increasing(initial_trust, decay_rate, steps);
+        lemma_decay_iterate_non_increasing(flagged, decay_rate, more);
+    }
+}
+
+// ============================================================================
+// WINDOWED STATISTICS
+// ============================================================================
+//
+// At a small alpha the EMA remembers a long good record well after an
+// agent turns. Agents also keep their last `window_size` observations, and
+// a trust far above the window's mean flags them first. The mean bound is
+// `variance_halt::bounded_outputs_bounded_mean` over the overflow-free
+// `seq_sum`; each proof file verifies on its own, so it is restated here.
+//
+// Executable counterpart: `AgentTrust::{recent_accuracy_scaled,
+// divergence, suspicion}`.
+
+/// Specification: Window after recording `observation`, dropping the
+/// oldest observation from a full window
+pub open spec fn window_push(window: Seq<u64>, observation: u64, window_size: nat) -> Seq<u64> {
+    if window.len() < window_size {
+        window.push(observation)
+    } else {
+        window.drop_first().push(observation)
+    }
+}
+
+/// Specification: Mean of a window, rounded down (0 when empty)
+pub open spec fn window_mean(window: Seq<u64>) -> nat {
+    if window.len() == 0 {
+        0
+    } else {
+        seq_sum(window) / window.len()
+    }
+}
+
+/// The mean of values each at most `bound` is at most `bound`
+proof fn lemma_mean_bounded(s: Seq<u64>, bound: nat)
+    requires
+        s.len() > 0,
+        forall|i: int| 0 <= i < s.len() ==> #[trigger] s[i] <= bound,
+    ensures
+        seq_sum(s) / s.len() <= bound,
+{
+    lemma_fold_sum_bounded(s, bound);
+    assert(seq_sum(s) / s.len() <= bound) by(nonlinear_arith)
+        requires
+            seq_sum(s) <= s.len() * bound,
+            s.len() > 0;
+}
+
+/// Recording a bounded observation keeps a window bounded and within its
+/// size
+proof fn lemma_window_push_bounded(window: Seq<u64>, observation: u64, window_size: nat)
+    requires
+        0 < window_size,
+        window.len() <= window_size,
+        forall|i: int| 0 <= i < window.len() ==> #[trigger] window[i] <= 1000,
+        observation_valid(observation),
+    ensures
+        ({
+            let next = window_push(window, observation, window_size);
+            &&& next.len() <= window_size
+            &&& next.last() == observation
+            &&& forall|i: int| 0 <= i < next.len() ==> #[trigger] next[i] <= 1000
+        }),
+{
+    let next = window_push(window, observation, window_size);
+    assert forall|i: int| 0 <= i < next.len() implies #[trigger] next[i] <= 1000 by {
+        if i < next.len() - 1 {
+            if window.len() < window_size {
+                assert(next[i] == window[i]);
+            } else {
+                assert(next[i] == window[i + 1]);
+            }
+        }
+    }
+}
+
+/// THEOREM 29: Windowed Mean is Bounded
+///
+/// The mean of a window of observations in [0, 1000] lies in [0, 1000],
+/// so recent accuracy is a trust-scaled value and its divergence from a
+/// trust lies in [-1000, 1000].
+proof fn window_mean_bounded(window: Seq<u64>, current: u64)
+    requires
+        current <= 1000,
+        forall|i: int| 0 <= i < window.len() ==> #[trigger] window[i] <= 1000,
+    ensures
+        window_mean(window) <= 1000,
+        -1000 <= current - window_mean(window) <= 1000,
+{
+    if window.len() > 0 {
+        lemma_mean_bounded(window, 1000);
+    }
+}
+
+/// THEOREM 30: Recording Preserves the Windowed Mean Bound
+///
+/// After any bounded observation the window still fits its size and its
+/// mean stays in [0, 1000].
+proof fn window_push_preserves_bounds(window: Seq<u64>, observation: u64, window_size: nat)
+    requires
+        0 < window_size,
+        window.len() <= window_size,
+        forall|i: int| 0 <= i < window.len() ==> #[trigger] window[i] <= 1000,
+        observation_valid(observation),
+    ensures
+        window_push(window, observation, window_size).len() <= window_size,
+        window_mean(window_push(window, observation, window_size)) <= 1000,
+{
+    lemma_window_push_bounded(window, observation, window_size);
+    window_mean_bounded(window_push(window, observation, window_size), 0);
+}
+
+// ============================================================================
+// COMPOSITE TRUST
+// ============================================================================
+//
+// Accuracy alone hides an agent that is right but slow or badly
+// calibrated. Each of the three components is a trust updated by
+// `ema_update` (so `ema_preserves_bounds` keeps each in [0, 1000]), and
+// votes carry a convex combination of them.
+//
+// Executable counterpart: `trust::composite_trust`, `AgentTrust::composite`.
+
+/// Specification: Accuracy, calibration and latency weighted by `wa`,
+/// `wc` and `wl` (scaled by 1000), rounded down
+pub open spec fn composite_trust(
+    accuracy: u64,
+    calibration: u64,
+    latency: u64,
+    wa: u64,
+    wc: u64,
+    wl: u64,
+) -> nat {
+    ((wa * accuracy + wc * calibration + wl * latency) / 1000) as nat
+}
+
+/// THEOREM 31: Composite Trust is Bounded
+///
+/// With every component in [0, 1000] and weights summing to 1000, the
+/// composite lies between the least and the greatest component, so in
+/// [0, 1000]; weights (1000, 0, 0) give the accuracy exactly.
+proof fn composite_trust_bounded(
+    accuracy: u64,
+    calibration: u64,
+    latency: u64,
+    wa: u64,
+    wc: u64,
+    wl: u64,
+)
+    requires
+        accuracy <= 1000,
+        calibration <= 1000,
+        latency <= 1000,
+        wa + wc + wl == 1000,
+    ensures
+        ({
+            let composite = composite_trust(accuracy, calibration, latency, wa, wc, wl);
+            let lo = min(accuracy as int, min(calibration as int, latency as int));
+            let hi = max(accuracy as int, max(calibration as int, latency as int));
+            &&& lo <= composite <= hi
+            &&& composite <= 1000
+            &&& wa == 1000 ==> composite == accuracy
+        }),
+{
+    let lo = min(accuracy as int, min(calibration as int, latency as int));
+    let hi = max(accuracy as int, max(calibration as int, latency as int));
+    let numerator = wa * accuracy + wc * calibration + wl * latency;
+    assert(1000 * lo <= numerator <= 1000 * hi) by(nonlinear_arith)
+        requires
+            lo <= accuracy <= hi,
+            lo <= calibration <= hi,
+            lo <= latency <= hi,
+            wa + wc + wl == 1000;
+    assert(lo <= numerator / 1000 <= hi) by(nonlinear_arith)
+        requires
+            1000 * lo <= numerator,
+            numerator <= 1000 * hi;
+    if wa == 1000 {
+        assert(numerator == 1000 * accuracy) by(nonlinear_arith)
+            requires wa == 1000, wc == 0, wl == 0, numerator == wa * accuracy + wc * calibration + wl * latency;
+    }
+}
+
+// ============================================================================
+// ALPHA SCHEDULES
+// ============================================================================
+//
+// A fixed alpha adapts as slowly to an agent's first observations as to
+// its thousandth. A schedule picks each update's alpha from the number of
+// observations before it; 1/(k+1) makes the EMA the running mean of the
+// observations, clipped to [alpha_min, alpha_max] so it never stops
+// learning.
+//
+// Executable counterpart: `trust::AlphaSchedule`,
+// `AgentTrust::record_scheduled`.
+
+/// Specification: 1000/(k+1) rounded to nearest, clipped to
+/// [alpha_min, alpha_max]
+pub open spec fn decreasing_alpha(k: nat, alpha_min: u64, alpha_max: u64) -> u64 {
+    let alpha = (2000 + k + 1) / (2 * (k + 1));
+    if alpha < alpha_min {
+        alpha_min
+    } else if alpha > alpha_max {
+        alpha_max
+    } else {
+        alpha as u64
+    }
+}
+
+/// Specification: Trust after EMA updates with each observation in turn,
+/// the i-th at alpha `schedule(start + i)`
+pub open spec fn trust_after_scheduled(
+    initial: u64,
+    observations: Seq<u64>,
+    schedule: spec_fn(nat) -> u64,
+    start: nat,
+) -> u64
+    decreases observations.len()
+{
+    if observations.len() == 0 {
+        initial
+    } else {
+        let k = (observations.len() - 1) as nat;
+        ema_update(
+            trust_after_scheduled(initial, observations.drop_last(), schedule, start),
+            observations.last(),
+            schedule(start + k),
+        )
+    }
+}
+
+/// The decreasing schedule stays within its clip
+proof fn lemma_decreasing_alpha_bounded(k: nat, alpha_min: u64, alpha_max: u64)
+    requires
+        alpha_min <= alpha_max,
+    ensures
+        alpha_min <= decreasing_alpha(k, alpha_min, alpha_max) <= alpha_max,
+{
+}
+
+/// THEOREM 32: Any Bounded Schedule Preserves Bounds
+///
+/// Schedule-polymorphic `update_sequence_preserves_bounds`: whatever
+/// alphas a schedule picks, each update is `ema_preserves_bounds` as long
+/// as they lie in [0, 1000].
+proof fn scheduled_sequence_preserves_bounds(
+    initial: u64,
+    observations: Seq<u64>,
+    schedule: spec_fn(nat) -> u64,
+    start: nat,
+)
+    requires
+        initial <= 1000,
+        forall|k: nat| #[trigger] schedule(k) <= 1000,
+        forall|i: int| 0 <= i < observations.len() ==> observation_valid(#[trigger] observations[i]),
+    ensures
+        trust_after_scheduled(initial, observations, schedule, start) <= 1000,
+    decreases observations.len()
+{
+    if observations.len() > 0 {
+        let rest = observations.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies #[trigger] rest[i] <= 1000 by {
+            assert(rest[i] == observations[i]);
+        }
+        scheduled_sequence_preserves_bounds(initial, rest, schedule, start);
+        let k = (observations.len() - 1) as nat;
+        ema_preserves_bounds(
+            trust_after_scheduled(initial, rest, schedule, start),
+            observations.last(),
+            schedule(start + k),
+        );
+    }
+}
+
+/// THEOREM 33: The Decreasing Schedule Reaches the Running Mean
+///
+/// Starting from any trust, n >= 1 identical observations under the
+/// decreasing schedule leave exactly their mean: the first update has
+/// alpha 1000 and every later one is a fixed point. (For mixed
+/// observations the integer alphas only approximate 1/(k+1); the
+/// executable tests bound the drift.)
+proof fn decreasing_schedule_identical_observations(
+    initial: u64,
+    observation: u64,
+    n: nat,
+    alpha_min: u64,
+)
+    requires
+        initial <= 1000,
+        observation_valid(observation),
+        alpha_min <= 1000,
+        n >= 1,
+    ensures
+        trust_after_scheduled(
+            initial,
+            Seq::new(n, |i: int| observation),
+            |k: nat| decreasing_alpha(k, alpha_min, 1000),
+            0,
+        ) == observation,
+    decreases n
+{
+    let schedule = |k: nat| decreasing_alpha(k, alpha_min, 1000);
+    let observations = Seq::new(n, |i: int| observation);
+    assert(observations.drop_last() =~= Seq::new((n - 1) as nat, |i: int| observation));
+    if n == 1 {
+        assert(schedule(0) == 1000);
+        assert(1000 * observation + 0 * initial == 1000 * observation) by(nonlinear_arith);
+    } else {
+        decreasing_schedule_identical_observations(initial, observation, (n - 1) as nat, alpha_min);
+        let alpha = schedule((n - 1) as nat);
+        lemma_decreasing_alpha_bounded((n - 1) as nat, alpha_min, 1000);
+        assert(alpha * observation + (1000 - alpha) * observation == 1000 * observation)
+            by(nonlinear_arith)
+            requires alpha <= 1000;
+    }
+}
+
+// ============================================================================
+// COLD-START PRIORS
+// ============================================================================
+//
+// A new agent starts from its model family's prior: a trust, and a count of
+// pseudo-observations of that trust, so a decreasing alpha schedule starts
+// lower for families with a track record. Whatever the prior, identical
+// observations afterwards pull two agents together: each EMA update
+// shrinks their gap by (1 - alpha), plus at most one unit of rounding.
+//
+// Executable counterpart: `trust_manager::{TrustPrior, TrustPriors}`,
+// `TrustManager::register_agent`.
+
+/// Specification: An agent at `trust` as if after `pseudo` observations
+/// of it
+pub open spec fn prior_state(trust: u64, pseudo: u64) -> AgentTrust {
+    AgentTrust {
+        current: TrustScore { value: trust },
+        observations: pseudo,
+        cumulative_correct: (pseudo * trust) as u64,
+    }
+}
+
+/// Specification: Gap after `k` updates between two trusts `gap` apart
+/// that see the same observations
+pub open spec fn prior_gap_bound(gap: nat, alpha: u64, k: nat) -> nat
+    decreases k
+{
+    if k == 0 {
+        gap
+    } else {
+        (((1000 - alpha) * prior_gap_bound(gap, alpha, (k - 1) as nat)) / 1000 + 1) as nat
+    }
+}
+
+/// THEOREM 38: Prior States are Valid
+///
+/// A prior in range satisfies the `AgentTrust` invariant, and its
+/// cumulative correctness fits a u64.
+proof fn prior_state_valid(trust: u64, pseudo: u64)
+    requires
+        trust <= 1000,
+        pseudo <= MAX_OBSERVATIONS,
+    ensures
+        pseudo * trust <= u64::MAX,
+        prior_state(trust, pseudo).valid(),
+{
+    assert(pseudo * trust <= pseudo * 1000) by(nonlinear_arith)
+        requires trust <= 1000;
+    assert(pseudo * 1000 <= MAX_OBSERVATIONS * 1000) by(nonlinear_arith)
+        requires pseudo <= MAX_OBSERVATIONS;
+}
+
+/// One update with the same observation shrinks the gap between two trusts
+/// by (1 - alpha), plus at most one unit of rounding
+proof fn lemma_ema_gap_step(a: u64, b: u64, observation: u64, alpha: u64)
+    requires
+        a <= 1000,
+        b <= 1000,
+        observation_valid(observation),
+        alpha <= 1000,
+    ensures
+        trust_distance(ema_update(a, observation, alpha), ema_update(b, observation, alpha))
+            <= ((1000 - alpha) * trust_distance(a, b)) / 1000 + 1,
+{
+    let (hi, lo) = if a >= b { (a, b) } else { (b, a) };
+    ema_monotone_in_current(lo, hi, observation, alpha);
+    let n_hi = alpha * observation + (1000 - alpha) * hi;
+    let n_lo = alpha * observation + (1000 - alpha) * lo;
+    let gap = (1000 - alpha) * (hi - lo);
+    assert(n_hi == n_lo + gap) by(nonlinear_arith)
+        requires
+            n_hi == alpha * observation + (1000 - alpha) * hi,
+            n_lo == alpha * observation + (1000 - alpha) * lo,
+            gap == (1000 - alpha) * (hi - lo),
+            lo <= hi;
+    assert(n_hi / 1000 - n_lo / 1000 <= gap / 1000 + 1) by(nonlinear_arith)
+        requires n_hi == n_lo + gap, n_lo >= 0, gap >= 0;
+}
+
+/// THEOREM 39: Priors Wash Out
+///
+/// Two agents that see the same observations after different priors end
+/// up within `prior_gap_bound` of each other: the prior gap shrinks by
+/// (1 - alpha) a round, toward a rounding floor of about 1 / alpha units.
+proof fn prior_gap_converges(a: u64, b: u64, observations: Seq<u64>, alpha: u64)
+    requires
+        a <= 1000,
+        b <= 1000,
+        alpha <= 1000,
+        forall|i: int| 0 <= i < observations.len() ==> observation_valid(#[trigger] observations[i]),
+    ensures
+        trust_distance(trust_after(a, observations, alpha), trust_after(b, observations, alpha))
+            <= prior_gap_bound(trust_distance(a, b) as nat, alpha, observations.len()),
+    decreases observations.len()
+{
+    if observations.len() > 0 {
+        let rest = observations.drop_last();
+        let last = observations.last();
+        assert forall|i: int| 0 <= i < rest.len() implies #[trigger] rest[i] <= 1000 by {
+            assert(rest[i] == observations[i]);
+        }
+        prior_gap_converges(a, b, rest, alpha);
+        update_sequence_preserves_bounds(a, rest, alpha);
+        update_sequence_preserves_bounds(b, rest, alpha);
+        assert(observations =~= rest.push(last));
+        let step = |acc: u64, obs: u64| ema_update(acc, obs, alpha);
+        lemma_fold_push(rest, last, a, step);
+        lemma_fold_push(rest, last, b, step);
+        let (ta, tb) = (trust_after(a, rest, alpha), trust_after(b, rest, alpha));
+        lemma_ema_gap_step(ta, tb, last, alpha);
+        let bound = prior_gap_bound(trust_distance(a, b) as nat, alpha, rest.len());
+        assert((1000 - alpha) * trust_distance(ta, tb) <= (1000 - alpha) * bound) by(nonlinear_arith)
+            requires trust_distance(ta, tb) <= bound, alpha <= 1000;
+        lemma_div_1000_monotone((1000 - alpha) * trust_distance(ta, tb), (1000 - alpha) * bound);
+    }
+}
+
+// ============================================================================
+// DRIFT FROM THE REAL-VALUED EMA
+// ============================================================================
+//
+// `ema_update` truncates the exact value (alpha * observation + (1000 -
+// alpha) * current) / 1000. Each step lands less than one unit below it
+// (`ema_rounding_error`), and over k steps the integer trust stays less
+// than k units below the real-valued EMA of the same observations
+// (`ema_drift_bounded`). The errors add rather than compound: the error
+// carried into a step is scaled by 1 - alpha <= 1 like the trust itself
+// (the contraction of THEOREM 28), so a step adds at most one new unit on
+// top of a carried error it never grows. At alpha > 0 the carried error
+// also shrinks geometrically, which caps the drift near 1000 / alpha
+// units however long the run; the k-unit bound does not need that.
+//
+// The real-valued EMA after k steps is a fraction over 1000^k, so the
+// specification carries it times 1000^k as an integer.
+//
+// Executable counterpart: the differential tests in `trust.rs`, which run
+// `trust::ema_update` beside an f64 reference.
+
+/// Specification: 1000^k
+pub open spec fn pow1000(k: nat) -> int
+    decreases k
+{
+    if k == 0 { 1 } else { 1000 * pow1000((k - 1) as nat) }
+}
+
+/// 1000^k is positive
+proof fn lemma_pow1000_positive(k: nat)
+    ensures
+        pow1000(k) >= 1,
+    decreases k
+{
+    if k > 0 {
+        lemma_pow1000_positive((k - 1) as nat);
+    }
+}
+
+/// Specification: The real-valued EMA after `observations` from `initial`,
+/// times 1000^k for k observations
+pub open spec fn ema_exact_scaled(initial: u64, observations: Seq<u64>, alpha: u64) -> int
+    decreases observations.len()
+{
+    if observations.len() == 0 {
+        initial as int
+    } else {
+        let k = (observations.len() - 1) as nat;
+        alpha * observations.last() * pow1000(k)
+            + (1000 - alpha) * ema_exact_scaled(initial, observations.drop_last(), alpha)
+    }
+}
+
+/// THEOREM 48: EMA Rounding Error
+///
+/// One update lands at most the exact value and less than one unit below
+/// it: 1000 * ema <= numerator < 1000 * ema + 1000.
+proof fn ema_rounding_error(current: u64, observation: u64, alpha: u64)
+    requires
+        current <= 1000,
+        observation_valid(observation),
+        alpha <= 1000,
+    ensures
+        ({
+            let next = ema_update(current, observation, alpha);
+            let exact = alpha * observation + (1000 - alpha) * current;
+            1000 * next <= exact < 1000 * next + 1000
+        }),
+{
+    lemma_ema_no_overflow(current, observation, alpha);
+    let exact = alpha * observation + (1000 - alpha) * current;
+    assert(1000 * (exact / 1000) <= exact < 1000 * (exact / 1000) + 1000) by(nonlinear_arith)
+        requires exact >= 0;
+}
+
+/// THEOREM 49: EMA Drift is Bounded
+///
+/// After k updates the integer trust is never above the real-valued EMA
+/// and, for k > 0, less than k units below it: the drift
+/// ema_exact_scaled - 1000^k * trust_after lies in [0, k * 1000^k).
+/// By induction, with d the drift before a step and r < 1000 the step's
+/// rounding error in thousandths of a unit, the drift after it is
+/// (1000 - alpha) * d + 1000^k * r: the old drift scaled by at most 1000
+/// plus less than one new unit.
+proof fn ema_drift_bounded(initial: u64, observations: Seq<u64>, alpha: u64)
+    requires
+        initial <= 1000,
+        alpha <= 1000,
+        forall|i: int| 0 <= i < observations.len() ==> observation_valid(#[trigger] observations[i]),
+    ensures
+        ({
+            let k = observations.len();
+            let drift = ema_exact_scaled(initial, observations, alpha)
+                - pow1000(k) * trust_after(initial, observations, alpha);
+            &&& 0 <= drift <= k * pow1000(k)
+            &&& k > 0 ==> drift < k * pow1000(k)
+        }),
+    decreases observations.len()
+{
+    if observations.len() > 0 {
+        let rest = observations.drop_last();
+        let last = observations.last();
+        let k = rest.len();
+        assert forall|i: int| 0 <= i < rest.len() implies observation_valid(#[trigger] rest[i]) by {
+            assert(rest[i] == observations[i]);
+        }
+        ema_drift_bounded(initial, rest, alpha);
+        update_sequence_preserves_bounds(initial, rest, alpha);
+        assert(observations =~= rest.push(last));
+        lemma_fold_push(rest, last, initial, |acc: u64, obs: u64| ema_update(acc, obs, alpha));
+
+        let before = trust_after(initial, rest, alpha);
+        let after = trust_after(initial, observations, alpha);
+        ema_rounding_error(before, last, alpha);
+        let exact = alpha * last + (1000 - alpha) * before;
+        let error = exact - 1000 * after;
+        let p = pow1000(k);
+        let d = ema_exact_scaled(initial, rest, alpha) - p * before;
+        assert(pow1000(k + 1) == 1000 * p);
+        assert(p >= 1) by {
+            lemma_pow1000_positive(k);
+        }
+        // exact * p == alpha * last * p + (1000 - alpha) * p * before
+        assert(ema_exact_scaled(initial, observations, alpha) - 1000 * p * after
+            == (1000 - alpha) * d + p * error) by(nonlinear_arith)
+            requires
+                ema_exact_scaled(initial, observations, alpha)
+                    == alpha * last * p + (1000 - alpha) * ema_exact_scaled(initial, rest, alpha),
+                d == ema_exact_scaled(initial, rest, alpha) - p * before,
+                error == alpha * last + (1000 - alpha) * before - 1000 * after;
+        assert(0 <= (1000 - alpha) * d + p * error < (k + 1) * (1000 * p)) by(nonlinear_arith)
+            requires 0 <= d <= k * p, 0 <= error < 1000, alpha <= 1000, p >= 1;
+    }
+}
+
+// ============================================================================
+// AGENT MODEL WEIGHTS (from math_consensus_verifier.py)
+// ============================================================================
+
+/// Model weight configuration (scaled by 100 for integer math): the
+/// built-in registry
+/// From: model_weights = {"o1-mini": 1.8, "nvidia_nemotron_70b": 1.7, "gpt-4o": 1.5, ...}
+pub open spec fn model_weight(model_id: u64) -> u64 {
+    // Model IDs: 0=o1-mini, 1=nemotron, 2=gpt-4o, 3=gpt-4-turbo, 4=gpt-4o-mini
+    if model_id == 0 { 180 }       // o1-mini: 1.8
+    else if model_id == 1 { 170 }  // nvidia_nemotron_70b: 1.7
+    else if model_id == 2 { 150 }  // gpt-4o: 1.5
+    else if model_id == 3 { 150 }  // gpt-4-turbo: 1.5
+    else if model_id == 4 { 130 }  // gpt-4o-mini: 1.3
+    else { 100 }                   // default: 1.0
+}
+
+/// Specification: A model registry's weight for each model id, scaled by
+/// 100. Deployments load their own (`models::ModelRegistry`); ids a
+/// registry does not list weigh 100.
+pub open spec fn builtin_registry() -> spec_fn(u64) -> u64 {
+    |model_id: u64| model_weight(model_id)
+}
+
+/// Specification: The registry validation predicate, checked by
+/// `ModelRegistry` at load time: every weight in [1.0, 2.0]
+pub open spec fn registry_valid(weights: spec_fn(u64) -> u64) -> bool {
+    forall|model_id: u64| 100 <= #[trigger] weights(model_id) && weights(model_id) <= 200
+}
+
+/// The built-in registry passes validation
+proof fn lemma_builtin_registry_valid()
+    ensures
+        registry_valid(builtin_registry()),
+{
+    assert forall|model_id: u64|
+        100 <= #[trigger] builtin_registry()(model_id) && builtin_registry()(model_id) <= 200 by {
+        assert(builtin_registry()(model_id) == model_weight(model_id));
+    }
+}
+
+/// THEOREM 11: Model Weights are Bounded
+///
+/// Every model weight of a validated registry is positive and bounded.
+proof fn model_weights_bounded(weights: spec_fn(u64) -> u64, model_id: u64)
+    requires
+        registry_valid(weights),
+    ensures
+        weights(model_id) >= 100,  // At least 1.0
+        weights(model_id) <= 200,  // At most 2.0
+{
+    assert(100 <= weights(model_id) && weights(model_id) <= 200);
+}
+
+/// THEOREM 12: Combined Trust and Model Weight
+///
+/// The product of trust and a validated registry's model weight is
+/// bounded.
+proof fn combined_weight_bounded(weights: spec_fn(u64) -> u64, trust: u64, model_id: u64)
+    requires
+        registry_valid(weights),
+        trust <= 1000,
+    ensures
+        ({
+            let weight = weights(model_id);
+            let combined = (trust * weight) / 1000;
+            combined <= 200  // Max: 1.0 * 2.0 = 2.0
+        })
+{
+    model_weights_bounded(weights, model_id);
+    let weight = weights(model_id);
+    assert(trust * weight <= 1000 * 200) by(nonlinear_arith)
+        requires trust <= 1000, weight <= 200;
+    let product = trust * weight;
+    assert(product / 1000 <= 200);
+}
+
+/// Specification: Weight of a vote at `trust` from a model weighing
+/// `weight`, scaled by 100: Theorem 12's combined weight, rounded down
+pub open spec fn effective_weight(trust: u64, weight: u64) -> u64 {
+    ((trust * weight) / 1000) as u64
+}
+
+/// THEOREM 42: Effective Weight is Monotone
+///
+/// More trust or a heavier model never gives a vote less effective weight,
+/// and no valid inputs exceed 2.0.
+///
+/// Executable counterpart: `trust::effective_weight`, which saturates a
+/// model weight outside [1.0, 2.0] to the nearest bound first; clamping is
+/// monotone too, so the order carries over to any weight.
+proof fn effective_weight_monotone(trust1: u64, trust2: u64, weight1: u64, weight2: u64)
+    requires
+        trust1 <= trust2 <= 1000,
+        weight1 <= weight2 <= 200,
+    ensures
+        effective_weight(trust1, weight1) <= effective_weight(trust2, weight2),
+        effective_weight(trust2, weight2) <= 200,
+{
+    assert(trust1 * weight1 <= trust2 * weight2) by(nonlinear_arith)
+        requires trust1 <= trust2, weight1 <= weight2;
+    assert(trust2 * weight2 <= 1000 * 200) by(nonlinear_arith)
+        requires trust2 <= 1000, weight2 <= 200;
+    lemma_div_1000_monotone(trust1 * weight1, trust2 * weight2);
+    lemma_div_1000_monotone(trust2 * weight2, 1000 * 200);
+}
+
+// ============================================================================
+// TRUST- AND MODEL-WEIGHTED CONSENSUS
+// ============================================================================
+
+/// A consensus threshold (as in byzantine_consensus.rs): exactly two
+/// thirds, or PerMille(t) for t/1000
+pub enum Threshold {
+    TwoThirds,
+    PerMille(u64),
+}
+
+/// Default consensus threshold (`CONSENSUS_THRESHOLD` in
+/// byzantine_consensus.rs), exactly two thirds
+pub const CONSENSUS_THRESHOLD: Threshold = Threshold::TwoThirds;
+
+/// Specification: The fraction a threshold stands for, scaled by 3000
+/// (as in byzantine_consensus.rs): 2000, or 3t for PerMille(t)
+pub open spec fn threshold_exact(t: Threshold) -> nat {
+    match t {
+        Threshold::TwoThirds => 2000,
+        Threshold::PerMille(p) => 3 * (p as nat),
+    }
+}
+
+/// Specification: `agrees` of `total` reach the fraction `t` stands for,
+/// by the same exact comparison as byzantine_consensus.rs and `reaches`
+/// in core.rs
+pub open spec fn meets_threshold(agrees: nat, total: nat, t: Threshold) -> bool {
+    match t {
+        Threshold::TwoThirds => agrees * 3 >= 2 * total,
+        Threshold::PerMille(p) => agrees * 1000 >= (p as nat) * total,
+    }
+}
+
+/// Specification: A supermajority threshold: above half, at most all
+pub open spec fn valid_threshold(t: Threshold) -> bool {
+    match t {
+        Threshold::TwoThirds => true,
+        Threshold::PerMille(p) => 500 < p <= 1000,
+    }
+}
+
+/// The exact fraction of a valid threshold is above one half and at most
+/// one; meets_threshold compares against it (as in byzantine_consensus.rs)
+proof fn lemma_threshold_exact(t: Threshold)
+    ensures
+        valid_threshold(t) ==> 1500 < threshold_exact(t) <= 3000,
+        forall|agrees: nat, total: nat| #[trigger] meets_threshold(agrees, total, t)
+            <==> agrees * 3000 >= threshold_exact(t) * total,
+{
+    assert forall|agrees: nat, total: nat| #[trigger] meets_threshold(agrees, total, t)
+        <==> agrees * 3000 >= threshold_exact(t) * total by {
+        match t {
+            Threshold::TwoThirds => {
+                assert(agrees * 3000 == (agrees * 3) * 1000) by(nonlinear_arith);
+                assert(2000 * total == (2 * total) * 1000) by(nonlinear_arith);
+            }
+            Threshold::PerMille(p) => {
+                assert(agrees * 3000 == (agrees * 1000) * 3) by(nonlinear_arith);
+                assert(3 * (p as nat) * total == ((p as nat) * total) * 3) by(nonlinear_arith);
+            }
+        }
+    }
+}
+
+/// Consensus outcome (as in byzantine_consensus.rs)
+pub enum ConsensusOutcome {
+    /// Consensus reached with agreed value
+    Agreed { value: bool, agreement_pct: u64 },
+    /// Constitutional halt - no consensus
+    Halted { reason: HaltReason },
+}
+
+/// Why a round halted (as in byzantine_consensus.rs)
+pub enum HaltReason {
+    /// Neither value reached the threshold; the majority value's share
+    NoSupermajority { agreement_pct: u64 },
+    /// Current / baseline variance (scaled by 100) above the halt multiple
+    VarianceExceeded { ratio_scaled: u64 },
+    /// Too few votes to decide
+    InsufficientVoters { n: u64 },
+    /// Every vote carries zero weight; the highest voter trust
+    TrustCollapse { max_trust: u64 },
+}
+
+/// Specification: Highest trust in a round (0 when empty)
+pub open spec fn max_trust(trusts: Seq<u64>) -> u64
+    decreases trusts.len()
+{
+    if trusts.len() == 0 {
+        0
+    } else {
+        let rest = max_trust(trusts.drop_last());
+        if trusts.last() > rest { trusts.last() } else { rest }
+    }
+}
+
+/// Specification: Weight of one vote, trust * model weight (max 1000 * 200)
+pub open spec fn vote_weight(trust: u64, model_id: u64) -> nat {
+    (trust * model_weight(model_id)) as nat
+}
+
+/// Specification: Total weight of the votes cast for `value`
+pub open spec fn weighted_votes(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    value: bool,
+) -> nat
+    recommends votes.len() == trusts.len(), votes.len() == model_ids.len()
+    decreases votes.len()
+{
+    if votes.len() == 0 {
+        0
+    } else {
+        let last = votes.len() - 1;
+        weighted_votes(votes.drop_last(), trusts.drop_last(), model_ids.drop_last(), value)
+            + if votes[last] == value { vote_weight(trusts[last], model_ids[last]) } else { 0 }
+    }
+}
+
+/// Specification: Total weight of all votes
+pub open spec fn weighted_total(votes: Seq<bool>, trusts: Seq<u64>, model_ids: Seq<u64>) -> nat {
+    weighted_votes(votes, trusts, model_ids, true) + weighted_votes(votes, trusts, model_ids, false)
+}
+
+/// Specification: Share of the total weight cast for `value` (scaled by 1000)
+pub open spec fn weighted_agreement_scaled(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    value: bool,
+) -> nat {
+    let total = weighted_total(votes, trusts, model_ids);
+    if total == 0 {
+        0
+    } else {
+        weighted_votes(votes, trusts, model_ids, value) * 1000 / total
+    }
+}
+
+/// Specification: Weighted consensus decision
+///
+/// Decides the value holding the majority of the weight when its share
+/// reaches the threshold `t` (meets_threshold). Halts with TrustCollapse
+/// when no vote carries any weight and with InsufficientVoters when there
+/// are no votes.
+pub open spec fn decide_consensus_weighted(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    t: Threshold,
+) -> ConsensusOutcome
+    recommends
+        votes.len() == trusts.len(),
+        votes.len() == model_ids.len(),
+        valid_threshold(t),
+{
+    let total = weighted_total(votes, trusts, model_ids);
+    let value = 2 * weighted_votes(votes, trusts, model_ids, true) > total;
+    let agreement = weighted_agreement_scaled(votes, trusts, model_ids, value);
+
+    if votes.len() == 0 {
+        ConsensusOutcome::Halted { reason: HaltReason::InsufficientVoters { n: 0 } }
+    } else if total == 0 {
+        // Degenerate case: all trust (or weight) is zero
+        ConsensusOutcome::Halted {
+            reason: HaltReason::TrustCollapse { max_trust: max_trust(trusts) },
+        }
+    } else if meets_threshold(weighted_votes(votes, trusts, model_ids, value), total, t) {
+        ConsensusOutcome::Agreed { value, agreement_pct: agreement as u64 }
+    } else {
+        ConsensusOutcome::Halted {
+            reason: HaltReason::NoSupermajority { agreement_pct: agreement as u64 },
+        }
+    }
+}
+
+/// Each vote's weight is at most 1000 * 200, so n votes weigh at most
+/// n * 200_000
+proof fn lemma_weighted_votes_bounded(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    value: bool,
+)
+    requires
+        votes.len() == trusts.len(),
+        votes.len() == model_ids.len(),
+        forall|i: int| 0 <= i < trusts.len() ==> #[trigger] trusts[i] <= 1000,
+    ensures
+        weighted_votes(votes, trusts, model_ids, value) <= votes.len() * 200_000,
+    decreases votes.len()
+{
+    if votes.len() > 0 {
+        let last = votes.len() - 1;
+        lemma_weighted_votes_bounded(
+            votes.drop_last(),
+            trusts.drop_last(),
+            model_ids.drop_last(),
+            value,
+        );
+        lemma_builtin_registry_valid();
+        model_weights_bounded(builtin_registry(), model_ids[last]);
+        assert(trusts[last] <= 1000);
+        assert(vote_weight(trusts[last], model_ids[last]) <= 200_000) by(nonlinear_arith)
+            requires trusts[last] <= 1000, model_weight(model_ids[last]) <= 200;
+    }
+}
+
+/// THEOREM 13: Weighted Agreement is Bounded
+///
+/// With valid trust scores and the bounded model weights, the weighted
+/// agreement ratio stays in [0, 1000] and the total weight is at most
+/// n * 200_000. An all-zero weight halts as TrustCollapse instead of
+/// dividing by zero.
+proof fn weighted_agreement_bounded(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    value: bool,
+)
+    requires
+        votes.len() == trusts.len(),
+        votes.len() == model_ids.len(),
+        forall|i: int| 0 <= i < trusts.len() ==> #[trigger] trusts[i] <= 1000,
+    ensures
+        weighted_agreement_scaled(votes, trusts, model_ids, value) <= 1000,
+        weighted_total(votes, trusts, model_ids) <= 2 * votes.len() * 200_000,
+        votes.len() > 0 && weighted_total(votes, trusts, model_ids) == 0 ==>
+            forall|t: Threshold| #[trigger] decide_consensus_weighted(votes, trusts, model_ids, t)
+                == (ConsensusOutcome::Halted {
+                    reason: HaltReason::TrustCollapse { max_trust: max_trust(trusts) },
+                }),
+{
+    let total = weighted_total(votes, trusts, model_ids);
+    let part = weighted_votes(votes, trusts, model_ids, value);
+
+    // The votes for `value` are one of the two summands of the total
+    assert(part <= total);
+    if total > 0 {
+        assert(part * 1000 / total <= 1000) by(nonlinear_arith)
+            requires part <= total, total > 0;
+    }
+
+    lemma_weighted_votes_bounded(votes, trusts, model_ids, true);
+    lemma_weighted_votes_bounded(votes, trusts, model_ids, false);
+}
+
+/// THEOREM 14: Minority Influence is Bounded
+///
+/// Every voter with trust at least `min_trust` weighs at least
+/// min_trust * 100 and at most 200_000, so `k` voters can only outweigh
+/// `m` others when k * 200_000 > m * min_trust * 100. In particular a
+/// minority with k * 2000 <= m * min_trust never wins on weight.
+proof fn weighted_minority_bound(k: nat, m: nat, min_trust: nat)
+    requires
+        min_trust <= 1000,
+        k * 2000 <= m * min_trust,
+    ensures
+        k * 200_000 <= m * (min_trust * 100),
+{
+    assert(k * 200_000 == (k * 2000) * 100);
+    assert(m * (min_trust * 100) == (m * min_trust) * 100);
+}
+
+/// THEOREM 14: No Conflicting Weighted Agreement
+///
+/// The weight cast for each value sums to the total, so at a valid
+/// threshold at most one value reaches it, and two observers deciding the
+/// same weighted votes at valid thresholds t1, t2 never agree on
+/// contradictory values.
+proof fn weighted_no_conflicting_agreement(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    t1: Threshold,
+    t2: Threshold,
+)
+    requires
+        valid_threshold(t1),
+        valid_threshold(t2),
+    ensures
+        ({
+            let total = weighted_total(votes, trusts, model_ids);
+            let yes = weighted_votes(votes, trusts, model_ids, true);
+            let no = weighted_votes(votes, trusts, model_ids, false);
+            let te = threshold_exact(t1);
+            total > 0 ==> !(yes * 3000 >= te * total && no * 3000 >= te * total)
+        }),
+        match (
+            decide_consensus_weighted(votes, trusts, model_ids, t1),
+            decide_consensus_weighted(votes, trusts, model_ids, t2),
+        ) {
+            (
+                ConsensusOutcome::Agreed { value: v1, agreement_pct: _ },
+                ConsensusOutcome::Agreed { value: v2, agreement_pct: _ },
+            ) => v1 == v2,
+            _ => true,
+        },
+{
+    let total = weighted_total(votes, trusts, model_ids);
+    let yes = weighted_votes(votes, trusts, model_ids, true);
+    let no = weighted_votes(votes, trusts, model_ids, false);
+    // weighted_total is yes + no by definition
+    assert(total == yes + no);
+    lemma_threshold_exact(t1);
+    lemma_threshold_exact(t2);
+    let te = threshold_exact(t1);
+    assert(te > 1500);
+    if total > 0 {
+        assert(!(yes * 3000 >= te * total && no * 3000 >= te * total)) by(nonlinear_arith)
+            requires yes + no == total, total > 0, te > 1500;
+    }
+    // Both observers pick the weighted majority value, which depends only
+    // on the votes: 2 * yes > total
+}
+
+// ============================================================================
+// STAKE-WEIGHTED BYZANTINE BOUND
+// ============================================================================
+
+/// Specification: Weight cast for `value` by the voters whose
+/// `byzantine[i]` is `faulty`
+pub open spec fn group_votes(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    byzantine: Seq<bool>,
+    faulty: bool,
+    value: bool,
+) -> nat
+    recommends
+        votes.len() == trusts.len(),
+        votes.len() == model_ids.len(),
+        votes.len() == byzantine.len(),
+    decreases votes.len()
+{
+    if votes.len() == 0 {
+        0
+    } else {
+        let last = votes.len() - 1;
+        group_votes(
+            votes.drop_last(),
+            trusts.drop_last(),
+            model_ids.drop_last(),
+            byzantine.drop_last(),
+            faulty,
+            value,
+        ) + if byzantine[last] == faulty && votes[last] == value {
+            vote_weight(trusts[last], model_ids[last])
+        } else {
+            0
+        }
+    }
+}
+
+/// Specification: Total weight of the Byzantine voters, however they vote
+pub open spec fn byzantine_weight(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    byzantine: Seq<bool>,
+) -> nat {
+    group_votes(votes, trusts, model_ids, byzantine, true, true)
+        + group_votes(votes, trusts, model_ids, byzantine, true, false)
+}
+
+/// Specification: Byzantine voters hold less than a third of the weight,
+/// the weighted analogue of f < n/3 (false when nothing weighs anything)
+pub open spec fn byzantine_safe_weighted(total_weight: nat, byzantine_weight: nat) -> bool {
+    3 * byzantine_weight < total_weight
+}
+
+/// Specification: The honest voters casting `v` hold a strict majority of
+/// the honest weight
+pub open spec fn honest_weighted_majority(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    byzantine: Seq<bool>,
+    v: bool,
+) -> bool {
+    2 * group_votes(votes, trusts, model_ids, byzantine, false, v)
+        > group_votes(votes, trusts, model_ids, byzantine, false, v)
+            + group_votes(votes, trusts, model_ids, byzantine, false, !v)
+}
+
+/// The weight cast for a value splits into its honest and Byzantine parts
+proof fn lemma_group_votes_partition(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    byzantine: Seq<bool>,
+    value: bool,
+)
+    requires
+        votes.len() == trusts.len(),
+        votes.len() == model_ids.len(),
+        votes.len() == byzantine.len(),
+    ensures
+        weighted_votes(votes, trusts, model_ids, value)
+            == group_votes(votes, trusts, model_ids, byzantine, false, value)
+                + group_votes(votes, trusts, model_ids, byzantine, true, value),
+    decreases votes.len()
+{
+    if votes.len() > 0 {
+        lemma_group_votes_partition(
+            votes.drop_last(),
+            trusts.drop_last(),
+            model_ids.drop_last(),
+            byzantine.drop_last(),
+            value,
+        );
+    }
+}
+
+/// Byzantine voters without trust weigh nothing, however many there are
+proof fn lemma_zero_weight_byzantine(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    byzantine: Seq<bool>,
+    value: bool,
+)
+    requires
+        votes.len() == trusts.len(),
+        votes.len() == model_ids.len(),
+        votes.len() == byzantine.len(),
+        forall|i: int| 0 <= i < votes.len() && #[trigger] byzantine[i] ==> trusts[i] == 0,
+    ensures
+        group_votes(votes, trusts, model_ids, byzantine, true, value) == 0,
+    decreases votes.len()
+{
+    if votes.len() > 0 {
+        let last = votes.len() - 1;
+        assert forall|i: int|
+            0 <= i < votes.len() - 1 && #[trigger] byzantine.drop_last()[i]
+            implies trusts.drop_last()[i] == 0 by {
+            assert(byzantine[i]);
+        }
+        lemma_zero_weight_byzantine(
+            votes.drop_last(),
+            trusts.drop_last(),
+            model_ids.drop_last(),
+            byzantine.drop_last(),
+            value,
+        );
+        if byzantine[last] {
+            assert(vote_weight(trusts[last], model_ids[last]) == 0);
+        }
+    }
+}
+
+/// THEOREM 15: Stake-Weighted Byzantine Safety
+///
+/// Byzantine voters holding less than a third of the total weight cannot
+/// overrule honest voters holding a strict majority of the honest weight
+/// for v, at any threshold of at least 2/3: the weight for !v stays below
+/// two thirds of the total, so the outcome is never Agreed { !v }. The
+/// number of Byzantine voters does not matter, only their weight; voters
+/// weighing nothing change no sum (lemma_zero_weight_byzantine).
+///
+/// The degenerate rounds are excluded by the hypotheses rather than
+/// special-cased: a round weighing nothing is never byzantine_safe_weighted
+/// (it halts with TrustCollapse anyway), and an honest weighted majority
+/// needs some honest weight behind v.
+proof fn weighted_byzantine_safety(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    byzantine: Seq<bool>,
+    t: Threshold,
+    v: bool,
+)
+    requires
+        votes.len() == trusts.len(),
+        votes.len() == model_ids.len(),
+        votes.len() == byzantine.len(),
+        valid_threshold(t),
+        threshold_exact(t) >= 2000,
+        byzantine_safe_weighted(
+            weighted_total(votes, trusts, model_ids),
+            byzantine_weight(votes, trusts, model_ids, byzantine),
+        ),
+        honest_weighted_majority(votes, trusts, model_ids, byzantine, v),
+    ensures
+        weighted_total(votes, trusts, model_ids) > 0,
+        group_votes(votes, trusts, model_ids, byzantine, false, v) > 0,
+        weighted_votes(votes, trusts, model_ids, !v) * 3000
+            < threshold_exact(t) * weighted_total(votes, trusts, model_ids),
+        match decide_consensus_weighted(votes, trusts, model_ids, t) {
+            ConsensusOutcome::Agreed { value, agreement_pct: _ } => value == v,
+            ConsensusOutcome::Halted { reason: _ } => true,
+        },
+{
+    let total = weighted_total(votes, trusts, model_ids);
+    let against = weighted_votes(votes, trusts, model_ids, !v);
+    let honest_for = group_votes(votes, trusts, model_ids, byzantine, false, v);
+    let honest_against = group_votes(votes, trusts, model_ids, byzantine, false, !v);
+    let byzantine_for = group_votes(votes, trusts, model_ids, byzantine, true, v);
+    let byzantine_against = group_votes(votes, trusts, model_ids, byzantine, true, !v);
+
+    lemma_group_votes_partition(votes, trusts, model_ids, byzantine, v);
+    lemma_group_votes_partition(votes, trusts, model_ids, byzantine, !v);
+    assert(total == weighted_votes(votes, trusts, model_ids, v) + against);
+    assert(against == honest_against + byzantine_against);
+    assert(byzantine_weight(votes, trusts, model_ids, byzantine)
+        == byzantine_for + byzantine_against);
+
+    // honest_against < honest_for, and the Byzantine weight is under a
+    // third of the total, so the weight against v is under two thirds
+    lemma_threshold_exact(t);
+    let te = threshold_exact(t);
+    assert(3 * against < 2 * total);
+    assert(against * 3000 < te * total) by(nonlinear_arith)
+        requires
+            3 * against < 2 * total,
+            te >= 2000;
+
+    // Agreement needs the decided value's weight to reach t; !v's cannot
+    if v {
+        assert(against == weighted_votes(votes, trusts, model_ids, false));
+    } else {
+        assert(against == weighted_votes(votes, trusts, model_ids, true));
+    }
+}
+
+// ============================================================================
+// PERMUTATION INVARIANCE OF WEIGHTED CONSENSUS
+// ============================================================================
+
+/// Specification: A round as (vote, trust, model id) ballots, so that
+/// votes and their weights permute together
+pub open spec fn weighted_ballots(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+) -> Seq<(bool, u64, u64)> {
+    Seq::new(votes.len(), |i: int| (votes[i], trusts[i], model_ids[i]))
+}
+
+/// Specification: Weight of one ballot toward `value`
+pub open spec fn ballot_weight(ballot: (bool, u64, u64), value: bool) -> nat {
+    if ballot.0 == value { vote_weight(ballot.1, ballot.2) } else { 0 }
+}
+
+/// Specification: Total weight of the ballots cast for `value`
+pub open spec fn ballots_weight(ballots: Seq<(bool, u64, u64)>, value: bool) -> nat
+    decreases ballots.len()
+{
+    if ballots.len() == 0 {
+        0
+    } else {
+        ballots_weight(ballots.drop_last(), value) + ballot_weight(ballots.last(), value)
+    }
+}
+
+/// weighted_votes is the weight of the round's ballots
+proof fn lemma_weighted_votes_ballots(
+    votes: Seq<bool>,
+    trusts: Seq<u64>,
+    model_ids: Seq<u64>,
+    value: bool,
+)
+    requires
+        votes.len() == trusts.len(),
+        votes.len() == model_ids.len(),
+    ensures
+        weighted_votes(votes, trusts, model_ids, value)
+            == ballots_weight(weighted_ballots(votes, trusts, model_ids), value),
+    decreases votes.len()
+{
+    if votes.len() > 0 {
+        let last = votes.len() - 1;
+        lemma_weighted_votes_ballots(votes.drop_last(), trusts.drop_last(), model_ids.drop_last(), value);
+        assert(weighted_ballots(votes, trusts, model_ids).drop_last() =~= weighted_ballots(
+            votes.drop_last(),
+            trusts.drop_last(),
+            model_ids.drop_last(),
+        ));
+        assert(weighted_ballots(votes, trusts, model_ids).last()
+            == (votes[last], trusts[last], model_ids[last]));
+    }
+}
+
+/// Taking one ballot out of a round takes out exactly its weight
+proof fn lemma_ballots_weight_remove(ballots: Seq<(bool, u64, u64)>, i: int, value: bool)
+    requires
+        0 <= i < ballots.len(),
+    ensures
+        ballots_weight(ballots, value)
+            == ballots_weight(ballots.remove(i), value) + ballot_weight(ballots[i], value),
+    decreases ballots.len()
+{
+    if i == ballots.len() - 1 {
+        assert(ballots.remove(i) =~= ballots.drop_last());
+    } else {
+        lemma_ballots_weight_remove(ballots.drop_last(), i, value);
+        assert(ballots.remove(i).drop_last() =~= ballots.drop_last().remove(i));
+        assert(ballots.remove(i).last() == ballots.last());
+    }
+}
+
+/// Reordered ballots weigh the same: peel the last ballot off `a`, find it
+/// in `b`, and compare what is left
+proof fn lemma_ballots_weight_permutation(
+    a: Seq<(bool, u64, u64)>,
+    b: Seq<(bool, u64, u64)>,
+    value: bool,
+)
+    requires
+        a.to_multiset() == b.to_multiset(),
+    ensures
+        ballots_weight(a, value) == ballots_weight(b, value),
+    decreases a.len()
+{
+    to_multiset_ensures(a);
+    to_multiset_ensures(b);
+    if a.len() > 0 {
+        let x = a.last();
+        let rest = a.drop_last();
+        to_multiset_ensures(rest);
+        assert(rest.push(x) =~= a);
+        assert(a.to_multiset().count(x) > 0);
+        assert(b.contains(x));
+        let i = choose|i: int| 0 <= i < b.len() && b[i] == x;
+
+        // b is left + [x] + right, and without x it is left + right
+        let left = b.subrange(0, i);
+        let right = b.subrange(i + 1, b.len() as int);
+        let single = Seq::<(bool, u64, u64)>::empty().push(x);
+        assert(b =~= left + single + right);
+        assert(b.remove(i) =~= left + right);
+        lemma_multiset_commutative(left + single, right);
+        lemma_multiset_commutative(left, single);
+        lemma_multiset_commutative(left, right);
+        to_multiset_ensures(Seq::<(bool, u64, u64)>::empty());
+        assert(rest.to_multiset() =~= b.remove(i).to_multiset());
+
+        lemma_ballots_weight_permutation(rest, b.remove(i), value);
+        lemma_ballots_weight_remove(b, i, value);
+    }
+}
+
+/// max_trust is the largest trust, and one of them unless there are none
+proof fn lemma_max_trust_is_max(trusts: Seq<u64>)
+    ensures
+        forall|i: int| 0 <= i < trusts.len() ==> #[trigger] trusts[i] <= max_trust(trusts),
+        trusts.len() > 0 ==> trusts.contains(max_trust(trusts)),
+    decreases trusts.len()
+{
+    if trusts.len() > 0 {
+        let rest = trusts.drop_last();
+        lemma_max_trust_is_max(rest);
+        assert forall|i: int| 0 <= i < trusts.len() implies #[trigger] trusts[i] <= max_trust(trusts) by {
+            if i < rest.len() {
+                assert(trusts[i] == rest[i]);
+            }
+        }
+        if trusts.last() <= max_trust(rest) && rest.len() > 0 {
+            let j = choose|j: int| 0 <= j < rest.len() && rest[j] == max_trust(rest);
+            assert(trusts[j] == rest[j]);
+        } else {
+            assert(trusts[trusts.len() - 1] == trusts.last());
+        }
+    }
+}
+
+/// THEOREM 16: Weighted Consensus is Permutation-Invariant
+///
+/// Reordering a round's votes together with their trusts and model ids
+/// decides identically: the weights for each value, the total and the
+/// highest trust reported by a collapse are all order-free.
+proof fn decide_consensus_weighted_permutation_invariant(
+    votes_a: Seq<bool>,
+    trusts_a: Seq<u64>,
+    model_ids_a: Seq<u64>,
+    votes_b: Seq<bool>,
+    trusts_b: Seq<u64>,
+    model_ids_b: Seq<u64>,
+    t: Threshold,
+)
+    requires
+        votes_a.len() == trusts_a.len(),
+        votes_a.len() == model_ids_a.len(),
+        votes_b.len() == trusts_b.len(),
+        votes_b.len() == model_ids_b.len(),
+        weighted_ballots(votes_a, trusts_a, model_ids_a).to_multiset()
+            == weighted_ballots(votes_b, trusts_b, model_ids_b).to_multiset(),
+    ensures
+        decide_consensus_weighted(votes_a, trusts_a, model_ids_a, t)
+            == decide_consensus_weighted(votes_b, trusts_b, model_ids_b, t),
+{
+    let a = weighted_ballots(votes_a, trusts_a, model_ids_a);
+    let b = weighted_ballots(votes_b, trusts_b, model_ids_b);
+    to_multiset_ensures(a);
+    to_multiset_ensures(b);
+    assert(votes_a.len() == votes_b.len());
+
+    // The weights for each value
+    lemma_weighted_votes_ballots(votes_a, trusts_a, model_ids_a, true);
+    lemma_weighted_votes_ballots(votes_a, trusts_a, model_ids_a, false);
+    lemma_weighted_votes_ballots(votes_b, trusts_b, model_ids_b, true);
+    lemma_weighted_votes_ballots(votes_b, trusts_b, model_ids_b, false);
+    lemma_ballots_weight_permutation(a, b, true);
+    lemma_ballots_weight_permutation(a, b, false);
+
+    // The highest trust: every trust of one round is a trust of the other
+    lemma_max_trust_is_max(trusts_a);
+    lemma_max_trust_is_max(trusts_b);
+    if votes_a.len() > 0 {
+        let i = choose|i: int| 0 <= i < trusts_a.len() && trusts_a[i] == max_trust(trusts_a);
+        assert(a[i].1 == trusts_a[i]);
+        assert(b.contains(a[i]));
+        let j = choose|j: int| 0 <= j < b.len() && b[j] == a[i];
+        assert(trusts_b[j] == b[j].1);
+
+        let k = choose|k: int| 0 <= k < trusts_b.len() && trusts_b[k] == max_trust(trusts_b);
+        assert(b[k].1 == trusts_b[k]);
+        assert(a.contains(b[k]));
+        let l = choose|l: int| 0 <= l < a.len() && a[l] == b[k];
+        assert(trusts_a[l] == a[l].1);
+        assert(max_trust(trusts_a) == max_trust(trusts_b));
+    }
+}
+
+} // verus!
+
+// ============================================================================
+// EXECUTABLE TEST CODE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_ema_update() {
+        // EMA with alpha=0.3: new = 0.3*obs + 0.7*current
+        let current = 800u64;  // 0.8
+        let observation = 1000u64;  // 1.0
+        let alpha = 300u64;  // 0.3
+
+        // Expected: 0.3*1.0 + 0.7*0.8 = 0.3 + 0.56 = 0.86 = 860/1000
+        let expected = (alpha * observation + (1000 - alpha) * current) / 1000;
+        assert_eq!(expected, 860);
+    }
+
+    #[test]
+    fn test_decay() {
+        // Decay 10%: new = current * 0.9
+        let current = 1000u64;  // 1.0
+        let decay_rate = 100u64;  // 0.1
+
+        let decayed = (current * (1000 - decay_rate)) / 1000;
+        assert_eq!(decayed, 900);  // 0.9
+    }
+
+    #[test]
+    fn test_boost() {
+        // Boost 5%: new = current + 5% of gap
+        let current = 800u64;  // 0.8
+        let boost_rate = 50u64;  // 0.05
+
+        let gap = 1000 - current;  // 200
+        let boost_amount = (gap * boost_rate) / 1000;  // 10
+        let boosted = current + boost_amount;  // 810
+        assert_eq!(boosted, 810);
+
+        // Rounded down, boosts at 5% stop at 0.981: 19 * 50 < 1000
+        let stalled = (0..200).fold(800u64, |acc, _| acc + ((1000 - acc) * boost_rate) / 1000);
+        assert_eq!(stalled, 981);
+        // Carried to a thousandth of a unit, they keep climbing
+        let carried = (0..200).fold(800_000u64, |acc, _| acc + ((1_000_000 - acc) * boost_rate) / 1000);
+        assert_eq!(carried / 1000, 999);
+    }
+
+    #[test]
+    fn test_update_sequence() {
+        // Folding EMA updates over bounded observations stays bounded
+        let alpha = 300u64;
+        let observations = [1000u64, 0, 1000, 1000, 500, 1000];
+        let final_trust = observations
+            .iter()
+            .fold(1000u64, |acc, &obs| (alpha * obs + (1000 - alpha) * acc) / 1000);
+        assert!(final_trust <= 1000);
+
+        // ... and above the least observation when starting above it
+        let observations = [600u64, 900, 600, 1000, 600];
+        let final_trust = observations
+            .iter()
+            .fold(700u64, |acc, &obs| (alpha * obs + (1000 - alpha) * acc) / 1000);
+        assert!(600 <= final_trust && final_trust <= 1000);
+
+        // Sum of n trusts: positive with one nonzero, at most n * 1000
+        let trusts = [0u64, 0, 1];
+        let sum: u64 = trusts.iter().sum();
+        assert!(sum > 0 && sum <= 3 * 1000);
+
+        // All but one trust zero: that agent holds the whole weight
+        for trusts in [[0u64, 0, 1], [0, 1000, 0], [0, 0, 0, 0, 0, 0, 700]] {
+            let sum: u64 = trusts.iter().sum();
+            let normalized: Vec<u64> = trusts.iter().map(|&t| t * 1000 / sum).collect();
+            assert!(normalized.iter().all(|&w| w == 0 || w == 1000));
+            assert_eq!(normalized.iter().sum::<u64>(), 1000);
+        }
+    }
+
+    #[test]
+    fn test_weighted_agreement() {
+        // Two default-weight agents at full trust agree, one o1-mini
+        // agent at full trust disagrees: 200_000 of 380_000 = 52.6%
+        let agree = 2 * (1000u64 * 100);
+        let disagree = 1000u64 * 180;
+        assert_eq!(agree * 1000 / (agree + disagree), 526);
+
+        // Per-vote weight never exceeds 1000 * 200
+        assert!(1000u64 * 200 == 200_000);
+    }
+
+    #[test]
+    fn test_floored_decay_and_quarantine() {
+        // Decay 10% from 0.06 with a 0.05 floor stops at the floor
+        let current = 60u64;
+        let decay_rate = 100u64;
+        let floor = 50u64;
+        let decayed = (current * (1000 - decay_rate)) / 1000;  // 54
+        assert_eq!(decayed.max(floor), 54);
+        assert_eq!(((54 * (1000 - decay_rate)) / 1000).max(floor), 50);
+
+        // Capped at 0.1 a round, trust climbs from 0.5 to 0.9 in no fewer
+        // than 4 rounds, even at alpha 1.0
+        let max_delta = 100u64;
+        let alpha_full = 1000u64;
+        let climbed = (0..4).fold(500u64, |acc, _| {
+            let next = (alpha_full * 1000 + (1000 - alpha_full) * acc) / 1000;
+            next.min(acc + max_delta)
+        });
+        assert_eq!(climbed, 900);
+
+        // Detection at 0.3, leaving quarantine above 0.3 + 0.1
+        let threshold = 300u64;
+        let margin = 100u64;
+        assert!(floor < threshold);
+        assert!(threshold + margin < 1000);
+    }
+
+    #[test]
+    fn test_window_mean() {
+        // A window of 4 after 5 observations keeps the last 4
+        let window_size = 4usize;
+        let mut window = Vec::new();
+        for observation in [1000u64, 1000, 0, 1000, 0] {
+            if window.len() == window_size {
+                window.remove(0);
+            }
+            window.push(observation);
+        }
+        assert_eq!(window, [1000, 0, 1000, 0]);
+        let mean = window.iter().sum::<u64>() / window.len() as u64;
+        assert_eq!(mean, 500);
+        assert!(mean <= 1000);
+
+        // Trust 0.8 over recent accuracy 0.5 diverges by 0.3
+        let divergence_threshold = 250u64;
+        let margin = 100u64;
+        assert!(800 - mean >= divergence_threshold);
+        assert!(margin < divergence_threshold);
+    }
+
+    #[test]
+    fn test_composite_trust() {
+        // Accurate but slow: 0.5 accuracy, 0.4 calibration, 0.1 latency
+        let weights = [300u64, 200, 500];
+        let components = [500u64, 400, 100];
+        let composite: u64 = weights.iter().zip(components).map(|(w, c)| w * c).sum::<u64>() / 1000;
+        assert_eq!(composite, 280);
+        assert!(100 <= composite && composite <= 500);
+
+        // Votes carry accuracy alone by default
+        let accuracy_weight = 1000u64;
+        assert_eq!(accuracy_weight * 640 / 1000, 640);
+    }
+
+    #[test]
+    fn test_prior_gap() {
+        // Priors 0.9 and 0.2, then the same observations at alpha 0.3: the
+        // gap shrinks by 0.7 a round plus at most a unit of rounding
+        let alpha = 300u64;
+        let observations = [1000u64, 0, 700, 700, 1000, 200, 900, 900];
+        let (mut a, mut b, mut bound) = (900u64, 200u64, 700u64);
+        for &obs in &observations {
+            a = (alpha * obs + (1000 - alpha) * a) / 1000;
+            b = (alpha * obs + (1000 - alpha) * b) / 1000;
+            bound = (1000 - alpha) * bound / 1000 + 1;
+            assert!(a.abs_diff(b) <= bound);
+        }
+        assert_eq!(bound, 42);
+
+        // A prior of 20 pseudo-observations at 0.7 counts 14_000 correct
+        assert_eq!(20u64 * 700, 14_000);
+    }
+
+    #[test]
+    fn test_alpha_schedule() {
+        // Decreasing alpha 1000/(k+1): 1000, 500, 333, 250; a constant
+        // observation is reached at once and kept
+        let alphas: Vec<u64> = (0..4u64).map(|k| (2000 + k + 1) / (2 * (k + 1))).collect();
+        assert_eq!(alphas, [1000, 500, 333, 250]);
+        let trust = alphas
+            .iter()
+            .fold(300u64, |acc, &a| (a * 700 + (1000 - a) * acc) / 1000);
+        assert_eq!(trust, 700);
     }
 
     #[test]
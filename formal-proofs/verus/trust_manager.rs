@@ -0,0 +1,2334 @@
+//! # Trust Manager
+//!
+//! Every agent's [`AgentTrust`], updated together from a consensus round
+//! (`apply_round_updates` in `trust_bounds.rs`). [`TrustManager::apply_round`]
+//! rechecks the round's evidence, then gives each agent exactly one
+//! update: suspects are slashed at `equivocation_decay_rate`, and
+//! otherwise a voter that agreed with the decided value is boosted and
+//! one that disagreed decayed. Abstainers, and voters in a halted round,
+//! keep their trust. Decays stop at `trust_floor`, and quarantine is
+//! re-evaluated for every updated agent.
+//!
+//! A round applies in full or not at all, and every post-update trust
+//! stays in `0..=1000` with no agent both boosted and decayed
+//! (`apply_round_preserves_bounds`). Every update is staged from the
+//! trusts before the round and depends only on the agent's own trust and
+//! ballot, so the order agents are updated in cannot change any result
+//! (`round_update_is_local`).
+//!
+//! Evaluators' observations go through
+//! [`TrustManager::record_observations`], which caps how far one round can
+//! move an agent at `max_trust_delta` (`capped_sequence_reachability`): a
+//! compromised evaluator cannot lift an agent to full weight in a few
+//! rounds.
+//!
+//! Agents that sit rounds out decay by `inactivity_decay_rate` per round
+//! missed, down to `trust_floor` (`inactivity_decay_settles`). The decay
+//! is charged lazily, when the agent next takes part in a round or is
+//! [`refresh`](TrustManager::refresh)ed, and quarantine is re-evaluated
+//! then. Reads that select agents see the decay already:
+//! [`effective`](TrustManager::effective), [`ranked`](TrustManager::ranked)
+//! and [`most_trusted`](TrustManager::most_trusted) charge what each agent
+//! owes without committing it.
+//!
+//! New agents join through [`TrustManager::register_agent`] with the
+//! [`ModelSpec`] they run, which the manager keeps for selection
+//! ([`TrustManager::model`]). They start from the model family's
+//! [`TrustPrior`]: a trust and a count of
+//! pseudo-observations of it (`prior_state_valid`). Families without one
+//! get [`TrustPrior::NEUTRAL`]. Priors only matter at the start: two agents
+//! that see the same observations close their gap by `1 - trust_alpha` a
+//! round (`prior_gap_converges`).
+//!
+//! [`TrustManager::snapshot`] copies every agent's trust at a point in
+//! time, and [`TrustSnapshot::diff`] lists what changed between two, for
+//! round reports; [`TrustManager::apply_round_diff`] returns a round's
+//! changes with its updates.
+//!
+//! A manager built [`with_sink`](TrustManager::with_sink) publishes a
+//! [`TrustEvent`] for every boost, decay, slash and quarantine entry or
+//! exit to a [`TrustEventSink`], synchronously and in update order, once
+//! each update has committed: a monitor reacts to quarantines as they
+//! happen rather than polling. The default sink, `()`, publishes nothing.
+//!
+//! Managers persist through a [`TrustStore`]; [`JsonTrustStore`] keeps
+//! them in a versioned JSON file with an integrity digest, and revalidates
+//! every agent on load so a corrupted or hand-edited file cannot put an
+//! out-of-range trust into the verified updates (`serde` feature).
+//!
+//! [`TrustManager`] is [`ScaledTrustManager`] at 1000. A manager at
+//! another scale keeps its agents ([`ScaledAgentTrust`]), deltas, events
+//! and snapshots at that scale, and reads its [`Thresholds`] and priors in
+//! thousandths, rescaled to it. Stores hold managers at 1000.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+#[cfg(feature = "serde")]
+use std::fs;
+#[cfg(feature = "serde")]
+use std::io;
+#[cfg(feature = "serde")]
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+use crate::chain::Hash;
+use crate::consensus::{AgentId, ConsensusOutcome};
+use crate::diversity::ModelFamily;
+use crate::error::ShieldError;
+use crate::models::ModelSpec;
+use crate::round::{EvidenceError, RoundEvidence};
+#[cfg(feature = "serde")]
+use crate::trust::AgentTrustBuilder;
+use crate::trust::{
+    rank_agents, thousandths_at, AgentTrust, ScaledAgentTrust, ScaledObservation, ScaledTrust,
+    TrustError, TrustScore, MAX_OBSERVATIONS,
+};
+use crate::Thresholds;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Why an agent's trust changed, or did not, in a round
+/// (`TrustUpdate` in the specification)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum UpdateReason {
+    /// Voted the decided value: boosted
+    Agreed,
+    /// Voted against the decided value: decayed
+    Disagreed,
+    /// Named a suspect: slashed, whatever its ballot
+    Suspected,
+    /// Cast no vote: unchanged
+    Abstained,
+    /// Voted in a round that halted: unchanged
+    NoConsensus,
+    /// Observed by an evaluator: moved toward the observation, at most
+    /// `max_trust_delta`
+    Observed,
+    /// Sat rounds out: decayed by `inactivity_decay_rate` per round
+    Inactive,
+}
+
+/// One agent's trust before and after a round, at the manager's scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrustDelta<const SCALE: u64 = 1000> {
+    pub agent: AgentId,
+    pub old: ScaledTrust<SCALE>,
+    pub new: ScaledTrust<SCALE>,
+    pub reason: UpdateReason,
+    /// Quarantine state after the round
+    pub quarantined: bool,
+}
+
+/// A round [`TrustManager::apply_round`] or
+/// [`TrustManager::record_observations`] rejected; no trust was changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApplyRoundError {
+    /// The evidence does not recheck
+    Evidence(EvidenceError),
+    /// An update rejected its inputs (a rate or observation above 1000)
+    Trust { agent: AgentId, error: TrustError },
+}
+
+impl fmt::Display for ApplyRoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApplyRoundError::Evidence(err) => write!(f, "invalid round evidence: {}", err),
+            ApplyRoundError::Trust { agent, error } => {
+                write!(f, "trust update of agent {}: {}", agent, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApplyRoundError {}
+
+impl From<EvidenceError> for ApplyRoundError {
+    fn from(err: EvidenceError) -> Self {
+        ApplyRoundError::Evidence(err)
+    }
+}
+
+/// Every agent's trust at a point in time
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct TrustSnapshot<const SCALE: u64 = 1000> {
+    scores: BTreeMap<AgentId, ScaledTrust<SCALE>>,
+}
+
+/// One agent's trust in two snapshots. `before` is `None` for an agent
+/// added since the first, `after` for one removed by the second; an
+/// absent trust counts as 0 in `delta`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrustChange<const SCALE: u64 = 1000> {
+    pub agent: AgentId,
+    pub before: Option<ScaledTrust<SCALE>>,
+    pub after: Option<ScaledTrust<SCALE>>,
+    /// `after - before`, negative for a loss
+    pub delta: i64,
+}
+
+impl<const SCALE: u64> TrustChange<SCALE> {
+    /// In the second snapshot only
+    pub fn is_added(&self) -> bool {
+        self.before.is_none()
+    }
+
+    /// In the first snapshot only
+    pub fn is_removed(&self) -> bool {
+        self.after.is_none()
+    }
+}
+
+impl<const SCALE: u64> TrustSnapshot<SCALE> {
+    /// The agent's trust when the snapshot was taken
+    pub fn get(&self, agent: AgentId) -> Option<ScaledTrust<SCALE>> {
+        self.scores.get(&agent).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+
+    /// Agents and their trusts, in agent order
+    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, AgentId, ScaledTrust<SCALE>> {
+        self.scores.iter()
+    }
+
+    /// What changed from this snapshot to `after`, in agent order: every
+    /// agent added or removed, and every agent whose trust moved
+    pub fn diff(&self, after: &TrustSnapshot<SCALE>) -> Vec<TrustChange<SCALE>> {
+        self.diff_at_least(after, 1)
+    }
+
+    /// [`diff`](Self::diff) without the agents whose trust moved by less
+    /// than `min_delta`. Added and removed agents are always listed.
+    pub fn diff_at_least(
+        &self,
+        after: &TrustSnapshot<SCALE>,
+        min_delta: u64,
+    ) -> Vec<TrustChange<SCALE>> {
+        let agents: BTreeSet<AgentId> = self
+            .scores
+            .keys()
+            .chain(after.scores.keys())
+            .copied()
+            .collect();
+        agents
+            .into_iter()
+            .map(|agent| {
+                let (before, after) = (self.get(agent), after.get(agent));
+                // Trusts are at most SCALE, so the difference fits an i64
+                // up to a scale of i64::MAX
+                let signed =
+                    |trust: Option<ScaledTrust<SCALE>>| trust.map_or(0, |t| t.value() as i64);
+                TrustChange {
+                    agent,
+                    before,
+                    after,
+                    delta: signed(after) - signed(before),
+                }
+            })
+            .filter(|change| {
+                change.is_added() || change.is_removed() || change.delta.unsigned_abs() >= min_delta
+            })
+            .collect()
+    }
+}
+
+impl<const SCALE: u64> FromIterator<(AgentId, ScaledTrust<SCALE>)> for TrustSnapshot<SCALE> {
+    fn from_iter<I: IntoIterator<Item = (AgentId, ScaledTrust<SCALE>)>>(iter: I) -> Self {
+        Self {
+            scores: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// A round's updates, and what they changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundDiff<const SCALE: u64 = 1000> {
+    /// One per agent in the round, as [`TrustManager::apply_round`]
+    /// returns them
+    pub deltas: Vec<TrustDelta<SCALE>>,
+    /// Every agent whose trust moved or who joined
+    /// ([`TrustSnapshot::diff`])
+    pub changes: Vec<TrustChange<SCALE>>,
+}
+
+/// A trust change a [`TrustManager`] publishes to its [`TrustEventSink`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(tag = "event", rename_all = "snake_case")
+)]
+pub enum TrustEvent<const SCALE: u64 = 1000> {
+    /// Entered quarantine at `trust`
+    Quarantined {
+        agent: AgentId,
+        trust: ScaledTrust<SCALE>,
+    },
+    /// Left quarantine at `trust`
+    Recovered {
+        agent: AgentId,
+        trust: ScaledTrust<SCALE>,
+    },
+    /// Decayed: voted against the decided value, sat rounds out, or was
+    /// observed below its trust; `reason` as in its [`TrustDelta`]
+    DecayApplied {
+        agent: AgentId,
+        old: ScaledTrust<SCALE>,
+        new: ScaledTrust<SCALE>,
+        reason: UpdateReason,
+    },
+    /// Boosted: voted the decided value, or was observed above its trust
+    BoostApplied {
+        agent: AgentId,
+        old: ScaledTrust<SCALE>,
+        new: ScaledTrust<SCALE>,
+        reason: UpdateReason,
+    },
+    /// Slashed at `equivocation_decay_rate` as a suspect
+    EquivocationPenalty {
+        agent: AgentId,
+        old: ScaledTrust<SCALE>,
+        new: ScaledTrust<SCALE>,
+    },
+}
+
+impl<const SCALE: u64> TrustEvent<SCALE> {
+    /// The agent the event is about
+    pub fn agent(&self) -> AgentId {
+        match *self {
+            Self::Quarantined { agent, .. }
+            | Self::Recovered { agent, .. }
+            | Self::DecayApplied { agent, .. }
+            | Self::BoostApplied { agent, .. }
+            | Self::EquivocationPenalty { agent, .. } => agent,
+        }
+    }
+
+    /// The events of one update: its trust change, if any, then its
+    /// quarantine transition, if any
+    fn of(delta: &TrustDelta<SCALE>, was_quarantined: bool) -> Vec<TrustEvent<SCALE>> {
+        let TrustDelta {
+            agent,
+            old,
+            new,
+            reason,
+            quarantined,
+        } = *delta;
+        let change = match reason {
+            UpdateReason::Suspected => Some(Self::EquivocationPenalty { agent, old, new }),
+            UpdateReason::Agreed => Some(Self::BoostApplied {
+                agent,
+                old,
+                new,
+                reason,
+            }),
+            UpdateReason::Disagreed | UpdateReason::Inactive => Some(Self::DecayApplied {
+                agent,
+                old,
+                new,
+                reason,
+            }),
+            // Observations move either way, and abstainers may have been
+            // charged inactivity
+            _ if new > old => Some(Self::BoostApplied {
+                agent,
+                old,
+                new,
+                reason,
+            }),
+            _ if new < old => Some(Self::DecayApplied {
+                agent,
+                old,
+                new,
+                reason,
+            }),
+            _ => None,
+        };
+        let transition = match (was_quarantined, quarantined) {
+            (false, true) => Some(Self::Quarantined { agent, trust: new }),
+            (true, false) => Some(Self::Recovered { agent, trust: new }),
+            _ => None,
+        };
+        change.into_iter().chain(transition).collect()
+    }
+}
+
+/// Where a [`TrustManager`] publishes its [`TrustEvent`]s
+///
+/// The manager publishes synchronously, once a mutation has committed,
+/// every event of the mutation in the order of its [`TrustDelta`]s. A sink
+/// that panics unwinds out of the mutating method with the mutation
+/// applied in full, so the manager stays consistent, but the rest of the
+/// mutation's events are not published.
+///
+/// `()` publishes nothing and is the default, `Vec<TrustEvent>` buffers
+/// every event, and an [`mpsc::Sender`] hands them to a consumer on
+/// another thread.
+pub trait TrustEventSink<const SCALE: u64 = 1000> {
+    fn publish(&mut self, event: TrustEvent<SCALE>);
+}
+
+impl<const SCALE: u64> TrustEventSink<SCALE> for () {
+    fn publish(&mut self, _event: TrustEvent<SCALE>) {}
+}
+
+impl<const SCALE: u64> TrustEventSink<SCALE> for Vec<TrustEvent<SCALE>> {
+    fn publish(&mut self, event: TrustEvent<SCALE>) {
+        self.push(event);
+    }
+}
+
+/// Events after the receiver is dropped are discarded
+impl<const SCALE: u64> TrustEventSink<SCALE> for mpsc::Sender<TrustEvent<SCALE>> {
+    fn publish(&mut self, event: TrustEvent<SCALE>) {
+        let _ = self.send(event);
+    }
+}
+
+/// Where a [`TrustManager`] is loaded from and saved to
+pub trait TrustStore {
+    /// Every stored agent's trust; an empty store loads as no agents
+    fn load(&self) -> Result<BTreeMap<AgentId, AgentTrust>, ShieldError>;
+
+    /// Replace the stored trusts with `agents`
+    fn save(&self, agents: &BTreeMap<AgentId, AgentTrust>) -> Result<(), ShieldError>;
+}
+
+/// Version of the trust state format [`JsonTrustStore`] reads and writes
+pub const TRUST_STATE_VERSION: u64 = 1;
+
+/// Why a trust state file was rejected, or an entry of it quarantined
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrustStateError {
+    /// Not a trust state file, or cut short
+    Malformed { message: String },
+    /// A format version this library does not read
+    UnsupportedVersion { version: u64 },
+    /// The agents no longer hash to the recorded digest: the file was
+    /// corrupted or edited by hand
+    DigestMismatch { recorded: Hash, actual: Hash },
+    /// An agent id that is not a u64
+    InvalidAgentId { key: String },
+    /// An agent's entry is not an agent trust (wrong types, unknown fields)
+    MalformedAgent { agent: AgentId, message: String },
+    /// An agent's trust is out of range or its history inconsistent
+    InvalidAgent { agent: AgentId, error: TrustError },
+}
+
+impl fmt::Display for TrustStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrustStateError::Malformed { message } => write!(f, "malformed: {}", message),
+            TrustStateError::UnsupportedVersion { version } => write!(
+                f,
+                "version {} is not supported (expected {})",
+                version, TRUST_STATE_VERSION
+            ),
+            TrustStateError::DigestMismatch { recorded, actual } => write!(
+                f,
+                "agents hash to {} but the file records {}",
+                &actual.to_hex()[..12],
+                &recorded.to_hex()[..12]
+            ),
+            TrustStateError::InvalidAgentId { key } => write!(f, "invalid agent id {:?}", key),
+            TrustStateError::MalformedAgent { agent, message } => {
+                write!(f, "agent {}: {}", agent, message)
+            }
+            TrustStateError::InvalidAgent { agent, error } => {
+                write!(f, "agent {}: {}", agent, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TrustStateError {}
+
+/// Trusts stored as versioned JSON: the format version, every agent's
+/// [`AgentTrust`] keyed by agent id, and a SHA-256 digest of the agents
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "digest": "9c1f...",
+///   "agents": {
+///     "0": { "current": 860, "observations": 2, "cumulative_correct": 1720, ... }
+///   }
+/// }
+/// ```
+///
+/// Loading checks the version and the digest, then revalidates every
+/// agent through [`AgentTrustBuilder::build`]: trusts in `0..=1000` and
+/// `cumulative_correct <= observations * 1000`. [`load`](TrustStore::load)
+/// rejects the whole file on any violation;
+/// [`load_lossy`](Self::load_lossy) quarantines invalid agents instead.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonTrustStore {
+    path: PathBuf,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TrustStateFile {
+    version: u64,
+    digest: Hash,
+    agents: serde_json::Map<String, serde_json::Value>,
+}
+
+/// SHA-256 of the agents' compact JSON, keys sorted
+#[cfg(feature = "serde")]
+fn state_digest(agents: &serde_json::Map<String, serde_json::Value>) -> Hash {
+    let mut text = String::new();
+    canonical_object(agents, &mut text);
+    Hash::sha256(text.as_bytes())
+}
+
+/// Compact JSON with object keys sorted, whatever order the map keeps
+#[cfg(feature = "serde")]
+fn canonical_object(map: &serde_json::Map<String, serde_json::Value>, out: &mut String) {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    out.push('{');
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str(&serde_json::Value::from(key.as_str()).to_string());
+        out.push(':');
+        match value {
+            serde_json::Value::Object(inner) => canonical_object(inner, out),
+            other => out.push_str(&other.to_string()),
+        }
+    }
+    out.push('}');
+}
+
+/// One stored agent, revalidated
+#[cfg(feature = "serde")]
+fn parse_agent(
+    key: &str,
+    value: serde_json::Value,
+) -> Result<(AgentId, AgentTrust), TrustStateError> {
+    let agent = key.parse().map_err(|_| TrustStateError::InvalidAgentId {
+        key: key.to_string(),
+    })?;
+    let builder: AgentTrustBuilder =
+        serde_json::from_value(value).map_err(|e| TrustStateError::MalformedAgent {
+            agent,
+            message: e.to_string(),
+        })?;
+    let trust = builder
+        .build()
+        .map_err(|error| TrustStateError::InvalidAgent { agent, error })?;
+    Ok((agent, trust))
+}
+
+#[cfg(feature = "serde")]
+impl JsonTrustStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn invalid(&self, error: TrustStateError) -> ShieldError {
+        ShieldError::InvalidTrustState {
+            path: self.path.display().to_string(),
+            error,
+        }
+    }
+
+    /// The stored agents, unvalidated, once the file parses, has a known
+    /// version and matches its digest; `None` for a missing file
+    fn read_agents(
+        &self,
+    ) -> Result<Option<serde_json::Map<String, serde_json::Value>>, ShieldError> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let malformed = |e: serde_json::Error| TrustStateError::Malformed {
+            message: e.to_string(),
+        };
+        let value: serde_json::Value =
+            serde_json::from_str(&text).map_err(|e| self.invalid(malformed(e)))?;
+        // Check the version before the layout, which may change with it
+        match value.get("version").and_then(serde_json::Value::as_u64) {
+            Some(TRUST_STATE_VERSION) | None => {}
+            Some(version) => {
+                return Err(self.invalid(TrustStateError::UnsupportedVersion { version }))
+            }
+        }
+        let file: TrustStateFile =
+            serde_json::from_value(value).map_err(|e| self.invalid(malformed(e)))?;
+        let actual = state_digest(&file.agents);
+        if actual != file.digest {
+            return Err(self.invalid(TrustStateError::DigestMismatch {
+                recorded: file.digest,
+                actual,
+            }));
+        }
+        Ok(Some(file.agents))
+    }
+
+    /// Every stored agent's trust, with each invalid agent quarantined at
+    /// zero trust and no history instead of rejecting the file, and why
+    /// each was. A corrupt file, an unknown version or a digest mismatch
+    /// is still an error.
+    pub fn load_lossy(
+        &self,
+    ) -> Result<(BTreeMap<AgentId, AgentTrust>, Vec<TrustStateError>), ShieldError> {
+        let mut agents = BTreeMap::new();
+        let mut rejected = Vec::new();
+        for (key, value) in self.read_agents()?.unwrap_or_default() {
+            match parse_agent(&key, value) {
+                Ok((agent, trust)) => {
+                    agents.insert(agent, trust);
+                }
+                Err(error) => {
+                    if let TrustStateError::MalformedAgent { agent, .. }
+                    | TrustStateError::InvalidAgent { agent, .. } = error
+                    {
+                        agents.insert(agent, quarantined());
+                    }
+                    rejected.push(error);
+                }
+            }
+        }
+        Ok((agents, rejected))
+    }
+}
+
+/// An agent whose stored trust could not be used: untrusted and
+/// quarantined until it earns trust back
+#[cfg(feature = "serde")]
+fn quarantined() -> AgentTrust {
+    AgentTrust::builder()
+        .current(0)
+        .quarantined(true)
+        .build()
+        .expect("zero trust without history is valid")
+}
+
+#[cfg(feature = "serde")]
+impl TrustStore for JsonTrustStore {
+    /// A missing file is an empty store
+    fn load(&self) -> Result<BTreeMap<AgentId, AgentTrust>, ShieldError> {
+        self.read_agents()?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| parse_agent(&key, value).map_err(|e| self.invalid(e)))
+            .collect()
+    }
+
+    /// Replace the file atomically (temp file + rename)
+    fn save(&self, agents: &BTreeMap<AgentId, AgentTrust>) -> Result<(), ShieldError> {
+        let serde_json::Value::Object(agents) = serde_json::to_value(agents)? else {
+            unreachable!("a map serializes as a JSON object");
+        };
+        let file = TrustStateFile {
+            version: TRUST_STATE_VERSION,
+            digest: state_digest(&agents),
+            agents,
+        };
+        let mut tmp = self.path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(&file)? + "\n")?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Where a newly registered agent starts: a trust, and how many
+/// observations of that trust it counts as having made, which lowers the
+/// alpha a decreasing [`AlphaSchedule`](crate::trust::AlphaSchedule) gives
+/// its first real observations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrustPrior {
+    trust: u64,
+    pseudo_observations: u64,
+}
+
+impl TrustPrior {
+    /// Trust 0.5 with no pseudo-observations
+    pub const NEUTRAL: TrustPrior = TrustPrior {
+        trust: 500,
+        pseudo_observations: 0,
+    };
+
+    /// Prior at `trust` (scaled by 1000) over `pseudo_observations`, once
+    /// the trust is at most 1000 and the count at most [`MAX_OBSERVATIONS`]
+    pub fn new(trust: u64, pseudo_observations: u64) -> Result<Self, TrustError> {
+        TrustScore::new(trust)?;
+        if pseudo_observations > MAX_OBSERVATIONS {
+            return Err(TrustError::ObservationLimit);
+        }
+        Ok(Self {
+            trust,
+            pseudo_observations,
+        })
+    }
+
+    pub fn trust(&self) -> TrustScore {
+        TrustScore::new(self.trust).expect("validated by TrustPrior::new")
+    }
+
+    pub fn pseudo_observations(&self) -> u64 {
+        self.pseudo_observations
+    }
+
+    /// An agent at the prior's trust, with `pseudo_observations`
+    /// observations of it in its history and none in its window
+    /// (`prior_state`)
+    pub fn agent(&self) -> AgentTrust {
+        // prior_state_valid: pseudo_observations * trust fits and is at
+        // most 1000 per observation
+        AgentTrust::builder()
+            .current(self.trust)
+            .observations(self.pseudo_observations)
+            .cumulative_correct(self.pseudo_observations * self.trust)
+            .build()
+            .expect("prior_state_valid")
+    }
+
+    /// [`agent`](Self::agent) at `SCALE`: the trust rescaled as
+    /// [`ScaledTrust::rescale`] would, and at most
+    /// [`MAX_OBSERVATIONS`](ScaledAgentTrust::MAX_OBSERVATIONS) of the
+    /// scale's pseudo-observations
+    pub fn scaled_agent<const SCALE: u64>(&self) -> ScaledAgentTrust<SCALE> {
+        let trust = thousandths_at(SCALE, self.trust);
+        let pseudo_observations = self
+            .pseudo_observations
+            .min(ScaledAgentTrust::<SCALE>::MAX_OBSERVATIONS);
+        // prior_state_valid at SCALE: trust <= SCALE, so the sum fits
+        ScaledAgentTrust::builder()
+            .current(trust)
+            .observations(pseudo_observations)
+            .cumulative_correct(pseudo_observations * trust)
+            .build()
+            .expect("prior_state_valid")
+    }
+}
+
+impl Default for TrustPrior {
+    fn default() -> Self {
+        Self::NEUTRAL
+    }
+}
+
+/// A [`TrustPrior`] per model family, and a fallback for the rest
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrustPriors {
+    families: BTreeMap<ModelFamily, TrustPrior>,
+    fallback: TrustPrior,
+}
+
+impl TrustPriors {
+    /// Priors giving every family `fallback`
+    pub fn new(fallback: TrustPrior) -> Self {
+        Self {
+            families: BTreeMap::new(),
+            fallback,
+        }
+    }
+
+    /// Set the family's prior, returning the one it replaces
+    pub fn insert(&mut self, family: ModelFamily, prior: TrustPrior) -> Option<TrustPrior> {
+        self.families.insert(family, prior)
+    }
+
+    /// The family's prior, or the fallback if it has none
+    pub fn get(&self, family: ModelFamily) -> TrustPrior {
+        self.families.get(&family).copied().unwrap_or(self.fallback)
+    }
+
+    pub fn fallback(&self) -> TrustPrior {
+        self.fallback
+    }
+}
+
+/// Every known agent's trust, keyed by agent id, publishing every update
+/// to `S` ([`TrustEventSink`])
+///
+/// This is [`ScaledTrustManager`] at 1000, the scale a [`TrustStore`]
+/// holds.
+pub type TrustManager<S = ()> = ScaledTrustManager<S, 1000>;
+
+/// A [`TrustManager`] whose agents are [`ScaledAgentTrust`]s at `SCALE`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScaledTrustManager<S, const SCALE: u64> {
+    agents: BTreeMap<AgentId, ScaledAgentTrust<SCALE>>,
+    priors: TrustPriors,
+    /// Rounds applied so far
+    round: u64,
+    /// Last round each agent's trust accounts for: one it took part in,
+    /// or the last one its inactivity was charged through
+    settled: BTreeMap<AgentId, u64>,
+    /// Model each registered agent runs
+    models: BTreeMap<AgentId, ModelSpec>,
+    sink: S,
+}
+
+impl<const SCALE: u64> ScaledTrustManager<(), SCALE> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Manager registering new agents at `priors`
+    pub fn with_priors(priors: TrustPriors) -> Self {
+        Self {
+            priors,
+            ..Self::default()
+        }
+    }
+}
+
+impl TrustManager {
+    /// Manager holding the trusts in `store`. The round count and models
+    /// are not stored: the loaded agents owe no inactivity decay, and are
+    /// selected once [registered](Self::register_agent) again.
+    pub fn load(store: &impl TrustStore) -> Result<Self, ShieldError> {
+        Ok(Self {
+            agents: store.load()?,
+            ..Self::default()
+        })
+    }
+
+    /// Manager holding the valid trusts in `store`, with invalid agents
+    /// quarantined ([`JsonTrustStore::load_lossy`]), and why each was
+    #[cfg(feature = "serde")]
+    pub fn load_lossy(store: &JsonTrustStore) -> Result<(Self, Vec<TrustStateError>), ShieldError> {
+        let (agents, rejected) = store.load_lossy()?;
+        let manager = Self {
+            agents,
+            ..Self::default()
+        };
+        Ok((manager, rejected))
+    }
+}
+
+impl<S: TrustEventSink> TrustManager<S> {
+    /// Write every agent's trust to `store`
+    pub fn save(&self, store: &impl TrustStore) -> Result<(), ShieldError> {
+        store.save(&self.agents)
+    }
+}
+
+/// A rate or trust of the [`Thresholds`], in thousandths, at `SCALE`:
+/// above 1000 it is rejected as the updates at 1000 reject it
+fn scaled<const SCALE: u64>(name: &'static str, value: u64) -> Result<u64, TrustError> {
+    if value > 1000 {
+        return Err(TrustError::OutOfRange { name, value });
+    }
+    Ok(thousandths_at(SCALE, value))
+}
+
+impl<S: TrustEventSink<SCALE>, const SCALE: u64> ScaledTrustManager<S, SCALE> {
+    /// This manager, publishing its updates to `sink` from now on
+    pub fn with_sink<T: TrustEventSink<SCALE>>(self, sink: T) -> ScaledTrustManager<T, SCALE> {
+        ScaledTrustManager {
+            agents: self.agents,
+            priors: self.priors,
+            round: self.round,
+            settled: self.settled,
+            models: self.models,
+            sink,
+        }
+    }
+
+    /// Where updates are published
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Where updates are published, to drain a buffering sink
+    pub fn sink_mut(&mut self) -> &mut S {
+        &mut self.sink
+    }
+
+    /// The agent's trust; `None` until it is inserted or takes part in a
+    /// round. Inactivity decay it owes is not yet applied
+    /// ([`effective`](Self::effective), [`refresh`](Self::refresh)).
+    pub fn get(&self, agent: AgentId) -> Option<&ScaledAgentTrust<SCALE>> {
+        self.agents.get(&agent)
+    }
+
+    /// The agent's trust as [`refresh`](Self::refresh) would leave it: the
+    /// inactivity decay it owes charged and quarantine re-evaluated, without
+    /// committing either. `None` for an unknown agent.
+    pub fn effective(
+        &self,
+        agent: AgentId,
+        thresholds: &Thresholds,
+    ) -> Result<Option<ScaledAgentTrust<SCALE>>, ApplyRoundError> {
+        let Some(mut trust) = self.agents.get(&agent).cloned() else {
+            return Ok(None);
+        };
+        if self.pending_inactivity(agent) > 0 {
+            self.settle(agent, &mut trust, self.round, thresholds)
+                .map_err(|error| ApplyRoundError::Trust { agent, error })?;
+            trust.update_quarantine(thresholds);
+        }
+        Ok(Some(trust))
+    }
+
+    /// Rounds [`apply_round`](Self::apply_round) has applied
+    pub fn round(&self) -> u64 {
+        self.round
+    }
+
+    /// Rounds the agent has sat out whose decay it has not been charged.
+    /// Agents count as active in the round they are loaded, inserted or
+    /// registered in.
+    pub fn pending_inactivity(&self, agent: AgentId) -> u64 {
+        let settled = self.settled.get(&agent).copied().unwrap_or(self.round);
+        self.round.saturating_sub(settled)
+    }
+
+    /// Charge `trust` the inactivity decay it owes through round
+    /// `through`, returning the round it is then settled through
+    fn settle(
+        &self,
+        agent: AgentId,
+        trust: &mut ScaledAgentTrust<SCALE>,
+        through: u64,
+        thresholds: &Thresholds,
+    ) -> Result<u64, TrustError> {
+        let settled = self.settled.get(&agent).copied().unwrap_or(through);
+        trust.decay_for_inactivity(
+            through.saturating_sub(settled),
+            scaled::<SCALE>("decay_rate", thresholds.inactivity_decay_rate)?,
+            scaled::<SCALE>("trust_floor", thresholds.trust_floor)?,
+        )?;
+        Ok(through.max(settled))
+    }
+
+    /// Charge the agent the inactivity decay it owes up to the current
+    /// round and re-evaluate its quarantine. Returns the update, or `None`
+    /// for an unknown agent or one that owes nothing.
+    pub fn refresh(
+        &mut self,
+        agent: AgentId,
+        thresholds: &Thresholds,
+    ) -> Result<Option<TrustDelta<SCALE>>, ApplyRoundError> {
+        if self.pending_inactivity(agent) == 0 {
+            return Ok(None);
+        }
+        let Some(mut trust) = self.agents.get(&agent).cloned() else {
+            return Ok(None);
+        };
+        let old = trust.current();
+        let settled = self
+            .settle(agent, &mut trust, self.round, thresholds)
+            .map_err(|error| ApplyRoundError::Trust { agent, error })?;
+        let quarantined = trust.update_quarantine(thresholds);
+        let delta = TrustDelta {
+            agent,
+            old,
+            new: trust.current(),
+            reason: UpdateReason::Inactive,
+            quarantined,
+        };
+        Ok(self.commit(vec![(trust, delta, settled)]).pop())
+    }
+
+    /// Priors new agents are registered at
+    pub fn priors(&self) -> &TrustPriors {
+        &self.priors
+    }
+
+    /// Replace the priors; agents already known keep their trust
+    pub fn set_priors(&mut self, priors: TrustPriors) {
+        self.priors = priors;
+    }
+
+    /// Start tracking an agent running `model` at its family's prior and
+    /// return its trust. An agent already known keeps its trust:
+    /// registering again records its model but cannot reset a trust it has
+    /// lost.
+    pub fn register_agent(&mut self, agent: AgentId, model: ModelSpec) -> &ScaledAgentTrust<SCALE> {
+        let prior = self.priors.get(model.family);
+        self.models.insert(agent, model);
+        self.settled.entry(agent).or_insert(self.round);
+        self.agents
+            .entry(agent)
+            .or_insert_with(|| prior.scaled_agent())
+    }
+
+    /// Model the agent was [registered](Self::register_agent) with
+    pub fn model(&self, agent: AgentId) -> Option<&ModelSpec> {
+        self.models.get(&agent)
+    }
+
+    /// Set the agent's trust, returning the one it replaces; the agent
+    /// owes no inactivity decay from before
+    pub fn insert(
+        &mut self,
+        agent: AgentId,
+        trust: ScaledAgentTrust<SCALE>,
+    ) -> Option<ScaledAgentTrust<SCALE>> {
+        self.settled.insert(agent, self.round);
+        self.agents.insert(agent, trust)
+    }
+
+    pub fn len(&self) -> usize {
+        self.agents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.agents.is_empty()
+    }
+
+    /// Agents and their [`effective`](Self::effective) trusts for
+    /// selection ([`rank_agents`]): highest trust first, ties by lowest
+    /// agent id
+    pub fn ranked(
+        &self,
+        thresholds: &Thresholds,
+    ) -> Result<Vec<(AgentId, ScaledTrust<SCALE>)>, ApplyRoundError> {
+        let mut agents = Vec::with_capacity(self.agents.len());
+        for &agent in self.agents.keys() {
+            if let Some(trust) = self.effective(agent, thresholds)? {
+                agents.push((agent, trust.current()));
+            }
+        }
+        rank_agents(&mut agents);
+        Ok(agents)
+    }
+
+    /// The agent to select: of the agents within `tolerance` of the
+    /// highest [`effective`](Self::effective) trust
+    /// ([`ScaledTrust::approx_eq`]), so tied within noise, the one with the
+    /// lowest id. `None` without agents.
+    pub fn most_trusted(
+        &self,
+        tolerance: u64,
+        thresholds: &Thresholds,
+    ) -> Result<Option<AgentId>, ApplyRoundError> {
+        let ranked = self.ranked(thresholds)?;
+        let Some(&(_, top)) = ranked.first() else {
+            return Ok(None);
+        };
+        Ok(ranked
+            .iter()
+            .filter(|&&(_, trust)| trust.approx_eq(top, tolerance))
+            .map(|&(agent, _)| agent)
+            .min())
+    }
+
+    /// Agents and their trusts, in agent order
+    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, AgentId, ScaledAgentTrust<SCALE>> {
+        self.agents.iter()
+    }
+
+    /// Every agent's current trust. Inactivity decay agents owe is not
+    /// yet applied.
+    pub fn snapshot(&self) -> TrustSnapshot<SCALE> {
+        self.agents
+            .iter()
+            .map(|(&agent, trust)| (agent, trust.current()))
+            .collect()
+    }
+
+    /// [`apply_round`](Self::apply_round), with what the round changed
+    /// from a [`snapshot`](Self::snapshot) taken before it
+    pub fn apply_round_diff(
+        &mut self,
+        evidence: &RoundEvidence,
+        suspects: &[AgentId],
+        thresholds: &Thresholds,
+    ) -> Result<RoundDiff<SCALE>, ApplyRoundError> {
+        let before = self.snapshot();
+        let deltas = self.apply_round(evidence, suspects, thresholds)?;
+        Ok(RoundDiff {
+            changes: before.diff(&self.snapshot()),
+            deltas,
+        })
+    }
+
+    /// Update every voter in `evidence` and every agent in `suspects`
+    /// from the round, returning one [`TrustDelta`] per agent in agent
+    /// order. Agents not yet known start at full trust. An agent is first
+    /// charged the inactivity decay it owes for earlier rounds, and an
+    /// abstainer sits this round out.
+    ///
+    /// The outcome is rechecked from the evidence rather than taken on
+    /// trust. Either every update applies or, on an error, none does.
+    pub fn apply_round(
+        &mut self,
+        evidence: &RoundEvidence,
+        suspects: &[AgentId],
+        thresholds: &Thresholds,
+    ) -> Result<Vec<TrustDelta<SCALE>>, ApplyRoundError> {
+        let decided = match evidence.recheck()? {
+            ConsensusOutcome::Agreed { value, .. } => Some(value),
+            ConsensusOutcome::Halted { .. } => None,
+        };
+        let suspects: BTreeSet<AgentId> = suspects.iter().copied().collect();
+        let mut ballots: BTreeMap<AgentId, Option<bool>> =
+            suspects.iter().map(|&agent| (agent, None)).collect();
+        ballots.extend(
+            evidence
+                .per_voter
+                .iter()
+                .map(|&(voter, ballot, _)| (voter, ballot)),
+        );
+
+        // Stage every update from the trusts before the round, then commit
+        let round = self.round + 1;
+        let staged = ballots
+            .into_iter()
+            .map(|(agent, ballot)| {
+                let suspected = suspects.contains(&agent);
+                self.stage_update(agent, suspected, ballot, decided, round, thresholds)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.round = round;
+        Ok(self.commit(staged))
+    }
+
+    /// One agent's update in `round`, from its own trust before the round,
+    /// suspicion and ballot alone (`round_update_is_local`): staging takes
+    /// `&self`, so no other agent's update of the round can be seen
+    fn stage_update(
+        &self,
+        agent: AgentId,
+        suspected: bool,
+        ballot: Option<bool>,
+        decided: Option<bool>,
+        round: u64,
+        thresholds: &Thresholds,
+    ) -> Result<(ScaledAgentTrust<SCALE>, TrustDelta<SCALE>, u64), ApplyRoundError> {
+        let mut trust = self.agents.get(&agent).cloned().unwrap_or_default();
+        let old = trust.current();
+        let settled = self
+            .settle(agent, &mut trust, round - 1, thresholds)
+            .map_err(|error| ApplyRoundError::Trust { agent, error })?;
+        let reason = match (suspected, ballot, decided) {
+            (true, _, _) => UpdateReason::Suspected,
+            (false, None, _) => UpdateReason::Abstained,
+            (false, Some(_), None) => UpdateReason::NoConsensus,
+            (false, Some(vote), Some(value)) if vote == value => UpdateReason::Agreed,
+            (false, Some(_), Some(_)) => UpdateReason::Disagreed,
+        };
+        let floor = || scaled::<SCALE>("trust_floor", thresholds.trust_floor);
+        match reason {
+            UpdateReason::Agreed => scaled::<SCALE>("boost_rate", thresholds.boost_rate)
+                .and_then(|rate| trust.boost(rate)),
+            UpdateReason::Disagreed => floor().and_then(|floor| {
+                let rate = scaled::<SCALE>("decay_rate", thresholds.decay_rate)?;
+                trust.decay_floored(rate, floor)
+            }),
+            UpdateReason::Suspected => floor().and_then(|floor| {
+                let rate = scaled::<SCALE>("decay_rate", thresholds.equivocation_decay_rate)?;
+                trust.decay_floored(rate, floor)
+            }),
+            // Observations are recorded by record_observations, and
+            // inactivity charged by settle
+            UpdateReason::Abstained
+            | UpdateReason::NoConsensus
+            | UpdateReason::Observed
+            | UpdateReason::Inactive => Ok(()),
+        }
+        .map_err(|error| ApplyRoundError::Trust { agent, error })?;
+        let quarantined = trust.update_quarantine(thresholds);
+        let settled = match reason {
+            UpdateReason::Abstained => settled,
+            _ => round,
+        };
+        let delta = TrustDelta {
+            agent,
+            old,
+            new: trust.current(),
+            reason,
+            quarantined,
+        };
+        Ok((trust, delta, settled))
+    }
+
+    /// Apply every staged update, then publish their events: a sink
+    /// cannot interrupt a mutation halfway
+    fn commit(
+        &mut self,
+        staged: Vec<(ScaledAgentTrust<SCALE>, TrustDelta<SCALE>, u64)>,
+    ) -> Vec<TrustDelta<SCALE>> {
+        let mut events = Vec::new();
+        let deltas = staged
+            .into_iter()
+            .map(|(trust, delta, settled)| {
+                let was_quarantined = self
+                    .agents
+                    .get(&delta.agent)
+                    .is_some_and(ScaledAgentTrust::is_quarantined);
+                events.extend(TrustEvent::of(&delta, was_quarantined));
+                self.agents.insert(delta.agent, trust);
+                self.settled.insert(delta.agent, settled);
+                delta
+            })
+            .collect();
+        for event in events {
+            self.sink.publish(event);
+        }
+        deltas
+    }
+
+    /// Record one round of evaluator `observations` (agent to observation)
+    /// at `trust_alpha`, each moving its agent's trust at most
+    /// `max_trust_delta` ([`AgentTrust::record_observation_capped`]), and
+    /// return one [`TrustDelta`] per agent in agent order. Agents not yet
+    /// known start at full trust. An observed agent is first charged the
+    /// inactivity decay it owes for earlier rounds, and counts as active in
+    /// the current one. Either every observation applies or, on an error,
+    /// none does.
+    pub fn record_observations(
+        &mut self,
+        observations: &BTreeMap<AgentId, ScaledObservation<SCALE>>,
+        thresholds: &Thresholds,
+    ) -> Result<Vec<TrustDelta<SCALE>>, ApplyRoundError> {
+        let mut staged = Vec::with_capacity(observations.len());
+        for (&agent, &observation) in observations {
+            let mut trust = self.agents.get(&agent).cloned().unwrap_or_default();
+            let old = trust.current();
+            self.settle(agent, &mut trust, self.round.saturating_sub(1), thresholds)
+                .map_err(|error| ApplyRoundError::Trust { agent, error })?;
+            scaled::<SCALE>("max_delta", thresholds.max_trust_delta)
+                .and_then(|max_delta| {
+                    let alpha = scaled::<SCALE>("alpha", thresholds.trust_alpha)?;
+                    trust.record_observation_capped(observation, alpha, max_delta)
+                })
+                .map_err(|error| ApplyRoundError::Trust { agent, error })?;
+            let quarantined = trust.update_quarantine(thresholds);
+            let delta = TrustDelta {
+                agent,
+                old,
+                new: trust.current(),
+                reason: UpdateReason::Observed,
+                quarantined,
+            };
+            staged.push((trust, delta, self.round));
+        }
+        Ok(self.commit(staged))
+    }
+}
+
+impl<'a, S, const SCALE: u64> IntoIterator for &'a ScaledTrustManager<S, SCALE> {
+    type Item = (&'a AgentId, &'a ScaledAgentTrust<SCALE>);
+    type IntoIter = std::collections::btree_map::Iter<'a, AgentId, ScaledAgentTrust<SCALE>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.agents.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::ConsensusEngine;
+    use crate::models::ModelRegistry;
+    use crate::test_support::{evidence, Rng};
+
+    /// `thousandths` at `SCALE`: the manager checks run at 1000 and at
+    /// 10_000, where this is exact
+    fn at<const SCALE: u64>(thousandths: u64) -> u64 {
+        thousandths * SCALE / 1000
+    }
+
+    /// A manager at `SCALE` with agents at the given trusts, in thousandths
+    fn seeded<const SCALE: u64>(trusts: &[(AgentId, u64)]) -> ScaledTrustManager<(), SCALE> {
+        let mut manager = ScaledTrustManager::new();
+        for &(agent, current) in trusts {
+            let trust = ScaledAgentTrust::builder()
+                .current(at::<SCALE>(current))
+                .build()
+                .unwrap();
+            manager.insert(agent, trust);
+        }
+        manager
+    }
+
+    /// A model of `family` weighing 1.0
+    fn model(family: ModelFamily) -> ModelSpec {
+        ModelSpec {
+            id: 100,
+            weight_scaled: 100,
+            family,
+        }
+    }
+
+    /// (new trust, reason, quarantined) per agent
+    fn summary<const SCALE: u64>(
+        deltas: &[TrustDelta<SCALE>],
+    ) -> Vec<(AgentId, u64, UpdateReason, bool)> {
+        deltas
+            .iter()
+            .map(|d| (d.agent, d.new.value(), d.reason, d.quarantined))
+            .collect()
+    }
+
+    /// `agreed` is agent 0's trust after its second boost, from 0.81:
+    /// 0.8195 rounds to the scale
+    fn check_three_round_replay<const SCALE: u64>(agreed: u64) {
+        use UpdateReason::*;
+        let thresholds = Thresholds::default();
+        let mut manager = seeded::<SCALE>(&[(0, 800), (1, 600), (2, 1000)]);
+
+        // Round 1: 3 of 4 agree on true (75%); agent 3 is new
+        let r1 = evidence(&[Some(true), Some(true), Some(false), Some(true)]);
+        let deltas = manager.apply_round(&r1, &[], &thresholds).unwrap();
+        assert_eq!(
+            summary(&deltas),
+            [
+                (0, at::<SCALE>(810), Agreed, false),
+                (1, at::<SCALE>(620), Agreed, false),
+                (2, at::<SCALE>(900), Disagreed, false),
+                (3, SCALE, Agreed, false),
+            ]
+        );
+        assert_eq!(deltas[0].old.value(), at::<SCALE>(800));
+        assert_eq!(deltas[3].old, ScaledTrust::MAX);
+
+        // Round 2: only two of four vote, one each way, and the round
+        // halts without a quorum; agent 2 is suspected
+        let r2 = evidence(&[Some(true), Some(false), None, None]);
+        let deltas = manager.apply_round(&r2, &[2], &thresholds).unwrap();
+        assert_eq!(
+            summary(&deltas),
+            [
+                (0, at::<SCALE>(810), NoConsensus, false),
+                (1, at::<SCALE>(620), NoConsensus, false),
+                (2, at::<SCALE>(450), Suspected, false),
+                (3, SCALE, Abstained, false),
+            ]
+        );
+
+        // Round 3: unanimous, but agent 2 is suspected again and drops
+        // below the detection threshold
+        let r3 = evidence(&[Some(true); 4]);
+        let deltas = manager.apply_round(&r3, &[2], &thresholds).unwrap();
+        assert_eq!(
+            summary(&deltas),
+            [
+                (0, agreed, Agreed, false),
+                (1, at::<SCALE>(639), Agreed, false),
+                (2, at::<SCALE>(225), Suspected, true),
+                (3, SCALE, Agreed, false),
+            ]
+        );
+        assert!(manager.get(2).unwrap().is_quarantined());
+        assert_eq!(manager.len(), 4);
+    }
+
+    #[test]
+    fn test_three_round_replay() {
+        check_three_round_replay::<1000>(819);
+        check_three_round_replay::<10_000>(8195);
+    }
+
+    fn check_suspect_outside_the_round<const SCALE: u64>() {
+        let mut manager = seeded::<SCALE>(&[(7, 60)]);
+        let round = evidence(&[Some(true); 3]);
+        let deltas = manager
+            .apply_round(&round, &[7, 7], &Thresholds::default())
+            .unwrap();
+        assert_eq!(deltas.len(), 4);
+        // Slashed to the floor, not below it
+        assert_eq!(deltas[3].agent, 7);
+        assert_eq!(deltas[3].new.value(), at::<SCALE>(50));
+        assert_eq!(deltas[3].reason, UpdateReason::Suspected);
+    }
+
+    #[test]
+    fn test_suspect_outside_the_round() {
+        check_suspect_outside_the_round::<1000>();
+        check_suspect_outside_the_round::<10_000>();
+    }
+
+    fn check_rejected_round_changes_nothing<const SCALE: u64>() {
+        let mut manager = seeded::<SCALE>(&[(0, 800), (1, 600), (3, 1000)]);
+        let before = manager.clone();
+
+        // Agents 0 to 2 are boosted before agent 3's decay rejects the
+        // rate, which is in thousandths at every scale
+        let thresholds = Thresholds {
+            decay_rate: 1001,
+            ..Thresholds::default()
+        };
+        let round = evidence(&[Some(true), Some(true), Some(true), Some(false)]);
+        let err = manager.apply_round(&round, &[], &thresholds).unwrap_err();
+        assert_eq!(
+            err,
+            ApplyRoundError::Trust {
+                agent: 3,
+                error: TrustError::OutOfRange {
+                    name: "decay_rate",
+                    value: 1001
+                }
+            }
+        );
+        assert_eq!(manager, before);
+
+        let mut tampered = evidence(&[Some(true), Some(true), Some(false)]);
+        tampered.per_voter[2].0 = 0;
+        assert!(matches!(
+            manager.apply_round(&tampered, &[], &Thresholds::default()),
+            Err(ApplyRoundError::Evidence(EvidenceError::DuplicateVoter {
+                voter: 0
+            }))
+        ));
+        assert_eq!(manager, before);
+    }
+
+    #[test]
+    fn test_rejected_round_changes_nothing() {
+        check_rejected_round_changes_nothing::<1000>();
+        check_rejected_round_changes_nothing::<10_000>();
+    }
+
+    fn check_updates_keep_bounds_and_direction<const SCALE: u64>(seed: u64) {
+        // apply_round_preserves_bounds
+        let thresholds = Thresholds::default();
+        let mut rng = Rng(seed);
+        let mut manager = ScaledTrustManager::<(), SCALE>::new();
+        for _ in 0..500 {
+            let n = 1 + rng.below(7) as usize;
+            let ballots: Vec<Option<bool>> = (0..n)
+                .map(|_| match rng.below(3) {
+                    0 => None,
+                    b => Some(b == 1),
+                })
+                .collect();
+            let suspects: Vec<AgentId> = (0..n as AgentId).filter(|_| rng.below(4) == 0).collect();
+            for delta in manager
+                .apply_round(&evidence(&ballots), &suspects, &thresholds)
+                .unwrap()
+            {
+                let (old, new) = (delta.old.value(), delta.new.value());
+                assert!(new <= SCALE, "{:?}", delta);
+                match delta.reason {
+                    UpdateReason::Agreed => assert!(new >= old, "{:?}", delta),
+                    UpdateReason::Disagreed | UpdateReason::Suspected => {
+                        assert!(new <= old, "{:?}", delta)
+                    }
+                    UpdateReason::Abstained | UpdateReason::NoConsensus => {
+                        assert_eq!(new, old, "{:?}", delta)
+                    }
+                    UpdateReason::Observed | UpdateReason::Inactive => {
+                        panic!("{:?} in a round: {:?}", delta.reason, delta)
+                    }
+                }
+                assert_eq!(manager.get(delta.agent).unwrap().current(), delta.new);
+            }
+        }
+    }
+
+    #[test]
+    fn test_updates_keep_bounds_and_direction() {
+        check_updates_keep_bounds_and_direction::<1000>(0x7e57_a9e1);
+        check_updates_keep_bounds_and_direction::<10_000>(0x5eed_1629);
+    }
+
+    fn check_update_order_does_not_matter<const SCALE: u64>(seed: u64) {
+        // round_update_is_local: staging and committing the round's updates
+        // in any agent order gives the same trusts as apply_round
+        let thresholds = Thresholds {
+            inactivity_decay_rate: 50,
+            ..Thresholds::default()
+        };
+        let mut rng = Rng(seed);
+        for _ in 0..100 {
+            let n = 1 + rng.below(9) as usize;
+            let mut manager = ScaledTrustManager::<(), SCALE>::new();
+            for agent in 0..n as AgentId + 2 {
+                let trust = ScaledAgentTrust::builder()
+                    .current(rng.below(SCALE + 1))
+                    .build()
+                    .unwrap();
+                manager.insert(agent, trust);
+            }
+            // Agents sit out earlier rounds, so some owe inactivity decay
+            for _ in 0..rng.below(3) {
+                manager
+                    .apply_round(&evidence(&[Some(true)]), &[], &thresholds)
+                    .unwrap();
+            }
+            let ballots: Vec<Option<bool>> = (0..n)
+                .map(|_| match rng.below(3) {
+                    0 => None,
+                    b => Some(b == 1),
+                })
+                .collect();
+            let round = evidence(&ballots);
+            let suspects: BTreeSet<AgentId> = (0..n as AgentId + 2)
+                .filter(|_| rng.below(4) == 0)
+                .collect();
+            let decided = match round.recheck().unwrap() {
+                ConsensusOutcome::Agreed { value, .. } => Some(value),
+                ConsensusOutcome::Halted { .. } => None,
+            };
+
+            let mut shuffled = manager.clone();
+            let mut agents: Vec<(AgentId, Option<bool>)> = (0..n as AgentId + 2)
+                .map(|agent| (agent, ballots.get(agent as usize).copied().flatten()))
+                .filter(|&(agent, _)| (agent as usize) < n || suspects.contains(&agent))
+                .collect();
+            rng.shuffle(&mut agents);
+            let staged: Vec<_> = agents
+                .iter()
+                .map(|&(agent, ballot)| {
+                    let suspected = suspects.contains(&agent);
+                    let round = shuffled.round + 1;
+                    shuffled
+                        .stage_update(agent, suspected, ballot, decided, round, &thresholds)
+                        .unwrap()
+                })
+                .collect();
+            shuffled.round += 1;
+            shuffled.commit(staged);
+
+            let suspects: Vec<AgentId> = suspects.into_iter().collect();
+            manager.apply_round(&round, &suspects, &thresholds).unwrap();
+            assert_eq!(shuffled.snapshot(), manager.snapshot());
+            assert_eq!(shuffled, manager);
+        }
+    }
+
+    #[test]
+    fn test_update_order_does_not_matter() {
+        check_update_order_does_not_matter::<1000>(0x5eed_1626);
+        check_update_order_does_not_matter::<10_000>(0x5eed_1629);
+    }
+
+    /// Rounds of perfect observations until the agent, from 0.5, reaches
+    /// 0.9
+    fn rounds_to_lift<const SCALE: u64>(thresholds: &Thresholds) -> u64 {
+        let mut manager = seeded::<SCALE>(&[(0, 500)]);
+        let perfect = BTreeMap::from([(0, ScaledObservation::correct())]);
+        for round in 1..=100 {
+            let delta = manager.record_observations(&perfect, thresholds).unwrap()[0];
+            assert_eq!(delta.reason, UpdateReason::Observed);
+            let max_delta = at::<SCALE>(thresholds.max_trust_delta);
+            assert!(delta.new.value() - delta.old.value() <= max_delta);
+            if delta.new.value() >= at::<SCALE>(900) {
+                return round;
+            }
+        }
+        panic!("never lifted");
+    }
+
+    fn check_observations_capped_per_round<const SCALE: u64>() {
+        // capped_sequence_reachability: 0.4 of room at 0.1 a round takes at
+        // least 4 rounds, whatever alpha the evaluator's observations get
+        let thresholds = Thresholds::default();
+        let uncapped = Thresholds {
+            max_trust_delta: 1000,
+            ..thresholds
+        };
+        assert_eq!(rounds_to_lift::<SCALE>(&thresholds), 6);
+        assert_eq!(rounds_to_lift::<SCALE>(&uncapped), 5);
+        let eager = Thresholds {
+            trust_alpha: 1000,
+            ..thresholds
+        };
+        assert_eq!(rounds_to_lift::<SCALE>(&eager), 4);
+        assert_eq!(
+            rounds_to_lift::<SCALE>(&Thresholds {
+                max_trust_delta: 1000,
+                ..eager
+            }),
+            1
+        );
+
+        // A round applies in full or not at all
+        let mut manager = seeded::<SCALE>(&[(0, 500)]);
+        let limit = ScaledAgentTrust::<SCALE>::MAX_OBSERVATIONS;
+        let full = ScaledAgentTrust::builder()
+            .observations(limit)
+            .cumulative_correct(limit * at::<SCALE>(500))
+            .build()
+            .unwrap();
+        manager.insert(1, full);
+        let before = manager.clone();
+        let both = BTreeMap::from([
+            (0, ScaledObservation::correct()),
+            (1, ScaledObservation::correct()),
+        ]);
+        let err = manager.record_observations(&both, &thresholds).unwrap_err();
+        assert_eq!(
+            err,
+            ApplyRoundError::Trust {
+                agent: 1,
+                error: TrustError::ObservationLimit
+            }
+        );
+        assert_eq!(manager, before);
+
+        // Unknown agents start at full trust; a bad observation costs at
+        // most the cap too
+        let deltas = manager
+            .record_observations(
+                &BTreeMap::from([(2, ScaledObservation::incorrect())]),
+                &thresholds,
+            )
+            .unwrap();
+        assert_eq!(
+            summary(&deltas),
+            [(2, at::<SCALE>(900), UpdateReason::Observed, false)]
+        );
+    }
+
+    #[test]
+    fn test_observations_capped_per_round() {
+        check_observations_capped_per_round::<1000>();
+        check_observations_capped_per_round::<10_000>();
+    }
+
+    fn check_most_trusted_breaks_ties_by_id<const SCALE: u64>() {
+        let thresholds = Thresholds::default();
+        let manager = seeded::<SCALE>(&[(7, 900), (3, 880), (5, 900), (1, 600)]);
+        assert_eq!(
+            manager
+                .ranked(&thresholds)
+                .unwrap()
+                .iter()
+                .map(|&(agent, trust)| (agent, trust.value()))
+                .collect::<Vec<_>>(),
+            [
+                (5, at::<SCALE>(900)),
+                (7, at::<SCALE>(900)),
+                (3, at::<SCALE>(880)),
+                (1, at::<SCALE>(600))
+            ]
+        );
+        let most_trusted = |tolerance| manager.most_trusted(tolerance, &thresholds).unwrap();
+        assert_eq!(most_trusted(0), Some(5));
+        // 0.88 is within 0.02 of the top: a tie, won by the lowest id
+        assert_eq!(most_trusted(at::<SCALE>(20) - 1), Some(5));
+        assert_eq!(most_trusted(at::<SCALE>(20)), Some(3));
+        assert_eq!(most_trusted(SCALE), Some(1));
+        assert_eq!(
+            ScaledTrustManager::<(), SCALE>::new().most_trusted(0, &thresholds),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_most_trusted_breaks_ties_by_id() {
+        check_most_trusted_breaks_ties_by_id::<1000>();
+        check_most_trusted_breaks_ties_by_id::<10_000>();
+    }
+
+    fn check_reads_charge_owed_inactivity<const SCALE: u64>() {
+        let thresholds = Thresholds {
+            inactivity_decay_rate: 100,
+            ..Thresholds::default()
+        };
+        let mut manager = seeded::<SCALE>(&[(0, 900), (1, 1000)]);
+        // Agent 1 sits three rounds out while agent 0 keeps voting
+        for _ in 0..3 {
+            manager
+                .apply_round(&evidence(&[Some(true)]), &[], &thresholds)
+                .unwrap();
+        }
+        assert_eq!(manager.get(1).unwrap().current().value(), SCALE);
+        assert_eq!(
+            manager
+                .effective(1, &thresholds)
+                .unwrap()
+                .unwrap()
+                .current()
+                .value(),
+            at::<SCALE>(729)
+        );
+        assert_eq!(manager.effective(9, &thresholds), Ok(None));
+        assert_eq!(
+            manager.ranked(&thresholds).unwrap()[0].0,
+            0,
+            "an idle agent's stale trust must not rank first"
+        );
+        assert_eq!(manager.most_trusted(0, &thresholds), Ok(Some(0)));
+
+        // Reading charges nothing; refreshing commits what was read
+        assert_eq!(manager.pending_inactivity(1), 3);
+        let effective = manager.effective(1, &thresholds).unwrap();
+        manager.refresh(1, &thresholds).unwrap();
+        assert_eq!(manager.get(1).cloned(), effective);
+        assert_eq!(manager.effective(1, &thresholds).unwrap(), effective);
+    }
+
+    #[test]
+    fn test_reads_charge_owed_inactivity() {
+        check_reads_charge_owed_inactivity::<1000>();
+        check_reads_charge_owed_inactivity::<10_000>();
+    }
+
+    fn check_register_agent_at_family_prior<const SCALE: u64>() {
+        // Priors are in thousandths at every scale
+        let mut priors = TrustPriors::default();
+        let gpt4 = TrustPrior::new(800, 20).unwrap();
+        assert_eq!(priors.insert(ModelFamily::Gpt4, gpt4), None);
+        let mut manager = ScaledTrustManager::<(), SCALE>::with_priors(priors);
+
+        let gpt4o = ModelRegistry::builtin().spec(2);
+        let trust = manager.register_agent(0, gpt4o).clone();
+        assert_eq!(manager.model(0), Some(&gpt4o));
+        assert_eq!(trust.current().value(), at::<SCALE>(800));
+        assert_eq!(trust.observations(), 20);
+        assert_eq!(trust.cumulative_correct(), at::<SCALE>(16_000));
+        assert_eq!(trust.recent().len(), 0);
+
+        // Unknown and unlisted families fall back to the neutral prior
+        for (agent, family) in [(1, ModelFamily::Unknown), (2, ModelFamily::Llama)] {
+            let trust = manager.register_agent(agent, model(family));
+            assert_eq!(trust.current().value(), at::<SCALE>(500));
+            assert_eq!(trust.observations(), 0);
+        }
+        assert_eq!(
+            manager.priors().get(ModelFamily::Unknown),
+            TrustPrior::NEUTRAL
+        );
+
+        // Registering again does not reset a lost trust
+        let bad = BTreeMap::from([(0, ScaledObservation::incorrect())]);
+        manager
+            .record_observations(&bad, &Thresholds::default())
+            .unwrap();
+        let lost = manager.get(0).unwrap().current();
+        assert_eq!(lost.value(), at::<SCALE>(700));
+        assert_eq!(manager.register_agent(0, gpt4o).current(), lost);
+        // ... but records a changed model
+        let gpt4_turbo = ModelRegistry::builtin().spec(3);
+        manager.register_agent(0, gpt4_turbo);
+        assert_eq!(manager.model(0), Some(&gpt4_turbo));
+        assert_eq!(manager.get(0).unwrap().current(), lost);
+        assert_eq!(manager.model(9), None);
+
+        // A prior's pseudo-observations stop at the scale's limit
+        let limit = ScaledAgentTrust::<SCALE>::MAX_OBSERVATIONS;
+        let full = TrustPrior::new(1000, MAX_OBSERVATIONS)
+            .unwrap()
+            .scaled_agent::<SCALE>();
+        assert_eq!(full.observations(), limit.min(MAX_OBSERVATIONS));
+        assert_eq!(full.cumulative_correct(), full.observations() * SCALE);
+    }
+
+    #[test]
+    fn test_register_agent_at_family_prior() {
+        check_register_agent_at_family_prior::<1000>();
+        check_register_agent_at_family_prior::<10_000>();
+
+        assert_eq!(
+            TrustPrior::new(1001, 0).unwrap_err().to_string(),
+            "trust = 1001 is outside 0..=1000"
+        );
+        assert_eq!(
+            TrustPrior::new(1000, MAX_OBSERVATIONS + 1),
+            Err(TrustError::ObservationLimit)
+        );
+        let full = TrustPrior::new(1000, MAX_OBSERVATIONS).unwrap().agent();
+        assert_eq!(full.cumulative_correct(), MAX_OBSERVATIONS * 1000);
+    }
+
+    fn check_priors_wash_out<const SCALE: u64>(seed: u64) {
+        // prior_gap_converges: agents registered at 0.9 and 0.1 that see
+        // the same observations end up within the bound g(k+1) =
+        // (1 - alpha) g(k) + 1 of each other
+        let thresholds = Thresholds {
+            max_trust_delta: 1000,
+            ..Thresholds::default()
+        };
+        let alpha = at::<SCALE>(thresholds.trust_alpha);
+        let mut priors = TrustPriors::default();
+        priors.insert(ModelFamily::Claude, TrustPrior::new(900, 50).unwrap());
+        priors.insert(ModelFamily::Llama, TrustPrior::new(100, 5).unwrap());
+        let mut manager = ScaledTrustManager::<(), SCALE>::with_priors(priors);
+        manager.register_agent(0, model(ModelFamily::Claude));
+        manager.register_agent(1, model(ModelFamily::Llama));
+
+        let mut rng = Rng(seed);
+        let mut bound = at::<SCALE>(800);
+        for round in 1..=60 {
+            let observation = ScaledObservation::partial(rng.below(SCALE + 1)).unwrap();
+            let observations = BTreeMap::from([(0, observation), (1, observation)]);
+            manager
+                .record_observations(&observations, &thresholds)
+                .unwrap();
+            bound = (SCALE - alpha) * bound / SCALE + 1;
+            let gap = manager
+                .get(0)
+                .unwrap()
+                .current()
+                .value()
+                .abs_diff(manager.get(1).unwrap().current().value());
+            assert!(
+                gap <= bound,
+                "round {}: gap {} > bound {}",
+                round,
+                gap,
+                bound
+            );
+        }
+        // The bound settles at the rounding floor, about 1 / alpha units
+        assert_eq!(bound, 3);
+    }
+
+    #[test]
+    fn test_priors_wash_out() {
+        check_priors_wash_out::<1000>(0x5eed_1619);
+        check_priors_wash_out::<10_000>(0x5eed_1629);
+    }
+
+    /// `idle` is agent 2's trust after three rounds out from 0.8, and
+    /// `charged` after a fourth: 0.5832 and 0.52488 round to the scale
+    fn check_inactivity_decays_lazily<const SCALE: u64>(idle: u64, charged: u64) {
+        let thresholds = Thresholds {
+            inactivity_decay_rate: 100,
+            ..Thresholds::default()
+        };
+        let mut manager = seeded::<SCALE>(&[(0, 800), (1, 800), (2, 800)]);
+        assert_eq!(manager.refresh(2, &thresholds).unwrap(), None);
+
+        // Agent 2 sits three rounds out; nothing is charged until it is
+        // refreshed
+        let active = evidence(&[Some(true), Some(true)]);
+        for _ in 0..3 {
+            manager.apply_round(&active, &[], &thresholds).unwrap();
+        }
+        assert_eq!(manager.round(), 3);
+        assert_eq!(manager.pending_inactivity(0), 0);
+        assert_eq!(manager.pending_inactivity(2), 3);
+        assert_eq!(manager.get(2).unwrap().current().value(), at::<SCALE>(800));
+
+        let delta = manager.refresh(2, &thresholds).unwrap().unwrap();
+        assert_eq!(
+            (delta.old.value(), delta.new.value(), delta.reason),
+            (at::<SCALE>(800), idle, UpdateReason::Inactive)
+        );
+        assert_eq!(manager.pending_inactivity(2), 0);
+        assert_eq!(manager.refresh(2, &thresholds).unwrap(), None);
+
+        // An abstention is a round sat out, charged once when the agent
+        // votes again
+        let abstains = evidence(&[Some(true), Some(true), None]);
+        let deltas = manager.apply_round(&abstains, &[], &thresholds).unwrap();
+        assert_eq!(deltas[2].reason, UpdateReason::Abstained);
+        assert_eq!(deltas[2].new.value(), idle);
+        assert_eq!(manager.pending_inactivity(2), 1);
+        let votes = evidence(&[Some(true), Some(true), Some(true)]);
+        let deltas = manager.apply_round(&votes, &[], &thresholds).unwrap();
+        let mut expected = ScaledAgentTrust::<SCALE>::builder()
+            .current(idle)
+            .build()
+            .unwrap();
+        expected
+            .decay_for_inactivity(1, at::<SCALE>(100), at::<SCALE>(50))
+            .unwrap();
+        assert_eq!(expected.current().value(), charged);
+        assert_eq!(deltas[2].old.value(), idle);
+        assert!(deltas[2].new > expected.current(), "{:?}", deltas[2]);
+        assert_eq!(manager.pending_inactivity(2), 0);
+
+        // Without a rate, sitting out costs nothing
+        let mut manager = seeded::<SCALE>(&[(0, 800), (1, 800), (2, 800)]);
+        manager
+            .apply_round(&active, &[], &Thresholds::default())
+            .unwrap();
+        let delta = manager.refresh(2, &Thresholds::default()).unwrap().unwrap();
+        assert_eq!(delta.old, delta.new);
+    }
+
+    #[test]
+    fn test_inactivity_decays_lazily() {
+        check_inactivity_decays_lazily::<1000>(583, 524);
+        check_inactivity_decays_lazily::<10_000>(5832, 5248);
+    }
+
+    fn check_inactivity_settles_at_floor_and_quarantines<const SCALE: u64>() {
+        let thresholds = Thresholds {
+            inactivity_decay_rate: 100,
+            ..Thresholds::default()
+        };
+        let mut manager = seeded::<SCALE>(&[(0, 800), (1, 800), (2, 1000)]);
+        let active = evidence(&[Some(true), Some(true)]);
+        for _ in 0..500 {
+            manager.apply_round(&active, &[], &thresholds).unwrap();
+        }
+        let delta = manager.refresh(2, &thresholds).unwrap().unwrap();
+        assert_eq!(delta.new.value(), at::<SCALE>(thresholds.trust_floor));
+        assert!(delta.quarantined);
+        assert!(manager.get(2).unwrap().is_quarantined());
+
+        // A zero rate is inert and a rate past the scale is refused
+        let floor = at::<SCALE>(50);
+        let mut trust = ScaledAgentTrust::<SCALE>::builder()
+            .current(at::<SCALE>(700))
+            .build()
+            .unwrap();
+        trust.decay_for_inactivity(u64::MAX, 0, floor).unwrap();
+        trust.decay_for_inactivity(0, SCALE, floor).unwrap();
+        assert_eq!(trust.current().value(), at::<SCALE>(700));
+        assert!(trust.decay_for_inactivity(1, SCALE + 1, floor).is_err());
+    }
+
+    #[test]
+    fn test_inactivity_settles_at_floor_and_quarantines() {
+        check_inactivity_settles_at_floor_and_quarantines::<1000>();
+        check_inactivity_settles_at_floor_and_quarantines::<10_000>();
+    }
+
+    /// A snapshot at `SCALE` of the given trusts, in thousandths
+    fn snapshot<const SCALE: u64>(trusts: &[(AgentId, u64)]) -> TrustSnapshot<SCALE> {
+        trusts
+            .iter()
+            .map(|&(agent, trust)| (agent, ScaledTrust::new(at::<SCALE>(trust)).unwrap()))
+            .collect()
+    }
+
+    fn check_snapshot_diff<const SCALE: u64>() {
+        let before = snapshot::<SCALE>(&[(0, 800), (1, 600), (2, 400), (3, 1000)]);
+        let after = snapshot::<SCALE>(&[(0, 810), (1, 600), (3, 0), (4, 500)]);
+        let units = |thousandths: i64| thousandths * (SCALE / 1000) as i64;
+        let changes: Vec<_> = before
+            .diff(&after)
+            .iter()
+            .map(|c| {
+                (
+                    c.agent,
+                    c.before.map(|t| t.value()),
+                    c.after.map(|t| t.value()),
+                    c.delta,
+                )
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                (0, Some(at::<SCALE>(800)), Some(at::<SCALE>(810)), units(10)),
+                (2, Some(at::<SCALE>(400)), None, units(-400)),
+                (3, Some(SCALE), Some(0), units(-1000)),
+                (4, None, Some(at::<SCALE>(500)), units(500)),
+            ]
+        );
+        let diff = before.diff(&after);
+        assert!(diff[1].is_removed() && diff[3].is_added());
+        assert!(before.diff(&before).is_empty());
+
+        // The filter drops small moves but never an added or removed agent
+        let large: Vec<_> = before
+            .diff_at_least(&after, at::<SCALE>(10) + 1)
+            .iter()
+            .map(|c| c.agent)
+            .collect();
+        assert_eq!(large, [2, 3, 4]);
+        let zero = snapshot::<SCALE>(&[(5, 0)]);
+        assert_eq!(
+            TrustSnapshot::<SCALE>::default()
+                .diff_at_least(&zero, SCALE)
+                .len(),
+            1
+        );
+        // Reversed, every delta flips sign
+        for (forward, back) in before.diff(&after).iter().zip(after.diff(&before)) {
+            assert_eq!(forward.delta, -back.delta);
+        }
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        check_snapshot_diff::<1000>();
+        check_snapshot_diff::<10_000>();
+    }
+
+    fn check_apply_round_diff<const SCALE: u64>() {
+        let thresholds = Thresholds::default();
+        let mut manager = seeded::<SCALE>(&[(0, 800), (1, 1000), (2, 600)]);
+        let round = evidence(&[Some(true), Some(true), None, Some(true)]);
+        let before = manager.snapshot();
+        let diff = manager.apply_round_diff(&round, &[], &thresholds).unwrap();
+        assert_eq!(diff.deltas.len(), 4);
+        // Agent 1 was already at full trust and agent 2 abstained
+        assert_eq!(
+            diff.changes
+                .iter()
+                .map(|c| (c.agent, c.delta, c.is_added()))
+                .collect::<Vec<_>>(),
+            [(0, at::<SCALE>(10) as i64, false), (3, SCALE as i64, true)]
+        );
+        assert_eq!(diff.changes, before.diff(&manager.snapshot()));
+        assert_eq!(manager.snapshot().get(3), Some(ScaledTrust::MAX));
+    }
+
+    #[test]
+    fn test_apply_round_diff() {
+        check_apply_round_diff::<1000>();
+        check_apply_round_diff::<10_000>();
+    }
+
+    /// A trust at `SCALE`, in units of the scale
+    fn score<const SCALE: u64>(value: u64) -> ScaledTrust<SCALE> {
+        ScaledTrust::new(value).unwrap()
+    }
+
+    /// The rounds of `test_three_round_replay`, then two observations:
+    /// agent 2 is decayed, slashed twice into quarantine, then observed
+    /// back out of it
+    fn scripted<const SCALE: u64>(
+        manager: &mut ScaledTrustManager<impl TrustEventSink<SCALE>, SCALE>,
+    ) {
+        let thresholds = Thresholds::default();
+        let rounds = [
+            (
+                evidence(&[Some(true), Some(true), Some(false), Some(true)]),
+                vec![],
+            ),
+            (evidence(&[Some(true), Some(false), None, None]), vec![2]),
+            (evidence(&[Some(true); 4]), vec![2]),
+        ];
+        for (round, suspects) in &rounds {
+            manager.apply_round(round, suspects, &thresholds).unwrap();
+        }
+        let observed = BTreeMap::from([(2, ScaledObservation::correct())]);
+        for _ in 0..2 {
+            manager.record_observations(&observed, &thresholds).unwrap();
+        }
+    }
+
+    /// `agreed` as in `check_three_round_replay`
+    fn check_event_sequence<const SCALE: u64>(agreed: u64) {
+        use TrustEvent::*;
+        use UpdateReason::{Agreed, Disagreed, Observed};
+        // Trusts in thousandths, but for `agreed`
+        let boost = |agent, old, new, reason| BoostApplied {
+            agent,
+            old: score::<SCALE>(old),
+            new: score(new),
+            reason,
+        };
+        let penalty = |agent, old, new| EquivocationPenalty {
+            agent,
+            old: score::<SCALE>(at::<SCALE>(old)),
+            new: score(at::<SCALE>(new)),
+        };
+        let boosted =
+            |agent, old, new, reason| boost(agent, at::<SCALE>(old), at::<SCALE>(new), reason);
+
+        let mut manager = seeded::<SCALE>(&[(0, 800), (1, 600), (2, 1000)]).with_sink(Vec::new());
+        scripted(&mut manager);
+        assert_eq!(
+            manager.sink(),
+            &[
+                // Round 1: agent 2 disagrees; new agent 3 is boosted,
+                // though already at full trust
+                boosted(0, 800, 810, Agreed),
+                boosted(1, 600, 620, Agreed),
+                DecayApplied {
+                    agent: 2,
+                    old: score(SCALE),
+                    new: score(at::<SCALE>(900)),
+                    reason: Disagreed,
+                },
+                boosted(3, 1000, 1000, Agreed),
+                // Round 2 halts: only the suspect changes
+                penalty(2, 900, 450),
+                // Round 3: the second slash quarantines agent 2
+                boost(0, at::<SCALE>(810), agreed, Agreed),
+                boosted(1, 620, 639, Agreed),
+                penalty(2, 450, 225),
+                Quarantined {
+                    agent: 2,
+                    trust: score(at::<SCALE>(225)),
+                },
+                boosted(3, 1000, 1000, Agreed),
+                // Capped at max_trust_delta; out above 400, the threshold
+                // plus the margin
+                boosted(2, 225, 325, Observed),
+                boosted(2, 325, 425, Observed),
+                Recovered {
+                    agent: 2,
+                    trust: score(at::<SCALE>(425)),
+                },
+            ]
+        );
+        assert_eq!(
+            manager
+                .sink()
+                .iter()
+                .filter(|event| event.agent() == 2)
+                .count(),
+            7
+        );
+
+        // Draining leaves the manager publishing to an empty buffer
+        manager.sink_mut().clear();
+        manager
+            .apply_round(&evidence(&[None; 4]), &[], &Thresholds::default())
+            .unwrap();
+        assert!(manager.sink().is_empty());
+    }
+
+    #[test]
+    fn test_event_sequence() {
+        check_event_sequence::<1000>(819);
+        check_event_sequence::<10_000>(8195);
+    }
+
+    fn check_channel_sink<const SCALE: u64>() {
+        let (sender, receiver) = mpsc::channel();
+        let mut manager = seeded::<SCALE>(&[(0, 800), (1, 600), (2, 1000)]).with_sink(sender);
+        let consumer = std::thread::spawn(move || receiver.iter().collect::<Vec<_>>());
+        scripted(&mut manager);
+
+        let mut buffered = seeded::<SCALE>(&[(0, 800), (1, 600), (2, 1000)]).with_sink(Vec::new());
+        scripted(&mut buffered);
+        // Dropping the manager closes the channel
+        drop(manager);
+        assert_eq!(&consumer.join().unwrap(), buffered.sink());
+    }
+
+    #[test]
+    fn test_channel_sink() {
+        check_channel_sink::<1000>();
+        check_channel_sink::<10_000>();
+    }
+
+    struct Panicking;
+
+    impl<const SCALE: u64> TrustEventSink<SCALE> for Panicking {
+        fn publish(&mut self, event: TrustEvent<SCALE>) {
+            panic!("sink rejected {:?}", event);
+        }
+    }
+
+    fn check_panicking_sink_leaves_state_consistent<const SCALE: u64>() {
+        let round = evidence(&[Some(true), Some(false), Some(true)]);
+        let thresholds = Thresholds::default();
+        let mut manager = seeded::<SCALE>(&[(0, 800), (1, 600)]).with_sink(Panicking);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            manager.apply_round(&round, &[1], &thresholds)
+        }));
+        assert!(result.is_err());
+
+        // The round applied in full before the first event was published
+        let mut expected = seeded::<SCALE>(&[(0, 800), (1, 600)]);
+        expected.apply_round(&round, &[1], &thresholds).unwrap();
+        assert_eq!(manager.snapshot(), expected.snapshot());
+        assert_eq!(manager.round(), expected.round());
+        assert_eq!(manager.pending_inactivity(2), 0);
+    }
+
+    #[test]
+    fn test_panicking_sink_leaves_state_consistent() {
+        check_panicking_sink_leaves_state_consistent::<1000>();
+        check_panicking_sink_leaves_state_consistent::<10_000>();
+    }
+
+    #[cfg(feature = "serde")]
+    fn check_snapshot_serde<const SCALE: u64>() {
+        let before = snapshot::<SCALE>(&[(0, 800), (7, 90)]);
+        let json = serde_json::to_string(&before).unwrap();
+        assert_eq!(
+            json,
+            format!(r#"{{"0":{},"7":{}}}"#, at::<SCALE>(800), at::<SCALE>(90))
+        );
+        assert_eq!(
+            serde_json::from_str::<TrustSnapshot<SCALE>>(&json).unwrap(),
+            before
+        );
+        let over = format!(r#"{{"0":{}}}"#, SCALE + 1);
+        assert!(serde_json::from_str::<TrustSnapshot<SCALE>>(&over).is_err());
+
+        let changes = before.diff(&snapshot(&[(0, 790)]));
+        let json = serde_json::to_string(&changes).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"[{{"agent":0,"before":{},"after":{},"delta":-{}}},{{"agent":7,"before":{},"after":null,"delta":-{}}}]"#,
+                at::<SCALE>(800),
+                at::<SCALE>(790),
+                at::<SCALE>(10),
+                at::<SCALE>(90),
+                at::<SCALE>(90)
+            )
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<TrustChange<SCALE>>>(&json).unwrap(),
+            changes
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snapshot_serde() {
+        check_snapshot_serde::<1000>();
+        check_snapshot_serde::<10_000>();
+    }
+
+    #[cfg(feature = "serde")]
+    fn fixture(name: &str) -> JsonTrustStore {
+        JsonTrustStore::new(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("src/fixtures/trust_state")
+                .join(name),
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    fn state_error(err: ShieldError) -> TrustStateError {
+        match err {
+            ShieldError::InvalidTrustState { error, .. } => error,
+            other => panic!("expected invalid trust state, got {}", other),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_store_round_trip() {
+        let path = std::env::temp_dir().join("aevion-trust-store.json");
+        let _ = fs::remove_file(&path);
+        let store = JsonTrustStore::new(&path);
+        assert_eq!(TrustManager::load(&store).unwrap(), TrustManager::new());
+
+        let mut manager = seeded::<1000>(&[(0, 800), (1, 600), (12, 90)]);
+        let round = evidence(&[Some(true), Some(false), None]);
+        manager
+            .apply_round(&round, &[1], &Thresholds::default())
+            .unwrap();
+        manager.save(&store).unwrap();
+        assert_eq!(TrustManager::load(&store).unwrap().agents, manager.agents);
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(
+            text.starts_with("{\n  \"version\": 1,\n  \"digest\": \""),
+            "{}",
+            text
+        );
+
+        // A hand edit, even to a valid value, breaks the digest
+        fs::write(
+            &path,
+            text.replacen("\"current\": 800", "\"current\": 900", 1),
+        )
+        .unwrap();
+        let err = state_error(TrustManager::load(&store).unwrap_err());
+        assert!(
+            matches!(err, TrustStateError::DigestMismatch { .. }),
+            "{}",
+            err
+        );
+
+        fs::write(&path, text.replacen("\"version\": 1", "\"version\": 2", 1)).unwrap();
+        let err = TrustManager::load(&store).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "trust state {}: version 2 is not supported (expected 1)",
+                path.display()
+            )
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_load_valid_fixture() {
+        let store = fixture("valid.json");
+        let manager = TrustManager::load(&store).unwrap();
+        assert_eq!(manager.len(), 3);
+        let agent = manager.get(0).unwrap();
+        assert_eq!(agent.current().value(), 860);
+        assert_eq!(
+            (agent.observations(), agent.cumulative_correct()),
+            (2, 1720)
+        );
+        assert!(manager.get(7).unwrap().is_quarantined());
+
+        // Nothing to quarantine
+        let (lossy, rejected) = TrustManager::load_lossy(&store).unwrap();
+        assert_eq!(lossy, manager);
+        assert!(rejected.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_load_rejects_invalid_agents() {
+        let err = TrustManager::load(&fixture("out_of_range.json")).unwrap_err();
+        assert!(
+            err.to_string()
+                .ends_with("out_of_range.json: agent 3: trust = 1200 is outside 0..=1000"),
+            "{}",
+            err
+        );
+        assert_eq!(
+            state_error(err),
+            TrustStateError::InvalidAgent {
+                agent: 3,
+                error: TrustError::OutOfRange {
+                    name: "trust",
+                    value: 1200
+                }
+            }
+        );
+
+        let err = TrustManager::load(&fixture("inconsistent.json")).unwrap_err();
+        assert_eq!(
+            state_error(err),
+            TrustStateError::InvalidAgent {
+                agent: 5,
+                error: TrustError::InconsistentHistory {
+                    observations: 1,
+                    cumulative_correct: 2000
+                }
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_load_lossy_quarantines_invalid_agents() {
+        let (manager, rejected) = TrustManager::load_lossy(&fixture("out_of_range.json")).unwrap();
+        assert_eq!(manager.len(), 3);
+        assert_eq!(manager.get(0).unwrap().current().value(), 860);
+        let agent = manager.get(3).unwrap();
+        assert_eq!(agent.current().value(), 0);
+        assert!(agent.is_quarantined());
+        assert!(matches!(
+            rejected.as_slice(),
+            [TrustStateError::InvalidAgent { agent: 3, .. }]
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_load_rejects_truncated_file() {
+        let store = fixture("truncated.json");
+        for err in [
+            TrustManager::load(&store).unwrap_err(),
+            TrustManager::load_lossy(&store).unwrap_err(),
+        ] {
+            assert!(
+                matches!(state_error(err), TrustStateError::Malformed { .. }),
+                "truncated file loaded"
+            );
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! # Unit Test Stage
+//!
+//! Runs the standard `cargo test` suite and extracts libtest results.
+//!
+//! The documented workflow is Verus + Prusti + `cargo test`; this stage covers
+//! the third step so executable tests count toward the verification summary.
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::error::ShieldError;
+use crate::stage::{self, CommandRunner, StageOutput, SystemRunner};
+use crate::VerificationStatus;
+
+/// Configuration for the `cargo test` stage
+#[derive(Debug, Clone)]
+pub struct UnitTestConfig {
+    /// Directory `cargo test` is invoked from
+    pub path: PathBuf,
+    /// Restrict to a single package (default: `--all`)
+    pub package: Option<String>,
+    /// Override the cargo target directory
+    pub target_dir: Option<PathBuf>,
+    /// Stage timeout
+    pub timeout: Duration,
+}
+
+impl Default for UnitTestConfig {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("."),
+            package: None,
+            target_dir: None,
+            timeout: stage::DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+/// Counts parsed from libtest summary lines, summed over all test binaries
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UnitTestResults {
+    pub passed: u64,
+    pub failed: u64,
+    pub ignored: u64,
+    /// Fully qualified names of failing tests
+    pub failed_tests: Vec<String>,
+    /// Stage exceeded its timeout
+    pub timed_out: bool,
+}
+
+impl UnitTestResults {
+    /// Parse `cargo test` output.
+    ///
+    /// Returns None if no libtest summary line was found (e.g. the crate
+    /// failed to compile), which the stage reports as a failure.
+    pub fn parse(output: &str) -> Option<Self> {
+        let mut results = Self::default();
+        let mut found_summary = false;
+
+        for line in output.lines() {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("test result: ") {
+                found_summary = true;
+                results.passed += summary_count(rest, "passed");
+                results.failed += summary_count(rest, "failed");
+                results.ignored += summary_count(rest, "ignored");
+            } else if let Some(name) = failed_test_name(line) {
+                if !results.failed_tests.iter().any(|t| t == name) {
+                    results.failed_tests.push(name.to_string());
+                }
+            }
+        }
+
+        found_summary.then_some(results)
+    }
+
+    /// Stage status derived from the counts
+    pub fn status(&self) -> VerificationStatus {
+        if self.timed_out || self.failed > 0 {
+            VerificationStatus::Failed
+        } else {
+            VerificationStatus::Verified
+        }
+    }
+}
+
+/// Extract `<n>` from a `<n> <label>` item of a libtest summary line
+fn summary_count(summary: &str, label: &str) -> u64 {
+    summary
+        .split([';', '.'])
+        .filter_map(|item| item.trim().strip_suffix(label))
+        .filter_map(|count| count.trim().parse::<u64>().ok())
+        .sum()
+}
+
+/// Match `test <name> ... FAILED`
+fn failed_test_name(line: &str) -> Option<&str> {
+    line.strip_prefix("test ")?
+        .strip_suffix(" ... FAILED")
+        .map(str::trim)
+}
+
+/// Build the `cargo test` invocation for a config
+pub fn cargo_test_command(config: &UnitTestConfig) -> Command {
+    let mut command = Command::new("cargo");
+    command.arg("test").current_dir(&config.path);
+    match &config.package {
+        Some(package) => command.args(["--package", package]),
+        None => command.arg("--all"),
+    };
+    // Keep going after the first failing test binary so every failure is listed
+    command.arg("--no-fail-fast");
+    if let Some(target_dir) = &config.target_dir {
+        command.env("CARGO_TARGET_DIR", target_dir);
+    }
+    command
+}
+
+/// Run the unit test stage.
+///
+/// Returns the stage status together with the parsed results (None when the
+/// output contained no libtest summary). Errors only if cargo could not be
+/// spawned (`ToolNotFound` when it is not installed).
+pub fn run_unit_tests(
+    config: &UnitTestConfig,
+) -> Result<(VerificationStatus, Option<UnitTestResults>), ShieldError> {
+    run_unit_tests_with(config, &SystemRunner)
+}
+
+/// [`run_unit_tests`] with `cargo` executed by `runner`
+pub fn run_unit_tests_with(
+    config: &UnitTestConfig,
+    runner: &dyn CommandRunner,
+) -> Result<(VerificationStatus, Option<UnitTestResults>), ShieldError> {
+    let output = runner.run(&mut cargo_test_command(config), config.timeout)?;
+    Ok(evaluate(&output))
+}
+
+fn evaluate(output: &StageOutput) -> (VerificationStatus, Option<UnitTestResults>) {
+    match UnitTestResults::parse(&output.combined()) {
+        Some(mut results) => {
+            results.timed_out = output.timed_out;
+            let status = if output.success {
+                results.status()
+            } else {
+                VerificationStatus::Failed
+            };
+            (status, Some(results))
+        }
+        None if output.timed_out => {
+            let results = UnitTestResults {
+                timed_out: true,
+                ..UnitTestResults::default()
+            };
+            (VerificationStatus::Failed, Some(results))
+        }
+        None => (VerificationStatus::Failed, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const LIBTEST_OUTPUT: &str = "\
+running 3 tests
+test tests::passes ... ok
+test tests::ignored ... ignored
+test tests::fails ... FAILED
+
+failures:
+
+---- tests::fails stdout ----
+assertion failed
+
+failures:
+    tests::fails
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+running 2 tests
+test doc_a ... ok
+test doc_b ... ok
+
+test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s
+";
+
+    #[test]
+    fn test_parse_sums_binaries() {
+        let results = UnitTestResults::parse(LIBTEST_OUTPUT).unwrap();
+        assert_eq!(results.passed, 3);
+        assert_eq!(results.failed, 1);
+        assert_eq!(results.ignored, 1);
+        assert_eq!(results.failed_tests, vec!["tests::fails".to_string()]);
+        assert_eq!(results.status(), VerificationStatus::Failed);
+    }
+
+    #[test]
+    fn test_parse_without_summary() {
+        let output = "error[E0425]: cannot find value `x` in this scope";
+        assert_eq!(UnitTestResults::parse(output), None);
+    }
+
+    #[test]
+    fn test_passing_status() {
+        let output = "test result: ok. 4 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out";
+        let results = UnitTestResults::parse(output).unwrap();
+        assert_eq!(results.passed, 4);
+        assert_eq!(results.status(), VerificationStatus::Verified);
+    }
+
+    #[test]
+    fn test_command_construction() {
+        let config = UnitTestConfig {
+            package: Some("aevion_shield".to_string()),
+            ..UnitTestConfig::default()
+        };
+        let command = cargo_test_command(&config);
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(
+            args,
+            ["test", "--package", "aevion_shield", "--no-fail-fast"]
+        );
+
+        let command = cargo_test_command(&UnitTestConfig::default());
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, ["test", "--all", "--no-fail-fast"]);
+    }
+
+    #[test]
+    fn test_fixture_crate_with_failing_test() {
+        let config = UnitTestConfig {
+            path: Path::new(env!("CARGO_MANIFEST_DIR")).join("src/fixtures/failing_crate"),
+            target_dir: Some(std::env::temp_dir().join("aevion-shield-fixture-target")),
+            ..UnitTestConfig::default()
+        };
+        let (status, results) = run_unit_tests(&config).unwrap();
+        let results = results.unwrap();
+
+        assert_eq!(status, VerificationStatus::Failed);
+        assert_eq!(results.passed, 1);
+        assert_eq!(results.failed, 1);
+        assert_eq!(results.ignored, 1);
+        assert_eq!(results.failed_tests, vec!["tests::fails".to_string()]);
+    }
+}
@@ -0,0 +1,420 @@
+//! # Output Variance
+//!
+//! Executable counterpart of the statistics in `variance_halt.rs`.
+//! [`variance_scaled`] is the specification's `variance_scaled`: the mean
+//! squared deviation of a round's outputs from their rounded-down mean,
+//! scaled by 100 and rounded down. It checks the preconditions Verus
+//! discharges statically, at least one and at most [`MAX_OUTPUTS`]
+//! outputs, each at most [`MAX_OUTPUT`] (`output_bounded`), and rejects
+//! anything else with a [`VarianceError`].
+//!
+//! Within those bounds no intermediate exceeds 10^16 and the variance is
+//! at most 10^10 (`variance_no_overflow`), so the result always fits a
+//! u64. The sums are accumulated in u128 regardless, so no input wraps
+//! before it is checked.
+//!
+//! [`OnlineVariance`] keeps the same variance as outputs arrive, in O(1)
+//! per output: Welford's algorithm, kept exact in integers, so after every
+//! push it equals [`variance_scaled`] of the outputs so far
+//! (`online_variance_matches_batch`) rather than approximating it.
+//!
+//! ```
+//! use aevion_shield::variance::{variance_scaled, OnlineVariance};
+//!
+//! // Mean 9280; deviations 0, 1 and 1 square to 2, over 3, times 100
+//! assert_eq!(variance_scaled(&[9280, 9281, 9279]), Ok(66));
+//!
+//! let mut online = OnlineVariance::new();
+//! for output in [9280, 9281, 9279] {
+//!     online.push(output).unwrap();
+//! }
+//! assert_eq!(online.variance_scaled(), Ok(66));
+//! ```
+//!
+//! Copyright (c) 2026 Aevion LLC. All rights reserved.
+
+use std::fmt;
+
+/// Highest output the variance accepts: 100.00 scaled by 100
+/// (`output_bounded`)
+pub const MAX_OUTPUT: u64 = 10_000;
+
+/// Most outputs the variance accepts (`MAX_OUTPUTS` in the specification)
+pub const MAX_OUTPUTS: usize = 1_000_000;
+
+/// Outputs [`variance_scaled`] cannot take
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarianceError {
+    /// No outputs: the variance is undefined
+    Empty,
+    /// More than [`MAX_OUTPUTS`] outputs
+    TooManyOutputs { len: usize },
+    /// An output above [`MAX_OUTPUT`]
+    OutputOutOfRange { index: usize, value: u64 },
+}
+
+impl fmt::Display for VarianceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VarianceError::Empty => write!(f, "variance of no outputs"),
+            VarianceError::TooManyOutputs { len } => write!(
+                f,
+                "{} outputs is more than the {} the variance accepts",
+                len, MAX_OUTPUTS
+            ),
+            VarianceError::OutputOutOfRange { index, value } => write!(
+                f,
+                "output {} = {} is outside 0..={}",
+                index, value, MAX_OUTPUT
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VarianceError {}
+
+/// `outputs` once they meet `variance_no_overflow`'s preconditions
+fn checked(outputs: &[u64]) -> Result<&[u64], VarianceError> {
+    if outputs.is_empty() {
+        return Err(VarianceError::Empty);
+    }
+    if outputs.len() > MAX_OUTPUTS {
+        return Err(VarianceError::TooManyOutputs { len: outputs.len() });
+    }
+    match outputs.iter().position(|&x| x > MAX_OUTPUT) {
+        Some(index) => Err(VarianceError::OutputOutOfRange {
+            index,
+            value: outputs[index],
+        }),
+        None => Ok(outputs),
+    }
+}
+
+/// `mean`: the outputs' mean, rounded down; at most [`MAX_OUTPUT`]
+/// (`bounded_outputs_bounded_mean`)
+pub fn mean(outputs: &[u64]) -> Result<u64, VarianceError> {
+    let outputs = checked(outputs)?;
+    let sum: u128 = outputs.iter().map(|&x| u128::from(x)).sum();
+    // At most MAX_OUTPUT (variance_no_overflow)
+    Ok((sum / outputs.len() as u128) as u64)
+}
+
+/// `variance_scaled`: the mean squared deviation from the rounded-down
+/// [`mean`], scaled by 100 and rounded down; at most 10^10
+/// (`variance_no_overflow`)
+pub fn variance_scaled(outputs: &[u64]) -> Result<u64, VarianceError> {
+    let mu = mean(outputs)?;
+    let ssd: u128 = outputs
+        .iter()
+        .map(|&x| u128::from(x.abs_diff(mu)).pow(2))
+        .sum();
+    let variance = ssd * 100 / outputs.len() as u128;
+    // Proven at most 10^10; checked rather than truncated all the same
+    Ok(u64::try_from(variance).expect("variance_no_overflow"))
+}
+
+/// The variance of outputs pushed one at a time (`welford` in the
+/// specification)
+///
+/// Welford's update moves the sum of squared deviations from the mean,
+/// M2, by `(x - mean)^2 * n / (n + 1)` for the (n + 1)th output `x`. Kept
+/// as `n * M2` over the exact sum, both sides of that update are integers
+/// and the division is exact (`lemma_welford_exact`), so nothing is
+/// rounded until [`variance_scaled`](Self::variance_scaled) measures from
+/// the rounded-down mean as the batch [`variance_scaled`] does. Every
+/// intermediate is at most 10^26, inside a u128 (`welford_no_overflow`).
+///
+/// An accumulator holds one round's outputs; [`reset`](Self::reset) it
+/// before the next.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OnlineVariance {
+    count: usize,
+    sum: u128,
+    /// n * M2: n times the sum of squared deviations from the exact mean
+    spread: u128,
+}
+
+impl OnlineVariance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an output, rejected above [`MAX_OUTPUT`] or past
+    /// [`MAX_OUTPUTS`]; a rejected output leaves the accumulator unchanged
+    pub fn push(&mut self, output: u64) -> Result<(), VarianceError> {
+        if output > MAX_OUTPUT {
+            return Err(VarianceError::OutputOutOfRange {
+                index: self.count,
+                value: output,
+            });
+        }
+        if self.count == MAX_OUTPUTS {
+            return Err(VarianceError::TooManyOutputs {
+                len: self.count + 1,
+            });
+        }
+        let n = self.count as u128;
+        let x = u128::from(output);
+        if n > 0 {
+            // n * (x - mean), so delta^2 / (n * (n + 1)) is Welford's step
+            let delta = (n * x).abs_diff(self.sum);
+            self.spread = ((n + 1) * self.spread + delta * delta) / n;
+        }
+        self.sum += x;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Outputs pushed since the accumulator was made or reset
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// [`mean`] of the outputs pushed
+    pub fn mean(&self) -> Result<u64, VarianceError> {
+        if self.count == 0 {
+            return Err(VarianceError::Empty);
+        }
+        Ok((self.sum / self.count as u128) as u64)
+    }
+
+    /// [`variance_scaled`] of the outputs pushed, exactly
+    pub fn variance_scaled(&self) -> Result<u64, VarianceError> {
+        let mu = u128::from(self.mean()?);
+        let n = self.count as u128;
+        // Squared deviations from the rounded-down mean: n * M2 plus n
+        // times the square of the mean's fractional part, over n
+        let remainder = self.sum - n * mu;
+        let ssd = (self.spread + remainder * remainder) / n;
+        let variance = ssd * 100 / n;
+        Ok(u64::try_from(variance).expect("variance_no_overflow"))
+    }
+
+    /// Forget every output, for the next round
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Rng;
+
+    /// The variance in f64 from the same rounded-down mean: the integer
+    /// result truncates it, so it is at most one unit below
+    fn reference(outputs: &[u64]) -> f64 {
+        let n = outputs.len() as f64;
+        let mu = (outputs.iter().sum::<u64>() / outputs.len() as u64) as f64;
+        100.0
+            * outputs
+                .iter()
+                .map(|&x| (x as f64 - mu).powi(2))
+                .sum::<f64>()
+            / n
+    }
+
+    #[test]
+    fn test_rejects_inputs_outside_the_preconditions() {
+        assert_eq!(variance_scaled(&[]), Err(VarianceError::Empty));
+        assert_eq!(mean(&[]), Err(VarianceError::Empty));
+        let err = variance_scaled(&[0, 10_000, 10_001]).unwrap_err();
+        assert_eq!(
+            err,
+            VarianceError::OutputOutOfRange {
+                index: 2,
+                value: 10_001
+            }
+        );
+        assert_eq!(err.to_string(), "output 2 = 10001 is outside 0..=10000");
+        assert!(matches!(
+            variance_scaled(&[0, u64::MAX]),
+            Err(VarianceError::OutputOutOfRange { index: 1, .. })
+        ));
+
+        let too_many = vec![0; MAX_OUTPUTS + 1];
+        assert_eq!(
+            variance_scaled(&too_many),
+            Err(VarianceError::TooManyOutputs {
+                len: MAX_OUTPUTS + 1
+            })
+        );
+        assert_eq!(variance_scaled(&too_many[1..]), Ok(0));
+    }
+
+    #[test]
+    fn test_boundary_values() {
+        // Constant outputs, at either end of the range, do not vary
+        for value in [0, 1, MAX_OUTPUT - 1, MAX_OUTPUT] {
+            assert_eq!(variance_scaled(&[value]), Ok(0));
+            assert_eq!(variance_scaled(&[value; 100]), Ok(0));
+        }
+
+        // Half at each end: mean 5000, every deviation 5000
+        assert_eq!(variance_scaled(&[0, MAX_OUTPUT]), Ok(2_500_000_000));
+
+        // One output at the far end of a million: the rounded-down mean
+        // is 0, and its one deviation of 10000 squared, times 100,
+        // averaged over a million outputs
+        let mut outputs = vec![0; MAX_OUTPUTS];
+        outputs[MAX_OUTPUTS - 1] = MAX_OUTPUT;
+        assert_eq!(mean(&outputs), Ok(0));
+        assert_eq!(variance_scaled(&outputs), Ok(10_000));
+
+        // A million outputs split between the ends: the widest spread at
+        // the largest count, a sum of squared deviations of 2.5 * 10^13
+        // scaled to 2.5 * 10^15 before dividing
+        let outputs: Vec<u64> = (0..MAX_OUTPUTS as u64)
+            .map(|i| MAX_OUTPUT * (i % 2))
+            .collect();
+        assert_eq!(mean(&outputs), Ok(5000));
+        assert_eq!(variance_scaled(&outputs), Ok(2_500_000_000));
+    }
+
+    #[test]
+    fn test_matches_f64_reference() {
+        let mut rng = Rng(0x5eed_1632);
+        for _ in 0..2000 {
+            let n = 1 + rng.below(300) as usize;
+            // Around a centre, or anywhere in range, or only the extremes
+            let outputs: Vec<u64> = match rng.below(3) {
+                0 => {
+                    let centre = rng.below(MAX_OUTPUT + 1);
+                    let spread = 1 + rng.below(200);
+                    (0..n)
+                        .map(|_| (centre + rng.below(spread)).min(MAX_OUTPUT))
+                        .collect()
+                }
+                1 => (0..n).map(|_| rng.below(MAX_OUTPUT + 1)).collect(),
+                _ => (0..n).map(|_| MAX_OUTPUT * rng.below(2)).collect(),
+            };
+            let variance = variance_scaled(&outputs).unwrap();
+            let real = reference(&outputs);
+            // Truncation: at most the exact value, less than a unit below
+            // it, with f64 rounding slack far below a unit
+            assert!(
+                variance as f64 <= real + 1e-6 && real < variance as f64 + 1.0 + 1e-6,
+                "{} against {} for {:?}",
+                variance,
+                real,
+                outputs
+            );
+            assert!(variance <= 10_000_000_000);
+        }
+    }
+
+    #[test]
+    fn test_low_variance_bounds_every_deviation() {
+        // low_variance_implies_agreement: below a variance of 1, every
+        // squared deviation from the mean is below the count
+        let mut rng = Rng(0x5eed_1634);
+        for _ in 0..5000 {
+            let n = 3 + rng.below(98) as usize;
+            let centre = rng.below(MAX_OUTPUT + 1);
+            let mut outputs: Vec<u64> = (0..n)
+                .map(|_| (centre + rng.below(2)).min(MAX_OUTPUT))
+                .collect();
+            let outlier = rng.below(n as u64) as usize;
+            outputs[outlier] = (centre + rng.below(12)).min(MAX_OUTPUT);
+            let variance = variance_scaled(&outputs).unwrap();
+            if variance >= 100 {
+                continue;
+            }
+            let mu = mean(&outputs).unwrap();
+            for &x in &outputs {
+                let d = x.abs_diff(mu);
+                assert!(d * d < n as u64 && d < 10, "{:?}", outputs);
+            }
+        }
+
+        // Outputs that all equal their mean do not vary
+        // (variance_non_negative)
+        assert_eq!(variance_scaled(&[4200; 7]), Ok(0));
+
+        // Past 100 outputs one outlier hides in the average
+        let mut outputs = vec![0; 1000];
+        outputs[999] = 20;
+        assert_eq!(variance_scaled(&outputs), Ok(40));
+    }
+
+    #[test]
+    fn test_online_matches_batch_at_every_prefix() {
+        let mut rng = Rng(0x5eed_1633);
+        for _ in 0..300 {
+            let n = 1 + rng.below(200) as usize;
+            let centre = rng.below(MAX_OUTPUT + 1);
+            let spread = 1 + rng.below(MAX_OUTPUT + 1);
+            let outputs: Vec<u64> = (0..n)
+                .map(|_| match rng.below(10) {
+                    0 => 0,
+                    1 => MAX_OUTPUT,
+                    _ => (centre + rng.below(spread)).min(MAX_OUTPUT),
+                })
+                .collect();
+            let mut online = OnlineVariance::new();
+            for (i, &output) in outputs.iter().enumerate() {
+                online.push(output).unwrap();
+                let prefix = &outputs[..=i];
+                assert_eq!(online.count(), i + 1);
+                assert_eq!(online.mean(), mean(prefix));
+                assert_eq!(
+                    online.variance_scaled(),
+                    variance_scaled(prefix),
+                    "{:?}",
+                    prefix
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_online_rejects_and_resets() {
+        let mut online = OnlineVariance::new();
+        assert_eq!(online.variance_scaled(), Err(VarianceError::Empty));
+        assert_eq!(online.mean(), Err(VarianceError::Empty));
+        online.push(9280).unwrap();
+        online.push(9281).unwrap();
+        let before = online.clone();
+        assert_eq!(
+            online.push(MAX_OUTPUT + 1),
+            Err(VarianceError::OutputOutOfRange {
+                index: 2,
+                value: MAX_OUTPUT + 1
+            })
+        );
+        assert_eq!(online, before);
+
+        // A new round starts only when reset
+        online.push(100).unwrap();
+        assert_eq!(
+            online.variance_scaled(),
+            variance_scaled(&[9280, 9281, 100])
+        );
+        online.reset();
+        assert_eq!(online, OnlineVariance::new());
+        online.push(100).unwrap();
+        assert_eq!(online.variance_scaled(), Ok(0));
+    }
+
+    #[test]
+    fn test_online_at_the_bounds() {
+        // A million outputs split between the ends, as in
+        // test_boundary_values, then one more is refused
+        let outputs: Vec<u64> = (0..MAX_OUTPUTS as u64)
+            .map(|i| MAX_OUTPUT * (i % 2))
+            .collect();
+        let mut online = OnlineVariance::new();
+        for &output in &outputs {
+            online.push(output).unwrap();
+        }
+        assert_eq!(online.variance_scaled(), Ok(2_500_000_000));
+        assert_eq!(online.variance_scaled(), variance_scaled(&outputs));
+        assert_eq!(
+            online.push(0),
+            Err(VarianceError::TooManyOutputs {
+                len: MAX_OUTPUTS + 1
+            })
+        );
+        assert_eq!(online.count(), MAX_OUTPUTS);
+    }
+}
@@ -95,11 +95,27 @@ pub open spec fn should_halt(current_variance_scaled: u64, baseline_variance_sca
 // MAIN THEOREMS
 // ============================================================================
 
+/// Highest baseline variance (scaled by 100, sigma ~5.9) at which a round
+/// that does not halt is guaranteed correct_consensus
+///
+/// Within 6.25x of it the variance is at most 21875; the executable
+/// engine, which rounds the ratio down, lets through anything below 6.26x,
+/// at most 21909. Both are below correct_consensus's 22500. At 3600 the
+/// threshold reaches 22500 itself, and at the old bound of 10000 it is
+/// 62500: outputs varying by 50000 (sigma ~22) pass it without being
+/// correct.
+pub const MAX_CORRECTNESS_BASELINE: u64 = 3500;
+
 /// THEOREM 1: Variance Halt Correctness
 ///
 /// If variance exceeds threshold, the system correctly identifies potential Byzantine attack.
 /// If variance is below threshold, consensus output is reliable.
 ///
+/// Reliable is the absolute correct_consensus (sigma < 15), so the
+/// guarantee needs a baseline small enough that 6.25x of it is below that:
+/// at most MAX_CORRECTNESS_BASELINE. Above it a round may neither halt
+/// nor be correct_consensus.
+///
 /// This formalizes the Constitutional Halt mechanism from Patent Claim 3.
 proof fn variance_halt_correctness(
     n: nat,
@@ -112,11 +128,9 @@ proof fn variance_halt_correctness(
         bounded_faults(n, f),
         outputs.len() == n,
         baseline_variance_scaled > 0,
-        baseline_variance_scaled <= 10000,  // Baseline sigma <= 10
+        baseline_variance_scaled <= MAX_CORRECTNESS_BASELINE,  // Baseline sigma <= ~5.9
         all_outputs_bounded(outputs),
     ensures
-        // Core invariant: if no halt, then consensus is likely correct
-        // (probabilistic guarantee from empirical validation)
         ({
             let current_var = variance_scaled(outputs);
             let thresh = halt_threshold_scaled(baseline_variance_scaled);
@@ -124,32 +138,21 @@ proof fn variance_halt_correctness(
             current_var <= thresh ==> correct_consensus(outputs)
         })
 {
-    // Proof approach:
-    // 1. If f = 0 (no Byzantine), all honest agents produce similar outputs
-    //    => variance bounded by natural model disagreement
-    //    => variance <= baseline * 1.5^2 = 2.25 * baseline
-    //    => since 2.25 < 6.25 (our threshold factor), no halt
-    //    => consensus correct
-    //
-    // 2. If f > 0 but f < n/3, Byzantine outputs are minority
-    //    => variance may increase but majority agreement dominates
-    //    => if variance still below threshold, consensus still correct
-    //
-    // 3. If variance > threshold, HALT triggered
-    //    => system refuses to output potentially corrupted consensus
-    //    => safe failure mode
-
     let current_var = variance_scaled(outputs);
     let thresh = halt_threshold_scaled(baseline_variance_scaled);
+    // The mean half of correct_consensus holds for any bounded outputs
+    bounded_outputs_bounded_mean(outputs);
+    variance_non_negative(outputs);
 
-    // The key insight: our threshold (6.25x baseline variance) is calibrated
-    // such that natural variation (up to ~2x baseline) doesn't trigger halt,
-    // but Byzantine manipulation (causing >2.5x sigma deviation) does.
+    // The threshold itself is below sigma = 15
+    assert(thresh <= 21875) by(nonlinear_arith)
+        requires
+            thresh == (625 * baseline_variance_scaled) / 100,
+            baseline_variance_scaled <= MAX_CORRECTNESS_BASELINE;
 
     if current_var <= thresh {
-        // Below threshold => variance is bounded
-        // From empirical data: 92.2% accuracy under stealth attacks
-        // This means low variance correlates with correct consensus
+        // Below threshold => variance below 22500, the variance half
+        assert(current_var < 22500);
         assert(correct_consensus(outputs));
     }
 }
@@ -242,31 +245,120 @@ proof fn bounded_outputs_bounded_mean(outputs: Seq<u64>)
         mean(outputs) <= 10000
 {
     // If all elements <= 10000, their sum <= n * 10000
+    lemma_output_sum_bounded(outputs);
+    let n = outputs.len();
+    let sum = outputs.fold_left(0u64, |acc: u64, x: u64| acc + x);
     // Mean = sum / n <= 10000
+    assert(sum / n <= 10000) by(nonlinear_arith)
+        requires sum <= n * 10000, n > 0;
 }
 
-/// Lemma: Variance is non-negative
+/// The sum of squared deviations vanishes when every output is `mu`
+proof fn lemma_ssd_zero(outputs: Seq<u64>, mu: u64)
+    requires
+        forall|i: int| 0 <= i < outputs.len() ==> #[trigger] outputs[i] == mu,
+    ensures
+        sum_squared_deviations(outputs, mu) == 0,
+    decreases outputs.len()
+{
+    if outputs.len() > 0 {
+        let rest = outputs.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies #[trigger] rest[i] == mu by {
+            assert(rest[i] == outputs[i]);
+        }
+        lemma_ssd_zero(rest, mu);
+        assert(outputs.last() == mu);
+    }
+}
+
+/// Lemma: Variance is non-negative, and zero when the outputs agree
+///
+/// Non-negativity alone is immediate for a u64; the content is that
+/// outputs which all equal their mean have no variance at all.
 proof fn variance_non_negative(outputs: Seq<u64>)
     requires
         outputs.len() > 0,
     ensures
-        variance_scaled(outputs) >= 0
+        variance_scaled(outputs) >= 0,
+        (forall|i: int| 0 <= i < outputs.len() ==> #[trigger] outputs[i] == mean(outputs))
+            ==> variance_scaled(outputs) == 0,
+{
+    if forall|i: int| 0 <= i < outputs.len() ==> #[trigger] outputs[i] == mean(outputs) {
+        // Sum of squared deviations is zero, and so is any multiple of it
+        lemma_ssd_zero(outputs, mean(outputs));
+    }
+}
+
+/// Any one output's squared deviation is part of the sum of squared
+/// deviations
+proof fn lemma_deviation_within_ssd(outputs: Seq<u64>, mu: u64, i: int)
+    requires
+        0 <= i < outputs.len(),
+        outputs.len() <= MAX_OUTPUTS,
+        all_outputs_bounded(outputs),
+        mu <= 10000,
+    ensures
+        (outputs[i] as int - mu as int) * (outputs[i] as int - mu as int)
+            <= sum_squared_deviations(outputs, mu),
+    decreases outputs.len()
 {
-    // Sum of squared deviations is always non-negative
+    let rest = outputs.drop_last();
+    assert forall|j: int| 0 <= j < rest.len() implies output_bounded(#[trigger] rest[j]) by {
+        assert(rest[j] == outputs[j]);
+    }
+    // No term wraps, so the sum only grows
+    lemma_ssd_bounded(outputs, mu);
+    let x = outputs.last();
+    let diff = if x >= mu { x - mu } else { mu - x };
+    assert(sum_squared_deviations(outputs, mu) == sum_squared_deviations(rest, mu) + diff * diff);
+    if i < rest.len() {
+        lemma_deviation_within_ssd(rest, mu, i);
+        assert(rest[i] == outputs[i]);
+    } else {
+        assert((x as int - mu as int) * (x as int - mu as int) == diff * diff) by(nonlinear_arith)
+            requires diff == x - mu || diff == mu - x;
+    }
 }
 
 /// Lemma: Low variance implies agreement
+///
+/// Chebyshev's bound for one output: a single output d away from the mean
+/// alone contributes d^2 to the sum of squared deviations, so a variance
+/// below 1 (scaled: 100) keeps every d^2 below n. For committees of at
+/// most 100 agents that puts every output within 10 of the mean; in a
+/// larger round one outlier among many agreeing outputs hides in the
+/// average (999 zeros and a 20 have variance 0.4).
 proof fn low_variance_implies_agreement(outputs: Seq<u64>)
     requires
         outputs.len() >= 3,
+        outputs.len() <= 100,
         all_outputs_bounded(outputs),
         variance_scaled(outputs) < 100,  // Very low variance (sigma < 1)
     ensures
-        // All outputs within ~3 units of mean
+        forall|i: int| 0 <= i < outputs.len() ==>
+            (#[trigger] outputs[i] as int - mean(outputs) as int)
+                * (outputs[i] as int - mean(outputs) as int) < outputs.len(),
+        // All outputs within 10 units (0.1 scaled) of the mean
         forall|i: int| 0 <= i < outputs.len() ==>
             (#[trigger] outputs[i] as int - mean(outputs) as int).abs() < 10
 {
-    // Low variance means outputs cluster tightly around mean
+    let n = outputs.len();
+    let mu = mean(outputs);
+    bounded_outputs_bounded_mean(outputs);
+    lemma_ssd_bounded(outputs, mu);
+    let ssd = sum_squared_deviations(outputs, mu);
+    // (ssd * 100) / n < 100 leaves ssd < n
+    assert(ssd < n) by(nonlinear_arith)
+        requires (ssd * 100) / n < 100, n > 0;
+    assert forall|i: int| 0 <= i < n implies
+        (#[trigger] outputs[i] as int - mu as int) * (outputs[i] as int - mu as int) < n
+        && (outputs[i] as int - mu as int).abs() < 10 by {
+        lemma_deviation_within_ssd(outputs, mu, i);
+        let d = outputs[i] as int - mu as int;
+        // A far outlier alone would exceed the bound: |d| >= 10 gives d^2 >= 100 >= n
+        assert(d.abs() < 10) by(nonlinear_arith)
+            requires d * d < n, n <= 100;
+    }
 }
 
 // ============================================================================
@@ -292,6 +384,44 @@ pub open spec fn constitutional_halt_decision(
     high_variance || low_agreement
 }
 
+/// Why a round halted (as in byzantine_consensus.rs; only the variance
+/// reason arises here)
+pub enum HaltReason {
+    /// Neither value reached the threshold; the majority value's share
+    NoSupermajority { agreement_pct: u64 },
+    /// Current / baseline variance (scaled by 100) above the halt multiple
+    VarianceExceeded { ratio_scaled: u64 },
+    /// Too few votes to decide
+    InsufficientVoters { n: u64 },
+    /// Every vote carries zero weight; the highest voter trust
+    TrustCollapse { max_trust: u64 },
+}
+
+/// Specification: Current variance as a multiple of the baseline, scaled
+/// by 100 (625 is the 6.25x halt multiple)
+pub open spec fn variance_ratio_scaled(outputs: Seq<u64>, baseline_variance_scaled: u64) -> u64
+    recommends baseline_variance_scaled > 0
+{
+    ((variance_scaled(outputs) as nat * 100) / (baseline_variance_scaled as nat)) as u64
+}
+
+/// Specification: Why constitutional_halt_decision halts, if it does
+///
+/// Both of its conditions measure variance, so a halt is VarianceExceeded.
+pub open spec fn constitutional_halt_reason(
+    outputs: Seq<u64>,
+    baseline_variance_scaled: u64,
+    agreement_threshold_pct: u64,
+) -> Option<HaltReason> {
+    if constitutional_halt_decision(outputs, baseline_variance_scaled, agreement_threshold_pct) {
+        Some(HaltReason::VarianceExceeded {
+            ratio_scaled: variance_ratio_scaled(outputs, baseline_variance_scaled),
+        })
+    } else {
+        None
+    }
+}
+
 /// THEOREM 5: Constitutional Halt Safety
 ///
 /// The Constitutional Halt mechanism never halts when all agents are honest
@@ -306,12 +436,14 @@ proof fn constitutional_halt_safety(
         outputs.len() == n,
         all_outputs_bounded(outputs),
         baseline_variance_scaled > 0,
-        baseline_variance_scaled <= 10000,
+        // 2 * baseline must stay within the low-agreement proxy (10000)
+        baseline_variance_scaled <= 5000,
         // All honest: variance <= 2 * baseline (natural disagreement)
         variance_scaled(outputs) <= 2 * baseline_variance_scaled,
     ensures
         // No false positive halts when all honest
-        !constitutional_halt_decision(outputs, baseline_variance_scaled, 67)
+        !constitutional_halt_decision(outputs, baseline_variance_scaled, 67),
+        constitutional_halt_reason(outputs, baseline_variance_scaled, 67) is None,
 {
     // When all agents are honest, variance is bounded by natural disagreement
     // which is well below the 6.25x threshold
@@ -323,6 +455,8 @@ proof fn constitutional_halt_safety(
     assert(thresh == (625 * baseline_variance_scaled) / 100);
     // 2 * baseline < 6.25 * baseline for baseline > 0
     assert(current_var < thresh);
+    // and 2 * baseline <= 10000, so the low-agreement proxy stays quiet
+    assert(current_var <= 10000);
 }
 
 /// THEOREM 6: Constitutional Halt Liveness
@@ -344,8 +478,12 @@ proof fn constitutional_halt_liveness(
         // Byzantine majority causes high variance: > 10x baseline
         variance_scaled(outputs) > 10 * baseline_variance_scaled,
     ensures
-        // Halt correctly triggers
-        constitutional_halt_decision(outputs, baseline_variance_scaled, 67)
+        // Halt correctly triggers, reporting the variance spike
+        constitutional_halt_decision(outputs, baseline_variance_scaled, 67),
+        constitutional_halt_reason(outputs, baseline_variance_scaled, 67)
+            == Some(HaltReason::VarianceExceeded {
+                ratio_scaled: variance_ratio_scaled(outputs, baseline_variance_scaled),
+            }),
 {
     // When majority is Byzantine, adversarial outputs cause high variance
     // 10 * baseline > 6.25 * baseline, so halt triggers
@@ -358,6 +496,330 @@ proof fn constitutional_halt_liveness(
     assert(current_var > thresh);
 }
 
+// ============================================================================
+// EXECUTABLE VARIANCE
+// ============================================================================
+//
+// Within the bounds the executable `variance::variance_scaled` checks (at
+// most MAX_OUTPUTS outputs, each output_bounded) the sum is at most 10^10,
+// the sum of squared deviations at most 10^14 and its 100-fold 10^16, so
+// every intermediate and the result fit a u64 with room to spare. The
+// executable code accumulates in u128 anyway, so an input it rejects
+// cannot wrap before it is rejected.
+
+/// Most outputs the executable variance accepts
+pub const MAX_OUTPUTS: u64 = 1_000_000;
+
+/// The outputs sum to at most 10000 each
+proof fn lemma_output_sum_bounded(outputs: Seq<u64>)
+    requires
+        all_outputs_bounded(outputs),
+    ensures
+        outputs.fold_left(0u64, |acc: u64, x: u64| acc + x) <= outputs.len() * 10000,
+    decreases outputs.len()
+{
+    if outputs.len() > 0 {
+        let rest = outputs.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies output_bounded(#[trigger] rest[i]) by {
+            assert(rest[i] == outputs[i]);
+        }
+        lemma_output_sum_bounded(rest);
+        assert(output_bounded(outputs.last()));
+    }
+}
+
+/// Every squared deviation from a bounded mean is at most 10^8
+proof fn lemma_ssd_bounded(outputs: Seq<u64>, mu: u64)
+    requires
+        all_outputs_bounded(outputs),
+        mu <= 10000,
+    ensures
+        sum_squared_deviations(outputs, mu) <= outputs.len() * 100_000_000,
+    decreases outputs.len()
+{
+    if outputs.len() > 0 {
+        let rest = outputs.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies output_bounded(#[trigger] rest[i]) by {
+            assert(rest[i] == outputs[i]);
+        }
+        lemma_ssd_bounded(rest, mu);
+        let x = outputs.last();
+        assert(output_bounded(x));
+        let diff = if x >= mu { x - mu } else { mu - x };
+        assert(diff * diff <= 100_000_000) by(nonlinear_arith)
+            requires diff <= 10000;
+    }
+}
+
+/// THEOREM 7: Variance Does Not Overflow
+///
+/// For 1 to MAX_OUTPUTS bounded outputs the sum, the mean, the sum of
+/// squared deviations and its 100-fold all fit a u64, and the variance is
+/// at most 10^10 (a spread of 10000 either side, scaled by 100).
+///
+/// Executable counterpart: `variance::variance_scaled`, which rejects
+/// anything outside these preconditions with a `VarianceError`.
+proof fn variance_no_overflow(outputs: Seq<u64>)
+    requires
+        0 < outputs.len() <= MAX_OUTPUTS,
+        all_outputs_bounded(outputs),
+    ensures
+        outputs.fold_left(0u64, |acc: u64, x: u64| acc + x) <= 10_000_000_000,
+        mean(outputs) <= 10000,
+        sum_squared_deviations(outputs, mean(outputs)) <= 100_000_000_000_000,
+        sum_squared_deviations(outputs, mean(outputs)) * 100 <= 10_000_000_000_000_000,
+        10_000_000_000_000_000 <= u64::MAX,
+        variance_scaled(outputs) <= 10_000_000_000,
+{
+    let n = outputs.len();
+    lemma_output_sum_bounded(outputs);
+    assert(n * 10000 <= 10_000_000_000) by(nonlinear_arith)
+        requires n <= 1_000_000;
+    bounded_outputs_bounded_mean(outputs);
+    let mu = mean(outputs);
+    lemma_ssd_bounded(outputs, mu);
+    let ssd = sum_squared_deviations(outputs, mu);
+    assert(n * 100_000_000 <= 100_000_000_000_000) by(nonlinear_arith)
+        requires n <= 1_000_000;
+    assert((ssd * 100) / n <= 10_000_000_000) by(nonlinear_arith)
+        requires ssd <= n * 100_000_000, n > 0;
+}
+
+// ============================================================================
+// ONLINE VARIANCE
+// ============================================================================
+//
+// `variance::OnlineVariance` takes outputs one at a time (Welford's
+// algorithm) in integers: it keeps the count n, the sum S and
+// D = n * M2 = n * Q - S^2, where Q is the sum of squares. Each output x
+// moves D to ((n + 1) * D + (n * x - S)^2) / n, a division that is always
+// exact. The rounded-down mean mu = S / n leaves r = S - n * mu, and the
+// batch sum of squared deviations from mu is (D + r^2) / n, so the online
+// variance is the batch variance exactly, not to within rounding.
+
+/// Sum of the outputs
+pub open spec fn output_sum(outputs: Seq<u64>) -> int
+    decreases outputs.len()
+{
+    if outputs.len() == 0 {
+        0
+    } else {
+        output_sum(outputs.drop_last()) + outputs.last()
+    }
+}
+
+/// Sum of the outputs' squares
+pub open spec fn output_square_sum(outputs: Seq<u64>) -> int
+    decreases outputs.len()
+{
+    if outputs.len() == 0 {
+        0
+    } else {
+        output_square_sum(outputs.drop_last()) + outputs.last() * outputs.last()
+    }
+}
+
+/// Specification: Welford's accumulator (n, S, n * M2) after pushing
+/// `outputs` in order
+pub open spec fn welford(outputs: Seq<u64>) -> (nat, int, int)
+    decreases outputs.len()
+{
+    if outputs.len() == 0 {
+        (0, 0, 0)
+    } else {
+        let (n, sum, spread) = welford(outputs.drop_last());
+        let x = outputs.last() as int;
+        if n == 0 {
+            (1, x, 0)
+        } else {
+            let delta = n * x - sum;
+            ((n + 1) as nat, sum + x, ((n + 1) * spread + delta * delta) / (n as int))
+        }
+    }
+}
+
+/// Specification: the variance read off the accumulator, scaled by 100
+pub open spec fn online_variance_scaled(outputs: Seq<u64>) -> int
+    recommends outputs.len() > 0
+{
+    let (n, sum, spread) = welford(outputs);
+    let mu = sum / (n as int);
+    let remainder = sum - n * mu;
+    ((spread + remainder * remainder) / (n as int)) * 100 / (n as int)
+}
+
+/// The accumulator counts and sums exactly, and its spread is
+/// n * Q - S^2 with every division exact
+proof fn lemma_welford_exact(outputs: Seq<u64>)
+    ensures
+        welford(outputs).0 == outputs.len(),
+        welford(outputs).1 == output_sum(outputs),
+        welford(outputs).2 == outputs.len() * output_square_sum(outputs)
+            - output_sum(outputs) * output_sum(outputs),
+    decreases outputs.len()
+{
+    if outputs.len() > 0 {
+        let rest = outputs.drop_last();
+        lemma_welford_exact(rest);
+        let n = rest.len() as int;
+        let s = output_sum(rest);
+        let q = output_square_sum(rest);
+        let x = outputs.last() as int;
+        if n > 0 {
+            let d = n * q - s * s;
+            let target = (n + 1) * (q + x * x) - (s + x) * (s + x);
+            assert((n + 1) * d + (n * x - s) * (n * x - s) == n * target) by(nonlinear_arith)
+                requires d == n * q - s * s, target == (n + 1) * (q + x * x) - (s + x) * (s + x);
+            vstd::arithmetic::div_mod::lemma_div_by_multiple(target, n);
+        }
+    }
+}
+
+/// The sum of squared deviations from mu, expanded over S and Q
+proof fn lemma_ssd_expansion(outputs: Seq<u64>, mu: u64)
+    requires
+        all_outputs_bounded(outputs),
+        outputs.len() <= MAX_OUTPUTS,
+        mu <= 10000,
+    ensures
+        sum_squared_deviations(outputs, mu) == output_square_sum(outputs)
+            - 2 * mu * output_sum(outputs) + outputs.len() * mu * mu,
+    decreases outputs.len()
+{
+    if outputs.len() > 0 {
+        let rest = outputs.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies output_bounded(#[trigger] rest[i]) by {
+            assert(rest[i] == outputs[i]);
+        }
+        lemma_ssd_expansion(rest, mu);
+        lemma_ssd_bounded(outputs, mu);
+        let x = outputs.last() as int;
+        let diff = if x >= mu { x - mu } else { mu - x };
+        assert(diff * diff == x * x - 2 * mu * x + mu * mu) by(nonlinear_arith)
+            requires diff == x - mu || diff == mu - x;
+    }
+}
+
+/// THEOREM 8: Online Variance Matches Batch
+///
+/// For 1 to MAX_OUTPUTS bounded outputs, the variance read off Welford's
+/// accumulator equals the batch variance, rounding included.
+///
+/// Executable counterpart: `variance::OnlineVariance`, which the halt
+/// engine decides from (`ConsensusEngine::final_verdict_online`).
+proof fn online_variance_matches_batch(outputs: Seq<u64>)
+    requires
+        0 < outputs.len() <= MAX_OUTPUTS,
+        all_outputs_bounded(outputs),
+    ensures
+        online_variance_scaled(outputs) == variance_scaled(outputs),
+{
+    let n = outputs.len() as int;
+    lemma_welford_exact(outputs);
+    variance_no_overflow(outputs);
+    let s = output_sum(outputs);
+    let q = output_square_sum(outputs);
+    let d = n * q - s * s;
+    // The fold and the recursive sum agree on bounded outputs
+    assert(outputs.fold_left(0u64, |acc: u64, x: u64| acc + x) == s);
+    let mu = mean(outputs);
+    assert(mu == s / n);
+    let remainder = s - n * mu;
+    lemma_ssd_expansion(outputs, mu);
+    let ssd = sum_squared_deviations(outputs, mu);
+    assert(d + remainder * remainder == n * ssd) by(nonlinear_arith)
+        requires
+            d == n * q - s * s,
+            remainder == s - n * mu,
+            ssd == q - 2 * mu * s + n * mu * mu;
+    vstd::arithmetic::div_mod::lemma_div_by_multiple(ssd as int, n);
+}
+
+/// Every intermediate of an online push is at most 10^26
+///
+/// Before the (n + 1)th of at most MAX_OUTPUTS bounded outputs, the spread
+/// is at most n * Q <= 10^20, its (n + 1)-fold at most 10^26, and
+/// (n * x - S)^2 at most 10^20, all inside a u128.
+proof fn welford_no_overflow(outputs: Seq<u64>, x: u64)
+    requires
+        outputs.len() < MAX_OUTPUTS,
+        all_outputs_bounded(outputs),
+        output_bounded(x),
+    ensures
+        0 <= welford(outputs).2 <= 100_000_000_000_000_000_000,
+        (outputs.len() + 1) * welford(outputs).2 <= 100_000_000_000_000_000_000_000_000,
+        (outputs.len() * x - welford(outputs).1) * (outputs.len() * x - welford(outputs).1)
+            <= 100_000_000_000_000_000_000,
+        100_000_000_000_000_000_000_000_000 <= u128::MAX,
+{
+    let n = outputs.len() as int;
+    lemma_welford_exact(outputs);
+    lemma_output_sum_bounded(outputs);
+    let s = output_sum(outputs);
+    let q = output_square_sum(outputs);
+    assert(outputs.fold_left(0u64, |acc: u64, y: u64| acc + y) == s);
+    lemma_output_square_sum_bounded(outputs);
+    // S^2 <= n * Q keeps the spread non-negative
+    lemma_cauchy_schwarz(outputs);
+    assert(n * q <= 100_000_000_000_000_000_000) by(nonlinear_arith)
+        requires 0 <= q <= n * 100_000_000, n <= 1_000_000;
+    assert((n + 1) * (n * q - s * s) <= 100_000_000_000_000_000_000_000_000) by(nonlinear_arith)
+        requires 0 <= n * q - s * s <= 100_000_000_000_000_000_000, n < 1_000_000;
+    assert((n * x - s) * (n * x - s) <= 100_000_000_000_000_000_000) by(nonlinear_arith)
+        requires 0 <= s <= n * 10000, x <= 10000, n <= 1_000_000;
+}
+
+/// The squares of bounded outputs sum to at most 10^8 each
+proof fn lemma_output_square_sum_bounded(outputs: Seq<u64>)
+    requires
+        all_outputs_bounded(outputs),
+    ensures
+        0 <= output_square_sum(outputs) <= outputs.len() * 100_000_000,
+    decreases outputs.len()
+{
+    if outputs.len() > 0 {
+        let rest = outputs.drop_last();
+        assert forall|i: int| 0 <= i < rest.len() implies output_bounded(#[trigger] rest[i]) by {
+            assert(rest[i] == outputs[i]);
+        }
+        lemma_output_square_sum_bounded(rest);
+        let x = outputs.last() as int;
+        assert(output_bounded(outputs.last()));
+        assert(0 <= x * x <= 100_000_000) by(nonlinear_arith)
+            requires 0 <= x <= 10000;
+    }
+}
+
+/// S^2 <= n * Q, so the spread is never negative
+proof fn lemma_cauchy_schwarz(outputs: Seq<u64>)
+    ensures
+        output_sum(outputs) * output_sum(outputs) <= outputs.len() * output_square_sum(outputs),
+    decreases outputs.len()
+{
+    if outputs.len() > 0 {
+        let rest = outputs.drop_last();
+        lemma_cauchy_schwarz(rest);
+        lemma_welford_exact(outputs);
+        lemma_welford_exact(rest);
+        let n = rest.len() as int;
+        let s = output_sum(rest);
+        let q = output_square_sum(rest);
+        let x = outputs.last() as int;
+        // (n + 1)(Q + x^2) - (S + x)^2 = (n * Q - S^2) + (n * x - S)^2 / n
+        if n > 0 {
+            assert(n * (q + n * x * x - 2 * s * x) >= (s - n * x) * (s - n * x)) by(nonlinear_arith)
+                requires s * s <= n * q, n > 0;
+            assert(q + n * x * x - 2 * s * x >= 0) by(nonlinear_arith)
+                requires n * (q + n * x * x - 2 * s * x) >= (s - n * x) * (s - n * x), n > 0;
+        }
+        assert((s + x) * (s + x) <= (n + 1) * (q + x * x)) by(nonlinear_arith)
+            requires
+                s * s <= n * q,
+                n == 0 ==> s == 0 && q == 0,
+                n > 0 ==> q + n * x * x - 2 * s * x >= 0;
+    }
+}
+
 } // verus!
 
 // ============================================================================